@@ -2,10 +2,11 @@
 //! host-assigned shared-memory descriptor (raw byte offsets) into safe Rust slices and typed state,
 //! so device DSP code is written entirely in safe Rust.
 //!
-//! Canonical descriptor (u32 words); every offset is a byte address into the shared linear memory:
+//! Canonical descriptor (pointer-sized words: `u32` on wasm32, a full address on a native host); every offset
+//! is a byte address into the shared linear memory:
 //!   [0]  frames
-//!   [1]  in_count       [2]  in_offsets_ptr   (-> u32[in_count],  each -> f32[frames])
-//!   [3]  out_count      [4]  out_offsets_ptr  (-> u32[out_count], each -> f32[frames])
+//!   [1]  in_count       [2]  in_offsets_ptr   (-> word[in_count],  each -> f32[frames])
+//!   [3]  out_count      [4]  out_offsets_ptr  (-> word[out_count], each -> f32[frames])
 //!   [5]  param_count    [6]  params_ptr       (-> f32[param_count])
 //!   [7]  state_ptr      (-> device instance state)
 //!   [8]  in_event_cap   [9]  in_events_ptr    (-> EventRecord[in_event_cap]; a device-owned SCRATCH the
@@ -22,14 +23,20 @@
 //! The engine no longer PUSHES a resolved event array. A device PULLS its own input event stream for a
 //! pulse range through the `host_pull_events` host import (bound to the engine's export by the loader),
 //! into its `[8]/[9]` scratch, and times its own sub-blocks over the `[12]/[13]` blocks.
+//!
+//! Off wasm there is no engine to import from: every host call goes to the [`native::Host`] a native harness
+//! installs for the call, and falls back to an inert stub when none is.
 
-#![cfg_attr(not(test), no_std)]
+#![cfg_attr(target_family = "wasm", no_std)]
 
 use core::ptr::NonNull;
 use core::slice;
 
+#[cfg(not(target_family = "wasm"))]
+pub mod native;
+
 /// One timed note event. CLAP-shaped: a flat, `#[repr(C)]` record read straight from shared memory (no
-/// heap). `kind` is `EVENT_NOTE_ON` / `EVENT_NOTE_OFF`. It carries TWO time fields: `position` is the
+/// heap). `kind` is `EVENT_NOTE_ON` / `EVENT_NOTE_OFF` (or a channel / expression kind below). It carries TWO time fields: `position` is the
 /// pulse position, the currency the MIDI-fx pull chain works in (a groove device warps it, the host
 /// resolves the chain in pulses); `offset` is the sample offset within `[0, frames)`, which the CONSUMER
 /// (an instrument's `render_instrument`) fills from `position` for its DSP. MIDI fx read/write `position`
@@ -62,6 +69,133 @@ pub const EVENT_PARAM: u32 = 2;
 /// inter-slot firing); the slot's `handle_event` treats it as a forced release. Carried in the event stream
 /// like a note event, so it is sample-accurate via the same sub-block split.
 pub const EVENT_CHOKE: u32 = 3;
+/// A MIDI continuous controller (CC) change for the whole channel: `pitch` carries the controller number
+/// (`0..=127`, e.g. [`CC_MOD_WHEEL`], [`CC_SUSTAIN`]), `velocity` the value normalised to `0..1`. Not a note:
+/// `id` is 0, and an instrument that does not use the controller ignores it.
+pub const EVENT_CONTROL: u32 = 4;
+/// The channel pitch wheel: `velocity` is the bipolar position `-1..1` (0 centred). The instrument scales it by
+/// its own bend range ([`PITCH_BEND_RANGE`] semitones for the stock synths) and applies it to every voice.
+pub const EVENT_PITCH_BEND: u32 = 5;
+/// Channel aftertouch (channel pressure): `velocity` is the pressure `0..1`.
+pub const EVENT_CHANNEL_PRESSURE: u32 = 6;
+
+/// A per-note expression (CLAP note expression / MPE): modulates ONE sounding note, addressed by `id` (its
+/// note-on's id), while the note plays or releases. `pitch` carries the expression ([`EXPRESSION_TUNING`],
+/// [`EXPRESSION_PRESSURE`], [`EXPRESSION_BRIGHTNESS`]), `velocity` its value; decode it with
+/// [`NoteExpression::from_record`]. At an equal position it sorts AFTER the note-on, so the note exists.
+pub const EVENT_NOTE_EXPRESSION: u32 = 7;
+
+/// Tuning, in semitones relative to the note's own pitch (`-120..120`, 0 untuned). A glide is a stream of these.
+pub const EXPRESSION_TUNING: u32 = 0;
+/// Pressure (polyphonic aftertouch), `0..1`, 0 at rest.
+pub const EXPRESSION_PRESSURE: u32 = 1;
+/// Brightness (MPE timbre), bipolar `-1..1`, 0 neutral.
+pub const EXPRESSION_BRIGHTNESS: u32 = 2;
+
+/// A decoded [`EVENT_NOTE_EXPRESSION`] value, what a voice receives.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum NoteExpression {
+    Tuning(f32),
+    Pressure(f32),
+    Brightness(f32)
+}
+
+impl NoteExpression {
+    /// Decode an expression record, clamping the value to the expression's range. `None` for any other record
+    /// (or an expression this SDK does not know, so a newer host never confuses an older device).
+    pub fn from_record(record: &EventRecord) -> Option<Self> {
+        if record.kind != EVENT_NOTE_EXPRESSION {
+            return None;
+        }
+        let value = record.velocity;
+        match record.pitch {
+            EXPRESSION_TUNING => Some(NoteExpression::Tuning(value.clamp(-120.0, 120.0))),
+            EXPRESSION_PRESSURE => Some(NoteExpression::Pressure(value.clamp(0.0, 1.0))),
+            EXPRESSION_BRIGHTNESS => Some(NoteExpression::Brightness(value.clamp(-1.0, 1.0))),
+            _ => None
+        }
+    }
+
+    /// The record modulating note `id` at pulse `position` (a MIDI fx emitting expressions).
+    pub fn record(self, id: u32, position: f64) -> EventRecord {
+        let (expression, value) = match self {
+            NoteExpression::Tuning(value) => (EXPRESSION_TUNING, value),
+            NoteExpression::Pressure(value) => (EXPRESSION_PRESSURE, value),
+            NoteExpression::Brightness(value) => (EXPRESSION_BRIGHTNESS, value)
+        };
+        EventRecord {position, offset: 0, kind: EVENT_NOTE_EXPRESSION, id, pitch: expression, velocity: value, cent: 0.0, duration: 0.0}
+    }
+}
+
+/// The mod-wheel controller number.
+pub const CC_MOD_WHEEL: u32 = 1;
+/// The sustain (damper) pedal controller number. Down at a value of `0.5` and above (the MIDI convention).
+pub const CC_SUSTAIN: u32 = 64;
+/// The stock instruments' pitch-wheel range in semitones, either way (the General MIDI default).
+pub const PITCH_BEND_RANGE: f32 = 2.0;
+
+/// Whether `kind` is a channel-wide event (a controller, the pitch wheel, channel pressure) rather than a
+/// note-lifecycle or parameter record. A MIDI effect that rewrites notes passes these through unchanged.
+#[inline]
+pub fn is_channel_event(kind: u32) -> bool {
+    matches!(kind, EVENT_CONTROL | EVENT_PITCH_BEND | EVENT_CHANNEL_PRESSURE)
+}
+
+/// The sustain pedal (CC 64) for an instrument: while it is down, a note-off is DEFERRED (the id is held) and
+/// the voice keeps sounding; lifting the pedal releases every held note. Heap-free and valid when zeroed, so it
+/// lives directly in a device's engine-allocated state. `N` bounds the held notes; a note-off beyond that
+/// releases immediately rather than hanging.
+#[derive(Clone, Copy)]
+pub struct SustainPedal<const N: usize> {
+    down: bool,
+    count: usize,
+    held: [u32; N]
+}
+
+impl<const N: usize> SustainPedal<N> {
+    pub const fn new() -> Self {
+        Self {down: false, count: 0, held: [0; N]}
+    }
+
+    #[inline]
+    pub fn is_down(&self) -> bool {
+        self.down
+    }
+
+    /// Apply a CC 64 `value` (`0..1`). When the pedal goes up, `release` is called for every held note id.
+    pub fn set(&mut self, value: f32, release: &mut dyn FnMut(u32)) {
+        self.down = value >= 0.5;
+        if !self.down {
+            for id in &self.held[..self.count] {
+                release(*id);
+            }
+            self.count = 0;
+        }
+    }
+
+    /// A note-off for `id`: `true` if the pedal holds it (the caller keeps the voice sounding), `false` if the
+    /// caller should release it now.
+    pub fn hold(&mut self, id: u32) -> bool {
+        if !self.down || self.count == N {
+            return false;
+        }
+        self.held[self.count] = id;
+        self.count += 1;
+        true
+    }
+
+    /// Forget the pedal and every held note (a transport stop drops the voices anyway).
+    pub fn reset(&mut self) {
+        self.down = false;
+        self.count = 0;
+    }
+}
+
+impl<const N: usize> Default for SustainPedal<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
 /// What a device IS, so the host knows how to wire it into the graph (it reads this from the device's
 /// `kind` export at load). An instrument voices notes into audio; an effect transforms an input buffer; a
@@ -76,7 +210,8 @@ pub const DEVICE_KIND_MIDI_EFFECT: u32 = 2;
 /// (`DISCONTINUOUS`, `BPM_CHANGED`) are one-shot, cleared after the first chunk via `EVENT_MASK`.
 /// `#[repr(transparent)]` so it is layout-identical to a `u32`: it is the `Block.flags` wire field AND
 /// carries the host's flag helpers. `DISCONTINUOUS` marks a transport jump (loop wrap / seek), the cue for
-/// a stateful device to release what it holds.
+/// a stateful device to release what it holds. `PUNCH` (a state flag with no TS counterpart) marks a block
+/// inside the punch range while recording, the window in which takes are captured.
 #[repr(transparent)]
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 pub struct BlockFlags(pub u32);
@@ -86,6 +221,7 @@ impl BlockFlags {
     pub const DISCONTINUOUS: u32 = 1 << 1;
     pub const PLAYING: u32 = 1 << 2;
     pub const BPM_CHANGED: u32 = 1 << 3;
+    pub const PUNCH: u32 = 1 << 4;
     pub const EVENT_MASK: u32 = Self::DISCONTINUOUS | Self::BPM_CHANGED;
 
     /// Mirror of TS `BlockFlags.create`.
@@ -119,6 +255,10 @@ impl BlockFlags {
         self.has(Self::BPM_CHANGED)
     }
 
+    pub fn punch(self) -> bool {
+        self.has(Self::PUNCH)
+    }
+
     /// Clear the one-shot event flags after the first chunk (TS `flags &= ~eventMask`).
     pub fn clear_event_flags(&mut self) {
         self.0 &= !Self::EVENT_MASK;
@@ -228,14 +368,14 @@ pub fn panic_to_host(info: &core::panic::PanicInfo) -> ! {
 }
 
 /// Pull this device's resolved input events for the pulse range `[from, to)` into `out`, returning the
-/// number written (offsets are absolute within the quantum, lifecycle-sorted). On native builds there is
-/// no host, so the stub returns 0 (native device tests drive `render` directly, never `process`).
+/// number written (offsets are absolute within the quantum, lifecycle-sorted). Natively the installed
+/// [`native::Host`] serves the pull; without one nothing is pulled.
 #[inline]
 pub fn pull_events(from: f64, to: f64, flags: u32, out: &mut [EventRecord]) -> usize {
     #[cfg(target_family = "wasm")]
     { unsafe { host_pull_events(from, to, flags, out.as_mut_ptr() as u32, out.len() as u32) as usize } }
     #[cfg(not(target_family = "wasm"))]
-    { let _ = (from, to, flags, out.len()); 0 }
+    { native::call(|host| host.pull_events(from, to, flags, out)).unwrap_or(0) }
 }
 
 /// Map a pulse position to its sample offset within the current quantum (the host resolves it against the
@@ -246,7 +386,7 @@ pub fn pulse_to_offset(pulse: f64) -> u32 {
     #[cfg(target_family = "wasm")]
     { unsafe { host_pulse_to_offset(pulse) } }
     #[cfg(not(target_family = "wasm"))]
-    { let _ = pulse; 0 }
+    { native::call(|host| host.pulse_to_offset(pulse)).unwrap_or(0) }
 }
 
 // The native tuning reference behind `base_frequency` (f32 bits), settable so a device test can assert
@@ -257,14 +397,17 @@ static NATIVE_BASE_FREQUENCY: core::sync::atomic::AtomicU32 =
 
 /// The project's TUNING REFERENCE in Hz (TS `EngineContext.baseFrequency`, `RootBox.baseFrequency`), pulled
 /// from the host. A device reads it exactly where its TS counterpart reads `context.baseFrequency` — the
-/// Vaporisateur per note-on (`computeFrequency`), never mid-voice. Native builds serve the test-settable
-/// default (440).
+/// Vaporisateur per note-on (`computeFrequency`), never mid-voice. Native builds ask the installed host, else
+/// serve the test-settable default (440).
 #[inline]
 pub fn base_frequency() -> f32 {
     #[cfg(target_family = "wasm")]
     { unsafe { host_base_frequency() } }
     #[cfg(not(target_family = "wasm"))]
-    { f32::from_bits(NATIVE_BASE_FREQUENCY.load(core::sync::atomic::Ordering::Relaxed)) }
+    {
+        native::call(|host| host.base_frequency())
+            .unwrap_or_else(|| f32::from_bits(NATIVE_BASE_FREQUENCY.load(core::sync::atomic::Ordering::Relaxed)))
+    }
 }
 
 /// Native TEST SEAM: set what [`base_frequency`] returns (a device test detunes, then restores 440).
@@ -321,6 +464,18 @@ impl ParamValue {
             _ => panic!("unknown parameter kind")
         }
     }
+
+    /// Encode into the wire `(kind, value)`, the inverse of [`ParamValue::from_wire`]: what a host passes to a
+    /// device's `parameter_changed` export.
+    #[inline]
+    pub fn to_wire(self) -> (u32, f32) {
+        match self {
+            ParamValue::Unit(unit) => (PARAM_KIND_UNIT, unit),
+            ParamValue::Int(int) => (PARAM_KIND_INT, int as f32),
+            ParamValue::Float(float) => (PARAM_KIND_FLOAT, float),
+            ParamValue::Bool(flag) => (PARAM_KIND_BOOL, if flag { 1.0 } else { 0.0 })
+        }
+    }
 }
 
 /// Resolve a FLOAT parameter from its [`ParamValue`]: a uniform automation value mapped through `mapping`, or
@@ -386,10 +541,10 @@ impl<'a> FieldValue<'a> {
     /// For a string, `bits`/`len` must point at `len` valid, initialized UTF-8 bytes that stay alive and
     /// unmoved for the whole call. The engine guarantees this (it passes a live box-field string).
     #[inline]
-    pub unsafe fn from_wire(kind: u32, bits: u32, len: u32) -> Self {
+    pub unsafe fn from_wire(kind: u32, bits: usize, len: u32) -> Self {
         match kind {
-            FIELD_KIND_INT => FieldValue::Int(bits as i32),
-            FIELD_KIND_FLOAT => FieldValue::Float(f32::from_bits(bits)),
+            FIELD_KIND_INT => FieldValue::Int(bits as u32 as i32),
+            FIELD_KIND_FLOAT => FieldValue::Float(f32::from_bits(bits as u32)),
             FIELD_KIND_BOOL => FieldValue::Bool(bits != 0),
             FIELD_KIND_STRING => {
                 let bytes = slice::from_raw_parts(bits as *const u8, len as usize);
@@ -398,17 +553,30 @@ impl<'a> FieldValue<'a> {
             _ => panic!("unknown field kind")
         }
     }
+
+    /// Encode into the wire `(kind, bits, len)`, the inverse of [`FieldValue::from_wire`]. A string's `bits`
+    /// point into `self`, so the triple is only valid while the borrowed string is.
+    #[inline]
+    pub fn to_wire(&self) -> (u32, usize, u32) {
+        match *self {
+            FieldValue::Int(int) => (FIELD_KIND_INT, int as u32 as usize, 0),
+            FieldValue::Float(float) => (FIELD_KIND_FLOAT, float.to_bits() as usize, 0),
+            FieldValue::Bool(flag) => (FIELD_KIND_BOOL, flag as usize, 0),
+            FieldValue::String(text) => (FIELD_KIND_STRING, text.as_ptr() as usize, text.len() as u32)
+        }
+    }
 }
 
 /// A resolved sample (Route F): decoded PLANAR f32 frames resident in the shared linear memory. `frames_ptr`
 /// points at channel 0's frames; channel `c` is at `frames_ptr + c * frame_count * 4` (each plane is
 /// `frame_count` consecutive f32). The host fills this from a sample handle via [`resolve_sample`]; a handle
 /// that is not yet resident resolves to `None`. `#[repr(C)]` so the engine writes it straight into the
-/// device's out pointer.
+/// device's out pointer. `frames_ptr` is a `usize`: 4 bytes on wasm32 (the layout is unchanged), a full
+/// address on a native host, where an engine reading the frames in-process must not truncate it.
 #[repr(C)]
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct SampleRef {
-    pub frames_ptr: u32,
+    pub frames_ptr: usize,
     pub frame_count: u32,
     pub channel_count: u32,
     pub sample_rate: f32
@@ -420,14 +588,14 @@ impl SampleRef {
     /// frames as a slice. The slice borrows the resident sample memory, valid while the sample stays resident.
     #[inline]
     pub fn plane(&self, channel: u32) -> &[f32] {
-        let offset = self.frames_ptr as usize + channel as usize * self.frame_count as usize * 4;
+        let offset = self.frames_ptr + channel as usize * self.frame_count as usize * 4;
         unsafe { slice::from_raw_parts(offset as *const f32, self.frame_count as usize) }
     }
 }
 
 /// Resolve a sample `handle` (Route F) to its resident PLANAR frames, or `None` when not yet resident (the
 /// device skips that sample for the block). The host writes a [`SampleRef`] into an on-stack scratch and
-/// returns 1 when resident. Native stub returns `None` (native device tests supply samples directly).
+/// returns 1 when resident. Native stub returns `None`.
 #[inline]
 pub fn resolve_sample(handle: u32) -> Option<SampleRef> {
     #[cfg(target_family = "wasm")]
@@ -440,20 +608,18 @@ pub fn resolve_sample(handle: u32) -> Option<SampleRef> {
         }
     }
     #[cfg(not(target_family = "wasm"))]
-    {
-        let _ = handle;
-        None
-    }
+    { native::call(|host| host.resolve_sample(handle)).flatten() }
 }
 
 /// A resolved SOUNDFONT: a pointer + byte length of the simplified soundfont BLOB resident in the engine's
 /// shared linear memory (built on the main thread from the parsed SF2 — sample table + region table + preset
 /// table + PLANAR-normalized f32 PCM). `#[repr(C)]` so the engine writes it straight into the device's out
 /// pointer, exactly like [`SampleRef`]. The device reads the blob IN PLACE (offset arithmetic, no allocation).
+/// `ptr` is a `usize` for the same reason as [`SampleRef::frames_ptr`].
 #[repr(C)]
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct SoundfontRef {
-    pub ptr: u32,
+    pub ptr: usize,
     pub len: u32
 }
 
@@ -481,10 +647,7 @@ pub fn resolve_soundfont(handle: u32) -> Option<SoundfontRef> {
         }
     }
     #[cfg(not(target_family = "wasm"))]
-    {
-        let _ = handle;
-        None
-    }
+    { native::call(|host| host.resolve_soundfont(handle)).flatten() }
 }
 
 /// Observe this device's SOUNDFONT reference by its box pointer-field PATH (e.g. `[10]` for the Soundfont
@@ -497,7 +660,7 @@ pub fn observe_soundfont(path: &[u16]) -> u32 {
     #[cfg(target_family = "wasm")]
     { unsafe { host_observe_soundfont(path.as_ptr() as u32, path.len() as u32) } }
     #[cfg(not(target_family = "wasm"))]
-    { let _ = path; 0 }
+    { native::call(|host| host.observe_soundfont(path)).unwrap_or(0) }
 }
 
 /// Observe this device's sample reference by its box pointer-field PATH (e.g. `[11]` for a Playfield slot's
@@ -511,7 +674,7 @@ pub fn observe_sample(path: &[u16]) -> u32 {
     #[cfg(target_family = "wasm")]
     { unsafe { host_observe_sample(path.as_ptr() as u32, path.len() as u32) } }
     #[cfg(not(target_family = "wasm"))]
-    { let _ = path; 0 }
+    { native::call(|host| host.observe_sample(path)).unwrap_or(0) }
 }
 
 /// Observe one of THIS device's PLAIN box fields by its field-key PATH (e.g. `[15]` for a Playfield slot's
@@ -525,7 +688,7 @@ pub fn observe_field(path: &[u16]) -> u32 {
     #[cfg(target_family = "wasm")]
     { unsafe { host_observe_field(path.as_ptr() as u32, path.len() as u32) } }
     #[cfg(not(target_family = "wasm"))]
-    { let _ = path; 0 }
+    { native::call(|host| host.observe_field(path)).unwrap_or(0) }
 }
 
 /// Observe a POINTER field of THIS device by its field-key PATH, delivering the TARGET box's STRING field
@@ -541,7 +704,7 @@ pub fn observe_target_string(path: &[u16], field_key: u16) -> u32 {
     #[cfg(target_family = "wasm")]
     { unsafe { host_observe_target_string(path.as_ptr() as u32, path.len() as u32, field_key as u32) } }
     #[cfg(not(target_family = "wasm"))]
-    { let _ = (path, field_key); 0 }
+    { native::call(|host| host.observe_target_string(path, field_key)).unwrap_or(0) }
 }
 
 /// Register one of THIS device's parameters with the host by its stable FIELD-KEY PATH on the device box
@@ -554,7 +717,7 @@ pub fn bind_parameter(path: &[u16]) -> u32 {
     #[cfg(target_family = "wasm")]
     { unsafe { host_bind_parameter(path.as_ptr() as u32, path.len() as u32) } }
     #[cfg(not(target_family = "wasm"))]
-    { let _ = path; 0 }
+    { native::call(|host| host.bind_parameter(path)).unwrap_or(0) }
 }
 
 /// Register a LIVE-DATA broadcast slot for THIS device (call from `init`): `len` floats published to the UI
@@ -569,7 +732,7 @@ pub fn bind_broadcast(path: &[u16], len: u32) -> u32 {
     #[cfg(target_family = "wasm")]
     { unsafe { host_bind_broadcast(path.as_ptr() as u32, path.len() as u32, len, 1) } }
     #[cfg(not(target_family = "wasm"))]
-    { let _ = (path, len); 0 }
+    { native::call(|host| host.bind_broadcast(path, len, 1)).unwrap_or(0) }
 }
 
 /// Register a SCALAR live-data broadcast slot (one f32, the TS `broadcaster.broadcastFloat(...)` mirror): the
@@ -580,7 +743,7 @@ pub fn bind_broadcast_float(path: &[u16]) -> u32 {
     #[cfg(target_family = "wasm")]
     { unsafe { host_bind_broadcast(path.as_ptr() as u32, path.len() as u32, 1, 0) } }
     #[cfg(not(target_family = "wasm"))]
-    { let _ = path; 0 }
+    { native::call(|host| host.bind_broadcast(path, 1, 0)).unwrap_or(0) }
 }
 
 /// Register an INT-RING broadcast slot (TS `broadcastIntegers` consume-on-read, e.g. the Velocity device's
@@ -591,16 +754,16 @@ pub fn bind_broadcast_ints(path: &[u16], ring_len: u32) -> u32 {
     #[cfg(target_family = "wasm")]
     { unsafe { host_bind_broadcast(path.as_ptr() as u32, path.len() as u32, ring_len + 1, 2) } }
     #[cfg(not(target_family = "wasm"))]
-    { let _ = (path, ring_len); 0 }
+    { native::call(|host| host.bind_broadcast(path, ring_len + 1, 2)).unwrap_or(0) }
 }
 
 /// The write pointer of a [`bind_broadcast`] slot (`len` f32s), or 0 while unbound. Stable for the device's
 /// life once non-zero; cache it in state.
-pub fn broadcast_ptr(id: u32) -> u32 {
+pub fn broadcast_ptr(id: u32) -> usize {
     #[cfg(target_family = "wasm")]
-    { unsafe { host_broadcast_ptr(id) } }
+    { unsafe { host_broadcast_ptr(id) as usize } }
     #[cfg(not(target_family = "wasm"))]
-    { let _ = id; 0 }
+    { native::call(|host| host.broadcast_ptr(id)).unwrap_or(0) }
 }
 
 /// Whether the UI currently SUBSCRIBES to a [`bind_broadcast`] slot (the LiveStream round-trip). Producers
@@ -609,7 +772,7 @@ pub fn broadcast_active(id: u32) -> bool {
     #[cfg(target_family = "wasm")]
     { unsafe { host_broadcast_active(id) != 0 } }
     #[cfg(not(target_family = "wasm"))]
-    { let _ = id; false }
+    { native::call(|host| host.broadcast_active(id)).unwrap_or(false) }
 }
 
 /// Declare one of THIS effect's SIDECHAIN input PORTS by its pointer FIELD-KEY PATH on the device box (e.g.
@@ -623,15 +786,14 @@ pub fn bind_sidechain(path: &[u16]) -> u32 {
     #[cfg(target_family = "wasm")]
     { unsafe { host_bind_sidechain(path.as_ptr() as u32, path.len() as u32) } }
     #[cfg(not(target_family = "wasm"))]
-    { let _ = path; 0 }
+    { native::call(|host| host.bind_sidechain(path)).unwrap_or(0) }
 }
 
 /// Resolve an audio input PORT by id to its stereo buffer for the current quantum (Route B/C): [`MAIN_INPUT`]
 /// for the through-signal, or a `bind_sidechain` port id. `Some` when the port is wired, `None` when it is not
 /// (an unconnected sidechain, or no upstream). The device reads the returned channels in absolute quantum
 /// coordinates. Like [`resolve_sample`], it reads an engine cell the host swaps in for this `process`, so it
-/// is O(1) and safe to call from the DSP. Native stub returns `None` (effect DSP is unit-tested via the pure
-/// per-sample path, not through the host).
+/// is O(1) and safe to call from the DSP. Native stub returns `None`.
 #[inline]
 pub fn resolve_input(id: u32) -> Option<AudioInputRef> {
     #[cfg(target_family = "wasm")]
@@ -644,10 +806,7 @@ pub fn resolve_input(id: u32) -> Option<AudioInputRef> {
         }
     }
     #[cfg(not(target_family = "wasm"))]
-    {
-        let _ = id;
-        None
-    }
+    { native::call(|host| host.resolve_input(id)).flatten() }
 }
 
 // ---- SCRIPT BRIDGE wrappers -------------------------------------------------------------------------------
@@ -669,15 +828,15 @@ pub fn self_uuid() -> [u8; 16] {
         out
     }
     #[cfg(not(target_family = "wasm"))]
-    { [0u8; 16] }
+    { native::call(|host| host.self_uuid()).unwrap_or([0u8; 16]) }
 }
 
 /// Create the JS-side script bridge for this device (from `init`): `uuid` + `kind` (`DEVICE_KIND_*`) identify
 /// it; returns a handle the device passes to every later `script_*` call. Native stub returns 0.
 #[inline]
-pub fn script_create(uuid: &[u8; 16], kind: u32, state_ptr: u32) -> u32 {
+pub fn script_create(uuid: &[u8; 16], kind: u32, state_ptr: usize) -> u32 {
     #[cfg(target_family = "wasm")]
-    { unsafe { host_script_create(uuid.as_ptr() as u32, kind, state_ptr) } }
+    { unsafe { host_script_create(uuid.as_ptr() as u32, kind, state_ptr as u32) } }
     #[cfg(not(target_family = "wasm"))]
     { let _ = (uuid, kind, state_ptr); 0 }
 }
@@ -686,9 +845,9 @@ pub fn script_create(uuid: &[u8; 16], kind: u32, state_ptr: u32) -> u32 {
 /// are the input channels' byte offsets (0 for an instrument with no input), `out_l`/`out_r` the output
 /// channels'. Returns 0 ok, nonzero when the bridge silenced the device (already reported). Native stub = 0.
 #[inline]
-pub fn script_audio(handle: u32, src_l: u32, src_r: u32, out_l: u32, out_r: u32, block: &Block) -> u32 {
+pub fn script_audio(handle: u32, src_l: usize, src_r: usize, out_l: usize, out_r: usize, block: &Block) -> u32 {
     #[cfg(target_family = "wasm")]
-    { unsafe { host_script_audio(handle, src_l, src_r, out_l, out_r, block.s0, block.s1, block.index, block.p0, block.p1, block.bpm, block.flags.0) } }
+    { unsafe { host_script_audio(handle, src_l as u32, src_r as u32, out_l as u32, out_r as u32, block.s0, block.s1, block.index, block.p0, block.p1, block.bpm, block.flags.0) } }
     #[cfg(not(target_family = "wasm"))]
     { let _ = (handle, src_l, src_r, out_l, out_r, block); 0 }
 }
@@ -734,12 +893,7 @@ pub fn script_release(handle: u32) {
 /// bridge maps `UNIT` via the script's `@param` mapping, uses `FLOAT`/`INT`/`BOOL` directly). Native stub no-op.
 #[inline]
 pub fn script_param(handle: u32, index: u32, value: ParamValue) {
-    let (kind, bits) = match value {
-        ParamValue::Unit(unit) => (PARAM_KIND_UNIT, unit),
-        ParamValue::Int(int) => (PARAM_KIND_INT, int as f32),
-        ParamValue::Float(float) => (PARAM_KIND_FLOAT, float),
-        ParamValue::Bool(flag) => (PARAM_KIND_BOOL, if flag { 1.0 } else { 0.0 })
-    };
+    let (kind, bits) = value.to_wire();
     #[cfg(target_family = "wasm")]
     { unsafe { host_script_param(handle, index, kind, bits) } }
     #[cfg(not(target_family = "wasm"))]
@@ -855,7 +1009,7 @@ pub fn update_parameters(position: f64, out: &mut [ParamChange]) -> usize {
     #[cfg(target_family = "wasm")]
     { unsafe { host_update_parameters(position, out.as_mut_ptr() as u32, out.len() as u32) as usize } }
     #[cfg(not(target_family = "wasm"))]
-    { let _ = (position, out.len()); 0 }
+    { native::call(|host| host.update_parameters(position, out)).unwrap_or(0) }
 }
 
 /// The FIRST update position at or AFTER `at` (INCLUSIVE) — the seed for a fragment loop, mirroring TS
@@ -867,7 +1021,7 @@ pub fn first_update_position(at: f64) -> f64 {
     #[cfg(target_family = "wasm")]
     { unsafe { host_first_update_position(at) } }
     #[cfg(not(target_family = "wasm"))]
-    { let _ = at; f64::INFINITY }
+    { native::call(|host| host.first_update_position(at)).unwrap_or(f64::INFINITY) }
 }
 
 /// The next parameter-update position STRICTLY after `after`, per the engine's update-clock policy (a fixed
@@ -875,13 +1029,13 @@ pub fn first_update_position(at: f64) -> f64 {
 /// `position = next_update_position(position)` always moves forward (the seed comes from the inclusive
 /// [`first_update_position`]). Returns `INFINITY` when THIS device has no automated parameter, so the loop
 /// stops. A device's render template walks these to fragment its work; it never computes a grid itself.
-/// Native stub returns `INFINITY` (no fragmentation off-engine).
+/// Native stub returns `INFINITY`.
 #[inline]
 pub fn next_update_position(after: f64) -> f64 {
     #[cfg(target_family = "wasm")]
     { unsafe { host_next_update_position(after) } }
     #[cfg(not(target_family = "wasm"))]
-    { let _ = after; f64::INFINITY }
+    { native::call(|host| host.next_update_position(after)).unwrap_or(f64::INFINITY) }
 }
 
 /// The most parameter changes [`update_parameters`] returns per call (a device's whole param set, comfortably).
@@ -908,14 +1062,14 @@ pub fn apply_param_changes<S>(state: &mut S, position: f64, apply: fn(&mut S, u3
 /// `state_ptr` must point at a live, uniquely-borrowed `S` (the engine's per-instance state block); nothing
 /// else may alias it for the call. The engine guarantees this (it calls these exports outside `process`).
 #[inline]
-pub unsafe fn with_state<S>(state_ptr: u32, body: impl FnOnce(&mut S)) {
+pub unsafe fn with_state<S>(state_ptr: usize, body: impl FnOnce(&mut S)) {
     body(&mut *(state_ptr as *mut S))
 }
 
 /// Read-only view over a device's input ports.
 #[derive(Clone, Copy)]
 pub struct Inputs<'a> {
-    offsets: &'a [u32],
+    offsets: &'a [usize],
     frames: usize,
 }
 
@@ -947,11 +1101,13 @@ pub const MAIN_INPUT: u32 = 1;
 
 /// A resolved audio input port (Route B/C): the source's stereo buffer for the WHOLE quantum, addressed by
 /// pointers. The device indexes it in absolute quantum coordinates (the same `block.s0..s1` it writes to
-/// `output`). Mirrors [`SampleRef`]: a thin handle whose accessors hand back safe slices.
+/// `output`). Mirrors [`SampleRef`]: a thin handle whose accessors hand back safe slices, with `usize` channel
+/// pointers like [`SampleRef::frames_ptr`].
+#[repr(C)]
 #[derive(Clone, Copy)]
 pub struct AudioInputRef {
-    pub left: u32,
-    pub right: u32,
+    pub left: usize,
+    pub right: usize,
     pub frames: u32,
 }
 
@@ -1000,30 +1156,30 @@ impl<'a, S> Ports<'a, S> {
     /// module's shared linear memory. The engine guarantees this when it assembles the descriptor;
     /// nothing else may call it.
     #[inline]
-    pub unsafe fn from_descriptor(desc_ptr: u32) -> Self {
-        let desc = desc_ptr as *const u32;
-        let frames = *desc.add(0) as usize;
-        let in_count = *desc.add(1) as usize;
-        let in_offsets_ptr = *desc.add(2) as *const u32;
-        let out_count = *desc.add(3) as usize;
-        let out_offsets_ptr = *desc.add(4) as *const u32;
-        let param_count = *desc.add(5) as usize;
+    pub unsafe fn from_descriptor(desc_ptr: usize) -> Self {
+        let desc = desc_ptr as *const usize;
+        let frames = *desc.add(0);
+        let in_count = *desc.add(1);
+        let in_offsets_ptr = *desc.add(2) as *const usize;
+        let out_count = *desc.add(3);
+        let out_offsets_ptr = *desc.add(4) as *const usize;
+        let param_count = *desc.add(5);
         let params_ptr = *desc.add(6) as *const f32;
         let state_ptr = *desc.add(7) as *mut S;
-        let in_event_cap = *desc.add(8) as usize;
+        let in_event_cap = *desc.add(8);
         let in_events_ptr = *desc.add(9) as *mut EventRecord;
         // [10] out_event_cap / [11] out_events_ptr: event-output devices (MIDI fx, phase 4); unused here.
-        let block_count = *desc.add(12) as usize;
+        let block_count = *desc.add(12);
         let blocks_ptr = *desc.add(13) as *const Block;
-        let sample_rate = f32::from_bits(*desc.add(14));
+        let sample_rate = f32::from_bits(*desc.add(14) as u32);
         let in_offsets = if in_count == 0 {
-            slice::from_raw_parts(NonNull::<u32>::dangling().as_ptr(), 0)
+            slice::from_raw_parts(NonNull::<usize>::dangling().as_ptr(), 0)
         } else {
             slice::from_raw_parts(in_offsets_ptr, in_count)
         };
         // The two output channels live at out_offsets[0] / [1] (distinct buffers, so the `&mut`s never alias).
         let out_offsets = if out_count == 0 {
-            slice::from_raw_parts(NonNull::<u32>::dangling().as_ptr(), 0)
+            slice::from_raw_parts(NonNull::<usize>::dangling().as_ptr(), 0)
         } else {
             slice::from_raw_parts(out_offsets_ptr, out_count)
         };
@@ -1079,7 +1235,9 @@ pub trait Instrument {
     /// (one-shot flags cleared after the first chunk), and `s0`/`s1` rebased to `0`/`len` to match the slice.
     /// The sample rate is the device's own (it stashed `Ports::sample_rate` in `state`), never a per-call argument.
     fn process_audio(state: &mut Self::State, output: [&mut [f32]; 2], block: &Block);
-    /// Apply one note event (on / off) at its sample offset.
+    /// Apply one note event (on / off / expression) or channel event (controller / pitch wheel / pressure, see
+    /// [`is_channel_event`]) at its sample offset. Match the kinds explicitly: a channel event carries `id` 0,
+    /// so treating "anything not a note-on" as a note-off would release note 0.
     fn handle_event(state: &mut Self::State, event: &EventRecord);
     /// Reset the device's RUNTIME state to silence on a transport STOP: drop every active voice, clear
     /// envelopes / read heads / filter history, so the next playback starts clean. Keep the bindings (the param
@@ -1165,11 +1323,14 @@ pub fn dispatch_range<I: Instrument>(state: &mut I::State, output: [&mut [f32];
 }
 
 /// Order resolved records for `dispatch_range`: by sample offset, then at an equal offset note-off ->
-/// param-update -> note-on, so a note starting at an update position sees the refreshed parameter.
+/// param-update / channel event -> note-on -> note expression, so a note starting at an update position sees
+/// the refreshed parameter, one starting with a bend or pedal change at its position starts bent / sustained,
+/// and an expression for a note starting at its position finds the note.
 fn record_rank(kind: u32) -> u8 {
     match kind {
         EVENT_NOTE_OFF | EVENT_CHOKE => 0, // releases first, so a choke at a position precedes any note-on there
-        EVENT_PARAM => 1,
+        EVENT_PARAM | EVENT_CONTROL | EVENT_PITCH_BEND | EVENT_CHANNEL_PRESSURE => 1,
+        EVENT_NOTE_EXPRESSION => 3,
         _ => 2 // EVENT_NOTE_ON
     }
 }
@@ -1247,6 +1408,13 @@ pub trait AudioEffect {
     fn reset(state: &mut Self::State) {
         let _ = state;
     }
+    /// The samples the output currently lags the input (a look-ahead delay), exported as `latency(state_ptr)`
+    /// so the engine's delay compensation can hold parallel paths back to match. May follow a parameter (a
+    /// look-ahead toggle); the engine re-reads it after each edit, outside render. Default: 0 (no latency).
+    fn latency(state: &Self::State) -> u32 {
+        let _ = state;
+        0
+    }
 }
 
 /// The per-quantum effect path a device calls from `process`: run the effect PER BLOCK, and within each block
@@ -1337,7 +1505,7 @@ const MIDI_PULL_SCRATCH: usize = 256;
 /// parameters at each boundary. A device with no automation gets no update positions (INFINITY), so this is
 /// ONE pull over `[from, to)` — the previous behaviour. The upstream range equals each sub-range, which suits
 /// pitch / velocity / arp transforms that do not move events in time.
-pub fn render_midi_effect<M: MidiEffect>(from: f64, to: f64, flags: u32, state_ptr: u32, out_ptr: u32, max: u32) -> u32 {
+pub fn render_midi_effect<M: MidiEffect>(from: f64, to: f64, flags: u32, state_ptr: usize, out_ptr: usize, max: u32) -> u32 {
     let state = unsafe { &mut *(state_ptr as *mut M::State) };
     let out = unsafe { slice::from_raw_parts_mut(out_ptr as *mut EventRecord, max as usize) };
     let blank = EventRecord {position: 0.0, offset: 0, kind: 0, id: 0, pitch: 0, velocity: 0.0, cent: 0.0, duration: 0.0};
@@ -1367,7 +1535,9 @@ mod tests {
     //! The instrument dispatch (`dispatch_range`): each inter-event chunk gets its OWN cloned block, with
     //! `s0`/`s1` rebased to the slice, `p0`/`p1` the chunk's pulse range, `bpm` carried, and the one-shot
     //! flags cleared after the first chunk. A recording mock instrument captures what each chunk received.
-    use super::{dispatch_range, Block, BlockFlags, EventRecord, Instrument, EVENT_NOTE_ON};
+    use super::{dispatch_range, record_rank, Block, BlockFlags, EventRecord, Instrument, SustainPedal};
+    use super::{EVENT_CONTROL, EVENT_NOTE_EXPRESSION, EVENT_NOTE_OFF, EVENT_NOTE_ON, EVENT_PARAM, EVENT_PITCH_BEND};
+    use super::{NoteExpression, EXPRESSION_BRIGHTNESS};
 
     struct Chunk {
         len: usize,
@@ -1461,4 +1631,46 @@ mod tests {
         assert!(state.chunks[1].flags & BlockFlags::DISCONTINUOUS == 0, "later chunks have it cleared");
         assert!(state.chunks[1].flags & BlockFlags::TRANSPORTING != 0, "state flags persist");
     }
+
+    #[test]
+    fn channel_events_sort_between_releases_and_note_ons() {
+        let mut kinds = [EVENT_NOTE_ON, EVENT_PITCH_BEND, EVENT_NOTE_OFF, EVENT_CONTROL, EVENT_PARAM];
+        kinds.sort_by_key(|kind| record_rank(*kind));
+        assert_eq!(kinds[0], EVENT_NOTE_OFF);
+        assert_eq!(kinds[4], EVENT_NOTE_ON, "a note at a bend's offset starts already bent");
+    }
+
+    #[test]
+    fn an_expression_sorts_after_the_note_it_modulates() {
+        let mut kinds = [EVENT_NOTE_EXPRESSION, EVENT_NOTE_ON, EVENT_NOTE_OFF];
+        kinds.sort_by_key(|kind| record_rank(*kind));
+        assert_eq!(kinds, [EVENT_NOTE_OFF, EVENT_NOTE_ON, EVENT_NOTE_EXPRESSION]);
+    }
+
+    #[test]
+    fn note_expressions_round_trip_and_clamp() {
+        let record = NoteExpression::Tuning(-3.5).record(9, 48.0);
+        assert_eq!((record.kind, record.id, record.position), (EVENT_NOTE_EXPRESSION, 9, 48.0));
+        assert_eq!(NoteExpression::from_record(&record), Some(NoteExpression::Tuning(-3.5)));
+        let loud = EventRecord {velocity: 4.0, ..NoteExpression::Pressure(0.0).record(1, 0.0)};
+        assert_eq!(NoteExpression::from_record(&loud), Some(NoteExpression::Pressure(1.0)));
+        let unknown = EventRecord {pitch: EXPRESSION_BRIGHTNESS + 1, ..loud};
+        assert_eq!(NoteExpression::from_record(&unknown), None, "an unknown expression is ignored");
+        assert_eq!(NoteExpression::from_record(&note_on(0, 0.0, 60)), None);
+    }
+
+    #[test]
+    fn the_sustain_pedal_defers_note_offs_until_it_lifts() {
+        let mut pedal = SustainPedal::<2>::new();
+        assert!(!pedal.hold(7), "pedal up: release now");
+        pedal.set(1.0, &mut |_| panic!("pressing releases nothing"));
+        assert!(pedal.hold(7));
+        assert!(pedal.hold(8));
+        assert!(!pedal.hold(9), "a full pedal releases immediately instead of hanging the note");
+        let mut released = Vec::new();
+        pedal.set(0.0, &mut |id| released.push(id));
+        assert_eq!(released, vec![7, 8]);
+        assert!(!pedal.is_down());
+        pedal.set(0.0, &mut |_| panic!("nothing left to release"));
+    }
 }
@@ -0,0 +1,199 @@
+//! The native side of the host imports. On wasm a device imports `host_*` from the engine; off wasm the same
+//! wrappers call the [`Host`] installed on the current thread by [`with_host`], so a device crate runs
+//! unchanged under a native harness (the offline tools, device tests). Every method defaults to the inert
+//! stub a wrapper returns when no host is installed, so a host implements only what it serves.
+//!
+//! The script and NAM bridges are not part of the surface: their DSP lives in JavaScript, so those wrappers
+//! stay no-ops natively.
+
+use core::cell::Cell;
+
+use crate::{AudioInputRef, EventRecord, ParamChange, SampleRef, SoundfontRef};
+
+/// The engine's import surface, one method per `host_*` import, with the wrappers' native stubs as defaults.
+/// Ids and handles are the host's own; the device only hands them back.
+pub trait Host {
+    /// `host_pull_events`: write the device's input events for `[from, to)` into `out`, returning the count.
+    fn pull_events(&mut self, from: f64, to: f64, flags: u32, out: &mut [EventRecord]) -> usize {
+        let _ = (from, to, flags, out);
+        0
+    }
+
+    /// `host_pulse_to_offset`: the sample offset of `pulse` within the current quantum.
+    fn pulse_to_offset(&mut self, pulse: f64) -> u32 {
+        let _ = pulse;
+        0
+    }
+
+    /// `host_bind_parameter`: record a parameter's field-key path, returning its id.
+    fn bind_parameter(&mut self, path: &[u16]) -> u32 {
+        let _ = path;
+        0
+    }
+
+    /// `host_update_parameters`: the automated parameters that changed at `position`.
+    fn update_parameters(&mut self, position: f64, out: &mut [ParamChange]) -> usize {
+        let _ = (position, out);
+        0
+    }
+
+    /// `host_first_update_position`: the first update position at or after `at`, `INFINITY` for none.
+    fn first_update_position(&mut self, at: f64) -> f64 {
+        let _ = at;
+        f64::INFINITY
+    }
+
+    /// `host_next_update_position`: the next update position strictly after `after`, `INFINITY` for none.
+    fn next_update_position(&mut self, after: f64) -> f64 {
+        let _ = after;
+        f64::INFINITY
+    }
+
+    /// `host_observe_sample`: record a sample pointer-field path, returning its id.
+    fn observe_sample(&mut self, path: &[u16]) -> u32 {
+        let _ = path;
+        0
+    }
+
+    /// `host_resolve_sample`: the resident frames behind a sample handle.
+    fn resolve_sample(&mut self, handle: u32) -> Option<SampleRef> {
+        let _ = handle;
+        None
+    }
+
+    /// `host_observe_soundfont`: record a soundfont pointer-field path, returning its id.
+    fn observe_soundfont(&mut self, path: &[u16]) -> u32 {
+        let _ = path;
+        0
+    }
+
+    /// `host_resolve_soundfont`: the resident blob behind a soundfont handle.
+    fn resolve_soundfont(&mut self, handle: u32) -> Option<SoundfontRef> {
+        let _ = handle;
+        None
+    }
+
+    /// `host_observe_field`: record a plain field path, returning its id.
+    fn observe_field(&mut self, path: &[u16]) -> u32 {
+        let _ = path;
+        0
+    }
+
+    /// `host_observe_target_string`: record a pointer path whose target's string field `field_key` is
+    /// delivered, returning an id in the [`Host::observe_field`] id space.
+    fn observe_target_string(&mut self, path: &[u16], field_key: u16) -> u32 {
+        let _ = (path, field_key);
+        0
+    }
+
+    /// `host_bind_sidechain`: record a sidechain pointer path, returning its port id (`2, 3, ...`).
+    fn bind_sidechain(&mut self, path: &[u16]) -> u32 {
+        let _ = path;
+        0
+    }
+
+    /// `host_resolve_input`: the stereo buffer wired to an input port for the current quantum.
+    fn resolve_input(&mut self, id: u32) -> Option<AudioInputRef> {
+        let _ = id;
+        None
+    }
+
+    /// `host_bind_broadcast`: register a live-data slot of `len` 4-byte values (`package_type` 0 = one float,
+    /// 1 = a float array, 2 = an int ring), returning its id.
+    fn bind_broadcast(&mut self, path: &[u16], len: u32, package_type: u32) -> u32 {
+        let _ = (path, len, package_type);
+        0
+    }
+
+    /// `host_broadcast_ptr`: the write address of a broadcast slot, 0 while unbound.
+    fn broadcast_ptr(&mut self, id: u32) -> usize {
+        let _ = id;
+        0
+    }
+
+    /// `host_broadcast_active`: whether anything reads the slot.
+    fn broadcast_active(&mut self, id: u32) -> bool {
+        let _ = id;
+        false
+    }
+
+    /// `host_base_frequency`: the tuning reference in Hz.
+    fn base_frequency(&mut self) -> f32 {
+        440.0
+    }
+
+    /// `host_self_uuid`: the device box's uuid.
+    fn self_uuid(&mut self) -> [u8; 16] {
+        [0; 16]
+    }
+}
+
+thread_local! {
+    static HOST: Cell<Option<*mut (dyn Host + 'static)>> = const { Cell::new(None) };
+}
+
+/// Run `body` with `host` serving this thread's host calls, restoring whatever was installed before (also
+/// when `body` panics). Wrap every device export call in it: `init` binds through the host, `process` pulls
+/// through it.
+pub fn with_host<R>(host: &mut dyn Host, body: impl FnOnce() -> R) -> R {
+    struct Restore(Option<*mut (dyn Host + 'static)>);
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            HOST.with(|cell| cell.set(self.0));
+        }
+    }
+    // The lifetime is erased for the thread-local; `Restore` uninstalls the pointer before `host`'s borrow ends.
+    let pointer: *mut (dyn Host + '_) = host;
+    let pointer: *mut (dyn Host + 'static) = unsafe { core::mem::transmute(pointer) };
+    let _restore = Restore(HOST.with(|cell| cell.replace(Some(pointer))));
+    body()
+}
+
+/// Call the installed host, `None` when there is none. The host is taken out for the call, so a host method
+/// that re-enters a device sees the stubs rather than a second `&mut` to itself.
+pub(crate) fn call<R>(body: impl FnOnce(&mut dyn Host) -> R) -> Option<R> {
+    let pointer = HOST.with(|cell| cell.take())?;
+    struct Reinstall(*mut (dyn Host + 'static));
+    impl Drop for Reinstall {
+        fn drop(&mut self) {
+            HOST.with(|cell| cell.set(Some(self.0)));
+        }
+    }
+    let reinstall = Reinstall(pointer);
+    Some(body(unsafe { &mut *reinstall.0 }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{with_host, Host};
+
+    struct Counter {
+        bound: Vec<Vec<u16>>
+    }
+
+    impl Host for Counter {
+        fn bind_parameter(&mut self, path: &[u16]) -> u32 {
+            self.bound.push(path.to_vec());
+            self.bound.len() as u32 - 1
+        }
+    }
+
+    #[test]
+    fn wrappers_reach_the_installed_host_and_fall_back_without_one() {
+        let mut host = Counter {bound: Vec::new()};
+        let ids = with_host(&mut host, || (crate::bind_parameter(&[10]), crate::bind_parameter(&[16, 10])));
+        assert_eq!(ids, (0, 1));
+        assert_eq!(host.bound, vec![vec![10], vec![16, 10]]);
+        assert_eq!(crate::bind_parameter(&[10]), 0, "no host, the stub answers");
+        assert_eq!(crate::first_update_position(0.0), f64::INFINITY);
+    }
+
+    #[test]
+    fn an_unserved_import_keeps_its_stub() {
+        let mut host = Counter {bound: Vec::new()};
+        with_host(&mut host, || {
+            assert!(crate::resolve_input(crate::MAIN_INPUT).is_none());
+            assert_eq!(crate::base_frequency(), 440.0);
+        });
+    }
+}
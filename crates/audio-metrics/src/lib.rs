@@ -0,0 +1,8 @@
+//! audio-metrics: the deterministic homebrew measurements shared by the native test harnesses (the
+//! stretch-lab judge, the device golden renders). std, native-only, never a dependency of shipped code.
+//! Envelopes, a single-bin Goertzel probe, band-energy balance and level deltas, and a small WAV codec.
+
+pub mod envelope;
+pub mod goertzel;
+pub mod spectral;
+pub mod wav;
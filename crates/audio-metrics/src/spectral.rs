@@ -24,8 +24,8 @@ pub fn band_fractions(mono: &[f32], sample_rate: f64) -> [f64; NUM_BANDS] {
             im[index] = 0.0;
         }
         fft.forward(&mut re, &mut im);
-        for bin in 0..FFT_SIZE / 2 {
-            spectrum[bin] += (re[bin] as f64).powi(2) + (im[bin] as f64).powi(2);
+        for (bin, power) in spectrum.iter_mut().enumerate() {
+            *power += (re[bin] as f64).powi(2) + (im[bin] as f64).powi(2);
         }
         frames += 1;
         offset += hop;
@@ -35,13 +35,13 @@ pub fn band_fractions(mono: &[f32], sample_rate: f64) -> [f64; NUM_BANDS] {
         return bands;
     }
     let ratio_per_band = (BAND_HIGH_HZ / BAND_LOW_HZ).powf(1.0 / NUM_BANDS as f64);
-    for bin in 1..FFT_SIZE / 2 {
+    for (bin, power) in spectrum.iter().enumerate().skip(1) {
         let frequency = bin as f64 * sample_rate / FFT_SIZE as f64;
-        if frequency < BAND_LOW_HZ || frequency >= BAND_HIGH_HZ {
+        if !(BAND_LOW_HZ..BAND_HIGH_HZ).contains(&frequency) {
             continue;
         }
         let band = ((frequency / BAND_LOW_HZ).ln() / ratio_per_band.ln()) as usize;
-        bands[band.min(NUM_BANDS - 1)] += spectrum[bin];
+        bands[band.min(NUM_BANDS - 1)] += power;
     }
     let total: f64 = bands.iter().sum();
     if total > 0.0 {
@@ -1,7 +1,8 @@
 //! Observe a `NoteEventCollectionBox`: keep an owned `EventCollection<NoteEvent>` in sync, built
 //! incrementally from membership and edit events. The note counterpart of `ValueCollection` (and the
 //! TS `NoteEventCollectionBoxAdapter`); simpler, because notes have no curve boxes — an edit affects
-//! the collection only if it touches a member note directly.
+//! the collection only if it touches a member note directly. The collection's channel events (its
+//! `controls` hub: CCs, the pitch wheel, channel pressure) are kept the same way in a second collection.
 
 use alloc::boxed::Box;
 use alloc::collections::BTreeMap;
@@ -13,8 +14,9 @@ use boxgraph::address::{Address, Uuid};
 use boxgraph::graph::BoxGraph;
 use boxgraph::subscription::{HubEvent, Propagation, SubscriptionId};
 use value::event::EventCollection;
+use value::control::ControlEvent;
 use value::note::NoteEvent;
-use crate::note_events::{read_note_event, COLLECTION_EVENTS};
+use crate::note_events::{read_control_event, read_note_event, COLLECTION_CONTROLS, COLLECTION_EVENTS};
 
 // Clonable: `state` is shared by `Rc`, so a clone reads the same live collection. A binding keeps one
 // clone for teardown (`terminate` unsubscribes by id) while the sequencer reads from another.
@@ -24,18 +26,26 @@ pub struct NoteCollection {
     subscriptions: Vec<SubscriptionId>
 }
 
-/// The shared cache the observers maintain: the position-sorted collection, a uuid → note index (so an
-/// edit can remove / replace a note by uuid, since the collection is keyed by position), and the per-note
-/// TARGETED edit subscription (one `Parent` monitor per member note) to drop when the note leaves.
+/// The shared cache the observers maintain: the position-sorted collections, a uuid → event index each (so
+/// an edit can remove / replace an event by uuid, since a collection is keyed by position), and the per-event
+/// TARGETED edit subscription (one `Parent` monitor per member) to drop when the event leaves.
 struct State {
     events: EventCollection<NoteEvent>,
     index: BTreeMap<Uuid, NoteEvent>,
+    controls: EventCollection<ControlEvent>,
+    control_index: BTreeMap<Uuid, ControlEvent>,
     edit_subs: BTreeMap<Uuid, SubscriptionId>
 }
 
 impl State {
     fn new() -> Self {
-        Self {events: EventCollection::new(), index: BTreeMap::new(), edit_subs: BTreeMap::new()}
+        Self {
+            events: EventCollection::new(),
+            index: BTreeMap::new(),
+            controls: EventCollection::new(),
+            control_index: BTreeMap::new(),
+            edit_subs: BTreeMap::new()
+        }
     }
 
     fn upsert(&mut self, graph: &BoxGraph, note_uuid: Uuid) {
@@ -57,38 +67,62 @@ impl State {
             self.events.remove(&previous);
         }
     }
+
+    fn upsert_control(&mut self, graph: &BoxGraph, control_uuid: Uuid) {
+        if graph.find_box(&control_uuid).is_none() {
+            return; // deleted: the hub `Removed` that follows drops it (see `upsert`)
+        }
+        let control = read_control_event(graph, control_uuid);
+        if let Some(previous) = self.control_index.insert(control_uuid, control) {
+            self.controls.remove(&previous);
+        }
+        self.controls.add(control);
+    }
+
+    fn remove_control(&mut self, control_uuid: Uuid) {
+        if let Some(previous) = self.control_index.remove(&control_uuid) {
+            self.controls.remove(&previous);
+        }
+    }
+}
+
+// Observe one membership hub of the collection: upsert a joining member (plus a TARGETED edit monitor
+// re-reading it, deferred) and remove a leaving one (dropping its monitor).
+fn observe_members(graph: &mut BoxGraph, state: &Rc<RefCell<State>>, hub: Address,
+                   upsert: fn(&mut State, &BoxGraph, Uuid), remove: fn(&mut State, Uuid)) -> SubscriptionId {
+    let hub_state = state.clone();
+    let deferred = graph.deferred();
+    graph.subscribe_pointer_hub(hub, Box::new(move |graph, event| match event {
+        HubEvent::Added(source) => {
+            let uuid = source.uuid;
+            upsert(&mut hub_state.borrow_mut(), graph, uuid);
+            let edit_state = hub_state.clone();
+            let id = deferred.subscribe_vertex(Propagation::Parent, Address::box_of(uuid),
+                Box::new(move |graph, _update| upsert(&mut edit_state.borrow_mut(), graph, uuid)));
+            hub_state.borrow_mut().edit_subs.insert(uuid, id);
+        }
+        HubEvent::Removed(source) => {
+            remove(&mut hub_state.borrow_mut(), source.uuid);
+            if let Some(id) = hub_state.borrow_mut().edit_subs.remove(&source.uuid) {
+                deferred.unsubscribe(id);
+            }
+        }
+    }))
 }
 
 impl NoteCollection {
-    /// Observe a note-event collection: membership via the pointer-hub, and a TARGETED per-note edit
-    /// subscription added when a note joins (deferred, applied after dispatch / reconcile) and dropped when
-    /// it leaves. No all-updates listener — a note edit dispatches only to that note's own monitor.
+    /// Observe a note-event collection: membership of its notes and of its controls via the pointer-hubs,
+    /// and a TARGETED per-event edit subscription added when an event joins (deferred, applied after
+    /// dispatch / reconcile) and dropped when it leaves. No all-updates listener — an edit dispatches only
+    /// to that event's own monitor.
     pub fn observe(graph: &mut BoxGraph, collection: Uuid) -> Self {
         let state = Rc::new(RefCell::new(State::new()));
-        let mut subscriptions = Vec::new();
-
-        let hub_state = state.clone();
-        let deferred = graph.deferred();
-        subscriptions.push(graph.subscribe_pointer_hub(
-            Address::of(collection, vec![COLLECTION_EVENTS]),
-            Box::new(move |graph, event| match event {
-                HubEvent::Added(source) => {
-                    let note_uuid = source.uuid;
-                    hub_state.borrow_mut().upsert(graph, note_uuid);
-                    let edit_state = hub_state.clone();
-                    let id = deferred.subscribe_vertex(Propagation::Parent, Address::box_of(note_uuid),
-                        Box::new(move |graph, _update| edit_state.borrow_mut().upsert(graph, note_uuid)));
-                    hub_state.borrow_mut().edit_subs.insert(note_uuid, id);
-                }
-                HubEvent::Removed(source) => {
-                    hub_state.borrow_mut().remove(source.uuid);
-                    if let Some(id) = hub_state.borrow_mut().edit_subs.remove(&source.uuid) {
-                        deferred.unsubscribe(id);
-                    }
-                }
-            })
-        ));
-        // The catch-up above queued a per-note edit sub for each existing member; register them now (we
+        let subscriptions = vec![
+            observe_members(graph, &state, Address::of(collection, vec![COLLECTION_EVENTS]), State::upsert, State::remove),
+            observe_members(graph, &state, Address::of(collection, vec![COLLECTION_CONTROLS]),
+                State::upsert_control, State::remove_control)
+        ];
+        // The catch-up above queued a per-event edit sub for each existing member; register them now (we
         // hold `&mut graph`, outside any dispatch). Later joins are flushed by the transaction's dispatch.
         graph.apply_deferred();
         Self {state, subscriptions}
@@ -99,6 +133,11 @@ impl NoteCollection {
         Ref::map(self.state.borrow(), |state| &state.events)
     }
 
+    /// The cached channel events (borrow; cheap to take per render).
+    pub fn controls(&self) -> Ref<'_, EventCollection<ControlEvent>> {
+        Ref::map(self.state.borrow(), |state| &state.controls)
+    }
+
     pub fn len(&self) -> usize {
         self.state.borrow().events.len()
     }
@@ -107,7 +146,7 @@ impl NoteCollection {
         self.state.borrow().events.is_empty()
     }
 
-    /// Unsubscribe the hub observer and every per-note edit monitor (mirrors the TS adapter's `terminate`).
+    /// Unsubscribe the hub observers and every per-event edit monitor (mirrors the TS adapter's `terminate`).
     pub fn terminate(self, graph: &mut BoxGraph) {
         for id in self.subscriptions {
             graph.unsubscribe(id);
@@ -1,6 +1,7 @@
 //! Read a `NoteEventBox` into a `NoteEvent` (the per-box read `NoteCollection` drives incrementally),
 //! mirroring `value_events`. A note box carries position (key 10), duration (11), pitch (20),
-//! velocity (21) and cent (24). `position` and `pitch` are mandatory schema fields, so a member
+//! velocity (21) and cent (24). A `NoteControlEventBox` (the collection's `controls`) carries position (10),
+//! type (11), controller (12) and value (13) and reads into a `ControlEvent`. `position` and `pitch` are mandatory schema fields, so a member
 //! missing them is a corrupt mirror and panics (naming box type, field and uuid for the panic buffer);
 //! duration / velocity / cent fall back to schema defaults. Rejecting the transaction instead is not
 //! reachable: this read fires from subscription dispatch (and the `observe` catch-up during rebind),
@@ -12,10 +13,12 @@ use boxgraph::address::{uuid_to_string, Address, Uuid};
 use boxgraph::field::FieldValue;
 use boxgraph::graph::BoxGraph;
 use math::clamp;
+use value::control::{ControlEvent, ControlKind};
 use value::note::NoteEvent;
 
 // WASM CONTRACT: generated box field keys (studio-boxes). Keep in lockstep with the forge schema.
 pub const COLLECTION_EVENTS: u16 = 1; // NoteEventCollectionBox.events (hub)
+pub const COLLECTION_CONTROLS: u16 = 3; // NoteEventCollectionBox.controls (hub)
 const NOTE_POSITION: u16 = 10; // Int32 pulses
 const NOTE_DURATION: u16 = 11; // Int32 pulses
 const NOTE_PITCH: u16 = 20; // Int32 (0..=127)
@@ -24,6 +27,10 @@ const NOTE_PLAY_COUNT: u16 = 22; // Int32 (>= 1, the ratchet repeat count)
 const NOTE_PLAY_CURVE: u16 = 23; // Float32 (the ratchet time-warp, 0 = linear)
 const NOTE_CENT: u16 = 24; // Float32 cents
 const NOTE_CHANCE: u16 = 25; // Int32 (0..=100, the play probability)
+const CONTROL_POSITION: u16 = 10; // Int32 pulses
+const CONTROL_TYPE: u16 = 11; // Int32 (NoteControlType: 0 controller, 1 pitch bend, 2 channel pressure)
+const CONTROL_CONTROLLER: u16 = 12; // Int32 (0..=127, read for a controller)
+const CONTROL_VALUE: u16 = 13; // Float32 (0..=1, -1..=1 for the pitch bend)
 const DEFAULT_DURATION: i32 = 240;
 const DEFAULT_VELOCITY: f32 = 0.787_401_57; // 100/127, the schema default
 
@@ -42,3 +49,17 @@ pub fn read_note_event(graph: &BoxGraph, note_uuid: Uuid) -> NoteEvent {
     event.play_curve = field(NOTE_PLAY_CURVE).and_then(FieldValue::as_float32).unwrap_or(0.0);
     event
 }
+
+pub fn read_control_event(graph: &BoxGraph, control_uuid: Uuid) -> ControlEvent {
+    let field = |key: u16| graph.field_value(&Address::of(control_uuid, vec![key]));
+    let position = field(CONTROL_POSITION).and_then(FieldValue::as_int32)
+        .unwrap_or_else(|| panic!("NoteControlEventBox.position (Int32) missing @{}", uuid_to_string(&control_uuid))) as f64;
+    let raw = field(CONTROL_VALUE).and_then(FieldValue::as_float32).unwrap_or(0.0);
+    let kind = match field(CONTROL_TYPE).and_then(FieldValue::as_int32).unwrap_or(0) {
+        1 => ControlKind::PitchBend,
+        2 => ControlKind::Pressure,
+        _ => ControlKind::Change(clamp(field(CONTROL_CONTROLLER).and_then(FieldValue::as_int32).unwrap_or(1), 0, 127) as u8)
+    };
+    let value = if kind == ControlKind::PitchBend {clamp(raw, -1.0, 1.0)} else {clamp(raw, 0.0, 1.0)};
+    ControlEvent {position, kind, value}
+}
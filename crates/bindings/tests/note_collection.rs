@@ -1,5 +1,6 @@
 //! The incremental NoteCollection observer: initial build via the pointer-hub catch-up, insert on a
 //! new member, remove on disconnect, re-read on a member edit, and an unrelated edit left untouched.
+//! The channel events on the `controls` hub follow the same path.
 
 use boxgraph::address::{Address, Uuid};
 use boxgraph::boxes::{GraphBox, Registry};
@@ -7,10 +8,12 @@ use boxgraph::field::{FieldValue, Fields};
 use boxgraph::graph::BoxGraph;
 use boxgraph::updates::Update;
 use bindings::note_collection::NoteCollection;
+use value::control::{ControlEvent, ControlKind};
 
 const COLLECTION: Uuid = [1u8; 16];
 const NOTE_A: Uuid = [2u8; 16];
 const NOTE_B: Uuid = [3u8; 16];
+const CONTROL: Uuid = [4u8; 16];
 const OTHER: Uuid = [9u8; 16];
 
 fn graph_box(uuid: Uuid, name: &str, fields: &[(u16, FieldValue)]) -> GraphBox {
@@ -22,7 +25,7 @@ fn graph_box(uuid: Uuid, name: &str, fields: &[(u16, FieldValue)]) -> GraphBox {
 }
 
 fn collection_box() -> GraphBox {
-    graph_box(COLLECTION, "NoteEventCollectionBox", &[(1, FieldValue::Hook), (2, FieldValue::Hook)])
+    graph_box(COLLECTION, "NoteEventCollectionBox", &[(1, FieldValue::Hook), (2, FieldValue::Hook), (3, FieldValue::Hook)])
 }
 
 /// A NoteEventBox; `events` (1) points at the collection hub when `member`.
@@ -38,6 +41,21 @@ fn note_box(uuid: Uuid, position: i32, pitch: i32, member: bool) -> GraphBox {
     ])
 }
 
+/// A NoteControlEventBox on the collection's `controls` hub (3).
+fn control_box(uuid: Uuid, position: i32, kind: i32, value: f32) -> GraphBox {
+    graph_box(uuid, "NoteControlEventBox", &[
+        (1, FieldValue::Pointer(Some(Address::of(COLLECTION, vec![3])))),
+        (10, FieldValue::Int32(position)),
+        (11, FieldValue::Int32(kind)),
+        (12, FieldValue::Int32(74)),
+        (13, FieldValue::Float32(value))
+    ])
+}
+
+fn controls(collection: &NoteCollection) -> Vec<(f64, ControlKind, f32)> {
+    collection.controls().as_slice().iter().map(|ControlEvent {position, kind, value}| (*position, *kind, *value)).collect()
+}
+
 fn pitches(collection: &NoteCollection) -> Vec<u8> {
     collection.events().as_slice().iter().map(|note| note.pitch).collect()
 }
@@ -98,3 +116,28 @@ fn an_unrelated_edit_leaves_the_collection_untouched() {
     }], &registry).unwrap();
     assert_eq!(pitches(&collection), vec![60]);
 }
+
+#[test]
+fn observes_and_re_reads_control_events() {
+    let mut graph = BoxGraph::from_boxes(vec![collection_box(), note_box(NOTE_A, 0, 60, true), control_box(CONTROL, 240, 0, 0.5)]);
+    let collection = NoteCollection::observe(&mut graph, COLLECTION);
+    assert_eq!(pitches(&collection), vec![60], "controls stay out of the notes");
+    assert_eq!(controls(&collection), vec![(240.0, ControlKind::Change(74), 0.5)]);
+    let registry = Registry::new();
+    graph.transaction(&[Update::Primitive {
+        address: Address::of(CONTROL, vec![11]),
+        old: FieldValue::Int32(0),
+        new: FieldValue::Int32(1)
+    }, Update::Primitive {
+        address: Address::of(CONTROL, vec![13]),
+        old: FieldValue::Float32(0.5),
+        new: FieldValue::Float32(-0.25)
+    }], &registry).unwrap();
+    assert_eq!(controls(&collection), vec![(240.0, ControlKind::PitchBend, -0.25)]);
+    graph.transaction(&[Update::Pointer {
+        address: Address::of(CONTROL, vec![1]),
+        old: Some(Address::of(COLLECTION, vec![3])),
+        new: None
+    }], &registry).unwrap();
+    assert!(controls(&collection).is_empty(), "disconnecting a control removes it");
+}
@@ -1,9 +1,11 @@
 //! A box record and the box-type registry. Mirrors lib-box `box.ts`: a box serializes as
 //! `creationIndex(int) + name(string) + uuid(16 raw) + FLDS`. Reading needs the schema for the
-//! box's name, so `read` takes a registry (name → field schema).
+//! box's name, so `read` takes a registry (name → field schema); text formats also take the field
+//! names (name → `FieldName`s), generated alongside it.
 
 use alloc::collections::BTreeMap;
 use alloc::string::String;
+use alloc::vec::Vec;
 use crate::address::{read_uuid, write_uuid, Uuid};
 use crate::bytes::{ByteReader, ByteWriter};
 use crate::field::{read_fields, write_fields, Fields, Schema};
@@ -11,6 +13,17 @@ use crate::Error;
 
 pub type Registry = BTreeMap<String, Schema>;
 
+/// The name of a field; `fields` names the members of an object field (or of an array's object elements).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FieldName {
+    pub key: u16,
+    pub name: &'static str,
+    pub fields: &'static [FieldName]
+}
+
+/// Box name → its field names (studio-boxes `names()`).
+pub type Names = BTreeMap<String, &'static [FieldName]>;
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct GraphBox {
     pub creation_index: i32,
@@ -27,6 +40,13 @@ impl GraphBox {
         write_fields(writer, &self.fields);
     }
 
+    /// The fields as `New` / `Delete` update settings (FLDS bytes).
+    pub fn settings(&self) -> Vec<u8> {
+        let mut writer = ByteWriter::new();
+        write_fields(&mut writer, &self.fields);
+        writer.into_bytes()
+    }
+
     pub fn read(reader: &mut ByteReader, registry: &Registry) -> Result<GraphBox, Error> {
         let creation_index = reader.read_int()?;
         let name = reader.read_string()?;
@@ -88,6 +88,11 @@ impl BoxGraph {
         self.boxes.get(uuid)
     }
 
+    /// Every box, in uuid order.
+    pub fn boxes(&self) -> impl Iterator<Item = &GraphBox> {
+        self.boxes.values()
+    }
+
     pub fn box_names(&self) -> Vec<&str> {
         self.boxes.values().map(|graph_box| graph_box.name.as_str()).collect()
     }
@@ -127,6 +132,15 @@ impl BoxGraph {
         self.incoming.get(target).map(|sources| sources.iter().collect()).unwrap_or_default()
     }
 
+    /// Sources of the pointer fields aiming at a box or any vertex inside it (TS `incomingEdgesOf(box)`).
+    pub fn incoming_to_box(&self, uuid: Uuid) -> Vec<&Address> {
+        self.incoming
+            .range(Address::box_of(uuid)..)
+            .take_while(|(target, _)| target.uuid == uuid)
+            .flat_map(|(_, sources)| sources.iter())
+            .collect()
+    }
+
     /// Edges whose non-empty target does not resolve to an existing vertex (dangling pointers).
     pub fn dangling(&self) -> Vec<Edge> {
         self.unresolved
@@ -174,6 +188,43 @@ impl BoxGraph {
         Ok(())
     }
 
+    /// `transaction`, also returning its inverse: the updates that, applied as a transaction, restore the
+    /// graph (in application order). Old values are read from the graph as each update lands, not trusted
+    /// from the update, so a forward-only stream (`decode_forward`) inverts as well; a deleted box is
+    /// re-created with its full field content, pointers included. Allocates per update, so it is for
+    /// editing tools, not the audio thread. A failing update rolls back the ones before it and notifies no one.
+    pub fn transaction_with_inverse(&mut self, updates: &[Update], registry: &Registry) -> Result<Vec<Update>, Error> {
+        self.affected.clear();
+        let mut inverse = Vec::with_capacity(updates.len());
+        for update in updates {
+            let result = self.invert(update)
+                .and_then(|inverted| self.apply(update, registry).map(|()| inverted));
+            match result {
+                Ok(inverted) => inverse.push(inverted),
+                Err(error) => {
+                    for inverted in inverse.iter().rev() {
+                        self.apply(inverted, registry)?;
+                    }
+                    self.rebuild_edges();
+                    self.affected.clear();
+                    return Err(error);
+                }
+            }
+            self.update_edges(update);
+        }
+        // A created box's inverse can only be filled in once it exists.
+        for (update, inverted) in updates.iter().zip(inverse.iter_mut()) {
+            if let (Update::New {uuid, ..}, Update::Delete {settings, ..}) = (update, inverted) {
+                if let Some(graph_box) = self.boxes.get(uuid) {
+                    *settings = graph_box.settings();
+                }
+            }
+        }
+        self.dispatch(updates);
+        inverse.reverse();
+        Ok(inverse)
+    }
+
     /// Notify subscribers after a transaction. The subscriptions are lifted out of `self` for the
     /// duration so each observer can be handed `&self` (the fully-resolved graph) to read — the
     /// standard "method needs &self plus one of its fields" move, not a second copy of state.
@@ -319,6 +370,27 @@ impl BoxGraph {
         }
     }
 
+    /// The update undoing `update`, against the graph as it is just before `update` applies.
+    fn invert(&self, update: &Update) -> Result<Update, Error> {
+        Ok(match update {
+            Update::New {uuid, name, settings} =>
+                Update::Delete {uuid: *uuid, name: name.clone(), settings: settings.clone()},
+            Update::Delete {uuid, ..} => {
+                let graph_box = self.boxes.get(uuid).ok_or(Error::AddressNotFound)?;
+                Update::New {uuid: *uuid, name: graph_box.name.clone(), settings: graph_box.settings()}
+            }
+            Update::Primitive {address, new, ..} => {
+                let old = self.field_value(address).ok_or(Error::AddressNotFound)?;
+                Update::Primitive {address: address.clone(), old: new.clone(), new: old.clone()}
+            }
+            Update::Pointer {address, new, ..} => match self.field_value(address) {
+                Some(FieldValue::Pointer(old)) =>
+                    Update::Pointer {address: address.clone(), old: new.clone(), new: old.clone()},
+                _ => return Err(Error::AddressNotFound)
+            }
+        })
+    }
+
     fn create_box(&mut self, uuid: Uuid, name: &str, settings: &[u8], registry: &Registry) -> Result<(), Error> {
         let schema = registry.get(name).ok_or(Error::UnknownBox)?;
         let mut reader = ByteReader::new(settings);
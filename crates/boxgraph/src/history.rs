@@ -0,0 +1,131 @@
+//! Editing history over a `BoxGraph`, mirroring lib-box `BoxEditing` (editing.ts): each transaction is
+//! recorded with its inverse as a modification; `mark` seals the pending modifications into one undo step,
+//! so a gesture spanning several transactions undoes as a whole. Every modification also keeps the graph
+//! checksum on either side of it: undo/redo first check the graph is still where the history left it and
+//! then that the replay landed where it was recorded, so an edit made behind the history's back (another
+//! participant, a forgotten `transaction`) fails loudly instead of corrupting the graph.
+
+use alloc::vec::Vec;
+use crate::boxes::Registry;
+use crate::graph::BoxGraph;
+use crate::updates::Update;
+use crate::Error;
+
+/// One recorded transaction.
+struct Modification {
+    forward: Vec<Update>,
+    inverse: Vec<Update>,
+    before: [u8; 32],
+    after: [u8; 32]
+}
+
+impl Modification {
+    fn undo(&self, graph: &mut BoxGraph, registry: &Registry) -> Result<(), Error> {
+        Self::replay(graph, &self.inverse, registry, self.after, self.before)
+    }
+
+    fn redo(&self, graph: &mut BoxGraph, registry: &Registry) -> Result<(), Error> {
+        Self::replay(graph, &self.forward, registry, self.before, self.after)
+    }
+
+    // Apply `updates` from the state checksummed `from`, verifying it lands on `to`; a wrong landing is
+    // rolled back, leaving the graph as it was found.
+    fn replay(graph: &mut BoxGraph, updates: &[Update], registry: &Registry, from: [u8; 32], to: [u8; 32]) -> Result<(), Error> {
+        if graph.checksum() != from {
+            return Err(Error::ChecksumMismatch);
+        }
+        let inverse = graph.transaction_with_inverse(updates, registry)?;
+        if graph.checksum() != to {
+            graph.transaction(&inverse, registry)?;
+            return Err(Error::ChecksumMismatch);
+        }
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+pub struct UndoHistory {
+    pending: Vec<Modification>,
+    marked: Vec<Vec<Modification>>,
+    index: usize // marked[..index] are done, marked[index..] undone (redoable)
+}
+
+impl UndoHistory {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Apply `updates` as a transaction and record it as pending, to be sealed into a step by `mark` (or the
+    /// next `undo`). Nothing is recorded when the transaction fails (it rolled itself back).
+    pub fn modify(&mut self, graph: &mut BoxGraph, updates: &[Update], registry: &Registry) -> Result<(), Error> {
+        let before = graph.checksum();
+        let inverse = graph.transaction_with_inverse(updates, registry)?;
+        if !updates.is_empty() {
+            self.pending.push(Modification {forward: updates.to_vec(), inverse, before, after: graph.checksum()});
+        }
+        Ok(())
+    }
+
+    /// Seal the pending modifications into one undo step. A new step drops the redoable ones.
+    pub fn mark(&mut self) {
+        if self.pending.is_empty() {
+            return;
+        }
+        self.marked.truncate(self.index);
+        self.marked.push(core::mem::take(&mut self.pending));
+        self.index = self.marked.len();
+    }
+
+    pub fn can_undo(&self) -> bool {
+        self.index > 0 || !self.pending.is_empty()
+    }
+
+    pub fn can_redo(&self) -> bool {
+        self.index < self.marked.len() && self.pending.is_empty()
+    }
+
+    /// Undo the last step (sealing any pending modifications first). `Ok(false)` when there is nothing to
+    /// undo; on an error the step's already-undone modifications are redone and the history is unchanged.
+    pub fn undo(&mut self, graph: &mut BoxGraph, registry: &Registry) -> Result<bool, Error> {
+        self.mark();
+        if self.index == 0 {
+            return Ok(false);
+        }
+        let step = &self.marked[self.index - 1];
+        for (count, modification) in step.iter().rev().enumerate() {
+            if let Err(error) = modification.undo(graph, registry) {
+                for undone in step.iter().rev().take(count).rev() {
+                    undone.redo(graph, registry)?;
+                }
+                return Err(error);
+            }
+        }
+        self.index -= 1;
+        Ok(true)
+    }
+
+    /// Redo the next undone step. `Ok(false)` when there is nothing to redo; errors as `undo`.
+    pub fn redo(&mut self, graph: &mut BoxGraph, registry: &Registry) -> Result<bool, Error> {
+        if !self.can_redo() {
+            return Ok(false);
+        }
+        let step = &self.marked[self.index];
+        for (count, modification) in step.iter().enumerate() {
+            if let Err(error) = modification.redo(graph, registry) {
+                for redone in step.iter().take(count).rev() {
+                    redone.undo(graph, registry)?;
+                }
+                return Err(error);
+            }
+        }
+        self.index += 1;
+        Ok(true)
+    }
+
+    /// Forget every step, done and undone, and anything pending.
+    pub fn clear(&mut self) {
+        self.pending.clear();
+        self.marked.clear();
+        self.index = 0;
+    }
+}
@@ -0,0 +1,382 @@
+//! A lossless, human-readable text form of the graph, for reviewing project diffs and writing fixtures by
+//! hand. The shape follows lib-box `BoxGraph.toJSON` (an object of boxes keyed by uuid, each with its
+//! `name` and `fields`), but fields are keyed by name instead of number, and each box also carries the
+//! `creationIndex` the binary format stores:
+//!
+//! ```text
+//! {
+//!   "5b3c…": {
+//!     "name": "TrackBox",
+//!     "creationIndex": 7,
+//!     "fields": {
+//!       "tracks": "9f1e…/4",
+//!       "regions": null,
+//!       "index": 0,
+//!       "enabled": true
+//!     }
+//!   }
+//! }
+//! ```
+//!
+//! Values map as in lib-box: pointers are address strings or `null`, bytes are arrays of signed bytes.
+//! Hooks are written as `null` rather than left out, because projects saved before a hook was added to
+//! their box do not carry it. Floats are written in their shortest exact form, with `"NaN"`, `"Infinity"`
+//! and `"-Infinity"` as strings; a NaN's payload is not kept. On import a field unknown to `names` may
+//! also be keyed by its number, and a box without `creationIndex` gets its position in the document.
+//! `from_json(to_json())` restores the same bytes and checksum.
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use crate::address::{uuid_parse, uuid_to_string, Address};
+use crate::boxes::{FieldName, GraphBox, Names, Registry};
+use crate::field::{FieldType, FieldValue, Fields, Schema};
+use crate::graph::BoxGraph;
+use crate::Error;
+
+impl BoxGraph {
+    /// The graph as pretty-printed JSON, boxes in uuid order (as `to_bytes`), fields in key order.
+    pub fn to_json(&self, names: &Names) -> String {
+        let mut out = String::new();
+        if self.box_count() == 0 {
+            out.push_str("{}\n");
+            return out;
+        }
+        out.push('{');
+        for (position, graph_box) in self.boxes().enumerate() {
+            if position > 0 {
+                out.push(',');
+            }
+            newline(&mut out, 1);
+            write_string(&mut out, &uuid_to_string(&graph_box.uuid));
+            out.push_str(": {");
+            newline(&mut out, 2);
+            out.push_str("\"name\": ");
+            write_string(&mut out, &graph_box.name);
+            out.push(',');
+            newline(&mut out, 2);
+            out.push_str(&format!("\"creationIndex\": {},", graph_box.creation_index));
+            newline(&mut out, 2);
+            out.push_str("\"fields\": ");
+            let field_names = names.get(&graph_box.name).copied().unwrap_or(&[]);
+            write_fields(&mut out, 2, &graph_box.fields, field_names);
+            newline(&mut out, 1);
+            out.push('}');
+        }
+        out.push_str("\n}\n");
+        out
+    }
+
+    pub fn from_json(text: &str, registry: &Registry, names: &Names) -> Result<Self, Error> {
+        let mut parser = Parser {bytes: text.as_bytes(), position: 0};
+        let document = parser.value()?;
+        parser.skip_whitespace();
+        if parser.position != parser.bytes.len() {
+            return Err(Error::BadJson);
+        }
+        let Json::Object(entries) = document else {
+            return Err(Error::BadJson);
+        };
+        let mut loaded = Vec::with_capacity(entries.len());
+        for (position, (uuid, record)) in entries.into_iter().enumerate() {
+            let uuid = uuid_parse(&uuid).ok_or(Error::BadJson)?;
+            let Json::Object(record) = record else {
+                return Err(Error::BadJson);
+            };
+            let (mut name, mut creation_index, mut fields) = (None, position as i32, None);
+            for (key, value) in record {
+                match (key.as_str(), value) {
+                    ("name", Json::String(value)) => name = Some(value),
+                    ("creationIndex", Json::Number(value)) => creation_index = value.parse().map_err(|_| Error::BadJson)?,
+                    ("fields", Json::Object(value)) => fields = Some(value),
+                    _ => return Err(Error::BadJson)
+                }
+            }
+            let name = name.ok_or(Error::BadJson)?;
+            let schema = registry.get(&name).ok_or(Error::UnknownBox)?;
+            let field_names = names.get(&name).copied().unwrap_or(&[]);
+            let fields = read_fields(fields.unwrap_or_default(), schema, field_names)?;
+            loaded.push(GraphBox {creation_index, name, uuid, fields});
+        }
+        loaded.sort_by_key(|graph_box| graph_box.creation_index);
+        Ok(Self::from_boxes(loaded))
+    }
+}
+
+fn newline(out: &mut String, depth: usize) {
+    out.push('\n');
+    for _ in 0..depth {
+        out.push_str("  ");
+    }
+}
+
+fn write_string(out: &mut String, text: &str) {
+    out.push('"');
+    for character in text.chars() {
+        match character {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            character if (character as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", character as u32)),
+            character => out.push(character)
+        }
+    }
+    out.push('"');
+}
+
+fn field_name(names: &[FieldName], key: u16) -> Option<&FieldName> {
+    names.iter().find(|field| field.key == key)
+}
+
+fn write_fields(out: &mut String, depth: usize, fields: &Fields, names: &[FieldName]) {
+    if fields.is_empty() {
+        out.push_str("{}");
+        return;
+    }
+    out.push('{');
+    for (index, (key, value)) in fields.iter().enumerate() {
+        if index > 0 {
+            out.push(',');
+        }
+        newline(out, depth + 1);
+        let name = field_name(names, *key);
+        write_string(out, &name.map_or_else(|| key.to_string(), |field| field.name.to_string()));
+        out.push_str(": ");
+        write_value(out, depth + 1, value, name.map_or(&[], |field| field.fields));
+    }
+    newline(out, depth);
+    out.push('}');
+}
+
+fn write_value(out: &mut String, depth: usize, value: &FieldValue, names: &[FieldName]) {
+    match value {
+        FieldValue::Int32(value) => out.push_str(&value.to_string()),
+        FieldValue::Float32(value) if value.is_nan() => out.push_str("\"NaN\""),
+        FieldValue::Float32(value) if value.is_infinite() => out.push_str(if *value > 0.0 {"\"Infinity\""} else {"\"-Infinity\""}),
+        FieldValue::Float32(value) => out.push_str(&format!("{value:?}")),
+        FieldValue::Boolean(value) => out.push_str(if *value {"true"} else {"false"}),
+        FieldValue::String(value) => write_string(out, value),
+        FieldValue::Bytes(value) => {
+            let bytes: Vec<String> = value.iter().map(|byte| (*byte as i8).to_string()).collect();
+            out.push_str(&format!("[{}]", bytes.join(", ")));
+        }
+        FieldValue::Pointer(Some(address)) => write_string(out, &address.to_string()),
+        FieldValue::Pointer(None) | FieldValue::Hook => out.push_str("null"),
+        FieldValue::Object(fields) => write_fields(out, depth, fields, names),
+        FieldValue::Array(elements) if elements.is_empty() => out.push_str("[]"),
+        FieldValue::Array(elements) => {
+            out.push('[');
+            for (index, element) in elements.iter().enumerate() {
+                if index > 0 {
+                    out.push(',');
+                }
+                newline(out, depth + 1);
+                write_value(out, depth + 1, element, names);
+            }
+            newline(out, depth);
+            out.push(']');
+        }
+    }
+}
+
+fn read_fields(entries: Vec<(String, Json)>, schema: &Schema, names: &[FieldName]) -> Result<Fields, Error> {
+    let mut fields = Fields::new();
+    for (name, value) in entries {
+        let key = match names.iter().find(|field| field.name == name) {
+            Some(field) => field.key,
+            None => name.parse().map_err(|_| Error::UnknownField)?
+        };
+        let field_type = schema.get(&key).ok_or(Error::UnknownField)?;
+        let nested = field_name(names, key).map_or(&[][..], |field| field.fields);
+        fields.insert(key, read_value(value, field_type, nested)?);
+    }
+    Ok(fields)
+}
+
+fn read_value(value: Json, field_type: &FieldType, names: &[FieldName]) -> Result<FieldValue, Error> {
+    let value = match (field_type, value) {
+        (FieldType::Int32, Json::Number(number)) => FieldValue::Int32(number.parse().map_err(|_| Error::BadJson)?),
+        (FieldType::Float32, Json::Number(number)) => FieldValue::Float32(number.parse().map_err(|_| Error::BadJson)?),
+        (FieldType::Float32, Json::String(text)) => FieldValue::Float32(match text.as_str() {
+            "NaN" => f32::NAN,
+            "Infinity" => f32::INFINITY,
+            "-Infinity" => f32::NEG_INFINITY,
+            _ => return Err(Error::BadJson)
+        }),
+        (FieldType::Boolean, Json::Bool(value)) => FieldValue::Boolean(value),
+        (FieldType::String, Json::String(value)) => FieldValue::String(value),
+        (FieldType::Bytes, Json::Array(values)) => FieldValue::Bytes(values.into_iter().map(|value| match value {
+            Json::Number(number) => number.parse::<i8>().map(|byte| byte as u8).map_err(|_| Error::BadJson),
+            _ => Err(Error::BadJson)
+        }).collect::<Result<_, _>>()?),
+        (FieldType::Pointer, Json::Null) => FieldValue::Pointer(None),
+        (FieldType::Pointer, Json::String(text)) => FieldValue::Pointer(Some(Address::decode(&text).ok_or(Error::BadJson)?)),
+        (FieldType::Hook, Json::Null) => FieldValue::Hook,
+        (FieldType::Object(schema), Json::Object(entries)) => FieldValue::Object(read_fields(entries, schema, names)?),
+        (FieldType::Array {element, length}, Json::Array(values)) if values.len() == *length => FieldValue::Array(values.into_iter()
+            .map(|value| read_value(value, element, names))
+            .collect::<Result<_, _>>()?),
+        _ => return Err(Error::BadJson)
+    };
+    Ok(value)
+}
+
+// Numbers stay text until their field type says how to read them (an int32 must not pass through f64).
+enum Json {
+    Null,
+    Bool(bool),
+    Number(String),
+    String(String),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>)
+}
+
+struct Parser<'a> {
+    bytes: &'a [u8],
+    position: usize
+}
+
+impl Parser<'_> {
+    fn skip_whitespace(&mut self) {
+        while let Some(b' ' | b'\t' | b'\n' | b'\r') = self.bytes.get(self.position) {
+            self.position += 1;
+        }
+    }
+
+    fn peek(&mut self) -> Option<u8> {
+        self.skip_whitespace();
+        self.bytes.get(self.position).copied()
+    }
+
+    fn expect(&mut self, byte: u8) -> Result<(), Error> {
+        if self.peek() != Some(byte) {
+            return Err(Error::BadJson);
+        }
+        self.position += 1;
+        Ok(())
+    }
+
+    fn value(&mut self) -> Result<Json, Error> {
+        match self.peek().ok_or(Error::BadJson)? {
+            b'{' => {
+                self.position += 1;
+                let mut entries = Vec::new();
+                if self.peek() == Some(b'}') {
+                    self.position += 1;
+                    return Ok(Json::Object(entries));
+                }
+                loop {
+                    self.skip_whitespace();
+                    let key = self.string()?;
+                    self.expect(b':')?;
+                    entries.push((key, self.value()?));
+                    if self.peek() == Some(b',') {
+                        self.position += 1;
+                    } else {
+                        self.expect(b'}')?;
+                        return Ok(Json::Object(entries));
+                    }
+                }
+            }
+            b'[' => {
+                self.position += 1;
+                let mut values = Vec::new();
+                if self.peek() == Some(b']') {
+                    self.position += 1;
+                    return Ok(Json::Array(values));
+                }
+                loop {
+                    values.push(self.value()?);
+                    if self.peek() == Some(b',') {
+                        self.position += 1;
+                    } else {
+                        self.expect(b']')?;
+                        return Ok(Json::Array(values));
+                    }
+                }
+            }
+            b'"' => Ok(Json::String(self.string()?)),
+            b't' => self.literal("true", Json::Bool(true)),
+            b'f' => self.literal("false", Json::Bool(false)),
+            b'n' => self.literal("null", Json::Null),
+            b'-' | b'0'..=b'9' => Ok(Json::Number(self.number()?)),
+            _ => Err(Error::BadJson)
+        }
+    }
+
+    fn literal(&mut self, word: &str, value: Json) -> Result<Json, Error> {
+        if !self.bytes[self.position..].starts_with(word.as_bytes()) {
+            return Err(Error::BadJson);
+        }
+        self.position += word.len();
+        Ok(value)
+    }
+
+    fn number(&mut self) -> Result<String, Error> {
+        let start = self.position;
+        while let Some(b'-' | b'+' | b'.' | b'e' | b'E' | b'0'..=b'9') = self.bytes.get(self.position) {
+            self.position += 1;
+        }
+        // The grammar is left to `str::parse` of the field type; JSON's is a subset of it.
+        core::str::from_utf8(&self.bytes[start..self.position]).map(String::from).map_err(|_| Error::BadJson)
+    }
+
+    fn string(&mut self) -> Result<String, Error> {
+        if self.bytes.get(self.position) != Some(&b'"') {
+            return Err(Error::BadJson);
+        }
+        self.position += 1;
+        let mut bytes = Vec::new();
+        loop {
+            let byte = *self.bytes.get(self.position).ok_or(Error::BadJson)?;
+            self.position += 1;
+            match byte {
+                b'"' => return String::from_utf8(bytes).map_err(|_| Error::BadJson),
+                b'\\' => {
+                    let escape = *self.bytes.get(self.position).ok_or(Error::BadJson)?;
+                    self.position += 1;
+                    let character = match escape {
+                        b'"' => '"',
+                        b'\\' => '\\',
+                        b'/' => '/',
+                        b'b' => '\u{8}',
+                        b'f' => '\u{c}',
+                        b'n' => '\n',
+                        b'r' => '\r',
+                        b't' => '\t',
+                        b'u' => self.unicode_escape()?,
+                        _ => return Err(Error::BadJson)
+                    };
+                    bytes.extend_from_slice(character.encode_utf8(&mut [0; 4]).as_bytes());
+                }
+                byte => bytes.push(byte)
+            }
+        }
+    }
+
+    // After `\u`: four hex digits, or a surrogate pair spelled as two escapes.
+    fn unicode_escape(&mut self) -> Result<char, Error> {
+        let high = self.hex4()?;
+        if !(0xD800..0xDC00).contains(&high) {
+            return char::from_u32(high).ok_or(Error::BadJson);
+        }
+        if !self.bytes[self.position..].starts_with(b"\\u") {
+            return Err(Error::BadJson);
+        }
+        self.position += 2;
+        let low = self.hex4()?;
+        if !(0xDC00..0xE000).contains(&low) {
+            return Err(Error::BadJson);
+        }
+        char::from_u32(0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00)).ok_or(Error::BadJson)
+    }
+
+    fn hex4(&mut self) -> Result<u32, Error> {
+        let digits = self.bytes.get(self.position..self.position + 4).ok_or(Error::BadJson)?;
+        self.position += 4;
+        let digits = core::str::from_utf8(digits).map_err(|_| Error::BadJson)?;
+        u32::from_str_radix(digits, 16).map_err(|_| Error::BadJson)
+    }
+}
@@ -14,6 +14,10 @@ pub mod graph;
 pub mod updates;
 pub mod checksum;
 pub mod subscription;
+pub mod history;
+pub mod rules;
+pub mod validate;
+pub mod json;
 
 #[derive(Debug, PartialEq, Eq)]
 pub enum Error {
@@ -22,6 +26,12 @@ pub enum Error {
     UnknownBox,
     UnknownUpdate,
     AddressNotFound,
+    /// The graph is not in the state an undo/redo step was recorded against.
+    ChecksumMismatch,
+    /// Text that is not JSON, or a JSON value that does not fit its field's type.
+    BadJson,
+    /// A JSON field name (or numeric key) the box's schema does not have.
+    UnknownField,
 }
 
 impl From<bytes::ByteError> for Error {
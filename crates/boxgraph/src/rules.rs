@@ -0,0 +1,63 @@
+//! The pointer contract of each box type, beyond what serialization needs: the type a pointer field
+//! carries and whether it may be empty, which pointer types a box or field accepts, whether it must be
+//! pointed at (`mandatory`) or by one pointer at most (`exclusive`), and the int field ordering a box in
+//! its collection. Mirrors lib-box `PointerRules` + `PointerField.mandatory`; generated by the box-forge
+//! next to the `Registry` and checked by `BoxGraph::validate`. Pointer types are enum member names.
+
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+
+/// A pointer field.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Pointer {
+    pub pointer_type: &'static str,
+    /// Must point somewhere; a box whose mandatory pointer loses its target goes with it.
+    pub mandatory: bool
+}
+
+/// A pointer target (a box, a hook, or a primitive field that can be automated or modulated).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Target {
+    pub accepts: &'static [&'static str],
+    pub mandatory: bool,
+    pub exclusive: bool
+}
+
+/// Nothing may point here: the rule of a field the schema gives no pointer rules.
+pub const NO_POINTERS: Target = Target {accepts: &[], mandatory: false, exclusive: false};
+
+/// The int field ordering a box among the others in its collection — the boxes whose `collection`
+/// pointer joins the same target (devices in an effect chain, tracks of a unit, clips of a track, ...).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Index {
+    pub field: &'static [u16],
+    pub collection: &'static [u16]
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BoxRules {
+    /// The rules of the box itself as a target (pointers at any vertex inside it count).
+    pub target: Target,
+    /// Every pointer field, by field path.
+    pub pointers: &'static [(&'static [u16], Pointer)],
+    /// Every non-box target, by field path.
+    pub targets: &'static [(&'static [u16], Target)],
+    pub index: Option<Index>
+}
+
+impl BoxRules {
+    pub fn pointer(&self, path: &[u16]) -> Option<&Pointer> {
+        self.pointers.iter().find(|(field, _)| *field == path).map(|(_, pointer)| pointer)
+    }
+
+    /// The target rules at `path` (the box's own for an empty path); `NO_POINTERS` where the schema has none.
+    pub fn target(&self, path: &[u16]) -> &Target {
+        if path.is_empty() {
+            return &self.target;
+        }
+        self.targets.iter().find(|(field, _)| *field == path).map_or(&NO_POINTERS, |(_, target)| target)
+    }
+}
+
+/// Box name → its pointer contract (studio-boxes `rules()`).
+pub type Rules = BTreeMap<String, BoxRules>;
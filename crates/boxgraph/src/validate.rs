@@ -0,0 +1,191 @@
+//! Whole-graph validation against the pointer `Rules`, and an optional repair. `dangling()` only finds
+//! broken edges; a project can also carry pointers to the wrong box type or into a hook of another kind,
+//! empty mandatory pointers, unowned mandatory targets, or two devices claiming one slot of a chain — all
+//! of which the engine's binders assume away. Validation reports them; repair heals them the way lib-box
+//! editing would have kept them from arising (clear a stray pointer, drop a box that lost its owner,
+//! renumber a collection), so a corrupted project can be rejected or healed before anything binds it.
+
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::string::String;
+use alloc::vec::Vec;
+use crate::address::{Address, Uuid};
+use crate::boxes::Registry;
+use crate::field::FieldValue;
+use crate::graph::BoxGraph;
+use crate::rules::Rules;
+use crate::updates::Update;
+use crate::Error;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Violation {
+    /// A box type the rules do not know (its rules were generated from another schema); left unchecked.
+    UnknownBox {uuid: Uuid, name: String},
+    /// A pointer whose target does not exist.
+    Dangling {source: Address, target: Address},
+    /// A pointer whose target does not accept its type: a box of the wrong type, or an incompatible hook.
+    Rejected {source: Address, target: Address, pointer_type: &'static str},
+    /// An empty mandatory pointer.
+    MissingTarget {source: Address},
+    /// A mandatory target nothing points at.
+    MissingPointer {target: Address},
+    /// An exclusive target with more than one pointer.
+    NotExclusive {target: Address, sources: Vec<Address>},
+    /// Boxes of one indexed collection sharing an index (the collection is their common target).
+    IndexCollision {collection: Address, index: i32, members: Vec<Uuid>}
+}
+
+impl BoxGraph {
+    /// Every rule violation, box by box in uuid order (index collisions last).
+    pub fn validate(&self, rules: &Rules) -> Vec<Violation> {
+        let mut violations = Vec::new();
+        let mut collections: BTreeMap<Address, Vec<(i32, Uuid)>> = BTreeMap::new();
+        for graph_box in self.boxes() {
+            let uuid = graph_box.uuid;
+            let Some(box_rules) = rules.get(&graph_box.name) else {
+                violations.push(Violation::UnknownBox {uuid, name: graph_box.name.clone()});
+                continue;
+            };
+            for (path, pointer) in box_rules.pointers {
+                let source = Address::of(uuid, path.to_vec());
+                match self.field_value(&source) {
+                    Some(FieldValue::Pointer(Some(target))) => {
+                        if !self.vertex_exists(target) {
+                            violations.push(Violation::Dangling {source, target: target.clone()});
+                        } else if !self.accepts(rules, target, pointer.pointer_type) {
+                            violations.push(Violation::Rejected {source, target: target.clone(), pointer_type: pointer.pointer_type});
+                        }
+                    }
+                    Some(FieldValue::Pointer(None)) if pointer.mandatory => violations.push(Violation::MissingTarget {source}),
+                    _ => {}
+                }
+            }
+            let targets = core::iter::once((Address::box_of(uuid), box_rules.target, self.incoming_to_box(uuid)))
+                .chain(box_rules.targets.iter().map(|(path, target)| {
+                    let address = Address::of(uuid, path.to_vec());
+                    let incoming = self.incoming(&address);
+                    (address, *target, incoming)
+                }));
+            for (address, target, incoming) in targets {
+                if target.mandatory && incoming.is_empty() {
+                    violations.push(Violation::MissingPointer {target: address});
+                } else if target.exclusive && incoming.len() > 1 {
+                    violations.push(Violation::NotExclusive {target: address, sources: incoming.into_iter().cloned().collect()});
+                }
+            }
+            if let Some(index) = box_rules.index {
+                let collection = self.target_of(&Address::of(uuid, index.collection.to_vec()));
+                let value = self.field_value(&Address::of(uuid, index.field.to_vec())).and_then(FieldValue::as_int32);
+                if let (Some(collection), Some(value)) = (collection, value) {
+                    collections.entry(collection.clone()).or_default().push((value, uuid));
+                }
+            }
+        }
+        for (collection, mut members) in collections {
+            members.sort();
+            for group in members.chunk_by(|a, b| a.0 == b.0).filter(|group| group.len() > 1) {
+                violations.push(Violation::IndexCollision {
+                    collection: collection.clone(),
+                    index: group[0].0,
+                    members: group.iter().map(|(_, uuid)| *uuid).collect()
+                });
+            }
+        }
+        violations
+    }
+
+    /// Heal what `validate` reports, in rounds until nothing is left to fix: a stray pointer (dangling or
+    /// rejected, or one of several at an exclusive target) is cleared, or takes its box with it when
+    /// mandatory; a box missing its mandatory pointer or its mandatory owner is deleted, which may strand
+    /// others for the next round (the lib-box cascade); a collection with colliding indices is renumbered
+    /// in (index, uuid) order. Returns the updates applied, in order, for mirrors and logs; `validate`
+    /// afterwards reports only what cannot be healed (unknown boxes).
+    pub fn repair(&mut self, registry: &Registry, rules: &Rules) -> Result<Vec<Update>, Error> {
+        let mut applied = Vec::new();
+        loop {
+            let violations = self.validate(rules);
+            let mut updates = self.structural_repairs(rules, &violations);
+            if updates.is_empty() {
+                updates = self.renumbering(rules, &violations);
+            }
+            if updates.is_empty() {
+                return Ok(applied);
+            }
+            self.transaction(&updates, registry)?;
+            applied.extend(updates);
+        }
+    }
+
+    fn accepts(&self, rules: &Rules, target: &Address, pointer_type: &str) -> bool {
+        let Some(target_rules) = self.find_box(&target.uuid).and_then(|graph_box| rules.get(&graph_box.name)) else {
+            return false;
+        };
+        target_rules.target(&target.field_keys).accepts.contains(&pointer_type)
+    }
+
+    // Deletions and pointer clears for one round; deleting a box supersedes clearing its pointers.
+    fn structural_repairs(&self, rules: &Rules, violations: &[Violation]) -> Vec<Update> {
+        let mut deleted = BTreeSet::new();
+        let mut cleared = Vec::new();
+        let mut drop_pointer = |source: &Address, deleted: &mut BTreeSet<Uuid>| {
+            let mandatory = self.find_box(&source.uuid)
+                .and_then(|graph_box| rules.get(&graph_box.name))
+                .and_then(|box_rules| box_rules.pointer(&source.field_keys))
+                .is_some_and(|pointer| pointer.mandatory);
+            if mandatory {
+                deleted.insert(source.uuid);
+            } else {
+                cleared.push(source.clone());
+            }
+        };
+        for violation in violations {
+            match violation {
+                Violation::Dangling {source, ..} | Violation::Rejected {source, ..} => drop_pointer(source, &mut deleted),
+                Violation::NotExclusive {sources, ..} => {
+                    for source in &sources[1..] {
+                        drop_pointer(source, &mut deleted);
+                    }
+                }
+                Violation::MissingTarget {source} => {deleted.insert(source.uuid);}
+                Violation::MissingPointer {target} => {deleted.insert(target.uuid);}
+                Violation::UnknownBox {..} | Violation::IndexCollision {..} => {}
+            }
+        }
+        let clears = cleared.into_iter()
+            .filter(|source| !deleted.contains(&source.uuid))
+            .map(|source| {
+                let old = self.target_of(&source).cloned();
+                Update::Pointer {address: source, old, new: None}
+            });
+        let deletes = deleted.iter().filter_map(|uuid| self.find_box(uuid)).map(|graph_box|
+            Update::Delete {uuid: graph_box.uuid, name: graph_box.name.clone(), settings: graph_box.settings()});
+        clears.chain(deletes).collect()
+    }
+
+    // Renumber each collection with colliding indices 0, 1, ... in (index, uuid) order.
+    fn renumbering(&self, rules: &Rules, violations: &[Violation]) -> Vec<Update> {
+        let collections: BTreeSet<&Address> = violations.iter().filter_map(|violation| match violation {
+            Violation::IndexCollision {collection, ..} => Some(collection),
+            _ => None
+        }).collect();
+        let mut updates = Vec::new();
+        for collection in collections {
+            let mut members: Vec<(i32, Address)> = self.incoming(collection).into_iter()
+                .filter_map(|source| {
+                    let index = rules.get(&self.find_box(&source.uuid)?.name)?.index?;
+                    if index.collection != source.field_keys.as_slice() {
+                        return None;
+                    }
+                    let field = Address::of(source.uuid, index.field.to_vec());
+                    Some((self.field_value(&field)?.as_int32()?, field))
+                })
+                .collect();
+            members.sort();
+            for (position, (old, field)) in members.into_iter().enumerate() {
+                if old != position as i32 {
+                    updates.push(Update::Primitive {address: field, old: FieldValue::Int32(old), new: FieldValue::Int32(position as i32)});
+                }
+            }
+        }
+        updates
+    }
+}
@@ -0,0 +1,111 @@
+use std::collections::BTreeMap;
+use boxgraph::address::{Address, Uuid};
+use boxgraph::boxes::{GraphBox, Registry};
+use boxgraph::bytes::ByteWriter;
+use boxgraph::field::{write_fields, FieldType, FieldValue, Fields, Schema};
+use boxgraph::graph::BoxGraph;
+use boxgraph::history::UndoHistory;
+use boxgraph::updates::Update;
+use boxgraph::Error;
+
+const A: Uuid = [0xA, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+const B: Uuid = [0xB, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+
+fn registry() -> Registry {
+    Registry::from([("Node".to_string(), Schema::from([(0, FieldType::Int32), (1, FieldType::Pointer)]))])
+}
+
+fn fields(value: i32, pointer: Option<Address>) -> Fields {
+    BTreeMap::from([(0, FieldValue::Int32(value)), (1, FieldValue::Pointer(pointer))])
+}
+
+fn graph() -> BoxGraph {
+    BoxGraph::from_boxes(vec![GraphBox {creation_index: 0, name: "Node".to_string(), uuid: A, fields: fields(1, None)}])
+}
+
+fn set(value: i32) -> Vec<Update> {
+    vec![Update::Primitive {address: Address::of(A, vec![0]), old: FieldValue::Int32(0), new: FieldValue::Int32(value)}]
+}
+
+fn create_b() -> Vec<Update> {
+    let mut writer = ByteWriter::new();
+    write_fields(&mut writer, &fields(2, Some(Address::box_of(A))));
+    vec![Update::New {uuid: B, name: "Node".to_string(), settings: writer.into_bytes()}]
+}
+
+fn value(graph: &BoxGraph) -> Option<i32> {
+    graph.field_value(&Address::of(A, vec![0])).and_then(FieldValue::as_int32)
+}
+
+#[test]
+fn undo_and_redo_walk_the_marked_steps() {
+    let registry = registry();
+    let mut graph = graph();
+    let mut history = UndoHistory::new();
+    assert!(!history.can_undo());
+    history.modify(&mut graph, &set(10), &registry).unwrap();
+    history.mark();
+    history.modify(&mut graph, &create_b(), &registry).unwrap();
+    history.mark();
+    assert_eq!(graph.incoming(&Address::box_of(A)).len(), 1);
+    assert!(history.undo(&mut graph, &registry).unwrap());
+    assert!(graph.find_box(&B).is_none());
+    assert!(graph.incoming(&Address::box_of(A)).is_empty());
+    assert!(history.undo(&mut graph, &registry).unwrap());
+    assert_eq!(value(&graph), Some(1));
+    assert!(!history.undo(&mut graph, &registry).unwrap(), "nothing left to undo");
+    assert!(history.redo(&mut graph, &registry).unwrap());
+    assert!(history.redo(&mut graph, &registry).unwrap());
+    assert!(!history.can_redo());
+    assert_eq!(value(&graph), Some(10));
+    assert_eq!(graph.incoming(&Address::box_of(A)), vec![&Address::of(B, vec![1])]);
+}
+
+#[test]
+fn a_mark_groups_transactions_into_one_step() {
+    let registry = registry();
+    let mut graph = graph();
+    let mut history = UndoHistory::new();
+    for value in [2, 3, 4] {
+        history.modify(&mut graph, &set(value), &registry).unwrap(); // a drag: one transaction per move
+    }
+    history.mark();
+    assert!(history.undo(&mut graph, &registry).unwrap());
+    assert_eq!(value(&graph), Some(1));
+    assert!(!history.can_undo());
+}
+
+#[test]
+fn undo_seals_pending_and_a_new_step_drops_the_redoable_ones() {
+    let registry = registry();
+    let mut graph = graph();
+    let mut history = UndoHistory::new();
+    history.modify(&mut graph, &set(2), &registry).unwrap();
+    history.modify(&mut graph, &set(3), &registry).unwrap();
+    assert!(history.undo(&mut graph, &registry).unwrap(), "the pending modifications undo as one step");
+    assert_eq!(value(&graph), Some(1));
+    assert!(history.can_redo());
+    history.modify(&mut graph, &set(5), &registry).unwrap();
+    assert!(!history.can_redo(), "pending work blocks redo");
+    history.mark();
+    assert!(!history.redo(&mut graph, &registry).unwrap());
+    assert!(history.undo(&mut graph, &registry).unwrap());
+    assert_eq!(value(&graph), Some(1));
+}
+
+#[test]
+fn an_edit_behind_the_history_fails_the_checksum_and_changes_nothing() {
+    let registry = registry();
+    let mut graph = graph();
+    let mut history = UndoHistory::new();
+    history.modify(&mut graph, &set(2), &registry).unwrap();
+    history.modify(&mut graph, &set(3), &registry).unwrap();
+    history.mark();
+    graph.transaction(&set(9), &registry).unwrap();
+    let diverged = graph.checksum();
+    assert_eq!(history.undo(&mut graph, &registry), Err(Error::ChecksumMismatch));
+    assert_eq!(graph.checksum(), diverged);
+    graph.transaction(&set(3), &registry).unwrap();
+    assert!(history.undo(&mut graph, &registry).unwrap(), "back where the history left it, undo works again");
+    assert_eq!(value(&graph), Some(1));
+}
@@ -0,0 +1,140 @@
+use std::collections::BTreeMap;
+use boxgraph::address::{Address, Uuid};
+use boxgraph::boxes::{FieldName, GraphBox, Names, Registry};
+use boxgraph::field::{FieldType, FieldValue, Schema};
+use boxgraph::graph::BoxGraph;
+use boxgraph::Error;
+
+const A: Uuid = [0xA, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+const B: Uuid = [0xB, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+
+// "Thing": one field of every type; key 40 is an object, key 50 an array of objects.
+fn registry() -> Registry {
+    let point = Schema::from([(1, FieldType::Float32), (2, FieldType::Float32)]);
+    Registry::from([("Thing".to_string(), Schema::from([
+        (1, FieldType::Int32),
+        (2, FieldType::Float32),
+        (3, FieldType::Boolean),
+        (4, FieldType::String),
+        (5, FieldType::Bytes),
+        (6, FieldType::Pointer),
+        (7, FieldType::Hook),
+        (40, FieldType::Object(Schema::from([(1, FieldType::Int32), (2, FieldType::Hook)]))),
+        (50, FieldType::Array {element: Box::new(FieldType::Object(point)), length: 2})
+    ]))])
+}
+
+const POINT: &[FieldName] = &[FieldName {key: 1, name: "x", fields: &[]}, FieldName {key: 2, name: "y", fields: &[]}];
+
+fn names() -> Names {
+    Names::from([("Thing".to_string(), &[
+        FieldName {key: 1, name: "count", fields: &[]},
+        FieldName {key: 2, name: "gain", fields: &[]},
+        FieldName {key: 3, name: "enabled", fields: &[]},
+        FieldName {key: 4, name: "label", fields: &[]},
+        FieldName {key: 5, name: "data", fields: &[]},
+        FieldName {key: 6, name: "target", fields: &[]},
+        FieldName {key: 7, name: "hook", fields: &[]},
+        FieldName {key: 40, name: "nested", fields: &[FieldName {key: 1, name: "value", fields: &[]}, FieldName {key: 2, name: "inner", fields: &[]}]},
+        FieldName {key: 50, name: "points", fields: POINT}
+    ] as &[FieldName])])
+}
+
+fn point(x: f32, y: f32) -> FieldValue {
+    FieldValue::Object(BTreeMap::from([(1, FieldValue::Float32(x)), (2, FieldValue::Float32(y))]))
+}
+
+fn thing(creation_index: i32, uuid: Uuid, label: &str, gain: f32, target: Option<Address>) -> GraphBox {
+    GraphBox {creation_index, name: "Thing".to_string(), uuid, fields: BTreeMap::from([
+        (1, FieldValue::Int32(-7)),
+        (2, FieldValue::Float32(gain)),
+        (3, FieldValue::Boolean(true)),
+        (4, FieldValue::String(label.to_string())),
+        (5, FieldValue::Bytes(vec![0, 1, 0x7f, 0x80, 0xff])),
+        (6, FieldValue::Pointer(target)),
+        (7, FieldValue::Hook),
+        (40, FieldValue::Object(BTreeMap::from([(1, FieldValue::Int32(i32::MIN)), (2, FieldValue::Hook)]))),
+        (50, FieldValue::Array(vec![point(0.1, -0.0), point(1e-30, f32::MAX)]))
+    ])}
+}
+
+fn round_trip(graph: &BoxGraph) -> BoxGraph {
+    BoxGraph::from_json(&graph.to_json(&names()), &registry(), &names()).unwrap()
+}
+
+#[test]
+fn round_trips_every_field_type() {
+    let graph = BoxGraph::from_boxes(vec![
+        thing(0, A, "quote \" slash \\ tab \t bell \u{7} umlaut ü emoji 🎛", 0.75, Some(Address::of(B, vec![7]))),
+        thing(1, B, "", -1.5, None)
+    ]);
+    let restored = round_trip(&graph);
+    assert_eq!(restored.to_bytes(), graph.to_bytes());
+    assert_eq!(restored.checksum(), graph.checksum());
+    assert_eq!(restored.incoming(&Address::of(B, vec![7])), vec![&Address::of(A, vec![6])]);
+}
+
+#[test]
+fn keeps_non_finite_floats_and_negative_zero() {
+    for gain in [f32::INFINITY, f32::NEG_INFINITY, -0.0, f32::MIN_POSITIVE, 16777217.0] {
+        let graph = BoxGraph::from_boxes(vec![thing(0, A, "", gain, None)]);
+        assert_eq!(round_trip(&graph).to_bytes(), graph.to_bytes(), "{gain}");
+    }
+    let graph = BoxGraph::from_boxes(vec![thing(0, A, "", f32::NAN, None)]);
+    let gain = round_trip(&graph).field_value(&Address::of(A, vec![2])).and_then(FieldValue::as_float32);
+    assert!(gain.is_some_and(f32::is_nan));
+}
+
+#[test]
+fn prints_fields_by_name() {
+    let graph = BoxGraph::from_boxes(vec![thing(3, A, "a", 0.5, Some(Address::of(B, vec![7])))]);
+    let json = graph.to_json(&names());
+    assert!(json.starts_with("{\n  \"0a000000-0000-0000-0000-000000000000\": {\n    \"name\": \"Thing\",\n    \"creationIndex\": 3,\n"));
+    for line in ["\"count\": -7,", "\"gain\": 0.5,", "\"data\": [0, 1, 127, -128, -1],", "\"hook\": null,",
+                 "\"target\": \"0b000000-0000-0000-0000-000000000000/7\",", "\"value\": -2147483648,", "\"y\": -0.0"] {
+        assert!(json.contains(line), "missing {line} in\n{json}");
+    }
+}
+
+#[test]
+fn reads_a_hand_written_fixture() {
+    // Numeric keys where a field has no name, missing fields left out, creation index by position.
+    let json = r#"{
+        "0b000000-0000-0000-0000-000000000000": {"name": "Thing", "fields": {"hook": null}},
+        "0a000000-0000-0000-0000-000000000000": {"name": "Thing", "fields": {"1": 42, "target": "0b000000-0000-0000-0000-000000000000/7"}}
+    }"#;
+    let graph = BoxGraph::from_json(json, &registry(), &names()).unwrap();
+    assert_eq!(graph.find_box(&B).map(|graph_box| graph_box.creation_index), Some(0));
+    assert_eq!(graph.find_box(&A).map(|graph_box| graph_box.creation_index), Some(1));
+    assert_eq!(graph.field_value(&Address::of(A, vec![1])), Some(&FieldValue::Int32(42)));
+    assert_eq!(graph.field_value(&Address::of(A, vec![2])), None);
+    assert!(graph.dangling().is_empty());
+}
+
+#[test]
+fn rejects_what_does_not_fit() {
+    let read = |fields: &str| {
+        let json = format!("{{\"0a000000-0000-0000-0000-000000000000\": {{\"name\": \"Thing\", \"fields\": {fields}}}}}");
+        BoxGraph::from_json(&json, &registry(), &names()).err()
+    };
+    assert_eq!(read("{}"), None);
+    assert_eq!(read("{\"volume\": 1}"), Some(Error::UnknownField));
+    assert_eq!(read("{\"99\": 1}"), Some(Error::UnknownField));
+    assert_eq!(read("{\"count\": 1.5}"), Some(Error::BadJson));
+    assert_eq!(read("{\"count\": 2147483648}"), Some(Error::BadJson));
+    assert_eq!(read("{\"data\": [128]}"), Some(Error::BadJson));
+    assert_eq!(read("{\"target\": \"not an address\"}"), Some(Error::BadJson));
+    assert_eq!(read("{\"points\": []}"), Some(Error::BadJson));
+    assert_eq!(read("{\"count\": 1,}"), Some(Error::BadJson));
+    assert_eq!(read("{\"label\": \"unterminated}"), Some(Error::BadJson));
+    let unknown = "{\"0a000000-0000-0000-0000-000000000000\": {\"name\": \"Other\", \"fields\": {}}}";
+    assert_eq!(BoxGraph::from_json(unknown, &registry(), &names()).err(), Some(Error::UnknownBox));
+    assert_eq!(BoxGraph::from_json("{} trailing", &registry(), &names()).err(), Some(Error::BadJson));
+}
+
+#[test]
+fn decodes_string_escapes() {
+    let json = r#"{"0a000000-0000-0000-0000-000000000000": {"name": "Thing", "fields": {"label": "\u00fc\ud83c\udf9b\/\n"}}}"#;
+    let graph = BoxGraph::from_json(json, &registry(), &names()).unwrap();
+    assert_eq!(graph.field_value(&Address::of(A, vec![4])).and_then(FieldValue::as_str), Some("ü🎛/\n"));
+}
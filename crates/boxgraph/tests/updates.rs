@@ -6,6 +6,7 @@ use boxgraph::field::{write_fields, FieldType, FieldValue, Fields, Schema};
 use boxgraph::graph::BoxGraph;
 use boxgraph::updates;
 use boxgraph::updates::Update;
+use boxgraph::Error;
 
 const A: Uuid = [0xA, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
 const B: Uuid = [0xB, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
@@ -102,3 +103,61 @@ fn abort_restores_original_exactly() {
     graph.abort(&updates, &registry).unwrap();
     assert_eq!(graph.to_bytes(), original, "abort should restore the original bytes exactly");
 }
+
+#[test]
+fn inverse_restores_fields_pointers_and_deleted_boxes() {
+    let registry = registry();
+    let mut graph = BoxGraph::from_boxes(vec![node(0, A, 1, Some(Address::box_of(B))), node(1, B, 2, Some(Address::box_of(A)))]);
+    let original = graph.checksum();
+    let updates = vec![
+        Update::Primitive {address: Address::of(A, vec![0]), old: FieldValue::Int32(1), new: FieldValue::Int32(50)},
+        Update::Pointer {address: Address::of(A, vec![1]), old: Some(Address::box_of(B)), new: None},
+        Update::New {uuid: C, name: "Node".to_string(), settings: node_settings(5, Some(Address::box_of(A)))},
+        Update::Delete {uuid: B, name: "Node".to_string(), settings: node_settings(2, Some(Address::box_of(A)))}
+    ];
+    let inverse = graph.transaction_with_inverse(&updates, &registry).unwrap();
+    assert_eq!(inverse.len(), updates.len());
+    assert!(matches!(inverse[0], Update::New {uuid: B, ..}), "the inverse runs back to front");
+    graph.transaction(&inverse, &registry).unwrap();
+    assert_eq!(graph.checksum(), original);
+    assert!(graph.find_box(&C).is_none());
+    assert_eq!(graph.find_box(&B).unwrap().fields, node(1, B, 2, Some(Address::box_of(A))).fields);
+    assert_eq!(graph.target_of(&Address::of(A, vec![1])), Some(&Address::box_of(B)));
+    assert_eq!(graph.incoming(&Address::box_of(B)), vec![&Address::of(A, vec![1])], "the re-created box is pointed at again");
+    assert!(graph.dangling().is_empty());
+}
+
+#[test]
+fn inverse_of_a_forward_stream_reads_old_values_from_the_graph() {
+    let registry = registry();
+    let mut graph = BoxGraph::from_boxes(vec![node(0, A, 1, None), node(1, B, 2, None)]);
+    let original = graph.checksum();
+    let mut writer = ByteWriter::new();
+    writer.write_int(2);
+    writer.write_string("update-primitive");
+    Address::of(A, vec![0]).write(&mut writer);
+    writer.write_string("int32");
+    writer.write_int(77);
+    writer.write_string("delete");
+    writer.write_raw(&B);
+    let bytes = writer.into_bytes();
+    let forward = updates::decode_forward(&mut ByteReader::new(&bytes)).unwrap();
+    let inverse = graph.transaction_with_inverse(&forward, &registry).unwrap();
+    assert_eq!(inverse[1], Update::Primitive {address: Address::of(A, vec![0]), old: FieldValue::Int32(77), new: FieldValue::Int32(1)});
+    graph.transaction(&inverse, &registry).unwrap();
+    assert_eq!(graph.checksum(), original);
+}
+
+#[test]
+fn a_failing_transaction_with_inverse_rolls_back() {
+    let registry = registry();
+    let mut graph = BoxGraph::from_boxes(vec![node(0, A, 1, None)]);
+    let original = graph.to_bytes();
+    let updates = vec![
+        Update::Primitive {address: Address::of(A, vec![0]), old: FieldValue::Int32(1), new: FieldValue::Int32(50)},
+        Update::New {uuid: C, name: "Node".to_string(), settings: node_settings(5, None)},
+        Update::Primitive {address: Address::of(B, vec![0]), old: FieldValue::Int32(0), new: FieldValue::Int32(1)}
+    ];
+    assert_eq!(graph.transaction_with_inverse(&updates, &registry), Err(Error::AddressNotFound));
+    assert_eq!(graph.to_bytes(), original);
+}
@@ -0,0 +1,172 @@
+use std::collections::BTreeMap;
+use boxgraph::address::{Address, Uuid};
+use boxgraph::boxes::{GraphBox, Registry};
+use boxgraph::field::{FieldType, FieldValue, Schema};
+use boxgraph::graph::BoxGraph;
+use boxgraph::rules::{BoxRules, Index, Pointer, Rules, Target, NO_POINTERS};
+use boxgraph::updates::Update;
+use boxgraph::validate::Violation;
+
+const CHAIN: Uuid = [0xC, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+const D1: Uuid = [0xD, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+const D2: Uuid = [0xD, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+const D3: Uuid = [0xD, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+const LABEL: Uuid = [0xE, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+
+// "Chain": a hook at key 1 collecting devices. "Device": its index at key 0, a mandatory pointer into a
+// chain at key 1, an optional pointer at an exclusively owned "Label" at key 2.
+fn registry() -> Registry {
+    Registry::from([
+        ("Chain".to_string(), Schema::from([(1, FieldType::Hook)])),
+        ("Device".to_string(), Schema::from([(0, FieldType::Int32), (1, FieldType::Pointer), (2, FieldType::Pointer)])),
+        ("Label".to_string(), Schema::from([(0, FieldType::Int32)])),
+        ("Stray".to_string(), Schema::from([(0, FieldType::Pointer)]))
+    ])
+}
+
+fn rules() -> Rules {
+    Rules::from([
+        ("Chain".to_string(), BoxRules {
+            target: NO_POINTERS,
+            pointers: &[],
+            targets: &[(&[1], Target {accepts: &["Device"], mandatory: false, exclusive: false})],
+            index: None
+        }),
+        ("Device".to_string(), BoxRules {
+            target: NO_POINTERS,
+            pointers: &[(&[1], Pointer {pointer_type: "Device", mandatory: true}), (&[2], Pointer {pointer_type: "Label", mandatory: false})],
+            targets: &[],
+            index: Some(Index {field: &[0], collection: &[1]})
+        }),
+        ("Label".to_string(), BoxRules {
+            target: Target {accepts: &["Label"], mandatory: true, exclusive: true},
+            pointers: &[],
+            targets: &[],
+            index: None
+        })
+    ])
+}
+
+fn chain() -> GraphBox {
+    GraphBox {creation_index: 0, name: "Chain".to_string(), uuid: CHAIN, fields: BTreeMap::from([(1, FieldValue::Hook)])}
+}
+
+fn device(uuid: Uuid, index: i32, chain: Option<Address>, label: Option<Address>) -> GraphBox {
+    GraphBox {
+        creation_index: uuid[1] as i32,
+        name: "Device".to_string(),
+        uuid,
+        fields: BTreeMap::from([(0, FieldValue::Int32(index)), (1, FieldValue::Pointer(chain)), (2, FieldValue::Pointer(label))])
+    }
+}
+
+fn label() -> GraphBox {
+    GraphBox {creation_index: 9, name: "Label".to_string(), uuid: LABEL, fields: BTreeMap::from([(0, FieldValue::Int32(0))])}
+}
+
+fn hook() -> Option<Address> {
+    Some(Address::of(CHAIN, vec![1]))
+}
+
+fn index(graph: &BoxGraph, uuid: Uuid) -> Option<i32> {
+    graph.field_value(&Address::of(uuid, vec![0])).and_then(FieldValue::as_int32)
+}
+
+#[test]
+fn a_well_formed_graph_has_no_violations() {
+    let graph = BoxGraph::from_boxes(vec![
+        chain(),
+        device(D1, 0, hook(), Some(Address::box_of(LABEL))),
+        device(D2, 1, hook(), None),
+        label()
+    ]);
+    assert_eq!(graph.validate(&rules()), vec![]);
+}
+
+#[test]
+fn reports_a_pointer_the_target_does_not_accept() {
+    let graph = BoxGraph::from_boxes(vec![chain(), device(D1, 0, Some(Address::box_of(CHAIN)), None)]);
+    assert_eq!(graph.validate(&rules()), vec![Violation::Rejected {
+        source: Address::of(D1, vec![1]),
+        target: Address::box_of(CHAIN),
+        pointer_type: "Device"
+    }]);
+}
+
+#[test]
+fn reports_missing_and_shared_owners() {
+    let graph = BoxGraph::from_boxes(vec![
+        chain(),
+        device(D1, 0, None, Some(Address::box_of(LABEL))),
+        device(D2, 1, hook(), Some(Address::box_of(LABEL))),
+        label()
+    ]);
+    assert_eq!(graph.validate(&rules()), vec![
+        Violation::MissingTarget {source: Address::of(D1, vec![1])},
+        Violation::NotExclusive {target: Address::box_of(LABEL), sources: vec![Address::of(D1, vec![2]), Address::of(D2, vec![2])]}
+    ]);
+    let unowned = BoxGraph::from_boxes(vec![label()]);
+    assert_eq!(unowned.validate(&rules()), vec![Violation::MissingPointer {target: Address::box_of(LABEL)}]);
+}
+
+#[test]
+fn reports_index_collisions_per_collection() {
+    let graph = BoxGraph::from_boxes(vec![chain(), device(D1, 0, hook(), None), device(D2, 0, hook(), None), device(D3, 1, hook(), None)]);
+    assert_eq!(graph.validate(&rules()), vec![Violation::IndexCollision {collection: Address::of(CHAIN, vec![1]), index: 0, members: vec![D1, D2]}]);
+}
+
+#[test]
+fn unknown_boxes_are_reported_and_left_alone() {
+    let stray = GraphBox {creation_index: 0, name: "Stray".to_string(), uuid: D1, fields: BTreeMap::from([(0, FieldValue::Pointer(None))])};
+    let mut graph = BoxGraph::from_boxes(vec![stray]);
+    let expected = vec![Violation::UnknownBox {uuid: D1, name: "Stray".to_string()}];
+    assert_eq!(graph.validate(&rules()), expected);
+    assert_eq!(graph.repair(&registry(), &rules()).unwrap(), vec![]);
+    assert_eq!(graph.validate(&rules()), expected);
+}
+
+#[test]
+fn repair_clears_an_optional_pointer_and_keeps_the_first_owner() {
+    let mut graph = BoxGraph::from_boxes(vec![
+        chain(),
+        device(D1, 0, hook(), Some(Address::box_of(LABEL))),
+        device(D2, 1, hook(), Some(Address::box_of(LABEL))),
+        label()
+    ]);
+    let updates = graph.repair(&registry(), &rules()).unwrap();
+    assert_eq!(updates, vec![Update::Pointer {address: Address::of(D2, vec![2]), old: Some(Address::box_of(LABEL)), new: None}]);
+    assert_eq!(graph.box_count(), 4);
+    assert_eq!(graph.validate(&rules()), vec![]);
+}
+
+#[test]
+fn repair_cascades_deletions_through_mandatory_rules() {
+    // D1 lost its chain; deleting it leaves the label unowned, which goes in the next round.
+    let mut graph = BoxGraph::from_boxes(vec![chain(), device(D1, 0, None, Some(Address::box_of(LABEL))), label()]);
+    let updates = graph.repair(&registry(), &rules()).unwrap();
+    let deleted: Vec<Uuid> = updates.iter().filter_map(|update| match update {
+        Update::Delete {uuid, ..} => Some(*uuid),
+        _ => None
+    }).collect();
+    assert_eq!(deleted, vec![D1, LABEL]);
+    assert_eq!(graph.box_count(), 1);
+    assert_eq!(graph.validate(&rules()), vec![]);
+    assert!(graph.dangling().is_empty());
+}
+
+#[test]
+fn repair_deletes_a_box_whose_mandatory_pointer_is_rejected() {
+    let mut graph = BoxGraph::from_boxes(vec![chain(), device(D1, 0, Some(Address::box_of(CHAIN)), None), device(D2, 0, hook(), None)]);
+    graph.repair(&registry(), &rules()).unwrap();
+    assert!(graph.find_box(&D1).is_none());
+    assert!(graph.find_box(&D2).is_some());
+    assert_eq!(graph.validate(&rules()), vec![]);
+}
+
+#[test]
+fn repair_renumbers_a_colliding_collection_in_order() {
+    let mut graph = BoxGraph::from_boxes(vec![chain(), device(D1, 3, hook(), None), device(D2, 0, hook(), None), device(D3, 0, hook(), None)]);
+    graph.repair(&registry(), &rules()).unwrap();
+    assert_eq!([index(&graph, D2), index(&graph, D3), index(&graph, D1)], [Some(0), Some(1), Some(2)]);
+    assert_eq!(graph.validate(&rules()), vec![]);
+}
@@ -0,0 +1,192 @@
+//! The device boundary as a trait: one method per export the engine's loader looks up (`DeviceReg`), with the
+//! engine's "0 if none" meaning as the default. [`Exports`] implements it over a device crate's export
+//! functions; a test may implement it directly over an `abi` template.
+
+/// A loaded device plugin. `state` is the address of the zeroed block of [`Device::state_size`] bytes the
+/// host allocated for one instance; `descriptor` the address of a canonical `abi` descriptor.
+pub trait Device {
+    /// `DEVICE_KIND_INSTRUMENT`, `DEVICE_KIND_AUDIO_EFFECT` or `DEVICE_KIND_MIDI_EFFECT`.
+    fn kind(&self) -> u32;
+
+    /// The bytes the host allocates, zeroed, per instance at `sample_rate`.
+    fn state_size(&self, sample_rate: f32) -> u32;
+
+    /// Bind the parameters, fields, samples and ports; the host imports are live during the call.
+    fn init(&self, state: usize, sample_rate: f32) {
+        let _ = (state, sample_rate);
+    }
+
+    /// Render one quantum (instruments and audio effects).
+    fn process(&self, descriptor: usize) {
+        let _ = descriptor;
+    }
+
+    /// Answer a pull for `[from, to)` with at most `max` events written to `out` (MIDI effects).
+    fn process_events(&self, from: f64, to: f64, flags: u32, state: usize, out: usize, max: u32) -> u32 {
+        let _ = (from, to, flags, state, out, max);
+        0
+    }
+
+    fn parameter_changed(&self, state: usize, id: u32, kind: u32, value: f32) {
+        let _ = (state, id, kind, value);
+    }
+
+    fn field_changed(&self, state: usize, id: u32, kind: u32, bits: usize, len: u32) {
+        let _ = (state, id, kind, bits, len);
+    }
+
+    fn sample_changed(&self, state: usize, id: u32, handle: u32, present: u32) {
+        let _ = (state, id, handle, present);
+    }
+
+    fn soundfont_changed(&self, state: usize, id: u32, handle: u32, present: u32) {
+        let _ = (state, id, handle, present);
+    }
+
+    /// Clear the sounding state on a transport stop.
+    fn reset(&self, state: usize) {
+        let _ = state;
+    }
+
+    /// The instance is dying; release what it holds outside its state block.
+    fn terminate(&self, state: usize) {
+        let _ = state;
+    }
+
+    /// The look-ahead delay in samples.
+    fn latency(&self, state: usize) -> u32 {
+        let _ = state;
+        0
+    }
+
+    /// The field key of its own MIDI-effect chain when hosted as a composite child (a Playfield slot).
+    fn midi_effects_field(&self) -> u32 {
+        0
+    }
+
+    /// The field key of its own audio-effect chain when hosted as a composite child.
+    fn audio_effects_field(&self) -> u32 {
+        0
+    }
+
+    /// The field key of the scripted parameter collection the host observes for it (Werkstatt and kin).
+    fn param_collection_field(&self) -> u32 {
+        0
+    }
+
+    /// The field key of the scripted sample collection the host observes for it (Apparat).
+    fn sample_collection_field(&self) -> u32 {
+        0
+    }
+}
+
+/// A device crate's exports as function pointers, the native counterpart of the engine's `DeviceReg`. Only
+/// `kind` and `state_size` are mandatory; a missing export behaves as the engine treats a zero table slot.
+#[derive(Clone, Copy)]
+pub struct Exports {
+    pub kind: extern "C" fn() -> u32,
+    pub state_size: extern "C" fn(f32) -> u32,
+    pub init: Option<extern "C" fn(usize, f32)>,
+    pub process: Option<extern "C" fn(usize)>,
+    pub process_events: Option<extern "C" fn(f64, f64, u32, usize, usize, u32) -> u32>,
+    pub parameter_changed: Option<extern "C" fn(usize, u32, u32, f32)>,
+    pub field_changed: Option<extern "C" fn(usize, u32, u32, usize, u32)>,
+    pub sample_changed: Option<extern "C" fn(usize, u32, u32, u32)>,
+    pub soundfont_changed: Option<extern "C" fn(usize, u32, u32, u32)>,
+    pub reset: Option<extern "C" fn(usize)>,
+    pub terminate: Option<extern "C" fn(usize)>,
+    pub latency: Option<extern "C" fn(usize) -> u32>,
+    pub midi_effects_field: Option<extern "C" fn() -> u32>,
+    pub audio_effects_field: Option<extern "C" fn() -> u32>,
+    pub observe_param_collection_field: Option<extern "C" fn() -> u32>,
+    pub observe_sample_collection_field: Option<extern "C" fn() -> u32>
+}
+
+impl Exports {
+    pub fn new(kind: extern "C" fn() -> u32, state_size: extern "C" fn(f32) -> u32) -> Self {
+        Self {
+            kind,
+            state_size,
+            init: None,
+            process: None,
+            process_events: None,
+            parameter_changed: None,
+            field_changed: None,
+            sample_changed: None,
+            soundfont_changed: None,
+            reset: None,
+            terminate: None,
+            latency: None,
+            midi_effects_field: None,
+            audio_effects_field: None,
+            observe_param_collection_field: None,
+            observe_sample_collection_field: None
+        }
+    }
+}
+
+impl Device for Exports {
+    fn kind(&self) -> u32 {
+        (self.kind)()
+    }
+
+    fn state_size(&self, sample_rate: f32) -> u32 {
+        (self.state_size)(sample_rate)
+    }
+
+    fn init(&self, state: usize, sample_rate: f32) {
+        if let Some(init) = self.init { init(state, sample_rate) }
+    }
+
+    fn process(&self, descriptor: usize) {
+        if let Some(process) = self.process { process(descriptor) }
+    }
+
+    fn process_events(&self, from: f64, to: f64, flags: u32, state: usize, out: usize, max: u32) -> u32 {
+        self.process_events.map_or(0, |process_events| process_events(from, to, flags, state, out, max))
+    }
+
+    fn parameter_changed(&self, state: usize, id: u32, kind: u32, value: f32) {
+        if let Some(parameter_changed) = self.parameter_changed { parameter_changed(state, id, kind, value) }
+    }
+
+    fn field_changed(&self, state: usize, id: u32, kind: u32, bits: usize, len: u32) {
+        if let Some(field_changed) = self.field_changed { field_changed(state, id, kind, bits, len) }
+    }
+
+    fn sample_changed(&self, state: usize, id: u32, handle: u32, present: u32) {
+        if let Some(sample_changed) = self.sample_changed { sample_changed(state, id, handle, present) }
+    }
+
+    fn soundfont_changed(&self, state: usize, id: u32, handle: u32, present: u32) {
+        if let Some(soundfont_changed) = self.soundfont_changed { soundfont_changed(state, id, handle, present) }
+    }
+
+    fn reset(&self, state: usize) {
+        if let Some(reset) = self.reset { reset(state) }
+    }
+
+    fn terminate(&self, state: usize) {
+        if let Some(terminate) = self.terminate { terminate(state) }
+    }
+
+    fn latency(&self, state: usize) -> u32 {
+        self.latency.map_or(0, |latency| latency(state))
+    }
+
+    fn midi_effects_field(&self) -> u32 {
+        self.midi_effects_field.map_or(0, |field| field())
+    }
+
+    fn audio_effects_field(&self) -> u32 {
+        self.audio_effects_field.map_or(0, |field| field())
+    }
+
+    fn param_collection_field(&self) -> u32 {
+        self.observe_param_collection_field.map_or(0, |field| field())
+    }
+
+    fn sample_collection_field(&self) -> u32 {
+        self.observe_sample_collection_field.map_or(0, |field| field())
+    }
+}
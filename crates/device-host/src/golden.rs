@@ -0,0 +1,132 @@
+//! Golden renders: a device's output for a fixed stimulus and parameter set, held against a reference WAV
+//! checked in next to the test. The comparison reuses the stretch-lab guards from `audio-metrics` (overall
+//! level and band balance) and adds a null test (the residual after subtracting the reference), so a `dsp`
+//! change that alters how a device sounds fails while float noise across platforms passes.
+//!
+//! Set `GOLDEN_BLESS=1` to record the references from the current output instead of checking them.
+
+use std::fmt;
+use std::path::Path;
+
+use audio_metrics::envelope::{mono, rms};
+use audio_metrics::goertzel::db;
+use audio_metrics::spectral::{band_fractions, level_delta_db, spectral_delta_db};
+use audio_metrics::wav;
+
+/// The rate every golden is rendered and stored at.
+pub const SAMPLE_RATE: f32 = 48_000.0;
+/// The length of a golden: 64 quanta, two analysis frames of the band balance.
+pub const FRAMES: usize = 8192;
+
+const BLESS: &str = "GOLDEN_BLESS";
+
+/// Seeded xorshift noise in [-0.5, 0.5), decorrelated between the channels.
+pub fn noise(frames: usize) -> [Vec<f32>; 2] {
+    let mut seed = 0x9e37_79b9u32;
+    let mut next = move || {
+        seed ^= seed << 13;
+        seed ^= seed >> 17;
+        seed ^= seed << 5;
+        seed as f32 / u32::MAX as f32 - 0.5
+    };
+    let left = (0..frames).map(|_| next()).collect();
+    let right = (0..frames).map(|_| next()).collect();
+    [left, right]
+}
+
+/// Noise for the first half, silence for the second: the response and the tail (release, decay, echo).
+pub fn burst(frames: usize) -> [Vec<f32>; 2] {
+    let mut signal = noise(frames);
+    for channel in &mut signal {
+        channel[frames / 2..].fill(0.0);
+    }
+    signal
+}
+
+/// An exponentially decaying sine, a stand-in for a recorded one-shot.
+pub fn decaying_sine(frames: usize, frequency: f32, sample_rate: f32) -> Vec<f32> {
+    let decay = (-5.0 / frames as f32).exp();
+    let mut gain = 0.8;
+    (0..frames).map(|frame| {
+        let sample = gain * (std::f32::consts::TAU * frequency * frame as f32 / sample_rate).sin();
+        gain *= decay;
+        sample
+    }).collect()
+}
+
+/// How far a render may drift from its reference.
+#[derive(Clone, Copy, Debug)]
+pub struct Tolerance {
+    /// Overall level difference, dB.
+    pub level_db: f64,
+    /// Mean per-band energy-share difference, dB.
+    pub spectral_db: f64,
+    /// The residual's level relative to the reference, dB.
+    pub residual_db: f64
+}
+
+impl Default for Tolerance {
+    fn default() -> Self {
+        Self {level_db: 0.05, spectral_db: 0.1, residual_db: -60.0}
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Comparison {
+    pub level_delta_db: f64,
+    pub spectral_delta_db: f64,
+    pub residual_db: f64
+}
+
+impl Comparison {
+    pub fn within(&self, tolerance: &Tolerance) -> bool {
+        self.level_delta_db <= tolerance.level_db
+            && self.spectral_delta_db <= tolerance.spectral_db
+            && self.residual_db <= tolerance.residual_db
+    }
+}
+
+impl fmt::Display for Comparison {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "level {:.3} dB, spectral {:.3} dB, residual {:.1} dB",
+            self.level_delta_db, self.spectral_delta_db, self.residual_db)
+    }
+}
+
+/// Measure `rendered` against `reference`. Level and band balance are taken on the mono sum; the residual
+/// covers both channels, so a stereo-image change shows there.
+pub fn compare(reference: &[Vec<f32>; 2], rendered: &[Vec<f32>; 2], sample_rate: f32) -> Comparison {
+    let reference_mono = mono(&reference[0], &reference[1]);
+    let rendered_mono = mono(&rendered[0], &rendered[1]);
+    let reference_bands = band_fractions(&reference_mono, sample_rate as f64);
+    let rendered_bands = band_fractions(&rendered_mono, sample_rate as f64);
+    let residual: Vec<f32> = reference.iter().zip(rendered)
+        .flat_map(|(reference, rendered)| reference.iter().zip(rendered).map(|(a, b)| b - a))
+        .collect();
+    let reference_level = rms(&[reference[0].as_slice(), reference[1].as_slice()].concat());
+    Comparison {
+        level_delta_db: level_delta_db(rms(&reference_mono), rms(&rendered_mono)),
+        spectral_delta_db: spectral_delta_db(&reference_bands, &rendered_bands),
+        residual_db: db(rms(&residual) / reference_level.max(1.0e-9))
+    }
+}
+
+/// Check `rendered` against the reference WAV at `path`, or record it there when blessing.
+pub fn check(path: &Path, rendered: &[Vec<f32>; 2], tolerance: &Tolerance) -> Result<Comparison, String> {
+    if std::env::var_os(BLESS).is_some() {
+        wav::write_32f(path, SAMPLE_RATE, &rendered[0], &rendered[1])?;
+        return Ok(compare(rendered, rendered, SAMPLE_RATE));
+    }
+    let data = wav::read(path).map_err(|error| format!("{error} (run with {BLESS}=1 to record it)"))?;
+    if data.sample_rate != SAMPLE_RATE || data.num_frames() != rendered[0].len() {
+        return Err(format!("{}: {} frames at {} Hz, rendered {} at {SAMPLE_RATE} Hz",
+            path.display(), data.num_frames(), data.sample_rate, rendered[0].len()));
+    }
+    let (left, right) = data.stereo();
+    let comparison = compare(&[left, right], rendered, SAMPLE_RATE);
+    if comparison.within(tolerance) {
+        Ok(comparison)
+    } else {
+        Err(format!("{}: {comparison}, outside {tolerance:?}", path.display()))
+    }
+}
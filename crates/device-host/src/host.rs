@@ -0,0 +1,252 @@
+//! The engine's side of the `abi` imports for one instance: what `init` bound, what the test scheduled and
+//! connected, and the current quantum's blocks. Each method mirrors its engine export (`host_*` in the
+//! engine crate), including the id spaces and the update-clock gate.
+
+use abi::native::Host;
+use abi::{AudioInputRef, Block, BlockFlags, EventRecord, ParamChange, SampleRef, SoundfontRef};
+use abi::{EVENT_CHOKE, EVENT_NOTE_EXPRESSION, EVENT_NOTE_OFF, EVENT_NOTE_ON, PARAM_KIND_UNIT};
+use dsp::ppqn::{first_update_position, pulses_to_samples, UPDATE_CLOCK_RATE};
+
+use crate::QUANTUM;
+
+pub(crate) struct Parameter {
+    pub(crate) path: Vec<u16>,
+    /// The uniform value over pulses, when automated.
+    pub(crate) automation: Option<Box<dyn Fn(f64) -> f32>>,
+    last: f32
+}
+
+pub(crate) struct Sample {
+    pub(crate) frames: Vec<f32>,
+    pub(crate) frame_count: u32,
+    pub(crate) channel_count: u32,
+    pub(crate) sample_rate: f32
+}
+
+/// A connected input port: the whole signal, played from frame 0.
+pub(crate) struct Input {
+    pub(crate) port: u32,
+    pub(crate) signal: [Vec<f32>; 2],
+    quantum: [Vec<f32>; 2]
+}
+
+pub(crate) struct NativeHost {
+    pub(crate) sample_rate: f32,
+    pub(crate) base_frequency: f32,
+    pub(crate) parameters: Vec<Parameter>,
+    pub(crate) fields: Vec<Vec<u16>>,
+    pub(crate) sample_paths: Vec<Vec<u16>>,
+    pub(crate) soundfont_paths: Vec<Vec<u16>>,
+    pub(crate) sidechains: Vec<Vec<u16>>,
+    pub(crate) broadcasts: Vec<(Vec<u16>, Vec<f32>)>,
+    pub(crate) samples: Vec<Sample>,
+    pub(crate) soundfonts: Vec<Vec<u8>>,
+    pub(crate) inputs: Vec<Input>,
+    /// Scheduled input events, in lifecycle order.
+    events: Vec<EventRecord>,
+    /// The quantum being processed.
+    pub(crate) blocks: Vec<Block>
+}
+
+// At one position: releases, then channel events, then note-ons, then expressions (the engine's
+// `compare_lifecycle`, which the instrument template relies on).
+fn lifecycle_rank(kind: u32) -> u8 {
+    match kind {
+        EVENT_NOTE_OFF | EVENT_CHOKE => 0,
+        EVENT_NOTE_ON => 2,
+        EVENT_NOTE_EXPRESSION => 3,
+        _ => 1
+    }
+}
+
+impl NativeHost {
+    pub(crate) fn new(sample_rate: f32) -> Self {
+        Self {
+            sample_rate,
+            base_frequency: 440.0,
+            parameters: Vec::new(),
+            fields: Vec::new(),
+            sample_paths: Vec::new(),
+            soundfont_paths: Vec::new(),
+            sidechains: Vec::new(),
+            broadcasts: Vec::new(),
+            samples: Vec::new(),
+            soundfonts: Vec::new(),
+            inputs: Vec::new(),
+            events: Vec::new(),
+            blocks: Vec::new()
+        }
+    }
+
+    pub(crate) fn schedule(&mut self, event: EventRecord) {
+        let key = |event: &EventRecord| (event.position, lifecycle_rank(event.kind));
+        let index = self.events.partition_point(|scheduled| key(scheduled) <= key(&event));
+        self.events.insert(index, event);
+    }
+
+    pub(crate) fn connect(&mut self, port: u32, signal: [Vec<f32>; 2]) {
+        self.inputs.retain(|input| input.port != port);
+        self.inputs.push(Input {port, signal, quantum: [vec![0.0; QUANTUM], vec![0.0; QUANTUM]]});
+    }
+
+    /// Copy each input's slice for the quantum starting at `frame`, silence past its end.
+    pub(crate) fn load_inputs(&mut self, frame: usize) {
+        for input in &mut self.inputs {
+            for (quantum, signal) in input.quantum.iter_mut().zip(&input.signal) {
+                for (index, sample) in quantum.iter_mut().enumerate() {
+                    *sample = signal.get(frame + index).copied().unwrap_or(0.0);
+                }
+            }
+        }
+    }
+
+    pub(crate) fn set_parameter_value(&mut self, id: usize, automation: Option<Box<dyn Fn(f64) -> f32>>) {
+        let parameter = &mut self.parameters[id];
+        parameter.automation = automation;
+        parameter.last = f32::NAN;
+    }
+
+    /// The sample offset of `pulse` in the block containing it (else the last block), clamped to that block.
+    fn offset_of(&self, pulse: f64) -> u32 {
+        let Some(block) = self.blocks.iter().find(|block| pulse >= block.p0 && pulse < block.p1).or(self.blocks.last()) else {
+            return 0;
+        };
+        let pulses = pulse - block.p0;
+        let offset = if pulses.abs() < 1.0e-7 {
+            block.s0
+        } else {
+            block.s0 + pulses_to_samples(pulses, block.bpm, self.sample_rate) as u32
+        };
+        offset.clamp(block.s0, block.s1)
+    }
+
+    fn automated(&self) -> bool {
+        self.parameters.iter().any(|parameter| parameter.automation.is_some())
+    }
+
+    fn transporting(&self) -> bool {
+        self.blocks.first().is_some_and(|block| block.flags.transporting())
+    }
+}
+
+impl Host for NativeHost {
+    fn pull_events(&mut self, from: f64, to: f64, flags: u32, out: &mut [EventRecord]) -> usize {
+        if !BlockFlags(flags).transporting() {
+            return 0; // a stopped transport plays no notes
+        }
+        let start = self.events.partition_point(|event| event.position < from);
+        let mut count = 0;
+        for event in self.events[start..].iter().take_while(|event| event.position < to).take(out.len()) {
+            out[count] = EventRecord {offset: self.offset_of(event.position), ..*event};
+            count += 1;
+        }
+        count
+    }
+
+    fn pulse_to_offset(&mut self, pulse: f64) -> u32 {
+        self.offset_of(pulse)
+    }
+
+    fn bind_parameter(&mut self, path: &[u16]) -> u32 {
+        self.parameters.push(Parameter {path: path.to_vec(), automation: None, last: f32::NAN});
+        self.parameters.len() as u32 - 1
+    }
+
+    fn update_parameters(&mut self, position: f64, out: &mut [ParamChange]) -> usize {
+        let mut count = 0;
+        for (id, parameter) in self.parameters.iter_mut().enumerate() {
+            let Some(automation) = &parameter.automation else { continue };
+            let value = automation(position);
+            if value != parameter.last && count < out.len() {
+                parameter.last = value;
+                out[count] = ParamChange {id: id as u32, kind: PARAM_KIND_UNIT, value};
+                count += 1;
+            }
+        }
+        count
+    }
+
+    fn first_update_position(&mut self, at: f64) -> f64 {
+        if !self.automated() || !self.transporting() {
+            return f64::INFINITY;
+        }
+        first_update_position(at)
+    }
+
+    fn next_update_position(&mut self, after: f64) -> f64 {
+        if !self.automated() || !self.transporting() {
+            return f64::INFINITY;
+        }
+        let mut position = ((after / UPDATE_CLOCK_RATE) as i64 + 1) as f64 * UPDATE_CLOCK_RATE;
+        if position <= after {
+            position += UPDATE_CLOCK_RATE;
+        }
+        position
+    }
+
+    fn observe_sample(&mut self, path: &[u16]) -> u32 {
+        self.sample_paths.push(path.to_vec());
+        self.sample_paths.len() as u32 - 1
+    }
+
+    fn resolve_sample(&mut self, handle: u32) -> Option<SampleRef> {
+        self.samples.get(handle as usize).map(|sample| SampleRef {
+            frames_ptr: sample.frames.as_ptr() as usize,
+            frame_count: sample.frame_count,
+            channel_count: sample.channel_count,
+            sample_rate: sample.sample_rate
+        })
+    }
+
+    fn observe_soundfont(&mut self, path: &[u16]) -> u32 {
+        self.soundfont_paths.push(path.to_vec());
+        self.soundfont_paths.len() as u32 - 1
+    }
+
+    fn resolve_soundfont(&mut self, handle: u32) -> Option<SoundfontRef> {
+        self.soundfonts.get(handle as usize).map(|blob| SoundfontRef {ptr: blob.as_ptr() as usize, len: blob.len() as u32})
+    }
+
+    fn observe_field(&mut self, path: &[u16]) -> u32 {
+        self.fields.push(path.to_vec());
+        self.fields.len() as u32 - 1
+    }
+
+    fn observe_target_string(&mut self, path: &[u16], field_key: u16) -> u32 {
+        // The target box is the test's to supply: `set_field` on `path` delivers the string directly.
+        let _ = field_key;
+        self.observe_field(path)
+    }
+
+    fn bind_sidechain(&mut self, path: &[u16]) -> u32 {
+        self.sidechains.push(path.to_vec());
+        self.sidechains.len() as u32 + 1
+    }
+
+    fn resolve_input(&mut self, id: u32) -> Option<AudioInputRef> {
+        self.inputs.iter().find(|input| input.port == id).map(|input| AudioInputRef {
+            left: input.quantum[0].as_ptr() as usize,
+            right: input.quantum[1].as_ptr() as usize,
+            frames: QUANTUM as u32
+        })
+    }
+
+    fn bind_broadcast(&mut self, path: &[u16], len: u32, package_type: u32) -> u32 {
+        let _ = package_type; // every package is 4-byte values; `broadcast` reads them as floats
+        self.broadcasts.push((path.to_vec(), vec![0.0; len as usize]));
+        self.broadcasts.len() as u32 - 1
+    }
+
+    fn broadcast_ptr(&mut self, id: u32) -> usize {
+        self.broadcasts.get_mut(id as usize).map_or(0, |(_, values)| values.as_mut_ptr() as usize)
+    }
+
+    fn broadcast_active(&mut self, id: u32) -> bool {
+        // Play the subscribed UI, so `Instance::broadcast` sees what a device only computes for a reader.
+        (id as usize) < self.broadcasts.len()
+    }
+
+    fn base_frequency(&mut self) -> f32 {
+        self.base_frequency
+    }
+}
@@ -0,0 +1,287 @@
+//! One hosted device: its state block, the descriptor the engine would assemble for it, and the transport.
+
+use abi::native::{with_host, Host};
+use abi::{Block, BlockFlags, EventRecord, FieldValue, ParamValue, DEVICE_KIND_AUDIO_EFFECT, EVENT_NOTE_OFF, EVENT_NOTE_ON, MAIN_INPUT};
+use dsp::ppqn::samples_to_pulses;
+
+use crate::device::Device;
+use crate::host::{NativeHost, Sample};
+use crate::QUANTUM;
+
+/// The event scratch a device pulls into per quantum, and the output capacity of a MIDI-effect pull.
+const MAX_EVENTS: usize = 256;
+
+const BLANK: EventRecord = EventRecord {position: 0.0, offset: 0, kind: 0, id: 0, pitch: 0, velocity: 0.0, cent: 0.0, duration: 0.0};
+
+/// A device instance driven by hand. The transport starts at pulse 0, playing at 120 bpm; every call into the
+/// device runs with this instance's host installed, so the device sees exactly the bindings it made in `init`.
+pub struct Instance<'a> {
+    device: &'a dyn Device,
+    sample_rate: f32,
+    // u64-backed for 8-aligned device state, as the engine allocates it.
+    state: Vec<u64>,
+    host: NativeHost,
+    scratch: Vec<EventRecord>,
+    output: [Vec<f32>; 2],
+    silence: Vec<f32>,
+    position: f64,
+    frame: usize,
+    bpm: f32,
+    transporting: bool,
+    discontinuous: bool,
+    bpm_changed: bool,
+    next_note_id: u32
+}
+
+impl<'a> Instance<'a> {
+    /// Allocate the zeroed state and run `init`, which binds the device's parameters, fields and ports.
+    pub fn new(device: &'a dyn Device, sample_rate: f32) -> Self {
+        let state_size = device.state_size(sample_rate) as usize;
+        let mut instance = Self {
+            device,
+            sample_rate,
+            state: vec![0u64; state_size.div_ceil(8).max(1)],
+            host: NativeHost::new(sample_rate),
+            scratch: vec![BLANK; MAX_EVENTS],
+            output: [vec![0.0; QUANTUM], vec![0.0; QUANTUM]],
+            silence: vec![0.0; QUANTUM],
+            position: 0.0,
+            frame: 0,
+            bpm: 120.0,
+            transporting: true,
+            discontinuous: true,
+            bpm_changed: false,
+            next_note_id: 0
+        };
+        if instance.kind() == DEVICE_KIND_AUDIO_EFFECT {
+            // the engine always wires the through-signal; unconnected, it is silence
+            instance.host.connect(MAIN_INPUT, [Vec::new(), Vec::new()]);
+        }
+        let state = instance.state_ptr();
+        with_host(&mut instance.host, || device.init(state, sample_rate));
+        instance
+    }
+
+    pub fn kind(&self) -> u32 {
+        self.device.kind()
+    }
+
+    /// The transport position in pulses: where the next quantum starts.
+    pub fn position(&self) -> f64 {
+        self.position
+    }
+
+    pub fn set_bpm(&mut self, bpm: f32) {
+        self.bpm_changed |= bpm != self.bpm;
+        self.bpm = bpm;
+    }
+
+    pub fn set_base_frequency(&mut self, hz: f32) {
+        self.host.base_frequency = hz;
+    }
+
+    /// Start or stop the transport. Stopping resets the device, as the engine does; starting again is a
+    /// discontinuity. A stopped quantum is still processed, with no events and no automation.
+    pub fn set_transporting(&mut self, transporting: bool) {
+        if self.transporting == transporting {
+            return;
+        }
+        self.transporting = transporting;
+        if transporting {
+            self.discontinuous = true;
+        } else {
+            self.reset();
+        }
+    }
+
+    /// The field-key paths of the bound parameters, by id.
+    pub fn parameter_paths(&self) -> impl Iterator<Item = &[u16]> {
+        self.host.parameters.iter().map(|parameter| parameter.path.as_slice())
+    }
+
+    /// Deliver a parameter value, dropping any automation on it. `false` when the device bound no such path.
+    pub fn set_parameter(&mut self, path: &[u16], value: ParamValue) -> bool {
+        let Some(id) = self.host.parameters.iter().position(|parameter| parameter.path == path) else {
+            return false;
+        };
+        self.host.set_parameter_value(id, None);
+        let (kind, value) = value.to_wire();
+        let (device, state) = (self.device, self.state_ptr());
+        with_host(&mut self.host, || device.parameter_changed(state, id as u32, kind, value));
+        true
+    }
+
+    /// Automate a parameter by a unit-value curve over pulses. The device reads it on the update grid while
+    /// the transport runs, like engine automation.
+    pub fn automate(&mut self, path: &[u16], curve: impl Fn(f64) -> f32 + 'static) -> bool {
+        let Some(id) = self.host.parameters.iter().position(|parameter| parameter.path == path) else {
+            return false;
+        };
+        self.host.set_parameter_value(id, Some(Box::new(curve)));
+        true
+    }
+
+    /// Deliver a field value to the device's observer on `path`.
+    pub fn set_field(&mut self, path: &[u16], value: FieldValue) -> bool {
+        let Some(id) = self.host.fields.iter().position(|field| field == path) else {
+            return false;
+        };
+        let (kind, bits, len) = value.to_wire();
+        let (device, state) = (self.device, self.state_ptr());
+        with_host(&mut self.host, || device.field_changed(state, id as u32, kind, bits, len));
+        true
+    }
+
+    /// Make `planes` (one per channel, equally long) the resident sample behind the pointer field on `path`.
+    pub fn set_sample(&mut self, path: &[u16], planes: &[Vec<f32>], sample_rate: f32) -> bool {
+        let Some(id) = self.host.sample_paths.iter().position(|sample| sample == path) else {
+            return false;
+        };
+        let frame_count = planes.first().map_or(0, Vec::len) as u32;
+        self.host.samples.push(Sample {frames: planes.concat(), frame_count, channel_count: planes.len() as u32, sample_rate});
+        let handle = self.host.samples.len() as u32 - 1;
+        let (device, state) = (self.device, self.state_ptr());
+        with_host(&mut self.host, || device.sample_changed(state, id as u32, handle, 1));
+        true
+    }
+
+    /// Make `blob` (the engine's parsed-soundfont layout) the resident soundfont behind `path`.
+    pub fn set_soundfont(&mut self, path: &[u16], blob: Vec<u8>) -> bool {
+        let Some(id) = self.host.soundfont_paths.iter().position(|soundfont| soundfont == path) else {
+            return false;
+        };
+        self.host.soundfonts.push(blob);
+        let handle = self.host.soundfonts.len() as u32 - 1;
+        let (device, state) = (self.device, self.state_ptr());
+        with_host(&mut self.host, || device.soundfont_changed(state, id as u32, handle, 1));
+        true
+    }
+
+    /// The main input signal, played from the first rendered frame.
+    pub fn set_input(&mut self, signal: [Vec<f32>; 2]) {
+        self.host.connect(MAIN_INPUT, signal);
+    }
+
+    /// Feed the sidechain bound on `path`.
+    pub fn connect_sidechain(&mut self, path: &[u16], signal: [Vec<f32>; 2]) -> bool {
+        let Some(index) = self.host.sidechains.iter().position(|sidechain| sidechain == path) else {
+            return false;
+        };
+        self.host.connect(index as u32 + 2, signal);
+        true
+    }
+
+    /// Schedule an input event; its `offset` is resolved against the quantum it lands in.
+    pub fn schedule(&mut self, event: EventRecord) {
+        self.host.schedule(event);
+    }
+
+    /// Schedule a note-on at `position` and its note-off `duration` pulses later.
+    pub fn note(&mut self, position: f64, duration: f64, pitch: u32, velocity: f32) {
+        let id = self.next_note_id;
+        self.next_note_id += 1;
+        let on = EventRecord {position, kind: EVENT_NOTE_ON, id, pitch, velocity, duration, ..BLANK};
+        self.schedule(on);
+        self.schedule(EventRecord {position: position + duration, kind: EVENT_NOTE_OFF, velocity: 0.0, duration: 0.0, ..on});
+    }
+
+    /// Render `frames` of output in whole quanta, advancing the transport.
+    pub fn render(&mut self, frames: usize) -> [Vec<f32>; 2] {
+        let mut rendered = [Vec::with_capacity(frames.next_multiple_of(QUANTUM)), Vec::with_capacity(frames.next_multiple_of(QUANTUM))];
+        while rendered[0].len() < frames {
+            self.process();
+            for (channel, output) in rendered.iter_mut().zip(&self.output) {
+                channel.extend_from_slice(output);
+            }
+        }
+        for channel in &mut rendered {
+            channel.truncate(frames);
+        }
+        rendered
+    }
+
+    /// Pull a MIDI effect's output for `[from, to)`, as a downstream consumer would.
+    pub fn process_events(&mut self, from: f64, to: f64) -> Vec<EventRecord> {
+        let frames = dsp::ppqn::pulses_to_samples(to - from, self.bpm, self.sample_rate) as u32;
+        let flags = self.flags();
+        self.host.blocks = vec![Block {index: 0, flags, p0: from, p1: to, s0: 0, s1: frames, bpm: self.bpm}];
+        let mut out = vec![BLANK; MAX_EVENTS];
+        let (device, state, out_ptr) = (self.device, self.state_ptr(), out.as_mut_ptr() as usize);
+        let count = with_host(&mut self.host, || device.process_events(from, to, flags.0, state, out_ptr, MAX_EVENTS as u32));
+        out.truncate(count as usize);
+        self.clear_event_flags();
+        out
+    }
+
+    pub fn reset(&mut self) {
+        let (device, state) = (self.device, self.state_ptr());
+        with_host(&mut self.host, || device.reset(state));
+    }
+
+    pub fn latency(&mut self) -> u32 {
+        let (device, state) = (self.device, self.state_ptr());
+        with_host(&mut self.host, || device.latency(state))
+    }
+
+    /// The values the device last published to the broadcast bound on `path`.
+    pub fn broadcast(&self, path: &[u16]) -> Option<&[f32]> {
+        self.host.broadcasts.iter().find(|(bound, _)| bound == path).map(|(_, values)| values.as_slice())
+    }
+
+    fn state_ptr(&self) -> usize {
+        self.state.as_ptr() as usize
+    }
+
+    fn flags(&self) -> BlockFlags {
+        if self.transporting {
+            BlockFlags::create(true, self.discontinuous, true, self.bpm_changed)
+        } else {
+            BlockFlags::create(false, false, false, false)
+        }
+    }
+
+    fn clear_event_flags(&mut self) {
+        self.discontinuous = false;
+        self.bpm_changed = false;
+    }
+
+    fn process(&mut self) {
+        let p1 = self.position + samples_to_pulses(QUANTUM as f64, self.bpm, self.sample_rate);
+        let flags = self.flags();
+        self.host.blocks = vec![Block {index: 0, flags, p0: self.position, p1, s0: 0, s1: QUANTUM as u32, bpm: self.bpm}];
+        self.host.load_inputs(self.frame);
+        for channel in &mut self.output {
+            channel.fill(0.0);
+        }
+        let in_offsets: Vec<usize> = if self.kind() == DEVICE_KIND_AUDIO_EFFECT {
+            let main = self.host.resolve_input(MAIN_INPUT);
+            main.map_or([self.silence.as_ptr() as usize; 2], |input| [input.left, input.right]).to_vec()
+        } else {
+            Vec::new()
+        };
+        let out_offsets = [self.output[0].as_mut_ptr() as usize, self.output[1].as_mut_ptr() as usize];
+        let descriptor: [usize; 15] = [
+            QUANTUM,
+            in_offsets.len(), in_offsets.as_ptr() as usize,
+            2, out_offsets.as_ptr() as usize,
+            0, 0,
+            self.state_ptr(),
+            MAX_EVENTS, self.scratch.as_mut_ptr() as usize,
+            0, 0,
+            1, self.host.blocks.as_ptr() as usize,
+            self.sample_rate.to_bits() as usize
+        ];
+        let device = self.device;
+        with_host(&mut self.host, || device.process(descriptor.as_ptr() as usize));
+        self.position = p1;
+        self.frame += QUANTUM;
+        self.clear_event_flags();
+    }
+}
+
+impl Drop for Instance<'_> {
+    fn drop(&mut self) {
+        let (device, state) = (self.device, self.state_ptr());
+        with_host(&mut self.host, || device.terminate(state));
+    }
+}
@@ -0,0 +1,22 @@
+//! device-host: runs the stock device plugins natively, outside the engine and the browser. The engine
+//! loads each device as a PIC side module and calls its exports through the function table; here the same
+//! exports are plain Rust functions, registered by box type in a [`Registry`], and an [`Instance`] plays the
+//! engine's part for one of them: it allocates the state block, assembles the descriptor, drives the
+//! transport quantum by quantum and serves the `abi` host imports (parameter binding and automation, the event
+//! pull, inputs, samples, soundfonts, fields, broadcasts) through `abi::native`.
+//!
+//! Scope: one device per instance. Composite hosting (Playfield), script-bridged devices (Werkstatt, Apparat,
+//! Spielwerk) and the NAM bridge run with their JavaScript side absent, exactly as their native stubs define.
+
+mod device;
+pub mod golden;
+mod host;
+mod instance;
+mod registry;
+
+pub use device::{Device, Exports};
+pub use instance::Instance;
+pub use registry::Registry;
+
+/// Frames per `process` call, the engine's render quantum.
+pub const QUANTUM: usize = dsp::RENDER_QUANTUM;
@@ -0,0 +1,95 @@
+//! Devices by the box type they realize, the native twin of the studio's device table
+//! (`packages/studio/core-wasm/src/engine-modules.ts`, `DEVICES`).
+
+use std::collections::BTreeMap;
+
+use crate::device::{Device, Exports};
+
+/// The export table of a device crate: `kind` and `state_size`, plus the optional exports it defines.
+macro_rules! exports {
+    ($device:ident $(, $export:ident)*) => {{
+        #[allow(unused_mut)]
+        let mut exports = Exports::new($device::kind, $device::state_size);
+        $(exports.$export = Some($device::$export);)*
+        exports
+    }};
+}
+
+#[derive(Default)]
+pub struct Registry {
+    devices: BTreeMap<String, Box<dyn Device>>
+}
+
+impl Registry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Every stock device, keyed like the studio's table.
+    pub fn stock() -> Self {
+        let mut registry = Self::new();
+        registry.register("VaporisateurDeviceBox", exports!(device_vaporisateur, init, process, parameter_changed, reset));
+        registry.register("FMSynthDeviceBox", exports!(device_fm_synth, init, process, parameter_changed, reset));
+        registry.register("WavetableDeviceBox", exports!(device_wavetable, init, process, parameter_changed, sample_changed, reset));
+        registry.register("NanoDeviceBox", exports!(device_nano, init, process, parameter_changed, field_changed, sample_changed, reset));
+        registry.register("RevampDeviceBox", exports!(device_revamp, init, process, parameter_changed, reset));
+        registry.register("TidalDeviceBox", exports!(device_tidal, init, process, parameter_changed));
+        registry.register("DelayDeviceBox", exports!(device_delay, init, process, parameter_changed, reset));
+        registry.register("GateDeviceBox", exports!(device_gate, init, process, parameter_changed, reset));
+        registry.register("FilterDeviceBox", exports!(device_filter, init, process, parameter_changed, reset));
+        registry.register("ChorusDeviceBox", exports!(device_chorus, init, process, parameter_changed, reset));
+        registry.register("ArpeggioDeviceBox", exports!(device_arpeggio, init, process_events, parameter_changed, field_changed));
+        registry.register("ZeitgeistDeviceBox", exports!(device_zeitgeist, init, process_events, field_changed));
+        registry.register("ScaleDeviceBox", exports!(device_scale, init, process_events, parameter_changed, reset));
+        registry.register("RatchetDeviceBox", exports!(device_ratchet, init, process_events, parameter_changed, field_changed, reset));
+        registry.register("PitchDeviceBox", exports!(device_pitch, init, process_events, parameter_changed, reset));
+        registry.register("WerkstattDeviceBox", exports!(device_werkstatt, init, process, parameter_changed, terminate, observe_param_collection_field));
+        registry.register("ApparatDeviceBox",
+            exports!(device_apparat, init, process, parameter_changed, sample_changed, reset, terminate,
+                observe_param_collection_field, observe_sample_collection_field));
+        registry.register("SpielwerkDeviceBox", exports!(device_spielwerk, init, process_events, parameter_changed, terminate, observe_param_collection_field));
+        registry.register("WaveshaperDeviceBox", exports!(device_waveshaper, init, process, parameter_changed, field_changed, reset));
+        registry.register("CrusherDeviceBox", exports!(device_crusher, init, process, parameter_changed, reset));
+        registry.register("FoldDeviceBox", exports!(device_fold, init, process, parameter_changed, field_changed, reset));
+        registry.register("StereoToolDeviceBox", exports!(device_stereo_tool, init, process, parameter_changed, field_changed, reset));
+        registry.register("VelocityDeviceBox", exports!(device_velocity, init, process_events, parameter_changed));
+        registry.register("MaximizerDeviceBox",
+            exports!(device_maximizer, init, process, parameter_changed, field_changed, reset, latency));
+        registry.register("CompressorDeviceBox", exports!(device_compressor, init, process, parameter_changed, reset, latency));
+        registry.register("MultibandCompressorDeviceBox",
+            exports!(device_multiband_compressor, init, process, parameter_changed, reset));
+        registry.register("ReverbDeviceBox", exports!(device_reverb, init, process, parameter_changed, reset));
+        registry.register("DattorroReverbDeviceBox", exports!(device_dattorro_reverb, init, process, parameter_changed, reset));
+        registry.register("ConvolutionReverbDeviceBox",
+            exports!(device_convolution_reverb, init, process, parameter_changed, sample_changed, reset));
+        registry.register("SoundfontDeviceBox", exports!(device_soundfont, init, process, field_changed, soundfont_changed, reset));
+        registry.register("VocoderDeviceBox", exports!(device_vocoder, init, process, parameter_changed, field_changed, reset));
+        registry.register("NeuralAmpDeviceBox",
+            exports!(device_neural_amp, init, process, parameter_changed, field_changed, reset, terminate));
+        registry.register("AutotuneDeviceBox", exports!(device_autotune, init, process, parameter_changed, reset));
+        registry.register("PlayfieldSampleBox",
+            exports!(device_playfield_sample, init, process, parameter_changed, field_changed, sample_changed, reset,
+                midi_effects_field, audio_effects_field));
+        registry
+    }
+
+    /// Register (or replace) the device realizing `box_type`.
+    pub fn register(&mut self, box_type: &str, device: impl Device + 'static) {
+        self.devices.insert(box_type.into(), Box::new(device));
+    }
+
+    pub fn get(&self, box_type: &str) -> Option<&dyn Device> {
+        self.devices.get(box_type).map(|device| device.as_ref())
+    }
+
+    /// The registered box types, sorted.
+    pub fn box_types(&self) -> impl Iterator<Item = &str> {
+        self.devices.keys().map(String::as_str)
+    }
+
+    /// Hand the devices over by box type, sorted, for a host that keeps its own table (the engine's offline
+    /// renderer).
+    pub fn into_devices(self) -> impl Iterator<Item = (String, Box<dyn Device>)> {
+        self.devices.into_iter()
+    }
+}
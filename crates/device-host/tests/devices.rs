@@ -0,0 +1,185 @@
+//! The stock devices hosted natively: every registered box type instantiates, binds and renders finite audio;
+//! an effect processes its main input, an instrument sounds on a scheduled note, a MIDI effect answers a pull,
+//! and automation reaches a device on the update grid, sample-accurately.
+
+use abi::{AudioEffect, Block, ParamValue, Ports, DEVICE_KIND_AUDIO_EFFECT, DEVICE_KIND_INSTRUMENT, DEVICE_KIND_MIDI_EFFECT, EVENT_NOTE_ON};
+use device_host::{Device, Instance, Registry, QUANTUM};
+
+const SR: f32 = 48_000.0;
+const QUARTER: f64 = 960.0;
+const CUTOFF_FIELD: [u16; 1] = [14]; // VaporisateurDeviceBox.cutoff; at mid-range the patch is all but filtered away
+
+// Deterministic white noise in [-0.5, 0.5).
+fn noise(frames: usize) -> [Vec<f32>; 2] {
+    let mut seed = 0x2545_f491u32;
+    let mut next = move || {
+        seed ^= seed << 13;
+        seed ^= seed >> 17;
+        seed ^= seed << 5;
+        seed as f32 / u32::MAX as f32 - 0.5
+    };
+    let left: Vec<f32> = (0..frames).map(|_| next()).collect();
+    let right: Vec<f32> = (0..frames).map(|_| next()).collect();
+    [left, right]
+}
+
+fn peak(frames: &[f32]) -> f32 {
+    frames.iter().fold(0.0f32, |peak, sample| peak.max(sample.abs()))
+}
+
+// Every bound parameter at mid-range, since the box defaults live with the studio, not the device.
+fn centered<'a>(device: &'a dyn Device) -> Instance<'a> {
+    let mut instance = Instance::new(device, SR);
+    let paths: Vec<Vec<u16>> = instance.parameter_paths().map(<[u16]>::to_vec).collect();
+    for path in &paths {
+        assert!(instance.set_parameter(path, ParamValue::Unit(0.5)));
+    }
+    instance
+}
+
+#[test]
+fn the_stock_registry_covers_the_studio_device_table() {
+    let registry = Registry::stock();
+    assert_eq!(registry.box_types().count(), 34);
+    assert!(registry.get("DelayDeviceBox").is_some());
+    assert!(registry.get("PlayfieldSampleBox").is_some());
+    assert!(registry.get("SineDeviceBox").is_none(), "the test synth is not a studio device");
+}
+
+#[test]
+fn every_stock_device_renders_finite_audio() {
+    let registry = Registry::stock();
+    for box_type in registry.box_types() {
+        let device = registry.get(box_type).unwrap();
+        let mut instance = centered(device);
+        match instance.kind() {
+            DEVICE_KIND_AUDIO_EFFECT => instance.set_input(noise(QUANTUM * 16)),
+            DEVICE_KIND_INSTRUMENT => instance.note(0.0, QUARTER, 60, 0.8),
+            DEVICE_KIND_MIDI_EFFECT => {
+                instance.note(0.0, QUARTER, 60, 0.8);
+                instance.process_events(0.0, QUARTER * 2.0);
+                continue;
+            }
+            kind => panic!("{box_type}: unknown kind {kind}")
+        }
+        let [left, right] = instance.render(QUANTUM * 16);
+        assert_eq!(left.len(), QUANTUM * 16);
+        assert!(left.iter().chain(&right).all(|sample| sample.is_finite()), "{box_type} renders finite samples");
+    }
+}
+
+#[test]
+fn the_delay_echoes_an_impulse() {
+    let registry = Registry::stock();
+    let mut delay = centered(registry.get("DelayDeviceBox").unwrap());
+    let mut impulse = [vec![0.0; SR as usize], vec![0.0; SR as usize]];
+    impulse[0][0] = 1.0;
+    impulse[1][0] = 1.0;
+    delay.set_input(impulse);
+    let [left, _] = delay.render(SR as usize);
+    assert!(peak(&left[QUANTUM..]) > 0.01, "an echo after the impulse's quantum");
+    assert!(delay.position() > 0.0, "the transport advanced");
+}
+
+#[test]
+fn vaporisateur_sounds_on_a_note_and_stays_silent_without_one() {
+    let registry = Registry::stock();
+    let device = registry.get("VaporisateurDeviceBox").unwrap();
+    let mut silent = centered(device);
+    assert_eq!(peak(&silent.render(QUANTUM * 8)[0]), 0.0);
+    let mut playing = centered(device);
+    assert!(playing.set_parameter(&CUTOFF_FIELD, ParamValue::Unit(1.0)));
+    playing.note(0.0, QUARTER, 69, 1.0);
+    assert!(peak(&playing.render(QUANTUM * 8)[0]) > 0.01);
+}
+
+#[test]
+fn the_arpeggio_answers_a_pull_with_a_stream_of_notes() {
+    let registry = Registry::stock();
+    let mut arpeggio = Instance::new(registry.get("ArpeggioDeviceBox").unwrap(), SR);
+    for pitch in [60, 64, 67] {
+        arpeggio.note(0.0, QUARTER * 8.0, pitch, 0.8);
+    }
+    let out = arpeggio.process_events(0.0, QUARTER * 4.0);
+    let notes = out.iter().filter(|event| event.kind == EVENT_NOTE_ON).count();
+    assert!(notes > 3, "{notes} arpeggiated notes from a three-note chord");
+}
+
+#[test]
+fn a_stopped_transport_plays_no_notes() {
+    let registry = Registry::stock();
+    let mut vaporisateur = centered(registry.get("VaporisateurDeviceBox").unwrap());
+    vaporisateur.set_parameter(&CUTOFF_FIELD, ParamValue::Unit(1.0));
+    vaporisateur.set_transporting(false);
+    vaporisateur.note(0.0, QUARTER, 69, 1.0);
+    assert_eq!(peak(&vaporisateur.render(QUANTUM * 8)[0]), 0.0);
+}
+
+// A gain stage on one automatable parameter, built from the `abi` effect template.
+struct Gain;
+
+struct GainState {
+    id: u32,
+    gain: f32
+}
+
+const GAIN_FIELD: [u16; 1] = [10];
+
+impl AudioEffect for Gain {
+    type State = GainState;
+
+    fn process_audio(state: &mut GainState, output: [&mut [f32]; 2], block: &Block) {
+        let Some(input) = abi::resolve_input(abi::MAIN_INPUT) else {return};
+        for (output, input) in output.into_iter().zip(input.channels()) {
+            for index in block.s0 as usize..block.s1 as usize {
+                output[index] = input[index] * state.gain;
+            }
+        }
+    }
+
+    fn init(state: &mut GainState, _sample_rate: f32) {
+        state.id = abi::bind_parameter(&GAIN_FIELD);
+    }
+
+    fn parameter_changed(state: &mut GainState, id: u32, value: ParamValue) {
+        if let (true, ParamValue::Unit(gain)) = (id == state.id, value) {
+            state.gain = gain;
+        }
+    }
+}
+
+impl Device for Gain {
+    fn kind(&self) -> u32 {
+        DEVICE_KIND_AUDIO_EFFECT
+    }
+
+    fn state_size(&self, _sample_rate: f32) -> u32 {
+        size_of::<GainState>() as u32
+    }
+
+    fn init(&self, state: usize, sample_rate: f32) {
+        unsafe { abi::with_state::<GainState>(state, |state| <Gain as AudioEffect>::init(state, sample_rate)) };
+    }
+
+    fn process(&self, descriptor: usize) {
+        abi::render_effect::<Gain>(unsafe { Ports::from_descriptor(descriptor) });
+    }
+
+    fn parameter_changed(&self, state: usize, id: u32, kind: u32, value: f32) {
+        unsafe { abi::with_state::<GainState>(state, |state| <Gain as AudioEffect>::parameter_changed(state, id, ParamValue::from_wire(kind, value))) };
+    }
+}
+
+#[test]
+fn automation_switches_on_the_update_grid_at_its_sample_offset() {
+    let mut gain = Instance::new(&Gain, SR);
+    gain.set_input([vec![1.0; QUANTUM * 16], vec![1.0; QUANTUM * 16]]);
+    assert!(gain.automate(&GAIN_FIELD, |position| if position < 40.0 {0.0} else {1.0}));
+    assert!(!gain.automate(&[99], |_| 1.0), "an unbound path");
+    let [left, _] = gain.render(QUANTUM * 16);
+    // 40 pulses at 120 bpm and 48 kHz are 1000 frames
+    let switch = left.iter().position(|sample| *sample == 1.0).expect("the gain opens");
+    assert!((999..=1000).contains(&switch), "opens at frame {switch}");
+    assert!(left[switch..].iter().all(|sample| *sample == 1.0));
+}
+
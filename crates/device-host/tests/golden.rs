@@ -0,0 +1,261 @@
+//! Golden renders of the stock devices: each renders a fixed stimulus (a noise burst through the effects, a
+//! short chord on the instruments) with every parameter at mid-range, plus the few overrides that keep the
+//! render meaningful, and must match its reference in `tests/golden/` (see `device_host::golden`).
+//!
+//! Not covered: the MIDI effects (their output is events, pinned by their own tests), and Werkstatt, Apparat
+//! and Neural Amp, whose DSP runs in JavaScript and renders silence natively.
+
+use std::path::PathBuf;
+
+use abi::{FieldValue, ParamValue};
+use device_host::golden::{self, Tolerance, FRAMES, SAMPLE_RATE};
+use device_host::{Instance, Registry};
+
+const SEMI_QUAVER: f64 = 240.0;
+const CHORD: [u32; 3] = [60, 64, 67];
+
+fn reference(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests").join("golden").join(format!("{name}.wav"))
+}
+
+fn assert_golden(name: &str, rendered: [Vec<f32>; 2]) {
+    assert!(rendered.iter().flatten().any(|sample| *sample != 0.0), "{name} renders silence");
+    if let Err(error) = golden::check(&reference(name), &rendered, &Tolerance::default()) {
+        panic!("{name}: {error}");
+    }
+}
+
+// An instance of `box_type` with every bound parameter at mid-range, then `overrides`.
+fn instance<'a>(registry: &'a Registry, box_type: &str, overrides: &[(&[u16], f32)]) -> Instance<'a> {
+    let mut instance = Instance::new(registry.get(box_type).unwrap(), SAMPLE_RATE);
+    let paths: Vec<Vec<u16>> = instance.parameter_paths().map(<[u16]>::to_vec).collect();
+    for path in &paths {
+        instance.set_parameter(path, ParamValue::Unit(0.5));
+    }
+    for (path, value) in overrides {
+        assert!(instance.set_parameter(path, ParamValue::Unit(*value)), "{box_type} binds {path:?}");
+    }
+    instance
+}
+
+fn effect(box_type: &str, name: &str, overrides: &[(&[u16], f32)]) {
+    let registry = Registry::stock();
+    let mut effect = instance(&registry, box_type, overrides);
+    effect.set_input(golden::burst(FRAMES));
+    assert_golden(name, effect.render(FRAMES));
+}
+
+fn chord(instrument: &mut Instance) {
+    for pitch in CHORD {
+        instrument.note(0.0, SEMI_QUAVER, pitch, 0.8);
+    }
+}
+
+#[test]
+fn delay() {
+    // unsynced: no pre-delay, echoes every 100 ms
+    effect("DelayDeviceBox", "delay", &[(&[16], 0.0), (&[17], 0.0), (&[19], 0.0), (&[20], 0.0), (&[10], 0.0)]);
+}
+
+#[test]
+fn reverb() {
+    effect("ReverbDeviceBox", "reverb", &[]);
+}
+
+#[test]
+fn dattorro_reverb() {
+    effect("DattorroReverbDeviceBox", "dattorro-reverb", &[(&[10], 0.0)]); // no pre-delay
+}
+
+#[test]
+fn compressor() {
+    effect("CompressorDeviceBox", "compressor", &[]);
+}
+
+#[test]
+fn gate() {
+    // opens at once and closes within the tail
+    effect("GateDeviceBox", "gate", &[(&[12], 0.0), (&[13], 0.1), (&[14], 0.05)]);
+}
+
+#[test]
+fn maximizer() {
+    effect("MaximizerDeviceBox", "maximizer", &[]);
+}
+
+#[test]
+fn crusher() {
+    effect("CrusherDeviceBox", "crusher", &[]);
+}
+
+#[test]
+fn fold() {
+    effect("FoldDeviceBox", "fold", &[]);
+}
+
+#[test]
+fn waveshaper() {
+    effect("WaveshaperDeviceBox", "waveshaper", &[]);
+}
+
+#[test]
+fn stereo_tool() {
+    effect("StereoToolDeviceBox", "stereo-tool", &[]);
+}
+
+#[test]
+fn revamp() {
+    // the high- and low-pass off (at mid-range both sit at 630 Hz), the mid bell boosted
+    effect("RevampDeviceBox", "revamp", &[(&[10, 1], 0.0), (&[16, 1], 0.0), (&[13, 11], 0.75)]);
+}
+
+#[test]
+fn tidal() {
+    effect("TidalDeviceBox", "tidal", &[]);
+}
+
+#[test]
+fn vocoder() {
+    effect("VocoderDeviceBox", "vocoder", &[]);
+}
+
+#[test]
+fn autotune() {
+    effect("AutotuneDeviceBox", "autotune", &[]);
+}
+
+#[test]
+fn filter() {
+    effect("FilterDeviceBox", "filter", &[(&[10], 1.0)]); // the ladder, resonant at mid-range
+}
+
+#[test]
+fn chorus() {
+    effect("ChorusDeviceBox", "chorus", &[(&[10], 0.0)]); // the chorus mode (mid-range is the flanger)
+}
+
+#[test]
+fn convolution_reverb() {
+    let registry = Registry::stock();
+    // the whole response: at mid-range its start and end meet
+    let mut reverb = instance(&registry, "ConvolutionReverbDeviceBox", &[(&[11], 0.0), (&[12], 1.0)]);
+    let response = golden::decaying_sine(FRAMES / 4, 1000.0, SAMPLE_RATE);
+    assert!(reverb.set_sample(&[20], &[response.clone(), response], SAMPLE_RATE));
+    reverb.set_input(golden::burst(FRAMES));
+    assert_golden("convolution-reverb", reverb.render(FRAMES));
+}
+
+#[test]
+fn multiband_compressor() {
+    // no band soloed or bypassed (their bools read true at mid-range)
+    effect("MultibandCompressorDeviceBox", "multiband-compressor", &[
+        (&[20, 0, 6], 0.0), (&[20, 0, 7], 0.0), (&[20, 1, 6], 0.0), (&[20, 1, 7], 0.0),
+        (&[20, 2, 6], 0.0), (&[20, 2, 7], 0.0), (&[20, 3, 6], 0.0), (&[20, 3, 7], 0.0)
+    ]);
+}
+
+#[test]
+fn wavetable() {
+    let registry = Registry::stock();
+    // polyphonic, the filter open, no glide; the position morphs half-way from a saw cycle to a square cycle
+    let mut wavetable = instance(&registry, "WavetableDeviceBox", &[(&[16], 1.0), (&[23], 0.0), (&[24], 1.0)]);
+    let saw = (0..2048).map(|index| index as f32 / 1024.0 - 1.0);
+    let square = (0..2048).map(|index| if index < 1024 {0.5} else {-0.5});
+    let table: Vec<f32> = saw.chain(square).collect();
+    assert!(wavetable.set_sample(&[11], &[table], SAMPLE_RATE));
+    chord(&mut wavetable);
+    assert_golden("wavetable", wavetable.render(FRAMES));
+}
+
+#[test]
+fn fm_synth() {
+    let registry = Registry::stock();
+    // polyphonic, no glide; the mid-range algorithm runs two stacks (2 -> 1, 4 -> 3)
+    let mut fm_synth = instance(&registry, "FMSynthDeviceBox", &[(&[13], 0.0), (&[14], 1.0)]);
+    chord(&mut fm_synth);
+    assert_golden("fm-synth", fm_synth.render(FRAMES));
+}
+
+#[test]
+fn vaporisateur() {
+    let registry = Registry::stock();
+    // the cutoff at mid-range all but silences the patch
+    let mut vaporisateur = instance(&registry, "VaporisateurDeviceBox", &[(&[14], 1.0)]);
+    chord(&mut vaporisateur);
+    assert_golden("vaporisateur", vaporisateur.render(FRAMES));
+}
+
+#[test]
+fn nano() {
+    let registry = Registry::stock();
+    let mut nano = instance(&registry, "NanoDeviceBox", &[]);
+    let one_shot = golden::decaying_sine(FRAMES, 261.63, SAMPLE_RATE);
+    assert!(nano.set_sample(&[15], &[one_shot.clone(), one_shot], SAMPLE_RATE));
+    chord(&mut nano);
+    assert_golden("nano", nano.render(FRAMES));
+}
+
+#[test]
+fn playfield_sample() {
+    let registry = Registry::stock();
+    // the whole sample: at mid-range its start and end meet
+    let mut slot = instance(&registry, "PlayfieldSampleBox", &[(&[46], 0.0), (&[47], 1.0)]);
+    let one_shot = golden::decaying_sine(FRAMES, 261.63, SAMPLE_RATE);
+    assert!(slot.set_sample(&[11], &[one_shot.clone(), one_shot], SAMPLE_RATE));
+    assert!(slot.set_field(&[15], FieldValue::Int(60)));
+    chord(&mut slot); // the slot plays its own note only
+    assert_golden("playfield-sample", slot.render(FRAMES));
+}
+
+// A one-preset soundfont in the device's blob layout (`device-soundfont/src/blob.rs`): one full-range region
+// over a decaying sine rooted at middle C.
+fn soundfont_blob() -> Vec<u8> {
+    const HEADER: usize = 32;
+    const SAMPLE: usize = HEADER;
+    const REGION: usize = SAMPLE + 24;
+    const PRESET: usize = REGION + 40;
+    const PCM: usize = PRESET + 8;
+    let pcm = golden::decaying_sine(FRAMES, 261.63, SAMPLE_RATE);
+    let mut blob = Vec::with_capacity(PCM + pcm.len() * 4);
+    for word in [0x4F53_4632, 1, 1, 1, 1, SAMPLE as u32, REGION as u32, PRESET as u32] {
+        blob.extend_from_slice(&u32::to_le_bytes(word));
+    }
+    for word in [PCM as u32, pcm.len() as u32, SAMPLE_RATE.to_bits(), 0, pcm.len() as u32, 60] {
+        blob.extend_from_slice(&u32::to_le_bytes(word));
+    }
+    blob.extend_from_slice(&[0, 127, 0, 127]);
+    let envelope = [0.0f32, 0.005, 0.005, 0.8, 0.05]; // pan, attack, decay, sustain, release
+    for word in [0, 60, 0].into_iter().chain(envelope.map(f32::to_bits)).chain([0]) {
+        blob.extend_from_slice(&u32::to_le_bytes(word));
+    }
+    for word in [0u32, 1] {
+        blob.extend_from_slice(&u32::to_le_bytes(word));
+    }
+    for sample in pcm {
+        blob.extend_from_slice(&sample.to_le_bytes());
+    }
+    blob
+}
+
+#[test]
+fn soundfont() {
+    let registry = Registry::stock();
+    let mut soundfont = instance(&registry, "SoundfontDeviceBox", &[]);
+    assert!(soundfont.set_soundfont(&[10], soundfont_blob()));
+    assert!(soundfont.set_field(&[11], FieldValue::Int(0)));
+    chord(&mut soundfont);
+    assert_golden("soundfont", soundfont.render(FRAMES));
+}
+
+#[test]
+fn a_drifted_render_fails_its_reference() {
+    let reference = golden::burst(FRAMES);
+    let tolerance = Tolerance::default();
+    assert!(golden::compare(&reference, &reference, SAMPLE_RATE).within(&tolerance));
+    let quieter = reference.clone().map(|channel| channel.iter().map(|sample| sample * 0.9).collect());
+    let comparison = golden::compare(&reference, &quieter, SAMPLE_RATE);
+    assert!(comparison.level_delta_db > 0.9 && !comparison.within(&tolerance), "{comparison}");
+    let mut nudged = reference.clone();
+    nudged[1][100] += 0.1;
+    assert!(!golden::compare(&reference, &nudged, SAMPLE_RATE).within(&tolerance), "one sample off fails the null test");
+}
@@ -62,6 +62,13 @@ const SMOOTHED_SNAP_EPSILON: f64 = 1.0e-4; // pull the one-pole residual to exac
 // always set, so a nearest allowed note always exists.
 pub const SCALE_MASKS: [u32; 8] = [0xFFF, 0xAB5, 0x5AD, 0x295, 0x4A9, 0x4E9, 0x6AD, 0x6B5];
 
+/// Whether the MIDI `note` belongs to the scale `mask` (one of `SCALE_MASKS`) rooted at pitch class `key`.
+pub fn in_scale(note: i32, key: i32, mask: u32) -> bool {
+    let pitch_class = ((note % 12) + 12) % 12;
+    let relative = ((pitch_class - key) % 12 + 12) % 12;
+    (mask >> relative) & 1 == 1
+}
+
 pub struct Autotune {
     ring: [f32; RING_SIZE],
     scratch: [f32; SPAN],
@@ -266,9 +273,7 @@ impl Autotune {
     }
 
     fn allowed(&self, note: i32) -> bool {
-        let pitch_class = ((note % 12) + 12) % 12;
-        let relative = ((pitch_class - self.key) % 12 + 12) % 12;
-        (self.mask >> relative) & 1 == 1
+        in_scale(note, self.key, self.mask)
     }
 
     fn nearest_allowed(&self, m: f64) -> i32 {
@@ -368,11 +368,46 @@ impl BiquadProcessor for BiquadStack {
     }
 }
 
+/// The response a [`ModulatedBiquad`] sweeps. `Lowpass` is the zero variant, so a zeroed filter is a low-pass.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum BiquadResponse {
+    #[default]
+    Lowpass,
+    Highpass,
+    Bandpass,
+    Notch
+}
+
+impl BiquadResponse {
+    /// The response at `index` (0 = low-pass, 1 = high-pass, 2 = band-pass, 3 = notch); anything else is low-pass.
+    pub fn from_index(index: i32) -> Self {
+        match index {
+            1 => BiquadResponse::Highpass,
+            2 => BiquadResponse::Bandpass,
+            3 => BiquadResponse::Notch,
+            _ => BiquadResponse::Lowpass
+        }
+    }
+}
+
+impl BiquadCoeff {
+    /// Set the coefficients for `response` at the normalised `cutoff` with the Q `resonance`.
+    pub fn set_response_params(&mut self, response: BiquadResponse, cutoff: f64, resonance: f64) {
+        match response {
+            BiquadResponse::Lowpass => self.set_lowpass_params(cutoff, resonance),
+            BiquadResponse::Highpass => self.set_highpass_params(cutoff, resonance),
+            BiquadResponse::Bandpass => self.set_bandpass_params(cutoff, resonance),
+            BiquadResponse::Notch => self.set_notch_params(cutoff, resonance)
+        }
+    }
+}
+
 /// The number of exponential frequency steps the modulated cutoff is quantised to (mirrors the TS LUT size).
 const MODULATION_STEPS: i32 = 512;
 
 /// A low-pass biquad whose cutoff is MODULATED per sample (filter envelope, LFO, keyboard tracking) — a
-/// heap-free port of lib-dsp `ModulatedBiquad`. The cutoff arrives as a UNIT value (0..1), is quantised to
+/// heap-free port of lib-dsp `ModulatedBiquad` (which [`set_response`](Self::set_response) extends to the
+/// other [`BiquadResponse`]s). The cutoff arrives as a UNIT value (0..1), is quantised to
 /// [`MODULATION_STEPS`] steps mapped EXPONENTIALLY onto `[min_cutoff, max_cutoff]`, and the coefficients are
 /// recomputed only when the quantised step changes — so a smoothly-moving cutoff costs a handful of updates
 /// per block, not one per sample. `order` cascades that many sections (shared Q). Unlike the TS there is no
@@ -381,13 +416,22 @@ const MODULATION_STEPS: i32 = 512;
 pub struct ModulatedBiquad {
     stack: BiquadStack,
     coeff: BiquadCoeff,
+    response: BiquadResponse,
     last_index: i32,
     coeff_valid: bool
 }
 
 impl ModulatedBiquad {
     pub fn new() -> Self {
-        Self {stack: BiquadStack::new(1), coeff: BiquadCoeff::new(), last_index: 0, coeff_valid: false}
+        Self {stack: BiquadStack::new(1), coeff: BiquadCoeff::new(), response: BiquadResponse::Lowpass, last_index: 0, coeff_valid: false}
+    }
+
+    /// Switch the swept response; the coefficients are recomputed on the next `process`, the state is kept.
+    pub fn set_response(&mut self, response: BiquadResponse) {
+        if self.response != response {
+            self.response = response;
+            self.coeff_valid = false;
+        }
     }
 
     /// Filter `buffer[from..to]` in place. `cutoffs[i]` is the unit cutoff (0..1) for sample `i`, mapped
@@ -408,7 +452,7 @@ impl ModulatedBiquad {
             let index = index_at(start);
             if !self.coeff_valid || index != self.last_index {
                 let frequency = min_cutoff * libm::exp(index as f64 / last as f64 * log_ratio);
-                self.coeff.set_lowpass_params(frequency * inv_sample_rate, q_reduced);
+                self.coeff.set_response_params(self.response, frequency * inv_sample_rate, q_reduced);
                 self.last_index = index;
                 self.coeff_valid = true;
             }
@@ -549,6 +593,25 @@ mod tests {
         assert!(buffer.iter().all(|sample| sample.abs() < 4.0), "stays finite across the sweep");
     }
 
+    #[test]
+    fn modulated_responses_shape_the_spectrum() {
+        // a constant mid cutoff (~630 Hz): high-pass passes the Nyquist tone, band-pass / notch split a tone at the centre
+        let run_response = |response: BiquadResponse, input: &[f32]| {
+            let mut buffer = input.to_vec();
+            let mut filter = ModulatedBiquad::new();
+            filter.set_response(response);
+            filter.process(&mut buffer, &vec![0.5f32; input.len()], 2.0, 1, MOD_MIN, MOD_MAX, MOD_SR, 0, input.len());
+            energy(&buffer[input.len() / 2..])
+        };
+        let noise = nyquist(4096);
+        assert!(run_response(BiquadResponse::Highpass, &noise) > 0.9 * energy(&noise[2048..]));
+        assert!(run_response(BiquadResponse::Lowpass, &noise) < 0.01 * energy(&noise[2048..]));
+        let centre = MOD_MIN * libm::exp((0.5 * 511.0) as i32 as f64 / 511.0 * libm::log(MOD_MAX / MOD_MIN)) / MOD_SR as f64;
+        let tone: Vec<f32> = (0..4096).map(|index| (core::f64::consts::TAU * centre * index as f64).sin() as f32).collect();
+        assert!(run_response(BiquadResponse::Bandpass, &tone) > 0.9 * energy(&tone[2048..]), "the band-pass passes its centre");
+        assert!(run_response(BiquadResponse::Notch, &tone) < 0.01 * energy(&tone[2048..]), "the notch removes it");
+    }
+
     #[test]
     fn modulated_reduces_resonance_by_order() {
         // Regression for the Vaporisateur "way louder + brighter" multi-pole bug: the cascade applies ONE
@@ -0,0 +1,242 @@
+//! A uniformly partitioned, overlap-save STEREO convolver: the impulse response is cut into `BLOCK`-frame
+//! partitions whose spectra are multiplied against a frequency-domain delay line of past input blocks, one
+//! `2 * BLOCK`-point FFT forward and one inverse per block. `BLOCK` is the render quantum, so the wet signal
+//! lags the input by exactly one quantum (`LATENCY`) however long the response is.
+//!
+//! Both channels ride ONE complex transform (left in the real part, right in the imaginary part) and are
+//! separated by conjugate symmetry, so a partition stores only the `BINS` non-redundant bins per channel
+//! ([`PARTITION_FLOATS`] floats). The partition spectra and the delay line are caller-owned slices (a
+//! device keeps them in its rate-sized state), so this is heap-free; the spectra can be (re)built a few
+//! partitions at a time with [`Convolver::load`] while the convolver runs on the ones already loaded.
+
+use crate::fft::FixedFft;
+use crate::RENDER_QUANTUM;
+
+/// The partition length in frames.
+pub const BLOCK: usize = RENDER_QUANTUM;
+/// The samples the output lags the input.
+pub const LATENCY: usize = BLOCK;
+const FFT_SIZE: usize = 2 * BLOCK;
+/// The non-redundant bins of a real `FFT_SIZE`-point spectrum (DC up to and including Nyquist).
+pub const BINS: usize = BLOCK + 1;
+/// The floats one stereo partition spectrum takes: `[left re, left im, right re, right im]`, `BINS` each.
+pub const PARTITION_FLOATS: usize = 4 * BINS;
+
+/// The partitions needed to hold `frames` of impulse response.
+pub fn partitions_for(frames: usize) -> usize {
+    frames.div_ceil(BLOCK)
+}
+
+pub struct Convolver {
+    fft: FixedFft<FFT_SIZE>,
+    window: [[f32; FFT_SIZE]; 2], // the previous input block, then the one filling
+    block: [[f32; BLOCK]; 2],     // the last computed output block, played while the next one fills
+    re: [f32; FFT_SIZE],
+    im: [f32; FFT_SIZE],
+    accumulator: [f32; PARTITION_FLOATS],
+    fill: usize,
+    cursor: usize,     // the delay-line slot holding the newest input spectrum
+    capacity: usize,   // the delay-line length in partitions (the longest response)
+    partitions: usize, // the current response's partitions
+    loaded: usize      // of those, the ones whose spectra are built
+}
+
+impl Convolver {
+    /// A convolver for responses up to `capacity` partitions; the spectra / history slices handed to
+    /// [`load`](Self::load) and [`process`](Self::process) hold `capacity * PARTITION_FLOATS` floats each.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            fft: FixedFft::new(),
+            window: [[0.0; FFT_SIZE]; 2],
+            block: [[0.0; BLOCK]; 2],
+            re: [0.0; FFT_SIZE],
+            im: [0.0; FFT_SIZE],
+            accumulator: [0.0; PARTITION_FLOATS],
+            fill: 0,
+            cursor: 0,
+            capacity: capacity.max(1),
+            partitions: 0,
+            loaded: 0
+        }
+    }
+
+    /// Start a new response of `frames` (capped at the capacity); its spectra are then built by [`load`]. Until
+    /// the first partitions are loaded the convolver is silent. The input history is kept.
+    ///
+    /// [`load`]: Self::load
+    pub fn set_length(&mut self, frames: usize) {
+        self.partitions = partitions_for(frames).min(self.capacity);
+        self.loaded = 0;
+    }
+
+    /// Whether every partition of the current response is built.
+    pub fn is_loaded(&self) -> bool {
+        self.loaded == self.partitions
+    }
+
+    /// Build up to `count` more partition spectra into `spectra` from `impulse(channel, frame)` (channel 0 or
+    /// 1, frame from the response start; frames past the response must read 0).
+    pub fn load<F: FnMut(usize, usize) -> f32>(&mut self, spectra: &mut [f32], count: usize, mut impulse: F) {
+        let end = (self.loaded + count).min(self.partitions);
+        for partition in self.loaded..end {
+            let offset = partition * BLOCK;
+            for index in 0..BLOCK {
+                self.re[index] = impulse(0, offset + index);
+                self.im[index] = impulse(1, offset + index);
+            }
+            self.re[BLOCK..].fill(0.0);
+            self.im[BLOCK..].fill(0.0);
+            self.fft.forward(&mut self.re, &mut self.im);
+            split(&self.re, &self.im, &mut spectra[partition * PARTITION_FLOATS..][..PARTITION_FLOATS]);
+        }
+        self.loaded = end;
+    }
+
+    /// Convolve `input[c][from..to]` into `output[c][from..to]` (overwritten with the wet signal, `LATENCY`
+    /// samples late). `history` is the frequency-domain delay line, zeroed by [`reset`](Self::reset).
+    pub fn process(&mut self, spectra: &[f32], history: &mut [f32], input: [&[f32]; 2], output: [&mut [f32]; 2],
+                   from: usize, to: usize) {
+        let [out_left, out_right] = output;
+        for index in from..to {
+            self.window[0][BLOCK + self.fill] = input[0][index];
+            self.window[1][BLOCK + self.fill] = input[1][index];
+            out_left[index] = self.block[0][self.fill];
+            out_right[index] = self.block[1][self.fill];
+            self.fill += 1;
+            if self.fill == BLOCK {
+                self.convolve(spectra, history);
+                self.fill = 0;
+            }
+        }
+    }
+
+    fn convolve(&mut self, spectra: &[f32], history: &mut [f32]) {
+        self.re.copy_from_slice(&self.window[0]);
+        self.im.copy_from_slice(&self.window[1]);
+        self.fft.forward(&mut self.re, &mut self.im);
+        self.cursor = (self.cursor + 1) % self.capacity;
+        split(&self.re, &self.im, &mut history[self.cursor * PARTITION_FLOATS..][..PARTITION_FLOATS]);
+        self.accumulator.fill(0.0);
+        for partition in 0..self.loaded {
+            let slot = (self.cursor + self.capacity - partition) % self.capacity;
+            let x = &history[slot * PARTITION_FLOATS..][..PARTITION_FLOATS];
+            let h = &spectra[partition * PARTITION_FLOATS..][..PARTITION_FLOATS];
+            for base in [0, 2 * BINS] {
+                for bin in base..base + BINS {
+                    let (xr, xi, hr, hi) = (x[bin], x[bin + BINS], h[bin], h[bin + BINS]);
+                    self.accumulator[bin] += xr * hr - xi * hi;
+                    self.accumulator[bin + BINS] += xr * hi + xi * hr;
+                }
+            }
+        }
+        // Re-pack Y = Y_left + j Y_right over the full circle, so the inverse yields left real, right imaginary.
+        let y = &self.accumulator;
+        for bin in 0..BINS {
+            let (lr, li, rr, ri) = (y[bin], y[BINS + bin], y[2 * BINS + bin], y[3 * BINS + bin]);
+            self.re[bin] = lr - ri;
+            self.im[bin] = li + rr;
+            if bin > 0 && bin < BLOCK {
+                self.re[FFT_SIZE - bin] = lr + ri;
+                self.im[FFT_SIZE - bin] = rr - li;
+            }
+        }
+        self.fft.inverse(&mut self.re, &mut self.im);
+        // overlap-save: the first half wrapped around, the second is the linear convolution
+        self.block[0].copy_from_slice(&self.re[BLOCK..]);
+        self.block[1].copy_from_slice(&self.im[BLOCK..]);
+        for window in &mut self.window {
+            window.copy_within(BLOCK.., 0);
+        }
+    }
+
+    /// Silence: clear the input window, the pending output block and the delay line (the spectra stay).
+    pub fn reset(&mut self, history: &mut [f32]) {
+        self.window = [[0.0; FFT_SIZE]; 2];
+        self.block = [[0.0; BLOCK]; 2];
+        history.fill(0.0);
+        self.fill = 0;
+        self.cursor = 0;
+    }
+}
+
+/// Separate the packed transform `Z = FFT(left + j right)` into the two half spectra by conjugate symmetry:
+/// `L[k] = (Z[k] + conj Z[-k]) / 2`, `R[k] = (Z[k] - conj Z[-k]) / 2j`.
+fn split(re: &[f32; FFT_SIZE], im: &[f32; FFT_SIZE], target: &mut [f32]) {
+    for bin in 0..BINS {
+        let mirror = (FFT_SIZE - bin) % FFT_SIZE;
+        target[bin] = 0.5 * (re[bin] + re[mirror]);
+        target[BINS + bin] = 0.5 * (im[bin] - im[mirror]);
+        target[2 * BINS + bin] = 0.5 * (im[bin] + im[mirror]);
+        target[3 * BINS + bin] = -0.5 * (re[bin] - re[mirror]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn noise(seed: &mut u32, len: usize) -> Vec<f32> {
+        (0..len).map(|_| {
+            *seed = seed.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+            (*seed >> 8) as f32 / (1 << 24) as f32 * 2.0 - 1.0
+        }).collect()
+    }
+
+    #[test]
+    fn matches_direct_convolution_one_block_late_per_channel() {
+        let mut seed = 7;
+        let impulses = [noise(&mut seed, 300), noise(&mut seed, 300)];
+        let input = [noise(&mut seed, 1500), noise(&mut seed, 1500)];
+        let capacity = 4;
+        let mut convolver = Convolver::new(capacity);
+        let mut spectra = vec![0.0f32; capacity * PARTITION_FLOATS];
+        let mut history = vec![0.0f32; capacity * PARTITION_FLOATS];
+        convolver.set_length(300);
+        // built over two calls, like a device spreading the work over blocks
+        let impulse = |channel: usize, frame: usize| impulses[channel].get(frame).copied().unwrap_or(0.0);
+        convolver.load(&mut spectra, 2, impulse);
+        assert!(!convolver.is_loaded());
+        convolver.load(&mut spectra, 2, impulse);
+        assert!(convolver.is_loaded());
+        let mut output = [vec![0.0f32; 1500], vec![0.0f32; 1500]];
+        // uneven sub-chunks, as the engine splits a quantum at parameter updates
+        let mut from = 0;
+        for len in [37, 128, 91, 200, 5].iter().cycle() {
+            let to = (from + len).min(1500);
+            let [left, right] = &mut output;
+            convolver.process(&spectra, &mut history, [&input[0], &input[1]], [left, right], from, to);
+            from = to;
+            if from == 1500 {
+                break;
+            }
+        }
+        for channel in 0..2 {
+            for (index, sample) in output[channel].iter().enumerate().skip(LATENCY) {
+                let n = index - LATENCY;
+                let direct: f32 = (0..=n.min(299)).map(|k| impulses[channel][k] * input[channel][n - k]).sum();
+                assert!((sample - direct).abs() < 1e-3, "channel {channel} frame {index}: {sample} vs {direct}");
+            }
+            assert!(output[channel][..LATENCY].iter().all(|sample| *sample == 0.0));
+        }
+    }
+
+    #[test]
+    fn the_response_is_capped_at_the_capacity_and_reset_silences() {
+        let mut convolver = Convolver::new(2);
+        convolver.set_length(10_000);
+        let mut spectra = vec![0.0f32; 2 * PARTITION_FLOATS];
+        let mut history = vec![0.0f32; 2 * PARTITION_FLOATS];
+        convolver.load(&mut spectra, usize::MAX, |_, frame| if frame == 0 {1.0} else {0.0});
+        assert!(convolver.is_loaded());
+        let input = vec![1.0f32; 512];
+        let mut output = [vec![0.0f32; 512], vec![0.0f32; 512]];
+        let [left, right] = &mut output;
+        convolver.process(&spectra, &mut history, [&input, &input], [left, right], 0, 512);
+        assert!((output[0][300] - 1.0).abs() < 1e-4, "a unit impulse passes the input: {}", output[0][300]);
+        convolver.reset(&mut history);
+        let silence = vec![0.0f32; 256];
+        let [left, right] = &mut output;
+        convolver.process(&spectra, &mut history, [&silence, &silence], [left, right], 0, 256);
+        assert!(output[0][..256].iter().chain(&output[1][..256]).all(|sample| *sample == 0.0));
+    }
+}
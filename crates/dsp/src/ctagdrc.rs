@@ -276,6 +276,11 @@ impl DelayLine {
         Self {buffer: [[0.0; DELAY_BUFFER_SIZE]; 2], delay_in_samples, write_position: 0}
     }
 
+    /// The delay in samples (the look-ahead latency a device reports for delay compensation).
+    pub fn delay_in_samples(&self) -> usize {
+        self.delay_in_samples
+    }
+
     pub fn process(&mut self, channels: [&mut [f32]; 2], from: usize, to: usize) {
         if self.delay_in_samples == 0 {
             return;
@@ -0,0 +1,32 @@
+//! Reads from a circular, pow2-sized delay line at a FRACTIONAL offset (linear interpolation between the two
+//! neighbouring frames), the read half of lib-dsp `Delay`'s interpolating path. The line itself is a plain
+//! `&[f32]` the caller owns (a device keeps it in its rate-sized state), so this is heap-free.
+
+/// The frame `offset` frames before `write_position` in `buffer` (whose length is a power of two), linearly
+/// interpolated. `offset` must lie in `[0, buffer.len())`; an offset of 0 reads the frame at the write head.
+pub fn read_fractional(buffer: &[f32], write_position: usize, offset: f64) -> f32 {
+    let size = buffer.len();
+    let mask = size - 1;
+    let mut read_position = write_position as f64 - offset;
+    if read_position < 0.0 {
+        read_position += size as f64;
+    }
+    let read_int = libm::floor(read_position) as usize;
+    let alpha = (read_position - read_int as f64) as f32;
+    let read0 = buffer[read_int & mask];
+    read0 + alpha * (buffer[(read_int + 1) & mask] - read0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::read_fractional;
+
+    #[test]
+    fn integer_offsets_read_frames_and_fractions_interpolate() {
+        let buffer: [f32; 8] = core::array::from_fn(|index| index as f32);
+        assert_eq!(read_fractional(&buffer, 5, 2.0), 3.0);
+        assert_eq!(read_fractional(&buffer, 5, 2.25), 2.75);
+        assert_eq!(read_fractional(&buffer, 1, 3.0), 6.0, "wraps around the start");
+        assert_eq!(read_fractional(&buffer, 0, 0.5), 3.5, "interpolates across the wrap: between frame 7 and frame 0");
+    }
+}
@@ -0,0 +1,114 @@
+//! The iterative radix-2 complex FFT shared by the feature crates: the table fill and the in-place DIT
+//! butterfly pass work on caller-owned tables, so `stretch::fft::Fft` keeps its runtime-sized `Vec` tables
+//! (it has `alloc`) while a heap-free device uses [`FixedFft`], whose tables are arrays sized at compile
+//! time. Twiddles are computed once in f64 via libm and stored f32.
+
+/// Fill the transform tables for `bit_rev.len()` points (a power of two >= 2): the bit-reversal permutation
+/// and the first half-circle of twiddles (`cos_table` / `sin_table`, `size / 2` long, angle `-2 pi k / size`).
+pub fn fill_tables(bit_rev: &mut [u32], cos_table: &mut [f32], sin_table: &mut [f32]) {
+    let size = bit_rev.len();
+    assert!(size.is_power_of_two() && size >= 2, "fft size must be a power of two >= 2");
+    let levels = size.trailing_zeros();
+    for (index, entry) in bit_rev.iter_mut().enumerate() {
+        *entry = (index as u32).reverse_bits() >> (32 - levels);
+    }
+    for index in 0..size / 2 {
+        let angle = -2.0 * core::f64::consts::PI * index as f64 / size as f64;
+        cos_table[index] = libm::cos(angle) as f32;
+        sin_table[index] = libm::sin(angle) as f32;
+    }
+}
+
+/// The unscaled in-place DFT of `re` / `im` (each `bit_rev.len()` long) over tables from [`fill_tables`];
+/// `inverse` conjugates the twiddles. The caller applies the `1 / N` of an inverse.
+pub fn transform(re: &mut [f32], im: &mut [f32], bit_rev: &[u32], cos_table: &[f32], sin_table: &[f32], inverse: bool) {
+    let size = bit_rev.len();
+    assert!(re.len() == size && im.len() == size, "buffer length must equal fft size");
+    for (index, &swap) in bit_rev.iter().enumerate() {
+        let swap = swap as usize;
+        if swap > index {
+            re.swap(index, swap);
+            im.swap(index, swap);
+        }
+    }
+    let mut half_block = 1;
+    while half_block < size {
+        let block = half_block * 2;
+        let stride = size / block;
+        for start in (0..size).step_by(block) {
+            let mut twiddle = 0;
+            for even in start..start + half_block {
+                let odd = even + half_block;
+                let cos = cos_table[twiddle];
+                let sin = if inverse { -sin_table[twiddle] } else { sin_table[twiddle] };
+                let odd_re = re[odd] * cos - im[odd] * sin;
+                let odd_im = re[odd] * sin + im[odd] * cos;
+                re[odd] = re[even] - odd_re;
+                im[odd] = im[even] - odd_im;
+                re[even] += odd_re;
+                im[even] += odd_im;
+                twiddle += stride;
+            }
+        }
+        half_block = block;
+    }
+}
+
+/// A `SIZE`-point FFT with its tables inline (no heap), for device state. The twiddle arrays are `SIZE`
+/// long for want of `SIZE / 2` in a const generic; only their first half is filled and read.
+#[derive(Clone, Copy)]
+pub struct FixedFft<const SIZE: usize> {
+    bit_rev: [u32; SIZE],
+    cos_table: [f32; SIZE],
+    sin_table: [f32; SIZE]
+}
+
+impl<const SIZE: usize> FixedFft<SIZE> {
+    pub fn new() -> Self {
+        let mut fft = Self {bit_rev: [0; SIZE], cos_table: [0.0; SIZE], sin_table: [0.0; SIZE]};
+        fill_tables(&mut fft.bit_rev, &mut fft.cos_table[..SIZE / 2], &mut fft.sin_table[..SIZE / 2]);
+        fft
+    }
+
+    /// In-place forward DFT (DIT). `re`/`im` must be `SIZE` long.
+    pub fn forward(&self, re: &mut [f32], im: &mut [f32]) {
+        transform(re, im, &self.bit_rev, &self.cos_table[..SIZE / 2], &self.sin_table[..SIZE / 2], false);
+    }
+
+    /// In-place inverse DFT, scaled by 1/N so `inverse(forward(x)) == x`.
+    pub fn inverse(&self, re: &mut [f32], im: &mut [f32]) {
+        transform(re, im, &self.bit_rev, &self.cos_table[..SIZE / 2], &self.sin_table[..SIZE / 2], true);
+        let scale = 1.0 / SIZE as f32;
+        for value in re.iter_mut().chain(im.iter_mut()) {
+            *value *= scale;
+        }
+    }
+}
+
+impl<const SIZE: usize> Default for FixedFft<SIZE> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FixedFft;
+
+    #[test]
+    fn a_shifted_impulse_is_a_phase_ramp_and_round_trips() {
+        let fft = FixedFft::<16>::new();
+        let (mut re, mut im) = ([0.0f32; 16], [0.0f32; 16]);
+        re[1] = 1.0;
+        fft.forward(&mut re, &mut im);
+        for bin in 0..16 {
+            let angle = -core::f64::consts::TAU * bin as f64 / 16.0;
+            assert!((re[bin] as f64 - libm::cos(angle)).abs() < 1e-6 && (im[bin] as f64 - libm::sin(angle)).abs() < 1e-6, "bin {bin}");
+        }
+        fft.inverse(&mut re, &mut im);
+        for (index, (real, imaginary)) in re.iter().zip(&im).enumerate() {
+            let expected = if index == 1 {1.0} else {0.0};
+            assert!((real - expected).abs() < 1e-6 && imaginary.abs() < 1e-6, "sample {index}");
+        }
+    }
+}
@@ -0,0 +1,127 @@
+//! A four-pole LADDER low-pass (24 dB/oct) in the zero-delay-feedback (topology-preserving) form: four
+//! trapezoidal one-pole stages with a global resonance feedback solved per sample, so the resonance tracks
+//! a swept cutoff without the unit-delay detuning of a naive ladder. Only the feedback path is soft-clipped:
+//! the fed-back output is estimated from the linear solve and passed through `tanh`, which bounds the
+//! self-oscillation and makes the resonance level-dependent while the dry input reaches the stages unclipped.
+//!
+//! The cutoff arrives as a UNIT value per sample and is quantised exactly like [`ModulatedBiquad`]'s
+//! (exponential steps onto `[min_cutoff, max_cutoff]`), so the `tan` prewarp runs only when the step changes.
+//! Valid when zeroed.
+//!
+//! [`ModulatedBiquad`]: crate::biquad::ModulatedBiquad
+
+use math::clamp;
+
+/// The exponential cutoff steps (the same resolution as the modulated biquad).
+const MODULATION_STEPS: i32 = 512;
+/// The feedback at which the ladder self-oscillates.
+pub const MAX_FEEDBACK: f64 = 4.0;
+
+#[derive(Clone, Copy, Default)]
+pub struct Ladder {
+    stages: [f64; 4],
+    gain: f64, // the one-pole stage gain G = g / (1 + g)
+    last_index: i32,
+    gain_valid: bool
+}
+
+impl Ladder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Filter `buffer[from..to]` in place. `cutoffs[i]` is the unit cutoff for sample `i` (mapped exponentially
+    /// onto `[min_cutoff, max_cutoff]` Hz at `sample_rate`); `feedback` is the resonance, `0..MAX_FEEDBACK`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn process(&mut self, buffer: &mut [f32], cutoffs: &[f32], feedback: f64,
+                   min_cutoff: f64, max_cutoff: f64, sample_rate: f32, from: usize, to: usize) {
+        let feedback = clamp(feedback, 0.0, MAX_FEEDBACK);
+        let last = (MODULATION_STEPS - 1) as f32;
+        let log_ratio = libm::log(max_cutoff / min_cutoff);
+        let nyquist_guard = 0.49 * sample_rate as f64;
+        for index in from..to {
+            let step = (clamp(cutoffs[index], 0.0, 1.0) * last) as i32;
+            if !self.gain_valid || step != self.last_index {
+                let frequency = (min_cutoff * libm::exp(step as f64 / last as f64 * log_ratio)).min(nyquist_guard);
+                let g = libm::tan(core::f64::consts::PI * frequency / sample_rate as f64);
+                self.gain = g / (1.0 + g);
+                self.last_index = step;
+                self.gain_valid = true;
+            }
+            buffer[index] = self.process_frame(buffer[index] as f64, feedback) as f32;
+        }
+    }
+
+    /// One sample through the ladder at the current stage gain.
+    fn process_frame(&mut self, x: f64, feedback: f64) -> f64 {
+        let gain = self.gain;
+        let [s1, s2, s3, s4] = self.stages;
+        // The ladder output is linear in its input: y4 = G^4 u + sigma, with sigma the stages' stored energy.
+        let sigma = gain * gain * gain * s1 * (1.0 - gain) + gain * gain * s2 * (1.0 - gain) + gain * s3 * (1.0 - gain) + s4 * (1.0 - gain);
+        let g4 = gain * gain * gain * gain;
+        let estimate = g4 * (x - feedback * sigma) / (1.0 + feedback * g4) + sigma;
+        let mut value = x - feedback * libm::tanh(estimate);
+        for stage in &mut self.stages {
+            let v = (value - *stage) * gain;
+            value = v + *stage;
+            *stage = value + v;
+        }
+        // the feedback pulls the passband down to `1 / (1 + feedback)` at DC; make up about half of that
+        value * (1.0 + 0.5 * feedback)
+    }
+
+    pub fn reset(&mut self) {
+        self.stages = [0.0; 4];
+        self.gain_valid = false;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SR: f32 = 48_000.0;
+
+    fn tone(frequency: f64, len: usize) -> Vec<f32> {
+        (0..len).map(|index| (0.25 * libm::sin(core::f64::consts::TAU * frequency * index as f64 / SR as f64)) as f32).collect()
+    }
+
+    fn filtered(frequency: f64, unit: f32, feedback: f64) -> f32 {
+        let mut buffer = tone(frequency, 9600);
+        Ladder::new().process(&mut buffer, &vec![unit; 9600], feedback, 20.0, 20_000.0, SR, 0, 9600);
+        buffer[4800..].iter().fold(0.0f32, |peak, sample| peak.max(sample.abs()))
+    }
+
+    #[test]
+    fn passes_below_the_cutoff_and_falls_at_24_db_per_octave_above() {
+        // unit 0.5 is ~630 Hz
+        assert!(filtered(100.0, 0.5, 0.0) > 0.2, "the passband is open");
+        let octave_one = filtered(2520.0, 0.5, 0.0);
+        let octave_two = filtered(5040.0, 0.5, 0.0);
+        assert!(octave_one < 0.25 * 0.01, "two octaves above the cutoff are over 40 dB down: {octave_one}");
+        assert!(octave_two < octave_one / 10.0, "another octave falls about 24 dB further: {octave_two}");
+    }
+
+    #[test]
+    fn only_the_feedback_path_saturates() {
+        let loud = |feedback: f64| {
+            let mut buffer: Vec<f32> = tone(100.0, 9600).iter().map(|sample| sample * 8.0).collect();
+            Ladder::new().process(&mut buffer, &[0.5; 9600], feedback, 20.0, 20_000.0, SR, 0, 9600);
+            buffer[4800..].iter().fold(0.0f32, |peak, sample| peak.max(sample.abs()))
+        };
+        let ratio = loud(0.0) / filtered(100.0, 0.5, 0.0);
+        assert!((ratio - 8.0).abs() < 0.01, "without feedback a loud input passes unclipped: {ratio}");
+        assert!(loud(2.0) / filtered(100.0, 0.5, 2.0) > 8.0, "a loud input drives only the feedback into the tanh");
+    }
+
+    #[test]
+    fn feedback_peaks_at_the_cutoff_and_self_oscillation_stays_bounded() {
+        let centre = 20.0 * libm::exp((0.5f32 * 511.0) as i32 as f64 / 511.0 * libm::log(1000.0));
+        assert!(filtered(centre, 0.5, 3.5) > 2.0 * filtered(centre, 0.5, 0.0), "resonance lifts the cutoff");
+        let mut buffer = vec![0.0f32; 48_000];
+        buffer[0] = 1.0;
+        let cutoffs: Vec<f32> = (0..48_000).map(|index| index as f32 / 48_000.0).collect();
+        Ladder::new().process(&mut buffer, &cutoffs, MAX_FEEDBACK, 20.0, 20_000.0, SR, 0, 48_000);
+        assert!(buffer.iter().all(|sample| sample.is_finite() && sample.abs() < 4.0), "the tanh bounds the loop");
+    }
+}
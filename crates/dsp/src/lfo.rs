@@ -59,6 +59,12 @@ impl Lfo {
         }
     }
 
+    /// Jump to `phase` (its fractional part is taken), so a tempo-synced user can lock the oscillator to the
+    /// song position at a block start and let `fill` run free within the block.
+    pub fn set_phase(&mut self, phase: f64) {
+        self.phase = phase - libm::floor(phase);
+    }
+
     pub fn reset(&mut self) {
         self.phase = 0.0;
     }
@@ -8,13 +8,19 @@ pub mod adsr;
 pub mod analyser;
 pub mod autotune;
 pub mod biquad;
+pub mod convolver;
 pub mod crusher;
 pub mod dattorro;
+pub mod delay_line;
 pub mod fast_math;
+pub mod fft;
 pub mod ctagdrc;
 pub mod freeverb;
 pub mod glide;
+pub mod interpolator;
+pub mod ladder;
 pub mod lfo;
+pub mod linkwitz_riley;
 pub mod meter;
 pub mod osc;
 pub mod panning;
@@ -27,6 +33,7 @@ pub mod smooth;
 pub mod tidal;
 pub mod vocoder;
 pub mod waveshaper;
+pub mod wavetable;
 
 pub use math::{clamp, fabs, fast_sin, PI};
 
@@ -0,0 +1,241 @@
+//! A mip-mapped WAVETABLE oscillator. A table is a run of single-cycle FRAMES (`FRAME_SIZE` samples each, the
+//! common 2048-sample wavetable format); the oscillator reads the two frames around a fractional, morphable
+//! frame `position` and crossfades them. Every frame is stored band-limited at `LEVELS` mip levels, one per
+//! octave: level `l` keeps the first `512 >> l` harmonics (the upper bins of the frame's spectrum zeroed, via
+//! [`FixedFft`]) at four samples per period of its top harmonic (at least `MIN_LEVEL_SIZE`), and the
+//! oscillator reads, per sample, the richest level whose top harmonic stays below Nyquist.
+//!
+//! Heap-free: the table is a caller-owned slice of `frames * FRAME_FLOATS` floats (a device keeps it in its
+//! state) that [`WavetableBuilder`] fills one frame at a time and [`Wavetable`] reads.
+
+use crate::fft::FixedFft;
+
+/// The samples of one source frame (a single cycle).
+pub const FRAME_SIZE: usize = 2048;
+/// The mip levels per frame, one per octave.
+pub const LEVELS: usize = 10;
+const TOP_HARMONICS: usize = 512; // the harmonics level 0 keeps
+const MIN_LEVEL_SIZE: usize = 64;
+
+const fn level_size(level: usize) -> usize {
+    let size = FRAME_SIZE >> level;
+    if size < MIN_LEVEL_SIZE {MIN_LEVEL_SIZE} else {size}
+}
+
+const fn level_offsets() -> [usize; LEVELS + 1] {
+    let mut offsets = [0; LEVELS + 1];
+    let mut level = 0;
+    while level < LEVELS {
+        offsets[level + 1] = offsets[level] + level_size(level);
+        level += 1;
+    }
+    offsets
+}
+
+const LEVEL_OFFSETS: [usize; LEVELS + 1] = level_offsets();
+/// The floats one band-limited frame takes (all its levels).
+pub const FRAME_FLOATS: usize = LEVEL_OFFSETS[LEVELS];
+
+/// The richest level whose top harmonic is below Nyquist at `inc` cycles per sample.
+fn level_for(inc: f64) -> usize {
+    let allowed = (0.5 / libm::fabs(inc)) as usize; // harmonics below Nyquist (saturates for a zero inc)
+    if allowed >= TOP_HARMONICS {
+        0
+    } else if allowed == 0 {
+        LEVELS - 1
+    } else {
+        (TOP_HARMONICS.ilog2() - allowed.ilog2()) as usize
+    }
+}
+
+/// Band-limits source cycles into table frames: one forward transform per frame, then one inverse per level.
+pub struct WavetableBuilder {
+    fft: FixedFft<FRAME_SIZE>,
+    spectrum: [[f32; FRAME_SIZE]; 2],
+    re: [f32; FRAME_SIZE],
+    im: [f32; FRAME_SIZE]
+}
+
+impl WavetableBuilder {
+    pub fn new() -> Self {
+        Self {fft: FixedFft::new(), spectrum: [[0.0; FRAME_SIZE]; 2], re: [0.0; FRAME_SIZE], im: [0.0; FRAME_SIZE]}
+    }
+
+    /// Build every level of one frame into `target` (`FRAME_FLOATS` long) from `cycle(index)`, index
+    /// `0..FRAME_SIZE` over one period. The DC offset is removed.
+    pub fn build<F: FnMut(usize) -> f32>(&mut self, target: &mut [f32], mut cycle: F) {
+        let [spectrum_re, spectrum_im] = &mut self.spectrum;
+        for (index, sample) in spectrum_re.iter_mut().enumerate() {
+            *sample = cycle(index);
+        }
+        spectrum_im.fill(0.0);
+        self.fft.forward(spectrum_re, spectrum_im);
+        spectrum_re[0] = 0.0;
+        spectrum_im[0] = 0.0;
+        for level in 0..LEVELS {
+            let harmonics = TOP_HARMONICS >> level;
+            self.re.copy_from_slice(spectrum_re);
+            self.im.copy_from_slice(spectrum_im);
+            self.re[harmonics + 1..FRAME_SIZE - harmonics].fill(0.0);
+            self.im[harmonics + 1..FRAME_SIZE - harmonics].fill(0.0);
+            self.fft.inverse(&mut self.re, &mut self.im);
+            let stride = FRAME_SIZE / level_size(level);
+            let samples = self.re.iter().step_by(stride);
+            for (value, sample) in target[LEVEL_OFFSETS[level]..LEVEL_OFFSETS[level + 1]].iter_mut().zip(samples) {
+                *value = *sample;
+            }
+        }
+    }
+}
+
+impl Default for WavetableBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A read view over `frames` built frames (frame-major, `FRAME_FLOATS` each).
+#[derive(Clone, Copy)]
+pub struct Wavetable<'a> {
+    data: &'a [f32],
+    frames: usize
+}
+
+impl<'a> Wavetable<'a> {
+    pub fn new(data: &'a [f32], frames: usize) -> Self {
+        assert!(data.len() >= frames * FRAME_FLOATS, "the table holds fewer floats than its frames");
+        Self {data, frames}
+    }
+
+    pub fn frames(&self) -> usize {
+        self.frames
+    }
+
+    /// The sample at `phase` (0..1) of `level`, crossfaded between the frames around the unit `position`.
+    fn read(&self, position: f32, phase: f64, level: usize) -> f32 {
+        let last = self.frames - 1;
+        let exact = position.clamp(0.0, 1.0) * last as f32;
+        let frame = (exact as usize).min(last);
+        let mix = exact - frame as f32;
+        let size = level_size(level);
+        let at = phase * size as f64;
+        let index = at as usize % size;
+        let fraction = (at - libm::floor(at)) as f32;
+        let sample = |frame: usize| {
+            let values = &self.data[frame * FRAME_FLOATS + LEVEL_OFFSETS[level]..][..size];
+            let (a, b) = (values[index], values[(index + 1) % size]);
+            a + (b - a) * fraction
+        };
+        let current = sample(frame);
+        if mix > 0.0 {current + (sample(frame + 1) - current) * mix} else {current}
+    }
+}
+
+/// A wavetable oscillator with a persistent phase; the table is handed in per call, so voices share one.
+#[derive(Clone, Copy, Default)]
+pub struct WavetableOscillator {
+    inv_sample_rate: f64,
+    phase: f64
+}
+
+impl WavetableOscillator {
+    pub fn new(sample_rate: f32) -> Self {
+        Self {inv_sample_rate: 1.0 / sample_rate as f64, phase: 0.0}
+    }
+
+    /// Fill `output[from..to]` from `table` at the per-sample `frequencies[i]` (Hz) and unit frame
+    /// `positions[i]`. An empty table is silent.
+    pub fn generate(&mut self, output: &mut [f32], frequencies: &[f32], positions: &[f32], table: &Wavetable, from: usize, to: usize) {
+        if table.frames == 0 {
+            output[from..to].fill(0.0);
+            return;
+        }
+        let mut phase = self.phase;
+        let inputs = frequencies[from..to].iter().zip(&positions[from..to]);
+        for (sample, (frequency, position)) in output[from..to].iter_mut().zip(inputs) {
+            let inc = *frequency as f64 * self.inv_sample_rate;
+            *sample = table.read(*position, phase, level_for(inc));
+            phase += inc;
+            phase -= libm::floor(phase);
+        }
+        self.phase = phase;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SR: f32 = 48_000.0;
+
+    fn sine(index: usize) -> f32 {
+        libm::sinf(core::f32::consts::TAU * index as f32 / FRAME_SIZE as f32)
+    }
+
+    fn saw(index: usize) -> f32 {
+        2.0 * index as f32 / FRAME_SIZE as f32 - 1.0
+    }
+
+    fn table(cycles: &[fn(usize) -> f32]) -> Vec<f32> {
+        let mut builder = Box::new(WavetableBuilder::new());
+        let mut data = vec![0.0f32; cycles.len() * FRAME_FLOATS];
+        for (frame, cycle) in data.chunks_mut(FRAME_FLOATS).zip(cycles) {
+            builder.build(frame, cycle);
+        }
+        data
+    }
+
+    #[test]
+    fn the_level_keeps_the_top_harmonic_below_nyquist() {
+        assert_eq!(level_for(20.0 / SR as f64), 0);
+        assert_eq!(level_for(5_000.0 / SR as f64), 7, "4 harmonics below Nyquist: 512 >> 7");
+        assert_eq!(level_for(15_000.0 / SR as f64), LEVELS - 1);
+        for level in 0..LEVELS {
+            assert!(level_size(level) >= 4 * (TOP_HARMONICS >> level), "level {level} keeps 4 samples per top period");
+        }
+    }
+
+    #[test]
+    fn a_sine_frame_plays_back_at_every_level() {
+        let data = table(&[sine]);
+        let table = Wavetable::new(&data, 1);
+        for frequency in [100.0f32, 1_000.0, 12_000.0] {
+            let mut oscillator = WavetableOscillator::new(SR);
+            let mut output = [0.0f32; 480];
+            oscillator.generate(&mut output, &[frequency; 480], &[0.0; 480], &table, 0, 480);
+            for (index, sample) in output.iter().enumerate() {
+                let expected = libm::sinf(core::f32::consts::TAU * frequency * index as f32 / SR);
+                assert!((sample - expected).abs() < 0.02, "{frequency} Hz sample {index}: {sample} vs {expected}");
+            }
+        }
+    }
+
+    #[test]
+    fn the_top_level_of_a_saw_is_its_fundamental() {
+        let data = table(&[saw]);
+        let level = &data[LEVEL_OFFSETS[LEVELS - 1]..LEVEL_OFFSETS[LEVELS]];
+        // the saw's first harmonic is -(2 / pi) sin, the DC is gone
+        for (index, value) in level.iter().enumerate() {
+            let expected = -2.0 / core::f32::consts::PI * libm::sinf(core::f32::consts::TAU * index as f32 / level.len() as f32);
+            assert!((value - expected).abs() < 1e-3, "sample {index}: {value} vs {expected}");
+        }
+    }
+
+    #[test]
+    fn the_position_morphs_between_frames() {
+        fn inverted(index: usize) -> f32 {
+            -sine(index)
+        }
+        let data = table(&[sine, inverted]);
+        let table = Wavetable::new(&data, 2);
+        let mut first = [0.0f32; 48];
+        WavetableOscillator::new(SR).generate(&mut first, &[1_000.0; 48], &[0.0; 48], &table, 0, 48);
+        assert!((first[12] - 1.0).abs() < 0.01, "the first frame: {}", first[12]);
+        let mut last = [0.0f32; 48];
+        WavetableOscillator::new(SR).generate(&mut last, &[1_000.0; 48], &[1.0; 48], &table, 0, 48);
+        assert!((last[12] + 1.0).abs() < 0.01, "the last frame: {}", last[12]);
+        let mut middle = [0.0f32; 48];
+        WavetableOscillator::new(SR).generate(&mut middle, &[1_000.0; 48], &[0.5; 48], &table, 0, 48);
+        assert!(middle.iter().all(|sample| sample.abs() < 1e-4), "opposite frames cancel halfway");
+    }
+}
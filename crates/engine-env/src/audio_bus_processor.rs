@@ -1,12 +1,16 @@
 //! A summing bus, ported from core-processors `AudioBusProcessor`. It owns an output buffer and a list
 //! of shared source buffers added via `add_audio_source`; each render it clears its output and sums the
 //! sources into it. Used for an audio bus and for the output audio unit (no channel-strip gain yet).
-//! Topological ordering guarantees the sources are rendered before this node runs.
+//! Topological ordering guarantees the sources are rendered before this node runs. Each source remembers the
+//! node producing it, so delay compensation can hold back an early source to the latest one (see
+//! `delay_compensation`).
 
 use alloc::rc::Rc;
 use alloc::vec::Vec;
 use crate::audio_buffer::SharedAudioBuffer;
 use crate::audio_generator::AudioGenerator;
+use crate::delay_compensation::CompensationDelay;
+use crate::engine_context::NodeId;
 use crate::event_buffer::EventBuffer;
 use crate::event_receiver::EventReceiver;
 use crate::process_info::ProcessInfo;
@@ -15,9 +19,16 @@ use crate::meter::Meter;
 use crate::telemetry::BroadcastSlot;
 use crate::RENDER_QUANTUM;
 
+// One summed source: its buffer, the node that renders it, and the compensation delay aligning it.
+struct BusSource {
+    buffer: SharedAudioBuffer,
+    node: NodeId,
+    delay: CompensationDelay
+}
+
 pub struct AudioBusProcessor {
     output: SharedAudioBuffer,
-    sources: Vec<SharedAudioBuffer>,
+    sources: Vec<BusSource>,
     enabled: bool,
     events: EventBuffer,
     meter: Option<Meter>
@@ -41,14 +52,22 @@ impl AudioBusProcessor {
         self.enabled = enabled;
     }
 
-    /// Add a source whose output is summed into this bus (TS `addAudioSource`).
-    pub fn add_audio_source(&mut self, source: SharedAudioBuffer) {
-        self.sources.push(source);
+    /// Add a source whose output is summed into this bus (TS `addAudioSource`). `node` is the processor
+    /// rendering it (the caller's ordering edge source), whose latency the compensation reads.
+    pub fn add_audio_source(&mut self, source: SharedAudioBuffer, node: NodeId) {
+        self.sources.push(BusSource {buffer: source, node, delay: CompensationDelay::new()});
     }
 
     /// Remove a previously added source (by buffer identity), e.g. when its audio unit is removed.
     pub fn remove_audio_source(&mut self, source: &SharedAudioBuffer) {
-        self.sources.retain(|existing| !Rc::ptr_eq(existing, source));
+        self.sources.retain(|existing| !Rc::ptr_eq(&existing.buffer, source));
+    }
+
+    /// The compensation delay currently applied to `source` (0 if aligned or unknown), for tests / introspection.
+    pub fn source_delay(&self, source: &SharedAudioBuffer) -> u32 {
+        self.sources.iter()
+            .find(|existing| Rc::ptr_eq(&existing.buffer, source))
+            .map_or(0, |existing| existing.delay.delay())
     }
 
     /// How many sources this bus currently sums (for tests / introspection).
@@ -72,6 +91,9 @@ impl AudioGenerator for AudioBusProcessor {
 impl Processor for AudioBusProcessor {
     fn reset(&mut self) {
         self.output.borrow_mut().clear();
+        for source in &mut self.sources {
+            source.delay.clear();
+        }
         if let Some(meter) = self.meter.as_mut() {
             meter.clear();
         }
@@ -81,9 +103,9 @@ impl Processor for AudioBusProcessor {
         let mut output = self.output.borrow_mut();
         output.clear_range(0, RENDER_QUANTUM);
         if self.enabled {
-            for source in &self.sources {
-                let source = source.borrow();
-                output.add_range(&source, 0, RENDER_QUANTUM);
+            for source in &mut self.sources {
+                let buffer = source.buffer.borrow();
+                source.delay.add_into(&buffer, &mut output);
             }
         }
         // A disabled (bypassed) bus meters its silence; the peak decays to zero.
@@ -91,4 +113,12 @@ impl Processor for AudioBusProcessor {
             meter.process(&output.left, &output.right);
         }
     }
+
+    fn compensate(&mut self, upstream: &dyn Fn(NodeId) -> u32) -> Option<u32> {
+        let latest = self.sources.iter().map(|source| upstream(source.node)).max().unwrap_or(0);
+        for source in &mut self.sources {
+            source.delay.set_delay(latest - upstream(source.node));
+        }
+        Some(latest)
+    }
 }
@@ -27,6 +27,8 @@ use crate::telemetry::{broadcast_slot, BroadcastSlot};
 use dsp::analyser::{AudioAnalyser, NUM_BINS};
 use alloc::boxed::Box;
 use crate::channel_strip::StripAutomation;
+use crate::delay_compensation::CompensationDelay;
+use crate::engine_context::NodeId;
 use crate::event_buffer::EventBuffer;
 use crate::event_receiver::EventReceiver;
 use crate::ppqn::{first_update_position, pulses_to_samples, UPDATE_CLOCK_RATE};
@@ -233,6 +235,9 @@ impl Default for DryWetParams {
 /// identity — its output buffer stays the SAME object either way, so the downstream chain is never re-wired.
 /// The gains resolve at the UPDATE CLOCK when automated (the `StripAutomation` shape the strip and the aux
 /// sends use: `volume` carries dry, `panning` carries wet) and are de-clicked through `LinearRamp`s.
+///
+/// Once told which nodes render the tap and the wet sum (`set_input_nodes`), it takes part in delay
+/// compensation: a latent entry holds the dry tap back by the same amount, so the blend stays phase-aligned.
 pub struct DryWetMixProcessor {
     params: Rc<DryWetParams>,
     meter: crate::meter::Meter, // peaks/RMS of the composite's OUTPUT (a broadcast slot), like any device
@@ -240,6 +245,11 @@ pub struct DryWetMixProcessor {
     output: SharedAudioBuffer,
     tap: SharedAudioBuffer,
     wet_sum: SharedAudioBuffer,
+    input_nodes: Option<(NodeId, NodeId)>, // (tap producer, wet-sum producer), for delay compensation
+    dry_delay: CompensationDelay,
+    wet_delay: CompensationDelay,
+    dry_aligned: SharedAudioBuffer, // the delayed tap, read instead of `tap` while `dry_delay` is non-zero
+    wet_aligned: SharedAudioBuffer,
     dry_gain: LinearRamp,
     wet_gain: LinearRamp,
     sample_rate: f32,
@@ -259,6 +269,11 @@ impl DryWetMixProcessor {
             output: shared_audio_buffer(),
             tap,
             wet_sum,
+            input_nodes: None,
+            dry_delay: CompensationDelay::new(),
+            wet_delay: CompensationDelay::new(),
+            dry_aligned: shared_audio_buffer(),
+            wet_aligned: shared_audio_buffer(),
             dry_gain: LinearRamp::linear(sample_rate),
             wet_gain: LinearRamp::linear(sample_rate),
             sample_rate,
@@ -269,6 +284,11 @@ impl DryWetMixProcessor {
         }
     }
 
+    /// The nodes rendering the dry tap (the distributor) and the wet sum, so `compensate` can align the two.
+    pub fn set_input_nodes(&mut self, dry: NodeId, wet: NodeId) {
+        self.input_nodes = Some((dry, wet));
+    }
+
     // Aim both ramps. Smooth after the first processed chunk; `set` no-ops on an unchanged target.
     fn retarget(&mut self, dry_db: f32, wet_db: f32) {
         self.dry_gain.set(db_to_gain(dry_db), self.processing);
@@ -365,15 +385,37 @@ impl AudioGenerator for DryWetMixProcessor {
 impl Processor for DryWetMixProcessor {
     fn reset(&mut self) {
         self.output.borrow_mut().clear();
+        self.dry_delay.clear();
+        self.wet_delay.clear();
         self.meter.clear();
     }
 
+    fn compensate(&mut self, upstream: &dyn Fn(NodeId) -> u32) -> Option<u32> {
+        let (dry, wet) = self.input_nodes?;
+        let (dry, wet) = (upstream(dry), upstream(wet));
+        let latest = dry.max(wet);
+        self.dry_delay.set_delay(latest - dry);
+        self.wet_delay.set_delay(latest - wet);
+        Some(latest)
+    }
+
     fn process(&mut self, info: &ProcessInfo) {
         // Clone the handles before borrowing: a borrow taken straight off `self` would freeze `self` and block
-        // the `&mut self` retargets below (the aux send does the same).
+        // the `&mut self` retargets below (the aux send does the same). A compensated input is read from its
+        // delayed copy instead.
         let output = self.output.clone();
-        let tap = self.tap.clone();
-        let wet_sum = self.wet_sum.clone();
+        let tap = if self.dry_delay.delay() > 0 {
+            self.dry_delay.process(&self.tap.borrow(), &mut self.dry_aligned.borrow_mut());
+            self.dry_aligned.clone()
+        } else {
+            self.tap.clone()
+        };
+        let wet_sum = if self.wet_delay.delay() > 0 {
+            self.wet_delay.process(&self.wet_sum.borrow(), &mut self.wet_aligned.borrow_mut());
+            self.wet_aligned.clone()
+        } else {
+            self.wet_sum.clone()
+        };
         let mut output = output.borrow_mut();
         let dry = tap.borrow();
         let wet = wet_sum.borrow();
@@ -0,0 +1,80 @@
+//! Plugin delay compensation (PDC). A device with look-ahead reports its latency (`Processor::latency`);
+//! `EngineContext::update_latencies` walks the topologically sorted graph and accumulates, per node, the
+//! latency its output carries. A node that MERGES paths (a bus sum, an effect composite's dry/wet mix) is
+//! handed those upstream latencies through `Processor::compensate` and delays each earlier input by its
+//! difference to the latest one, so a compressed parallel bus stays sample-aligned with the dry path.
+//!
+//! The delay lines are (re)allocated by `set_delay`, which runs at reconcile time only; `process` never
+//! allocates.
+
+use alloc::vec;
+use alloc::vec::Vec;
+use crate::audio_buffer::AudioBuffer;
+use crate::RENDER_QUANTUM;
+
+/// A stereo ring-buffer delay of a whole number of samples, applied over a full render quantum. A zero delay
+/// is a plain pass-through with no buffer at all.
+pub struct CompensationDelay {
+    left: Vec<f32>,
+    right: Vec<f32>,
+    position: usize
+}
+
+impl CompensationDelay {
+    pub fn new() -> Self {
+        Self {left: Vec::new(), right: Vec::new(), position: 0}
+    }
+
+    /// The delay in samples.
+    pub fn delay(&self) -> u32 {
+        self.left.len() as u32
+    }
+
+    /// Re-size the delay. An unchanged delay keeps its history; a changed one starts from silence. Reconcile-time.
+    pub fn set_delay(&mut self, samples: u32) {
+        if samples as usize == self.left.len() {
+            return;
+        }
+        self.left = vec![0.0; samples as usize];
+        self.right = vec![0.0; samples as usize];
+        self.position = 0;
+    }
+
+    /// Silence the history (a transport stop), keeping the delay length.
+    pub fn clear(&mut self) {
+        self.left.fill(0.0);
+        self.right.fill(0.0);
+        self.position = 0;
+    }
+
+    /// Add `input` delayed by this line into `output`, over the whole quantum.
+    pub fn add_into(&mut self, input: &AudioBuffer, output: &mut AudioBuffer) {
+        let length = self.left.len();
+        if length == 0 {
+            output.add_range(input, 0, RENDER_QUANTUM);
+            return;
+        }
+        for index in 0..RENDER_QUANTUM {
+            output.left[index] += self.left[self.position];
+            output.right[index] += self.right[self.position];
+            self.left[self.position] = input.left[index];
+            self.right[self.position] = input.right[index];
+            self.position += 1;
+            if self.position == length {
+                self.position = 0;
+            }
+        }
+    }
+
+    /// Write `input` delayed by this line into `output` (overwriting it), over the whole quantum.
+    pub fn process(&mut self, input: &AudioBuffer, output: &mut AudioBuffer) {
+        output.clear_range(0, RENDER_QUANTUM);
+        self.add_into(input, output);
+    }
+}
+
+impl Default for CompensationDelay {
+    fn default() -> Self {
+        Self::new()
+    }
+}
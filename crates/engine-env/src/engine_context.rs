@@ -55,7 +55,10 @@ pub struct EngineContext {
     // The topsorted processors CACHED as handles (rebuilt with the sort), so the steady-state render loop is
     // a linear walk with zero per-node BTreeMap lookups. Rc clones only; capacity reserved at registration.
     queue: Vec<(NodeId, SharedProcessor)>,
-    needs_sort: bool
+    needs_sort: bool,
+    // Delay compensation: the latency (samples) each node's OUTPUT carries, accumulated over the sorted graph
+    // by `update_latencies`. Reconcile-time only; the render loop never reads it.
+    latencies: BTreeMap<NodeId, u32>
 }
 
 impl EngineContext {
@@ -70,7 +73,8 @@ impl EngineContext {
             labels: BTreeMap::new(),
             profiler: None,
             queue: Vec::new(),
-            needs_sort: false
+            needs_sort: false,
+            latencies: BTreeMap::new()
         }
     }
 
@@ -133,6 +137,7 @@ impl EngineContext {
         self.graph.remove_vertex(id);
         self.processors.remove(&id);
         self.labels.remove(&id); // ids are never reused; keeping dead labels would grow forever
+        self.latencies.remove(&id);
         // Drop the cached render queue NOW (it rebuilds on the next `process` anyway): its Rc clones would
         // keep the removed processor alive past this reconcile — its telemetry slots then read as ALIVE,
         // blocking a same-address re-registration and surviving the sweep, only to die mid-render and leave
@@ -199,16 +204,7 @@ impl EngineContext {
     /// dependency order, then emit `After` (TS `EngineProcessor.process` over the sorted queue).
     pub fn process(&mut self, info: &ProcessInfo) {
         self.emit(ProcessPhase::Before);
-        if self.needs_sort {
-            self.sort.update(&self.graph);
-            self.queue.clear();
-            for &id in self.sort.sorted() {
-                if let Some(processor) = self.processors.get(&id) {
-                    self.queue.push((id, processor.clone()));
-                }
-            }
-            self.needs_sort = false;
-        }
+        self.ensure_sorted();
         match &mut self.profiler {
             Some(profiler) => {
                 for (id, processor) in &self.queue {
@@ -227,6 +223,32 @@ impl EngineContext {
         self.emit(ProcessPhase::After);
     }
 
+    /// Plugin delay compensation (see `delay_compensation`): walk the sorted graph, give every merging node the
+    /// latencies its upstream paths carry (`Processor::compensate`, which re-sizes its delay lines), and record
+    /// each node's output latency (its aligned input latency plus its own `Processor::latency`). A serial node's
+    /// input latency is the max over its predecessors. Call after wiring changes or a device's latency changes;
+    /// it allocates, so never from render.
+    pub fn update_latencies(&mut self) {
+        self.ensure_sorted();
+        self.latencies.clear();
+        for (id, processor) in &self.queue {
+            let latencies = &self.latencies;
+            let upstream = |node: NodeId| latencies.get(&node).copied().unwrap_or(0);
+            let mut processor = processor.borrow_mut();
+            let input = match processor.compensate(&upstream) {
+                Some(input) => input,
+                None => self.graph.get_predecessors(*id).iter().map(|&node| upstream(node)).max().unwrap_or(0)
+            };
+            let output = input + processor.latency();
+            self.latencies.insert(*id, output);
+        }
+    }
+
+    /// The latency (samples) a node's output carries as of the last `update_latencies`; 0 for an unknown node.
+    pub fn latency_of(&self, id: NodeId) -> u32 {
+        self.latencies.get(&id).copied().unwrap_or(0)
+    }
+
     /// Reset every processor (a transport STOP): each device clears its runtime state, and the buses / channel
     /// strips clear their buffers, so the next playback starts silent. Outside render.
     pub fn reset_all(&mut self) {
@@ -235,6 +257,20 @@ impl EngineContext {
         }
     }
 
+    // Re-sort (and re-cache the render queue) if the graph changed since the last sort.
+    fn ensure_sorted(&mut self) {
+        if self.needs_sort {
+            self.sort.update(&self.graph);
+            self.queue.clear();
+            for &id in self.sort.sorted() {
+                if let Some(processor) = self.processors.get(&id) {
+                    self.queue.push((id, processor.clone()));
+                }
+            }
+            self.needs_sort = false;
+        }
+    }
+
     fn emit(&mut self, phase: ProcessPhase) {
         for observer in &mut self.phase_observers {
             observer(phase);
@@ -1,6 +1,6 @@
 //! The runtime per-block event, ported from the TS `Event` union (`lib/dsp/events.ts` base +
-//! core-processors `NoteEventSource` / `UpdateClock`). Note-on, note-off, and update-clock ticks all
-//! flow through one `EventBuffer` and are dispatched together per block, so in Rust they are one enum
+//! core-processors `NoteEventSource` / `UpdateClock`). Note-on, note-off, channel controls (live MIDI
+//! CCs, the pitch wheel, channel pressure) and update-clock ticks all flow through one `EventBuffer` and are dispatched together per block, so in Rust they are one enum
 //! matched by every processor. This is the runtime stream, distinct from the value crate's `Event`
 //! trait (the sorted timeline-collection element).
 //!
@@ -14,13 +14,29 @@ pub enum Event {
     /// A note ends. (TS `NoteCompleteEvent`, type `note-complete-event`.)
     NoteComplete {id: u64, position: f64, pitch: u8},
     /// An update-clock tick driving parameter-automation polling. (TS `UpdateEvent`, type `update-event`.)
-    Update {position: f64}
+    Update {position: f64},
+    /// A channel-wide control change, not tied to a note (live MIDI input: mod wheel, sustain pedal, bend).
+    Control {position: f64, control: Control}
+}
+
+/// What a `Event::Control` changes. Values are normalised the way the device ABI carries them.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Control {
+    /// A continuous controller `controller` (`0..=127`) set to `value` in `0..1`.
+    Change {controller: u8, value: f32},
+    /// The pitch wheel, bipolar `-1..1` (0 centred).
+    PitchBend {value: f32},
+    /// Channel aftertouch, `0..1`.
+    Pressure {value: f32}
 }
 
 impl Event {
     pub fn position(&self) -> f64 {
         match self {
-            Event::NoteStart {position, ..} | Event::NoteComplete {position, ..} | Event::Update {position} => *position
+            Event::NoteStart {position, ..}
+            | Event::NoteComplete {position, ..}
+            | Event::Update {position}
+            | Event::Control {position, ..} => *position
         }
     }
 }
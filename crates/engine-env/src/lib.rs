@@ -16,6 +16,7 @@ pub mod aux_send;
 pub mod channel_strip;
 pub mod clip_sequencer;
 pub mod composite_mix;
+pub mod delay_compensation;
 pub mod telemetry;
 pub mod ramp;
 pub mod audio_generator;
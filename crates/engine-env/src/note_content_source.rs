@@ -4,18 +4,22 @@
 //! sequencer can split the block into clip sections per track (TS `clipSequencing.iterate`) and read
 //! the right content per section. The engine implements this over the box-graph bindings.
 
+use value::control::ControlEvent;
 use value::event::EventCollection;
 use value::note::NoteEvent;
 use crate::note_region::NoteRegion;
 
+/// Visits a region's span with its notes and channel events.
+pub type RegionVisitor<'a> = dyn FnMut(&NoteRegion, &EventCollection<NoteEvent>, &EventCollection<ControlEvent>) + 'a;
+
 pub trait NoteTrackAccess {
-    /// Visit each active note region overlapping `[from, to)` with its loopable span and its
-    /// region-local events; the sequencer resolves looping + retaining.
-    fn for_each_region(&self, from: f64, to: f64, visit: &mut dyn FnMut(&NoteRegion, &EventCollection<NoteEvent>));
+    /// Visit each active note region overlapping `[from, to)` with its loopable span, its region-local
+    /// notes and its region-local channel events; the sequencer resolves looping + retaining.
+    fn for_each_region(&self, from: f64, to: f64, visit: &mut RegionVisitor);
     /// A clip's live `(duration, looped)`; `None` when the clip vanished.
     fn clip_info(&self, clip: &[u8; 16]) -> Option<(f64, bool)>;
-    /// Visit a clip's note events; an absent clip visits nothing.
-    fn clip_events(&self, clip: &[u8; 16], visit: &mut dyn FnMut(&EventCollection<NoteEvent>));
+    /// Visit a clip's note events and channel events; an absent clip visits nothing.
+    fn clip_events(&self, clip: &[u8; 16], visit: &mut dyn FnMut(&EventCollection<NoteEvent>, &EventCollection<ControlEvent>));
 }
 
 pub trait NoteContentSource {
@@ -4,7 +4,7 @@
 //! no generators, so TS's `yield` becomes a sink callback, alloc-free and still pull-ordered.
 
 use crate::block_flags::BlockFlags;
-use crate::event::Event;
+use crate::event::{Control, Event};
 
 pub trait NoteEventSource {
     fn process_notes(&mut self, from: f64, to: f64, flags: BlockFlags, sink: &mut dyn FnMut(Event));
@@ -12,6 +12,9 @@ pub trait NoteEventSource {
     /// next block and sustains until its note-off. A source that cannot voice live notes ignores them.
     fn push_raw_note_on(&mut self, _pitch: u8, _velocity: f32) {}
     fn push_raw_note_off(&mut self, _pitch: u8) {}
+    /// A live channel control (a MIDI CC, the pitch wheel, channel pressure), emitted at the next block's start
+    /// like a raw note. A source that cannot voice live input ignores it.
+    fn push_raw_control(&mut self, _control: Control) {}
     /// A scheduled one-shot preview note with a fixed duration in pulses (TS `auditionNote`).
     fn audition_note(&mut self, _pitch: u8, _duration: f64, _velocity: f32) {}
 }
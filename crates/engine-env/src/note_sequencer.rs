@@ -6,7 +6,10 @@
 //!
 //! RAW notes (live MIDI / on-screen keys, TS `pushRawNoteOn/Off`) and AUDITION notes (fixed-duration
 //! previews, TS `auditionNote`) are emitted BEFORE the transport gate, so they sound while stopped too
-//! (the paused render keeps the pulse range advancing).
+//! (the paused render keeps the pulse range advancing). Live channel CONTROLS (MIDI CCs, the pitch wheel,
+//! channel pressure) take the same path: queued by `push_raw_control`, emitted at the next block's start.
+//! The channel events stored in a region or clip are emitted as `Event::Control` at their global
+//! position, looping with the notes (a muted region emits none).
 
 use alloc::boxed::Box;
 use alloc::rc::Rc;
@@ -14,13 +17,14 @@ use alloc::vec::Vec;
 use core::cell::{Cell, RefCell};
 use math::clamp;
 use math::random::Mulberry32;
+use value::control::{ControlEvent, ControlKind};
 use value::event::{EventCollection, EventSpan};
 use value::note::NoteEvent;
 use value::note::{curve_func, inverse_curve_func};
 use value::region::locate_loops;
 use value::retainer::EventSpanRetainer;
 use crate::block_flags::BlockFlags;
-use crate::event::Event;
+use crate::event::{Control, Event};
 use crate::clip_sequencer::{ClipInfo, ClipKey, ClipSequencer};
 use crate::note_event_source::NoteEventSource;
 use crate::note_content_source::{NoteContentSource, NoteTrackAccess};
@@ -72,6 +76,7 @@ pub struct NoteSequencer {
     clips: Rc<RefCell<ClipSequencer>>,
     retainer: EventSpanRetainer<RetainedNote>,
     raw_notes: Vec<RawNote>,
+    raw_controls: Vec<Control>,
     audition_queue: Vec<ScheduledNote>,
     audition_retainer: EventSpanRetainer<RetainedNote>,
     random: Mulberry32,
@@ -86,6 +91,7 @@ impl NoteSequencer {
             clips,
             retainer: EventSpanRetainer::new(),
             raw_notes: Vec::new(),
+            raw_controls: Vec::new(),
             audition_queue: Vec::new(),
             audition_retainer: EventSpanRetainer::new(),
             random: Mulberry32::new(CHANCE_SEED),
@@ -130,6 +136,11 @@ impl NoteEventSource for NoteSequencer {
                 sink(Event::NoteComplete {id: retained.id, position, pitch: retained.pitch});
             });
         }
+        // RAW controls, in arrival order, ahead of the raw notes (the consumer's sort puts them before note-ons
+        // at the same position anyway, so a key pressed with the pedal starts sustained).
+        for control in self.raw_controls.drain(..) {
+            sink(Event::Control {position: from, control});
+        }
         // RAW notes: start every note not yet running (infinite duration, released by its note-off), and
         // release + drop every gated-off note. Runs BEFORE the read gate, so live keys sound while stopped.
         let mut index = 0;
@@ -171,7 +182,7 @@ impl NoteEventSource for NoteSequencer {
             clips.iterate(track, from, to, &info, &mut |section| {
                 match section.clip {
                     // Timeline: the track's regions within the section (TS `#processRegions`).
-                    None => access.for_each_region(section.from, section.to, &mut |region, notes| {
+                    None => access.for_each_region(section.from, section.to, &mut |region, notes, controls| {
                         if region.mute {
                             return; // TS `#processRegions`: `region.mute -> continue` — a muted region emits no notes
                         }
@@ -182,20 +193,24 @@ impl NoteEventSource for NoteSequencer {
                             let local_from = cycle.result_start - cycle.raw_start;
                             let local_to = cycle.result_end - cycle.raw_start;
                             process_collection(notes, local_from, local_to, cycle.raw_start, end, retainer, random, next_id, sink);
+                            process_controls(controls, local_from, local_to, cycle.raw_start, sink);
                         }
                     }),
                     // A launched clip: its collection cycles at the CLIP duration (TS `#processClip`).
                     Some(clip) => {
                         let Some((clip_duration, _)) = access.clip_info(&clip) else { return };
-                        access.clip_events(&clip, &mut |notes| {
+                        access.clip_events(&clip, &mut |notes, controls| {
                             let clip_start = quantize_floor(section.from, clip_duration);
                             let clip_end = clip_start + clip_duration;
                             let truncate_end = if truncate { clip_duration } else { f64::INFINITY };
                             if section.to > clip_end {
                                 process_collection(notes, section.from - clip_start, clip_duration, clip_start, truncate_end, retainer, random, next_id, sink);
+                                process_controls(controls, section.from - clip_start, clip_duration, clip_start, sink);
                                 process_collection(notes, 0.0, section.to - clip_end, clip_end, truncate_end, retainer, random, next_id, sink);
+                                process_controls(controls, 0.0, section.to - clip_end, clip_end, sink);
                             } else {
                                 process_collection(notes, section.from - clip_start, section.to - clip_start, clip_start, truncate_end, retainer, random, next_id, sink);
+                                process_controls(controls, section.from - clip_start, section.to - clip_start, clip_start, sink);
                             }
                         });
                     }
@@ -230,6 +245,10 @@ impl NoteEventSource for NoteSequencer {
         }
     }
 
+    fn push_raw_control(&mut self, control: Control) {
+        self.raw_controls.push(control);
+    }
+
     fn audition_note(&mut self, pitch: u8, duration: f64, velocity: f32) {
         self.audition_queue.push(ScheduledNote {pitch, duration, velocity});
     }
@@ -319,6 +338,19 @@ fn process_collection(notes: &EventCollection<NoteEvent>, local_from: f64, local
     }
 }
 
+/// Emit the channel events in `[local_from, local_to)` of a collection placed at `delta`.
+fn process_controls(controls: &EventCollection<ControlEvent>, local_from: f64, local_to: f64, delta: f64,
+                    sink: &mut dyn FnMut(Event)) {
+    for event in controls.iterate_range(local_from, local_to) {
+        let control = match event.kind {
+            ControlKind::Change(controller) => Control::Change {controller, value: event.value},
+            ControlKind::PitchBend => Control::PitchBend {value: event.value},
+            ControlKind::Pressure => Control::Pressure {value: event.value}
+        };
+        sink(Event::Control {position: delta + event.position, control});
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use alloc::vec::Vec;
@@ -390,6 +422,19 @@ mod tests {
         assert!(matches!(events[0], Event::NoteStart {position, pitch: 72, ..} if position == 100.0));
     }
 
+    #[test]
+    fn raw_controls_emit_once_at_the_next_block_start() {
+        let mut sequencer = sequencer();
+        sequencer.push_raw_control(Control::Change {controller: 64, value: 1.0});
+        sequencer.push_raw_control(Control::PitchBend {value: -0.5});
+        let events = collect(&mut sequencer, 40.0, 45.0, stopped());
+        assert_eq!(events, vec![
+            Event::Control {position: 40.0, control: Control::Change {controller: 64, value: 1.0}},
+            Event::Control {position: 40.0, control: Control::PitchBend {value: -0.5}}
+        ]);
+        assert!(collect(&mut sequencer, 45.0, 50.0, stopped()).is_empty(), "a control is emitted once");
+    }
+
     #[test]
     fn audition_note_plays_for_its_duration() {
         let mut sequencer = sequencer();
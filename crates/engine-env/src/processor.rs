@@ -1,10 +1,25 @@
 //! A graph node (`Processor` in TS): renders one quantum's blocks and can be reset. Extends
 //! `EventReceiver` because every processor has an event input.
 
+use crate::engine_context::NodeId;
 use crate::event_receiver::EventReceiver;
 use crate::process_info::ProcessInfo;
 
 pub trait Processor: EventReceiver {
     fn reset(&mut self);
     fn process(&mut self, info: &ProcessInfo);
+
+    /// The delay in samples this node adds to the signal passing through it (a look-ahead device). Read by
+    /// `EngineContext::update_latencies`, outside render.
+    fn latency(&self) -> u32 {
+        0
+    }
+
+    /// Delay compensation for a node that MERGES several paths: `upstream(node)` is the latency an upstream
+    /// node's output carries. The node delays each of its inputs up to the latest one and returns that aligned
+    /// input latency. `None` (the default) for a serial node, whose input latency is the max over its
+    /// predecessors.
+    fn compensate(&mut self, _upstream: &dyn Fn(NodeId) -> u32) -> Option<u32> {
+        None
+    }
 }
@@ -58,7 +58,8 @@ impl AudioProcessor for Recorder {
     fn handle_event(&mut self, event: &Event) {
         match event {
             Event::NoteStart {id, ..} | Event::NoteComplete {id, ..} => self.log.push(Step::Note(*id)),
-            Event::Update {..} => unreachable!("updates go to update_parameters")
+            Event::Update {..} => unreachable!("updates go to update_parameters"),
+            Event::Control {..} => unreachable!("these tests carry no channel controls")
         }
     }
     fn introduce_block(&mut self, block: &Block) {
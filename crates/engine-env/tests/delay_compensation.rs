@@ -0,0 +1,209 @@
+//! Plugin delay compensation: the delay line itself, the per-node latency accumulation over the sorted graph,
+//! and the two merging nodes (a bus sum, an effect composite's dry/wet mix) holding an early path back so a
+//! latent parallel path stays sample-aligned with the dry one.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use engine_env::audio_buffer::{shared_audio_buffer, AudioBuffer, SharedAudioBuffer};
+use engine_env::audio_bus_processor::AudioBusProcessor;
+use engine_env::audio_generator::AudioGenerator;
+use engine_env::channel_strip::StripAutomation;
+use engine_env::composite_mix::{DryWetMixProcessor, DryWetParams};
+use engine_env::delay_compensation::CompensationDelay;
+use engine_env::engine_context::EngineContext;
+use engine_env::event_buffer::EventBuffer;
+use engine_env::event_receiver::EventReceiver;
+use engine_env::process_info::ProcessInfo;
+use engine_env::processor::Processor;
+use engine_env::RENDER_QUANTUM;
+
+const SR: f32 = 48_000.0;
+
+// Writes a unit impulse at sample 0 of its first quantum, silence after.
+struct Impulse {
+    output: SharedAudioBuffer,
+    fired: bool,
+    events: EventBuffer
+}
+
+impl EventReceiver for Impulse {
+    fn event_input(&mut self) -> &mut EventBuffer {
+        &mut self.events
+    }
+}
+
+impl Processor for Impulse {
+    fn reset(&mut self) {}
+    fn process(&mut self, _info: &ProcessInfo) {
+        let mut output = self.output.borrow_mut();
+        output.clear();
+        if !self.fired {
+            output.left[0] = 1.0;
+            output.right[0] = 1.0;
+            self.fired = true;
+        }
+    }
+}
+
+// A look-ahead stand-in: delays its input by `latency` samples and reports it.
+struct Latent {
+    input: SharedAudioBuffer,
+    output: SharedAudioBuffer,
+    line: CompensationDelay,
+    events: EventBuffer
+}
+
+impl EventReceiver for Latent {
+    fn event_input(&mut self) -> &mut EventBuffer {
+        &mut self.events
+    }
+}
+
+impl Processor for Latent {
+    fn reset(&mut self) {}
+    fn process(&mut self, _info: &ProcessInfo) {
+        self.line.process(&self.input.borrow(), &mut self.output.borrow_mut());
+    }
+    fn latency(&self) -> u32 {
+        self.line.delay()
+    }
+}
+
+fn impulse() -> (Rc<RefCell<Impulse>>, SharedAudioBuffer) {
+    let output = shared_audio_buffer();
+    (Rc::new(RefCell::new(Impulse {output: output.clone(), fired: false, events: EventBuffer::new()})), output)
+}
+
+fn latent(input: &SharedAudioBuffer, latency: u32) -> (Rc<RefCell<Latent>>, SharedAudioBuffer) {
+    let output = shared_audio_buffer();
+    let mut line = CompensationDelay::new();
+    line.set_delay(latency);
+    (Rc::new(RefCell::new(Latent {input: input.clone(), output: output.clone(), line, events: EventBuffer::new()})), output)
+}
+
+// The (quantum, sample) positions of every non-zero left sample over `quanta` renders of `buffer`.
+fn render_hits(context: &mut EngineContext, buffer: &SharedAudioBuffer, quanta: usize) -> Vec<(usize, usize, f32)> {
+    let mut hits = Vec::new();
+    for quantum in 0..quanta {
+        context.process(&ProcessInfo {blocks: &[]});
+        for (index, &sample) in buffer.borrow().left.iter().enumerate() {
+            if sample != 0.0 {
+                hits.push((quantum, index, sample));
+            }
+        }
+    }
+    hits
+}
+
+#[test]
+fn the_delay_line_shifts_a_signal_across_quanta() {
+    let mut line = CompensationDelay::new();
+    line.set_delay(200);
+    let mut input = AudioBuffer::new();
+    input.left[5] = 1.0;
+    let mut output = AudioBuffer::new();
+    line.process(&input, &mut output);
+    assert!(output.left.iter().all(|&sample| sample == 0.0), "nothing arrives within the delay");
+    line.process(&AudioBuffer::new(), &mut output);
+    assert_eq!(output.left[205 - RENDER_QUANTUM], 1.0, "the impulse lands 200 samples later");
+    line.clear();
+    assert_eq!(line.delay(), 200, "clearing keeps the length");
+}
+
+#[test]
+fn a_zero_delay_is_a_pass_through() {
+    let mut line = CompensationDelay::new();
+    let mut input = AudioBuffer::new();
+    input.right[7] = 0.5;
+    let mut output = AudioBuffer::new();
+    output.right[7] = 0.25;
+    line.add_into(&input, &mut output);
+    assert_eq!(output.right[7], 0.75);
+}
+
+#[test]
+fn latency_accumulates_along_a_serial_chain() {
+    let mut context = EngineContext::new();
+    let (source, source_out) = impulse();
+    let (first, first_out) = latent(&source_out, 10);
+    let (second, _) = latent(&first_out, 32);
+    let source_id = context.register_processor(source);
+    let first_id = context.register_processor(first);
+    let second_id = context.register_processor(second);
+    context.register_edge(source_id, first_id);
+    context.register_edge(first_id, second_id);
+    context.update_latencies();
+    assert_eq!(context.latency_of(source_id), 0);
+    assert_eq!(context.latency_of(first_id), 10);
+    assert_eq!(context.latency_of(second_id), 42);
+}
+
+#[test]
+fn a_bus_holds_the_dry_source_back_to_the_latent_one() {
+    let mut context = EngineContext::new();
+    let (source, source_out) = impulse();
+    let (effect, effect_out) = latent(&source_out, 150);
+    let bus = Rc::new(RefCell::new(AudioBusProcessor::new(shared_audio_buffer())));
+    let bus_out = bus.borrow().audio_output();
+    let source_id = context.register_processor(source);
+    let effect_id = context.register_processor(effect);
+    let bus_id = context.register_processor(bus.clone());
+    context.register_edge(source_id, effect_id);
+    context.register_edge(source_id, bus_id);
+    context.register_edge(effect_id, bus_id);
+    bus.borrow_mut().add_audio_source(source_out.clone(), source_id);
+    bus.borrow_mut().add_audio_source(effect_out.clone(), effect_id);
+    context.update_latencies();
+    assert_eq!(bus.borrow().source_delay(&source_out), 150, "the dry path is delayed by the effect's latency");
+    assert_eq!(bus.borrow().source_delay(&effect_out), 0, "the latest path is not delayed");
+    assert_eq!(context.latency_of(bus_id), 150, "the bus output carries the aligned latency downstream");
+    // Both impulses arrive at once: one coherent 2.0 sample instead of two separate 1.0s.
+    assert_eq!(render_hits(&mut context, &bus_out, 3), vec![(1, 150 - RENDER_QUANTUM, 2.0)]);
+}
+
+#[test]
+fn removing_the_latent_source_releases_the_compensation() {
+    let mut context = EngineContext::new();
+    let (source, source_out) = impulse();
+    let (effect, effect_out) = latent(&source_out, 64);
+    let bus = Rc::new(RefCell::new(AudioBusProcessor::new(shared_audio_buffer())));
+    let source_id = context.register_processor(source);
+    let effect_id = context.register_processor(effect);
+    let bus_id = context.register_processor(bus.clone());
+    context.register_edge(source_id, effect_id);
+    context.register_edge(source_id, bus_id);
+    context.register_edge(effect_id, bus_id);
+    bus.borrow_mut().add_audio_source(source_out.clone(), source_id);
+    bus.borrow_mut().add_audio_source(effect_out.clone(), effect_id);
+    context.update_latencies();
+    assert_eq!(bus.borrow().source_delay(&source_out), 64);
+    bus.borrow_mut().remove_audio_source(&effect_out);
+    context.remove_edge(effect_id, bus_id);
+    context.remove_processor(effect_id);
+    context.update_latencies();
+    assert_eq!(bus.borrow().source_delay(&source_out), 0);
+    assert_eq!(context.latency_of(bus_id), 0);
+}
+
+#[test]
+fn the_dry_wet_mix_aligns_the_tap_with_a_latent_wet_path() {
+    let mut context = EngineContext::new();
+    let (source, tap) = impulse();
+    let (effect, wet) = latent(&tap, 40);
+    let params = Rc::new(DryWetParams::new());
+    params.bypass.set(false);
+    params.dry_db.set(0.0);
+    let mix = Rc::new(RefCell::new(DryWetMixProcessor::new(params, Rc::new(StripAutomation::new()), tap, wet, SR)));
+    let mix_out = mix.borrow().audio_output();
+    let source_id = context.register_processor(source);
+    let effect_id = context.register_processor(effect);
+    let mix_id = context.register_processor(mix.clone());
+    context.register_edge(source_id, effect_id);
+    context.register_edge(source_id, mix_id);
+    context.register_edge(effect_id, mix_id);
+    mix.borrow_mut().set_input_nodes(source_id, effect_id);
+    context.update_latencies();
+    assert_eq!(context.latency_of(mix_id), 40);
+    assert_eq!(render_hits(&mut context, &mix_out, 2), vec![(0, 40, 2.0)], "dry and wet sum coherently");
+}
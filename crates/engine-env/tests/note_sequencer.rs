@@ -7,11 +7,12 @@ use std::cell::RefCell;
 use std::rc::Rc;
 use engine_env::block_flags::BlockFlags;
 use engine_env::clip_sequencer::ClipSequencer;
-use engine_env::event::Event;
+use engine_env::event::{Control, Event};
 use engine_env::note_event_source::NoteEventSource;
 use engine_env::note_region::NoteRegion;
-use engine_env::note_content_source::{NoteContentSource, NoteTrackAccess};
+use engine_env::note_content_source::{NoteContentSource, NoteTrackAccess, RegionVisitor};
 use engine_env::note_sequencer::NoteSequencer;
+use value::control::{ControlEvent, ControlKind};
 use value::event::EventCollection;
 use value::note::NoteEvent;
 
@@ -19,19 +20,20 @@ const TRACK: [u8; 16] = [1; 16];
 
 struct OneRegion {
     region: NoteRegion,
-    notes: EventCollection<NoteEvent>
+    notes: EventCollection<NoteEvent>,
+    controls: EventCollection<ControlEvent>
 }
 
 impl NoteTrackAccess for OneRegion {
-    fn for_each_region(&self, from: f64, to: f64, visit: &mut dyn FnMut(&NoteRegion, &EventCollection<NoteEvent>)) {
+    fn for_each_region(&self, from: f64, to: f64, visit: &mut RegionVisitor) {
         if self.region.position < to && self.region.complete() > from {
-            visit(&self.region, &self.notes)
+            visit(&self.region, &self.notes, &self.controls)
         }
     }
     fn clip_info(&self, _clip: &[u8; 16]) -> Option<(f64, bool)> {
         None
     }
-    fn clip_events(&self, _clip: &[u8; 16], _visit: &mut dyn FnMut(&EventCollection<NoteEvent>)) {}
+    fn clip_events(&self, _clip: &[u8; 16], _visit: &mut dyn FnMut(&EventCollection<NoteEvent>, &EventCollection<ControlEvent>)) {}
 }
 
 impl NoteContentSource for OneRegion {
@@ -45,7 +47,7 @@ fn sequencer(region: NoteRegion, notes: &[NoteEvent]) -> NoteSequencer {
     for note in notes {
         collection.add(*note);
     }
-    NoteSequencer::new(Box::new(OneRegion {region, notes: collection}), Rc::new(RefCell::new(ClipSequencer::new())))
+    NoteSequencer::new(Box::new(OneRegion {region, notes: collection, controls: EventCollection::new()}), Rc::new(RefCell::new(ClipSequencer::new())))
 }
 
 fn pull(sequencer: &mut NoteSequencer, from: f64, to: f64) -> Vec<Event> {
@@ -187,6 +189,33 @@ fn a_ratchet_spanning_blocks_retriggers_via_the_lookback() {
     assert_eq!(starts, vec![80.0, 120.0], "the lookback finds the running ratchet: {second:?}");
 }
 
+#[test]
+fn region_controls_are_emitted_at_their_global_positions_and_loop() {
+    let mut controls = EventCollection::new();
+    controls.add(ControlEvent {position: 10.0, kind: ControlKind::PitchBend, value: -0.5});
+    controls.add(ControlEvent {position: 60.0, kind: ControlKind::Change(74), value: 0.25});
+    let region = NoteRegion {position: 200.0, duration: 200.0, loop_offset: 0.0, loop_duration: 100.0, mute: false};
+    let source = OneRegion {region, notes: EventCollection::new(), controls};
+    let mut sequencer = NoteSequencer::new(Box::new(source), Rc::new(RefCell::new(ClipSequencer::new())));
+    let events = pull(&mut sequencer, 0.0, 480.0);
+    assert_eq!(events, vec![
+        Event::Control {position: 210.0, control: Control::PitchBend {value: -0.5}},
+        Event::Control {position: 260.0, control: Control::Change {controller: 74, value: 0.25}},
+        Event::Control {position: 310.0, control: Control::PitchBend {value: -0.5}},
+        Event::Control {position: 360.0, control: Control::Change {controller: 74, value: 0.25}}
+    ]);
+}
+
+#[test]
+fn a_muted_region_emits_no_controls() {
+    let mut controls = EventCollection::new();
+    controls.add(ControlEvent {position: 10.0, kind: ControlKind::Pressure, value: 1.0});
+    let region = NoteRegion {position: 0.0, duration: 100.0, loop_offset: 0.0, loop_duration: 100.0, mute: true};
+    let source = OneRegion {region, notes: EventCollection::new(), controls};
+    let mut sequencer = NoteSequencer::new(Box::new(source), Rc::new(RefCell::new(ClipSequencer::new())));
+    assert!(pull(&mut sequencer, 0.0, 100.0).is_empty());
+}
+
 struct TrackWithClip {
     region: NoteRegion,
     region_notes: EventCollection<NoteEvent>,
@@ -195,17 +224,17 @@ struct TrackWithClip {
 }
 
 impl NoteTrackAccess for TrackWithClip {
-    fn for_each_region(&self, from: f64, to: f64, visit: &mut dyn FnMut(&NoteRegion, &EventCollection<NoteEvent>)) {
+    fn for_each_region(&self, from: f64, to: f64, visit: &mut RegionVisitor) {
         if self.region.position < to && self.region.complete() > from {
-            visit(&self.region, &self.region_notes)
+            visit(&self.region, &self.region_notes, &EventCollection::new())
         }
     }
     fn clip_info(&self, clip: &[u8; 16]) -> Option<(f64, bool)> {
         (clip == &self.clip).then_some((960.0, true))
     }
-    fn clip_events(&self, clip: &[u8; 16], visit: &mut dyn FnMut(&EventCollection<NoteEvent>)) {
+    fn clip_events(&self, clip: &[u8; 16], visit: &mut dyn FnMut(&EventCollection<NoteEvent>, &EventCollection<ControlEvent>)) {
         if clip == &self.clip {
-            visit(&self.clip_notes)
+            visit(&self.clip_notes, &EventCollection::new())
         }
     }
 }
@@ -485,12 +485,12 @@ fn render_region(output: &mut AudioBuffer, region: &AudioRegion, left: &[f32], r
             if base >= source_frames {
                 break; // ran past the end of the source
             }
-            let frac = (frame - base as f64) as f32;
             let pulse = block.p0 + (index as f64 - block.s0 as f64) / samples * pulses;
             let envelope = fade_gain(pulse - region.position, region.duration, region, declick_pulses, declick_in);
             let scale = gain * envelope;
-            output.left[index] += interpolate(left, base, frac) * scale;
-            output.right[index] += interpolate(right, base, frac) * scale;
+            let (sample_left, sample_right) = region.interpolation.read_stereo(left, right, frame, rate);
+            output.left[index] += sample_left * scale;
+            output.right[index] += sample_right * scale;
         }
         // Advance the free-running cursor by this cycle's FULL span (even if the render broke early at EOF), so a
         // continuation next block reads from the right place. Only the no-stretch path uses the cursor.
@@ -509,13 +509,6 @@ fn sample_of(block: &Block, pulse: f64, pulses: f64, samples: f64) -> usize {
     (block.s0 as f64 + samples * ratio).clamp(block.s0 as f64, block.s1 as f64) as usize
 }
 
-/// Linear interpolation of a planar source at a fractional frame; reads 0.0 past the end (TS `inp[i + 1] ?? 0`).
-fn interpolate(buffer: &[f32], index: usize, frac: f32) -> f32 {
-    let here = buffer.get(index).copied().unwrap_or(0.0);
-    let next = buffer.get(index + 1).copied().unwrap_or(0.0);
-    here * (1.0 - frac) + next * frac
-}
-
 /// The region's fade gain at `position` pulses into it: the lesser of the start- and end-edge envelopes. Each
 /// edge uses the AUTHORED fade when present (TS `FadingEnvelope.gainAt`, slope-shaped), else a short boundary
 /// DECLICK of `declick_pulses` (~20 ms) so a region boundary does not hard-cut into a click — the engine analog
@@ -580,12 +573,13 @@ mod tests {
     use alloc::vec;
     use alloc::vec::Vec;
     use engine_env::block_flags::BlockFlags;
+    use dsp::interpolator::Interpolation;
 
     fn region(gain_db: f32, fade_in: f64, fade_out: f64) -> AudioRegion {
         AudioRegion {
             region_uuid: [1u8; 16], position: 0.0, duration: 96_000.0, loop_offset: 0.0, loop_duration: 96_000.0,
             file: [9u8; 16], gain_db, mute: false, waveform_offset: 0.0, fade_in, fade_out,
-            fade_in_slope: 0.5, fade_out_slope: 0.5, warp: Vec::new(), time_stretch: None, signalsmith: None, transients: Vec::new()
+            fade_in_slope: 0.5, fade_out_slope: 0.5, interpolation: Interpolation::Linear, warp: Vec::new(), time_stretch: None, signalsmith: None, transients: Vec::new()
         }
     }
 
@@ -646,6 +640,22 @@ mod tests {
         }
     }
 
+    #[test]
+    fn a_sinc_region_resamples_a_bright_source_cleanly() {
+        // a 10 kHz tone recorded at 44.1 kHz played at 48 kHz: linear smears it, the windowed sinc follows it
+        let tone = |i: f64, rate: f64| (2.0 * core::f64::consts::PI * 10_000.0 * i / rate).sin() as f32;
+        let source: Vec<f32> = (0..256).map(|i| tone(i as f64, 44_100.0)).collect();
+        let worst = |interpolation: Interpolation| {
+            let mut output = AudioBuffer::new();
+            let region = AudioRegion {interpolation, ..region(0.0, 0.0, 0.0)};
+            render_region(&mut output, &region, &source, &source, 44_100.0, block().p0, block().p1, &block(), 48_000.0, &TempoMap::fixed(120.0), &mut NativeCursor::new());
+            // past the kernel's reach into the silence before frame 0
+            (16..64).map(|i| (output.left[i] - tone(i as f64, 48_000.0)).abs()).fold(0.0f32, f32::max)
+        };
+        assert!(worst(Interpolation::Linear) > 0.05, "linear {}", worst(Interpolation::Linear));
+        assert!(worst(Interpolation::Sinc) < 0.01, "sinc {}", worst(Interpolation::Sinc));
+    }
+
     #[test]
     fn applies_region_gain_in_decibels() {
         let source = vec![1.0f32; 128];
@@ -46,9 +46,10 @@ use engine_env::engine_context::NodeId;
 use engine_env::note_event_instrument::SharedNoteEventSource;
 use engine_env::note_region::NoteRegion;
 use engine_env::clip_sequencer::ClipSequencer;
-use engine_env::note_content_source::{NoteContentSource, NoteTrackAccess};
+use engine_env::note_content_source::{NoteContentSource, NoteTrackAccess, RegionVisitor};
 use engine_env::note_sequencer::NoteSequencer;
 use value::event::EventCollection;
+use value::control::ControlEvent;
 use value::note::NoteEvent;
 use value::region::{RegionCollection, Span};
 use crate::param_automation::{BoundValueClip, FieldPath, ParamCurve, ParamHandle, ParamSink, ValueBoundRegion};
@@ -462,7 +463,7 @@ pub(crate) struct BuiltCluster {
 pub(crate) struct DeviceParams {
     pub(crate) device_uuid: Uuid,
     pub(crate) reg: DeviceReg,
-    pub(crate) state_ptr: u32,
+    pub(crate) state_ptr: usize,
     pub(crate) sink: ParamNode,       // the node, to re-set params on a re-bind
     pub(crate) paths: Vec<FieldPath>, // the parameter field-paths the device declared in `init`
     pub(crate) handles: Vec<ParamHandle>,
@@ -733,6 +734,10 @@ impl Engine {
         if self.solo_dirty.replace(false) {
             self.update_solo();
         }
+        // Delay compensation re-aligns the parallel paths over the (possibly new) wiring. It runs on EVERY pass,
+        // not only after work: a device's latency can follow a plain observed field (a look-ahead toggle) that
+        // enqueues no unit. Cheap when nothing moved — only a delay line whose length changed re-allocates.
+        self.context.update_latencies();
     }
 
     /// Resolve SOLO into per-strip `forced_silent` flags, mirroring TS `Mixer.updateSolo` + the strip's
@@ -1,5 +1,6 @@
 use super::*;
 use super::tracks::{TRACK_TARGET_KEY, TRACK_REGIONS_KEY, TRACK_CLIPS_KEY, TRACK_ENABLED_KEY, track_enabled, value_regions_of_track, value_clips_of_track};
+use boxgraph::field::FieldType;
 
 // The LIGHT per-unit signal a plain FIELD edit raises while `reconcile_one` runs (set around the unit's
 // work, the `CURRENT_DEVICE_UUID` pattern): a knob drag then only marks `params_dirty` (one value push at
@@ -47,7 +48,7 @@ pub(crate) fn automation_invalidate(unit: &AudioUnitBinding) -> Rc<dyn Fn()> {
 /// Call a device's `init(state_ptr, sample_rate)` to collect the parameter field-paths it declares (it binds
 /// them via `host_bind_parameter`, which records into `BIND`) and let it stash the sample rate. Touches no
 /// graph, so it is a free fn.
-pub(crate) fn bind_paths(reg: DeviceReg, state_ptr: u32, sample_rate: f32) -> Vec<FieldPath> {
+pub(crate) fn bind_paths(reg: DeviceReg, state_ptr: usize, sample_rate: f32) -> Vec<FieldPath> {
     unsafe { BIND.get() }.clear();
     unsafe { BROADCAST_BINDS.get() }.clear();
     unsafe { SAMPLE_OBS.get() }.clear();
@@ -76,7 +77,7 @@ pub(crate) fn refresh_member(member: &Member, position: f64) {
 /// `updateAutomation` compare). The `kind` tag tells the device how to read the value (uniform automation to
 /// map, or a real Int / Float / Bool field value). Called at build (every param, `last` is NaN) and on a
 /// runtime edit / field change. Never during render.
-pub(crate) fn refresh_params(handles: &[ParamHandle], reg: DeviceReg, state_ptr: u32, position: f64) {
+pub(crate) fn refresh_params(handles: &[ParamHandle], reg: DeviceReg, state_ptr: usize, position: f64) {
     for handle in handles {
         let (value, kind) = handle.resolve(position);
         if value != handle.last.get() {
@@ -90,7 +91,7 @@ pub(crate) fn refresh_params(handles: &[ParamHandle], reg: DeviceReg, state_ptr:
 /// handle when the `file` pointer targets an `AudioFileBox` (the frames are requested through `SAMPLES`), or
 /// "unbound" (`present = 0`) when the pointer has no target (cleared). Touches `SAMPLES` (its own cell) and the
 /// device, never `&mut Engine`, so it is safe from a transaction observer.
-pub(crate) fn resolve_and_deliver_sample(graph: &BoxGraph, device_uuid: Uuid, path: &[u16], sample_changed_index: u32, state_ptr: u32, id: u32) {
+pub(crate) fn resolve_and_deliver_sample(graph: &BoxGraph, device_uuid: Uuid, path: &[u16], sample_changed_index: u32, state_ptr: usize, id: u32) {
     match graph.target_of(&Address::of(device_uuid, path.to_vec())) {
         Some(target) => {
             let handle = unsafe { SAMPLES.get() }.request(target.uuid);
@@ -104,7 +105,7 @@ pub(crate) fn resolve_and_deliver_sample(graph: &BoxGraph, device_uuid: Uuid, pa
 /// handle when the `file` pointer targets a `SoundfontFileBox` (the blob is requested through `SOUNDFONTS`), or
 /// "unbound" (`present = 0`) when the pointer has no target. Touches `SOUNDFONTS` (its own cell) and the device,
 /// never `&mut Engine`. Mirrors `resolve_and_deliver_sample`.
-pub(crate) fn resolve_and_deliver_soundfont(graph: &BoxGraph, device_uuid: Uuid, path: &[u16], soundfont_changed_index: u32, state_ptr: u32, id: u32) {
+pub(crate) fn resolve_and_deliver_soundfont(graph: &BoxGraph, device_uuid: Uuid, path: &[u16], soundfont_changed_index: u32, state_ptr: usize, id: u32) {
     match graph.target_of(&Address::of(device_uuid, path.to_vec())) {
         Some(target) => {
             let handle = unsafe { SOUNDFONTS.get() }.request(target.uuid);
@@ -116,15 +117,15 @@ pub(crate) fn resolve_and_deliver_soundfont(graph: &BoxGraph, device_uuid: Uuid,
 
 /// Encode a field's typed value onto the `field_changed` wire `(kind, bits, len)`: numeric bits, or a
 /// string's pointer + length into the shared memory (valid for the synchronous call).
-pub(crate) fn deliver_field_value(value: &FieldValue, field_changed_index: u32, state_ptr: u32, id: u32) {
+pub(crate) fn deliver_field_value(value: &FieldValue, field_changed_index: u32, state_ptr: usize, id: u32) {
     if let Some(value) = value.as_int32() {
-        call_device_field_changed(field_changed_index, state_ptr, id, FIELD_KIND_INT, value as u32, 0);
+        call_device_field_changed(field_changed_index, state_ptr, id, FIELD_KIND_INT, value as u32 as usize, 0);
     } else if let Some(value) = value.as_float32() {
-        call_device_field_changed(field_changed_index, state_ptr, id, FIELD_KIND_FLOAT, value.to_bits(), 0);
+        call_device_field_changed(field_changed_index, state_ptr, id, FIELD_KIND_FLOAT, value.to_bits() as usize, 0);
     } else if let Some(value) = value.as_bool() {
-        call_device_field_changed(field_changed_index, state_ptr, id, FIELD_KIND_BOOL, value as u32, 0);
+        call_device_field_changed(field_changed_index, state_ptr, id, FIELD_KIND_BOOL, value as usize, 0);
     } else if let Some(value) = value.as_str() {
-        call_device_field_changed(field_changed_index, state_ptr, id, FIELD_KIND_STRING, value.as_ptr() as u32, value.len() as u32);
+        call_device_field_changed(field_changed_index, state_ptr, id, FIELD_KIND_STRING, value.as_ptr() as usize, value.len() as u32);
     }
 }
 
@@ -133,7 +134,7 @@ pub(crate) fn deliver_field_value(value: &FieldValue, field_changed_index: u32,
 /// same-transaction "repoint, then the OLD target's field edited": the swap-out of this subscription is
 /// deferred (applied after dispatch), so it can still fire within the repointing transaction and must not
 /// overwrite the new target's delivered value.
-pub(crate) fn pointer_target_field_observer(pointer: Address, target_uuid: Uuid, field_changed_index: u32, state_ptr: u32, id: u32) -> UpdateObserver {
+pub(crate) fn pointer_target_field_observer(pointer: Address, target_uuid: Uuid, field_changed_index: u32, state_ptr: usize, id: u32) -> UpdateObserver {
     Box::new(move |graph, update| {
         if let Update::Primitive {new, ..} = update {
             if graph.target_of(&pointer).map(|target| target.uuid) == Some(target_uuid) {
@@ -148,12 +149,12 @@ pub(crate) fn pointer_target_field_observer(pointer: Address, target_uuid: Uuid,
 /// or the target lacks that string field. The delivered ptr/len reference the live box-graph string, valid for
 /// the synchronous call (the device copies or forwards it before returning). Mirrors
 /// `resolve_and_deliver_soundfont`, but needs no resource handshake: the payload already lives in the graph.
-pub(crate) fn resolve_and_deliver_target_string(graph: &BoxGraph, device_uuid: Uuid, path: &[u16], target_key: u16, field_changed_index: u32, state_ptr: u32, id: u32) {
+pub(crate) fn resolve_and_deliver_target_string(graph: &BoxGraph, device_uuid: Uuid, path: &[u16], target_key: u16, field_changed_index: u32, state_ptr: usize, id: u32) {
     let text = graph.target_of(&Address::of(device_uuid, path.to_vec()))
         .and_then(|target| graph.field_value(&Address::of(target.uuid, vec![target_key])))
         .and_then(|value| value.as_str())
         .unwrap_or("");
-    call_device_field_changed(field_changed_index, state_ptr, id, FIELD_KIND_STRING, text.as_ptr() as u32, text.len() as u32);
+    call_device_field_changed(field_changed_index, state_ptr, id, FIELD_KIND_STRING, text.as_ptr() as usize, text.len() as u32);
 }
 
 /// The automation curve for a device parameter, if a Value track targets `(device_uuid, path)`: build a
@@ -220,12 +221,14 @@ pub(crate) fn build_param_track(graph: &mut BoxGraph, device_uuid: Uuid, path: &
 pub(crate) enum NoteSignal {
     On {pitch: u8, velocity: f32},
     Off {pitch: u8},
-    Audition {pitch: u8, duration: f64, velocity: f32}
+    Audition {pitch: u8, duration: f64, velocity: f32},
+    Control(engine_env::event::Control)
 }
 
 /// Route a live note signal to the unit's note sources: the leaf sequencer, or every composite SLOT's
 /// sequencer (each slot pulls independently; its device filters by pad note). Tape / bus units have none.
-/// Mirrors TS `EngineProcessor.noteSignal` -> `NoteSequencer.pushRawNoteOn/Off/auditionNote`.
+/// Mirrors TS `EngineProcessor.noteSignal` -> `NoteSequencer.pushRawNoteOn/Off/auditionNote`; a channel
+/// control rides along to the same sources.
 pub(crate) fn note_signal_to_unit(unit: &AudioUnitBinding, signal: NoteSignal) {
     let mut sources: Vec<SharedNoteEventSource> = Vec::new();
     match unit.wired.as_ref() {
@@ -239,7 +242,8 @@ pub(crate) fn note_signal_to_unit(unit: &AudioUnitBinding, signal: NoteSignal) {
         match signal {
             NoteSignal::On {pitch, velocity} => source.push_raw_note_on(pitch, velocity),
             NoteSignal::Off {pitch} => source.push_raw_note_off(pitch),
-            NoteSignal::Audition {pitch, duration, velocity} => source.audition_note(pitch, duration, velocity)
+            NoteSignal::Audition {pitch, duration, velocity} => source.audition_note(pitch, duration, velocity),
+            NoteSignal::Control(control) => source.push_raw_control(control)
         }
     }
 }
@@ -339,7 +343,7 @@ impl Engine {
     /// Bind one device's parameters: call its `init` (which records its parameter field-paths via
     /// `host_bind_parameter`), observe each path's field value + automation track, hand the node its
     /// parameter set, and return the bookkeeping for teardown / re-bind.
-    pub(crate) fn bind_device(&mut self, device_uuid: Uuid, reg: DeviceReg, state_ptr: u32, sink: ParamNode, invalidate: &Rc<dyn Fn()>) -> DeviceParams {
+    pub(crate) fn bind_device(&mut self, device_uuid: Uuid, reg: DeviceReg, state_ptr: usize, sink: ParamNode, invalidate: &Rc<dyn Fn()>) -> DeviceParams {
         // Make the device's own box uuid available to `host_self_uuid` for the duration of its `init` (a script
         // device reads it there to key its JS-side bridge); the engine knows it, the device does not.
         unsafe { *CURRENT_DEVICE_UUID.get() = device_uuid; }
@@ -364,7 +368,7 @@ impl Engine {
                 _ => crate::broadcast::PACKAGE_FLOAT_ARRAY
             };
             self.broadcasts.register(device_uuid, &path, package_type, &slot);
-            let ptr = slot.borrow().as_ptr() as u32;
+            let ptr = slot.borrow().as_ptr() as usize;
             if let Some(entry) = unsafe { DEVICE_BROADCASTS.get() }.get_mut(id as usize) {
                 *entry = (ptr, false);
             }
@@ -412,7 +416,7 @@ impl Engine {
     /// run on catch-up and on edits, only inside a transaction, never during render, so calling the device is
     /// safe. Returns the fixed subscriptions plus the pointer-crossing observations' swappable target-field
     /// subscription cells, both for teardown.
-    pub(crate) fn observe_fields(&mut self, device_uuid: Uuid, reg: DeviceReg, state_ptr: u32, paths: &[FieldObs]) -> (Vec<SubscriptionId>, Vec<Rc<Cell<Option<SubscriptionId>>>>) {
+    pub(crate) fn observe_fields(&mut self, device_uuid: Uuid, reg: DeviceReg, state_ptr: usize, paths: &[FieldObs]) -> (Vec<SubscriptionId>, Vec<Rc<Cell<Option<SubscriptionId>>>>) {
         let mut subs = Vec::new();
         let mut pointer_subs = Vec::new();
         for (index, obs) in paths.iter().enumerate() {
@@ -476,7 +480,7 @@ impl Engine {
     /// target and subscribe to that pointer field, so a set / repoint / clear (inside a transaction, never
     /// during render) re-resolves and re-delivers through the device's `sample_changed` export. Returns the
     /// subscriptions for teardown.
-    pub(crate) fn observe_samples(&mut self, device_uuid: Uuid, reg: DeviceReg, state_ptr: u32, paths: &[Vec<u16>]) -> Vec<SubscriptionId> {
+    pub(crate) fn observe_samples(&mut self, device_uuid: Uuid, reg: DeviceReg, state_ptr: usize, paths: &[Vec<u16>]) -> Vec<SubscriptionId> {
         let mut subs = Vec::new();
         for (index, path) in paths.iter().enumerate() {
             let id = index as u32;
@@ -496,7 +500,7 @@ impl Engine {
     /// current target and subscribe to that pointer field, so a set / repoint / clear (inside a transaction,
     /// never during render) re-resolves and re-delivers through the device's `soundfont_changed` export.
     /// Mirrors `observe_samples`.
-    pub(crate) fn observe_soundfonts(&mut self, device_uuid: Uuid, reg: DeviceReg, state_ptr: u32, paths: &[Vec<u16>]) -> Vec<SubscriptionId> {
+    pub(crate) fn observe_soundfonts(&mut self, device_uuid: Uuid, reg: DeviceReg, state_ptr: usize, paths: &[Vec<u16>]) -> Vec<SubscriptionId> {
         let mut subs = Vec::new();
         for (index, path) in paths.iter().enumerate() {
             let id = index as u32;
@@ -532,6 +536,17 @@ impl Engine {
         (handles, subs, collections, armed)
     }
 
+    /// The schema type of the field at `path` in box `box_uuid`, whether or not the box stores a value there.
+    fn declared_field_type(&self, box_uuid: Uuid, path: &[u16]) -> Option<&FieldType> {
+        let name = &self.graph.find_box(&box_uuid)?.name;
+        let (first, rest) = path.split_first()?;
+        rest.iter().try_fold(self.registry.get(name)?.get(first)?, |field_type, key| match field_type {
+            FieldType::Object(fields) => fields.get(key),
+            FieldType::Array {element, length} => (usize::from(*key) < *length).then_some(element.as_ref()),
+            _ => None
+        })
+    }
+
     /// Observe ONE parameter field's value (a reactive cell) + its automation track, returning the handle, its
     /// subscriptions, the curve collections, and whether it is automated. Shared by [`observe_params`] (a device's
     /// fixed field paths) and [`observe_script_params`] (a scriptable device's dynamic `WerkstattParameterBox`
@@ -547,11 +562,17 @@ impl Engine {
         // (a toggle), fixed by the schema. Read it once so the wire tags the un-automated value with its kind;
         // the device then receives a typed `ParamValue`. (A script param's `value` is Float32 -> the static value
         // arrives as `PARAM_KIND_FLOAT` for the bridge to use directly; an automated one arrives as `_UNIT`.)
-        let kind = self.graph.field_value(&address).map_or(PARAM_KIND_FLOAT, |value| {
-            if value.as_int32().is_some() { PARAM_KIND_INT }
-            else if value.as_bool().is_some() { PARAM_KIND_BOOL }
-            else { PARAM_KIND_FLOAT }
-        });
+        // A project saved before the field existed does not store it: the schema still fixes its type.
+        let kind = match self.graph.field_value(&address) {
+            Some(value) if value.as_int32().is_some() => PARAM_KIND_INT,
+            Some(value) if value.as_bool().is_some() => PARAM_KIND_BOOL,
+            Some(_) => PARAM_KIND_FLOAT,
+            None => match self.declared_field_type(box_uuid, path) {
+                Some(FieldType::Int32) => PARAM_KIND_INT,
+                Some(FieldType::Boolean) => PARAM_KIND_BOOL,
+                _ => PARAM_KIND_FLOAT
+            }
+        };
         let field = Rc::new(core::cell::Cell::new(0.0f32));
         let cell = field.clone();
         // A VALUE change is a light edit (push only); everything structural below keeps the heavy signal.
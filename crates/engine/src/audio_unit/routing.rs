@@ -159,7 +159,7 @@ impl Engine {
         if self.context.would_cycle(strip_id, sum_id) {
             return; // a feedback loop: leave unrouted (silent); a later edit can fix it
         }
-        sum_rc.borrow_mut().add_audio_source(strip_output.clone());
+        sum_rc.borrow_mut().add_audio_source(strip_output.clone(), strip_id);
         self.context.register_edge(strip_id, sum_id);
         unit.routed = Some(Routed {bus: target_bus, sum_id, strip_id, strip_output});
     }
@@ -321,7 +321,7 @@ impl Engine {
             send.target = None; // a feedback loop: leave unrouted
             return;
         }
-        sum_rc.borrow_mut().add_audio_source(send.proc.borrow().audio_output());
+        sum_rc.borrow_mut().add_audio_source(send.proc.borrow().audio_output(), send.node_id);
         self.context.register_edge(send.node_id, sum_id);
         send.target = Some(new_target);
     }
@@ -155,7 +155,7 @@ const HOST_KEY: u16 = 1; // the device's `host` pointer field (-> the unit's cha
 fn stub_device(kind: u32) -> DeviceReg {
     DeviceReg {
         process_index: 0, state_size: 64, kind, init_index: 0, parameter_changed_index: 0,
-        field_changed_index: 0, sample_changed_index: 0, soundfont_changed_index: 0, reset_index: 0, terminate_index: 0,
+        field_changed_index: 0, sample_changed_index: 0, soundfont_changed_index: 0, reset_index: 0, terminate_index: 0, latency_index: 0,
         midi_effects_field: 0, audio_effects_field: 0, param_collection_field: 0, sample_collection_field: 0
     }
 }
@@ -222,6 +222,58 @@ fn adding_an_effect_keeps_the_existing_processors() {
     assert!(audio_after[1] > fx_a_node, "the joiner FX_B is a freshly created processor");
 }
 
+#[test]
+fn a_latent_effect_delays_the_dry_path_it_is_summed_with() {
+    // The effect device reports 96 samples of look-ahead through its `latency` slot (a native device that
+    // answers nothing else). Its unit's strip feeds the master alongside a dry source: the master must hold
+    // the dry source back by the effect's latency so both stay sample-aligned.
+    use engine_env::audio_generator::AudioGenerator;
+    struct Latent;
+    impl device_host::Device for Latent {
+        fn kind(&self) -> u32 {
+            DEVICE_KIND_AUDIO_EFFECT
+        }
+
+        fn state_size(&self, _sample_rate: f32) -> u32 {
+            64
+        }
+
+        fn latency(&self, _state: usize) -> u32 {
+            96
+        }
+    }
+    let mut engine = engine_with_devices();
+    engine.devices[1].latency_index = crate::native_host::install(Rc::new(Latent));
+    engine.graph = unit_graph();
+    let mut unit = engine.build_unit(UNIT);
+    engine.reconcile_one(&mut unit);
+    let (_, audio) = leaf_nodes(&unit);
+    let (strip_id, strip_output) = unit.wired.as_ref().unwrap().strip();
+    engine.audio_units.push(unit);
+    engine.resolve_outputs();
+    let dry = Rc::new(RefCell::new(AudioBusProcessor::new(shared_audio_buffer())));
+    let dry_output = dry.borrow().audio_output();
+    let dry_id = engine.context.register_processor(dry);
+    engine.context.register_edge(dry_id, engine.master_id);
+    let master = engine.master.clone().unwrap();
+    master.borrow_mut().add_audio_source(dry_output.clone(), dry_id);
+    engine.context.update_latencies();
+    assert_eq!(engine.context.latency_of(audio[0]), 96, "the effect node reports its device latency");
+    assert_eq!(engine.context.latency_of(strip_id), 96, "the latency carries through the channel strip");
+    assert_eq!(master.borrow().source_delay(&dry_output), 96, "the dry source is delayed to match");
+    assert_eq!(master.borrow().source_delay(&strip_output), 0, "the latent path is not delayed");
+    // Removing the effect drops its latency: the next pass re-aligns the master.
+    engine.graph.transaction(&[Update::Pointer {
+        address: Address::of(FX_A, vec![HOST_KEY]), old: Some(Address::of(UNIT, vec![UNIT_AUDIO_KEY])), new: None
+    }], &engine.registry).expect("disconnect FX_A");
+    let mut unit = engine.audio_units.pop().unwrap();
+    engine.reconcile_one(&mut unit);
+    engine.audio_units.push(unit);
+    engine.resolve_outputs();
+    engine.context.update_latencies();
+    assert_eq!(master.borrow().source_delay(&dry_output), 0, "without the effect nothing is delayed");
+}
+
 #[test]
 fn a_chain_teardown_never_leaves_a_dead_or_skipped_meter_entry() {
     let _guard = pull_lock();
@@ -1609,7 +1661,7 @@ fn a_device_param_automation_rebind_keeps_its_ui_broadcast_alive() {
     struct StubSink;
     impl crate::param_automation::ParamSink for StubSink {
         fn set_params(&mut self, _: alloc::vec::Vec<crate::param_automation::ParamHandle>, _: bool) {}
-        fn state_ptr(&self) -> u32 { 0 }
+        fn state_ptr(&self) -> usize { 0 }
     }
     let mut params = DeviceParams {
         device_uuid: DEV, reg: stub_device(DEVICE_KIND_AUDIO_EFFECT), state_ptr: 0,
@@ -1994,21 +2046,21 @@ fn a_pointer_head_field_observation_tracks_the_target_box_and_the_repoint() {
     let base = engine.graph.subscription_count();
     let (subs, pointer_subs) = engine.observe_fields(ZDEV, reg, 0, &paths);
     assert_eq!(deliveries(), vec![
-        (0, abi::FIELD_KIND_FLOAT, 0.6f32.to_bits(), 0),
+        (0, abi::FIELD_KIND_FLOAT, 0.6f32.to_bits() as usize, 0),
         (1, abi::FIELD_KIND_INT, 480, 0)
     ], "catch-up delivers the CONNECTED groove box's values across the pointer");
     let edit = |engine: &mut Engine, uuid: Uuid, key: u16, old: FieldValue, new: FieldValue|
         engine.graph.transaction(&[Update::Primitive {address: Address::of(uuid, vec![key]), old, new}],
             &engine.registry).expect("edit");
     edit(&mut engine, GROOVE_A, 10, FieldValue::Float32(0.6), FieldValue::Float32(0.9));
-    assert_eq!(deliveries(), vec![(0, abi::FIELD_KIND_FLOAT, 0.9f32.to_bits(), 0)],
+    assert_eq!(deliveries(), vec![(0, abi::FIELD_KIND_FLOAT, 0.9f32.to_bits() as usize, 0)],
         "a target field edit is delivered live");
     let repoint = |engine: &mut Engine, old: Option<Address>, new: Option<Address>|
         engine.graph.transaction(&[Update::Pointer {address: Address::of(ZDEV, vec![10]), old, new}],
             &engine.registry).expect("repoint");
     repoint(&mut engine, Some(Address::box_of(GROOVE_A)), Some(Address::box_of(GROOVE_B)));
     assert_eq!(deliveries(), vec![
-        (0, abi::FIELD_KIND_FLOAT, 0.25f32.to_bits(), 0),
+        (0, abi::FIELD_KIND_FLOAT, 0.25f32.to_bits() as usize, 0),
         (1, abi::FIELD_KIND_INT, 960, 0)
     ], "a repoint delivers the NEW target's values");
     edit(&mut engine, GROOVE_A, 10, FieldValue::Float32(0.9), FieldValue::Float32(0.1));
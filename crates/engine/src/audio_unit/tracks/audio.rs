@@ -2,6 +2,7 @@
 //! (`read_audio_region`/`read_warp_markers`/`read_time_stretch`/`read_signalsmith`/`read_transients`), and the
 //! region/clip binding cascade — including the play-mode-pointer / warp / transient live-edit subscriptions.
 use super::*;
+use dsp::interpolator::Interpolation;
 
 /// ONE audio track's player-visible content: the track uuid, its regions kept SORTED BY POSITION (each a
 /// self-contained `AudioRegion` — its playback data, no shared event collection), and its launchable audio
@@ -91,6 +92,7 @@ pub(crate) const AUDIO_REGION_MUTE_KEY: u16 = 14;
 pub(crate) const AUDIO_REGION_GAIN_KEY: u16 = 17;            // decibels
 pub(crate) const AUDIO_REGION_FADING_KEY: u16 = 18;          // object: 1 in, 2 out (ppqn), 3 in-slope, 4 out-slope (ratio)
 pub(crate) const AUDIO_REGION_PLAYMODE_KEY: u16 = 8;         // -> an AudioPitchStretchBox / AudioTimeStretchBox, or unset (native)
+pub(crate) const AUDIO_REGION_INTERPOLATION_KEY: u16 = 19;   // 0 linear, 1 sinc, 2 sinc high (the read head's quality)
 pub(crate) const PITCH_STRETCH_WARP_HUB_KEY: u16 = 1;        // AudioPitchStretchBox.warp-markers hub
 // Every play-mode box (Pitch / TimeStretch / Signalsmith) exposes its warp-markers hub at field 1, so the
 // live warp-marker observer can watch the hub without knowing the play-mode type.
@@ -129,6 +131,8 @@ pub(crate) struct AudioRegion {
     pub(crate) fade_out: f64,        // ppqn
     pub(crate) fade_in_slope: f32,   // 0..1 ratio
     pub(crate) fade_out_slope: f32,  // 0..1 ratio
+    // The read head's interpolation (native and PitchStretch reads; the granular / spectral modes have their own).
+    pub(crate) interpolation: Interpolation,
     // PitchStretch play-mode warp markers (content ppqn -> source seconds), sorted by ppqn. EMPTY = no
     // PitchStretch play-mode (native, or a TimeStretch play-mode — see `time_stretch`).
     pub(crate) warp: Vec<(f64, f64)>,
@@ -152,6 +156,11 @@ pub(crate) fn region_float(graph: &BoxGraph, uuid: Uuid, path: &[u16]) -> f32 {
     graph.field_value(&Address::of(uuid, path.to_vec())).and_then(|value| value.as_float32()).unwrap_or(0.0)
 }
 
+/// The region's (or clip's) read-head interpolation; projects saved before the field read as linear.
+fn read_interpolation(graph: &BoxGraph, uuid: Uuid, key: u16) -> Interpolation {
+    graph.field_value(&Address::of(uuid, vec![key])).and_then(|value| value.as_int32()).map_or(Interpolation::Linear, Interpolation::from_index)
+}
+
 /// Read an `AudioRegionBox`'s span + playback fields. `None` when it has no `file` pointer (an unresolved /
 /// half-built region is skipped, never played). The loopable span is normalized to PPQN: in a `Seconds`
 /// time-base (the no-stretch / NoWarp default) `duration` + `loop-duration` are stored in SECONDS and converted
@@ -181,6 +190,7 @@ pub(crate) fn read_audio_region(graph: &BoxGraph, region_uuid: Uuid, tempo_map:
         fade_out: region_float(graph, region_uuid, &[AUDIO_REGION_FADING_KEY, 2]) as f64,
         fade_in_slope: region_float(graph, region_uuid, &[AUDIO_REGION_FADING_KEY, 3]),
         fade_out_slope: region_float(graph, region_uuid, &[AUDIO_REGION_FADING_KEY, 4]),
+        interpolation: read_interpolation(graph, region_uuid, AUDIO_REGION_INTERPOLATION_KEY),
         warp: read_warp_markers(graph, region_uuid),
         time_stretch,
         signalsmith: read_signalsmith(graph, region_uuid),
@@ -363,6 +373,7 @@ pub(crate) const AUDIO_CLIP_WAVEFORM_OFFSET_KEY: u16 = 7;
 pub(crate) const AUDIO_CLIP_DURATION_KEY: u16 = 10;
 pub(crate) const AUDIO_CLIP_MUTE_KEY: u16 = 11;
 pub(crate) const AUDIO_CLIP_GAIN_KEY: u16 = 14;
+pub(crate) const AUDIO_CLIP_INTERPOLATION_KEY: u16 = 15;
 
 /// Read an audio CLIP as its virtual region (TS Tape clip branch: `{position: 0, loopDuration: clip.duration,
 /// loopOffset: 0, complete: +Infinity}`, no fades) plus the `triggerMode.loop` flag for the sequencer.
@@ -385,6 +396,7 @@ pub(crate) fn read_audio_clip(graph: &BoxGraph, clip_uuid: Uuid, _tempo_map: &Te
         fade_out: 0.0,
         fade_in_slope: 0.0,
         fade_out_slope: 0.0,
+        interpolation: read_interpolation(graph, clip_uuid, AUDIO_CLIP_INTERPOLATION_KEY),
         warp: read_warp_markers(graph, clip_uuid),
         time_stretch,
         signalsmith: read_signalsmith(graph, clip_uuid),
@@ -83,7 +83,7 @@ pub(crate) struct BoundNoteClip {
 }
 
 impl NoteTrackAccess for NoteTrackContent {
-    fn for_each_region(&self, from: f64, to: f64, visit: &mut dyn FnMut(&NoteRegion, &EventCollection<NoteEvent>)) {
+    fn for_each_region(&self, from: f64, to: f64, visit: &mut RegionVisitor) {
         // Binary-search the regions overlapping [from, to) within this track (sorted by position). A region
         // being RECORDED INTO is skipped (TS `context.ignoresRegion` in `NoteSequencer.#processRegions`).
         let ignored = unsafe { crate::IGNORED_REGIONS.get() };
@@ -91,18 +91,18 @@ impl NoteTrackAccess for NoteTrackContent {
             if ignored.contains(&bound.region_uuid) {
                 continue;
             }
-            visit(&bound.region, &bound.collection.events());
+            visit(&bound.region, &bound.collection.events(), &bound.collection.controls());
         }
     }
     fn clip_info(&self, clip: &[u8; 16]) -> Option<(f64, bool)> {
         self.clips.iter().find(|bound| &bound.clip_uuid == clip).map(|bound| (bound.duration, bound.looped))
     }
-    fn clip_events(&self, clip: &[u8; 16], visit: &mut dyn FnMut(&EventCollection<NoteEvent>)) {
+    fn clip_events(&self, clip: &[u8; 16], visit: &mut dyn FnMut(&EventCollection<NoteEvent>, &EventCollection<ControlEvent>)) {
         if let Some(bound) = self.clips.iter().find(|bound| &bound.clip_uuid == clip) {
             if bound.mute {
                 return; // a muted launched clip emits no notes (the UI also gates launching a muted clip)
             }
-            visit(&bound.collection.events());
+            visit(&bound.collection.events(), &bound.collection.controls());
         }
     }
 }
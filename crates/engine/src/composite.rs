@@ -103,9 +103,9 @@ struct CompositeChild {
 /// Sync a child's sum membership to its `enabled`: add its output as a source when it should be summed but is
 /// not, remove it when it should not be but is. Returns the new `summed` state. The one bypass invariant, shared
 /// by the build / slot-reconcile / wholesale-reconcile paths.
-fn sync_sum(sum: &Rc<RefCell<AudioBusProcessor>>, output: &SharedAudioBuffer, summed: bool, enabled: bool) -> bool {
+fn sync_sum(sum: &Rc<RefCell<AudioBusProcessor>>, output: &SharedAudioBuffer, output_node: NodeId, summed: bool, enabled: bool) -> bool {
     if enabled && !summed {
-        sum.borrow_mut().add_audio_source(output.clone());
+        sum.borrow_mut().add_audio_source(output.clone(), output_node);
     } else if !enabled && summed {
         sum.borrow_mut().remove_audio_source(output);
     }
@@ -333,7 +333,7 @@ impl Engine {
                 } else {
                     (cluster, output, output_node, summed)
                 };
-                let summed = sync_sum(&binding.sum, &output, summed, self.child_enabled(uuid, spec.child_enabled_key));
+                let summed = sync_sum(&binding.sum, &output, output_node, summed, self.child_enabled(uuid, spec.child_enabled_key));
                 Some(CompositeChild {uuid, choke, body: ChildBody::Slot {cluster, device, midi_obs, audio_obs},
                     output, output_node, summed, enabled_sub, effects_dirty, gate, gate_subs})
             }
@@ -366,7 +366,7 @@ impl Engine {
             self.teardown_child(child);
             return self.build_one_child(binding.sum.clone(), binding.sum_id, track_sets, uuid, choke, spec, unit_midi, signal, invalidate);
         }
-        child.summed = sync_sum(&binding.sum, &child.output, child.summed, self.child_enabled(child.uuid, spec.child_enabled_key));
+        child.summed = sync_sum(&binding.sum, &child.output, child.output_node, child.summed, self.child_enabled(child.uuid, spec.child_enabled_key));
         Some(child)
     }
 
@@ -476,7 +476,7 @@ impl Engine {
             }
         };
         self.output_registry.register(Address::of(child_uuid, vec![]), output.clone(), output_node);
-        let summed = sync_sum(&sum, &output, false, self.child_enabled(child_uuid, spec.child_enabled_key));
+        let summed = sync_sum(&sum, &output, output_node, false, self.child_enabled(child_uuid, spec.child_enabled_key));
         self.context.register_edge(output_node, sum_id);
         let enabled_sub = self.subscribe_child_enabled(child_uuid, spec.child_enabled_key, signal);
         // A mute / solo toggle enqueues the owning unit (like `enabled`); the per-child reconcile re-resolves
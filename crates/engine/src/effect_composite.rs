@@ -286,7 +286,9 @@ impl Engine {
             self.broadcasts.attach_producer_active(composite_uuid, &[0xFFF],
                 crate::broadcast::PACKAGE_FLOAT_ARRAY, spectrum_active);
         }
-        // The dry path and the wet sum both feed the mix; ordering edges only (the mix reads the buffers).
+        // The dry path and the wet sum both feed the mix; ordering edges only (the mix reads the buffers). The
+        // mix also learns which nodes they are, so a latent entry delays the dry tap to match.
+        mix.borrow_mut().set_input_nodes(distributor_id, wet_sum_id);
         self.context.register_edge(distributor_id, mix_id);
         self.context.register_edge(wet_sum_id, mix_id);
         let tail_edges = vec![(distributor_id, mix_id), (wet_sum_id, mix_id)];
@@ -636,7 +638,7 @@ impl Engine {
         self.context.register_edge(entry.strip_id, binding.wet_sum_id);
         entry.edges.push((entry.strip_id, binding.wet_sum_id));
         if !entry.summed {
-            binding.wet_sum.borrow_mut().add_audio_source(entry.strip_output.clone());
+            binding.wet_sum.borrow_mut().add_audio_source(entry.strip_output.clone(), entry.strip_id);
             entry.summed = true;
         }
         entry.wired_index = index;
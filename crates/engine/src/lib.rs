@@ -13,8 +13,12 @@
 //!
 //! ALLOCATOR: talc (`WasmDynamicTalc`), a reclaiming allocator that grows linear memory via
 //! `memory.grow` on demand and frees blocks back for reuse. Single-threaded build, so no lock.
+//!
+//! NATIVE: the same crate builds as an `rlib` with std for the [`offline`] renderer (batch bounces and
+//! regression renders without a browser). The C ABI is the wasm module's surface only: natively the exports
+//! keep mangled names, so `bind` / `pause` never shadow the libc symbols of a binary linking the engine.
 
-#![cfg_attr(not(test), no_std)]
+#![cfg_attr(target_family = "wasm", no_std)]
 
 extern crate alloc;
 
@@ -33,14 +37,14 @@ use boxgraph::subscription::HubEvent;
 use boxgraph::bytes::ByteReader;
 use boxgraph::graph::BoxGraph;
 use boxgraph::updates::{decode_forward, Update};
-use abi::{EventRecord, ParamChange, EVENT_CHOKE, EVENT_NOTE_OFF, EVENT_NOTE_ON};
+use abi::{EventRecord, ParamChange, EVENT_CHANNEL_PRESSURE, EVENT_CHOKE, EVENT_CONTROL, EVENT_NOTE_OFF, EVENT_NOTE_ON, EVENT_PITCH_BEND};
 use engine_env::audio_buffer::{shared_audio_buffer, SharedAudioBuffer};
 use engine_env::audio_bus_processor::AudioBusProcessor;
 use engine_env::audio_output_buffer_registry::AudioOutputBufferRegistry;
 use engine_env::block::Block;
 use engine_env::block_flags::BlockFlags;
 use engine_env::engine_context::{EngineContext, NodeId};
-use engine_env::event::Event;
+use engine_env::event::{Control, Event};
 use engine_env::note_event_instrument::SharedNoteEventSource;
 use engine_env::ppqn::{first_update_position, pulses_to_samples, UPDATE_CLOCK_RATE};
 use engine_env::process_info::ProcessInfo;
@@ -66,6 +70,8 @@ struct DeviceReg {
     terminate_index: u32,          // slot of the device's `terminate` export (its INSTANCE is dying — a genuine
                                     // removal, never a chain-edit survivor); releases external resources (e.g.
                                     // a bridge's JS-side instance); 0 if none
+    latency_index: u32,            // slot of the device's `latency` export (look-ahead delay, for delay
+                                    // compensation); 0 if none (the device adds no latency)
     midi_effects_field: u16,       // the device's OWN midi-fx host field key when hosted as a composite child; 0 if none
     audio_effects_field: u16,      // the device's OWN audio-fx host field key when hosted as a composite child; 0 if none
     // SCRIPTABLE devices (Werkstatt / Apparat / Spielwerk): the field keys of the dynamic parameter / sample
@@ -166,129 +172,143 @@ pub(crate) struct EffectCompositeSpec {
 // so transmuting the index to a fn and calling it emits `call_indirect` on the imported table.
 #[cfg(target_family = "wasm")]
 #[inline]
-fn call_device_process(process_index: u32, desc_ptr: u32) {
-    let process: extern "C" fn(u32) = unsafe { core::mem::transmute(process_index as usize) };
+fn call_device_process(process_index: u32, desc_ptr: usize) {
+    let process: extern "C" fn(usize) = unsafe { core::mem::transmute(process_index as usize) };
     process(desc_ptr);
 }
-// Native (cargo test) never runs the audio path; stub so the crate builds.
+// Native: the slot indexes the native device table instead (see `native_host`); the device runs with the
+// engine serving its host imports. An empty slot renders nothing.
 #[cfg(not(target_family = "wasm"))]
-fn call_device_process(_process_index: u32, _desc_ptr: u32) {}
+fn call_device_process(process_index: u32, desc_ptr: usize) {
+    native_host::call(process_index, |device| device.process(desc_ptr));
+}
 
 // Call a MIDI-fx device's `process_events` pull responder through the shared function table (same
 // table-index-is-fn-pointer trick as `call_device_process`). `state_ptr` is its per-instance state block.
 // Returns the count of events it wrote.
 #[cfg(target_family = "wasm")]
 #[inline]
-fn call_device_process_events(process_index: u32, from: f64, to: f64, flags: u32, state_ptr: u32, out_ptr: u32, max: u32) -> u32 {
-    let process_events: extern "C" fn(f64, f64, u32, u32, u32, u32) -> u32 =
+fn call_device_process_events(process_index: u32, from: f64, to: f64, flags: u32, state_ptr: usize, out_ptr: usize, max: u32) -> u32 {
+    let process_events: extern "C" fn(f64, f64, u32, usize, usize, u32) -> u32 =
         unsafe { core::mem::transmute(process_index as usize) };
     process_events(from, to, flags, state_ptr, out_ptr, max)
 }
 #[cfg(not(target_family = "wasm"))]
-fn call_device_process_events(_process_index: u32, _from: f64, _to: f64, _flags: u32, _state_ptr: u32, _out_ptr: u32, _max: u32) -> u32 { 0 }
+fn call_device_process_events(process_index: u32, from: f64, to: f64, flags: u32, state_ptr: usize, out_ptr: usize, max: u32) -> u32 {
+    native_host::call(process_index, |device| device.process_events(from, to, flags, state_ptr, out_ptr, max)).unwrap_or(0)
+}
 
 // Call a device's `init(state_ptr, sample_rate)` export (it binds its parameters via `host_bind_parameter`
 // and learns the engine's sample rate, stable for its life). Same table-index-is-fn-pointer trick. Called
 // once when the device is wired, NOT during render.
 #[cfg(target_family = "wasm")]
 #[inline]
-fn call_device_init(init_index: u32, state_ptr: u32, sample_rate: f32) {
+fn call_device_init(init_index: u32, state_ptr: usize, sample_rate: f32) {
     if init_index == 0 {
         return; // the device exports no `init`; index 0 is the "none" sentinel
     }
-    let init: extern "C" fn(u32, f32) = unsafe { core::mem::transmute(init_index as usize) };
+    let init: extern "C" fn(usize, f32) = unsafe { core::mem::transmute(init_index as usize) };
     init(state_ptr, sample_rate);
 }
 #[cfg(not(target_family = "wasm"))]
-fn call_device_init(_init_index: u32, _state_ptr: u32, _sample_rate: f32) {}
+fn call_device_init(init_index: u32, state_ptr: usize, sample_rate: f32) {
+    native_host::call(init_index, |device| device.init(state_ptr, sample_rate));
+}
 
 // Call a device's `parameter_changed(state_ptr, id, value)` export to push a resolved parameter value. The
 // engine calls this at build / edit time (never during the device's `process`, so it never aliases the
 // state the render path borrows).
 #[cfg(target_family = "wasm")]
 #[inline]
-fn call_device_parameter_changed(parameter_changed_index: u32, state_ptr: u32, id: u32, kind: u32, value: f32) {
+fn call_device_parameter_changed(parameter_changed_index: u32, state_ptr: usize, id: u32, kind: u32, value: f32) {
     if parameter_changed_index == 0 {
         return; // the device exports no `parameter_changed`; index 0 is the "none" sentinel
     }
     unsafe { *PARAM_PUSHES.get() = PARAM_PUSHES.get().wrapping_add(1); }
-    let parameter_changed: extern "C" fn(u32, u32, u32, f32) = unsafe { core::mem::transmute(parameter_changed_index as usize) };
+    let parameter_changed: extern "C" fn(usize, u32, u32, f32) = unsafe { core::mem::transmute(parameter_changed_index as usize) };
     parameter_changed(state_ptr, id, kind, value);
 }
 #[cfg(not(target_family = "wasm"))]
-fn call_device_parameter_changed(_parameter_changed_index: u32, _state_ptr: u32, _id: u32, _kind: u32, _value: f32) {}
+fn call_device_parameter_changed(parameter_changed_index: u32, state_ptr: usize, id: u32, kind: u32, value: f32) {
+    native_host::call(parameter_changed_index, |device| device.parameter_changed(state_ptr, id, kind, value));
+}
 
 // Call a device's `field_changed(state_ptr, id, value)` export to deliver an observed plain field's value
 // (catch-up + edits). Called only inside a transaction (the `catchup_and_subscribe` callback), never during
 // `process`, so it never aliases the state the render path borrows.
 #[cfg(target_family = "wasm")]
 #[inline]
-fn call_device_field_changed(field_changed_index: u32, state_ptr: u32, id: u32, kind: u32, bits: u32, len: u32) {
+fn call_device_field_changed(field_changed_index: u32, state_ptr: usize, id: u32, kind: u32, bits: usize, len: u32) {
     if field_changed_index == 0 {
         return; // the device exports no `field_changed`; index 0 is the "none" sentinel
     }
-    let field_changed: extern "C" fn(u32, u32, u32, u32, u32) = unsafe { core::mem::transmute(field_changed_index as usize) };
+    let field_changed: extern "C" fn(usize, u32, u32, usize, u32) = unsafe { core::mem::transmute(field_changed_index as usize) };
     field_changed(state_ptr, id, kind, bits, len);
 }
-// Native: record the delivery (id, kind, bits, len) for tests when the device declares a `field_changed`
-// export (mirroring the wasm "index 0 = none" sentinel), so field-observation tests can assert what reached
-// the device without a wasm build.
+// Native: a test additionally records the delivery (id, kind, bits, len) when the device declares a
+// `field_changed` export (mirroring the wasm "index 0 = none" sentinel), so field-observation tests can assert
+// what reached the device without a device in the slot.
 #[cfg(not(target_family = "wasm"))]
-fn call_device_field_changed(field_changed_index: u32, _state_ptr: u32, id: u32, kind: u32, bits: u32, len: u32) {
+fn call_device_field_changed(field_changed_index: u32, state_ptr: usize, id: u32, kind: u32, bits: usize, len: u32) {
     #[cfg(test)]
     if field_changed_index != 0 {
         unsafe { FIELD_DELIVERIES.get() }.push((id, kind, bits, len));
     }
-    #[cfg(not(test))]
-    let _ = (field_changed_index, id, kind, bits, len);
+    native_host::call(field_changed_index, |device| device.field_changed(state_ptr, id, kind, bits, len));
 }
 
 #[cfg(all(not(target_family = "wasm"), test))]
-pub(crate) static FIELD_DELIVERIES: Shared<Vec<(u32, u32, u32, u32)>> = Shared::new(Vec::new());
+pub(crate) static FIELD_DELIVERIES: Shared<Vec<(u32, u32, usize, u32)>> = Shared::new(Vec::new());
 
 // Call a device's `sample_changed(state_ptr, id, handle, present)` export to deliver an observed sample (the
 // resolved handle, or `present == 0` when the pointer is unbound). Called only inside a transaction (the
 // pointer observer), never during `process`.
 #[cfg(target_family = "wasm")]
 #[inline]
-fn call_device_sample_changed(sample_changed_index: u32, state_ptr: u32, id: u32, handle: u32, present: u32) {
+fn call_device_sample_changed(sample_changed_index: u32, state_ptr: usize, id: u32, handle: u32, present: u32) {
     if sample_changed_index == 0 {
         return; // the device exports no `sample_changed`; index 0 is the "none" sentinel
     }
-    let sample_changed: extern "C" fn(u32, u32, u32, u32) = unsafe { core::mem::transmute(sample_changed_index as usize) };
+    let sample_changed: extern "C" fn(usize, u32, u32, u32) = unsafe { core::mem::transmute(sample_changed_index as usize) };
     sample_changed(state_ptr, id, handle, present);
 }
 #[cfg(not(target_family = "wasm"))]
-fn call_device_sample_changed(_sample_changed_index: u32, _state_ptr: u32, _id: u32, _handle: u32, _present: u32) {}
+fn call_device_sample_changed(sample_changed_index: u32, state_ptr: usize, id: u32, handle: u32, present: u32) {
+    native_host::call(sample_changed_index, |device| device.sample_changed(state_ptr, id, handle, present));
+}
 
 // Call a device's `soundfont_changed(state_ptr, id, handle, present)` export to deliver an observed soundfont
 // (the resolved blob handle, or `present == 0` when the pointer is unbound). Called only inside a transaction
 // (the pointer observer), never during `process`. Mirrors `call_device_sample_changed`.
 #[cfg(target_family = "wasm")]
 #[inline]
-fn call_device_soundfont_changed(soundfont_changed_index: u32, state_ptr: u32, id: u32, handle: u32, present: u32) {
+fn call_device_soundfont_changed(soundfont_changed_index: u32, state_ptr: usize, id: u32, handle: u32, present: u32) {
     if soundfont_changed_index == 0 {
         return; // the device exports no `soundfont_changed`; index 0 is the "none" sentinel
     }
-    let soundfont_changed: extern "C" fn(u32, u32, u32, u32) = unsafe { core::mem::transmute(soundfont_changed_index as usize) };
+    let soundfont_changed: extern "C" fn(usize, u32, u32, u32) = unsafe { core::mem::transmute(soundfont_changed_index as usize) };
     soundfont_changed(state_ptr, id, handle, present);
 }
 #[cfg(not(target_family = "wasm"))]
-fn call_device_soundfont_changed(_soundfont_changed_index: u32, _state_ptr: u32, _id: u32, _handle: u32, _present: u32) {}
+fn call_device_soundfont_changed(soundfont_changed_index: u32, state_ptr: usize, id: u32, handle: u32, present: u32) {
+    native_host::call(soundfont_changed_index, |device| device.soundfont_changed(state_ptr, id, handle, present));
+}
 
 // Call a device's `reset(state_ptr)` export to clear its runtime state on a transport STOP. Called outside
 // render, never during `process`.
 #[cfg(target_family = "wasm")]
 #[inline]
-fn call_device_reset(reset_index: u32, state_ptr: u32) {
+fn call_device_reset(reset_index: u32, state_ptr: usize) {
     if reset_index == 0 {
         return; // the device exports no `reset`; index 0 is the "none" sentinel
     }
-    let reset: extern "C" fn(u32) = unsafe { core::mem::transmute(reset_index as usize) };
+    let reset: extern "C" fn(usize) = unsafe { core::mem::transmute(reset_index as usize) };
     reset(state_ptr);
 }
 #[cfg(not(target_family = "wasm"))]
-fn call_device_reset(_reset_index: u32, _state_ptr: u32) {}
+fn call_device_reset(reset_index: u32, state_ptr: usize) {
+    native_host::call(reset_index, |device| device.reset(state_ptr));
+}
 
 // Call a device's `terminate(state_ptr)` export when its INSTANCE dies (a genuine removal — the box left the
 // graph, or the unit/bus/cluster it belonged to was torn down wholesale). NEVER called for a chain-edit
@@ -297,21 +317,41 @@ fn call_device_reset(_reset_index: u32, _state_ptr: u32) {}
 // trick as `call_device_reset`.
 #[cfg(target_family = "wasm")]
 #[inline]
-fn call_device_terminate(terminate_index: u32, state_ptr: u32) {
+fn call_device_terminate(terminate_index: u32, state_ptr: usize) {
     if terminate_index == 0 {
         return; // the device exports no `terminate`; index 0 is the "none" sentinel
     }
     unsafe { *TERMINATES.get() = TERMINATES.get().wrapping_add(1); }
-    let terminate: extern "C" fn(u32) = unsafe { core::mem::transmute(terminate_index as usize) };
+    let terminate: extern "C" fn(usize) = unsafe { core::mem::transmute(terminate_index as usize) };
     terminate(state_ptr);
 }
-// Native: count the call (for a device that declares `terminate`) so a removal-vs-reorder test can assert it
-// without a wasm build, mirroring `call_device_field_changed`'s native counter.
+// Native: also count the call (for a device that declares `terminate`) so a removal-vs-reorder test can
+// assert it with an empty slot.
 #[cfg(not(target_family = "wasm"))]
-fn call_device_terminate(terminate_index: u32, _state_ptr: u32) {
+fn call_device_terminate(terminate_index: u32, state_ptr: usize) {
     if terminate_index != 0 {
         unsafe { *TERMINATES.get() = TERMINATES.get().wrapping_add(1); }
     }
+    native_host::call(terminate_index, |device| device.terminate(state_ptr));
+}
+
+// Call a device's `latency(state_ptr) -> u32` export: the samples its output currently lags its input (a
+// look-ahead compressor / limiter). Read off-render by delay compensation after a reconcile, so a latency that
+// follows a parameter (a look-ahead toggle) is re-read once the new value was pushed. Same table-index-is-fn-
+// pointer trick as `call_device_reset`.
+#[cfg(target_family = "wasm")]
+#[inline]
+fn call_device_latency(latency_index: u32, state_ptr: usize) -> u32 {
+    if latency_index == 0 {
+        return 0; // the device exports no `latency`; index 0 is the "none" sentinel
+    }
+    let latency: extern "C" fn(usize) -> u32 = unsafe { core::mem::transmute(latency_index as usize) };
+    latency(state_ptr)
+}
+// Native: the device in the slot answers; an empty slot lags by nothing.
+#[cfg(not(target_family = "wasm"))]
+fn call_device_latency(latency_index: u32, state_ptr: usize) -> u32 {
+    native_host::call(latency_index, |device| device.latency(state_ptr)).unwrap_or(0)
 }
 
 const DEVICE_MAX_EVENTS: usize = 256; // per-quantum event scratch the device pulls into
@@ -348,8 +388,9 @@ mod effect_composite;
 /// Serialises every test that touches the global `PULL` context. The production engine is single-threaded
 /// (only the audio thread runs engine code), so `PULL` is a plain `Shared` cell — but the test harness runs
 /// tests on parallel threads, where concurrent access is a data race (it segfaults). ONE lock for the crate:
-/// per-module mutexes would not serialise against each other.
-#[cfg(test)]
+/// per-module mutexes would not serialise against each other. The offline renderer holds it for a whole
+/// render, since it drives the same global cells from whichever thread calls it.
+#[cfg(not(target_family = "wasm"))]
 pub(crate) fn pull_lock() -> std::sync::MutexGuard<'static, ()> {
     static LOCK: std::sync::Mutex<()> = std::sync::Mutex::new(());
     LOCK.lock().unwrap_or_else(std::sync::PoisonError::into_inner)
@@ -360,6 +401,10 @@ mod sample;
 use sample::SampleResource;
 mod soundfont;
 use soundfont::SoundfontResource;
+#[cfg(not(target_family = "wasm"))]
+pub mod offline;
+#[cfg(not(target_family = "wasm"))]
+mod native_host;
 
 const INPUT_CAPACITY: usize = 1 << 20; // initial input scratch (1 MiB); grows on demand, keeps the high-water mark
 
@@ -394,7 +439,7 @@ pub(crate) fn note_device_build() {
     unsafe { *DEVICE_BUILDS.get() = DEVICE_BUILDS.get().wrapping_add(1); }
 }
 
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn device_build_count() -> u32 {
     unsafe { *DEVICE_BUILDS.get() }
 }
@@ -403,7 +448,7 @@ pub extern "C" fn device_build_count() -> u32 {
 // assert a chain REORDER does NOT re-push a survivor's parameters (which would glide e.g. the delay's offset).
 static PARAM_PUSHES: Shared<u32> = Shared::new(0);
 
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn param_push_count() -> u32 {
     unsafe { *PARAM_PUSHES.get() }
 }
@@ -413,7 +458,7 @@ pub extern "C" fn param_push_count() -> u32 {
 // rewire of survivors terminates none.
 static TERMINATES: Shared<u32> = Shared::new(0);
 
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn device_terminate_count() -> u32 {
     unsafe { *TERMINATES.get() }
 }
@@ -447,7 +492,7 @@ static BROADCAST_BINDS: Shared<Vec<(u32, Vec<u16>, u32, u32)>> = Shared::new(Vec
 // by `host_broadcast_ptr` / `host_broadcast_active` from a device's `process` (never touches `ENGINE`);
 // `bind_device` fills the ptr, teardown zeroes it and returns the id to the free list (no unbounded growth
 // across chain edits).
-pub(crate) static DEVICE_BROADCASTS: Shared<Vec<(u32, bool)>> = Shared::new(Vec::new());
+pub(crate) static DEVICE_BROADCASTS: Shared<Vec<(usize, bool)>> = Shared::new(Vec::new());
 pub(crate) static DEVICE_BROADCAST_FREE: Shared<Vec<u32>> = Shared::new(Vec::new());
 // The sample resource (Route F): decoded frames resident in shared memory, keyed by AudioFileBox uuid. Held
 // in its OWN cell (NOT `ENGINE`) so a device's re-entrant `host_resolve_sample` call during render never
@@ -502,7 +547,7 @@ static SIDECHAIN_BIND: Shared<Vec<Vec<u16>>> = Shared::new(Vec::new());
 // for the device call so `host_resolve_input` resolves a port to its buffer: id `1` is the through-signal, ids
 // `2, 3, ...` the resolved sidechains. Its own cell (NOT `ENGINE`); read-only during render, never aliases the
 // graph, exactly like `host_resolve_sample` reading `SAMPLES`.
-static INPUTS: Shared<Vec<(u32, u32, u32)>> = Shared::new(Vec::new());
+static INPUTS: Shared<Vec<(u32, usize, usize)>> = Shared::new(Vec::new());
 
 // The box uuid of the device whose `init` is currently running, so a SCRIPTABLE device's `init` can read its
 // own uuid via `host_self_uuid` (the engine knows it at bind; the device does not, since it only uses relative
@@ -614,6 +659,7 @@ fn lifecycle_rank(event: &Event) -> u8 {
     match event {
         Event::NoteComplete {..} => 0, // note-off first
         Event::Update {..} => 1,       // then the param-update (clock tick)
+        Event::Control {..} => 1,      // and channel controls, so a note there starts bent / sustained
         Event::NoteStart {..} => 2     // then note-on, so it sees the updated parameter
     }
 }
@@ -621,7 +667,7 @@ fn lifecycle_rank(event: &Event) -> u8 {
 fn lifecycle_id(event: &Event) -> u64 {
     match event {
         Event::NoteStart {id, ..} | Event::NoteComplete {id, ..} => *id,
-        Event::Update {..} => 0
+        Event::Update {..} | Event::Control {..} => 0
     }
 }
 
@@ -630,17 +676,16 @@ fn lifecycle_id(event: &Event) -> u64 {
 /// sample-offset `EventRecord`s directly; a MIDI-fx link descends (routing the fx device's own upstream
 /// pull to the next link) and invokes that device's `process_events`. Reads only `PULL`, never `ENGINE`,
 /// so it is safe to call re-entrantly from inside `render`.
-#[no_mangle]
-pub extern "C" fn host_pull_events(from: f64, to: f64, flags: u32, out_ptr: u32, max: u32) -> u32 {
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn host_pull_events(from: f64, to: f64, flags: u32, out_ptr: usize, max: u32) -> u32 {
     let out = unsafe { core::slice::from_raw_parts_mut(out_ptr as *mut EventRecord, max as usize) };
     pull_events_into(from, to, flags, out, out_ptr)
 }
 
-/// The slice-based body of `host_pull_events`, shared with ENGINE-SIDE consumers (the MidiOut node) whose
-/// scratch lives behind a real slice — going through the raw `as u32` address would truncate the pointer on
-/// a native (test) build. `out_ptr` is the wasm address of `out`, forwarded only to a MIDI-fx device call
-/// (wasm-to-wasm; a native build's device stub never dereferences it).
-pub(crate) fn pull_events_into(from: f64, to: f64, flags: u32, out: &mut [EventRecord], out_ptr: u32) -> u32 {
+/// The slice-based body of `host_pull_events`, shared with ENGINE-SIDE consumers (the MidiOut node) and the
+/// native host whose scratch lives behind a real slice. `out_ptr` is the address of `out`, forwarded only to a
+/// MIDI-fx device's `process_events`.
+pub(crate) fn pull_events_into(from: f64, to: f64, flags: u32, out: &mut [EventRecord], out_ptr: usize) -> u32 {
     let max = out.len() as u32;
     let link = { unsafe { PULL.get() }.current.clone() };
     // The CONSUMER's note-bits slot, taken before any descent below swaps it out.
@@ -708,7 +753,7 @@ fn quantum_transporting() -> bool {
 /// (INCLUSIVE), so a grid point exactly on a block's start fires (mirrors TS `Fragmentor`'s `ceil`). Returns
 /// `f64::INFINITY` when the CURRENT device has no automated parameter, or while the transport is NOT running
 /// (TS `UpdateClock` emits no update events on a non-transporting block). Reads only `PULL`.
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn host_first_update_position(at: f64) -> f64 {
     let pull = unsafe { PULL.get() };
     if !pull.clock_armed || !quantum_transporting() {
@@ -723,7 +768,7 @@ pub extern "C" fn host_first_update_position(at: f64) -> f64 {
 /// parameter (so its render simply does not fragment) or while the transport is NOT running (the TS
 /// `UpdateClock` gate). Reads only `PULL` (the current device's clock-armed state, set by its node / the fx
 /// descent).
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn host_next_update_position(after: f64) -> f64 {
     let pull = unsafe { PULL.get() };
     if !pull.clock_armed || !quantum_transporting() {
@@ -742,8 +787,8 @@ pub extern "C" fn host_next_update_position(after: f64) -> f64 {
 /// the path (into `BIND`) and returns the id (the index); the engine observes the field + track after `init`
 /// returns. The host stays mapping-agnostic. Touches no graph and no `&mut Engine`, so it is safe to call
 /// re-entrantly from `init`.
-#[no_mangle]
-pub extern "C" fn host_bind_parameter(path_ptr: u32, path_len: u32) -> u32 {
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn host_bind_parameter(path_ptr: usize, path_len: u32) -> u32 {
     let path = unsafe { core::slice::from_raw_parts(path_ptr as *const u16, path_len as usize) };
     let bind = unsafe { BIND.get() };
     bind.push(path.to_vec());
@@ -759,8 +804,8 @@ pub extern "C" fn host_bind_parameter(path_ptr: u32, path_len: u32) -> u32 {
 /// RECORDS the request (into `BROADCAST_BINDS`) and returns the GLOBAL slot id; `bind_device` creates and
 /// registers the slot after `init` returns. The device fetches the write ptr via `host_broadcast_ptr`.
 /// Touches only its own cells, so it is safe to call re-entrantly from `init`.
-#[no_mangle]
-pub extern "C" fn host_bind_broadcast(path_ptr: u32, path_len: u32, len: u32, package_type: u32) -> u32 {
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn host_bind_broadcast(path_ptr: usize, path_len: u32, len: u32, package_type: u32) -> u32 {
     let path = unsafe { core::slice::from_raw_parts(path_ptr as *const u16, path_len as usize) };
     let registry = unsafe { DEVICE_BROADCASTS.get() };
     let id = match unsafe { DEVICE_BROADCAST_FREE.get() }.pop() {
@@ -773,20 +818,20 @@ pub extern "C" fn host_bind_broadcast(path_ptr: u32, path_len: u32, len: u32, pa
 
 /// The write pointer of a device broadcast slot (0 while unbound). Reads only the registry cell, so a device
 /// may call it lazily from `process`.
-#[no_mangle]
-pub extern "C" fn host_broadcast_ptr(id: u32) -> u32 {
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn host_broadcast_ptr(id: u32) -> usize {
     unsafe { DEVICE_BROADCASTS.get() }.get(id as usize).map_or(0, |entry| entry.0)
 }
 
 /// Whether the UI currently subscribes to a device broadcast slot (round-tripped by the worklet through
 /// `broadcast_set_active`). Producers may skip cold work (the spectrum FFT) while inactive.
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn host_broadcast_active(id: u32) -> u32 {
     unsafe { DEVICE_BROADCASTS.get() }.get(id as usize).map_or(0, |entry| entry.1 as u32)
 }
 
-#[no_mangle]
-pub extern "C" fn host_observe_sample(path_ptr: u32, path_len: u32) -> u32 {
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn host_observe_sample(path_ptr: usize, path_len: u32) -> u32 {
     let path = unsafe { core::slice::from_raw_parts(path_ptr as *const u16, path_len as usize) };
     let obs = unsafe { SAMPLE_OBS.get() };
     obs.push(path.to_vec());
@@ -798,8 +843,8 @@ pub extern "C" fn host_observe_sample(path_ptr: u32, path_len: u32) -> u32 {
 /// after `init` the engine reactively tracks the pointer, resolving + requesting the soundfont and delivering
 /// the handle via `soundfont_changed`. Touches no `&mut Engine`, so it is safe from `init`. Mirrors
 /// `host_observe_sample`.
-#[no_mangle]
-pub extern "C" fn host_observe_soundfont(path_ptr: u32, path_len: u32) -> u32 {
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn host_observe_soundfont(path_ptr: usize, path_len: u32) -> u32 {
     let path = unsafe { core::slice::from_raw_parts(path_ptr as *const u16, path_len as usize) };
     let obs = unsafe { SOUNDFONT_OBS.get() };
     obs.push(path.to_vec());
@@ -810,8 +855,8 @@ pub extern "C" fn host_observe_soundfont(path_ptr: u32, path_len: u32) -> u32 {
 /// Only RECORDS the path (into `FIELD_OBS`) and returns its id; after `init` the engine `catchup_and_subscribe`s
 /// it and delivers the value through the device's `field_changed`. NOT a parameter (no automation). Touches no
 /// `&mut Engine`, so it is safe from `init`.
-#[no_mangle]
-pub extern "C" fn host_observe_field(path_ptr: u32, path_len: u32) -> u32 {
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn host_observe_field(path_ptr: usize, path_len: u32) -> u32 {
     let path = unsafe { core::slice::from_raw_parts(path_ptr as *const u16, path_len as usize) };
     let obs = unsafe { FIELD_OBS.get() };
     obs.push(FieldObs {path: path.to_vec(), target_key: 0});
@@ -822,8 +867,8 @@ pub extern "C" fn host_observe_field(path_ptr: u32, path_len: u32) -> u32 {
 /// TARGET box's STRING field `field_key`: on catch-up and every set / repoint / clear the engine resolves the
 /// target and delivers its string through `field_changed` (`FIELD_KIND_STRING`, empty = unbound). Shares the
 /// `FIELD_OBS` id space with `host_observe_field`. Touches no `&mut Engine`, so it is safe from `init`.
-#[no_mangle]
-pub extern "C" fn host_observe_target_string(path_ptr: u32, path_len: u32, field_key: u32) -> u32 {
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn host_observe_target_string(path_ptr: usize, path_len: u32, field_key: u32) -> u32 {
     let path = unsafe { core::slice::from_raw_parts(path_ptr as *const u16, path_len as usize) };
     let obs = unsafe { FIELD_OBS.get() };
     obs.push(FieldObs {path: path.to_vec(), target_key: field_key as u16});
@@ -833,8 +878,8 @@ pub extern "C" fn host_observe_target_string(path_ptr: u32, path_len: u32, field
 /// Host import a SCRIPTABLE device calls from `init` to learn its own box uuid (16 bytes written to `out16_ptr`),
 /// which it passes to the JS script bridge so the bridge finds the matching user `Processor`. Reads only the
 /// `CURRENT_DEVICE_UUID` cell the engine set before `init`, never `&mut Engine`.
-#[no_mangle]
-pub extern "C" fn host_self_uuid(out16_ptr: u32) {
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn host_self_uuid(out16_ptr: usize) {
     let uuid = unsafe { *CURRENT_DEVICE_UUID.get() };
     unsafe { core::ptr::copy_nonoverlapping(uuid.as_ptr(), out16_ptr as *mut u8, 16); }
 }
@@ -843,8 +888,8 @@ pub extern "C" fn host_self_uuid(out16_ptr: u32) {
 /// path. Records the path (the engine resolves it after `init`) and returns the device-facing PORT id: `2` for
 /// the first sidechain, `3` for the next, and so on, after the reserved `MAIN_INPUT` (1). Touches no
 /// `&mut Engine`, so it is safe from `init`.
-#[no_mangle]
-pub extern "C" fn host_bind_sidechain(path_ptr: u32, path_len: u32) -> u32 {
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn host_bind_sidechain(path_ptr: usize, path_len: u32) -> u32 {
     let path = unsafe { core::slice::from_raw_parts(path_ptr as *const u16, path_len as usize) };
     let bind = unsafe { SIDECHAIN_BIND.get() };
     bind.push(path.to_vec());
@@ -855,8 +900,8 @@ pub extern "C" fn host_bind_sidechain(path_ptr: u32, path_len: u32) -> u32 {
 /// `out_ptr` and return 1 if the port is wired, else 0. `INPUTS` holds the current effect's ports (swapped in
 /// by `PluginAudioEffect::process`): id 1 the through-signal, ids 2+ its resolved sidechains. Reads `INPUTS`
 /// read-only, so it never aliases the `&mut Engine` the render path holds, exactly like `host_resolve_sample`.
-#[no_mangle]
-pub extern "C" fn host_resolve_input(id: u32, out_ptr: u32) -> u32 {
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn host_resolve_input(id: u32, out_ptr: usize) -> u32 {
     let ports = unsafe { INPUTS.get() };
     for &(port_id, left, right) in ports.iter() {
         if port_id == id {
@@ -871,7 +916,7 @@ pub extern "C" fn host_resolve_input(id: u32, out_ptr: u32) -> u32 {
 /// `RootBox.baseFrequency`): the Vaporisateur reads it per note-on, mirroring TS `computeFrequency`. Reads
 /// only the `BASE_FREQUENCY` cell, so it is safe re-entrantly during render, exactly like
 /// `host_resolve_sample` reading `SAMPLES`.
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn host_base_frequency() -> f32 {
     unsafe { *BASE_FREQUENCY.get() }
 }
@@ -881,8 +926,8 @@ pub extern "C" fn host_base_frequency() -> f32 {
 /// it handed out, and writes the changed `(id, value)` into `out` (a `ParamChange` scratch in the device's
 /// memory), returning the count. Static parameters are pushed at build / edit time, not here. Reads only
 /// `PULL` (the current device's params, swapped in by its node), so it is safe to call from inside `process`.
-#[no_mangle]
-pub extern "C" fn host_update_parameters(position: f64, out_ptr: u32, max: u32) -> u32 {
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn host_update_parameters(position: f64, out_ptr: usize, max: u32) -> u32 {
     let pull = unsafe { PULL.get() };
     let out = unsafe { core::slice::from_raw_parts_mut(out_ptr as *mut ParamChange, max as usize) };
     let mut count = 0;
@@ -909,7 +954,7 @@ pub extern "C" fn host_update_parameters(position: f64, out_ptr: u32, max: u32)
 /// Host import a (generative) device calls to map a pulse position to its sample offset within the current
 /// quantum, resolved against the block containing `pulse`. An arpeggiator uses it to time the events it
 /// emits on a rate grid. Reads only `PULL`, like `host_pull_events`.
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn host_pulse_to_offset(pulse: f64) -> u32 {
     let pull = unsafe { PULL.get() };
     if pull.blocks.is_null() {
@@ -958,6 +1003,7 @@ fn pull_from_source(source: &SharedNoteEventSource, from: f64, to: f64, flags: u
                 cent: 0.0,
                 duration: 0.0
             },
+            Event::Control {position, control} => control_record(position, control),
             Event::Update {..} => continue
         };
         out[count] = record;
@@ -966,6 +1012,16 @@ fn pull_from_source(source: &SharedNoteEventSource, from: f64, to: f64, flags: u
     count as u32
 }
 
+/// The ABI record for a channel control: the controller number in `pitch`, the normalised value in `velocity`.
+fn control_record(position: f64, control: Control) -> EventRecord {
+    let (kind, pitch, velocity) = match control {
+        Control::Change {controller, value} => (EVENT_CONTROL, controller as u32, value),
+        Control::PitchBend {value} => (EVENT_PITCH_BEND, 0, value),
+        Control::Pressure {value} => (EVENT_CHANNEL_PRESSURE, 0, value)
+    };
+    EventRecord {position, offset: 0, kind, id: 0, pitch, velocity, cent: 0.0, duration: 0.0}
+}
+
 /// Resolve a composite child's choke injector: pull the unit's full note stream and pass every note through
 /// (the child DEVICE filters to its own note, by the `index` it observed), ADDING a `CHOKE` record when a note
 /// in this child's choke group (`choke`) fires. The choke is emitted just before that note (and the device
@@ -998,6 +1054,9 @@ fn pull_from_slot_route(upstream: &SharedNoteEventSource, choke: &[i32], gate: &
             Event::NoteComplete {id, position, pitch} => {
                 out[count] = EventRecord {position, offset: 0, kind: EVENT_NOTE_OFF, id: id as u32, pitch: pitch as u32, velocity: 0.0, cent: 0.0, duration: 0.0};
             }
+            Event::Control {position, control} => {
+                out[count] = control_record(position, control); // channel-wide: reaches the slot even when gated
+            }
             Event::Update {..} => continue
         }
         count += 1;
@@ -1069,12 +1128,18 @@ struct Engine {
     // reaching `recording_start` flips to recording and restores the metronome preference.
     is_recording: bool,
     is_counting_in: bool,
+    // A count-in WITHOUT the forced click (no TS counterpart): the pre-roll or the wait for the playhead to
+    // reach the punch-in, armed from the playhead or again after a take's punch-out while looping takes.
+    pre_rolling: bool,
     recording_start: f64,
     recording_denominator: i32, // the signature denominator at the recording start (the count-in remaining unit)
     metronome_pref: bool,
     // Recording/loop preferences (TS settings.recording.allowTakes / settings.playback.pauseOnLoopDisabled).
     allow_takes: bool,
     pause_on_loop_disabled: bool,
+    // The pre-roll: the bars played before the punch-in when recording is armed from a stop (the punch range
+    // itself lives on the transport). Set by the worklet; no TS counterpart.
+    pre_roll_bars: f64,
     click_pending: Vec<f32>, // the buffer the worklet fills between `click_allocate` and `set_click_sound`
     // The EFFECTS-monitoring map (TS `#monitoringMap`): units whose chains inject the staged live input
     // and whose strip outputs are copied back for the worklet's monitor return.
@@ -1159,11 +1224,13 @@ impl Engine {
             metronome: Metronome::new(sample_rate),
             is_recording: false,
             is_counting_in: false,
+            pre_rolling: false,
             recording_start: 0.0,
             recording_denominator: 4,
             metronome_pref: false,
             allow_takes: true,
             pause_on_loop_disabled: false,
+            pre_roll_bars: 0.0,
             click_pending: Vec::new(),
             monitoring_map: Vec::new(),
             stem_exports: Vec::new(),
@@ -1215,9 +1282,9 @@ impl Engine {
     /// Register a loaded device: the table slot holding its `process` and the bytes its state block needs.
     /// Returns the device id (its index). The host calls this once per device, before `bind`.
     #[allow(clippy::too_many_arguments)] // one slot per device export; positional to match the loader's call
-    fn device_register(&mut self, process_index: u32, state_size: u32, kind: u32, init_index: u32, parameter_changed_index: u32, field_changed_index: u32, sample_changed_index: u32, soundfont_changed_index: u32, reset_index: u32, terminate_index: u32, midi_effects_field: u32, audio_effects_field: u32, param_collection_field: u32, sample_collection_field: u32) -> u32 {
+    fn device_register(&mut self, process_index: u32, state_size: u32, kind: u32, init_index: u32, parameter_changed_index: u32, field_changed_index: u32, sample_changed_index: u32, soundfont_changed_index: u32, reset_index: u32, terminate_index: u32, latency_index: u32, midi_effects_field: u32, audio_effects_field: u32, param_collection_field: u32, sample_collection_field: u32) -> u32 {
         let id = self.devices.len() as u32;
-        self.devices.push(DeviceReg {process_index, state_size, kind, init_index, parameter_changed_index, field_changed_index, sample_changed_index, soundfont_changed_index, reset_index, terminate_index,
+        self.devices.push(DeviceReg {process_index, state_size, kind, init_index, parameter_changed_index, field_changed_index, sample_changed_index, soundfont_changed_index, reset_index, terminate_index, latency_index,
             midi_effects_field: midi_effects_field as u16, audio_effects_field: audio_effects_field as u16,
             param_collection_field: param_collection_field as u16, sample_collection_field: sample_collection_field as u16});
         id
@@ -1307,9 +1374,12 @@ impl Engine {
         }
         // apply the latest timeline values recorded by the subscriptions
         self.transport.set_bpm(self.controls.bpm.get());
+        self.follow_recording_range();
         // TS BlockRenderer:143 loop gate: no wrap during a count-in, none while recording without takes;
         // `pauseOnLoopDisabled` keeps the loop ACTION armed (it pauses at the loop end instead of wrapping).
-        let loop_gate = (self.controls.loop_enabled.get() && !self.is_counting_in
+        // A pre-roll is no count-in here: waiting for the punch-in of the next take has to wrap.
+        let counting_in = self.is_counting_in && !self.pre_rolling;
+        let loop_gate = (self.controls.loop_enabled.get() && !counting_in
             && (!self.is_recording || self.allow_takes)) || self.pause_on_loop_disabled;
         self.transport.set_loop_enabled(loop_gate);
         self.transport.set_loop_pause(self.pause_on_loop_disabled);
@@ -1323,14 +1393,6 @@ impl Engine {
             None
         };
         self.tempo_map.borrow_mut().update(self.controls.bpm.get(), tempo_curve);
-        // The count-in flip (TS `renderer.setCallback(recordingStartTime, ...)`): once the playhead reaches
-        // the recording start, counting-in becomes recording and the metronome returns to its preference.
-        // Quantum-granular (TS splits the block at the exact position; one quantum ≈ 2.7 ms).
-        if self.is_counting_in && self.transport.position() >= self.recording_start {
-            self.is_counting_in = false;
-            self.is_recording = true;
-            self.metronome.set_enabled(self.metronome_pref);
-        }
         let recording_start = self.recording_start;
         let denominator = self.recording_denominator;
         let sample_rate = self.sample_rate;
@@ -1343,7 +1405,7 @@ impl Engine {
         }
         let Engine {transport, metronome, metronome_staging, context, output_bus, blocks, tempo, tempo_map: _,
             controls, signature, marker_track, marker_changes, midi_out, is_recording, is_counting_in,
-            metronome_pref, ..} = self;
+            metronome_pref, pre_rolling, ..} = self;
         // `Metronome::process` mixes ADDITIVELY, so its buffer starts cleared every quantum, exactly like
         // `output` above.
         metronome_staging.fill(0.0);
@@ -1375,9 +1437,15 @@ impl Engine {
                 // additionally be copied out as its own stem.
                 let (left, right) = metronome_staging.split_at_mut(RENDER_QUANTUM);
                 metronome.process(block, signature_slice, &mut left[block.s0..block.s1], &mut right[block.s0..block.s1]);
+                let mut flags = BlockFlags::create(true, block.discontinuous, true, false);
+                // Sample-accurate, unlike the quantum-granular punch-in flip: an armed recording marks every
+                // block inside the punch range, including the rest of the quantum the punch-in lands in.
+                if block.punch && (*is_recording || *is_counting_in) {
+                    flags.0 |= BlockFlags::PUNCH;
+                }
                 blocks.push(Block {
                     index: blocks.len() as u32,
-                    flags: BlockFlags::create(true, block.discontinuous, true, false),
+                    flags,
                     p0: block.p0,
                     p1: block.p1,
                     s0: block.s0 as u32,
@@ -1392,6 +1460,7 @@ impl Engine {
             if !transport.is_playing() {
                 *is_recording = false;
                 *is_counting_in = false;
+                *pre_rolling = false;
                 metronome.set_enabled(*metronome_pref); // apply_metronome with the count-in force gone
                 let covered = blocks.last().map_or(0, |block| block.s1 as usize);
                 if covered < RENDER_QUANTUM {
@@ -1470,20 +1539,43 @@ impl Engine {
     /// Arm recording (TS `EngineProcessor.#prepareRecordingState`): stopped + count-in wanted -> run the
     /// transport from `recording_start - countInBars` with the metronome forced on (the flip to recording
     /// happens in `render` when the playhead reaches the start); else record immediately from here.
+    /// With a punch range the recording start is the punch-in: armed from a stop, playback starts the
+    /// pre-roll ahead of it (or the count-in when there is no pre-roll), else it runs on from the playhead.
+    /// Up to the punch-in the engine reports a count-in, so nothing before it is captured.
     fn prepare_recording_state(&mut self, count_in: bool, count_in_bars: f64) {
         if self.is_recording || self.is_counting_in {
             return;
         }
-        if !self.transport.is_playing() && count_in {
-            // The count-in bar length: the signature IN EFFECT at the recording start (via the signature
-            // track; the storage signature when the track is empty/disabled). NOTE: TS `#prepareRecordingState`
-            // reads the static `timelineBoxAdapter.signature` even with signature events present — this
-            // resolves the track instead, identical whenever the track is empty or disabled.
+        let stopped = !self.transport.is_playing();
+        if let Some((punch_in, _)) = self.transport.punch_range() {
+            let count_in = stopped && count_in && self.pre_roll_bars <= 0.0;
+            let lead_in = if stopped && self.pre_roll_bars > 0.0 {
+                self.pre_roll_bars
+            } else if count_in {
+                count_in_bars
+            } else {
+                0.0
+            };
+            let (offset, denominator) = self.bars_before(punch_in, lead_in);
+            self.recording_start = punch_in;
+            self.recording_denominator = denominator;
+            self.is_counting_in = true;
+            self.pre_rolling = !count_in;
+            self.apply_metronome();
+            if lead_in > 0.0 {
+                self.transport.seek(punch_in - offset);
+            }
+            self.transport.play();
+            if stopped {
+                self.schedule_midi_transport(midi_output::position_message(self.transport.position()));
+                if !count_in {
+                    self.schedule_midi_transport(midi_output::start_message());
+                }
+            }
+            self.follow_recording_range(); // armed inside the punch range: record from here
+        } else if stopped && count_in {
             let position = self.transport.position();
-            let (nominator, denominator) = self.signature.as_ref()
-                .map_or((self.controls.nominator.get(), self.controls.denominator.get()),
-                        |track| track.signature_at(position));
-            let offset = dsp::ppqn::from_signature((count_in_bars * nominator as f64) as i32, denominator);
+            let (offset, denominator) = self.bars_before(position, count_in_bars);
             self.recording_start = position;
             self.recording_denominator = denominator;
             self.is_counting_in = true;
@@ -1499,6 +1591,47 @@ impl Engine {
         }
     }
 
+    /// The recording flips that follow the playhead, once per quantum before it renders (quantum-granular:
+    /// TS splits the block at the exact position; one quantum ≈ 2.7 ms). Reaching the recording start turns
+    /// the count-in into recording and returns the metronome to its preference (TS
+    /// `renderer.setCallback(recordingStartTime, ...)`), with a punch range only inside it. Leaving the punch
+    /// range ends the recording while the transport plays on; when the loop wraps takes, the engine instead
+    /// waits for the next pass to reach the punch-in.
+    fn follow_recording_range(&mut self) {
+        let position = self.transport.position();
+        let punch = self.transport.punch_range();
+        if self.is_counting_in {
+            if position >= self.recording_start && punch.is_none_or(|(_, punch_out)| position < punch_out) {
+                self.is_counting_in = false;
+                self.pre_rolling = false;
+                self.is_recording = true;
+                self.apply_metronome();
+            }
+        } else if let Some((punch_in, punch_out)) = punch.filter(|_| self.is_recording) {
+            if position < punch_in || position >= punch_out {
+                self.is_recording = false;
+                if self.controls.loop_enabled.get() && self.allow_takes {
+                    self.is_counting_in = true;
+                    self.pre_rolling = true;
+                } else {
+                    unsafe { IGNORED_REGIONS.get() }.clear();
+                }
+                self.apply_metronome();
+            }
+        }
+    }
+
+    /// The pulse length of `bars` bars in the signature in effect at `position`, and that signature's
+    /// denominator. The signature comes from the signature track (the storage signature when the track is
+    /// empty/disabled). NOTE: TS `#prepareRecordingState` reads the static `timelineBoxAdapter.signature`
+    /// even with signature events present — identical whenever the track is empty or disabled.
+    fn bars_before(&self, position: f64, bars: f64) -> (f64, i32) {
+        let (nominator, denominator) = self.signature.as_ref()
+            .map_or((self.controls.nominator.get(), self.controls.denominator.get()),
+                    |track| track.signature_at(position));
+        (dsp::ppqn::from_signature((bars * nominator as f64) as i32, denominator), denominator)
+    }
+
     /// End recording (TS `EngineProcessor.#stopRecording`): drop the flags, restore the metronome, and
     /// PAUSE the transport (no reset — the recorded takes stay audible where the playhead stopped).
     fn stop_recording(&mut self) {
@@ -1507,6 +1640,7 @@ impl Engine {
         }
         self.is_recording = false;
         self.is_counting_in = false;
+        self.pre_rolling = false;
         self.apply_metronome();
         self.transport.stop(false);
         unsafe { IGNORED_REGIONS.get() }.clear();
@@ -1518,6 +1652,7 @@ impl Engine {
     fn pause(&mut self) {
         self.is_recording = false;
         self.is_counting_in = false;
+        self.pre_rolling = false;
         self.apply_metronome();
         unsafe { IGNORED_REGIONS.get() }.clear();
         // TS `#stop` schedules ONE MidiData.Stop per stop command; the worklet's stop command always runs
@@ -1532,6 +1667,7 @@ impl Engine {
     fn stop(&mut self) {
         self.is_recording = false;
         self.is_counting_in = false;
+        self.pre_rolling = false;
         self.apply_metronome();
         unsafe { IGNORED_REGIONS.get() }.clear();
         self.transport.stop(true);
@@ -1607,6 +1743,15 @@ impl Engine {
         }
     }
 
+    /// Configure the stem export (before `bind`): the unit stems in export order, plus the metronome as one
+    /// further pair when `metronome_stem` is set. Sizes the staging every render fills.
+    fn set_stem_export(&mut self, stems: Vec<StemEntry>, metronome_stem: bool) {
+        let pairs = stems.len() + usize::from(metronome_stem);
+        self.stem_staging = vec![0.0; pairs * 2 * RENDER_QUANTUM];
+        self.stem_exports = stems;
+        self.metronome_stem = metronome_stem;
+    }
+
     /// The stem-export options of `unit` (TS `AudioUnitOptions.Default` when it is not a stem), consulted
     /// by the chain wiring and the send/output resolution.
     pub(crate) fn unit_options(&self, unit: &[u8; 16]) -> StemEntry {
@@ -1652,7 +1797,7 @@ impl Engine {
     /// The effective metronome: the preference, FORCED on during a count-in (TS sets
     /// `timeInfo.metronomeEnabled = true` for the count-in and restores the preference at the flip).
     fn apply_metronome(&mut self) {
-        self.metronome.set_enabled(self.metronome_pref || self.is_counting_in);
+        self.metronome.set_enabled(self.metronome_pref || (self.is_counting_in && !self.pre_rolling));
     }
 
     /// Subscribe the timeline controls + the tempo / note collections to the synced `TimelineBox`.
@@ -1867,8 +2012,9 @@ fn write_engine_state(transport: &Transport, state: &mut [u8], is_recording: boo
     state[STATE_POSITION..STATE_POSITION + 4].copy_from_slice(&(transport.position() as f32).to_be_bytes());
     state[STATE_BPM..STATE_BPM + 4].copy_from_slice(&transport.bpm().to_be_bytes());
     state[STATE_PLAYBACK_TIMESTAMP..STATE_PLAYBACK_TIMESTAMP + 4].copy_from_slice(&0f32.to_be_bytes());
+    // Waiting for the punch-in of the next take starts PAST the recording start (at the punch-out): no count yet.
     let count_in_remaining = if is_counting_in {
-        ((recording_start - transport.position()) / dsp::ppqn::from_signature(1, denominator.max(1))) as f32
+        ((recording_start - transport.position()) / dsp::ppqn::from_signature(1, denominator.max(1))).max(0.0) as f32
     } else {
         0.0
     };
@@ -1881,12 +2027,12 @@ fn write_engine_state(transport: &Transport, state: &mut [u8], is_recording: boo
 
 // ---- The C ABI: thin wrappers over the single `Engine` + the I/O buffers. ----
 
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn input_ptr() -> *mut u8 {
     unsafe { INPUT.get().as_mut_ptr() }
 }
 
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn input_capacity() -> usize {
     unsafe { INPUT.get().capacity() }
 }
@@ -1894,7 +2040,7 @@ pub extern "C" fn input_capacity() -> usize {
 /// Ensure the input scratch can hold `len` bytes, growing it (and keeping the larger buffer) if needed.
 /// Returns the buffer's address, which a grow may have moved, so the host must use this result. Cheap when
 /// `len` already fits (the common case), so the host can call it before every transaction.
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn input_reserve(len: usize) -> *mut u8 {
     unsafe {
         let input = INPUT.get();
@@ -1929,12 +2075,12 @@ fn perf_now() -> f64 {
 // ---- live-telemetry BROADCAST TABLE (plans/wasm-audio/live-broadcaster.md) -------------------------------
 
 /// Bumps whenever the broadcast table changed (a register or a sweep); the worklet re-reads the table then.
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn broadcast_generation() -> u32 {
     unsafe { ENGINE.get().as_ref().map_or(0, |engine| engine.broadcasts.generation()) }
 }
 
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn broadcast_count() -> u32 {
     unsafe { ENGINE.get().as_ref().map_or(0, |engine| engine.broadcasts.len() as u32) }
 }
@@ -1943,7 +2089,7 @@ pub extern "C" fn broadcast_count() -> u32 {
 /// `[uuid 16][package_type u32][ptr u32][len u32][keys_count u32][keys u16 x 8]`. Returns 1, or 0 when out
 /// of range OR when the entry's owning slot died (its `ptr` points into freed heap — serving it would hand
 /// the worklet a view over allocator garbage; the next sweep drops it).
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn broadcast_entry(index: u32, out_ptr: u32) -> u32 {
     unsafe {
         let Some(engine) = ENGINE.get().as_ref() else { return 0 };
@@ -1969,7 +2115,7 @@ pub extern "C" fn broadcast_entry(index: u32, out_ptr: u32) -> u32 {
 /// [processors, labels, queue_len, queue_cap, next_node_id, output_registry, graph_vertices,
 ///  box_count, subscription_count, broadcasts, audio_units, output_registry_engine,
 ///  sample_slots_ever, sample_slots_live].
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn debug_probe(out_ptr: u32) {
     unsafe {
         let Some(engine) = ENGINE.get().as_ref() else { return };
@@ -1988,7 +2134,7 @@ pub extern "C" fn debug_probe(out_ptr: u32) {
 }
 
 /// The UI's subscription flag round-trip (a producer MAY skip cold work; meters are always-on today).
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn broadcast_set_active(index: u32, active: u32) {
     unsafe {
         if let Some(engine) = ENGINE.get().as_mut() {
@@ -1998,7 +2144,7 @@ pub extern "C" fn broadcast_set_active(index: u32, active: u32) {
             if let Some(entry) = engine.broadcasts.entry(index as usize) {
                 let ptr = entry.ptr;
                 for slot in DEVICE_BROADCASTS.get().iter_mut() {
-                    if slot.0 == ptr {
+                    if slot.0 as u32 == ptr {
                         slot.1 = active != 0;
                     }
                 }
@@ -2008,7 +2154,7 @@ pub extern "C" fn broadcast_set_active(index: u32, active: u32) {
 }
 
 /// Enable (or re-zero) per-node render profiling.
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn profile_enable() {
     unsafe {
         if let Some(engine) = ENGINE.get().as_mut() {
@@ -2020,7 +2166,7 @@ pub extern "C" fn profile_enable() {
 /// Write the profile as a UTF-8 text table into `out_ptr` (client-reserved via `input_reserve`), sorted by
 /// accumulated time descending: `micros<TAB>label` per line, headed by `quanta <n>`. Returns bytes written
 /// (truncated to `max`). Diagnostic path, allocation is fine here.
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn profile_report(out_ptr: u32, max: u32) -> u32 {
     unsafe {
         let Some(engine) = ENGINE.get().as_mut() else { return 0 };
@@ -2040,7 +2186,7 @@ pub extern "C" fn profile_report(out_ptr: u32, max: u32) -> u32 {
 /// Refresh the 32-byte checksum buffer from the current graph and return its pointer. Computing the
 /// checksum is a full-graph walk (O(all boxes)), so it runs ONLY here — on the throttled verification
 /// round-trip (~1/s), never per transaction. Called from the worklet's checksum-verify path.
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn checksum_ptr() -> *const u8 {
     unsafe {
         if let Some(engine) = ENGINE.get().as_ref() {
@@ -2050,22 +2196,22 @@ pub extern "C" fn checksum_ptr() -> *const u8 {
     }
 }
 
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn output_ptr() -> *const f32 {
     unsafe { OUTPUT.get().as_ptr() }
 }
 
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn output_len() -> usize {
     RENDER_QUANTUM * 2
 }
 
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn engine_state_ptr() -> *const u8 {
     unsafe { ENGINE_STATE.get().as_ptr() }
 }
 
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn engine_state_len() -> usize {
     ENGINE_STATE_LEN
 }
@@ -2073,7 +2219,7 @@ pub extern "C" fn engine_state_len() -> usize {
 /// Reset to a fresh engine with an empty graph, KEEPING the sample rate the engine was created with
 /// (call before replaying a fresh session). No-op if `init` has not created the engine yet: the sample
 /// rate is only known from creation, so there is nothing to reset to before then.
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn reset() {
     unsafe {
         if let Some(sample_rate) = ENGINE.get().as_ref().map(|engine| engine.sample_rate) {
@@ -2086,7 +2232,7 @@ pub extern "C" fn reset() {
 
 /// Apply one forward-only transaction from the first `len` input bytes, refreshing the checksum
 /// buffer. Returns 0 on success, 1 on a decode/apply error or if the engine was not created (`init`).
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn apply_updates(len: usize) -> i32 {
     unsafe {
         let engine = match ENGINE.get().as_mut() {
@@ -2109,7 +2255,7 @@ pub extern "C" fn apply_updates(len: usize) -> i32 {
 
 /// Initialize the engine for `sample_rate`: empty graph, a STOPPED transport (the UI starts playback with
 /// `play`), and a metronome.
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn init(sample_rate: f32) {
     let engine = Engine::new(sample_rate);
     unsafe {
@@ -2120,7 +2266,7 @@ pub extern "C" fn init(sample_rate: f32) {
 }
 
 /// Render one 128-frame quantum into the output buffer and refresh the EngineState back-channel.
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn render() {
     unsafe {
         if let Some(engine) = ENGINE.get().as_mut() {
@@ -2138,11 +2284,10 @@ pub extern "C" fn render() {
 /// `metronome_stem` (TS `exportConfiguration.metronome.stem`) appends the metronome as one further pair
 /// AFTER the unit stems, so it is part of the same allocation rather than a second call that could leave the
 /// staging a pair short.
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn set_stem_export(count: u32, metronome_stem: u32) {
     unsafe {
         if let Some(engine) = ENGINE.get().as_mut() {
-            engine.metronome_stem = metronome_stem != 0;
             let bytes = core::slice::from_raw_parts(INPUT.get().as_ptr(), count as usize * 20);
             let mut stems = Vec::with_capacity(count as usize);
             for index in 0..count as usize {
@@ -2158,9 +2303,7 @@ pub extern "C" fn set_stem_export(count: u32, metronome_stem: u32) {
                     skip_channel_strip: flags & 8 != 0
                 });
             }
-            let pairs = stems.len() + usize::from(engine.metronome_stem);
-            engine.stem_staging = alloc::vec![0.0; pairs * 2 * RENDER_QUANTUM];
-            engine.stem_exports = stems;
+            engine.set_stem_export(stems, metronome_stem != 0);
         }
     }
 }
@@ -2168,7 +2311,7 @@ pub extern "C" fn set_stem_export(count: u32, metronome_stem: u32) {
 /// FREEZE step 1 (TS `EngineCommands.setFrozenAudio`): allocate the pending PCM buffer (ALWAYS
 /// `frame_count * 2` f32, the final planar stereo layout) and return its write pointer; the writer fills
 /// plane 0 (and plane 1 when stereo), then calls `set_frozen_audio` with the unit uuid in the input scratch.
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn frozen_allocate(frame_count: u32, _channels: u32) -> *mut f32 {
     unsafe {
         match ENGINE.get().as_mut() {
@@ -2185,7 +2328,7 @@ pub extern "C" fn frozen_allocate(frame_count: u32, _channels: u32) -> *mut f32
 /// whose 16-byte uuid sits in the input scratch, and re-wire it to frozen playback. Takes the pending
 /// buffer AS-IS (no allocation, no copy — it already has the stereo layout); a mono freeze duplicates
 /// plane 0 into plane 1 in place.
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn set_frozen_audio(frame_count: u32, channels: u32, sample_rate: f32) {
     unsafe {
         if let Some(engine) = ENGINE.get().as_mut() {
@@ -2203,7 +2346,7 @@ pub extern "C" fn set_frozen_audio(frame_count: u32, channels: u32, sample_rate:
 
 /// UNFREEZE (TS `setFrozenAudio(uuid, null)`): drop the unit's frozen PCM (uuid in the input scratch) and
 /// re-wire its live chain.
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn clear_frozen_audio() {
     unsafe {
         if let Some(engine) = ENGINE.get().as_mut() {
@@ -2215,7 +2358,7 @@ pub extern "C" fn clear_frozen_audio() {
 }
 
 /// The stem staging base pointer (`stems * 2 * 128` f32, planar per stem).
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn stem_output_ptr() -> *const f32 {
     unsafe {
         match ENGINE.get().as_ref() {
@@ -2227,14 +2370,14 @@ pub extern "C" fn stem_output_ptr() -> *const f32 {
 
 /// The EFFECTS-monitoring INPUT staging (`MONITOR_CHANNELS * 128` f32, channel-planar): the worklet writes
 /// the live input channels here BEFORE each `render`.
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn monitor_input_ptr() -> *mut f32 {
     unsafe { MONITOR_INPUT.get().as_mut_ptr() }
 }
 
 /// The EFFECTS-monitoring OUTPUT staging (same layout): each mapped unit's strip output lands here after
 /// `render`; the worklet forwards it on its second output (the MonitoringRouter return).
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn monitor_output_ptr() -> *const f32 {
     unsafe { MONITOR_OUTPUT.get().as_ptr() }
 }
@@ -2242,7 +2385,7 @@ pub extern "C" fn monitor_output_ptr() -> *const f32 {
 /// Replace the EFFECTS-monitoring map (TS `EngineCommands.updateMonitoringMap`): `count` records of
 /// `[unit uuid 16][left channel i32 LE][right channel i32 LE]` (right -1 = mono) in the input scratch.
 /// Every unit leaving or joining the map re-wires (the `MonitorMix` injector joins / leaves its chain).
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn set_monitoring_map(count: u32) {
     unsafe {
         if let Some(engine) = ENGINE.get().as_mut() {
@@ -2261,7 +2404,7 @@ pub extern "C" fn set_monitoring_map(count: u32) {
     }
 }
 
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn play() {
     unsafe {
         if let Some(engine) = ENGINE.get().as_mut() {
@@ -2270,7 +2413,7 @@ pub extern "C" fn play() {
     }
 }
 
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn pause() {
     unsafe {
         if let Some(engine) = ENGINE.get().as_mut() {
@@ -2279,7 +2422,7 @@ pub extern "C" fn pause() {
     }
 }
 
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn stop() {
     unsafe {
         if let Some(engine) = ENGINE.get().as_mut() {
@@ -2290,7 +2433,7 @@ pub extern "C" fn stop() {
 
 /// Arm recording (TS `EngineCommands.prepareRecordingState`): `count_in_bars` comes from the caller's
 /// preferences (the engine owns the signature). See `Engine::prepare_recording_state`.
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn prepare_recording_state(count_in: i32, count_in_bars: f64) {
     unsafe {
         if let Some(engine) = ENGINE.get().as_mut() {
@@ -2300,7 +2443,7 @@ pub extern "C" fn prepare_recording_state(count_in: i32, count_in_bars: f64) {
 }
 
 /// End recording (TS `EngineCommands.stopRecording`): drops the flags and pauses the transport.
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn stop_recording() {
     unsafe {
         if let Some(engine) = ENGINE.get().as_mut() {
@@ -2312,7 +2455,7 @@ pub extern "C" fn stop_recording() {
 /// Exclude a note region from playback (TS `EngineCommands.ignoreNoteRegion`): the region currently being
 /// RECORDED INTO must not re-trigger the notes being captured. The 16-byte region uuid is written into the
 /// input scratch first. Cleared on stop / stopRecording.
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn ignore_note_region() {
     let mut uuid = [0u8; 16];
     uuid.copy_from_slice(unsafe { core::slice::from_raw_parts(INPUT.get().as_ptr(), 16) });
@@ -2322,7 +2465,7 @@ pub extern "C" fn ignore_note_region() {
     }
 }
 
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn set_position(position: f64) {
     unsafe {
         if let Some(engine) = ENGINE.get().as_mut() {
@@ -2347,7 +2490,7 @@ fn input_uuid() -> Uuid {
 /// through the clip's `clips` pointer, key 1); `schedule_clip_stop` takes a TRACK uuid. Transitions queue
 /// for `clip_changes_take`: records of [uuid 16][kind u32 LE] (0 started, 1 stopped, 2 obsolete), feeding
 /// the notifyClipSequenceChanges back-channel.
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn schedule_clip_play() {
     unsafe {
         if let Some(engine) = ENGINE.get().as_mut() {
@@ -2356,7 +2499,7 @@ pub extern "C" fn schedule_clip_play() {
     }
 }
 
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn schedule_clip_stop() {
     unsafe {
         if let Some(engine) = ENGINE.get().as_mut() {
@@ -2365,7 +2508,7 @@ pub extern "C" fn schedule_clip_stop() {
     }
 }
 
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn clip_changes_count() -> u32 {
     unsafe {
         match ENGINE.get().as_ref() {
@@ -2375,7 +2518,7 @@ pub extern "C" fn clip_changes_count() -> u32 {
     }
 }
 
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn clip_changes_take(out_ptr: u32) -> u32 {
     unsafe {
         let Some(engine) = ENGINE.get().as_mut() else { return 0 };
@@ -2401,7 +2544,7 @@ pub extern "C" fn clip_changes_take(out_ptr: u32) -> u32 {
 /// fall-through, a seek into another section). Changes queue as 24-byte records
 /// [uuid 16][count u32 LE][flag u32 LE: 1 active marker, 0 none] drained via `marker_changes_take`
 /// (the clip-changes pattern; reserve `marker_changes_count() * 24` input bytes first).
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn marker_changes_count() -> u32 {
     unsafe {
         match ENGINE.get().as_ref() {
@@ -2411,7 +2554,7 @@ pub extern "C" fn marker_changes_count() -> u32 {
     }
 }
 
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn marker_changes_take(out_ptr: u32) -> u32 {
     unsafe {
         let Some(engine) = ENGINE.get().as_mut() else { return 0 };
@@ -2433,7 +2576,7 @@ pub extern "C" fn marker_changes_take(out_ptr: u32) -> u32 {
 /// (reserve `midi_out_count() * 16` input bytes first). `device` resolves to the `MIDIOutputBox.id` string
 /// via `midi_out_device_id` (UTF-8 written to out_ptr, byte length returned; 0 = unknown number) — numbers
 /// are stable first-seen indices, so the worklet caches the mapping.
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn midi_out_count() -> u32 {
     unsafe {
         match ENGINE.get().as_ref() {
@@ -2443,7 +2586,7 @@ pub extern "C" fn midi_out_count() -> u32 {
     }
 }
 
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn midi_out_take(out_ptr: u32) -> u32 {
     unsafe {
         let Some(engine) = ENGINE.get().as_mut() else { return 0 };
@@ -2462,7 +2605,7 @@ pub extern "C" fn midi_out_take(out_ptr: u32) -> u32 {
     }
 }
 
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn midi_out_device_id(num: u32, out_ptr: u32, max: u32) -> u32 {
     unsafe {
         let Some(engine) = ENGINE.get().as_ref() else { return 0 };
@@ -2475,7 +2618,7 @@ pub extern "C" fn midi_out_device_id(num: u32, out_ptr: u32, max: u32) -> u32 {
     }
 }
 
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn note_signal_on(pitch: u32, velocity: f32) {
     unsafe {
         if let Some(engine) = ENGINE.get().as_ref() {
@@ -2484,7 +2627,7 @@ pub extern "C" fn note_signal_on(pitch: u32, velocity: f32) {
     }
 }
 
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn note_signal_off(pitch: u32) {
     unsafe {
         if let Some(engine) = ENGINE.get().as_ref() {
@@ -2493,7 +2636,7 @@ pub extern "C" fn note_signal_off(pitch: u32) {
     }
 }
 
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn note_signal_audition(pitch: u32, duration: f64, velocity: f32) {
     unsafe {
         if let Some(engine) = ENGINE.get().as_ref() {
@@ -2502,8 +2645,39 @@ pub extern "C" fn note_signal_audition(pitch: u32, duration: f64, velocity: f32)
     }
 }
 
+/// A live MIDI controller change (CC `controller`, `value` in `0..1`) for the unit in the input scratch.
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn note_signal_control(controller: u32, value: f32) {
+    unsafe {
+        if let Some(engine) = ENGINE.get().as_ref() {
+            let control = Control::Change {controller: controller.min(127) as u8, value};
+            engine.note_signal(input_uuid(), audio_unit::NoteSignal::Control(control))
+        }
+    }
+}
+
+/// A live pitch-wheel move (`value` in `-1..1`) for the unit in the input scratch.
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn note_signal_pitch_bend(value: f32) {
+    unsafe {
+        if let Some(engine) = ENGINE.get().as_ref() {
+            engine.note_signal(input_uuid(), audio_unit::NoteSignal::Control(Control::PitchBend {value}))
+        }
+    }
+}
+
+/// Live channel pressure (`value` in `0..1`) for the unit in the input scratch.
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn note_signal_channel_pressure(value: f32) {
+    unsafe {
+        if let Some(engine) = ENGINE.get().as_ref() {
+            engine.note_signal(input_uuid(), audio_unit::NoteSignal::Control(Control::Pressure {value}))
+        }
+    }
+}
+
 /// TS `settings.recording.allowTakes`: while recording WITHOUT takes the loop wrap is suppressed.
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn set_allow_takes(enabled: i32) {
     unsafe {
         if let Some(engine) = ENGINE.get().as_mut() {
@@ -2512,8 +2686,33 @@ pub extern "C" fn set_allow_takes(enabled: i32) {
     }
 }
 
+/// The punch range in pulses: while enabled, recording runs only inside `from..to`, arming recording
+/// targets the punch-in and the armed blocks inside the range carry `BlockFlags::PUNCH`. An empty range
+/// (`from >= to`) never punches.
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn set_punch(enabled: i32, from: f64, to: f64) {
+    unsafe {
+        if let Some(engine) = ENGINE.get().as_mut() {
+            engine.transport.set_punch_enabled(enabled != 0);
+            engine.transport.set_punch_from(from);
+            engine.transport.set_punch_to(to);
+        }
+    }
+}
+
+/// The pre-roll in bars: arming recording from a stop with a punch range starts playback this far ahead
+/// of the punch-in (0 = no pre-roll; a count-in then leads into the punch-in instead).
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn set_pre_roll_bars(bars: f64) {
+    unsafe {
+        if let Some(engine) = ENGINE.get().as_mut() {
+            engine.pre_roll_bars = bars.max(0.0)
+        }
+    }
+}
+
 /// TS `settings.playback.pauseOnLoopDisabled`: reaching the loop end PAUSES instead of wrapping.
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn set_pause_on_loop_disabled(enabled: i32) {
     unsafe {
         if let Some(engine) = ENGINE.get().as_mut() {
@@ -2523,7 +2722,7 @@ pub extern "C" fn set_pause_on_loop_disabled(enabled: i32) {
 }
 
 /// The `playback.truncateNotesAtRegionEnd` preference (TS `NoteSequencer` reads it live per block).
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn set_truncate_notes_at_region_end(enabled: i32) {
     unsafe {
         if let Some(engine) = ENGINE.get().as_ref() {
@@ -2532,7 +2731,7 @@ pub extern "C" fn set_truncate_notes_at_region_end(enabled: i32) {
     }
 }
 
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn set_metronome_enabled(enabled: i32) {
     unsafe {
         if let Some(engine) = ENGINE.get().as_mut() {
@@ -2543,7 +2742,7 @@ pub extern "C" fn set_metronome_enabled(enabled: i32) {
 
 /// The metronome preferences (TS `preferences.settings.metronome`), forwarded by the worklet's
 /// engine-preferences subscriptions: click gain in dB (<= 0).
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn set_metronome_gain(gain_db: f32) {
     unsafe {
         if let Some(engine) = ENGINE.get().as_mut() {
@@ -2553,7 +2752,7 @@ pub extern "C" fn set_metronome_gain(gain_db: f32) {
 }
 
 /// Beat sub-division (1 | 2 | 4 | 8): clicks every `1 / (denominator * division)` note.
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn set_metronome_beat_sub_division(division: u32) {
     unsafe {
         if let Some(engine) = ENGINE.get().as_mut() {
@@ -2563,7 +2762,7 @@ pub extern "C" fn set_metronome_beat_sub_division(division: u32) {
 }
 
 /// Monophonic clicks: a new click fades every sounding one out over 5 ms (TS `Click.fadeOut`).
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn set_metronome_monophonic(enabled: i32) {
     unsafe {
         if let Some(engine) = ENGINE.get().as_mut() {
@@ -2575,7 +2774,7 @@ pub extern "C" fn set_metronome_monophonic(enabled: i32) {
 /// CLICK-SOUND upload step 1 (TS `EngineCommands.loadClickSound`, the frozen-audio pattern): allocate
 /// the pending PCM buffer (`frame_count * channels` f32, planar) and return its write pointer; the
 /// worklet fills the planes, then calls `set_click_sound`.
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn click_allocate(frame_count: u32, channels: u32) -> *mut f32 {
     unsafe {
         match ENGINE.get().as_mut() {
@@ -2590,7 +2789,7 @@ pub extern "C" fn click_allocate(frame_count: u32, channels: u32) -> *mut f32 {
 
 /// CLICK-SOUND upload step 2: hand the pending PCM to the metronome as click `index` (0 downbeat,
 /// 1 beat), keeping the sound's own sample rate (playback resamples like the TS `Click`).
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn set_click_sound(index: u32, frame_count: u32, channels: u32, sample_rate: f32) {
     unsafe {
         if let Some(engine) = ENGINE.get().as_mut() {
@@ -2602,7 +2801,7 @@ pub extern "C" fn set_click_sound(index: u32, frame_count: u32, channels: u32, s
 }
 
 /// Bind the synced `TimelineBox`. Returns 0 on success, 1 if absent.
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn bind() -> i32 {
     unsafe {
         match ENGINE.get().as_mut() {
@@ -2614,7 +2813,7 @@ pub extern "C" fn bind() -> i32 {
 
 /// Allocate `size` bytes of engine (talc) memory for a loading device and return the address. The host
 /// loader uses this for a device's relocated data region (its `__memory_base`) and its stack.
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn device_alloc(size: u32) -> u32 {
     unsafe {
         match ENGINE.get().as_mut() {
@@ -2628,14 +2827,15 @@ pub extern "C" fn device_alloc(size: u32) -> u32 {
 /// `state_size` the bytes per instance state block, `kind` its `kind` export (instrument / effect), and
 /// `init_index` / `parameter_changed_index` / `reset_index` / `terminate_index` its lifecycle-hook slots (0
 /// when the device exports none). `terminate_index` fires once, only when the device's INSTANCE dies (a
-/// genuine removal), never on a chain-edit survivor. Returns the device id. Call once per device, before
-/// `bind` (which builds the graph and wires devices).
-#[no_mangle]
+/// genuine removal), never on a chain-edit survivor. `latency_index` is its optional `latency` slot, read by
+/// delay compensation. Returns the device id. Call once per device, before `bind` (which builds the graph and
+/// wires devices).
+#[cfg_attr(target_family = "wasm", no_mangle)]
 #[allow(clippy::too_many_arguments)] // one positional arg per device export, matching the loader's call
-pub extern "C" fn device_register(process_index: u32, state_size: u32, kind: u32, init_index: u32, parameter_changed_index: u32, field_changed_index: u32, sample_changed_index: u32, soundfont_changed_index: u32, reset_index: u32, terminate_index: u32, midi_effects_field: u32, audio_effects_field: u32, param_collection_field: u32, sample_collection_field: u32) -> u32 {
+pub extern "C" fn device_register(process_index: u32, state_size: u32, kind: u32, init_index: u32, parameter_changed_index: u32, field_changed_index: u32, sample_changed_index: u32, soundfont_changed_index: u32, reset_index: u32, terminate_index: u32, latency_index: u32, midi_effects_field: u32, audio_effects_field: u32, param_collection_field: u32, sample_collection_field: u32) -> u32 {
     unsafe {
         match ENGINE.get().as_mut() {
-            Some(engine) => engine.device_register(process_index, state_size, kind, init_index, parameter_changed_index, field_changed_index, sample_changed_index, soundfont_changed_index, reset_index, terminate_index, midi_effects_field, audio_effects_field, param_collection_field, sample_collection_field),
+            Some(engine) => engine.device_register(process_index, state_size, kind, init_index, parameter_changed_index, field_changed_index, sample_changed_index, soundfont_changed_index, reset_index, terminate_index, latency_index, midi_effects_field, audio_effects_field, param_collection_field, sample_collection_field),
             None => 0
         }
     }
@@ -2645,7 +2845,7 @@ pub extern "C" fn device_register(process_index: u32, state_size: u32, kind: u32
 /// name into the input buffer (first `name_len` bytes) and calls this once per device after registering it.
 /// This table is the entire device-to-plugin glue; the engine instantiates a device box by looking its
 /// type up here.
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn device_set_box_type(device_id: u32, name_len: usize) {
     unsafe {
         let engine = match ENGINE.get().as_mut() {
@@ -2663,7 +2863,7 @@ pub extern "C" fn device_set_box_type(device_id: u32, name_len: usize) {
 /// writes the UTF-8 composite box name into the input buffer (first `name_len` bytes) and passes the child
 /// collection's host field key + the child index/routing key. Mirrors `device_set_box_type`; the engine reads
 /// no composite specifics beyond this, so a composite box plays with zero engine changes.
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 #[allow(clippy::too_many_arguments)]
 pub extern "C" fn composite_register(name_len: usize, children_field: u32, index_key: u32, exclude_key: u32,
                                      cell_instrument_field: u32, cell_midi_field: u32, cell_audio_field: u32, child_enabled_key: u32,
@@ -2685,7 +2885,7 @@ pub extern "C" fn composite_register(name_len: usize, children_field: u32, index
 /// Register an EFFECT composite box type (a parallel fx / note stack): its entry collection + the entry box's
 /// field keys + the composite's dry / wet + input tap. The sibling of `composite_register`; the engine reads no
 /// specifics beyond this record, so a new split container needs no engine change.
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 #[allow(clippy::too_many_arguments)]
 pub extern "C" fn effect_composite_register(name_len: usize, kind: u32, distributor: u32, entries_field: u32,
                                             index_key: u32, chain_field: u32, label_key: u32, gain_key: u32,
@@ -2718,8 +2918,8 @@ pub(crate) fn resolve_sample(uuid: boxgraph::address::Uuid) -> Option<abi::Sampl
 /// Resolve a sample handle (Route F) for a device DURING render: write a `SampleRef` to `out_ptr` and return
 /// 1 if the sample is resident (ready), else 0. Bound into each device's `env` like the other `host_*`
 /// imports; reads the `SAMPLES` cell read-only, so it never aliases the `&mut Engine` the render path holds.
-#[no_mangle]
-pub extern "C" fn host_resolve_sample(handle: u32, out_ptr: u32) -> u32 {
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn host_resolve_sample(handle: u32, out_ptr: usize) -> u32 {
     match unsafe { SAMPLES.get() }.resolve(handle) {
         Some(sample_ref) => {
             unsafe { *(out_ptr as *mut abi::SampleRef) = sample_ref; }
@@ -2732,7 +2932,7 @@ pub extern "C" fn host_resolve_sample(handle: u32, out_ptr: u32) -> u32 {
 /// Pop the next sample awaiting a load (the engine queued it on seeing an `AudioFileBox`): write its 16-byte
 /// uuid to `out_ptr` and return its handle, or return -1 when none are pending. The worklet drains these
 /// after applying a transaction and dispatches each to the main-thread loader. Off-render.
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn sample_take_request(out_ptr: u32) -> i32 {
     match unsafe { SAMPLES.get() }.take_pending() {
         Some((handle, uuid)) => {
@@ -2745,14 +2945,14 @@ pub extern "C" fn sample_take_request(out_ptr: u32) -> i32 {
 
 /// Reserve `byte_len` zeroed bytes for the sample's planar f32 frames and return the pointer the loader
 /// writes into. Off-render (the worklet calls it once the loader reports the decoded size).
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn sample_allocate(handle: u32, byte_len: u32) -> u32 {
-    unsafe { SAMPLES.get() }.allocate(handle, byte_len as usize)
+    unsafe { SAMPLES.get() }.allocate(handle, byte_len as usize) as u32
 }
 
 /// Mark a sample ready once the loader has written its frames: `channel_count` planes of `frame_count` f32
 /// each, at `sample_rate`. After this the sample resolves for devices. Off-render.
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn sample_set_ready(handle: u32, frame_count: u32, channel_count: u32, sample_rate: f32) {
     unsafe { SAMPLES.get() }.set_ready(handle, frame_count, channel_count, sample_rate);
 }
@@ -2760,8 +2960,8 @@ pub extern "C" fn sample_set_ready(handle: u32, frame_count: u32, channel_count:
 /// Resolve a soundfont handle for a device DURING render: write a `SoundfontRef` (ptr + len) to `out_ptr` and
 /// return 1 if the blob is resident (ready), else 0. Bound into each device's `env`; reads the `SOUNDFONTS`
 /// cell read-only, so it never aliases the `&mut Engine` the render path holds. Mirrors `host_resolve_sample`.
-#[no_mangle]
-pub extern "C" fn host_resolve_soundfont(handle: u32, out_ptr: u32) -> u32 {
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn host_resolve_soundfont(handle: u32, out_ptr: usize) -> u32 {
     match unsafe { SOUNDFONTS.get() }.resolve(handle) {
         Some(soundfont_ref) => {
             unsafe { *(out_ptr as *mut abi::SoundfontRef) = soundfont_ref; }
@@ -2774,7 +2974,7 @@ pub extern "C" fn host_resolve_soundfont(handle: u32, out_ptr: u32) -> u32 {
 /// Pop the next soundfont awaiting a load (queued on seeing a `SoundfontFileBox` target): write its 16-byte
 /// uuid to `out_ptr` and return its handle, or -1 when none pending. The worklet drains these after applying a
 /// transaction and dispatches each to the main-thread soundfont loader. Off-render.
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn soundfont_take_request(out_ptr: u32) -> i32 {
     match unsafe { SOUNDFONTS.get() }.take_pending() {
         Some((handle, uuid)) => {
@@ -2787,14 +2987,14 @@ pub extern "C" fn soundfont_take_request(out_ptr: u32) -> i32 {
 
 /// Reserve `byte_len` zeroed bytes for the soundfont blob and return the pointer the loader writes into.
 /// Off-render (the worklet calls it once the loader reports the built blob size).
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn soundfont_allocate(handle: u32, byte_len: u32) -> u32 {
     unsafe { SOUNDFONTS.get() }.allocate(handle, byte_len as usize)
 }
 
 /// Mark a soundfont ready once the loader has written its blob. After this the soundfont resolves for the
 /// device. Off-render.
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn soundfont_set_ready(handle: u32) {
     unsafe { SOUNDFONTS.get() }.set_ready(handle);
 }
@@ -2830,13 +3030,13 @@ mod heap {
     static TALC: EngineAlloc = EngineAlloc(TalcCell::new(WasmGrowAndClaim));
 
     /// Bytes currently allocated (live).
-    #[no_mangle]
+    #[cfg_attr(target_family = "wasm", no_mangle)]
     pub extern "C" fn heap_used() -> usize {
         TALC.0.counters().allocated_bytes
     }
 
     /// Total bytes the heap manages (live + free) — the claimed footprint.
-    #[no_mangle]
+    #[cfg_attr(target_family = "wasm", no_mangle)]
     pub extern "C" fn heap_claimed() -> usize {
         let counters = TALC.0.counters();
         counters.allocated_bytes + counters.available_bytes
@@ -2851,11 +3051,13 @@ const PANIC_MESSAGE_CAPACITY: usize = 512;
 static PANIC_MESSAGE: Shared<([u8; PANIC_MESSAGE_CAPACITY], usize)> =
     Shared::new(([0; PANIC_MESSAGE_CAPACITY], 0));
 
+#[cfg(target_family = "wasm")]
 struct PanicWriter {
     buffer: &'static mut [u8],
     written: usize
 }
 
+#[cfg(target_family = "wasm")]
 impl core::fmt::Write for PanicWriter {
     fn write_str(&mut self, text: &str) -> core::fmt::Result {
         let bytes = text.as_bytes();
@@ -2866,27 +3068,27 @@ impl core::fmt::Write for PanicWriter {
     }
 }
 
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn panic_message_ptr() -> *const u8 {
     unsafe { PANIC_MESSAGE.get() }.0.as_ptr()
 }
 
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn panic_message_len() -> usize {
     unsafe { PANIC_MESSAGE.get() }.1
 }
 
 /// Host import a DEVICE's panic handler calls to deposit ITS panic message before trapping (the abi crate's
 /// shared handler): copied into the same buffer the worklet reads. Writes nothing else, safe at any time.
-#[no_mangle]
-pub extern "C" fn host_panic(msg_ptr: u32, msg_len: u32) {
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn host_panic(msg_ptr: usize, msg_len: u32) {
     let (buffer, written) = unsafe { PANIC_MESSAGE.get() };
     let count = (msg_len as usize).min(PANIC_MESSAGE_CAPACITY);
     unsafe { core::ptr::copy_nonoverlapping(msg_ptr as *const u8, buffer.as_mut_ptr(), count); }
     *written = count;
 }
 
-#[cfg(not(test))]
+#[cfg(target_family = "wasm")]
 #[panic_handler]
 fn panic(info: &core::panic::PanicInfo) -> ! {
     use core::fmt::Write;
@@ -2901,8 +3103,10 @@ fn panic(info: &core::panic::PanicInfo) -> ! {
 
 #[cfg(test)]
 mod tests {
-    use super::{compare_lifecycle};
-    use engine_env::event::Event;
+    use super::{compare_lifecycle, control_record, Engine, ENGINE_STATE_LEN, RENDER_QUANTUM};
+    use abi::BlockFlags;
+    use abi::{EVENT_CONTROL, EVENT_PITCH_BEND};
+    use engine_env::event::{Control, Event};
 
     fn note_on(position: f64) -> Event {
         Event::NoteStart {id: 0, position, duration: 240.0, pitch: 60, cent: 0.0, velocity: 0.8}
@@ -2913,10 +3117,14 @@ mod tests {
     fn update(position: f64) -> Event {
         Event::Update {position}
     }
+    fn sustain(position: f64) -> Event {
+        Event::Control {position, control: Control::Change {controller: 64, value: 1.0}}
+    }
     fn kinds(events: &[Event]) -> Vec<&'static str> {
         events.iter().map(|event| match event {
             Event::NoteComplete {..} => "off",
             Event::Update {..} => "param",
+            Event::Control {..} => "control",
             Event::NoteStart {..} => "on"
         }).collect()
     }
@@ -2930,10 +3138,113 @@ mod tests {
         assert_eq!(kinds(&events), vec!["off", "param", "on", "on"]);
     }
 
+    #[test]
+    fn a_control_at_a_note_position_precedes_the_note_on() {
+        let mut events = vec![note_on(0.0), sustain(0.0), note_off(0.0)];
+        events.sort_unstable_by(compare_lifecycle);
+        assert_eq!(kinds(&events), vec!["off", "control", "on"]);
+    }
+
+    #[test]
+    fn controls_convert_to_channel_records() {
+        let record = control_record(12.0, Control::Change {controller: 1, value: 0.25});
+        assert_eq!((record.kind, record.pitch, record.velocity, record.position), (EVENT_CONTROL, 1, 0.25, 12.0));
+        let record = control_record(3.0, Control::PitchBend {value: -1.0});
+        assert_eq!((record.kind, record.id, record.velocity), (EVENT_PITCH_BEND, 0, -1.0));
+    }
+
     #[test]
     fn earlier_position_always_precedes_regardless_of_kind() {
         let mut events = [note_on(5.0), note_off(20.0), update(1.0)];
         events.sort_unstable_by(compare_lifecycle);
         assert_eq!(events.iter().map(Event::position).collect::<Vec<_>>(), vec![1.0, 5.0, 20.0]);
     }
+
+    const BAR: f64 = 3840.0;
+    // The pulses of one quantum at the default 120 bpm; a flip lands in the quantum after it is due and
+    // `render_until` reads the position after that quantum rendered.
+    const QUANTUM_PULSES: f64 = 5.12;
+
+    // Render quanta until `done` holds (or fail after a few bars' worth), returning the blocks of the last one.
+    fn render_until(engine: &mut Engine, done: impl Fn(&Engine) -> bool) -> Vec<abi::Block> {
+        let mut output = [0.0f32; RENDER_QUANTUM * 2];
+        let mut state = vec![0u8; ENGINE_STATE_LEN];
+        for _ in 0..10_000 {
+            engine.render(&mut output, &mut state);
+            if done(engine) {
+                return engine.blocks.clone();
+            }
+        }
+        panic!("never reached at {}", engine.transport.position());
+    }
+
+    fn punch_engine(from: f64, to: f64) -> Engine {
+        let mut engine = Engine::new(48_000.0);
+        engine.transport.set_punch_enabled(true);
+        engine.transport.set_punch_from(from);
+        engine.transport.set_punch_to(to);
+        engine
+    }
+
+    #[test]
+    fn a_pre_roll_plays_ahead_of_the_punch_in_and_records_only_inside_the_punch_range() {
+        let mut engine = punch_engine(2.0 * BAR, 3.0 * BAR);
+        engine.pre_roll_bars = 1.0;
+        engine.prepare_recording_state(true, 2.0);
+        assert_eq!(engine.transport.position(), BAR, "playback starts one bar (not the count-in) ahead");
+        assert!(engine.transport.is_playing() && engine.is_counting_in && !engine.is_recording);
+        assert!(engine.pre_rolling, "a pre-roll does not force the click");
+        render_until(&mut engine, |engine| engine.is_recording);
+        assert!(engine.transport.position() - 2.0 * BAR <= 2.0 * QUANTUM_PULSES, "recording starts at the punch-in");
+        render_until(&mut engine, |engine| !engine.is_recording);
+        assert!(engine.transport.position() - 3.0 * BAR <= 2.0 * QUANTUM_PULSES, "and ends at the punch-out");
+        assert!(engine.transport.is_playing() && !engine.is_counting_in, "playback runs on");
+        let blocks = render_until(&mut engine, |_| true);
+        assert!(blocks.iter().all(|block| !block.flags.has(BlockFlags::PUNCH)));
+    }
+
+    #[test]
+    fn armed_before_the_punch_in_waits_and_armed_inside_records_at_once() {
+        let mut engine = punch_engine(BAR + 1.0, 2.0 * BAR); // a punch-in within a quantum
+        engine.prepare_recording_state(false, 1.0);
+        assert!(engine.is_counting_in && !engine.is_recording, "the playhead runs on to the punch-in");
+        assert_eq!(engine.transport.position(), 0.0);
+        let blocks = render_until(&mut engine, |engine| engine.transport.position() >= BAR + 1.0);
+        assert!(blocks.last().is_some_and(|block| block.flags.punch()), "the blocks from the punch-in on are marked");
+        assert!(!blocks[0].flags.punch(), "the blocks ahead of it are not");
+        render_until(&mut engine, |engine| engine.is_recording);
+        engine.stop_recording();
+        engine.transport.seek(1.5 * BAR);
+        engine.prepare_recording_state(false, 1.0);
+        assert!(engine.is_recording && !engine.is_counting_in, "inside the range recording starts right away");
+    }
+
+    #[test]
+    fn looping_takes_wait_for_the_punch_in_of_every_pass() {
+        let mut engine = punch_engine(BAR, 2.0 * BAR);
+        engine.controls.loop_enabled.set(true);
+        engine.controls.loop_to.set(3.0 * BAR);
+        engine.prepare_recording_state(false, 1.0);
+        render_until(&mut engine, |engine| engine.is_recording);
+        render_until(&mut engine, |engine| !engine.is_recording);
+        assert!(engine.is_counting_in, "past the punch-out the next take is armed");
+        render_until(&mut engine, |engine| engine.transport.position() < BAR);
+        assert!(engine.is_counting_in && !engine.is_recording, "the loop wrapped while waiting");
+        render_until(&mut engine, |engine| engine.is_recording);
+        assert!(engine.transport.position() - BAR <= 2.0 * QUANTUM_PULSES, "the second take starts at the punch-in");
+    }
+
+    #[test]
+    fn without_takes_the_punch_out_ends_the_recording_and_the_loop_plays_on() {
+        let mut engine = punch_engine(BAR, 2.0 * BAR);
+        engine.allow_takes = false;
+        engine.controls.loop_enabled.set(true);
+        engine.controls.loop_to.set(3.0 * BAR);
+        engine.prepare_recording_state(false, 1.0);
+        render_until(&mut engine, |engine| engine.is_recording);
+        render_until(&mut engine, |engine| !engine.is_recording);
+        assert!(!engine.is_counting_in, "no take follows");
+        render_until(&mut engine, |engine| engine.transport.position() < BAR);
+        assert!(!engine.is_recording && !engine.is_counting_in && engine.transport.is_playing());
+    }
 }
@@ -260,7 +260,7 @@ mod tests {
         let mut p0 = start;
         for quantum in 0..quanta {
             let p1 = start + (quantum + 1) as f64 * step;
-            let block = Block {p0, p1, s0: 0, s1: QUANTUM, bpm: BPM, discontinuous: false};
+            let block = Block {p0, p1, s0: 0, s1: QUANTUM, bpm: BPM, discontinuous: false, punch: false};
             p0 = p1; // contiguous ranges like the transport's (p1 carries into the next block's p0)
             let mut left = [0.0f32; QUANTUM];
             let mut right = [0.0f32; QUANTUM];
@@ -306,7 +306,7 @@ mod tests {
         // (only 0), the region after restarts the (shorter) eighth grid AT the event.
         let mut metronome = impulse_metronome();
         let signature = [storage(4, 4), event(0, 480.0, 3, 8)];
-        let block = Block {p0: 0.0, p1: 1000.0, s0: 0, s1: QUANTUM, bpm: 96000.0, discontinuous: false};
+        let block = Block {p0: 0.0, p1: 1000.0, s0: 0, s1: QUANTUM, bpm: 96000.0, discontinuous: false, punch: false};
         let mut left = [0.0f32; QUANTUM];
         let mut right = [0.0f32; QUANTUM];
         metronome.process(&block, &signature, &mut left, &mut right);
@@ -394,7 +394,7 @@ mod tests {
         metronome.load_click_sound(0, ClickSound::new(vec![0.0, 1.0, 0.0], 3, 1, SAMPLE_RATE * 0.5));
         metronome.set_gain(0.0);
         let signature = [storage(4, 4)];
-        let block = Block {p0: 0.0, p1: 1.0, s0: 0, s1: QUANTUM, bpm: BPM, discontinuous: false};
+        let block = Block {p0: 0.0, p1: 1.0, s0: 0, s1: QUANTUM, bpm: BPM, discontinuous: false, punch: false};
         let mut left = [0.0f32; QUANTUM];
         let mut right = [0.0f32; QUANTUM];
         metronome.process(&block, &signature, &mut left, &mut right);
@@ -415,7 +415,7 @@ mod tests {
         metronome.load_click_sound(0, ClickSound::new(vec![1.0, 1.0, -1.0, -1.0], 2, 2, SAMPLE_RATE));
         metronome.set_gain(0.0);
         let signature = [storage(4, 4)];
-        let block = Block {p0: 0.0, p1: 1.0, s0: 0, s1: QUANTUM, bpm: BPM, discontinuous: false};
+        let block = Block {p0: 0.0, p1: 1.0, s0: 0, s1: QUANTUM, bpm: BPM, discontinuous: false, punch: false};
         let mut left = [0.0f32; QUANTUM];
         let mut right = [0.0f32; QUANTUM];
         metronome.process(&block, &signature, &mut left, &mut right);
@@ -425,7 +425,7 @@ impl MidiOutProcessor {
             pull.clock_armed = false;
             pull.note_bits = self.note_bits.clone(); // pulled notes mark THIS unit's note indicator
         }
-        let out_ptr = self.scratch.as_ptr() as usize as u32; // wasm address; only a MIDI-fx device call reads it
+        let out_ptr = self.scratch.as_ptr() as usize; // only a MIDI-fx device call reads it
         let count = crate::pull_events_into(block.p0, block.p1, block.flags.0,
             &mut self.scratch, out_ptr) as usize;
         {
@@ -0,0 +1,133 @@
+//! The native device table: off wasm there is no shared function table, so a device's `*_index` slot names a
+//! `device_host::Device` installed here instead, and every `call_device_*` dispatches through [`call`]. The
+//! device's `abi` wrappers reach the engine through [`EngineHost`], which serves each import from the same
+//! cells the wasm `host_*` exports read, so a device behaves exactly as it does in the worklet.
+//!
+//! The table is per thread: the offline renderer installs the stock devices on the thread it renders on, and
+//! a unit test's stub slots (`process_index: 1`, ...) stay empty on every other thread.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use abi::native::Host;
+use abi::{AudioInputRef, EventRecord, ParamChange, SampleRef, SoundfontRef};
+use device_host::Device;
+
+use crate::{host_base_frequency, host_bind_broadcast, host_bind_parameter, host_bind_sidechain, host_broadcast_active,
+            host_broadcast_ptr, host_first_update_position, host_next_update_position, host_observe_field,
+            host_observe_sample, host_observe_soundfont, host_observe_target_string, host_pulse_to_offset,
+            host_update_parameters, pull_events_into, CURRENT_DEVICE_UUID, INPUTS, RENDER_QUANTUM, SAMPLES, SOUNDFONTS};
+
+std::thread_local! {
+    // Slot `n` is entry `n - 1`; slot 0 stays the "none" sentinel.
+    static DEVICES: RefCell<Vec<Rc<dyn Device>>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Install a device and return its slot, the native stand-in for a function-table index.
+pub(crate) fn install(device: Rc<dyn Device>) -> u32 {
+    DEVICES.with(|devices| {
+        let mut devices = devices.borrow_mut();
+        devices.push(device);
+        devices.len() as u32
+    })
+}
+
+/// Drop every installed device (a fresh offline render re-installs them).
+pub(crate) fn clear() {
+    DEVICES.with(|devices| devices.borrow_mut().clear());
+}
+
+/// Run `body` against the device in `slot` with the engine serving its host imports; `None` for slot 0 or an
+/// empty slot. The device is cloned out first, so a host import that re-enters another device finds the table
+/// unborrowed.
+pub(crate) fn call<R>(slot: u32, body: impl FnOnce(&dyn Device) -> R) -> Option<R> {
+    if slot == 0 {
+        return None;
+    }
+    let device = DEVICES.with(|devices| devices.borrow().get(slot as usize - 1).cloned())?;
+    Some(abi::native::with_host(&mut EngineHost, || body(device.as_ref())))
+}
+
+/// The engine's import surface for a native device: each method forwards to its `host_*` export (whose
+/// pointer arguments are address-sized) or reads the cell that export reads.
+struct EngineHost;
+
+impl Host for EngineHost {
+    fn pull_events(&mut self, from: f64, to: f64, flags: u32, out: &mut [EventRecord]) -> usize {
+        let out_ptr = out.as_mut_ptr() as usize;
+        pull_events_into(from, to, flags, out, out_ptr) as usize
+    }
+
+    fn pulse_to_offset(&mut self, pulse: f64) -> u32 {
+        host_pulse_to_offset(pulse)
+    }
+
+    fn bind_parameter(&mut self, path: &[u16]) -> u32 {
+        host_bind_parameter(path.as_ptr() as usize, path.len() as u32)
+    }
+
+    fn update_parameters(&mut self, position: f64, out: &mut [ParamChange]) -> usize {
+        host_update_parameters(position, out.as_mut_ptr() as usize, out.len() as u32) as usize
+    }
+
+    fn first_update_position(&mut self, at: f64) -> f64 {
+        host_first_update_position(at)
+    }
+
+    fn next_update_position(&mut self, after: f64) -> f64 {
+        host_next_update_position(after)
+    }
+
+    fn observe_sample(&mut self, path: &[u16]) -> u32 {
+        host_observe_sample(path.as_ptr() as usize, path.len() as u32)
+    }
+
+    fn resolve_sample(&mut self, handle: u32) -> Option<SampleRef> {
+        unsafe { SAMPLES.get() }.resolve(handle)
+    }
+
+    fn observe_soundfont(&mut self, path: &[u16]) -> u32 {
+        host_observe_soundfont(path.as_ptr() as usize, path.len() as u32)
+    }
+
+    fn resolve_soundfont(&mut self, handle: u32) -> Option<SoundfontRef> {
+        unsafe { SOUNDFONTS.get() }.resolve(handle)
+    }
+
+    fn observe_field(&mut self, path: &[u16]) -> u32 {
+        host_observe_field(path.as_ptr() as usize, path.len() as u32)
+    }
+
+    fn observe_target_string(&mut self, path: &[u16], field_key: u16) -> u32 {
+        host_observe_target_string(path.as_ptr() as usize, path.len() as u32, field_key as u32)
+    }
+
+    fn bind_sidechain(&mut self, path: &[u16]) -> u32 {
+        host_bind_sidechain(path.as_ptr() as usize, path.len() as u32)
+    }
+
+    fn resolve_input(&mut self, id: u32) -> Option<AudioInputRef> {
+        unsafe { INPUTS.get() }.iter().find(|(port_id, _, _)| *port_id == id)
+            .map(|&(_, left, right)| AudioInputRef {left, right, frames: RENDER_QUANTUM as u32})
+    }
+
+    fn bind_broadcast(&mut self, path: &[u16], len: u32, package_type: u32) -> u32 {
+        host_bind_broadcast(path.as_ptr() as usize, path.len() as u32, len, package_type)
+    }
+
+    fn broadcast_ptr(&mut self, id: u32) -> usize {
+        host_broadcast_ptr(id)
+    }
+
+    fn broadcast_active(&mut self, id: u32) -> bool {
+        host_broadcast_active(id) != 0
+    }
+
+    fn base_frequency(&mut self) -> f32 {
+        host_base_frequency()
+    }
+
+    fn self_uuid(&mut self) -> [u8; 16] {
+        unsafe { *CURRENT_DEVICE_UUID.get() }
+    }
+}
@@ -0,0 +1,39 @@
+//! The device table of an offline render: what the studio's loader (`device-linker.ts`) does with the wasm
+//! side modules, done with the stock devices linked in natively. Every `device_host::Registry::stock` device
+//! is installed into the native device table and registered by box type, and the composite box types are
+//! registered as `engine-modules.ts` lists them, so a project renders with the same devices as the browser.
+
+use std::rc::Rc;
+
+use crate::{native_host, Distributor, Engine};
+
+impl Engine {
+    /// Install and register the stock devices and the composite box types.
+    pub(super) fn register_stock_devices(&mut self) {
+        native_host::clear();
+        for (box_type, device) in device_host::Registry::stock().into_devices() {
+            let device: Rc<dyn device_host::Device> = Rc::from(device);
+            let fields = [device.midi_effects_field(), device.audio_effects_field(), device.param_collection_field(),
+                device.sample_collection_field()];
+            let (kind, state_size) = (device.kind(), device.state_size(self.sample_rate));
+            // One slot serves every export: a `Device` answers an export it lacks with the engine's "none"
+            // behaviour (no call, no events, no latency), so no slot needs to stay 0.
+            let slot = native_host::install(device);
+            let id = self.device_register(slot, state_size, kind, slot, slot, slot, slot, slot, slot, slot, slot,
+                fields[0], fields[1], fields[2], fields[3]);
+            self.set_device_box_type(box_type, id as usize);
+        }
+        // `COMPOSITES` in engine-modules.ts.
+        self.register_composite(String::from("PlayfieldDeviceBox"), 10, 15, 42, 0, 0, 0, 22, 40, 41);
+        self.register_composite(String::from("CompositeDeviceBox"), 10, 5, 0, 2, 3, 4, 0, 0, 0);
+        // `EFFECT_COMPOSITES` in engine-modules.ts.
+        for (box_type, distributor, crossover_keys) in [
+            ("AudioEffectCompositeBox", Distributor::Broadcast, [0, 0, 0]),
+            ("StereoCompositeBox", Distributor::Stereo, [0, 0, 0]),
+            ("FrequencySplitBox", Distributor::Frequency, [14, 15, 16])
+        ] {
+            self.register_effect_composite(String::from(box_type), abi::DEVICE_KIND_AUDIO_EFFECT as u8, distributor,
+                10, 3, 2, 4, 40, 43, 41, 42, 12, 13, 11, crossover_keys);
+        }
+    }
+}
@@ -0,0 +1,385 @@
+//! The OFFLINE renderer: a native (non-wasm) entry point that loads a project, resolves its samples and
+//! soundfonts from a local directory, and renders a pulse range faster than realtime in one call — the
+//! batch-bounce / CI counterpart of the worklet calling `render` per quantum (and of the TS offline worker's
+//! `set_stem_export` + bind + render loop, whose order it follows).
+//!
+//! It drives the very same `Engine`: the same `bind`, the same per-quantum `render`, the same stem taps, so a
+//! bounce here is what the browser renders. The range ends sample-exactly through the transport's own
+//! pause-at-loop-end (TS `pauseOnLoopDisabled`), which leaves the graph running PAUSED for the release tail.
+//!
+//! The engine's resource cells (`SAMPLES`, `PULL`, ...) are process-wide, so a render holds the crate lock for
+//! its whole duration and resets them first: renders on parallel threads queue rather than race.
+//!
+//! Device plugins are wasm side modules the browser host installs into the engine's function table; here the
+//! stock devices are linked in natively and installed into the engine's native device table instead (see
+//! `devices`), so instruments and effects render as they do in the browser. Only the script and NAM bridges,
+//! whose DSP lives in JavaScript, stay silent.
+
+mod devices;
+mod project;
+mod wav;
+
+pub use project::{inspect, MarkerInfo, ProjectInfo, Signature, UnitInfo};
+
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use boxgraph::address::{uuid_to_string, Uuid};
+use boxgraph::bytes::ByteReader;
+use boxgraph::graph::BoxGraph;
+use crate::{pull_lock, Engine, StemEntry, BASE_FREQUENCY, DEVICE_BROADCASTS, DEVICE_BROADCAST_FREE, DEVICE_MAX_EVENTS,
+    ENGINE_STATE_LEN, IGNORED_REGIONS, MONITOR_INPUT, MONITOR_OUTPUT, PULL, RENDER_QUANTUM, SAMPLES, SOUNDFONTS};
+use crate::sample::SampleResource;
+use crate::soundfont::SoundfontResource;
+use soundfont_blob::sf2;
+use soundfont_blob::sfz::{self, SamplePcm, SfzFiles};
+
+const MAGIC_OPEN: i32 = 0x4F50_454E; // "OPEN", the ProjectSkeleton header
+const FORMAT_VERSION: i32 = 2;
+const SILENCE: f32 = 2.5e-4; // -72 dB, the TS offline render's default silence threshold
+const SILENCE_SECONDS: f64 = 0.25; // a tail this long below SILENCE has rung out
+
+/// One stem of a stem export (TS `ExportStemConfiguration` minus the file name): the audio unit to tap and
+/// how much of its chain the stem carries.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Stem {
+    pub unit: Uuid,
+    pub include_audio_effects: bool,
+    pub include_sends: bool,
+    pub use_instrument_output: bool,
+    pub skip_channel_strip: bool
+}
+
+impl Stem {
+    /// The TS export default: the unit's full chain with its sends, after the channel strip.
+    pub fn new(unit: Uuid) -> Self {
+        Self {unit, include_audio_effects: true, include_sends: true, use_instrument_output: false, skip_channel_strip: false}
+    }
+}
+
+/// What to render. `from` / `to` are pulses (960 per quarter); the project's own loop area is ignored, the
+/// range plays `loops` times back to back. With no stems the result is the stereo master mix.
+#[derive(Clone, Debug)]
+pub struct OfflineConfig {
+    pub sample_rate: f32,
+    pub from: f64,
+    pub to: f64,
+    /// Passes over the range, wrapping seamlessly like the loop area does (at least one). A range shorter
+    /// than a render quantum can overshoot: passes are counted per quantum.
+    pub loops: u32,
+    /// The longest release tail rendered after `to` (transport paused, so nothing new starts). It ends
+    /// earlier once the output has stayed below -72 dB for a quarter second.
+    pub tail_seconds: f64,
+    /// Unit stems in export order: stem `i` lands in channels `2i` / `2i + 1`.
+    pub stems: Vec<Stem>,
+    pub metronome: bool,
+    /// Append the metronome as one further stem pair after the unit stems (TS `metronome.stem`).
+    pub metronome_stem: bool
+}
+
+impl OfflineConfig {
+    pub fn new(sample_rate: f32, from: f64, to: f64) -> Self {
+        Self {sample_rate, from, to, loops: 1, tail_seconds: 0.0, stems: Vec::new(), metronome: false, metronome_stem: false}
+    }
+}
+
+/// A decoded sample: `channel_count` PLANAR f32 channels back to back, at `sample_rate`.
+pub struct SampleData {
+    pub frames: Vec<f32>,
+    pub channel_count: u32,
+    pub sample_rate: f32
+}
+
+/// Where a render finds the project's assets, keyed by the `AudioFileBox` / `SoundfontFileBox` uuid. `None`
+/// is a missing asset (reported in [`OfflineRender::missing`]); an `Err` aborts the render.
+pub trait AssetSource {
+    fn sample(&self, uuid: &Uuid) -> Result<Option<SampleData>, OfflineError>;
+    /// The engine's simplified soundfont blob (the `OSF2` layout the Soundfont device reads in place).
+    fn soundfont(&self, uuid: &Uuid) -> Result<Option<Vec<u8>>, OfflineError>;
+}
+
+/// An extracted project bundle (.odb): `samples/<uuid>/audio.wav` and `soundfonts/<uuid>/soundfont.sf2`, with
+/// the studio's `samples/v2/<uuid>/audio.wav` storage layout accepted too. The SF2 is built into the blob here,
+/// as the browser's main thread does (a file already holding the blob is taken as is), and so is a
+/// `soundfonts/<uuid>/soundfont.sfz` instrument with its WAV samples beside it.
+pub struct AssetDirectory {
+    root: PathBuf
+}
+
+impl AssetDirectory {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self {root: root.into()}
+    }
+
+    fn sample_path(&self, uuid: &Uuid) -> Option<PathBuf> {
+        let name = uuid_to_string(uuid);
+        [self.root.join("samples").join(&name), self.root.join("samples").join("v2").join(&name)]
+            .into_iter()
+            .map(|folder| folder.join("audio.wav"))
+            .find(|path| path.is_file())
+    }
+}
+
+impl AssetSource for AssetDirectory {
+    fn sample(&self, uuid: &Uuid) -> Result<Option<SampleData>, OfflineError> {
+        let Some(path) = self.sample_path(uuid) else { return Ok(None) };
+        let decoded = wav::decode(&fs::read(&path)?)
+            .map_err(|reason| OfflineError::Asset {uuid: *uuid, reason: format!("{}: {reason}", path.display())})?;
+        Ok(Some(SampleData {frames: decoded.frames, channel_count: decoded.channel_count, sample_rate: decoded.sample_rate}))
+    }
+
+    fn soundfont(&self, uuid: &Uuid) -> Result<Option<Vec<u8>>, OfflineError> {
+        let folder = self.root.join("soundfonts").join(uuid_to_string(uuid));
+        let sfz = folder.join("soundfont.sfz");
+        if sfz.is_file() {
+            let instrument = sfz::build(&fs::read_to_string(&sfz)?, &SfzFolder(&folder))
+                .map_err(|error| OfflineError::Asset {uuid: *uuid, reason: format!("{}: {error}", sfz.display())})?;
+            return Ok(Some(soundfont_blob::encode(&instrument)));
+        }
+        let path = folder.join("soundfont.sf2");
+        if !path.is_file() {
+            return Ok(None);
+        }
+        let bytes = fs::read(&path)?;
+        if bytes.len() >= 4 && u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) == soundfont_blob::MAGIC {
+            return Ok(Some(bytes));
+        }
+        let soundfont = sf2::build(&bytes)
+            .map_err(|error| OfflineError::Asset {uuid: *uuid, reason: format!("{}: {error}", path.display())})?;
+        Ok(Some(soundfont_blob::encode(&soundfont)))
+    }
+}
+
+/// An SFZ instrument's folder: its includes and WAV samples, by the paths the instrument names.
+struct SfzFolder<'a>(&'a Path);
+
+impl SfzFiles for SfzFolder<'_> {
+    fn text(&self, path: &str) -> Result<String, String> {
+        fs::read_to_string(self.0.join(path)).map_err(|error| error.to_string())
+    }
+
+    fn sample(&self, path: &str) -> Result<SamplePcm, String> {
+        let decoded = wav::decode(&fs::read(self.0.join(path)).map_err(|error| error.to_string())?)?;
+        let frame_count = decoded.frames.len() / decoded.channel_count as usize;
+        let planes = decoded.frames.chunks_exact(frame_count.max(1)).map(<[f32]>::to_vec).collect();
+        Ok(SamplePcm {planes, sample_rate: decoded.sample_rate})
+    }
+}
+
+/// A finished render: INTERLEAVED f32 frames, `channels` per frame (2 for a mixdown, `2 * pairs` for stems).
+pub struct OfflineRender {
+    pub sample_rate: f32,
+    pub channels: usize,
+    pub frames: Vec<f32>,
+    /// The assets the project references but the source could not provide; they rendered silent.
+    pub missing: Vec<Uuid>
+}
+
+impl OfflineRender {
+    pub fn frame_count(&self) -> usize {
+        self.frames.len() / self.channels.max(1)
+    }
+
+    /// Stem pair `index` as its own interleaved stereo signal: the stems in configuration order, then the
+    /// metronome stem if requested.
+    pub fn stereo_pair(&self, index: usize) -> Vec<f32> {
+        self.frames.chunks_exact(self.channels)
+            .flat_map(|frame| [frame[index * 2], frame[index * 2 + 1]])
+            .collect()
+    }
+
+    /// The whole render as one 32-bit float WAV file.
+    pub fn to_wav(&self) -> Vec<u8> {
+        wav::encode_32f(&self.frames, self.channels, self.sample_rate)
+    }
+
+    /// Write the render to `path` as a WAV file, creating its folder if needed.
+    pub fn write_wav(&self, path: &Path) -> io::Result<()> {
+        if let Some(parent) = path.parent().filter(|parent| !parent.as_os_str().is_empty()) {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, self.to_wav())
+    }
+}
+
+#[derive(Debug)]
+pub enum OfflineError {
+    /// The bytes are neither a project file nor a box graph the registry can decode.
+    Project(String),
+    /// The graph has no `TimelineBox`, so there is no transport to render.
+    NoTimeline,
+    /// `to` does not lie after `from`.
+    Range {from: f64, to: f64},
+    /// An asset exists but cannot be decoded.
+    Asset {uuid: Uuid, reason: String},
+    Io(io::Error)
+}
+
+impl fmt::Display for OfflineError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OfflineError::Project(reason) => write!(f, "invalid project: {reason}"),
+            OfflineError::NoTimeline => write!(f, "the project has no TimelineBox"),
+            OfflineError::Range {from, to} => write!(f, "empty render range {from}..{to}"),
+            OfflineError::Asset {uuid, reason} => write!(f, "asset {}: {reason}", uuid_to_string(uuid)),
+            OfflineError::Io(error) => write!(f, "{error}")
+        }
+    }
+}
+
+impl std::error::Error for OfflineError {}
+
+impl From<io::Error> for OfflineError {
+    fn from(error: io::Error) -> Self {
+        OfflineError::Io(error)
+    }
+}
+
+/// The box-graph chunk of `bytes`: a project file (.od, the ProjectSkeleton `OPEN` + version + chunk length
+/// header) is unwrapped, anything else is taken to be the bare chunk.
+pub fn project_chunk(bytes: &[u8]) -> Result<&[u8], OfflineError> {
+    let mut reader = ByteReader::new(bytes);
+    if reader.read_int() != Ok(MAGIC_OPEN) {
+        return Ok(bytes);
+    }
+    let version = reader.read_int().map_err(|_| OfflineError::Project(String::from("truncated header")))?;
+    if version != FORMAT_VERSION {
+        return Err(OfflineError::Project(format!("unsupported format version {version}")));
+    }
+    let length = reader.read_int().map_err(|_| OfflineError::Project(String::from("truncated header")))?;
+    let length = usize::try_from(length).map_err(|_| OfflineError::Project(format!("negative chunk length {length}")))?;
+    12usize.checked_add(length).and_then(|end| bytes.get(12..end))
+        .ok_or_else(|| OfflineError::Project(String::from("truncated box graph")))
+}
+
+/// Render `config`'s range of `project` (a project file or a bare box-graph chunk), resolving its assets
+/// through `assets`.
+pub fn render(project: &[u8], assets: &dyn AssetSource, config: &OfflineConfig) -> Result<OfflineRender, OfflineError> {
+    if config.to <= config.from {
+        return Err(OfflineError::Range {from: config.from, to: config.to});
+    }
+    let _lock = pull_lock();
+    reset_globals();
+    let mut engine = Engine::new(config.sample_rate);
+    engine.register_stock_devices();
+    engine.graph = BoxGraph::from_bytes(project_chunk(project)?, &engine.registry)
+        .map_err(|error| OfflineError::Project(format!("{error:?}")))?;
+    let stems = config.stems.iter().map(|stem| StemEntry {
+        uuid: stem.unit,
+        include_audio_effects: stem.include_audio_effects,
+        include_sends: stem.include_sends,
+        use_instrument_output: stem.use_instrument_output,
+        skip_channel_strip: stem.skip_channel_strip
+    }).collect();
+    engine.set_stem_export(stems, config.metronome_stem);
+    engine.set_metronome_enabled(config.metronome);
+    if engine.bind() != 0 {
+        return Err(OfflineError::NoTimeline);
+    }
+    let missing = load_assets(assets)?;
+    let frames = engine.render_range(config.from, config.to, config.loops, config.tail_seconds);
+    let channels = if engine.stem_staging.is_empty() { 2 } else { engine.stem_staging.len() / RENDER_QUANTUM };
+    Ok(OfflineRender {sample_rate: config.sample_rate, channels, frames, missing})
+}
+
+// Back to a fresh module's state: a previous render's resources and tuning must not leak into this one.
+fn reset_globals() {
+    unsafe {
+        *SAMPLES.get() = SampleResource::new();
+        *SOUNDFONTS.get() = SoundfontResource::new();
+        *BASE_FREQUENCY.get() = 440.0;
+        IGNORED_REGIONS.get().clear();
+        DEVICE_BROADCASTS.get().clear();
+        DEVICE_BROADCAST_FREE.get().clear();
+        MONITOR_INPUT.get().fill(0.0);
+        MONITOR_OUTPUT.get().fill(0.0);
+        PULL.get().scratch.reserve(DEVICE_MAX_EVENTS);
+    }
+}
+
+// The load handshake the worklet and the main thread run, done in-process: drain every sample / soundfont
+// `bind` requested and deliver it decoded. Returns the uuids the source could not provide.
+fn load_assets(assets: &dyn AssetSource) -> Result<Vec<Uuid>, OfflineError> {
+    let mut missing = Vec::new();
+    while let Some((handle, uuid)) = unsafe { SAMPLES.get() }.take_pending() {
+        match assets.sample(&uuid)? {
+            Some(sample) => unsafe { SAMPLES.get() }.deliver(handle, &sample.frames, sample.channel_count, sample.sample_rate),
+            None => missing.push(uuid)
+        }
+    }
+    while let Some((handle, uuid)) = unsafe { SOUNDFONTS.get() }.take_pending() {
+        match assets.soundfont(&uuid)? {
+            Some(blob) => unsafe { SOUNDFONTS.get() }.deliver(handle, &blob),
+            None => missing.push(uuid)
+        }
+    }
+    Ok(missing)
+}
+
+impl Engine {
+    /// Play `[from, to)` `loops` times and return the interleaved output (the stem staging when a stem
+    /// export is configured, else the master), followed by up to `tail_seconds` of paused release tail. The
+    /// range is the loop area: enabled while passes remain, then DISABLED with `pauseOnLoopDisabled` set, so
+    /// the transport stops exactly at `to` mid-quantum and the rest of that quantum is already the paused tail.
+    fn render_range(&mut self, from: f64, to: f64, loops: u32, tail_seconds: f64) -> Vec<f32> {
+        let mut passes = loops.max(1);
+        self.controls.loop_enabled.set(passes > 1);
+        self.controls.loop_from.set(from);
+        self.controls.loop_to.set(to);
+        self.pause_on_loop_disabled = passes == 1;
+        self.set_position(from);
+        self.play();
+        let channels = if self.stem_staging.is_empty() { 2 } else { self.stem_staging.len() / RENDER_QUANTUM };
+        let tail_frames = (tail_seconds.max(0.0) * self.sample_rate as f64).ceil() as usize;
+        let silence_frames = (SILENCE_SECONDS * self.sample_rate as f64).ceil() as usize;
+        let mut output = [0.0f32; RENDER_QUANTUM * 2];
+        let mut state = [0u8; ENGINE_STATE_LEN];
+        let mut frames = Vec::new();
+        let mut tail = 0usize; // tail frames rendered so far
+        let mut silent = 0usize; // trailing frames below SILENCE
+        let mut last_p1 = from;
+        loop {
+            let playing = self.transport.is_playing();
+            self.render(&mut output, &mut state);
+            self.copy_stem_outputs();
+            // A wrap is a jump back to `from` (the block before it ends at `to` within rounding, or one
+            // sample short when the split fell on a quantum boundary); once the last pass has begun, the
+            // next arrival at `to` pauses instead.
+            for block in self.blocks.iter().filter(|block| block.flags.transporting()) {
+                if block.p0 == from && block.p0 < last_p1 {
+                    passes = passes.saturating_sub(1);
+                }
+                last_p1 = block.p1;
+            }
+            if passes <= 1 && !self.pause_on_loop_disabled {
+                self.controls.loop_enabled.set(false);
+                self.pause_on_loop_disabled = true;
+            }
+            // Where the range ends in this quantum: after the last transporting block once the transport
+            // paused at `to`, nowhere while it still plays, at the quantum start when it was already paused.
+            let range_end = match (playing, self.transport.is_playing()) {
+                (true, true) => RENDER_QUANTUM,
+                (true, false) => self.blocks.iter().filter(|block| block.flags.transporting()).map(|block| block.s1 as usize).next_back().unwrap_or(0),
+                (false, _) => 0
+            };
+            let end = (range_end + (tail_frames - tail)).min(RENDER_QUANTUM);
+            let planar: &[f32] = if self.stem_staging.is_empty() { &output } else { &self.stem_staging };
+            for frame in 0..end {
+                let mut loud = false;
+                for channel in 0..channels {
+                    let sample = planar[channel * RENDER_QUANTUM + frame];
+                    loud |= sample.abs() > SILENCE;
+                    frames.push(sample);
+                }
+                if frame >= range_end {
+                    tail += 1;
+                    silent = if loud { 0 } else { silent + 1 };
+                }
+            }
+            if !self.transport.is_playing() && (tail == tail_frames || silent >= silence_frames) {
+                return frames;
+            }
+        }
+    }
+}
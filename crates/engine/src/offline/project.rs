@@ -0,0 +1,167 @@
+//! A read-only outline of a project's arrangement, for choosing what to render before rendering it: the
+//! timeline's tempo, length, loop area, signature changes and markers, and the audio units a stem export
+//! can tap. Bar and marker ranges resolve to pulses here.
+
+use boxgraph::address::{Address, Uuid};
+use boxgraph::field::FieldValue;
+use boxgraph::graph::BoxGraph;
+use dsp::ppqn::from_signature;
+use crate::signature_track::SignatureTrack;
+use super::{project_chunk, OfflineError};
+
+// Generated box field keys (studio-boxes), as the engine's binders use them.
+const TIMELINE_LOOP_AREA: u16 = 11; // {enabled (1), from (2), to (3)}
+const TIMELINE_MARKER_TRACK: u16 = 21; // {markers (1, hub), enabled (20)}
+const TIMELINE_DURATION: u16 = 30; // durationInPulses
+const TIMELINE_BPM: u16 = 31;
+const MARKER_POSITION: u16 = 2;
+const MARKER_PLAYS: u16 = 3;
+const MARKER_LABEL: u16 = 4;
+const UNIT_TYPE: u16 = 1; // "instrument" | "audio" | "aux" | "bus" | "output"
+const UNIT_INPUT: u16 = 22; // the host hub the instrument / bus box points at
+const UNIT_INDEX: u16 = 11;
+const DEVICE_LABEL: u16 = 2; // every instrument device box
+const BUS_LABEL: u16 = 6; // AudioBusBox
+
+/// A time-signature change: in effect from `position` (pulses) until the next one.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Signature {
+    pub position: f64,
+    pub nominator: i32,
+    pub denominator: i32
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct MarkerInfo {
+    pub position: f64,
+    /// How often the section up to the next marker plays; 0 repeats it forever.
+    pub plays: i32,
+    pub label: String
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct UnitInfo {
+    pub uuid: Uuid,
+    /// The `AudioUnitBox` type: `instrument`, `audio`, `aux`, `bus` or `output`.
+    pub kind: String,
+    /// The label of the instrument or bus feeding the unit (empty when it has none).
+    pub label: String
+}
+
+/// The arrangement facts a render range is chosen from. Positions are pulses (960 per quarter).
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProjectInfo {
+    pub bpm: f32,
+    /// The timeline length (`TimelineBox.durationInPulses`).
+    pub duration: f64,
+    pub loop_enabled: bool,
+    pub loop_from: f64,
+    pub loop_to: f64,
+    /// Ascending, the first at pulse 0 (the timeline's own signature).
+    pub signatures: Vec<Signature>,
+    /// Ascending by position.
+    pub markers: Vec<MarkerInfo>,
+    /// In mixer order, the output unit included.
+    pub units: Vec<UnitInfo>
+}
+
+impl ProjectInfo {
+    /// The start of zero-based `bar`, walking the signature changes (a change lands on a bar line).
+    pub fn bar_position(&self, bar: u32) -> f64 {
+        let mut position = 0.0;
+        let mut remaining = bar as f64;
+        for (index, signature) in self.signatures.iter().enumerate() {
+            let length = from_signature(signature.nominator, signature.denominator);
+            match self.signatures.get(index + 1) {
+                Some(next) if position + remaining * length > next.position => {
+                    remaining -= ((next.position - position) / length).round();
+                    position = next.position;
+                }
+                _ => return position + remaining * length
+            }
+        }
+        position
+    }
+
+    /// The section of the marker at `index`: from its position to the next marker, or to the timeline end
+    /// for the last one.
+    pub fn marker_range(&self, index: usize) -> Option<(f64, f64)> {
+        let marker = self.markers.get(index)?;
+        let end = self.markers.get(index + 1).map_or(self.duration, |next| next.position);
+        Some((marker.position, end))
+    }
+}
+
+/// Outline `project` (a project file or a bare box-graph chunk) without rendering it.
+pub fn inspect(project: &[u8]) -> Result<ProjectInfo, OfflineError> {
+    let mut graph = BoxGraph::from_bytes(project_chunk(project)?, &studio_boxes::registry())
+        .map_err(|error| OfflineError::Project(format!("{error:?}")))?;
+    let timeline = graph.find_by_name("TimelineBox").ok_or(OfflineError::NoTimeline)?.uuid;
+    let field = |graph: &BoxGraph, uuid: Uuid, keys: &[u16]| graph.field_value(&Address::of(uuid, keys.to_vec())).cloned();
+    let int = |graph: &BoxGraph, uuid: Uuid, keys: &[u16]| field(graph, uuid, keys).as_ref().and_then(FieldValue::as_int32);
+    let signatures = SignatureTrack::observe(&mut graph, timeline).events().iter()
+        .map(|event| Signature {position: event.accumulated_ppqn, nominator: event.nominator, denominator: event.denominator})
+        .collect();
+    let mut markers: Vec<MarkerInfo> = graph.incoming(&Address::of(timeline, vec![TIMELINE_MARKER_TRACK, 1])).into_iter()
+        .map(|source| MarkerInfo {
+            position: int(&graph, source.uuid, &[MARKER_POSITION]).unwrap_or(0) as f64,
+            plays: int(&graph, source.uuid, &[MARKER_PLAYS]).unwrap_or(1),
+            label: field(&graph, source.uuid, &[MARKER_LABEL]).as_ref().and_then(FieldValue::as_str).unwrap_or("").into()
+        })
+        .collect();
+    markers.sort_by(|a, b| a.position.total_cmp(&b.position));
+    let mut units: Vec<(i32, UnitInfo)> = graph.find_all_by_name("AudioUnitBox").into_iter()
+        .map(|unit| {
+            let label = graph.incoming(&Address::of(unit.uuid, vec![UNIT_INPUT])).first()
+                .and_then(|source| graph.find_box(&source.uuid))
+                .and_then(|input| input.fields.get(if input.name == "AudioBusBox" { &BUS_LABEL } else { &DEVICE_LABEL }))
+                .and_then(FieldValue::as_str)
+                .unwrap_or("")
+                .into();
+            let kind = unit.fields.get(&UNIT_TYPE).and_then(FieldValue::as_str).unwrap_or("").into();
+            let index = unit.fields.get(&UNIT_INDEX).and_then(FieldValue::as_int32).unwrap_or(0);
+            (index, UnitInfo {uuid: unit.uuid, kind, label})
+        })
+        .collect();
+    units.sort_by_key(|(index, _)| *index);
+    Ok(ProjectInfo {
+        bpm: field(&graph, timeline, &[TIMELINE_BPM]).as_ref().and_then(FieldValue::as_float32).unwrap_or(120.0),
+        duration: int(&graph, timeline, &[TIMELINE_DURATION]).unwrap_or(0) as f64,
+        loop_enabled: field(&graph, timeline, &[TIMELINE_LOOP_AREA, 1]).as_ref().and_then(FieldValue::as_bool).unwrap_or(false),
+        loop_from: int(&graph, timeline, &[TIMELINE_LOOP_AREA, 2]).unwrap_or(0) as f64,
+        loop_to: int(&graph, timeline, &[TIMELINE_LOOP_AREA, 3]).unwrap_or(0) as f64,
+        signatures,
+        markers,
+        units: units.into_iter().map(|(_, unit)| unit).collect()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ProjectInfo, Signature};
+
+    fn info(signatures: &[(f64, i32, i32)]) -> ProjectInfo {
+        ProjectInfo {
+            bpm: 120.0, duration: 0.0, loop_enabled: false, loop_from: 0.0, loop_to: 0.0,
+            signatures: signatures.iter()
+                .map(|&(position, nominator, denominator)| Signature {position, nominator, denominator})
+                .collect(),
+            markers: Vec::new(),
+            units: Vec::new()
+        }
+    }
+
+    #[test]
+    fn bars_follow_the_signature_changes() {
+        // two bars of 4/4, then 3/4
+        let info = info(&[(0.0, 4, 4), (7680.0, 3, 4)]);
+        assert_eq!(info.bar_position(0), 0.0);
+        assert_eq!(info.bar_position(2), 7680.0);
+        assert_eq!(info.bar_position(3), 7680.0 + 2880.0);
+    }
+
+    #[test]
+    fn a_single_signature_is_a_plain_grid() {
+        assert_eq!(info(&[(0.0, 7, 8)]).bar_position(4), 4.0 * 3360.0);
+    }
+}
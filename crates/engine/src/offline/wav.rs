@@ -0,0 +1,125 @@
+//! Homebrew RIFF WAV codec for the offline renderer: reads PCM 16/24/32-bit and IEEE float 32/64 (plus
+//! WAVE_FORMAT_EXTENSIBLE wrappers) into the engine's PLANAR sample layout, writes interleaved 32-bit float
+//! with any channel count (a stem export is `stems * 2` channels). No external crates.
+
+const FORMAT_PCM: u16 = 1;
+const FORMAT_FLOAT: u16 = 3;
+const FORMAT_EXTENSIBLE: u16 = 0xFFFE;
+
+/// A decoded file: `channel_count` planes of `frame_count` f32 each, back to back (the `SampleResource`
+/// layout, so it is delivered as-is).
+pub(crate) struct Decoded {
+    pub(crate) frames: Vec<f32>,
+    pub(crate) channel_count: u32,
+    pub(crate) sample_rate: f32
+}
+
+pub(crate) fn decode(bytes: &[u8]) -> Result<Decoded, String> {
+    if bytes.len() < 12 || &bytes[0..4] != b"RIFF" || &bytes[8..12] != b"WAVE" {
+        return Err(String::from("not a RIFF/WAVE file"));
+    }
+    let mut offset = 12usize;
+    let mut format_code = 0u16;
+    let mut channel_count = 0usize;
+    let mut sample_rate = 0u32;
+    let mut bits = 0u16;
+    let mut data: Option<&[u8]> = None;
+    while offset + 8 <= bytes.len() {
+        let id = &bytes[offset..offset + 4];
+        let size = u32::from_le_bytes([bytes[offset + 4], bytes[offset + 5], bytes[offset + 6], bytes[offset + 7]]) as usize;
+        let body_start = offset + 8;
+        let body_end = body_start.saturating_add(size).min(bytes.len());
+        let body = &bytes[body_start..body_end];
+        match id {
+            b"fmt " => {
+                if body.len() < 16 {
+                    return Err(String::from("fmt chunk too small"));
+                }
+                format_code = u16::from_le_bytes([body[0], body[1]]);
+                channel_count = u16::from_le_bytes([body[2], body[3]]) as usize;
+                sample_rate = u32::from_le_bytes([body[4], body[5], body[6], body[7]]);
+                bits = u16::from_le_bytes([body[14], body[15]]);
+                if format_code == FORMAT_EXTENSIBLE && body.len() >= 26 {
+                    format_code = u16::from_le_bytes([body[24], body[25]]);
+                }
+            }
+            b"data" => data = Some(body),
+            _ => {}
+        }
+        offset = body_start.saturating_add(size + (size & 1));
+    }
+    let data = data.ok_or_else(|| String::from("no data chunk"))?;
+    if channel_count == 0 || sample_rate == 0 {
+        return Err(String::from("missing or invalid fmt chunk"));
+    }
+    let width = bits as usize / 8;
+    let decode: fn(&[u8]) -> f32 = match (format_code, bits) {
+        (FORMAT_PCM, 16) => |chunk| i16::from_le_bytes([chunk[0], chunk[1]]) as f32 / 32768.0,
+        (FORMAT_PCM, 24) => |chunk| ((chunk[2] as i32) << 24 | (chunk[1] as i32) << 16 | (chunk[0] as i32) << 8) as f32 / 2147483648.0,
+        (FORMAT_PCM, 32) => |chunk| i32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]) as f32 / 2147483648.0,
+        (FORMAT_FLOAT, 32) => |chunk| f32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]),
+        (FORMAT_FLOAT, 64) => |chunk| f64::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3], chunk[4], chunk[5], chunk[6], chunk[7]]) as f32,
+        _ => return Err(format!("unsupported format code {format_code} at {bits} bit"))
+    };
+    let frame_count = data.len() / (width * channel_count);
+    let mut frames = vec![0.0; frame_count * channel_count];
+    for (frame, chunk) in data.chunks_exact(width * channel_count).enumerate() {
+        for channel in 0..channel_count {
+            frames[channel * frame_count + frame] = decode(&chunk[channel * width..(channel + 1) * width]);
+        }
+    }
+    Ok(Decoded {frames, channel_count: channel_count as u32, sample_rate: sample_rate as f32})
+}
+
+/// Encode INTERLEAVED `frames` (`channel_count` samples per frame) as a 32-bit float WAV.
+pub(crate) fn encode_32f(frames: &[f32], channel_count: usize, sample_rate: f32) -> Vec<u8> {
+    let data_size = (frames.len() * 4) as u32;
+    let block_align = (channel_count * 4) as u16;
+    let mut bytes: Vec<u8> = Vec::with_capacity(44 + data_size as usize);
+    bytes.extend_from_slice(b"RIFF");
+    bytes.extend_from_slice(&(36 + data_size).to_le_bytes());
+    bytes.extend_from_slice(b"WAVE");
+    bytes.extend_from_slice(b"fmt ");
+    bytes.extend_from_slice(&16u32.to_le_bytes());
+    bytes.extend_from_slice(&FORMAT_FLOAT.to_le_bytes());
+    bytes.extend_from_slice(&(channel_count as u16).to_le_bytes());
+    bytes.extend_from_slice(&(sample_rate as u32).to_le_bytes());
+    bytes.extend_from_slice(&(sample_rate as u32 * block_align as u32).to_le_bytes());
+    bytes.extend_from_slice(&block_align.to_le_bytes());
+    bytes.extend_from_slice(&32u16.to_le_bytes());
+    bytes.extend_from_slice(b"data");
+    bytes.extend_from_slice(&data_size.to_le_bytes());
+    for sample in frames {
+        bytes.extend_from_slice(&sample.to_le_bytes());
+    }
+    bytes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decode, encode_32f};
+
+    #[test]
+    fn a_float_file_round_trips_into_planes() {
+        let interleaved = [0.5, -0.5, 0.25, 1.0, -1.0, 0.0];
+        let decoded = decode(&encode_32f(&interleaved, 2, 44_100.0)).expect("decodes");
+        assert_eq!((decoded.channel_count, decoded.sample_rate), (2, 44_100.0));
+        assert_eq!(decoded.frames, vec![0.5, 0.25, -1.0, -0.5, 1.0, 0.0], "left plane, then right plane");
+    }
+
+    #[test]
+    fn pcm_24_is_sign_extended() {
+        let mut bytes = encode_32f(&[], 1, 48_000.0);
+        bytes[20..22].copy_from_slice(&1u16.to_le_bytes()); // PCM
+        bytes[34..36].copy_from_slice(&24u16.to_le_bytes());
+        bytes[40..44].copy_from_slice(&6u32.to_le_bytes());
+        bytes.extend_from_slice(&[0x00, 0x00, 0x40, 0x00, 0x00, 0xC0]); // +0.5, -0.5
+        let decoded = decode(&bytes).expect("decodes");
+        assert_eq!(decoded.frames, vec![0.5, -0.5]);
+    }
+
+    #[test]
+    fn a_non_wav_is_rejected() {
+        assert!(decode(b"OggS not a wav file").is_err());
+    }
+}
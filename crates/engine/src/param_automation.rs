@@ -128,7 +128,7 @@ pub(crate) trait ParamSink {
     /// track. The node swaps `params` into the pull context each `process` for `host_update_parameters`.
     fn set_params(&mut self, params: Vec<ParamHandle>, clock_armed: bool);
     /// The address of this device's state block, for the engine's `init` / `parameter_changed` calls.
-    fn state_ptr(&self) -> u32;
+    fn state_ptr(&self) -> usize;
 }
 
 /// One launchable VALUE clip's automation content (TS `ValueClipBoxAdapter`): its live event curve, read
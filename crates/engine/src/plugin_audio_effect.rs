@@ -20,7 +20,7 @@ use engine_env::process_info::ProcessInfo;
 use engine_env::processor::Processor;
 use transport::transport::RENDER_QUANTUM;
 use crate::param_automation::{ParamHandle, ParamSink};
-use crate::{call_device_process, call_device_reset, DeviceReg, INPUTS, PULL};
+use crate::{call_device_latency, call_device_process, call_device_reset, DeviceReg, INPUTS, PULL};
 
 /// A graph node that runs an audio-EFFECT device after an upstream node (Route B). It reads the upstream's
 /// stereo output (both channels) through the device, into the engine-allocated stereo output, then copies
@@ -30,6 +30,7 @@ use crate::{call_device_process, call_device_reset, DeviceReg, INPUTS, PULL};
 pub(crate) struct PluginAudioEffect {
     process_index: u32,
     reset_index: u32,
+    latency_index: u32, // the device's `latency` slot (look-ahead delay for compensation); 0 if none
     sample_rate: f32,
     meter: engine_env::meter::Meter, // peaks/RMS of the device output (a broadcast slot)
     events: EventBuffer, // unused here (the device PULLS its events) but required by `Processor: EventReceiver`
@@ -47,16 +48,16 @@ pub(crate) struct PluginAudioEffect {
     // `set_audio_source`), ports 2+ the resolved sidechains (set by `set_sidechain`). Swapped into `INPUTS`
     // per `process` so `host_resolve_input` finds them. The sidechain source buffers are kept alive so their
     // captured pointers stay valid.
-    input_ports: Vec<(u32, u32, u32)>,
+    input_ports: Vec<(u32, usize, usize)>,
     #[allow(dead_code)]
     sidechain_buffers: Vec<SharedAudioBuffer>,
     device_output: [Box<[f32]>; 2], // the device's stereo output buffers ([left, right])
-    in_offsets: Box<[u32]>,
+    in_offsets: Box<[usize]>,
     #[allow(dead_code)]
-    out_offsets: Box<[u32]>,
+    out_offsets: Box<[usize]>,
     #[allow(dead_code)]
     device_state: Box<[u64]>,
-    descriptor: Box<[u32]>
+    descriptor: Box<[usize]>
 }
 
 impl PluginAudioEffect {
@@ -70,25 +71,26 @@ impl PluginAudioEffect {
         // u64-backed so the block is 8-aligned for any device state struct (e.g. a biquad's f64 fields); a
         // 4-aligned block would be misaligned for those.
         let device_state = vec![0u64; state_size.div_ceil(8)].into_boxed_slice();
-        let in_offsets = vec![0u32, 0u32].into_boxed_slice(); // L, R input ptrs, set by set_audio_source
-        let out_offsets = vec![device_output[0].as_ptr() as u32, device_output[1].as_ptr() as u32].into_boxed_slice();
+        let in_offsets = vec![0usize, 0usize].into_boxed_slice(); // L, R input ptrs, set by set_audio_source
+        let out_offsets = vec![device_output[0].as_ptr() as usize, device_output[1].as_ptr() as usize].into_boxed_slice();
         // descriptor (see `abi`): frames, in_count/ptr (2, stereo), out_count/ptr (2, stereo), no params, state,
         // NO event scratch (the effect no longer pulls notes; it fragments at the engine's update positions),
         // no out events, block_count/ptr (set per quantum from the ProcessInfo for tempo sync), sample_rate.
         let descriptor = vec![
-            RENDER_QUANTUM as u32,
-            2, in_offsets.as_ptr() as u32,
-            2, out_offsets.as_ptr() as u32,
+            RENDER_QUANTUM,
+            2, in_offsets.as_ptr() as usize,
+            2, out_offsets.as_ptr() as usize,
             0, 0,
-            device_state.as_ptr() as u32,
+            device_state.as_ptr() as usize,
             0, 0,
             0, 0,
             0, 0,
-            sample_rate.to_bits()
+            sample_rate.to_bits() as usize
         ].into_boxed_slice();
         Self {
             process_index: device.process_index,
             reset_index: device.reset_index,
+            latency_index: device.latency_index,
             sample_rate,
             meter: engine_env::meter::Meter::new(sample_rate),
             events: EventBuffer::new(),
@@ -118,8 +120,8 @@ impl ParamSink for PluginAudioEffect {
         self.clock_armed = clock_armed;
     }
 
-    fn state_ptr(&self) -> u32 {
-        self.device_state.as_ptr() as u32
+    fn state_ptr(&self) -> usize {
+        self.device_state.as_ptr() as usize
     }
 }
 
@@ -132,7 +134,7 @@ impl EventReceiver for PluginAudioEffect {
 impl AudioInput for PluginAudioEffect {
     fn set_audio_source(&mut self, source: SharedAudioBuffer) {
         let buffer = source.borrow();
-        let (left, right) = (buffer.left.as_ptr() as u32, buffer.right.as_ptr() as u32);
+        let (left, right) = (buffer.left.as_ptr() as usize, buffer.right.as_ptr() as usize);
         self.in_offsets[0] = left;
         self.in_offsets[1] = right;
         self.input_ports[0] = (abi::MAIN_INPUT, left, right); // port 1, the through-signal resolve_input(1) returns
@@ -152,7 +154,7 @@ impl PluginAudioEffect {
         self.sidechain_buffers.clear();
         for (port_id, source) in sources {
             let buffer = source.borrow();
-            let (left, right) = (buffer.left.as_ptr() as u32, buffer.right.as_ptr() as u32);
+            let (left, right) = (buffer.left.as_ptr() as usize, buffer.right.as_ptr() as usize);
             drop(buffer);
             self.input_ports.push((*port_id, left, right));
             self.sidechain_buffers.push(source.clone());
@@ -170,16 +172,20 @@ impl Processor for PluginAudioEffect {
     fn reset(&mut self) {
         // Transport STOP: clear the device's runtime state (delay lines, filter history, detector / envelope),
         // keeping its bindings. Also drop the buffered input residual.
-        call_device_reset(self.reset_index, self.device_state.as_ptr() as u32);
+        call_device_reset(self.reset_index, self.device_state.as_ptr() as usize);
+    }
+
+    fn latency(&self) -> u32 {
+        call_device_latency(self.latency_index, self.device_state.as_ptr() as usize)
     }
 
     fn process(&mut self, info: &ProcessInfo) {
         // Point the descriptor straight at the engine's per-quantum block array (shared wire type, in
         // shared memory) so the effect can sync to tempo — no per-node copy. Refresh the pointer each
         // quantum (the blocks Vec may move).
-        self.descriptor[0] = RENDER_QUANTUM as u32;
-        self.descriptor[12] = info.blocks.len() as u32;
-        self.descriptor[13] = info.blocks.as_ptr() as u32;
+        self.descriptor[0] = RENDER_QUANTUM;
+        self.descriptor[12] = info.blocks.len();
+        self.descriptor[13] = info.blocks.as_ptr() as usize;
         // Hand the device its pull context: no note source (an effect has none), but the blocks and — when
         // it has automation — the armed global clock + this device's params, so its per-block pull returns
         // the update events that drive `parameter_changed`. Scope the borrows so none is held across the call.
@@ -195,7 +201,7 @@ impl Processor for PluginAudioEffect {
             // and any sidechains for THIS device's call.
             core::mem::swap(&mut self.input_ports, unsafe { INPUTS.get() });
         }
-        call_device_process(self.process_index, self.descriptor.as_ptr() as u32);
+        call_device_process(self.process_index, self.descriptor.as_ptr() as usize);
         {
             let pull = unsafe { PULL.get() };
             pull.blocks = core::ptr::null();
@@ -18,7 +18,7 @@ use engine_env::process_info::ProcessInfo;
 use engine_env::processor::Processor;
 use transport::transport::RENDER_QUANTUM;
 use crate::param_automation::{ParamHandle, ParamSink};
-use crate::{call_device_process, call_device_reset, DeviceReg, PullLink, DEVICE_MAX_EVENTS, PULL};
+use crate::{call_device_latency, call_device_process, call_device_reset, DeviceReg, PullLink, DEVICE_MAX_EVENTS, PULL};
 
 /// A graph node that voices its notes through a loaded instrument device (e.g. `device_sine.wasm`). It
 /// pulls notes from its `PullLink` chain (resolved by the device via `host_pull_events`), fills the
@@ -29,6 +29,7 @@ use crate::{call_device_process, call_device_reset, DeviceReg, PullLink, DEVICE_
 pub(crate) struct PluginInstrument {
     process_index: u32, // the device's `process` slot in the shared function table
     reset_index: u32,   // the device's `reset` slot (clears voices on STOP); 0 if none
+    latency_index: u32, // the device's `latency` slot (its output lag, for delay compensation); 0 if none
     sample_rate: f32,
     // A disabled instrument is SILENCED at the source (mirrors TS instrument processors, e.g. Nano:
     // `if (!enabled) return` in processAudio + `reset()` on disable). Unlike an effect (bypassed = passthrough),
@@ -55,10 +56,10 @@ pub(crate) struct PluginInstrument {
     #[allow(dead_code)]
     device_events: Box<[EventRecord]>,
     #[allow(dead_code)]
-    device_state: Box<[u32]>, // u32 so the block is 4-aligned for the device's SynthState
+    device_state: Box<[u64]>, // u64 so the block is 8-aligned for any device state struct
     #[allow(dead_code)]
-    out_offsets: Box<[u32]>,
-    descriptor: Box<[u32]>
+    out_offsets: Box<[usize]>,
+    descriptor: Box<[usize]>
 }
 
 impl PluginInstrument {
@@ -71,26 +72,27 @@ impl PluginInstrument {
         let blank = EventRecord {position: 0.0, offset: 0, kind: 0, id: 0, pitch: 0, velocity: 0.0, cent: 0.0, duration: 0.0};
         let device_events = vec![blank; DEVICE_MAX_EVENTS].into_boxed_slice();
         let state_size = device.state_size as usize;
-        let device_state = vec![0u32; state_size.div_ceil(4)].into_boxed_slice(); // 4-aligned, >= state_size bytes
+        let device_state = vec![0u64; state_size.div_ceil(8)].into_boxed_slice(); // 8-aligned, >= state_size bytes
         // Two output offsets (L, R) -> the two device output buffers; the engine is stereo.
-        let out_offsets = vec![device_output[0].as_ptr() as u32, device_output[1].as_ptr() as u32].into_boxed_slice();
+        let out_offsets = vec![device_output[0].as_ptr() as usize, device_output[1].as_ptr() as usize].into_boxed_slice();
         // descriptor words (see the `abi` layout): frames, in_count/ptr, out_count/ptr (2, stereo),
         // param_count/ptr, state_ptr, in_event_cap/ptr (pull scratch), out_event_cap/ptr (0, instrument has no
         // event out), block_count/ptr (set per quantum from the ProcessInfo), sample_rate (f32 bits).
         let descriptor = vec![
-            RENDER_QUANTUM as u32,
+            RENDER_QUANTUM,
             0, 0,
-            2, out_offsets.as_ptr() as u32,
+            2, out_offsets.as_ptr() as usize,
             0, 0,
-            device_state.as_ptr() as u32,
-            DEVICE_MAX_EVENTS as u32, device_events.as_ptr() as u32,
+            device_state.as_ptr() as usize,
+            DEVICE_MAX_EVENTS, device_events.as_ptr() as usize,
             0, 0,
             0, 0,
-            sample_rate.to_bits()
+            sample_rate.to_bits() as usize
         ].into_boxed_slice();
         Self {
             process_index: device.process_index,
             reset_index: device.reset_index,
+            latency_index: device.latency_index,
             sample_rate,
             enabled: true,
             pull_chain: None,
@@ -135,8 +137,8 @@ impl ParamSink for PluginInstrument {
         self.clock_armed = clock_armed;
     }
 
-    fn state_ptr(&self) -> u32 {
-        self.device_state.as_ptr() as u32
+    fn state_ptr(&self) -> usize {
+        self.device_state.as_ptr() as usize
     }
 }
 
@@ -156,11 +158,15 @@ impl Processor for PluginInstrument {
     fn reset(&mut self) {
         // Transport STOP: drop every active voice (the device clears its voice pool / envelopes), and clear the
         // buffered events.
-        call_device_reset(self.reset_index, self.device_state.as_ptr() as u32);
+        call_device_reset(self.reset_index, self.device_state.as_ptr() as usize);
         self.events.clear();
         self.meter.clear();
     }
 
+    fn latency(&self) -> u32 {
+        call_device_latency(self.latency_index, self.device_state.as_ptr() as usize)
+    }
+
     fn process(&mut self, info: &ProcessInfo) {
         if !self.enabled {
             // Disabled: render silence and don't call the device (no notes pulled, no CPU). Voices were already
@@ -172,9 +178,9 @@ impl Processor for PluginInstrument {
         }
         // Point the descriptor straight at the engine's per-quantum block array (the shared wire type, in
         // shared memory) — no per-node copy. The blocks Vec may move between quanta, so refresh the pointer.
-        self.descriptor[0] = RENDER_QUANTUM as u32;
-        self.descriptor[12] = info.blocks.len() as u32;
-        self.descriptor[13] = info.blocks.as_ptr() as u32;
+        self.descriptor[0] = RENDER_QUANTUM;
+        self.descriptor[12] = info.blocks.len();
+        self.descriptor[13] = info.blocks.as_ptr() as usize;
         // Hand the device its pull context, then call it. The device PULLS its events via host_pull_events.
         // Scope the `PULL.get()` borrow so none is live across `call_device_process` (the device's
         // host_pull_events takes its own `PULL.get()`); single-threaded, so the two never overlap.
@@ -188,7 +194,7 @@ impl Processor for PluginInstrument {
             pull.note_bits = self.note_bits.clone(); // pulled notes mark THIS unit's note indicator
             core::mem::swap(&mut self.params, &mut pull.params); // move our params in (no alloc)
         }
-        call_device_process(self.process_index, self.descriptor.as_ptr() as u32);
+        call_device_process(self.process_index, self.descriptor.as_ptr() as usize);
         {
             let pull = unsafe { PULL.get() };
             pull.current = None;
@@ -27,8 +27,8 @@ impl DeviceState {
         Self(vec![0u64; bytes.div_ceil(8)].into_boxed_slice())
     }
 
-    pub(crate) fn ptr(&self) -> u32 {
-        self.0.as_ptr() as u32
+    pub(crate) fn ptr(&self) -> usize {
+        self.0.as_ptr() as usize
     }
 }
 
@@ -74,7 +74,7 @@ impl PluginMidiEffect {
     }
 
     /// The address of this device's state block, for the engine's `init` / `parameter_changed` calls.
-    pub(crate) fn state_ptr(&self) -> u32 {
+    pub(crate) fn state_ptr(&self) -> usize {
         self.state.ptr()
     }
 
@@ -98,7 +98,7 @@ impl PluginMidiEffect {
     /// Invoked when something downstream pulls this fx for `[from, to)`: run the device's `process_events`
     /// with its instance state, writing the produced events to `out_ptr` and returning the count. The device
     /// pulls its own upstream from inside this call (the engine has pointed the pull context at it).
-    pub(crate) fn process_events(&self, from: f64, to: f64, flags: u32, out_ptr: u32, max: u32) -> u32 {
+    pub(crate) fn process_events(&self, from: f64, to: f64, flags: u32, out_ptr: usize, max: u32) -> u32 {
         call_device_process_events(self.process_index, from, to, flags, self.state.ptr(), out_ptr, max)
     }
 }
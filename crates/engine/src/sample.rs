@@ -133,13 +133,23 @@ impl SampleResource {
 
     /// Reserve `byte_len` zeroed bytes for the slot's planar f32 frames and return the pointer the host
     /// writes into. The storage lives in the slot, so the pointer is stable until the sample is freed.
-    pub fn allocate(&mut self, handle: u32, byte_len: usize) -> u32 {
+    pub fn allocate(&mut self, handle: u32, byte_len: usize) -> usize {
         let Some(slot) = self.slot_mut(handle) else {
             return 0;
         };
         slot.storage = vec![0u8; byte_len];
         slot.state = State::Allocated;
-        slot.storage.as_ptr() as u32
+        slot.storage.as_ptr() as usize
+    }
+
+    /// The whole handshake in one call, for a host that decodes in-process (the native offline renderer):
+    /// store the PLANAR `frames` (`channel_count` planes of equal length) and mark the slot ready.
+    pub fn deliver(&mut self, handle: u32, frames: &[f32], channel_count: u32, sample_rate: f32) {
+        let Some(slot) = self.slot_mut(handle) else { return };
+        slot.storage = frames.iter().flat_map(|sample| sample.to_ne_bytes()).collect();
+        slot.state = State::Allocated;
+        let frame_count = frames.len() / channel_count.max(1) as usize;
+        self.set_ready(handle, frame_count as u32, channel_count, sample_rate);
     }
 
     /// Mark the slot ready once the host has written its frames: `channel_count` planes of `frame_count`
@@ -167,7 +177,7 @@ impl SampleResource {
             return None;
         }
         Some(SampleRef {
-            frames_ptr: slot.storage.as_ptr() as u32,
+            frames_ptr: slot.storage.as_ptr() as usize,
             frame_count: slot.frame_count,
             channel_count: slot.channel_count,
             sample_rate: slot.sample_rate
@@ -229,6 +239,18 @@ mod tests {
         assert_eq!((sample.frame_count, sample.channel_count, sample.sample_rate), (100, 2, 48_000.0));
     }
 
+    #[test]
+    fn deliver_stores_the_planes_and_readies_in_one_step() {
+        let mut resource = SampleResource::new();
+        let handle = resource.request(uuid(9));
+        resource.deliver(handle, &[0.5, -0.5, 0.25, 1.0, 0.0, -1.0], 2, 44_100.0);
+        let sample = resource.resolve(handle).expect("a delivered sample is ready");
+        assert_eq!((sample.frame_count, sample.channel_count), (3, 2));
+        assert_eq!(sample.plane(0), &[0.5, -0.5, 0.25]);
+        assert_eq!(sample.plane(1), &[1.0, 0.0, -1.0]);
+        assert!(resource.take_pending().is_none(), "a delivered sample is no longer awaiting a load");
+    }
+
     #[test]
     fn free_drops_the_slot_and_a_stale_handle_resolves_to_none() {
         let mut resource = SampleResource::new();
@@ -54,7 +54,7 @@ impl Engine {
     /// `sample_changed`, keyed by the child's declaration `index` (key 3). Reuses [`resolve_and_deliver_sample`]
     /// (so residency / repoint / clear all behave like a fixed device sample); returns the per-child `file`
     /// pointer subscriptions for teardown.
-    pub(crate) fn observe_script_samples(&mut self, device_uuid: Uuid, reg: DeviceReg, state_ptr: u32, hub_field: u16) -> Vec<SubscriptionId> {
+    pub(crate) fn observe_script_samples(&mut self, device_uuid: Uuid, reg: DeviceReg, state_ptr: usize, hub_field: u16) -> Vec<SubscriptionId> {
         let sample_changed_index = reg.sample_changed_index;
         let mut subs = Vec::new();
         for child in self.collection_children(device_uuid, hub_field) {
@@ -123,6 +123,16 @@ impl SoundfontResource {
         slot.storage.as_ptr() as u32
     }
 
+    /// Store a blob built in-process (the native offline renderer) and mark the slot ready: the `allocate` +
+    /// write + `set_ready` handshake in one call.
+    pub fn deliver(&mut self, handle: u32, blob: &[u8]) {
+        if let Some(slot) = self.slot_mut(handle) {
+            slot.storage = blob.to_vec();
+            slot.byte_len = blob.len() as u32;
+            slot.state = State::Ready;
+        }
+    }
+
     /// Mark the slot ready once the host has written the blob.
     pub fn set_ready(&mut self, handle: u32) {
         if let Some(slot) = self.slot_mut(handle) {
@@ -136,7 +146,7 @@ impl SoundfontResource {
         if slot.state != State::Ready {
             return None;
         }
-        Some(SoundfontRef {ptr: slot.storage.as_ptr() as u32, len: slot.byte_len})
+        Some(SoundfontRef {ptr: slot.storage.as_ptr() as usize, len: slot.byte_len})
     }
 
     /// Free the soundfont for `uuid` (its box was removed): drop the slot's storage, bump the generation (so
@@ -172,7 +182,7 @@ mod tests {
         assert!(resource.resolve(first).is_none(), "not resolvable until the blob is written + readied");
         resource.set_ready(first);
         let reference = resource.resolve(first).expect("ready resolves");
-        assert_eq!((reference.ptr, reference.len), (pointer, 128));
+        assert_eq!((reference.ptr as u32, reference.len), (pointer, 128), "allocate hands out the wasm32 address");
     }
 
     #[test]
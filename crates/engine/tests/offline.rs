@@ -0,0 +1,236 @@
+//! The native offline renderer against a real project file: the range ends where the transport pauses,
+//! samples resolve from an asset directory (a missing one is reported, not fatal), a stem export widens
+//! the output, and instruments play through the natively hosted stock devices. An SF2 font or SFZ instrument
+//! in the asset directory is built into the soundfont blob the Soundfont device plays.
+
+use std::f32::consts::TAU;
+use std::fs;
+use std::path::PathBuf;
+
+use boxgraph::address::{uuid_to_string, Address, Uuid};
+use boxgraph::boxes::GraphBox;
+use boxgraph::field::FieldValue;
+use boxgraph::graph::BoxGraph;
+use engine::offline::{inspect, project_chunk, render, AssetDirectory, AssetSource, OfflineConfig, OfflineError, OfflineRender, Stem};
+
+const SR: f32 = 48_000.0;
+// tape.od: 150 bpm, no tempo automation, one drum loop file under two musical regions at bars 1-4 and 5-8.
+const TAPE: &str = "../../packages/app/wasm/public/projects/tape.od";
+// vapo-bassline.od: 120 bpm, one Vaporisateur bassline from the first bar on.
+const BASSLINE: &str = "../../packages/app/wasm/public/projects/vapo-bassline.od";
+const DRUM_LOOP: Uuid = [162, 57, 103, 93, 186, 112, 70, 47, 129, 100, 215, 24, 135, 48, 202, 194];
+const AUDIO_UNIT: Uuid = [158, 116, 221, 86, 235, 17, 65, 199, 165, 3, 172, 118, 222, 89, 201, 80];
+const BAR: f64 = 3840.0;
+const BAR_FRAMES: usize = 76_800; // 4 beats at 150 bpm, 48 kHz
+
+// The range ends where the transport pauses, and its block split floors the pulse-to-sample conversion, so
+// an end landing on a quantum boundary can come out one frame early.
+fn assert_range_frames(rendered: &OfflineRender, frames: usize) {
+    let count = rendered.frame_count();
+    assert!(count == frames || count + 1 == frames, "{count} frames for a {frames} frame range");
+}
+
+fn tape() -> Vec<u8> {
+    fs::read(TAPE).expect("tape.od is part of the repo")
+}
+
+// A fresh asset directory holding a stereo sine as the drum loop's file.
+fn asset_directory(name: &str) -> PathBuf {
+    let root = std::env::temp_dir().join(format!("opendaw-offline-{name}-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&root);
+    let sine: Vec<f32> = (0..SR as usize * 8)
+        .flat_map(|frame| {
+            let sample = 0.5 * (TAU * 220.0 * frame as f32 / SR).sin();
+            [sample, sample]
+        })
+        .collect();
+    let file = OfflineRender {sample_rate: SR, channels: 2, frames: sine, missing: Vec::new()};
+    file.write_wav(&root.join("samples").join(uuid_to_string(&DRUM_LOOP)).join("audio.wav")).expect("writes");
+    root
+}
+
+// The bassline with its Vaporisateur swapped for a Soundfont device playing `font`'s first preset.
+fn soundfont_bassline(font: Uuid) -> Vec<u8> {
+    let project = fs::read(BASSLINE).expect("part of the repo");
+    let graph = BoxGraph::from_bytes(project_chunk(&project).expect("a valid header"), &studio_boxes::registry()).expect("decodes");
+    let mut boxes: Vec<GraphBox> = graph.boxes().cloned().collect();
+    let creation_index = boxes.iter().map(|graph_box| graph_box.creation_index).max().unwrap_or(0) + 1;
+    let device = boxes.iter_mut().find(|graph_box| graph_box.name == "VaporisateurDeviceBox").expect("the instrument");
+    device.name = String::from("SoundfontDeviceBox");
+    device.fields.retain(|key, _| *key < 10); // the instrument attributes (host, label, enabled, ...)
+    device.fields.insert(10, FieldValue::Pointer(Some(Address::box_of(font))));
+    device.fields.insert(11, FieldValue::Int32(0));
+    boxes.push(GraphBox {creation_index, name: String::from("SoundfontFileBox"), uuid: font,
+        fields: [(1, FieldValue::String(String::from("parity.sf2")))].into()});
+    BoxGraph::from_boxes(boxes).to_bytes()
+}
+
+fn peak(frames: &[f32]) -> f32 {
+    frames.iter().fold(0.0f32, |peak, sample| peak.max(sample.abs()))
+}
+
+#[test]
+fn the_project_header_is_stripped() {
+    let bytes = tape();
+    let chunk = project_chunk(&bytes).expect("a valid header");
+    assert_eq!(chunk.len(), bytes.len() - 12);
+    assert_eq!(project_chunk(chunk).expect("a bare chunk").len(), chunk.len(), "a bare chunk passes through");
+}
+
+#[test]
+fn a_negative_chunk_length_is_rejected() {
+    let mut bytes = tape();
+    bytes[8..12].fill(0xFF); // -1 in either byte order
+    assert!(matches!(project_chunk(&bytes), Err(OfflineError::Project(_))));
+}
+
+#[test]
+fn a_bar_renders_its_length_and_reports_the_missing_file() {
+    let mut config = OfflineConfig::new(SR, 0.0, BAR);
+    config.metronome = true;
+    let rendered = render(&tape(), &AssetDirectory::new("/nonexistent"), &config).expect("renders");
+    assert_eq!(rendered.channels, 2);
+    assert_range_frames(&rendered, BAR_FRAMES);
+    assert_eq!(rendered.missing, vec![DRUM_LOOP]);
+    assert!(peak(&rendered.frames) > 0.01, "the metronome clicks");
+}
+
+#[test]
+fn a_resolved_sample_plays_and_the_range_can_start_late() {
+    let root = asset_directory("resolved");
+    let config = OfflineConfig::new(SR, BAR, BAR * 2.0);
+    let rendered = render(&tape(), &AssetDirectory::new(&root), &config).expect("renders");
+    let _ = fs::remove_dir_all(&root);
+    assert!(rendered.missing.is_empty());
+    assert_range_frames(&rendered, BAR_FRAMES);
+    assert!(peak(&rendered.frames) > 0.05, "the region plays the sine");
+}
+
+#[test]
+fn the_tail_stops_once_it_has_rung_out() {
+    let mut config = OfflineConfig::new(SR, 0.0, BAR);
+    config.tail_seconds = 10.0;
+    let rendered = render(&tape(), &AssetDirectory::new("/nonexistent"), &config).expect("renders");
+    let tail = rendered.frame_count() + 1 - BAR_FRAMES;
+    assert!(tail >= SR as usize / 4 && tail < SR as usize, "a silent tail ends after a quarter second, got {tail}");
+}
+
+#[test]
+fn a_stem_export_carries_one_pair_per_stem() {
+    let root = asset_directory("stems");
+    let mut config = OfflineConfig::new(SR, 0.0, BAR);
+    config.metronome = true;
+    config.metronome_stem = true;
+    config.stems = vec![Stem::new(AUDIO_UNIT)];
+    let rendered = render(&tape(), &AssetDirectory::new(&root), &config).expect("renders");
+    let _ = fs::remove_dir_all(&root);
+    assert_eq!(rendered.channels, 4);
+    assert_range_frames(&rendered, BAR_FRAMES);
+    assert!(peak(&rendered.stereo_pair(0)) > 0.05, "the audio unit's stem");
+    assert!(peak(&rendered.stereo_pair(1)) > 0.01, "the metronome stem");
+}
+
+#[test]
+fn loops_play_the_range_back_to_back() {
+    // two bars: the wrap lands on a quantum boundary, where the split rounds down to an empty block
+    let mut config = OfflineConfig::new(SR, 0.0, BAR * 2.0);
+    config.metronome = true;
+    config.loops = 3;
+    let rendered = render(&tape(), &AssetDirectory::new("/nonexistent"), &config).expect("renders");
+    let count = rendered.frame_count();
+    assert!((6 * BAR_FRAMES - 3..=6 * BAR_FRAMES).contains(&count), "{count} frames for three passes");
+    // every pass starts on the downbeat click
+    for pass in 0..3 {
+        let start = pass * (count / 3) * 2;
+        assert!(peak(&rendered.frames[start..start + 2 * 256]) > 0.01, "pass {pass} clicks at its start");
+    }
+}
+
+#[test]
+fn an_instrument_track_plays_through_its_natively_hosted_device() {
+    let project = fs::read(BASSLINE).expect("part of the repo");
+    let rendered = render(&project, &AssetDirectory::new("/nonexistent"), &OfflineConfig::new(SR, 0.0, BAR)).expect("renders");
+    assert!(rendered.missing.is_empty());
+    assert!(peak(&rendered.frames) > 0.05, "the Vaporisateur voices the bassline");
+}
+
+#[test]
+fn the_outline_lists_the_timeline_and_units() {
+    let info = inspect(&tape()).expect("inspects");
+    assert_eq!(info.bpm, 150.0);
+    assert_eq!((info.loop_enabled, info.loop_from, info.loop_to), (false, 0.0, 30720.0));
+    assert_eq!(info.bar_position(4), 4.0 * BAR);
+    assert!(info.units.iter().any(|unit| unit.uuid == AUDIO_UNIT && unit.label == "Tape"));
+    assert!(info.units.iter().any(|unit| unit.kind == "output"));
+}
+
+#[test]
+fn an_empty_range_is_rejected() {
+    let config = OfflineConfig::new(SR, BAR, BAR);
+    let result = render(&tape(), &AssetDirectory::new("/nonexistent"), &config);
+    assert!(matches!(result, Err(OfflineError::Range {..})));
+}
+
+#[test]
+fn an_sfz_instrument_resolves_as_a_soundfont_blob() {
+    const PIANO: Uuid = [7; 16];
+    let root = std::env::temp_dir().join(format!("opendaw-offline-sfz-{}", std::process::id()));
+    let folder = root.join("soundfonts").join(uuid_to_string(&PIANO));
+    let note = OfflineRender {sample_rate: SR, channels: 1, frames: vec![0.5; 480], missing: Vec::new()};
+    note.write_wav(&folder.join("samples").join("c4.wav")).expect("writes");
+    fs::write(folder.join("soundfont.sfz"), "<group> default_path=samples\\ seq_length=2\n<region> sample=c4.wav key=c4 seq_position=1\n")
+        .expect("writes");
+    let assets = AssetDirectory::new(&root);
+    let blob = assets.soundfont(&PIANO).expect("builds").expect("resolves");
+    fs::write(folder.join("soundfont.sfz"), "<region> sample=missing.wav").expect("writes");
+    let broken = assets.soundfont(&PIANO);
+    let _ = fs::remove_dir_all(&root);
+    let word = |index: usize| u32::from_le_bytes(blob[index * 4..index * 4 + 4].try_into().unwrap());
+    assert_eq!((word(0), word(1), word(2), word(3)), (0x4F53_4632, 2, 1, 1), "an OSF2 version 2 blob, one sample, one region");
+    assert!(matches!(broken, Err(OfflineError::Asset {uuid: PIANO, ..})), "a missing sample fails the load");
+}
+
+#[test]
+fn an_sf2_font_resolves_as_the_blob_the_browser_builds() {
+    const FONT: Uuid = [9; 16];
+    let fixtures = PathBuf::from("../../test-files/soundfont");
+    let root = std::env::temp_dir().join(format!("opendaw-offline-sf2-{}", std::process::id()));
+    let folder = root.join("soundfonts").join(uuid_to_string(&FONT));
+    fs::create_dir_all(&folder).expect("creates");
+    fs::copy(fixtures.join("parity.sf2"), folder.join("soundfont.sf2")).expect("copies");
+    let blob = AssetDirectory::new(&root).soundfont(&FONT);
+    let _ = fs::remove_dir_all(&root);
+    assert_eq!(blob.expect("builds"), Some(fs::read(fixtures.join("parity.osf2")).expect("reads")));
+}
+
+#[test]
+fn an_sf2_font_plays_through_the_soundfont_device() {
+    const FONT: Uuid = [11; 16];
+    let root = std::env::temp_dir().join(format!("opendaw-offline-sf2-play-{}", std::process::id()));
+    let folder = root.join("soundfonts").join(uuid_to_string(&FONT));
+    fs::create_dir_all(&folder).expect("creates");
+    fs::copy("../../test-files/soundfont/parity.sf2", folder.join("soundfont.sf2")).expect("copies");
+    let rendered = render(&soundfont_bassline(FONT), &AssetDirectory::new(&root), &OfflineConfig::new(SR, 0.0, BAR));
+    let _ = fs::remove_dir_all(&root);
+    let rendered = rendered.expect("renders");
+    assert!(rendered.missing.is_empty());
+    assert!(peak(&rendered.frames) > 0.05, "the font's looped sine voices the bassline");
+}
+
+#[test]
+fn an_sfz_instrument_plays_through_the_soundfont_device() {
+    const FONT: Uuid = [13; 16];
+    let root = std::env::temp_dir().join(format!("opendaw-offline-sfz-play-{}", std::process::id()));
+    let folder = root.join("soundfonts").join(uuid_to_string(&FONT));
+    let cycle = SR / 261.63;
+    let tone = (0..4800).map(|frame| (frame as f32 / cycle * std::f32::consts::TAU).sin() * 0.5).collect();
+    let tone = OfflineRender {sample_rate: SR, channels: 1, frames: tone, missing: Vec::new()};
+    tone.write_wav(&folder.join("samples").join("tone.wav")).expect("writes");
+    fs::write(folder.join("soundfont.sfz"),
+        "<region> sample=samples/tone.wav lokey=0 hikey=127 pitch_keycenter=60 loop_mode=loop_continuous\n").expect("writes");
+    let rendered = render(&soundfont_bassline(FONT), &AssetDirectory::new(&root), &OfflineConfig::new(SR, 0.0, BAR));
+    let _ = fs::remove_dir_all(&root);
+    let rendered = rendered.expect("renders");
+    assert!(rendered.missing.is_empty());
+    assert!(peak(&rendered.frames) > 0.05, "the instrument's looped tone voices the bassline");
+}
@@ -39,7 +39,7 @@ fn a_looping_note_region_produces_recurring_audio() {
     let mut note_onsets = 0;
     let mut events = Vec::new();
     for _ in 0..quanta {
-        let block = Block {p0: position, p1: position + pulses_per_quantum, s0: 0, s1: RENDER_QUANTUM, bpm: BPM, discontinuous: false};
+        let block = Block {p0: position, p1: position + pulses_per_quantum, s0: 0, s1: RENDER_QUANTUM, bpm: BPM, discontinuous: false, punch: false};
         events.clear();
         sequencer.process(&region, &notes, &block, true, &mut events);
         events.sort_by_key(|timed| timed.offset); // note-offs before note-ons at the same offset
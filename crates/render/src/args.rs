@@ -0,0 +1,146 @@
+//! Command-line parsing. Hand-rolled like the other tools in the workspace: the surface is small and a
+//! parser crate would be the only dependency outside the tree.
+
+use std::path::PathBuf;
+
+pub(crate) const USAGE: &str = "\
+usage: opendaw-render <project.od> [options]
+
+  -a, --assets <dir>      asset folder (an extracted .odb bundle or the studio's storage);
+                          default: the project's folder
+  -o, --out <path>        output WAV, or the output folder with --stems;
+                          default: next to the project
+  -r, --rate <hz>         sample rate (default 48000)
+      --bars <a-b>        render bars a through b (1-based, inclusive)
+      --marker <label|n>  render the section starting at a marker, by label or 1-based number
+      --loop-area         render the project's loop area
+  -n, --loops <n>         play the range n times (default 1)
+      --tail <seconds>    longest release tail after the range (default 0)
+      --stems             one WAV per audio unit instead of the master mix
+      --metronome         include the metronome (its own stem with --stems)
+      --info              print the project outline and exit
+
+Without a range option the whole timeline renders. The stock devices run natively; only the
+script devices and the neural amp, whose DSP lives in the browser, render silent.";
+
+/// What part of the timeline to render.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum Range {
+    Timeline,
+    Bars(u32, u32),
+    Marker(String),
+    LoopArea
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct Options {
+    pub(crate) project: PathBuf,
+    pub(crate) assets: Option<PathBuf>,
+    pub(crate) out: Option<PathBuf>,
+    pub(crate) sample_rate: f32,
+    pub(crate) range: Range,
+    pub(crate) loops: u32,
+    pub(crate) tail_seconds: f64,
+    pub(crate) stems: bool,
+    pub(crate) metronome: bool,
+    pub(crate) info: bool
+}
+
+/// Parse the arguments after the program name.
+pub(crate) fn parse(args: impl IntoIterator<Item = String>) -> Result<Options, String> {
+    let mut args = args.into_iter();
+    let mut project = None;
+    let mut options = Options {
+        project: PathBuf::new(),
+        assets: None,
+        out: None,
+        sample_rate: 48_000.0,
+        range: Range::Timeline,
+        loops: 1,
+        tail_seconds: 0.0,
+        stems: false,
+        metronome: false,
+        info: false
+    };
+    while let Some(arg) = args.next() {
+        let mut value = |name: &str| args.next().ok_or_else(|| format!("{name} needs a value"));
+        match arg.as_str() {
+            "-a" | "--assets" => options.assets = Some(PathBuf::from(value(&arg)?)),
+            "-o" | "--out" => options.out = Some(PathBuf::from(value(&arg)?)),
+            "-r" | "--rate" => options.sample_rate = number(&arg, &value(&arg)?)?,
+            "--bars" => {
+                let text = value(&arg)?;
+                let (first, last) = text.split_once('-').ok_or_else(|| format!("--bars expects a-b, got '{text}'"))?;
+                let (first, last): (u32, u32) = (number(&arg, first)?, number(&arg, last)?);
+                if first == 0 || last < first {
+                    return Err(format!("--bars {text}: bars count from 1 and the last may not precede the first"));
+                }
+                options.range = Range::Bars(first, last);
+            }
+            "--marker" => options.range = Range::Marker(value(&arg)?),
+            "--loop-area" => options.range = Range::LoopArea,
+            "-n" | "--loops" => options.loops = number(&arg, &value(&arg)?)?,
+            "--tail" => options.tail_seconds = number(&arg, &value(&arg)?)?,
+            "--stems" => options.stems = true,
+            "--metronome" => options.metronome = true,
+            "--info" => options.info = true,
+            _ if arg.starts_with('-') => return Err(format!("unknown option '{arg}'")),
+            _ if project.is_none() => project = Some(PathBuf::from(arg)),
+            _ => return Err(format!("unexpected argument '{arg}'"))
+        }
+    }
+    options.project = project.ok_or_else(|| String::from("no project file given"))?;
+    if !(options.sample_rate.is_finite() && options.sample_rate > 0.0) {
+        return Err(String::from("the sample rate must be positive"));
+    }
+    if options.loops == 0 {
+        return Err(String::from("--loops must be at least 1"));
+    }
+    Ok(options)
+}
+
+fn number<T: std::str::FromStr>(option: &str, text: &str) -> Result<T, String> {
+    text.trim().parse().map_err(|_| format!("{option}: '{text}' is not a valid number"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, Range};
+
+    fn args(line: &str) -> Vec<String> {
+        line.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn defaults_render_the_timeline_once() {
+        let options = parse(args("song.od")).expect("parses");
+        assert_eq!(options.project.to_str(), Some("song.od"));
+        assert_eq!((options.sample_rate, options.loops, options.range), (48_000.0, 1, Range::Timeline));
+        assert!(!options.stems && !options.metronome && options.assets.is_none());
+    }
+
+    #[test]
+    fn options_may_precede_the_project() {
+        let options = parse(args("--bars 5-8 -r 44100 -n 2 --stems song.od -o out")).expect("parses");
+        assert_eq!(options.range, Range::Bars(5, 8));
+        assert_eq!((options.sample_rate, options.loops, options.stems), (44_100.0, 2, true));
+        assert_eq!(options.out.as_deref().and_then(|path| path.to_str()), Some("out"));
+    }
+
+    #[test]
+    fn bad_input_is_reported() {
+        assert!(parse(args("")).is_err(), "no project");
+        assert!(parse(args("song.od --bars 0-4")).is_err(), "bars count from 1");
+        assert!(parse(args("song.od --bars 4-2")).is_err(), "a reversed range");
+        assert!(parse(args("song.od --loops 0")).is_err());
+        assert!(parse(args("song.od --rate")).is_err(), "a missing value");
+        assert!(parse(args("song.od --volume 3")).is_err(), "an unknown option");
+    }
+
+    #[test]
+    fn the_sample_rate_must_be_a_positive_number() {
+        for rate in ["0", "-44100", "nan", "inf"] {
+            assert!(parse(args(&format!("song.od --rate {rate}"))).is_err(), "--rate {rate}");
+        }
+    }
+}
@@ -0,0 +1,149 @@
+//! `opendaw-render`: renders an openDAW project to WAV without a browser, for automated mixdowns and
+//! golden-file regression renders. A thin shell over the engine's offline renderer: it picks the range
+//! (the whole timeline, bars, a marker section or the loop area), resolves assets from a folder and writes
+//! the master mix or one file per audio unit.
+
+mod args;
+
+use std::fs;
+use std::path::Path;
+use std::process::ExitCode;
+use boxgraph::address::uuid_to_string;
+use engine::offline::{inspect, render, AssetDirectory, OfflineConfig, OfflineRender, ProjectInfo, Stem};
+use args::{Options, Range, USAGE};
+
+fn main() -> ExitCode {
+    let arguments: Vec<String> = std::env::args().skip(1).collect();
+    if arguments.iter().any(|arg| arg == "-h" || arg == "--help") {
+        println!("{USAGE}");
+        return ExitCode::SUCCESS;
+    }
+    let options = match args::parse(arguments) {
+        Ok(options) => options,
+        Err(error) => {
+            eprintln!("{error}\n\n{USAGE}");
+            return ExitCode::FAILURE;
+        }
+    };
+    match run(&options) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("opendaw-render: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(options: &Options) -> Result<(), String> {
+    let project = fs::read(&options.project).map_err(|error| format!("{}: {error}", options.project.display()))?;
+    let info = inspect(&project).map_err(|error| error.to_string())?;
+    if options.info {
+        print_info(&info);
+        return Ok(());
+    }
+    let (from, to) = resolve_range(&info, &options.range)?;
+    let stem_units: Vec<_> = info.units.iter().filter(|unit| unit.kind != "output").collect();
+    let mut config = OfflineConfig::new(options.sample_rate, from, to);
+    config.loops = options.loops;
+    config.tail_seconds = options.tail_seconds;
+    config.metronome = options.metronome;
+    if options.stems {
+        config.stems = stem_units.iter().map(|unit| Stem::new(unit.uuid)).collect();
+        config.metronome_stem = options.metronome;
+    }
+    let folder = options.project.parent().unwrap_or(Path::new("")).to_path_buf();
+    let assets = AssetDirectory::new(options.assets.clone().unwrap_or(folder));
+    let rendered = render(&project, &assets, &config).map_err(|error| error.to_string())?;
+    for uuid in &rendered.missing {
+        eprintln!("warning: asset {} not found, it renders silent", uuid_to_string(uuid));
+    }
+    let seconds = rendered.frame_count() as f64 / options.sample_rate as f64;
+    if options.stems {
+        let folder = options.out.clone().unwrap_or_else(|| options.project.with_extension("stems"));
+        let mut names: Vec<String> = stem_units.iter().enumerate()
+            .map(|(index, unit)| format!("{:02}-{}", index + 1, file_name(&unit.label, &unit.kind)))
+            .collect();
+        if config.metronome_stem {
+            names.push(format!("{:02}-metronome", names.len() + 1));
+        }
+        for (index, name) in names.iter().enumerate() {
+            let path = folder.join(format!("{name}.wav"));
+            let stem = OfflineRender {sample_rate: rendered.sample_rate, channels: 2, frames: rendered.stereo_pair(index), missing: Vec::new()};
+            stem.write_wav(&path).map_err(|error| format!("{}: {error}", path.display()))?;
+        }
+        println!("{} stems, {seconds:.2}s -> {}", names.len(), folder.display());
+    } else {
+        let path = options.out.clone().unwrap_or_else(|| options.project.with_extension("wav"));
+        rendered.write_wav(&path).map_err(|error| format!("{}: {error}", path.display()))?;
+        println!("{seconds:.2}s -> {}", path.display());
+    }
+    Ok(())
+}
+
+/// The pulse range `range` covers in `info`'s arrangement.
+fn resolve_range(info: &ProjectInfo, range: &Range) -> Result<(f64, f64), String> {
+    let (from, to) = match range {
+        Range::Timeline => (0.0, info.duration),
+        Range::Bars(first, last) => (info.bar_position(first - 1), info.bar_position(*last)),
+        Range::LoopArea => (info.loop_from, info.loop_to),
+        Range::Marker(key) => {
+            let index = info.markers.iter().position(|marker| &marker.label == key)
+                .or_else(|| key.parse::<usize>().ok().filter(|number| *number >= 1).map(|number| number - 1))
+                .ok_or_else(|| format!("no marker labelled '{key}'"))?;
+            info.marker_range(index).ok_or_else(|| format!("no marker {key}: the project has {}", info.markers.len()))?
+        }
+    };
+    if to <= from {
+        return Err(format!("the range {from}..{to} (pulses) is empty"));
+    }
+    Ok((from, to))
+}
+
+fn print_info(info: &ProjectInfo) {
+    println!("bpm {}, {} pulses", info.bpm, info.duration);
+    println!("loop area {}..{}{}", info.loop_from, info.loop_to, if info.loop_enabled { "" } else { " (disabled)" });
+    for signature in &info.signatures {
+        println!("signature {}/{} at {}", signature.nominator, signature.denominator, signature.position);
+    }
+    for (index, marker) in info.markers.iter().enumerate() {
+        println!("marker {} '{}' at {} (plays {})", index + 1, marker.label, marker.position, marker.plays);
+    }
+    for unit in &info.units {
+        println!("{} '{}' {}", unit.kind, unit.label, uuid_to_string(&unit.uuid));
+    }
+}
+
+// A label as a file name: path separators and other awkward characters become '_'.
+fn file_name(label: &str, fallback: &str) -> String {
+    let name: String = label.trim().chars()
+        .map(|char| if char.is_alphanumeric() || matches!(char, ' ' | '-' | '_' | '.') { char } else { '_' })
+        .collect();
+    if name.is_empty() { fallback.into() } else { name }
+}
+
+#[cfg(test)]
+mod tests {
+    use engine::offline::{MarkerInfo, ProjectInfo, Signature};
+    use super::{resolve_range, Range};
+
+    fn info() -> ProjectInfo {
+        let marker = |position: f64, label: &str| MarkerInfo {position, plays: 1, label: label.into()};
+        ProjectInfo {
+            bpm: 120.0, duration: 38400.0, loop_enabled: false, loop_from: 3840.0, loop_to: 7680.0,
+            signatures: vec![Signature {position: 0.0, nominator: 4, denominator: 4}],
+            markers: vec![marker(0.0, "Intro"), marker(15360.0, "Verse")],
+            units: Vec::new()
+        }
+    }
+
+    #[test]
+    fn ranges_resolve_to_pulses() {
+        let info = info();
+        assert_eq!(resolve_range(&info, &Range::Timeline), Ok((0.0, 38400.0)));
+        assert_eq!(resolve_range(&info, &Range::Bars(2, 3)), Ok((3840.0, 11520.0)));
+        assert_eq!(resolve_range(&info, &Range::LoopArea), Ok((3840.0, 7680.0)));
+        assert_eq!(resolve_range(&info, &Range::Marker("Intro".into())), Ok((0.0, 15360.0)));
+        assert_eq!(resolve_range(&info, &Range::Marker("2".into())), Ok((15360.0, 38400.0)), "the last runs to the end");
+        assert!(resolve_range(&info, &Range::Marker("Bridge".into())).is_err());
+    }
+}
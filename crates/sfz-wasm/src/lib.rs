@@ -0,0 +1,109 @@
+//! The standalone SFZ importer wasm for the main thread: builds the engine's simplified soundfont blob (the
+//! `OSF2` layout `device-soundfont` reads) from an SFZ instrument, with `soundfont_blob::sfz` doing the reading
+//! exactly as the offline renderer does. Runs in its own instance/memory — never the audio thread.
+//!
+//! The host stages the instrument's folder first: its text files (includes) as UTF-8 and its samples decoded
+//! to planar f32 (the browser decodes any format it plays), each under its path relative to the `.sfz` file.
+//! [`sfz_build`] then reads the instrument text against the staged files and leaves the blob (or the error
+//! message) in the output buffer. Addresses cross the boundary as `usize`, as the engine's exports take them.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+use soundfont_blob::sfz::{self, SamplePcm, SfzFiles};
+
+#[derive(Default)]
+struct Staged {
+    texts: HashMap<String, String>,
+    samples: HashMap<String, SamplePcm>,
+    output: Vec<u8>
+}
+
+impl SfzFiles for Staged {
+    fn text(&self, path: &str) -> Result<String, String> {
+        self.texts.get(path).cloned().ok_or_else(|| String::from("not in the instrument folder"))
+    }
+
+    fn sample(&self, path: &str) -> Result<SamplePcm, String> {
+        let pcm = self.samples.get(path).ok_or_else(|| String::from("not in the instrument folder"))?;
+        Ok(SamplePcm {planes: pcm.planes.clone(), sample_rate: pcm.sample_rate})
+    }
+}
+
+std::thread_local! {
+    static STAGED: RefCell<Staged> = RefCell::new(Staged::default());
+}
+
+fn utf8(ptr: usize, len: usize) -> String {
+    String::from_utf8_lossy(unsafe { core::slice::from_raw_parts(ptr as *const u8, len) }).into_owned()
+}
+
+/// Allocate a buffer inside this module's memory (the host copies files in).
+#[no_mangle]
+pub extern "C" fn alloc_bytes(len: usize) -> *mut u8 {
+    let mut buffer = Vec::<u8>::with_capacity(len);
+    let ptr = buffer.as_mut_ptr();
+    core::mem::forget(buffer);
+    ptr
+}
+
+#[no_mangle]
+pub extern "C" fn free_bytes(ptr: usize, len: usize) {
+    unsafe { drop(Vec::from_raw_parts(ptr as *mut u8, 0, len)) };
+}
+
+/// Drop every staged file and the last output (start of the next instrument).
+#[no_mangle]
+pub extern "C" fn sfz_reset() {
+    STAGED.with(|staged| *staged.borrow_mut() = Staged::default());
+}
+
+/// Stage a text file (an `#include`d file) under `path`.
+#[no_mangle]
+pub extern "C" fn sfz_stage_text(path_ptr: usize, path_len: usize, text_ptr: usize, text_len: usize) {
+    let (path, text) = (utf8(path_ptr, path_len), utf8(text_ptr, text_len));
+    STAGED.with(|staged| staged.borrow_mut().texts.insert(path, text));
+}
+
+/// Stage a decoded sample under `path`: `channel_count` planes of `frame_count` f32 frames, back to back.
+#[no_mangle]
+pub extern "C" fn sfz_stage_sample(
+    path_ptr: usize,
+    path_len: usize,
+    planes_ptr: usize,
+    channel_count: usize,
+    frame_count: usize,
+    sample_rate: f32
+) {
+    let path = utf8(path_ptr, path_len);
+    let frames = unsafe { core::slice::from_raw_parts(planes_ptr as *const f32, channel_count * frame_count) };
+    let planes = frames.chunks_exact(frame_count.max(1)).take(channel_count).map(<[f32]>::to_vec).collect();
+    STAGED.with(|staged| staged.borrow_mut().samples.insert(path, SamplePcm {planes, sample_rate}));
+}
+
+/// Build the blob from the instrument text against the staged files. Returns 1 with the blob in the output
+/// buffer, or 0 with the error message (UTF-8) there instead.
+#[no_mangle]
+pub extern "C" fn sfz_build(text_ptr: usize, text_len: usize) -> u32 {
+    let text = utf8(text_ptr, text_len);
+    STAGED.with(|staged| {
+        let mut staged = staged.borrow_mut();
+        let (output, built) = match sfz::build(&text, &*staged) {
+            Ok(instrument) => (soundfont_blob::encode(&instrument), 1),
+            Err(error) => (error.to_string().into_bytes(), 0)
+        };
+        staged.output = output;
+        built
+    })
+}
+
+/// The output buffer of the last [`sfz_build`].
+#[no_mangle]
+pub extern "C" fn sfz_output_ptr() -> *const u8 {
+    STAGED.with(|staged| staged.borrow().output.as_ptr())
+}
+
+#[no_mangle]
+pub extern "C" fn sfz_output_len() -> usize {
+    STAGED.with(|staged| staged.borrow().output.len())
+}
@@ -0,0 +1,34 @@
+//! The importer's exports driven the way the main thread drives them: stage the folder, build, read the output.
+
+use sfz_wasm::{sfz_build, sfz_output_len, sfz_output_ptr, sfz_reset, sfz_stage_sample, sfz_stage_text};
+
+fn output() -> Vec<u8> {
+    unsafe { core::slice::from_raw_parts(sfz_output_ptr(), sfz_output_len()) }.to_vec()
+}
+
+fn build(text: &str) -> (u32, Vec<u8>) {
+    let built = sfz_build(text.as_ptr() as usize, text.len());
+    (built, output())
+}
+
+#[test]
+fn a_staged_instrument_builds_into_a_blob() {
+    sfz_reset();
+    let include = "<region> sample=samples/tone.wav lokey=0 hikey=127 pitch_keycenter=60";
+    let path = "regions.sfz";
+    sfz_stage_text(path.as_ptr() as usize, path.len(), include.as_ptr() as usize, include.len());
+    let planes: Vec<f32> = (0..200).map(|frame| (frame as f32 * 0.1).sin()).collect();
+    let path = "samples/tone.wav";
+    sfz_stage_sample(path.as_ptr() as usize, path.len(), planes.as_ptr() as usize, 2, 100, 44100.0);
+    let (built, blob) = build("#include \"regions.sfz\"");
+    assert_eq!(built, 1, "{}", String::from_utf8_lossy(&blob));
+    assert_eq!(u32::from_le_bytes([blob[0], blob[1], blob[2], blob[3]]), soundfont_blob::MAGIC);
+}
+
+#[test]
+fn a_missing_sample_reports_its_path() {
+    sfz_reset();
+    let (built, message) = build("<region> sample=missing.wav");
+    assert_eq!(built, 0);
+    assert_eq!(String::from_utf8(message).unwrap(), "missing.wav: not in the instrument folder");
+}
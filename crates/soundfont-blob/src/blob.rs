@@ -0,0 +1,198 @@
+//! The plain soundfont description and its serialization into the blob (WASM CONTRACT, mirrored from
+//! `device-soundfont/src/blob.rs` like the TS builder mirrors it). A description whose regions all keep the
+//! neutral [`RegionRules`] encodes as version 1, byte for byte what `encodeSoundfont` writes; any rule in use
+//! selects version 2, whose region records grow from 40 to 64 bytes.
+
+pub const MAGIC: u32 = 0x4F53_4632; // "OSF2"
+const HEADER_BYTES: usize = 32;
+const SAMPLE_STRIDE: usize = 24;
+const REGION_STRIDE: usize = 40;
+const REGION_STRIDE_V2: usize = 64;
+const PRESET_STRIDE: usize = 8;
+
+/// The "no key" marker of the key-switch bytes (outside the MIDI range).
+pub const NO_KEY: u8 = 0xFF;
+pub const TRIGGER_ATTACK: u8 = 0;
+pub const TRIGGER_RELEASE: u8 = 1;
+pub const XF_CURVE_POWER: u8 = 0;
+pub const XF_CURVE_GAIN: u8 = 1;
+
+/// One mono sample plane, already normalized, with its loop points relative to the sample start.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SimpleSample {
+    pub pcm: Vec<f32>,
+    pub sample_rate: f32,
+    pub loop_start: u32,
+    pub loop_end: u32
+}
+
+/// The version 2 region rules: a level and fine tune, the velocity crossfade ramps, the key-switch, the
+/// round-robin slot and the trigger. [`Default`] is the neutral set a version 1 region reads as.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RegionRules {
+    pub gain: f32,
+    pub tune: f32, // cents
+    pub xfin_lo: u8,
+    pub xfin_hi: u8,
+    pub xfout_lo: u8,
+    pub xfout_hi: u8,
+    pub xf_curve: u8,
+    pub sw_lo: u8,
+    pub sw_hi: u8,
+    pub sw_last: u8,
+    pub sw_default: u8,
+    pub seq_length: u8,
+    pub seq_position: u8,
+    pub trigger: u8
+}
+
+impl Default for RegionRules {
+    fn default() -> Self {
+        Self {
+            gain: 1.0, tune: 0.0, xfin_lo: 0, xfin_hi: 0, xfout_lo: 127, xfout_hi: 127, xf_curve: XF_CURVE_POWER,
+            sw_lo: NO_KEY, sw_hi: 0, sw_last: NO_KEY, sw_default: NO_KEY, seq_length: 1, seq_position: 1,
+            trigger: TRIGGER_ATTACK
+        }
+    }
+}
+
+/// One region with every setting resolved: times in seconds, sustain and pan as unit values.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SimpleRegion {
+    pub key_lo: u8,
+    pub key_hi: u8,
+    pub vel_lo: u8,
+    pub vel_hi: u8,
+    pub sample_index: u32,
+    pub root_key: u32,
+    pub loop_mode: u32, // 0 = no loop, 1 = loop
+    pub pan: f32,
+    pub attack: f32,
+    pub decay: f32,
+    pub sustain: f32,
+    pub release: f32,
+    pub rules: RegionRules
+}
+
+/// The samples and, per preset (index = preset index), its regions.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SimpleSoundfont {
+    pub samples: Vec<SimpleSample>,
+    pub presets: Vec<Vec<SimpleRegion>>
+}
+
+fn put_u32(bytes: &mut [u8], offset: usize, value: u32) {
+    bytes[offset..offset + 4].copy_from_slice(&value.to_le_bytes());
+}
+
+fn put_f32(bytes: &mut [u8], offset: usize, value: f32) {
+    bytes[offset..offset + 4].copy_from_slice(&value.to_le_bytes());
+}
+
+/// Serialize `soundfont` into the blob: header, sample table, region table (all presets flattened), preset
+/// table, then the PCM planes back to back.
+pub fn encode(soundfont: &SimpleSoundfont) -> Vec<u8> {
+    let SimpleSoundfont {samples, presets} = soundfont;
+    let extended = presets.iter().flatten().any(|region| region.rules != RegionRules::default());
+    let (version, region_stride) = if extended {(2, REGION_STRIDE_V2)} else {(1, REGION_STRIDE)};
+    let region_count: usize = presets.iter().map(Vec::len).sum();
+    let samples_off = HEADER_BYTES;
+    let regions_off = samples_off + samples.len() * SAMPLE_STRIDE;
+    let presets_off = regions_off + region_count * region_stride;
+    let pcm_off = presets_off + presets.len() * PRESET_STRIDE;
+    let pcm_frames: usize = samples.iter().map(|sample| sample.pcm.len()).sum();
+    let mut bytes = vec![0u8; pcm_off + pcm_frames * 4];
+    let header = [MAGIC, version, samples.len() as u32, region_count as u32, presets.len() as u32,
+        samples_off as u32, regions_off as u32, presets_off as u32];
+    for (index, value) in header.into_iter().enumerate() {
+        put_u32(&mut bytes, index * 4, value);
+    }
+    let mut pcm_cursor = pcm_off;
+    for (index, sample) in samples.iter().enumerate() {
+        let base = samples_off + index * SAMPLE_STRIDE;
+        put_u32(&mut bytes, base, pcm_cursor as u32);
+        put_u32(&mut bytes, base + 4, sample.pcm.len() as u32);
+        put_f32(&mut bytes, base + 8, sample.sample_rate);
+        put_u32(&mut bytes, base + 12, sample.loop_start);
+        put_u32(&mut bytes, base + 16, sample.loop_end);
+        for value in &sample.pcm {
+            put_f32(&mut bytes, pcm_cursor, *value);
+            pcm_cursor += 4;
+        }
+    }
+    let mut region_cursor = 0;
+    for (preset_index, regions) in presets.iter().enumerate() {
+        let preset_base = presets_off + preset_index * PRESET_STRIDE;
+        put_u32(&mut bytes, preset_base, region_cursor as u32);
+        put_u32(&mut bytes, preset_base + 4, regions.len() as u32);
+        for region in regions {
+            let base = regions_off + region_cursor * region_stride;
+            bytes[base..base + 4].copy_from_slice(&[region.key_lo, region.key_hi, region.vel_lo, region.vel_hi]);
+            put_u32(&mut bytes, base + 4, region.sample_index);
+            put_u32(&mut bytes, base + 8, region.root_key);
+            put_u32(&mut bytes, base + 12, region.loop_mode);
+            put_f32(&mut bytes, base + 16, region.pan);
+            put_f32(&mut bytes, base + 20, region.attack);
+            put_f32(&mut bytes, base + 24, region.decay);
+            put_f32(&mut bytes, base + 28, region.sustain);
+            put_f32(&mut bytes, base + 32, region.release);
+            if extended {
+                let rules = &region.rules;
+                put_f32(&mut bytes, base + 40, rules.gain);
+                put_f32(&mut bytes, base + 44, rules.tune);
+                bytes[base + 48..base + 60].copy_from_slice(&[
+                    rules.xfin_lo, rules.xfin_hi, rules.xfout_lo, rules.xfout_hi,
+                    rules.sw_lo, rules.sw_hi, rules.sw_last, rules.sw_default,
+                    rules.seq_length, rules.seq_position, rules.trigger, rules.xf_curve
+                ]);
+            }
+            region_cursor += 1;
+        }
+    }
+    bytes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn u32_at(bytes: &[u8], offset: usize) -> u32 {
+        u32::from_le_bytes(bytes[offset..offset + 4].try_into().unwrap())
+    }
+
+    fn region(sample_index: u32, rules: RegionRules) -> SimpleRegion {
+        SimpleRegion {
+            key_lo: 0, key_hi: 127, vel_lo: 0, vel_hi: 127, sample_index, root_key: 60, loop_mode: 0, pan: 0.0,
+            attack: 0.005, decay: 0.005, sustain: 1.0, release: 0.005, rules
+        }
+    }
+
+    fn soundfont(rules: RegionRules) -> SimpleSoundfont {
+        SimpleSoundfont {
+            samples: vec![SimpleSample {pcm: vec![0.25, -0.5, 1.0], sample_rate: 44_100.0, loop_start: 1, loop_end: 3}],
+            presets: vec![vec![region(0, RegionRules::default())], vec![region(0, rules)]]
+        }
+    }
+
+    #[test]
+    fn neutral_rules_encode_the_version_1_layout() {
+        let bytes = encode(&soundfont(RegionRules::default()));
+        let header: Vec<u32> = (0..8).map(|index| u32_at(&bytes, index * 4)).collect();
+        assert_eq!(header, vec![MAGIC, 1, 1, 2, 2, 32, 56, 136]);
+        assert_eq!((u32_at(&bytes, 136), u32_at(&bytes, 140)), (0, 1), "preset 0 is region 0");
+        assert_eq!((u32_at(&bytes, 144), u32_at(&bytes, 148)), (1, 1), "preset 1 is region 1");
+        assert_eq!(u32_at(&bytes, 32), 152, "the PCM follows the preset table");
+        assert_eq!(bytes.len(), 152 + 3 * 4);
+        assert_eq!(f32::from_le_bytes(bytes[156..160].try_into().unwrap()), -0.5);
+    }
+
+    #[test]
+    fn a_rule_in_use_widens_every_region() {
+        let rules = RegionRules {seq_length: 2, seq_position: 2, trigger: TRIGGER_RELEASE, ..RegionRules::default()};
+        let bytes = encode(&soundfont(rules));
+        assert_eq!((u32_at(&bytes, 4), u32_at(&bytes, 28)), (2, 56 + 2 * 64), "version 2, 64-byte regions");
+        assert_eq!(&bytes[56 + 48..56 + 60], &[0, 0, 127, 127, NO_KEY, 0, NO_KEY, NO_KEY, 1, 1, 0, 0], "region 0 stays neutral");
+        assert_eq!(&bytes[120 + 56..120 + 60], &[2, 2, TRIGGER_RELEASE, XF_CURVE_POWER]);
+        assert_eq!(f32::from_le_bytes(bytes[120 + 40..120 + 44].try_into().unwrap()), 1.0);
+    }
+}
@@ -0,0 +1,13 @@
+//! Native builders of the engine's simplified soundfont BLOB (the `OSF2` layout `device-soundfont` reads in
+//! place, see its `blob.rs`): the Rust counterpart of the main thread's `soundfont-simplify.ts`, for hosts
+//! without a browser (the offline renderer) and for instrument formats the TS side does not read.
+//!
+//! [`encode`] serializes a plain description ([`SimpleSoundfont`]) exactly like the TS `encodeSoundfont`;
+//! [`sfz::build`] turns an SFZ instrument into one.
+
+mod blob;
+pub mod sf2;
+pub mod sfz;
+
+pub use blob::{encode, RegionRules, SimpleRegion, SimpleSample, SimpleSoundfont};
+pub use blob::{MAGIC, NO_KEY, TRIGGER_ATTACK, TRIGGER_RELEASE, XF_CURVE_GAIN, XF_CURVE_POWER};
@@ -0,0 +1,462 @@
+//! SoundFont 2 files (RIFF `sfbk`) as a [`SimpleSoundfont`]: [`parse`] reads the sample data and the
+//! `pdta` hydra (preset / instrument headers, their zones with generators and modulators, sample headers),
+//! [`simplify`] resolves it EXACTLY as `soundfont-simplify.ts` does, so a font builds the same blob natively
+//! as in the browser.
+//!
+//! Inheritance follows the spec within a level: a preset's or instrument's global zone (its first zone when
+//! that has no `instrument` / `sampleID` generator) supplies the generators its other zones do not set. Across
+//! levels it follows the TS voice instead of the spec: an instrument zone's generator overrides the preset
+//! zone's, rather than the preset one adding to it. Modulators are read but not applied (the voice has none).
+
+use std::collections::BTreeMap;
+use std::fmt;
+use crate::blob::{RegionRules, SimpleRegion, SimpleSample, SimpleSoundfont};
+
+/// The SF2 generator ids (spec-fixed) the parser and the simplification read.
+pub mod generator {
+    pub const PAN: u16 = 17;
+    pub const ATTACK_VOL_ENV: u16 = 34;
+    pub const DECAY_VOL_ENV: u16 = 36;
+    pub const SUSTAIN_VOL_ENV: u16 = 37;
+    pub const RELEASE_VOL_ENV: u16 = 38;
+    pub const INSTRUMENT: u16 = 41;
+    pub const KEY_RANGE: u16 = 43;
+    pub const VEL_RANGE: u16 = 44;
+    pub const SAMPLE_ID: u16 = 53;
+    pub const SAMPLE_MODES: u16 = 54;
+    pub const OVERRIDING_ROOT_KEY: u16 = 58;
+}
+
+const PHDR_STRIDE: usize = 38;
+const BAG_STRIDE: usize = 4;
+const MOD_STRIDE: usize = 10;
+const GEN_STRIDE: usize = 4;
+const INST_STRIDE: usize = 22;
+const SHDR_STRIDE: usize = 46;
+const DEFAULT_SECONDS: f64 = 0.005; // the TS builder's envelope time when a generator is absent
+
+#[derive(Debug, PartialEq)]
+pub enum Sf2Error {
+    /// The bytes are not a RIFF `sfbk` file.
+    NotSoundfont,
+    /// A required chunk is missing, or a chunk or index points outside the file.
+    Malformed(String)
+}
+
+impl fmt::Display for Sf2Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Sf2Error::NotSoundfont => write!(f, "not a RIFF sfbk file"),
+            Sf2Error::Malformed(reason) => write!(f, "malformed soundfont: {reason}")
+        }
+    }
+}
+
+impl std::error::Error for Sf2Error {}
+
+fn malformed(reason: &str) -> Sf2Error {
+    Sf2Error::Malformed(reason.to_string())
+}
+
+/// One modulator record, as stored (`sfModSrcOper`, `sfModDestOper`, `modAmount`, `sfModAmtSrcOper`,
+/// `sfModTransOper`).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Modulator {
+    pub source: u16,
+    pub destination: u16,
+    pub amount: i16,
+    pub amount_source: u16,
+    pub transform: u16
+}
+
+/// One zone: its generators by id (the raw 16-bit amount, the global zone's already filled in) and its
+/// modulators.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Zone {
+    pub generators: BTreeMap<u16, u16>,
+    pub modulators: Vec<Modulator>
+}
+
+impl Zone {
+    /// A generator's signed amount.
+    pub fn value(&self, id: u16) -> Option<i16> {
+        self.generators.get(&id).map(|amount| *amount as i16)
+    }
+
+    /// A range generator's `lo..=hi`, the whole MIDI range when absent.
+    pub fn range(&self, id: u16) -> (u8, u8) {
+        self.generators.get(&id).map_or((0, 127), |amount| (*amount as u8, (*amount >> 8) as u8))
+    }
+
+    /// The amount of an index generator (`instrument`, `sampleID`).
+    fn index(&self, id: u16) -> Option<usize> {
+        self.generators.get(&id).map(|amount| *amount as usize)
+    }
+
+    /// Fill in every generator `global` sets and this zone does not; the modulators add up.
+    fn inherit(&mut self, global: &Zone) {
+        for (id, amount) in &global.generators {
+            self.generators.entry(*id).or_insert(*amount);
+        }
+        let inherited: Vec<Modulator> = global.modulators.iter()
+            .filter(|modulator| !self.modulators.iter().any(|own| same_modulator(own, modulator)))
+            .copied()
+            .collect();
+        self.modulators.extend(inherited);
+    }
+}
+
+/// Modulators are identical when all but their amount match (the spec's rule for overriding).
+fn same_modulator(a: &Modulator, b: &Modulator) -> bool {
+    (a.source, a.destination, a.amount_source, a.transform) == (b.source, b.destination, b.amount_source, b.transform)
+}
+
+/// A sample header with its 16-bit PCM; loop points are relative to the sample start.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Sf2Sample {
+    pub name: String,
+    pub pcm: Vec<i16>,
+    pub sample_rate: u32,
+    pub loop_start: u32,
+    pub loop_end: u32,
+    pub original_pitch: u8,
+    pub pitch_correction: i8,
+    pub link: u16,
+    pub kind: u16
+}
+
+/// An instrument: its zones (each naming a sample through `sampleID`), the global zone folded in.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Instrument {
+    pub name: String,
+    pub zones: Vec<Zone>
+}
+
+/// A preset: its zones (each naming an instrument through `instrument`), the global zone folded in.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Preset {
+    pub name: String,
+    pub preset: u16,
+    pub bank: u16,
+    pub zones: Vec<Zone>
+}
+
+/// A parsed font: presets in header order (the order the device's preset index counts).
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Sf2 {
+    pub presets: Vec<Preset>,
+    pub instruments: Vec<Instrument>,
+    pub samples: Vec<Sf2Sample>
+}
+
+fn u16_at(bytes: &[u8], offset: usize) -> u16 {
+    u16::from_le_bytes([bytes[offset], bytes[offset + 1]])
+}
+
+fn u32_at(bytes: &[u8], offset: usize) -> u32 {
+    u32::from_le_bytes([bytes[offset], bytes[offset + 1], bytes[offset + 2], bytes[offset + 3]])
+}
+
+fn name_at(bytes: &[u8], offset: usize) -> String {
+    let raw = &bytes[offset..offset + 20];
+    let end = raw.iter().position(|byte| *byte == 0).unwrap_or(raw.len());
+    String::from_utf8_lossy(&raw[..end]).into_owned()
+}
+
+/// The sub-chunks of a RIFF list body as `(id, body)` (a truncated last chunk is cut to the bytes present).
+fn chunks(mut body: &[u8]) -> Vec<(&[u8], &[u8])> {
+    let mut list = Vec::new();
+    while body.len() >= 8 {
+        let size = u32_at(body, 4) as usize;
+        let end = 8usize.saturating_add(size).min(body.len());
+        list.push((&body[..4], &body[8..end]));
+        body = &body[end.saturating_add(size & 1).min(body.len())..];
+    }
+    list
+}
+
+/// The body of the `LIST` chunk of `kind` among `chunks`.
+fn list<'a>(chunks: &[(&'a [u8], &'a [u8])], kind: &[u8]) -> Option<&'a [u8]> {
+    chunks.iter().find(|(id, body)| *id == b"LIST" && body.len() >= 4 && &body[..4] == kind).map(|(_, body)| &body[4..])
+}
+
+/// A hydra table: the `id` chunk of `pdta` split into records of `stride` bytes.
+fn records<'a>(pdta: &[(&'a [u8], &'a [u8])], id: &[u8], stride: usize) -> Result<Vec<&'a [u8]>, Sf2Error> {
+    let (_, body) = pdta.iter().find(|(chunk, _)| *chunk == id)
+        .ok_or_else(|| Sf2Error::Malformed(format!("no {} chunk", String::from_utf8_lossy(id))))?;
+    Ok(body.chunks_exact(stride).collect())
+}
+
+/// The zones of one preset or instrument: bags `bags[first..last]`, each spanning its generators and
+/// modulators up to the next bag's. The first zone is global when it lacks the `terminal` generator; it is
+/// folded into the others and dropped.
+fn zones(bags: &[&[u8]], generators: &[&[u8]], modulators: &[&[u8]], first: usize, last: usize, terminal: u16) -> Result<Vec<Zone>, Sf2Error> {
+    if first > last || last >= bags.len() {
+        return Err(malformed("a bag index is out of range"));
+    }
+    let mut zones = Vec::with_capacity(last - first);
+    for bag in first..last {
+        let (gen_from, mod_from) = (u16_at(bags[bag], 0) as usize, u16_at(bags[bag], 2) as usize);
+        let (gen_to, mod_to) = (u16_at(bags[bag + 1], 0) as usize, u16_at(bags[bag + 1], 2) as usize);
+        let (Some(gens), Some(mods)) = (generators.get(gen_from..gen_to), modulators.get(mod_from..mod_to)) else {
+            return Err(malformed("a generator or modulator index is out of range"));
+        };
+        let mut zone = Zone::default();
+        for record in gens {
+            zone.generators.insert(u16_at(record, 0), u16_at(record, 2));
+        }
+        zone.modulators = mods.iter().map(|record| Modulator {
+            source: u16_at(record, 0),
+            destination: u16_at(record, 2),
+            amount: u16_at(record, 4) as i16,
+            amount_source: u16_at(record, 6),
+            transform: u16_at(record, 8)
+        }).collect();
+        zones.push(zone);
+    }
+    if zones.first().is_some_and(|zone| !zone.generators.contains_key(&terminal)) {
+        let global = zones.remove(0);
+        for zone in &mut zones {
+            zone.inherit(&global);
+        }
+    }
+    zones.retain(|zone| zone.generators.contains_key(&terminal));
+    Ok(zones)
+}
+
+/// Read a `.sf2` file.
+pub fn parse(bytes: &[u8]) -> Result<Sf2, Sf2Error> {
+    if bytes.len() < 12 || &bytes[0..4] != b"RIFF" || &bytes[8..12] != b"sfbk" {
+        return Err(Sf2Error::NotSoundfont);
+    }
+    let top = chunks(&bytes[12..]);
+    let sdta = chunks(list(&top, b"sdta").ok_or_else(|| malformed("no sdta list"))?);
+    let pdta = chunks(list(&top, b"pdta").ok_or_else(|| malformed("no pdta list"))?);
+    let smpl = sdta.iter().find(|(id, _)| *id == b"smpl").map_or(&[][..], |(_, body)| *body);
+    let pcm: Vec<i16> = smpl.chunks_exact(2).map(|pair| i16::from_le_bytes([pair[0], pair[1]])).collect();
+    let phdr = records(&pdta, b"phdr", PHDR_STRIDE)?;
+    let pbag = records(&pdta, b"pbag", BAG_STRIDE)?;
+    let pmod = records(&pdta, b"pmod", MOD_STRIDE)?;
+    let pgen = records(&pdta, b"pgen", GEN_STRIDE)?;
+    let inst = records(&pdta, b"inst", INST_STRIDE)?;
+    let ibag = records(&pdta, b"ibag", BAG_STRIDE)?;
+    let imod = records(&pdta, b"imod", MOD_STRIDE)?;
+    let igen = records(&pdta, b"igen", GEN_STRIDE)?;
+    let shdr = records(&pdta, b"shdr", SHDR_STRIDE)?;
+    // Every header table ends in a terminal record (EOP / EOI / EOS) that only bounds the last entry.
+    let samples = shdr.iter().take(shdr.len().saturating_sub(1)).map(|record| {
+        let start = u32_at(record, 20);
+        let end = u32_at(record, 24).max(start);
+        Sf2Sample {
+            name: name_at(record, 0),
+            pcm: pcm.get(start as usize..(end as usize).min(pcm.len())).map_or_else(Vec::new, <[i16]>::to_vec),
+            sample_rate: u32_at(record, 36),
+            loop_start: u32_at(record, 28).saturating_sub(start),
+            loop_end: u32_at(record, 32).saturating_sub(start),
+            original_pitch: record[40],
+            pitch_correction: record[41] as i8,
+            link: u16_at(record, 42),
+            kind: u16_at(record, 44)
+        }
+    }).collect();
+    let instruments = inst.windows(2).map(|pair| Ok(Instrument {
+        name: name_at(pair[0], 0),
+        zones: zones(&ibag, &igen, &imod, u16_at(pair[0], 20) as usize, u16_at(pair[1], 20) as usize, generator::SAMPLE_ID)?
+    })).collect::<Result<_, Sf2Error>>()?;
+    let presets = phdr.windows(2).map(|pair| Ok(Preset {
+        name: name_at(pair[0], 0),
+        preset: u16_at(pair[0], 20),
+        bank: u16_at(pair[0], 22),
+        zones: zones(&pbag, &pgen, &pmod, u16_at(pair[0], 24) as usize, u16_at(pair[1], 24) as usize, generator::INSTRUMENT)?
+    })).collect::<Result<_, Sf2Error>>()?;
+    Ok(Sf2 {presets, instruments, samples})
+}
+
+fn timecents_to_seconds(value: Option<i16>) -> f32 {
+    value.map_or(DEFAULT_SECONDS, |value| 2.0f64.powf(value as f64 / 1200.0)) as f32
+}
+
+/// Flatten `sf2` into the blob description: every preset zone × instrument zone whose key and velocity ranges
+/// intersect becomes a region (the intersection), samples are taken in order of first use.
+pub fn simplify(sf2: &Sf2) -> SimpleSoundfont {
+    use generator::*;
+    let mut samples = Vec::new();
+    let mut sample_index: BTreeMap<usize, u32> = BTreeMap::new();
+    let presets = sf2.presets.iter().map(|preset| {
+        let mut regions = Vec::new();
+        for preset_zone in &preset.zones {
+            let Some(instrument) = preset_zone.index(INSTRUMENT).and_then(|index| sf2.instruments.get(index)) else { continue };
+            let (preset_key, preset_vel) = (preset_zone.range(KEY_RANGE), preset_zone.range(VEL_RANGE));
+            for zone in &instrument.zones {
+                let (key, vel) = (zone.range(KEY_RANGE), zone.range(VEL_RANGE));
+                let (key_lo, key_hi) = (preset_key.0.max(key.0), preset_key.1.min(key.1));
+                let (vel_lo, vel_hi) = (preset_vel.0.max(vel.0), preset_vel.1.min(vel.1));
+                if key_lo > key_hi || vel_lo > vel_hi {
+                    continue; // an empty intersection: no note reaches it
+                }
+                let Some((sample_id, sample)) = zone.index(SAMPLE_ID).and_then(|id| Some((id, sf2.samples.get(id)?))) else { continue };
+                let index = *sample_index.entry(sample_id).or_insert_with(|| {
+                    samples.push(SimpleSample {
+                        pcm: sample.pcm.iter().map(|value| (*value as f64 / 32768.0) as f32).collect(),
+                        sample_rate: sample.sample_rate as f32,
+                        loop_start: sample.loop_start,
+                        loop_end: sample.loop_end
+                    });
+                    samples.len() as u32 - 1
+                });
+                // instrument-overrides-preset, as the TS `getCombinedGenerator`
+                let combined = |id: u16| zone.value(id).or_else(|| preset_zone.value(id));
+                // `-1` (or any value off the keyboard) is "no override", as the spec (and `simplifySoundfont`) read it.
+                let root_key = zone.value(OVERRIDING_ROOT_KEY).filter(|key| (0..128).contains(key))
+                    .map_or(sample.original_pitch as u32, |key| key as u32);
+                let sample_modes = combined(SAMPLE_MODES).unwrap_or(0);
+                regions.push(SimpleRegion {
+                    key_lo, key_hi, vel_lo, vel_hi,
+                    sample_index: index,
+                    root_key,
+                    loop_mode: (sample_modes == 1 || sample_modes == 3) as u32,
+                    pan: (combined(PAN).unwrap_or(0) as f64 / 1000.0) as f32,
+                    attack: timecents_to_seconds(combined(ATTACK_VOL_ENV)),
+                    decay: timecents_to_seconds(combined(DECAY_VOL_ENV)),
+                    sustain: (1.0 - combined(SUSTAIN_VOL_ENV).unwrap_or(0) as f64 / 1000.0) as f32,
+                    release: timecents_to_seconds(combined(RELEASE_VOL_ENV)),
+                    rules: RegionRules::default()
+                });
+            }
+        }
+        regions
+    }).collect();
+    SimpleSoundfont {samples, presets}
+}
+
+/// Parse and simplify a `.sf2` file in one step.
+pub fn build(bytes: &[u8]) -> Result<SimpleSoundfont, Sf2Error> {
+    parse(bytes).map(|sf2| simplify(&sf2))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::generator::*;
+
+    type Gens = Vec<(u16, u16)>;
+
+    fn chunk(id: &[u8], body: &[u8]) -> Vec<u8> {
+        let mut bytes = id.to_vec();
+        bytes.extend_from_slice(&(body.len() as u32).to_le_bytes());
+        bytes.extend_from_slice(body);
+        if body.len() % 2 == 1 {
+            bytes.push(0);
+        }
+        bytes
+    }
+
+    fn riff_list(kind: &[u8], chunks: &[Vec<u8>]) -> Vec<u8> {
+        chunk(b"LIST", &[kind.to_vec(), chunks.concat()].concat())
+    }
+
+    fn name(text: &str) -> Vec<u8> {
+        let mut bytes = text.as_bytes().to_vec();
+        bytes.resize(20, 0);
+        bytes
+    }
+
+    fn words(values: &[u16]) -> Vec<u8> {
+        values.iter().flat_map(|value| value.to_le_bytes()).collect()
+    }
+
+    /// A font with one preset zone list (`preset`), one instrument zone list (`instrument`, each zone with one
+    /// modulator) and one sample: `pcm` behind four frames of padding, looping over `loop_points`.
+    fn font(preset: &[Gens], instrument: &[Gens], pcm: &[i16], loop_points: (u32, u32)) -> Vec<u8> {
+        let (mut pbag, mut pgen, mut ibag, mut igen, mut imod) = (Vec::new(), Vec::new(), Vec::new(), Vec::new(), Vec::new());
+        for zone in preset {
+            pbag.extend(words(&[(pgen.len() / 4) as u16, 0]));
+            zone.iter().for_each(|(id, amount)| pgen.extend(words(&[*id, *amount])));
+        }
+        pbag.extend(words(&[(pgen.len() / 4) as u16, 0]));
+        pgen.extend(words(&[0, 0]));
+        for (index, zone) in instrument.iter().enumerate() {
+            ibag.extend(words(&[(igen.len() / 4) as u16, index as u16]));
+            zone.iter().for_each(|(id, amount)| igen.extend(words(&[*id, *amount])));
+            imod.extend(words(&[0x0502, 48, 960, 0, index as u16]));
+        }
+        ibag.extend(words(&[(igen.len() / 4) as u16, instrument.len() as u16]));
+        igen.extend(words(&[0, 0]));
+        imod.extend(words(&[0; 5]));
+        let phdr = [name("Piano"), words(&[3, 0, 0]), vec![0; 12], name("EOP"), words(&[0, 0, preset.len() as u16]), vec![0; 12]].concat();
+        let inst = [name("Piano"), words(&[0]), name("EOI"), words(&[instrument.len() as u16])].concat();
+        let (start, end) = (4u32, 4 + pcm.len() as u32);
+        let mut shdr = name("C4");
+        for value in [start, end, start + loop_points.0, start + loop_points.1, 44_100] {
+            shdr.extend(value.to_le_bytes());
+        }
+        shdr.extend([48, 0, 0, 0, 1, 0]);
+        shdr.extend([name("EOS"), vec![0; 26]].concat());
+        let smpl: Vec<u8> = [0i16; 4].iter().chain(pcm).chain(&[0i16; 46]).flat_map(|value| value.to_le_bytes()).collect();
+        let body = [
+            b"sfbk".to_vec(),
+            riff_list(b"INFO", &[chunk(b"ifil", &words(&[2, 1]))]),
+            riff_list(b"sdta", &[chunk(b"smpl", &smpl)]),
+            riff_list(b"pdta", &[
+                chunk(b"phdr", &phdr), chunk(b"pbag", &pbag), chunk(b"pmod", &[0; 10]), chunk(b"pgen", &pgen),
+                chunk(b"inst", &inst), chunk(b"ibag", &ibag), chunk(b"imod", &imod), chunk(b"igen", &igen),
+                chunk(b"shdr", &shdr)
+            ])
+        ].concat();
+        chunk(b"RIFF", &body)
+    }
+
+    fn range(lo: u8, hi: u8) -> u16 {
+        lo as u16 | (hi as u16) << 8
+    }
+
+    #[test]
+    fn zones_resolve_like_the_ts_builder() {
+        // The TS builder's own test case: a preset zone (key 36..72, pan 250) over an instrument zone (key
+        // 48..84) that sets attack / sustain / sample modes / root key and overrides the pan.
+        let preset = vec![vec![(KEY_RANGE, range(36, 72)), (VEL_RANGE, range(0, 127)), (PAN, 250), (INSTRUMENT, 0)]];
+        let instrument = vec![vec![
+            (KEY_RANGE, range(48, 84)), (ATTACK_VOL_ENV, 1200), (SUSTAIN_VOL_ENV, 100), (SAMPLE_MODES, 1),
+            (OVERRIDING_ROOT_KEY, 50), (PAN, 500), (SAMPLE_ID, 0)
+        ]];
+        let pcm = [0, 16384, -16384, 32767, -32768, 0, 100, 200];
+        let soundfont = build(&font(&preset, &instrument, &pcm, (1, 7))).expect("parses");
+        let region = &soundfont.presets[0][0];
+        assert_eq!((region.key_lo, region.key_hi, region.vel_lo, region.vel_hi), (48, 72, 0, 127), "the intersection");
+        assert_eq!((region.root_key, region.loop_mode, region.pan), (50, 1, 0.5));
+        assert_eq!((region.attack, region.decay, region.sustain, region.release), (2.0, 0.005, 0.9, 0.005));
+        let sample = &soundfont.samples[0];
+        assert_eq!((sample.sample_rate, sample.loop_start, sample.loop_end), (44_100.0, 1, 7), "loops relative to the start");
+        assert_eq!(&sample.pcm[..5], &[0.0, 0.5, -0.5, 32767.0 / 32768.0, -1.0]);
+    }
+
+    #[test]
+    fn a_global_zone_supplies_what_a_zone_does_not_set() {
+        let instrument = vec![
+            vec![(ATTACK_VOL_ENV, (-1200i16) as u16), (PAN, 100)], // global: no sampleID
+            vec![(PAN, (-300i16) as u16), (SAMPLE_ID, 0)],
+            vec![(KEY_RANGE, range(60, 127)), (SAMPLE_ID, 0)]
+        ];
+        let sf2 = parse(&font(&[vec![(INSTRUMENT, 0)]], &instrument, &[1, 2, 3], (0, 3))).expect("parses");
+        let zones = &sf2.instruments[0].zones;
+        assert_eq!(zones.len(), 2, "the global zone is folded in, not a zone of its own");
+        assert_eq!((zones[0].value(PAN), zones[1].value(PAN)), (Some(-300), Some(100)));
+        assert_eq!(zones[1].modulators.iter().map(|modulator| modulator.transform).collect::<Vec<_>>(), vec![2, 0],
+            "the global modulator joins, the own one comes first");
+        let regions = &simplify(&sf2).presets[0];
+        assert_eq!(regions.iter().map(|region| region.attack).collect::<Vec<_>>(), vec![0.5, 0.5]);
+        assert_eq!(regions[0].root_key, 48, "the sample's own pitch without an override");
+        assert_eq!(sf2.samples[0].name, "C4");
+    }
+
+    #[test]
+    fn damaged_files_are_rejected() {
+        assert_eq!(parse(b"RIFF\0\0\0\0WAVE"), Err(Sf2Error::NotSoundfont));
+        let mut bytes = font(&[vec![(INSTRUMENT, 0)]], &[vec![(SAMPLE_ID, 0)]], &[0], (0, 1));
+        let phdr = bytes.windows(4).position(|window| window == b"phdr").unwrap();
+        bytes[phdr + 8 + 38 + 24] = 9; // EOP's bag index past the bag table
+        assert_eq!(parse(&bytes), Err(Sf2Error::Malformed(String::from("a bag index is out of range"))));
+        let truncated = font(&[vec![(INSTRUMENT, 0)]], &[vec![(SAMPLE_ID, 0)]], &[0], (0, 1));
+        assert!(matches!(parse(&truncated[..truncated.len() - 100]), Err(Sf2Error::Malformed(_))));
+    }
+}
@@ -0,0 +1,535 @@
+//! SFZ instruments as a one-preset [`SimpleSoundfont`]. The text is read like an SFZ player reads it: `//`
+//! and `/* */` comments, `#define $NAME value` substitution, `#include "file"`, and the header hierarchy
+//! `<control>` → `<global>` → `<master>` → `<group>` → `<region>`, where a region inherits every opcode set
+//! above it and a header clears the levels below it.
+//!
+//! The opcodes that map onto the blob are read; any other opcode (and any other header's content) is ignored,
+//! as players ignore what they do not implement:
+//!
+//! - `sample`, `default_path`: the sample file, decoded by [`SfzFiles::sample`]. A stereo file becomes two
+//!   planes on two regions panned hard left and right, as an SF2 stereo pair does.
+//! - `lokey`, `hikey`, `key`, `pitch_keycenter`, `lovel`, `hivel`, `transpose`, `tune`: keys as numbers or
+//!   note names (`c4` is 60).
+//! - `volume`, `amplitude`, `pan`, `loop_mode`, `loop_start`, `loop_end`, `ampeg_attack`, `ampeg_decay`,
+//!   `ampeg_sustain`, `ampeg_release`. Both loop modes loop continuously; `one_shot` plays like `no_loop`.
+//! - the region rules: `xfin_lovel`, `xfin_hivel`, `xfout_lovel`, `xfout_hivel`, `xf_velcurve` (velocity
+//!   crossfades), `sw_lokey`, `sw_hikey`, `sw_last`, `sw_default` (key-switches), `seq_length`,
+//!   `seq_position` (round-robin, counted per key) and `trigger` (`release` starts on note-off).
+
+use std::collections::HashMap;
+use std::fmt;
+use crate::blob::{RegionRules, SimpleRegion, SimpleSample, SimpleSoundfont};
+use crate::blob::{NO_KEY, TRIGGER_ATTACK, TRIGGER_RELEASE, XF_CURVE_GAIN, XF_CURVE_POWER};
+
+const MAX_INCLUDE_DEPTH: usize = 16;
+
+/// A decoded sample file: one plane per channel, normalized.
+pub struct SamplePcm {
+    pub planes: Vec<Vec<f32>>,
+    pub sample_rate: f32
+}
+
+/// Where an instrument's files come from. Paths are as written in the instrument (with `default_path`
+/// prepended and `\` turned into `/`), relative to the folder of the `.sfz` file.
+pub trait SfzFiles {
+    fn text(&self, path: &str) -> Result<String, String>;
+    fn sample(&self, path: &str) -> Result<SamplePcm, String>;
+}
+
+#[derive(Debug, PartialEq)]
+pub enum SfzError {
+    /// A malformed header, opcode or directive at `line` (1-based) of `file` (empty for the instrument itself).
+    Syntax {file: String, line: usize, reason: String},
+    /// A referenced file cannot be read or decoded.
+    File {path: String, reason: String}
+}
+
+impl fmt::Display for SfzError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SfzError::Syntax {file, line, reason} if file.is_empty() => write!(f, "line {line}: {reason}"),
+            SfzError::Syntax {file, line, reason} => write!(f, "{file}, line {line}: {reason}"),
+            SfzError::File {path, reason} => write!(f, "{path}: {reason}")
+        }
+    }
+}
+
+impl std::error::Error for SfzError {}
+
+/// One `name=value` where it was written, for error reports.
+#[derive(Clone)]
+struct Opcode {
+    file: String,
+    line: usize,
+    name: String,
+    value: String
+}
+
+impl Opcode {
+    fn error(&self, reason: String) -> SfzError {
+        SfzError::Syntax {file: self.file.clone(), line: self.line, reason: format!("{}={}: {reason}", self.name, self.value)}
+    }
+}
+
+enum Token {
+    Header(String),
+    Opcode(Opcode)
+}
+
+/// Replace every `/* */` comment by spaces, keeping its newlines so line numbers stay put.
+fn strip_block_comments(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find("/*") {
+        out.push_str(&rest[..start]);
+        let end = rest[start..].find("*/").map_or(rest.len(), |end| start + end + 2);
+        out.extend(rest[start..end].chars().map(|char| if char == '\n' {'\n'} else {' '}));
+        rest = &rest[end..];
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Whether `line` continues with an opcode name and its `=` at `at` (where a value that may hold spaces ends).
+fn opcode_follows(line: &str, at: usize) -> bool {
+    let name_len = line[at..].find(|char: char| !(char.is_ascii_alphanumeric() || char == '_')).unwrap_or(line.len() - at);
+    name_len > 0 && line[at + name_len..].starts_with('=')
+}
+
+/// Split one comment-free line into headers and opcodes. A value runs up to the next header or the next
+/// `name=`, so sample paths may hold spaces.
+fn tokenize_line(line: &str, file: &str, number: usize, tokens: &mut Vec<Token>) -> Result<(), SfzError> {
+    let syntax = |reason: String| SfzError::Syntax {file: file.to_string(), line: number, reason};
+    let mut at = 0;
+    while at < line.len() {
+        let rest = &line[at..];
+        let trimmed = rest.trim_start();
+        if trimmed.is_empty() {
+            break;
+        }
+        at += rest.len() - trimmed.len();
+        if let Some(header) = trimmed.strip_prefix('<') {
+            let end = header.find('>').ok_or_else(|| syntax(String::from("unterminated header")))?;
+            tokens.push(Token::Header(header[..end].trim().to_string()));
+            at += end + 2;
+            continue;
+        }
+        let equals = trimmed.find('=').filter(|_| opcode_follows(line, at))
+            .ok_or_else(|| syntax(format!("expected an opcode at '{}'", trimmed.split_whitespace().next().unwrap_or(""))))?;
+        let value_start = at + equals + 1;
+        let mut value_end = line.len();
+        let mut cursor = value_start;
+        while let Some(offset) = line[cursor..].find(|char: char| char.is_whitespace() || char == '<') {
+            let position = cursor + offset;
+            if line[position..].starts_with('<') {
+                value_end = position;
+                break;
+            }
+            let next = position + line[position..].len() - line[position..].trim_start().len();
+            if next < line.len() && (line[next..].starts_with('<') || opcode_follows(line, next)) {
+                value_end = position;
+                break;
+            }
+            cursor = next; // past the whole whitespace run, which may hold multi-byte characters
+        }
+        tokens.push(Token::Opcode(Opcode {
+            file: file.to_string(),
+            line: number,
+            name: trimmed[..equals].to_string(),
+            value: line[value_start..value_end].trim().to_string()
+        }));
+        at = value_end;
+    }
+    Ok(())
+}
+
+/// Tokenize `text` (the instrument, or an included `file`), expanding `#define`s and `#include`s in place.
+fn tokenize(text: &str, file: &str, files: &dyn SfzFiles, defines: &mut Vec<(String, String)>, depth: usize,
+            tokens: &mut Vec<Token>) -> Result<(), SfzError> {
+    for (index, raw) in strip_block_comments(text).lines().enumerate() {
+        let number = index + 1;
+        let syntax = |reason: &str| SfzError::Syntax {file: file.to_string(), line: number, reason: reason.to_string()};
+        let line = raw.find("//").map_or(raw, |comment| &raw[..comment]);
+        if let Some(define) = line.trim_start().strip_prefix("#define") {
+            let mut parts = define.split_whitespace();
+            let (Some(name), Some(value)) = (parts.next(), parts.next()) else { return Err(syntax("#define needs a name and a value")) };
+            if !name.starts_with('$') {
+                return Err(syntax("a #define name starts with '$'"));
+            }
+            defines.retain(|(existing, _)| existing != name);
+            defines.push((name.to_string(), value.to_string()));
+            defines.sort_by_key(|(name, _)| usize::MAX - name.len()); // `$NOTE_HI` before `$NOTE`
+            continue;
+        }
+        let mut line = line.to_string();
+        for (name, value) in defines.iter() {
+            line = line.replace(name.as_str(), value);
+        }
+        if let Some(include) = line.trim_start().strip_prefix("#include") {
+            let path = include.trim().trim_matches('"').replace('\\', "/");
+            if depth == MAX_INCLUDE_DEPTH {
+                return Err(syntax("#include nested too deep"));
+            }
+            let included = files.text(&path).map_err(|reason| SfzError::File {path: path.clone(), reason})?;
+            tokenize(&included, &path, files, defines, depth + 1, tokens)?;
+        } else {
+            tokenize_line(&line, file, number, tokens)?;
+        }
+    }
+    Ok(())
+}
+
+/// A key as a MIDI number or a note name (`c4` = 60, `c#4` / `db4` = 61, `c-1` = 0).
+fn parse_key(opcode: &Opcode) -> Result<i32, SfzError> {
+    let value = opcode.value.as_str();
+    if let Ok(key) = value.parse::<i32>() {
+        return Ok(key);
+    }
+    let invalid = || opcode.error(String::from("not a key"));
+    let mut chars = value.chars();
+    let step = match chars.next().map(|char| char.to_ascii_lowercase()) {
+        Some('c') => 0, Some('d') => 2, Some('e') => 4, Some('f') => 5, Some('g') => 7, Some('a') => 9, Some('b') => 11,
+        _ => return Err(invalid())
+    };
+    let rest = chars.as_str();
+    let (accidental, octave) = match rest.chars().next() {
+        Some('#') => (1, &rest[1..]),
+        Some('b') if rest.len() > 1 => (-1, &rest[1..]),
+        _ => (0, rest)
+    };
+    let octave: i32 = octave.parse().map_err(|_| invalid())?;
+    Ok((octave + 1) * 12 + step + accidental)
+}
+
+fn parse_number(opcode: &Opcode) -> Result<f32, SfzError> {
+    opcode.value.parse().map_err(|_| opcode.error(String::from("not a number")))
+}
+
+fn midi_byte(key: i32) -> u8 {
+    key.clamp(0, 127) as u8
+}
+
+/// A region's opcodes resolved onto the blob's settings (the SFZ defaults before any opcode).
+struct Spec {
+    sample: Option<String>,
+    default_path: String,
+    key_lo: i32,
+    key_hi: i32,
+    key_center: i32,
+    vel_lo: i32,
+    vel_hi: i32,
+    transpose: f32,
+    tune: f32,
+    volume: f32,
+    amplitude: f32,
+    pan: f32,
+    looping: bool,
+    loop_start: Option<u32>,
+    loop_end: Option<u32>,
+    attack: f32,
+    decay: f32,
+    sustain: f32,
+    release: f32,
+    sw_range: Option<(i32, i32)>,
+    rules: RegionRules
+}
+
+impl Spec {
+    fn new() -> Self {
+        Self {
+            sample: None, default_path: String::new(), key_lo: 0, key_hi: 127, key_center: 60, vel_lo: 0, vel_hi: 127,
+            transpose: 0.0, tune: 0.0, volume: 0.0, amplitude: 100.0, pan: 0.0, looping: false, loop_start: None,
+            loop_end: None, attack: 0.0, decay: 0.0, sustain: 100.0, release: 0.001, sw_range: None,
+            rules: RegionRules::default()
+        }
+    }
+
+    fn apply(&mut self, opcode: &Opcode) -> Result<(), SfzError> {
+        let key = || parse_key(opcode);
+        let number = || parse_number(opcode);
+        let byte = || number().map(|value| value.clamp(0.0, 127.0) as u8);
+        match opcode.name.as_str() {
+            "sample" => self.sample = Some(opcode.value.replace('\\', "/")),
+            "default_path" => self.default_path = opcode.value.replace('\\', "/"),
+            "lokey" => self.key_lo = key()?,
+            "hikey" => self.key_hi = key()?,
+            "key" => {
+                self.key_lo = key()?;
+                self.key_hi = self.key_lo;
+                self.key_center = self.key_lo;
+            }
+            "pitch_keycenter" => self.key_center = key()?,
+            "lovel" => self.vel_lo = number()? as i32,
+            "hivel" => self.vel_hi = number()? as i32,
+            "transpose" => self.transpose = number()?,
+            "tune" => self.tune = number()?,
+            "volume" => self.volume = number()?,
+            "amplitude" => self.amplitude = number()?,
+            "pan" => self.pan = number()?.clamp(-100.0, 100.0) / 100.0,
+            "loop_mode" | "loopmode" => self.looping = match opcode.value.as_str() {
+                "loop_continuous" | "loop_sustain" => true,
+                "no_loop" | "one_shot" => false,
+                _ => return Err(opcode.error(String::from("unknown loop mode")))
+            },
+            "loop_start" | "loopstart" => self.loop_start = Some(number()?.max(0.0) as u32),
+            "loop_end" | "loopend" => self.loop_end = Some(number()?.max(0.0) as u32),
+            "ampeg_attack" => self.attack = number()?,
+            "ampeg_decay" => self.decay = number()?,
+            "ampeg_sustain" => self.sustain = number()?,
+            "ampeg_release" => self.release = number()?,
+            "xfin_lovel" => self.rules.xfin_lo = byte()?,
+            "xfin_hivel" => self.rules.xfin_hi = byte()?,
+            "xfout_lovel" => self.rules.xfout_lo = byte()?,
+            "xfout_hivel" => self.rules.xfout_hi = byte()?,
+            "xf_velcurve" => self.rules.xf_curve = match opcode.value.as_str() {
+                "power" => XF_CURVE_POWER,
+                "gain" => XF_CURVE_GAIN,
+                _ => return Err(opcode.error(String::from("unknown crossfade curve")))
+            },
+            "sw_lokey" => self.sw_range = Some((key()?, self.sw_range.map_or(127, |(_, hi)| hi))),
+            "sw_hikey" => self.sw_range = Some((self.sw_range.map_or(0, |(lo, _)| lo), key()?)),
+            "sw_last" => self.rules.sw_last = midi_byte(key()?),
+            "sw_default" => self.rules.sw_default = midi_byte(key()?),
+            "seq_length" => self.rules.seq_length = number()?.clamp(1.0, 255.0) as u8,
+            "seq_position" => self.rules.seq_position = number()?.clamp(1.0, 255.0) as u8,
+            "trigger" => self.rules.trigger = match opcode.value.as_str() {
+                "attack" | "first" | "legato" => TRIGGER_ATTACK,
+                "release" | "release_key" => TRIGGER_RELEASE,
+                _ => return Err(opcode.error(String::from("unknown trigger")))
+            },
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// The finished rules: the level and tune folded in, and a key-switch without its own range switching on
+    /// its `sw_last` key alone.
+    fn rules(&self) -> RegionRules {
+        let mut rules = self.rules;
+        rules.gain = 10.0f32.powf(self.volume / 20.0) * self.amplitude / 100.0;
+        rules.tune = self.transpose * 100.0 + self.tune;
+        if rules.sw_last != NO_KEY {
+            let (lo, hi) = self.sw_range.unwrap_or((rules.sw_last as i32, rules.sw_last as i32));
+            [rules.sw_lo, rules.sw_hi] = [midi_byte(lo), midi_byte(hi)];
+        }
+        rules
+    }
+}
+
+/// The header levels whose opcodes a region inherits, outermost first.
+#[derive(Clone, Copy, PartialEq)]
+enum Level {
+    Control,
+    Global,
+    Master,
+    Group,
+    Region,
+    Ignored
+}
+
+/// Flatten the header hierarchy: one opcode list per region, inherited opcodes first so the region's own win.
+fn regions(tokens: Vec<Token>) -> Vec<Vec<Opcode>> {
+    let mut levels: [Vec<Opcode>; 5] = Default::default();
+    let mut current = Level::Ignored;
+    let mut regions = Vec::new();
+    let mut finish = |levels: &mut [Vec<Opcode>; 5], current: Level| {
+        if current == Level::Region {
+            regions.push(levels.concat());
+        }
+    };
+    for token in tokens {
+        match token {
+            Token::Header(name) => {
+                finish(&mut levels, current);
+                current = match name.as_str() {
+                    "control" => Level::Control,
+                    "global" => Level::Global,
+                    "master" => Level::Master,
+                    "group" => Level::Group,
+                    "region" => Level::Region,
+                    _ => Level::Ignored
+                };
+                if current != Level::Ignored {
+                    for level in &mut levels[current as usize..] {
+                        level.clear();
+                    }
+                }
+            }
+            Token::Opcode(opcode) if current != Level::Ignored => levels[current as usize].push(opcode),
+            Token::Opcode(_) => {}
+        }
+    }
+    finish(&mut levels, current);
+    regions
+}
+
+/// Read the instrument `text`, loading its includes and samples through `files`. Regions without a sample
+/// file (or with a generated `*sine`-style one), and those no key can reach, are dropped.
+pub fn build(text: &str, files: &dyn SfzFiles) -> Result<SimpleSoundfont, SfzError> {
+    let mut tokens = Vec::new();
+    tokenize(text, "", files, &mut Vec::new(), 0, &mut tokens)?;
+    let mut samples: Vec<SimpleSample> = Vec::new();
+    let mut sample_index: HashMap<(String, usize, u32, u32), u32> = HashMap::new();
+    let mut decoded: HashMap<String, SamplePcm> = HashMap::new();
+    let mut preset = Vec::new();
+    for opcodes in regions(tokens) {
+        let mut spec = Spec::new();
+        for opcode in &opcodes {
+            spec.apply(opcode)?;
+        }
+        let Some(sample) = spec.sample.as_ref().filter(|sample| !sample.starts_with('*')) else { continue };
+        if spec.key_hi < 0 || spec.key_lo > spec.key_hi || spec.vel_lo > spec.vel_hi {
+            continue;
+        }
+        let path = format!("{}{sample}", spec.default_path);
+        if !decoded.contains_key(&path) {
+            let pcm = files.sample(&path).map_err(|reason| SfzError::File {path: path.clone(), reason})?;
+            decoded.insert(path.clone(), pcm);
+        }
+        let pcm = &decoded[&path];
+        let channels = pcm.planes.len().min(2);
+        for channel in 0..channels {
+            let frames = pcm.planes[channel].len() as u32;
+            // An SFZ loop end is the loop's last frame; the blob's is the frame after it.
+            let (loop_start, loop_end) = match (spec.loop_start, spec.loop_end) {
+                _ if !spec.looping => (0, 0),
+                (start, Some(end)) => (start.unwrap_or(0), (end + 1).min(frames)),
+                (start, None) => (start.unwrap_or(0), frames)
+            };
+            let index = *sample_index.entry((path.clone(), channel, loop_start, loop_end)).or_insert_with(|| {
+                samples.push(SimpleSample {pcm: pcm.planes[channel].clone(), sample_rate: pcm.sample_rate, loop_start, loop_end});
+                samples.len() as u32 - 1
+            });
+            preset.push(SimpleRegion {
+                key_lo: midi_byte(spec.key_lo),
+                key_hi: midi_byte(spec.key_hi),
+                vel_lo: midi_byte(spec.vel_lo),
+                vel_hi: midi_byte(spec.vel_hi),
+                sample_index: index,
+                root_key: midi_byte(spec.key_center) as u32,
+                loop_mode: spec.looping as u32,
+                pan: if channels == 2 {channel as f32 * 2.0 - 1.0} else {spec.pan},
+                attack: spec.attack,
+                decay: spec.decay,
+                sustain: spec.sustain.clamp(0.0, 100.0) / 100.0,
+                release: spec.release,
+                rules: spec.rules()
+            });
+        }
+    }
+    Ok(SimpleSoundfont {samples, presets: vec![preset]})
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// In-memory files: texts by path, and mono (or stereo) samples whose frames count up from `0.0`.
+    #[derive(Default)]
+    struct Files {
+        texts: Vec<(&'static str, &'static str)>,
+        stereo: Vec<&'static str>
+    }
+
+    impl SfzFiles for Files {
+        fn text(&self, path: &str) -> Result<String, String> {
+            self.texts.iter().find(|(name, _)| *name == path).map(|(_, text)| text.to_string()).ok_or_else(|| String::from("not found"))
+        }
+
+        fn sample(&self, path: &str) -> Result<SamplePcm, String> {
+            if !path.ends_with(".wav") {
+                return Err(String::from("not found"));
+            }
+            let plane: Vec<f32> = (0..8).map(|frame| frame as f32).collect();
+            let channels = if self.stereo.contains(&path) {2} else {1};
+            Ok(SamplePcm {planes: vec![plane; channels], sample_rate: 44_100.0})
+        }
+    }
+
+    fn build_text(text: &str) -> SimpleSoundfont {
+        build(text, &Files::default()).expect("builds")
+    }
+
+    #[test]
+    fn regions_inherit_their_headers_and_a_header_clears_the_levels_below() {
+        let soundfont = build_text("
+            <global> ampeg_release=0.5 volume=-6
+            <group> lovel=64 default_path=Samples\\
+            <region> sample=piano c4.wav key=c4
+            <region> sample=piano c4.wav lokey=61 hikey=d#4 pitch_keycenter=62 volume=0
+            <group> trigger=release
+            <region> sample=noise.wav
+        ");
+        let [a, b, c] = &soundfont.presets[0][..] else { panic!("three regions") };
+        assert_eq!((a.key_lo, a.key_hi, a.root_key, a.vel_lo), (60, 60, 60, 64));
+        assert_eq!((b.key_lo, b.key_hi, b.root_key), (61, 63, 62));
+        assert_eq!((c.vel_lo, c.rules.trigger), (0, TRIGGER_RELEASE), "the second group drops the first one's opcodes");
+        assert_eq!([a.release, b.release, c.release], [0.5; 3]);
+        assert!((a.rules.gain - 0.501_187).abs() < 1.0e-5 && b.rules.gain == 1.0, "a region's own opcode wins");
+        assert_eq!(soundfont.samples.len(), 2, "one plane per file, shared by the regions reading it");
+    }
+
+    #[test]
+    fn a_value_may_hold_non_ascii_whitespace() {
+        let soundfont = build_text("<region> sample=my\u{a0}piano.wav key=c4");
+        let [region] = &soundfont.presets[0][..] else { panic!("one region") };
+        assert_eq!((region.key_lo, soundfont.samples.len()), (60, 1));
+    }
+
+    #[test]
+    fn comments_defines_and_includes_expand_in_place() {
+        let files = Files {texts: vec![("inc/keys.sfz", "<region> sample=a.wav key=$KEY // an included region")], ..Files::default()};
+        let soundfont = build("
+            /* a block comment
+               spanning <region> lines */
+            #define $KEY 40
+            #define $KEY_HI 50
+            #include \"inc\\keys.sfz\"
+            <region> sample=b.wav lokey=$KEY hikey=$KEY_HI
+        ", &files).expect("builds");
+        let keys: Vec<(u8, u8)> = soundfont.presets[0].iter().map(|region| (region.key_lo, region.key_hi)).collect();
+        assert_eq!(keys, vec![(40, 40), (40, 50)]);
+    }
+
+    #[test]
+    fn the_region_rules_are_read() {
+        let soundfont = build_text("
+            <group> sw_lokey=24 sw_hikey=26 sw_default=24 seq_length=3
+            <region> sample=a.wav sw_last=25 seq_position=2 xfin_lovel=10 xfin_hivel=20 xf_velcurve=gain tune=-10 transpose=1
+            <region> sample=a.wav sw_last=c1 xfout_lovel=100 xfout_hivel=120
+        ");
+        let [a, b] = &soundfont.presets[0][..] else { panic!("two regions") };
+        assert_eq!((a.rules.sw_lo, a.rules.sw_hi, a.rules.sw_last, a.rules.sw_default), (24, 26, 25, 24));
+        assert_eq!((a.rules.seq_length, a.rules.seq_position, b.rules.seq_position), (3, 2, 1));
+        assert_eq!((a.rules.xfin_lo, a.rules.xfin_hi, a.rules.xf_curve, a.rules.tune), (10, 20, XF_CURVE_GAIN, 90.0));
+        assert_eq!((b.rules.sw_last, b.rules.xfout_lo, b.rules.xfout_hi, b.rules.xf_curve), (24, 100, 120, XF_CURVE_POWER));
+    }
+
+    #[test]
+    fn a_plain_instrument_keeps_the_neutral_rules() {
+        let soundfont = build_text("<region> sample=a.wav loop_mode=loop_continuous loop_start=2 loop_end=5 ampeg_sustain=50");
+        let region = &soundfont.presets[0][0];
+        assert_eq!(region.rules, RegionRules::default());
+        assert_eq!((region.loop_mode, region.sustain), (1, 0.5));
+        let sample = &soundfont.samples[0];
+        assert_eq!((sample.loop_start, sample.loop_end), (2, 6), "the loop end frame is included");
+    }
+
+    #[test]
+    fn a_stereo_file_plays_as_a_hard_panned_pair() {
+        let files = Files {stereo: vec!["pad.wav"], ..Files::default()};
+        let soundfont = build("<region> sample=pad.wav pan=30", &files).expect("builds");
+        let pans: Vec<(u32, f32)> = soundfont.presets[0].iter().map(|region| (region.sample_index, region.pan)).collect();
+        assert_eq!(pans, vec![(0, -1.0), (1, 1.0)]);
+    }
+
+    #[test]
+    fn unplayable_regions_are_dropped_and_errors_name_their_line() {
+        let soundfont = build_text("<region> sample=*sine <region> key=60 <region> sample=a.wav hikey=-1 <region> sample=a.wav");
+        assert_eq!(soundfont.presets[0].len(), 1);
+        let error = build("<region> sample=a.wav\n<region> lokey=h2", &Files::default()).unwrap_err();
+        assert_eq!(error, SfzError::Syntax {file: String::new(), line: 2, reason: String::from("lokey=h2: not a key")});
+        let missing = build("<region> sample=a.flac", &Files::default()).unwrap_err();
+        assert_eq!(missing, SfzError::File {path: String::from("a.flac"), reason: String::from("not found")});
+    }
+}
@@ -0,0 +1,38 @@
+//! Parity against `test-files/soundfont/`: `parity.sf2` is a three-preset font (layered and split zones,
+//! velocity layers, an empty key intersection, looping and one-shot samples, an unused sample, modulators, an
+//! overriding root key of `-1`), `parity.osf2` the blob the TS `simplifySoundfont` builds from it (pinned on
+//! the TS side by `packages/app/wasm/test/soundfont-parity.test.ts`). The native builder must match it byte
+//! for byte.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use soundfont_blob::{encode, sf2};
+
+fn fixture(name: &str) -> Vec<u8> {
+    let path: PathBuf = Path::new(env!("CARGO_MANIFEST_DIR")).join("../../test-files/soundfont").join(name);
+    fs::read(&path).unwrap_or_else(|error| panic!("read {path:?}: {error}"))
+}
+
+#[test]
+fn the_native_blob_matches_the_ts_builder() {
+    let soundfont = sf2::build(&fixture("parity.sf2")).expect("parses");
+    let expected = fixture("parity.osf2");
+    let blob = encode(&soundfont);
+    assert_eq!(blob.len(), expected.len());
+    if let Some(offset) = blob.iter().zip(&expected).position(|(a, b)| a != b) {
+        panic!("the blobs differ first at byte {offset}");
+    }
+}
+
+#[test]
+fn the_font_reads_as_written() {
+    let font = sf2::parse(&fixture("parity.sf2")).expect("parses");
+    let presets: Vec<(&str, u16, usize)> = font.presets.iter().map(|preset| (preset.name.as_str(), preset.preset, preset.zones.len())).collect();
+    assert_eq!(presets, vec![("Keys", 0, 1), ("Split", 1, 3), ("Pad", 2, 1)]);
+    assert_eq!(font.instruments.iter().map(|instrument| instrument.zones.len()).collect::<Vec<_>>(), vec![3, 2]);
+    assert_eq!(font.samples.iter().map(|sample| sample.pcm.len()).collect::<Vec<_>>(), vec![32, 20, 3]);
+    assert!(font.presets.iter().flat_map(|preset| &preset.zones).all(|zone| zone.modulators.len() == 1));
+    let soundfont = sf2::simplify(&font);
+    assert_eq!(soundfont.samples.len(), 2, "the unused sample stays out of the blob");
+    assert_eq!(soundfont.presets.iter().map(Vec::len).collect::<Vec<_>>(), vec![3, 6, 2]);
+}
@@ -30,28 +30,28 @@ pub struct ApparatState {
     sample_rate: f32
 }
 
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn kind() -> u32 {
     DEVICE_KIND_INSTRUMENT
 }
 
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn state_size(_sample_rate: f32) -> u32 {
     core::mem::size_of::<ApparatState>() as u32
 }
 
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn observe_param_collection_field() -> u32 {
     11
 }
 
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn observe_sample_collection_field() -> u32 {
     12
 }
 
-#[no_mangle]
-pub extern "C" fn init(state_ptr: u32, sample_rate: f32) {
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn init(state_ptr: usize, sample_rate: f32) {
     unsafe {
         abi::with_state::<ApparatState>(state_ptr, |state| {
             state.sample_rate = sample_rate;
@@ -66,8 +66,8 @@ fn forward_param(state: &mut ApparatState, id: u32, value: ParamValue) {
     abi::script_param(state.handle, id, value);
 }
 
-#[no_mangle]
-pub extern "C" fn parameter_changed(state_ptr: u32, id: u32, kind: u32, value: f32) {
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn parameter_changed(state_ptr: usize, id: u32, kind: u32, value: f32) {
     unsafe {
         abi::with_state::<ApparatState>(state_ptr, |state| {
             forward_param(state, id, ParamValue::from_wire(kind, value));
@@ -76,8 +76,8 @@ pub extern "C" fn parameter_changed(state_ptr: u32, id: u32, kind: u32, value: f
 }
 
 /// A `@sample` slot's resolved handle (or `present == 0` to clear), keyed by the child's declaration `id`.
-#[no_mangle]
-pub extern "C" fn sample_changed(state_ptr: u32, id: u32, handle: u32, present: u32) {
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn sample_changed(state_ptr: usize, id: u32, handle: u32, present: u32) {
     unsafe {
         abi::with_state::<ApparatState>(state_ptr, |state| {
             abi::script_sample(state.handle, id, handle, present != 0);
@@ -86,26 +86,29 @@ pub extern "C" fn sample_changed(state_ptr: u32, id: u32, handle: u32, present:
 }
 
 /// Transport STOP: tell the user `Processor` to reset (drop voices).
-#[no_mangle]
-pub extern "C" fn reset(state_ptr: u32) {
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn reset(state_ptr: usize) {
     unsafe { abi::with_state::<ApparatState>(state_ptr, |state| abi::script_reset(state.handle)) }
 }
 
 /// This device's INSTANCE is dying (a genuine removal, never a chain-edit survivor): release the JS-side
 /// script bridge (its Processor + limiter + runtime), so removing/rebinding an Apparat device no longer
 /// orphans one.
-#[no_mangle]
-pub extern "C" fn terminate(state_ptr: u32) {
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn terminate(state_ptr: usize) {
     unsafe { abi::with_state::<ApparatState>(state_ptr, |state| abi::script_release(state.handle)) }
 }
 
-/// Sort key at an equal offset: releases (note-off / choke) before a parameter refresh before note-ons, so a
-/// note starting at an update position sees the refreshed parameter and a choke precedes a re-trigger there
-/// (mirrors `render_instrument`'s `record_rank`).
+/// Sort key at an equal offset: releases (note-off / choke) before a parameter refresh or channel event before
+/// note-ons, so a note starting at an update position sees the refreshed parameter and a choke precedes a
+/// re-trigger there, and a note expression follows the note-on it modulates (mirrors `render_instrument`'s
+/// `record_rank`).
 fn rank(kind: u32) -> u8 {
     match kind {
         EVENT_NOTE_OFF | EVENT_CHOKE => 0,
         EVENT_PARAM => 1,
+        kind if abi::is_channel_event(kind) => 1,
+        abi::EVENT_NOTE_EXPRESSION => 3,
         _ => 2
     }
 }
@@ -114,13 +117,13 @@ fn rank(kind: u32) -> u8 {
 /// (Route D), offset-sort the combined stream, then walk it — rendering the user `process` over the audio chunk
 /// up to each event, delivering note-on/off at note events and refreshing automated `@param`s at the markers.
 /// This splits the block at note offsets AND parameter epochs, mirroring `render_instrument`/`dispatch_range`.
-#[no_mangle]
-pub extern "C" fn process(desc_ptr: u32) {
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn process(desc_ptr: usize) {
     let ports = unsafe { Ports::<ApparatState>::from_descriptor(desc_ptr) };
     let Ports {output, state, blocks, event_scratch, ..} = ports;
     let [out_left, out_right] = output;
-    let out_l = out_left.as_ptr() as u32;
-    let out_r = out_right.as_ptr() as u32;
+    let out_l = out_left.as_ptr() as usize;
+    let out_r = out_right.as_ptr() as usize;
     let handle = state.handle;
     for block in blocks {
         let mut count = abi::pull_events(block.p0, block.p1, block.flags.0, event_scratch);
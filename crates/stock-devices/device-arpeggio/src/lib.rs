@@ -6,22 +6,36 @@
 //! note-on, scheduling the matching note-off `duration` pulses later. So a few held notes become a long
 //! STREAM, and the active-note set + scheduled note-offs PERSIST across blocks that carry no new input.
 //!
-//! Parameters (`ArpeggioDeviceBox`): modeIndex `[10]` (0=Up/1=Down/2=UpDown), numOctaves `[11]` (1..5),
+//! Parameters (`ArpeggioDeviceBox`): modeIndex `[10]` (see `MODE_*`), numOctaves `[11]` (1..5),
 //! rateIndex `[12]` (into `RATE_FRACTIONS`), gate `[13]` (0..2, step length as a fraction of the rate),
 //! repeat `[14]` (1..16, how many grid steps per arp step), velocity `[15]` (bipolar, a magnet toward 1.0).
 //! Parameter automation is honored: the block is split at update boundaries (as `render_midi_effect` does).
 //!
+//! Up / Down / UpDown are the TS modes and walk the stack in (start, pitch) order. The added modes go beyond the
+//! TS device: AsPlayed walks it in arrival order, Random picks a note per step from a `Mulberry32` reseeded by
+//! the step index (so a render, a replay and a seek all pick the same notes), Converge / Diverge walk the
+//! pitch-sorted octave range from the outside in / the inside out, and Chord repeats the whole stack each step.
+//!
+//! A step PATTERN (`pattern-length` `[16]`, 0 = off, over the first steps of the `pattern` array `[17]`) shapes
+//! the rhythm on the rate grid: each step scales the velocity and the gate, a REST emits nothing, and a TIE
+//! holds the note through the next step instead of striking it again. The pattern fields are plain field
+//! observations (delivered through `field_changed`), not automatable parameters.
+//!
 //! Timing is musical: `rate` is `Fraction.toPPQN(RATE_FRACTIONS[rateIndex])` in pulses, and steps land on the
 //! absolute grid `index * rate` (mirroring `Fragmentor.iterateWithIndex`). On a transport jump (DISCONTINUOUS)
-//! it releases everything it holds, mirroring the TS `releaseAll`.
+//! it releases everything it holds, mirroring the TS `releaseAll`. Channel events (controllers, the pitch wheel,
+//! pressure) are not arpeggiated: they pass through at their position so the instrument still sees them. Note
+//! expressions are dropped, since the arp's own notes carry new ids the source note's expression can't address.
 //!
-//! Exports: `kind()` (midi effect), `state_size()`, `init(...)`, `parameter_changed(...)`, `process_events(...)`.
+//! Exports: `kind()` (midi effect), `state_size()`, `init(...)`, `parameter_changed(...)`, `field_changed(...)`,
+//! `process_events(...)`.
 
 #![cfg_attr(target_family = "wasm", no_std)]
 
 #[cfg(target_family = "wasm")]
 use core::panic::PanicInfo;
-use abi::{EventRecord, ParamValue, EVENT_NOTE_OFF, EVENT_NOTE_ON};
+use abi::{EventRecord, FieldValue, ParamValue, EVENT_NOTE_OFF, EVENT_NOTE_ON};
+use math::random::Mulberry32;
 use math::value_mapping::{Linear, LinearInteger};
 
 #[cfg(target_family = "wasm")]
@@ -52,8 +66,20 @@ const RATE_FIELD: [u16; 1] = [12];
 const GATE_FIELD: [u16; 1] = [13];
 const REPEAT_FIELD: [u16; 1] = [14];
 const VELOCITY_FIELD: [u16; 1] = [15];
-
-const MODE_MAPPING: LinearInteger = LinearInteger {min: 0, max: 2};
+const PATTERN_LENGTH_FIELD: [u16; 1] = [16];
+const PATTERN_KEY: u16 = 17; // the step array: velocity 1, gate 2, tie 3, rest 4
+
+// WASM CONTRACT: the mode-index values.
+pub const MODE_UP: i32 = 0;
+pub const MODE_DOWN: i32 = 1;
+pub const MODE_UP_DOWN: i32 = 2;
+pub const MODE_AS_PLAYED: i32 = 3;
+pub const MODE_RANDOM: i32 = 4;
+pub const MODE_CONVERGE: i32 = 5;
+pub const MODE_DIVERGE: i32 = 6;
+pub const MODE_CHORD: i32 = 7;
+
+const MODE_MAPPING: LinearInteger = LinearInteger {min: 0, max: MODE_CHORD};
 const OCTAVES_MAPPING: LinearInteger = LinearInteger {min: 1, max: 5};
 const RATE_MAPPING: LinearInteger = LinearInteger {min: 0, max: (RATE_FRACTIONS.len() - 1) as i32};
 const GATE_MAPPING: Linear = Linear {min: 0.0, max: 2.0};
@@ -64,13 +90,31 @@ const MAX_SOURCE: usize = 32; // simultaneously active source notes (chord + ove
 const MAX_RETAINED: usize = 64; // emitted notes awaiting their scheduled note-off
 const EMIT_MAX: usize = 128; // events emitted in one block (note-offs + note-ons)
 const PULL_SCRATCH: usize = 256; // on-stack buffer the upstream pull writes into
+pub const PATTERN_STEPS: usize = 16;
 
 #[derive(Clone, Copy)]
 struct SourceNote {
     start: f64,
     end: f64,
     pitch: u32,
-    velocity: f32
+    velocity: f32,
+    order: u32 // arrival order, for AsPlayed
+}
+
+/// One step of the rhythm pattern: `velocity` and `gate` scale the arp's own, a `rest` step stays silent and
+/// a `tie` step holds its note through the following step.
+#[derive(Clone, Copy)]
+pub struct PatternStep {
+    pub velocity: f32,
+    pub gate: f32,
+    pub tie: bool,
+    pub rest: bool
+}
+
+impl Default for PatternStep {
+    fn default() -> Self {
+        Self {velocity: 1.0, gate: 1.0, tie: false, rest: false}
+    }
 }
 
 #[derive(Clone, Copy)]
@@ -90,6 +134,9 @@ pub struct ArpState {
     source_count: u32,
     retained_count: u32,
     next_id: u32,
+    next_order: u32,
+    pattern: [PatternStep; PATTERN_STEPS],
+    pattern_length: usize,
     mode: i32,
     octaves: i32,
     rate: f64,
@@ -102,7 +149,9 @@ pub struct ArpState {
     rate_id: u32,
     gate_id: u32,
     repeat_id: u32,
-    velocity_id: u32
+    velocity_id: u32,
+    pattern_length_id: u32,
+    pattern_ids: [[u32; 4]; PATTERN_STEPS]
 }
 
 /// Set the velocity magnet (mirrors the TS `parameterChanged` velocity branch): at `v <= 0` fade the note's
@@ -122,37 +171,82 @@ fn apply_velocity(state: &ArpState, velocity: f32) -> f32 {
 }
 
 /// One arp step: pick a pitch + octave from the sorted active-note `stack` for the global `step_index`, per the
-/// mode. Mirrors `ArpeggioModes` (up / down / up-down) exactly. `stack` is (pitch, velocity), pre-sorted by
-/// (start, pitch). Returns (pitch, velocity).
+/// mode. Up / down / up-down mirror `ArpeggioModes` exactly. `stack` is (pitch, velocity), pre-sorted per
+/// [`sort_key`]. Returns (pitch, velocity). Chord mode plays the whole stack and never gets here.
 fn mode_run(state: &ArpState, stack: &[(u32, f32)], step_index: i64) -> (u32, f32) {
     let count = stack.len() as i64;
     let octaves = if state.octaves < 1 { 1 } else { state.octaves as i64 };
+    let amount = count * octaves;
+    // the position within the octave-expanded run (octave-major), as the outside-in walk visits it
+    let converge = |index: i64| if index % 2 == 0 { index / 2 } else { amount - 1 - index / 2 };
     let (local_index, octave) = match state.mode {
-        1 => {
-            // down
-            let amount = count * octaves;
+        MODE_AS_PLAYED => (step_index % count, (step_index % amount) / count),
+        MODE_RANDOM => {
+            let mut random = Mulberry32::default();
+            random.set_seed(step_index as u32);
+            let pick = ((random.uniform_f64() * amount as f64) as i64).min(amount - 1);
+            (pick % count, pick / count)
+        }
+        MODE_CONVERGE => {
+            let pick = converge(step_index % amount);
+            (pick % count, pick / count)
+        }
+        MODE_DIVERGE => {
+            let pick = converge(amount - 1 - step_index % amount);
+            (pick % count, pick / count)
+        }
+        MODE_DOWN => {
             let local = (count - 1) - step_index % count;
             let octave = (octaves - 1) - (step_index % amount) / count;
             (local, octave)
         }
-        2 => {
-            // up-down
+        MODE_UP_DOWN => {
             let process_length = count * octaves;
             let sequence_length = if process_length * 2 - 2 < 1 { 1 } else { process_length * 2 - 2 };
             let sequence_index = step_index % sequence_length;
             let process_index = if sequence_index < process_length { sequence_index } else { sequence_length - sequence_index };
             (process_index % count, process_index / count)
         }
-        _ => {
-            // up (default)
-            let amount = count * octaves;
-            (step_index % count, (step_index % amount) / count)
-        }
+        _ => (step_index % count, (step_index % amount) / count) // up (the default)
     };
     let (pitch, velocity) = stack[local_index as usize];
     ((pitch as i64 + octave * 12) as u32, apply_velocity(state, velocity))
 }
 
+/// How the stack is ordered for a mode: the TS modes keep the sequencer's (start, pitch) order, AsPlayed the
+/// (start, arrival) order, and the pitch-walking modes plain pitch order.
+fn sort_key(mode: i32, note: &SourceNote) -> (f64, f64) {
+    match mode {
+        MODE_UP | MODE_DOWN | MODE_UP_DOWN => (note.start, note.pitch as f64),
+        MODE_AS_PLAYED => (note.start, note.order as f64),
+        _ => (note.pitch as f64, note.order as f64)
+    }
+}
+
+/// The pattern step at grid `index` and the length of the note it strikes: the gated step, or for a tied
+/// step a full rate plus the following step's length (ties chain, up to the pattern length). `None` for a
+/// silent grid step: a rest, or a step the tie before it already holds. Without a pattern every step strikes
+/// at the plain gate.
+fn pattern_step(state: &ArpState, index: i64, gate: f64) -> Option<(PatternStep, f64)> {
+    if state.pattern_length == 0 {
+        return Some((PatternStep::default(), state.rate * gate));
+    }
+    let length = state.pattern_length as i64;
+    let step_at = |offset: i64| state.pattern[(index + offset).rem_euclid(length) as usize];
+    let step = step_at(0);
+    let held = |previous: PatternStep| previous.tie && !previous.rest;
+    if step.rest || (index > 0 && held(step_at(-1))) {
+        return None;
+    }
+    let mut duration = 0.0;
+    let mut offset = 0;
+    while held(step_at(offset)) && !step_at(offset + 1).rest && offset < length - 1 {
+        duration += state.rate;
+        offset += 1;
+    }
+    Some((step, duration + state.rate * gate * step_at(offset).gate.max(0.0) as f64))
+}
+
 /// Drop active source notes whose span has ended at/before `from` (they can no longer overlap any grid step in
 /// this or a later block).
 fn prune_source(state: &mut ArpState, from: f64) {
@@ -174,9 +268,11 @@ fn ingest(state: &mut ArpState, input: &[EventRecord]) {
         if record.kind == EVENT_NOTE_ON {
             if (state.source_count as usize) < MAX_SOURCE && record.duration > 0.0 {
                 state.source[state.source_count as usize] = SourceNote {
-                    start: record.position, end: record.position + record.duration, pitch: record.pitch, velocity: record.velocity
+                    start: record.position, end: record.position + record.duration, pitch: record.pitch, velocity: record.velocity,
+                    order: state.next_order
                 };
                 state.source_count += 1;
+                state.next_order = state.next_order.wrapping_add(1);
             }
         } else if record.kind == EVENT_NOTE_OFF {
             let mut index = 0;
@@ -234,7 +330,8 @@ fn release_completed(state: &mut ArpState, to: f64, events: &mut [EventRecord],
 
 /// Produce one block's events for `[from, to)`. Sequence mirrors `ArpeggioDeviceProcessor.processNotes`: release
 /// due note-offs (all of them on a DISCONTINUOUS jump), ingest the upstream, walk the rate grid emitting a
-/// note-on per step through the active-note stack, then release the note-offs that come due within the block.
+/// note-on per step through the active-note stack (every stacked note in Chord mode; shaped by the pattern),
+/// then release the note-offs that come due within the block.
 /// Returns the count of position-sorted events written (note-ON before note-off at an equal position,
 /// mirroring the TS yield order — see `lifecycle_rank`).
 pub fn process(state: &mut ArpState, from: f64, to: f64, flags: u32, input: &[EventRecord], out: &mut [EventRecord]) -> usize {
@@ -257,30 +354,33 @@ pub fn process(state: &mut ArpState, from: f64, to: f64, flags: u32, input: &[Ev
     }
     prune_source(state, from);
     ingest(state, input);
+    for record in input.iter().filter(|record| abi::is_channel_event(record.kind)) {
+        emit(&mut events, &mut count, *record);
+    }
     // onlyExternal (= !transporting) yields no sequenced notes in the TS source, so the arp emits nothing while
     // the transport is not moving.
     if transporting && state.rate > 0.0 && state.source_count > 0 {
         let repeat = if state.repeat < 1 { 1 } else { state.repeat as i64 };
-        let step_len = state.rate * state.gate.max(0.0) as f64;
-        let duration = if step_len < 1.0 { 1.0 } else { (step_len as i64) as f64 };
+        let gate = state.gate.max(0.0) as f64;
         let mut index = first_index(from, state.rate);
         let mut position = index as f64 * state.rate;
         while position < to {
             let mut stack = [(0u32, 0.0f32); MAX_SOURCE];
-            let mut stack_key = [(0.0f64, 0u32); MAX_SOURCE]; // (start, pitch) sort key
+            let mut stack_key = [(0.0f64, 0.0f64); MAX_SOURCE];
             let mut stack_len = 0;
             let mut source_index = 0;
             while source_index < state.source_count as usize {
                 let note = state.source[source_index];
                 if note.start <= position && position < note.end {
                     stack[stack_len] = (note.pitch, note.velocity);
-                    stack_key[stack_len] = (note.start, note.pitch);
+                    stack_key[stack_len] = sort_key(state.mode, &note);
                     stack_len += 1;
                 }
                 source_index += 1;
             }
-            if stack_len > 0 {
-                // Insertion-sort by (start position, pitch) to match the TS sequencer's `NoteEvent.Comparator`.
+            let step = if stack_len > 0 { pattern_step(state, index, gate) } else { None };
+            if let Some((pattern, step_len)) = step {
+                // Insertion-sort by the mode's key (for the TS modes the sequencer's `NoteEvent.Comparator`).
                 let mut outer = 1;
                 while outer < stack_len {
                     let value = stack[outer];
@@ -297,15 +397,29 @@ pub fn process(state: &mut ArpState, from: f64, to: f64, flags: u32, input: &[Ev
                     outer += 1;
                 }
                 let step_index = index / repeat;
-                let (pitch, velocity) = mode_run(state, &stack[..stack_len], step_index);
-                if (state.retained_count as usize) < MAX_RETAINED {
-                    let id = state.next_id;
-                    state.next_id = state.next_id.wrapping_add(1);
-                    emit(&mut events, &mut count, EventRecord {
-                        position, offset: 0, kind: EVENT_NOTE_ON, id, pitch, velocity, cent: 0.0, duration
-                    });
-                    state.retained[state.retained_count as usize] = Retained {id, pitch, complete: position + duration};
-                    state.retained_count += 1;
+                let duration = if step_len < 1.0 { 1.0 } else { (step_len as i64) as f64 };
+                let mut strike = |state: &mut ArpState, pitch: u32, velocity: f32| {
+                    if (state.retained_count as usize) < MAX_RETAINED {
+                        let id = state.next_id;
+                        state.next_id = state.next_id.wrapping_add(1);
+                        let velocity = velocity * pattern.velocity;
+                        emit(&mut events, &mut count, EventRecord {
+                            position, offset: 0, kind: EVENT_NOTE_ON, id, pitch, velocity, cent: 0.0, duration
+                        });
+                        state.retained[state.retained_count as usize] = Retained {id, pitch, complete: position + duration};
+                        state.retained_count += 1;
+                    }
+                };
+                if state.mode == MODE_CHORD {
+                    let octaves = if state.octaves < 1 { 1 } else { state.octaves as i64 };
+                    let octave = (step_index % octaves) as u32;
+                    for &(pitch, velocity) in &stack[..stack_len] {
+                        let velocity = apply_velocity(state, velocity);
+                        strike(state, pitch + octave * 12, velocity);
+                    }
+                } else {
+                    let (pitch, velocity) = mode_run(state, &stack[..stack_len], step_index);
+                    strike(state, pitch, velocity);
                 }
             }
             index += 1;
@@ -322,20 +436,20 @@ pub fn process(state: &mut ArpState, from: f64, to: f64, flags: u32, input: &[Ev
 }
 
 /// What the host wires this device as (read at load): a MIDI effect (a pull source in the event chain).
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn kind() -> u32 {
     abi::DEVICE_KIND_MIDI_EFFECT
 }
 
 /// Bytes the engine must allocate (zeroed) for one instance's state block.
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn state_size(_sample_rate: f32) -> u32 {
     core::mem::size_of::<ArpState>() as u32
 }
 
 /// Seed a (zeroed) state with the box parameter defaults and bind each parameter. Kept separate from the
-/// `init` export so tests can seed a state directly (the export takes a `u32` pointer, which truncates on a
-/// 64-bit native test build). Mirrors the `ArpeggioDeviceBox` field defaults (1/16, up, 1 octave, gate 1).
+/// `init` export so tests can seed a state directly, without the raw state pointer the extern takes.
+/// Mirrors the `ArpeggioDeviceBox` field defaults (1/16, up, 1 octave, gate 1).
 pub fn seed(state: &mut ArpState) {
     state.mode = 0;
     state.octaves = 1;
@@ -343,16 +457,24 @@ pub fn seed(state: &mut ArpState) {
     state.gate = 1.0;
     state.repeat = 1;
     set_velocity_matrix(state, 0.0);
+    state.pattern = [PatternStep::default(); PATTERN_STEPS];
+    state.pattern_length = 0;
     state.mode_id = abi::bind_parameter(&MODE_FIELD);
     state.octaves_id = abi::bind_parameter(&OCTAVES_FIELD);
     state.rate_id = abi::bind_parameter(&RATE_FIELD);
     state.gate_id = abi::bind_parameter(&GATE_FIELD);
     state.repeat_id = abi::bind_parameter(&REPEAT_FIELD);
     state.velocity_id = abi::bind_parameter(&VELOCITY_FIELD);
+    state.pattern_length_id = abi::observe_field(&PATTERN_LENGTH_FIELD);
+    for (index, ids) in state.pattern_ids.iter_mut().enumerate() {
+        for (key, id) in ids.iter_mut().enumerate() {
+            *id = abi::observe_field(&[PATTERN_KEY, index as u16, key as u16 + 1]);
+        }
+    }
 }
 
-#[no_mangle]
-pub extern "C" fn init(state_ptr: u32, _sample_rate: f32) {
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn init(state_ptr: usize, _sample_rate: f32) {
     seed(unsafe { &mut *(state_ptr as *mut ArpState) });
 }
 
@@ -372,14 +494,39 @@ fn apply_parameter(state: &mut ArpState, id: u32, value: ParamValue) {
     }
 }
 
-#[no_mangle]
-pub extern "C" fn parameter_changed(state_ptr: u32, id: u32, kind: u32, value: f32) {
+/// Apply a delivered pattern field: the length (clamped to the step array) or one step's velocity / gate /
+/// tie / rest.
+pub fn apply_field(state: &mut ArpState, id: u32, value: FieldValue) {
+    if id == state.pattern_length_id {
+        let FieldValue::Int(length) = value else { panic!("pattern-length must be an int") };
+        state.pattern_length = (length.max(0) as usize).min(PATTERN_STEPS);
+        return;
+    }
+    let Some(index) = state.pattern_ids.iter().position(|ids| ids.contains(&id)) else { return };
+    let step = &mut state.pattern[index];
+    match (state.pattern_ids[index].iter().position(|&step_id| step_id == id), value) {
+        (Some(0), FieldValue::Float(velocity)) => step.velocity = velocity.clamp(0.0, 1.0),
+        (Some(1), FieldValue::Float(gate)) => step.gate = gate.clamp(0.0, 2.0),
+        (Some(2), FieldValue::Bool(tie)) => step.tie = tie,
+        (Some(3), FieldValue::Bool(rest)) => step.rest = rest,
+        _ => panic!("unexpected pattern step field")
+    }
+}
+
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn field_changed(state_ptr: usize, id: u32, kind: u32, bits: usize, len: u32) {
+    let state = unsafe { &mut *(state_ptr as *mut ArpState) };
+    apply_field(state, id, unsafe { FieldValue::from_wire(kind, bits, len) });
+}
+
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn parameter_changed(state_ptr: usize, id: u32, kind: u32, value: f32) {
     let state = unsafe { &mut *(state_ptr as *mut ArpState) };
     apply_parameter(state, id, ParamValue::from_wire(kind, value));
 }
 
 /// Parity probe: the REAL value stored for a UNIT automation value, ids in `init` bind order.
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn map_parameter(id: u32, unit: f32) -> f32 {
     let value = ParamValue::Unit(unit);
     match id {
@@ -393,8 +540,8 @@ pub extern "C" fn map_parameter(id: u32, unit: f32) -> f32 {
     }
 }
 
-#[no_mangle]
-pub extern "C" fn process_events(from: f64, to: f64, flags: u32, state_ptr: u32, out_ptr: u32, max: u32) -> u32 {
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn process_events(from: f64, to: f64, flags: u32, state_ptr: usize, out_ptr: usize, max: u32) -> u32 {
     let state = unsafe { &mut *(state_ptr as *mut ArpState) };
     let blank = EventRecord {position: 0.0, offset: 0, kind: 0, id: 0, pitch: 0, velocity: 0.0, cent: 0.0, duration: 0.0};
     let out = unsafe { core::slice::from_raw_parts_mut(out_ptr as *mut EventRecord, max as usize) };
@@ -429,6 +576,7 @@ mod tests {
         state.rate = rate_ppqn(9); // 1/16 = 240
         state.gate = 1.0;
         state.repeat = 1;
+        state.pattern = [PatternStep::default(); PATTERN_STEPS];
         set_velocity_matrix(&mut state, 0.0);
         state
     }
@@ -441,6 +589,16 @@ mod tests {
         out.iter().filter(|event| event.kind == EVENT_NOTE_ON).count()
     }
 
+    #[test]
+    fn channel_events_pass_through_unarpeggiated() {
+        let mut state = state();
+        let bend = EventRecord {kind: abi::EVENT_PITCH_BEND, id: 0, pitch: 0, velocity: 0.5, ..note_on(100.0, 0.0, 0, 0.0)};
+        let mut out = [note_on(0.0, 0.0, 0, 0.0); 8];
+        let written = process(&mut state, 0.0, 240.0, abi::BlockFlags::TRANSPORTING, &[bend], &mut out);
+        assert_eq!(written, 1, "no held note, so the bend is all that comes out");
+        assert_eq!((out[0].kind, out[0].position, out[0].velocity), (abi::EVENT_PITCH_BEND, 100.0, 0.5));
+    }
+
     #[test]
     fn rate_index_maps_to_ppqn() {
         assert_eq!(rate_ppqn(0), 3840.0); // 1/1
@@ -543,6 +701,118 @@ mod tests {
         assert!(out[..written].iter().all(|event| event.position == 1000.0), "released at `from`");
     }
 
+    // The note-ons of one bar of 1/16 steps over `input` (held from 0).
+    fn ons(state: &mut ArpState, input: &[EventRecord]) -> Vec<(f64, u32, f32, f64)> {
+        let mut out = [note_on(0.0, 0.0, 0, 0.0); 128];
+        let written = process(state, 0.0, 3840.0, abi::BlockFlags::TRANSPORTING, input, &mut out);
+        out[..written].iter().filter(|event| event.kind == EVENT_NOTE_ON)
+            .map(|event| (event.position, event.pitch, event.velocity, event.duration)).collect()
+    }
+
+    fn chord(pitches: &[u32]) -> Vec<EventRecord> {
+        pitches.iter().map(|&pitch| note_on(0.0, 8000.0, pitch, 0.8)).collect()
+    }
+
+    fn pitches(state: &mut ArpState, input: &[EventRecord], count: usize) -> Vec<u32> {
+        ons(state, input).iter().take(count).map(|&(_, pitch, _, _)| pitch).collect()
+    }
+
+    #[test]
+    fn as_played_follows_the_arrival_order() {
+        let mut state = state();
+        state.mode = MODE_AS_PLAYED;
+        assert_eq!(pitches(&mut state, &chord(&[67, 60, 64]), 6), [67, 60, 64, 67, 60, 64]);
+    }
+
+    #[test]
+    fn converge_walks_in_and_diverge_walks_out() {
+        let mut state = state();
+        state.mode = MODE_CONVERGE;
+        assert_eq!(pitches(&mut state, &chord(&[64, 60, 72, 67]), 4), [60, 72, 64, 67]);
+        let mut state = self::state();
+        state.mode = MODE_DIVERGE;
+        assert_eq!(pitches(&mut state, &chord(&[64, 60, 72, 67]), 4), [67, 64, 72, 60]);
+        let mut state = self::state();
+        state.mode = MODE_CONVERGE;
+        state.octaves = 2;
+        assert_eq!(pitches(&mut state, &chord(&[60, 64]), 4), [60, 76, 64, 72], "the octaves join the walk");
+    }
+
+    #[test]
+    fn random_is_seeded_by_the_step_so_renders_repeat() {
+        let run = |split: bool| {
+            let mut state = state();
+            state.mode = MODE_RANDOM;
+            state.octaves = 2;
+            let input = chord(&[60, 64, 67]);
+            let mut out = [note_on(0.0, 0.0, 0, 0.0); 128];
+            let mut picked = Vec::new();
+            let bounds: &[(f64, f64)] = if split { &[(0.0, 1000.0), (1000.0, 3840.0)] } else { &[(0.0, 3840.0)] };
+            for (index, &(from, to)) in bounds.iter().enumerate() {
+                let written = process(&mut state, from, to, abi::BlockFlags::TRANSPORTING, if index == 0 { &input } else { &[] }, &mut out);
+                picked.extend(out[..written].iter().filter(|event| event.kind == EVENT_NOTE_ON).map(|event| event.pitch));
+            }
+            picked
+        };
+        let picked = run(false);
+        assert_eq!(picked.len(), 16);
+        assert_eq!(picked, run(true), "the same notes whatever the block boundaries");
+        let distinct: std::collections::BTreeSet<u32> = picked.iter().copied().collect();
+        assert!(distinct.len() >= 4 && distinct.iter().all(|pitch| [60, 64, 67, 72, 76, 79].contains(pitch)), "{distinct:?}");
+    }
+
+    #[test]
+    fn chord_mode_strikes_the_whole_stack_and_climbs_the_octaves() {
+        let mut state = state();
+        state.mode = MODE_CHORD;
+        state.octaves = 2;
+        let steps = ons(&mut state, &chord(&[60, 64, 67]));
+        assert_eq!(steps.len(), 48, "three notes on each of the 16 steps");
+        let mut pitches: Vec<u32> = steps.iter().take(6).map(|&(_, pitch, _, _)| pitch).collect();
+        pitches.sort_unstable(); // equal positions come out in no particular order
+        assert_eq!(pitches, [60, 64, 67, 72, 76, 79]);
+        assert_eq!((steps[2].0, steps[3].0), (0.0, 240.0));
+    }
+
+    #[test]
+    fn a_pattern_shapes_velocity_gate_rests_and_ties() {
+        let mut state = state();
+        state.pattern_length = 4;
+        state.pattern[0].velocity = 0.5;
+        state.pattern[1].rest = true;
+        state.pattern[2].tie = true;
+        state.pattern[3].gate = 0.5;
+        let steps = ons(&mut state, &chord(&[60, 64]));
+        // a bar is four passes: struck on steps 0 and 2 (arp steps 0 and 2, both the lower note), the tie
+        // carrying step 2 through half of step 3
+        assert_eq!(steps.len(), 8);
+        assert_eq!(steps[..3], [(0.0, 60, 0.5, 240.0), (480.0, 60, 1.0, 360.0), (960.0, 60, 0.5, 240.0)]);
+    }
+
+    #[test]
+    fn ties_chain_and_stop_at_a_rest() {
+        let mut state = state();
+        state.pattern_length = 4;
+        state.pattern[0].tie = true;
+        state.pattern[1].tie = true;
+        state.pattern[2].rest = true;
+        let steps = ons(&mut state, &chord(&[60]));
+        assert_eq!(steps[..3], [(0.0, 60, 1.0, 480.0), (720.0, 60, 1.0, 240.0), (960.0, 60, 1.0, 480.0)], "the chain ends before the rest");
+    }
+
+    #[test]
+    fn pattern_fields_arrive_through_field_changed() {
+        let mut state = state();
+        state.pattern_length_id = 1;
+        state.pattern_ids = core::array::from_fn(|index| core::array::from_fn(|key| 2 + (index * 4 + key) as u32));
+        apply_field(&mut state, 1, FieldValue::Int(40));
+        assert_eq!(state.pattern_length, PATTERN_STEPS, "the length clamps to the step array");
+        apply_field(&mut state, 2 + 4 + 2, FieldValue::Bool(true)); // step 1 tie
+        apply_field(&mut state, 2 + 8 + 3, FieldValue::Bool(true)); // step 2 rest
+        apply_field(&mut state, 2 + 12 + 1, FieldValue::Float(3.0)); // step 3 gate
+        assert!(state.pattern[1].tie && state.pattern[2].rest && state.pattern[3].gate == 2.0);
+    }
+
     #[test]
     fn not_transporting_emits_nothing() {
         let mut state = state();
@@ -11,7 +11,7 @@ const PLAYING: u32 = BlockFlags::TRANSPORTING;
 
 fn seeded() -> ArpState {
     // The engine hands a zeroed block, then calls `init`; `seed` applies the same defaults (1/16, up, 1 octave,
-    // gate 1) without the extern's `u32` pointer (which truncates on a 64-bit native test build).
+    // gate 1) on a typed state instead of the extern's raw pointer.
     let mut state: ArpState = unsafe { core::mem::zeroed() };
     seed(&mut state);
     state
@@ -52,7 +52,7 @@ pub struct AutotuneState {
     shift_id: u32,
     smooth_id: u32,
     tuner_id: u32,
-    tuner_ptr: u32
+    tuner_ptr: usize
 }
 
 pub struct AutotuneDevice;
@@ -114,33 +114,33 @@ impl AudioEffect for AutotuneDevice {
     }
 }
 
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn kind() -> u32 {
     abi::DEVICE_KIND_AUDIO_EFFECT
 }
 
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn state_size(_sample_rate: f32) -> u32 {
     core::mem::size_of::<AutotuneState>() as u32
 }
 
-#[no_mangle]
-pub extern "C" fn process(desc_ptr: u32) {
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn process(desc_ptr: usize) {
     let ports = unsafe { Ports::<AutotuneState>::from_descriptor(desc_ptr) };
     abi::render_effect::<AutotuneDevice>(ports);
 }
 
-#[no_mangle]
-pub extern "C" fn init(state_ptr: u32, sample_rate: f32) {
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn init(state_ptr: usize, sample_rate: f32) {
     unsafe { abi::with_state(state_ptr, |state| <AutotuneDevice as AudioEffect>::init(state, sample_rate)) }
 }
 
-#[no_mangle]
-pub extern "C" fn parameter_changed(state_ptr: u32, id: u32, kind: u32, value: f32) {
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn parameter_changed(state_ptr: usize, id: u32, kind: u32, value: f32) {
     unsafe { abi::with_state(state_ptr, |state| <AutotuneDevice as AudioEffect>::parameter_changed(state, id, ParamValue::from_wire(kind, value))) }
 }
 
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn map_parameter(id: u32, unit: f32) -> f32 {
     let value = ParamValue::Unit(unit);
     match id {
@@ -154,8 +154,8 @@ pub extern "C" fn map_parameter(id: u32, unit: f32) -> f32 {
     }
 }
 
-#[no_mangle]
-pub extern "C" fn reset(state_ptr: u32) {
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn reset(state_ptr: usize) {
     unsafe { abi::with_state(state_ptr, <AutotuneDevice as AudioEffect>::reset) }
 }
 
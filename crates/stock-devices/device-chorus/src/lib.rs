@@ -0,0 +1,441 @@
+//! The CHORUS, a modulation AUDIO EFFECT (`ChorusDeviceBox`) with three modes:
+//!
+//! - CHORUS and FLANGER sweep the read offset of a short delay line per sample and read it FRACTIONALLY
+//!   (`dsp::delay_line::read_fractional`, the interpolating read lifted out of the Delay's pre-delay). The
+//!   chorus sweeps 10..25 ms, the flanger 0.5..5.5 ms; the feedback taps the swept read back into the line.
+//! - PHASER runs the input through `PHASER_STAGES` second-order all-passes (`dsp::biquad`) whose frequency is
+//!   swept exponentially over `PHASER_OCTAVES` above `PHASER_MIN_HZ`; the feedback taps the last stage back
+//!   into the first. The coefficients are recomputed every `PHASER_CONTROL` samples.
+//!
+//! Each channel has its own `dsp::lfo::Lfo` (a sine); the right one runs `spread` degrees ahead of the left.
+//! With `sync` on, a period is one `RATE_FRACTIONS` entry at the block's tempo and the LFOs are re-locked to
+//! the song position at every block while the transport runs; with it off (or stopped) they run free at
+//! `rate` Hz. Depth 0 parks the sweep at its centre.
+//!
+//! The delay lines are rate-sized: the state ends with a flexible `[f32; 0]` tail and `state_size` adds two
+//! pow2 lines long enough for the longest chorus offset at the given sample rate. No `Vec`.
+//!
+//! Parameters: mode `[10]` (see `MODE_*`), sync `[11]` (bool), rate-index `[12]` (an index into
+//! `RATE_FRACTIONS`), rate `[13]` (Hz, exponential), depth `[14]`, feedback `[15]` (bipolar), spread `[16]`
+//! (degrees) and mix `[17]`.
+//!
+//! Exports: `kind()` (audio effect), `state_size()`, `process(desc_ptr)`, `init(state_ptr, sample_rate)`,
+//! `parameter_changed(...)`, `map_parameter(...)`, `reset(state_ptr)`.
+
+#![cfg_attr(target_family = "wasm", no_std)]
+
+#[cfg(target_family = "wasm")]
+use core::panic::PanicInfo;
+use abi::{bool_value, float_value, int_value, AudioEffect, Block, ParamValue, Ports, MAIN_INPUT};
+use dsp::biquad::{BiquadCoeff, BiquadMono, BiquadProcessor};
+use dsp::delay_line::read_fractional;
+use dsp::lfo::Lfo;
+use dsp::osc::ClassicWaveform;
+use dsp::{ppqn, RENDER_QUANTUM};
+use math::value_mapping::{Exponential, Linear, LinearInteger};
+
+#[cfg(target_family = "wasm")]
+#[panic_handler]
+fn panic(info: &PanicInfo) -> ! {
+    abi::panic_to_host(info) // deposit the message in the engine's panic buffer, then trap (never a silent hang)
+}
+
+// The ChorusDeviceBox field-key paths (the stable schema keys).
+const MODE_FIELD: [u16; 1] = [10];
+const SYNC_FIELD: [u16; 1] = [11];
+const RATE_INDEX_FIELD: [u16; 1] = [12];
+const RATE_FIELD: [u16; 1] = [13];
+const DEPTH_FIELD: [u16; 1] = [14];
+const FEEDBACK_FIELD: [u16; 1] = [15];
+const SPREAD_FIELD: [u16; 1] = [16];
+const MIX_FIELD: [u16; 1] = [17];
+
+mod param {
+    pub const MODE: usize = 0;
+    pub const SYNC: usize = 1;
+    pub const RATE_INDEX: usize = 2;
+    pub const RATE: usize = 3;
+    pub const DEPTH: usize = 4;
+    pub const FEEDBACK: usize = 5;
+    pub const SPREAD: usize = 6;
+    pub const MIX: usize = 7;
+    pub const COUNT: usize = 8;
+}
+
+// WASM CONTRACT: the mode-index values.
+pub const MODE_CHORUS: i32 = 0;
+pub const MODE_FLANGER: i32 = 1;
+pub const MODE_PHASER: i32 = 2;
+
+// The Tidal rate table (descending, so the index is the rate-index value): one LFO period per fraction.
+const RATE_FRACTIONS: [(i32, i32); 17] = [
+    (1, 1), (1, 2), (1, 3), (1, 4), (3, 16), (1, 6), (1, 8), (3, 32), (1, 12),
+    (1, 16), (3, 64), (1, 24), (1, 32), (1, 48), (1, 64), (1, 96), (1, 128)
+];
+
+/// The delay sweeps (base, swing) in ms: the offset runs over `base..base + swing`.
+const CHORUS_SWEEP_MS: (f64, f64) = (10.0, 15.0);
+const FLANGER_SWEEP_MS: (f64, f64) = (0.5, 5.0);
+const PHASER_STAGES: usize = 4;
+const PHASER_MIN_HZ: f64 = 100.0;
+const PHASER_OCTAVES: f64 = 6.0;
+const PHASER_Q: f64 = 0.5;
+const PHASER_CONTROL: u32 = 16;
+/// The loop gain at full feedback (below 1, so the comb and the all-pass loop always decay).
+const MAX_FEEDBACK: f32 = 0.95;
+
+const MODE_MAPPING: LinearInteger = LinearInteger {min: 0, max: MODE_PHASER};
+const RATE_INDEX_MAPPING: LinearInteger = LinearInteger {min: 0, max: RATE_FRACTIONS.len() as i32 - 1};
+const RATE_MAPPING: Exponential = Exponential {min: 0.05, max: 10.0};
+const UNIPOLAR: Linear = Linear::unipolar();
+const BIPOLAR: Linear = Linear::bipolar();
+const SPREAD_MAPPING: Linear = Linear {min: 0.0, max: 180.0};
+
+/// The pow2 delay-line length holding the longest chorus offset (+2 frames for the interpolated read).
+fn line_size(sample_rate: f32) -> usize {
+    let frames = libm::ceil((CHORUS_SWEEP_MS.0 + CHORUS_SWEEP_MS.1) * 0.001 * sample_rate as f64) as usize + 2;
+    frames.next_power_of_two()
+}
+
+/// The device state: the two LFOs with their shared song-locked phase, the per-quantum LFO scratch, the
+/// phaser sections + coefficients per channel, the delay write head, the real parameter values, the bound
+/// parameter ids, and the flexible `[f32; 0]` tail the engine allocates to hold the two pow2 delay lines.
+#[repr(C)]
+pub struct ChorusState {
+    lfos: [Lfo; 2],
+    lfo_values: [[f32; RENDER_QUANTUM]; 2],
+    phase: f64,
+    phasers: [[BiquadMono; PHASER_STAGES]; 2],
+    phaser_coeffs: [BiquadCoeff; 2],
+    phaser_countdown: u32,
+    last_wet: [f32; 2],
+    write_position: usize,
+    sample_rate: f32,
+    buffer_size: usize,
+    mode: i32,
+    sync: bool,
+    rate_index: i32,
+    rate: f32,
+    depth: f32,
+    feedback: f32,
+    spread: f32,
+    mix: f32,
+    ids: [u32; param::COUNT],
+    tail: [f32; 0]
+}
+
+/// The DSP, plugged into the SDK's `AudioEffect` template ([`abi::render_effect`]).
+pub struct Chorus;
+
+impl AudioEffect for Chorus {
+    type State = ChorusState;
+
+    fn init(state: &mut ChorusState, sample_rate: f32) {
+        state.sample_rate = sample_rate; // stable for the device's life
+        state.buffer_size = line_size(sample_rate);
+        state.lfos = [Lfo::new(sample_rate), Lfo::new(sample_rate)];
+        // box defaults; the engine pushes the real values right after via `parameter_changed`
+        state.mode = MODE_CHORUS;
+        state.sync = true;
+        state.rate_index = 0;
+        state.rate = 0.5;
+        state.depth = 0.5;
+        state.feedback = 0.0;
+        state.spread = 90.0;
+        state.mix = 0.5;
+        state.ids[param::MODE] = abi::bind_parameter(&MODE_FIELD);
+        state.ids[param::SYNC] = abi::bind_parameter(&SYNC_FIELD);
+        state.ids[param::RATE_INDEX] = abi::bind_parameter(&RATE_INDEX_FIELD);
+        state.ids[param::RATE] = abi::bind_parameter(&RATE_FIELD);
+        state.ids[param::DEPTH] = abi::bind_parameter(&DEPTH_FIELD);
+        state.ids[param::FEEDBACK] = abi::bind_parameter(&FEEDBACK_FIELD);
+        state.ids[param::SPREAD] = abi::bind_parameter(&SPREAD_FIELD);
+        state.ids[param::MIX] = abi::bind_parameter(&MIX_FIELD);
+    }
+
+    fn process_audio(state: &mut ChorusState, output: [&mut [f32]; 2], block: &Block) {
+        let Some(input) = abi::resolve_input(MAIN_INPUT) else {return};
+        let transporting = block.flags.0 & abi::BlockFlags::TRANSPORTING != 0;
+        // Slice the two pow2 delay lines out of the engine-allocated tail (disjoint from the header, so the
+        // raw slices never alias the rest of the state).
+        let size = state.buffer_size;
+        let tail = unsafe { core::slice::from_raw_parts_mut(state.tail.as_mut_ptr(), 2 * size) };
+        let (line_left, line_right) = tail.split_at_mut(size);
+        let [out_left, out_right] = output;
+        Chorus::dsp(state, [input.left(), input.right()], [out_left, out_right], [line_left, line_right],
+            block.s0 as usize, block.s1 as usize, transporting.then_some(block.p0), block.bpm);
+    }
+
+    fn parameter_changed(state: &mut ChorusState, id: u32, value: ParamValue) {
+        let Some(index) = state.ids.iter().position(|bound| *bound == id) else {
+            return;
+        };
+        match index {
+            param::MODE => state.mode = int_value(value, &MODE_MAPPING),
+            param::SYNC => state.sync = bool_value(value),
+            param::RATE_INDEX => state.rate_index = int_value(value, &RATE_INDEX_MAPPING),
+            param::RATE => state.rate = float_value(value, &RATE_MAPPING),
+            param::DEPTH => state.depth = float_value(value, &UNIPOLAR),
+            param::FEEDBACK => state.feedback = float_value(value, &BIPOLAR),
+            param::SPREAD => state.spread = float_value(value, &SPREAD_MAPPING),
+            param::MIX => state.mix = float_value(value, &UNIPOLAR),
+            _ => {}
+        }
+    }
+
+    fn reset(state: &mut ChorusState) {
+        let size = state.buffer_size;
+        let tail = unsafe { core::slice::from_raw_parts_mut(state.tail.as_mut_ptr(), 2 * size) };
+        tail.fill(0.0);
+        for section in state.phasers.iter_mut().flatten() {
+            section.reset();
+        }
+        state.last_wet = [0.0; 2];
+        state.phaser_countdown = 0;
+        state.phase = 0.0;
+    }
+}
+
+impl Chorus {
+    /// The pure per-range DSP (unit-tested directly) over `[s0, s1)` in absolute quantum coordinates. `lines`
+    /// are the two pow2 delay lines; `position` is the song position at `s0` while the transport runs (a synced
+    /// LFO locks to it), `None` when stopped.
+    #[allow(clippy::too_many_arguments)]
+    fn dsp(state: &mut ChorusState, input: [&[f32]; 2], output: [&mut [f32]; 2], lines: [&mut [f32]; 2],
+           s0: usize, s1: usize, position: Option<f64>, bpm: f32) {
+        let sample_rate = state.sample_rate;
+        let frequency = if state.sync {
+            let (numerator, denominator) = RATE_FRACTIONS[state.rate_index.clamp(0, RATE_FRACTIONS.len() as i32 - 1) as usize];
+            let period = ppqn::from_signature(numerator, denominator);
+            if let Some(position) = position {
+                state.phase = position / period;
+            }
+            1.0 / ppqn::pulses_to_seconds(period, bpm)
+        } else {
+            state.rate as f64
+        };
+        let offsets = [0.0, state.spread as f64 / 360.0];
+        for ((lfo, values), offset) in state.lfos.iter_mut().zip(&mut state.lfo_values).zip(offsets) {
+            lfo.set_phase(state.phase + offset);
+            lfo.fill(values, ClassicWaveform::Sine, frequency as f32, s0, s1);
+        }
+        let phase = state.phase + (s1 - s0) as f64 * frequency / sample_rate as f64;
+        state.phase = phase - libm::floor(phase);
+        let feedback = state.feedback * MAX_FEEDBACK;
+        let (dry, wet) = (1.0 - state.mix, state.mix);
+        if state.mode == MODE_PHASER {
+            for index in s0..s1 {
+                if state.phaser_countdown == 0 {
+                    for (coeff, values) in state.phaser_coeffs.iter_mut().zip(&state.lfo_values) {
+                        let sweep = 0.5 + 0.5 * values[index] * state.depth;
+                        let frequency = PHASER_MIN_HZ * libm::exp2(PHASER_OCTAVES * sweep as f64);
+                        coeff.set_allpass_params(frequency / sample_rate as f64, PHASER_Q);
+                    }
+                    state.phaser_countdown = PHASER_CONTROL;
+                }
+                state.phaser_countdown -= 1;
+                for channel in 0..2 {
+                    let source = input[channel][index];
+                    let mut value = (source + feedback * state.last_wet[channel]) as f64;
+                    for section in &mut state.phasers[channel] {
+                        value = section.process_frame(&state.phaser_coeffs[channel], value);
+                    }
+                    state.last_wet[channel] = value as f32;
+                    output[channel][index] = dry * source + wet * value as f32;
+                }
+            }
+        } else {
+            let (base, swing) = if state.mode == MODE_FLANGER { FLANGER_SWEEP_MS } else { CHORUS_SWEEP_MS };
+            let samples_per_ms = 0.001 * sample_rate as f64;
+            let mask = state.buffer_size - 1;
+            let mut write_position = state.write_position;
+            for index in s0..s1 {
+                for channel in 0..2 {
+                    let sweep = 0.5 + 0.5 * state.lfo_values[channel][index] * state.depth;
+                    let offset = (base + swing * sweep as f64) * samples_per_ms;
+                    // read before the write: the offset is at least the base, so the head is never read
+                    let delayed = read_fractional(lines[channel], write_position, offset);
+                    let source = input[channel][index];
+                    lines[channel][write_position] = source + feedback * delayed;
+                    output[channel][index] = dry * source + wet * delayed;
+                }
+                write_position = (write_position + 1) & mask;
+            }
+            state.write_position = write_position;
+        }
+    }
+}
+
+// ---- The device ABI: shared with the engine, called wasm-to-wasm. ----
+
+/// What the host wires this device as (read at load): an audio effect that transforms its input.
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn kind() -> u32 {
+    abi::DEVICE_KIND_AUDIO_EFFECT
+}
+
+/// Bytes the engine must allocate (zeroed) for one instance's state block: the header plus the two delay
+/// lines, which scale with the sample rate (the rate-sized tail).
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn state_size(sample_rate: f32) -> u32 {
+    (core::mem::size_of::<ChorusState>() + 2 * line_size(sample_rate) * core::mem::size_of::<f32>()) as u32
+}
+
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn process(desc_ptr: usize) {
+    let ports = unsafe { Ports::<ChorusState>::from_descriptor(desc_ptr) };
+    abi::render_effect::<Chorus>(ports);
+}
+
+/// Boot hook: bind this device's parameters with the host, and stash the sample rate.
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn init(state_ptr: usize, sample_rate: f32) {
+    unsafe { abi::with_state(state_ptr, |state| <Chorus as AudioEffect>::init(state, sample_rate)) }
+}
+
+/// Apply a parameter value the host resolved (initial / edit / automation), by the id `init` got back.
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn parameter_changed(state_ptr: usize, id: u32, kind: u32, value: f32) {
+    unsafe { abi::with_state(state_ptr, |state| <Chorus as AudioEffect>::parameter_changed(state, id, ParamValue::from_wire(kind, value))) }
+}
+
+/// Parity probe: the REAL value stored for a UNIT automation value, ids in `init` bind order (the `param` slots).
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn map_parameter(id: u32, unit: f32) -> f32 {
+    let value = ParamValue::Unit(unit);
+    match id as usize {
+        param::MODE => int_value(value, &MODE_MAPPING) as f32,
+        param::SYNC => if bool_value(value) {1.0} else {0.0},
+        param::RATE_INDEX => int_value(value, &RATE_INDEX_MAPPING) as f32,
+        param::RATE => float_value(value, &RATE_MAPPING),
+        param::DEPTH | param::MIX => float_value(value, &UNIPOLAR),
+        param::FEEDBACK => float_value(value, &BIPOLAR),
+        param::SPREAD => float_value(value, &SPREAD_MAPPING),
+        _ => f32::NAN
+    }
+}
+
+/// Transport STOP: zero the delay lines and the phaser history so nothing rings into the next playback.
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn reset(state_ptr: usize) {
+    unsafe { abi::with_state(state_ptr, <Chorus as AudioEffect>::reset) }
+}
+
+#[cfg(test)]
+mod tests {
+    //! The chorus DSP, driven directly (`resolve_input` has no host on native).
+    use super::*;
+
+    const SR: f32 = 48_000.0;
+
+    fn state(mode: i32) -> ChorusState {
+        let mut state: ChorusState = unsafe { core::mem::zeroed() };
+        state.sample_rate = SR;
+        state.buffer_size = line_size(SR);
+        state.lfos = [Lfo::new(SR), Lfo::new(SR)];
+        state.mode = mode;
+        state.rate = 0.5;
+        state.mix = 1.0;
+        state
+    }
+
+    // Run `input` (both channels) through in quanta and return the (left, right) output.
+    fn run(state: &mut ChorusState, input: &[f32], transporting: bool) -> (Vec<f32>, Vec<f32>) {
+        let mut lines = vec![0.0f32; 2 * state.buffer_size];
+        let (line_left, line_right) = lines.split_at_mut(state.buffer_size);
+        let (mut left, mut right) = (Vec::with_capacity(input.len()), Vec::with_capacity(input.len()));
+        let (mut out_left, mut out_right) = ([0.0f32; RENDER_QUANTUM], [0.0f32; RENDER_QUANTUM]);
+        for (index, chunk) in input.chunks(RENDER_QUANTUM).enumerate() {
+            let position = transporting.then_some(index as f64 * ppqn::samples_to_pulses(RENDER_QUANTUM as f64, 120.0, SR));
+            Chorus::dsp(state, [chunk, chunk], [&mut out_left, &mut out_right], [&mut *line_left, &mut *line_right],
+                0, chunk.len(), position, 120.0);
+            left.extend_from_slice(&out_left[..chunk.len()]);
+            right.extend_from_slice(&out_right[..chunk.len()]);
+        }
+        (left, right)
+    }
+
+    fn impulse(len: usize) -> Vec<f32> {
+        let mut input = vec![0.0f32; len];
+        input[0] = 1.0;
+        input
+    }
+
+    fn rms(samples: &[f32]) -> f32 {
+        libm::sqrtf(samples.iter().map(|sample| sample * sample).sum::<f32>() / samples.len() as f32)
+    }
+
+    #[test]
+    fn without_depth_the_chorus_delays_by_the_centre_of_its_sweep() {
+        // 10 + 15 / 2 ms at 48 kHz is exactly 840 samples
+        let (left, right) = run(&mut state(MODE_CHORUS), &impulse(2048), false);
+        assert_eq!(left[840], 1.0);
+        assert_eq!(right[840], 1.0);
+        assert_eq!(left.iter().map(|sample| sample.abs()).sum::<f32>(), 1.0, "a single clean tap");
+    }
+
+    #[test]
+    fn flanger_feedback_repeats_the_tap_with_its_sign() {
+        // 0.5 + 5 / 2 ms is 144 samples; each round trip scales by the loop gain
+        for feedback in [0.5, -0.5] {
+            let mut state = state(MODE_FLANGER);
+            state.feedback = feedback;
+            let gain = feedback * MAX_FEEDBACK;
+            let (left, _) = run(&mut state, &impulse(1024), false);
+            assert_eq!(left[144], 1.0);
+            assert!((left[288] - gain).abs() < 1e-6, "second tap {}", left[288]);
+            assert!((left[432] - gain * gain).abs() < 1e-6, "third tap {}", left[432]);
+        }
+    }
+
+    #[test]
+    fn the_sweep_moves_the_tap_and_spread_offsets_the_right_channel() {
+        let input = impulse(4096);
+        let mut centred = state(MODE_CHORUS);
+        centred.depth = 1.0;
+        centred.sync = true; // a whole note at 120 bpm: the sine starts at phase 0, so both channels sit at the centre
+        let (left, right) = run(&mut centred, &input, true);
+        assert_eq!(left, right, "no spread: the channels move together");
+        let mut spread = state(MODE_CHORUS);
+        spread.depth = 1.0;
+        spread.sync = true;
+        spread.spread = 90.0;
+        let (left, right) = run(&mut spread, &input, true);
+        let peak = |samples: &[f32]| samples.iter().enumerate().fold((0, 0.0f32), |best, (index, sample)| if *sample > best.1 { (index, *sample) } else { best }).0;
+        assert!(peak(&left) < peak(&right), "the right channel is a quarter period ahead, at the long end of the sweep");
+        assert!(peak(&right) > 1150, "the right tap sits near 25 ms: {}", peak(&right));
+    }
+
+    #[test]
+    fn the_synced_lfo_locks_to_the_song_position() {
+        // a quarter-note period at 120 bpm; a block starting a quarter period in reads the sine's peak
+        let mut state = state(MODE_CHORUS);
+        state.sync = true;
+        state.rate_index = 3;
+        state.spread = 180.0;
+        let period = ppqn::from_signature(1, 4);
+        let (mut left, mut right) = ([0.0f32; RENDER_QUANTUM], [0.0f32; RENDER_QUANTUM]);
+        let mut lines = vec![0.0f32; 2 * state.buffer_size];
+        let (line_left, line_right) = lines.split_at_mut(state.buffer_size);
+        let input = [0.0f32; RENDER_QUANTUM];
+        Chorus::dsp(&mut state, [&input, &input], [&mut left, &mut right], [line_left, line_right], 0, RENDER_QUANTUM,
+            Some(0.25 * period), 120.0);
+        assert!((state.lfo_values[0][0] - 1.0).abs() < 1e-3, "left at the peak: {}", state.lfo_values[0][0]);
+        assert!((state.lfo_values[1][0] + 1.0).abs() < 1e-3, "right half a period on: {}", state.lfo_values[1][0]);
+    }
+
+    #[test]
+    fn the_phaser_mix_notches_the_spectrum() {
+        // without depth the all-passes sit at 800 Hz; mixed half-and-half they cancel where the phase is 180
+        let gains: Vec<f32> = [100.0f32, 200.0, 400.0, 600.0, 800.0, 1200.0, 1600.0, 3200.0, 6400.0].iter().map(|frequency| {
+            let input: Vec<f32> = (0..9600).map(|index| 0.5 * libm::sinf(core::f32::consts::TAU * frequency * index as f32 / SR)).collect();
+            let mut state = state(MODE_PHASER);
+            state.mix = 0.5;
+            let (left, _) = run(&mut state, &input, false);
+            rms(&left[4800..]) / rms(&input[4800..])
+        }).collect();
+        assert!(gains.iter().any(|gain| *gain < 0.2), "a notch: {gains:?}");
+        assert!(gains.iter().any(|gain| *gain > 0.9), "and pass bands: {gains:?}");
+        assert!(gains.iter().all(|gain| *gain < 1.01), "an all-pass mix never boosts: {gains:?}");
+    }
+}
@@ -8,7 +8,8 @@
 //! (bools); inputgain `[14]` (dB), threshold `[15]` (dB), ratio `[16]` (exp 1..24), knee `[17]` (dB), attack
 //! `[18]` (ms), release `[19]` (ms), makeup `[20]` (dB), mix `[21]` (unipolar); side-chain `[30]` (pointer).
 //!
-//! Exports: `kind()` (audio effect), `state_size()`, `process(desc_ptr)`, `init(...)`, `parameter_changed(...)`.
+//! Exports: `kind()` (audio effect), `state_size()`, `process(desc_ptr)`, `init(...)`, `parameter_changed(...)`,
+//! `latency(state_ptr)` (the look-ahead delay while look-ahead is on, for the engine's delay compensation).
 
 #![cfg_attr(target_family = "wasm", no_std)]
 
@@ -96,7 +97,7 @@ pub struct CompressorState {
     // Live editor telemetry (TS `#editorValues` at address `[0]`): detection peak, last reduction, output
     // peak — the peaks hold with a 500 ms decay (TS `PEAK_DECAY_PER_SAMPLE`), written per block.
     editor_id: u32,
-    editor_ptr: u32,
+    editor_ptr: usize,
     editor_peak_decay: f32,
     inp_max: f32,
     out_max: f32
@@ -193,6 +194,10 @@ impl AudioEffect for Compressor {
         state.lookahead_mix.set(if state.lookahead {1.0} else {0.0}, false);
     }
 
+    fn latency(state: &CompressorState) -> u32 {
+        if state.lookahead {state.delay.delay_in_samples() as u32} else {0}
+    }
+
     fn process_audio(state: &mut CompressorState, output: [&mut [f32]; 2], block: &Block) {
         let Some(input) = abi::resolve_input(MAIN_INPUT) else {return};
         let sidechain = abi::resolve_input(state.sidechain_id);
@@ -287,34 +292,34 @@ impl AudioEffect for Compressor {
     }
 }
 
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn kind() -> u32 {
     abi::DEVICE_KIND_AUDIO_EFFECT
 }
 
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn state_size(_sample_rate: f32) -> u32 {
     core::mem::size_of::<CompressorState>() as u32
 }
 
-#[no_mangle]
-pub extern "C" fn process(desc_ptr: u32) {
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn process(desc_ptr: usize) {
     let ports = unsafe { Ports::<CompressorState>::from_descriptor(desc_ptr) };
     abi::render_effect::<Compressor>(ports);
 }
 
-#[no_mangle]
-pub extern "C" fn init(state_ptr: u32, sample_rate: f32) {
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn init(state_ptr: usize, sample_rate: f32) {
     unsafe { abi::with_state(state_ptr, |state| <Compressor as AudioEffect>::init(state, sample_rate)) }
 }
 
-#[no_mangle]
-pub extern "C" fn parameter_changed(state_ptr: u32, id: u32, kind: u32, value: f32) {
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn parameter_changed(state_ptr: usize, id: u32, kind: u32, value: f32) {
     unsafe { abi::with_state(state_ptr, |state| <Compressor as AudioEffect>::parameter_changed(state, id, ParamValue::from_wire(kind, value))) }
 }
 
 /// Parity probe: the REAL value stored for a UNIT automation value, ids in `init` bind order.
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn map_parameter(id: u32, unit: f32) -> f32 {
     let value = ParamValue::Unit(unit);
     match id {
@@ -331,8 +336,16 @@ pub extern "C" fn map_parameter(id: u32, unit: f32) -> f32 {
     }
 }
 
+/// Delay compensation: the samples the output lags the input (the look-ahead delay while look-ahead is on).
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn latency(state_ptr: usize) -> u32 {
+    let mut latency = 0;
+    unsafe { abi::with_state(state_ptr, |state: &mut CompressorState| latency = <Compressor as AudioEffect>::latency(state)) }
+    latency
+}
+
 /// Transport STOP: clear the runtime state (mirrors the TS processor's `reset`).
-#[no_mangle]
-pub extern "C" fn reset(state_ptr: u32) {
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn reset(state_ptr: usize) {
     unsafe { abi::with_state(state_ptr, <Compressor as AudioEffect>::reset) }
 }
@@ -0,0 +1,509 @@
+//! The CONVOLUTION REVERB, an AUDIO EFFECT (`ConvolutionReverbDeviceBox`) that convolves its input with an
+//! impulse-response SAMPLE through the uniformly partitioned `dsp::convolver::Convolver` (one render quantum
+//! per partition, so the wet path is a fixed quantum late, which the pre-delay absorbs).
+//!
+//! The response is the `file` pointer's `AudioFileBox`, observed with `abi::observe_sample` and resolved per
+//! block with `abi::resolve_sample` like the Nano's sample (silent wet while it loads). A mono file feeds both
+//! channels. The response is trimmed to `start..end` (fractions of the file), resampled to the engine rate
+//! and stretched by `stretch` (2 = twice as long and an octave darker), capped at `MAX_IR_SECONDS`, and
+//! normalised to the unit energy of that capped part. Whenever the file or a trim / stretch value changes,
+//! the response is first measured and then its partition spectra are rebuilt, both `LOAD_PARTITIONS` worth
+//! per block on the render thread, so a long response fades in over a few blocks instead of stalling one.
+//!
+//! The partition spectra, the convolver's delay line and the stereo pre-delay lines are rate-sized: the
+//! state ends with a flexible `[f32; 0]` tail and `state_size(sample_rate)` adds them. No `Vec`.
+//!
+//! Parameters: pre-delay `[10]` (exp 0.001..0.5 s), start `[11]` / end `[12]` (unipolar, of the file),
+//! stretch `[13]` (exp 0.5..2), wet `[14]` / dry `[15]` (decibel), and the `file` sample pointer `[20]`.
+//!
+//! Exports: `kind()` (audio effect), `state_size()`, `process(desc_ptr)`, `init(state_ptr, sample_rate)`,
+//! `parameter_changed(...)`, `sample_changed(...)`, `map_parameter(...)`, `reset(state_ptr)`.
+
+#![cfg_attr(target_family = "wasm", no_std)]
+
+#[cfg(target_family = "wasm")]
+use core::panic::PanicInfo;
+use abi::{float_value, AudioEffect, Block, ParamValue, Ports, SampleRef, MAIN_INPUT};
+use dsp::convolver::{partitions_for, Convolver, BLOCK, LATENCY, PARTITION_FLOATS};
+use dsp::{db_to_gain, RENDER_QUANTUM};
+use math::value_mapping::{Decibel, Exponential, Linear};
+
+#[cfg(target_family = "wasm")]
+#[panic_handler]
+fn panic(info: &PanicInfo) -> ! {
+    abi::panic_to_host(info) // deposit the message in the engine's panic buffer, then trap (never a silent hang)
+}
+
+// The ConvolutionReverbDeviceBox field-key paths (the stable schema keys).
+const PRE_DELAY_FIELD: [u16; 1] = [10];
+const START_FIELD: [u16; 1] = [11];
+const END_FIELD: [u16; 1] = [12];
+const STRETCH_FIELD: [u16; 1] = [13];
+const WET_FIELD: [u16; 1] = [14];
+const DRY_FIELD: [u16; 1] = [15];
+const SAMPLE_POINTER: [u16; 1] = [20];
+
+mod param {
+    pub const PRE_DELAY: usize = 0;
+    pub const START: usize = 1;
+    pub const END: usize = 2;
+    pub const STRETCH: usize = 3;
+    pub const WET: usize = 4;
+    pub const DRY: usize = 5;
+    pub const COUNT: usize = 6;
+}
+
+/// The longest response (after trim and stretch) in seconds; it sizes the partition storage.
+const MAX_IR_SECONDS: f32 = 4.0;
+const MAX_PRE_DELAY_SECONDS: f32 = 0.5;
+/// The partitions measured, then rebuilt, per `process_audio` call while a response loads.
+const LOAD_PARTITIONS: usize = 32;
+
+const PRE_DELAY_MAPPING: Exponential = Exponential {min: 0.001, max: MAX_PRE_DELAY_SECONDS};
+const UNIPOLAR: Linear = Linear::unipolar();
+const STRETCH_MAPPING: Exponential = Exponential {min: 0.5, max: 2.0};
+const GAIN_MAPPING: Decibel = Decibel::default_volume(); // ValueMapping.DefaultDecibel = decibel(-72, -12, 0)
+
+/// The response capacity in partitions at `sample_rate`.
+fn capacity(sample_rate: f32) -> usize {
+    partitions_for(libm::ceilf(MAX_IR_SECONDS * sample_rate) as usize)
+}
+
+/// The pow2 pre-delay line length holding the longest pre-delay.
+fn pre_delay_size(sample_rate: f32) -> usize {
+    (libm::ceilf(MAX_PRE_DELAY_SECONDS * sample_rate) as usize + 1).next_power_of_two()
+}
+
+/// What the current partition spectra were built from: a changed handle or trim / stretch value rebuilds them.
+#[derive(Clone, Copy, PartialEq)]
+struct ResponseKey {
+    handle: u32,
+    start: f32,
+    end: f32,
+    stretch: f32
+}
+
+/// How response frame `i` reads the file: source position `offset + i * step` (linearly interpolated) while
+/// below `end`, scaled by `gain`.
+#[derive(Clone, Copy, Default)]
+struct ResponseMapping {
+    offset: f64,
+    step: f64,
+    end: f64,
+    gain: f32
+}
+
+impl ResponseMapping {
+    fn read(&self, sample: &SampleRef, channel: usize, frame: usize) -> f32 {
+        let position = self.offset + frame as f64 * self.step;
+        if position >= self.end {
+            return 0.0;
+        }
+        let plane = sample.plane((channel as u32).min(sample.channel_count - 1));
+        let index = position as usize;
+        let alpha = (position - index as f64) as f32;
+        let current = plane[index];
+        let next = plane.get(index + 1).copied().unwrap_or(0.0);
+        self.gain * (current + alpha * (next - current))
+    }
+}
+
+/// The device state: the convolver (built in `init`), the bound sample handle and the response the spectra
+/// hold, the energy measurement in progress (`measuring` up to source frame `measured`), the pre-delay write head, the real parameter values, the per-quantum pre-delayed scratch, the bound
+/// ids, and the flexible `[f32; 0]` tail holding the spectra, the delay line and the two pre-delay lines.
+#[repr(C)]
+pub struct ConvolutionReverbState {
+    convolver: Convolver,
+    sample: Option<u32>, // the resolved sample handle while the `file` pointer is bound; `None` when unbound
+    response: Option<ResponseKey>,
+    mapping: ResponseMapping,
+    measuring: bool,
+    measured: usize,
+    energy: [f64; 2],
+    delayed: [[f32; RENDER_QUANTUM]; 2],
+    sample_rate: f32,
+    capacity: usize,
+    pre_size: usize,
+    pre_write: usize,
+    pre_delay: f32, // seconds
+    start: f32,
+    end: f32,
+    stretch: f32,
+    wet: f32, // linear
+    dry: f32, // linear
+    ids: [u32; param::COUNT],
+    sample_id: u32,
+    tail: [f32; 0]
+}
+
+/// The rate-sized tail at `tail` as (partition spectra, delay line, pre-delay left, pre-delay right). The
+/// tail is disjoint from the state header, so the slices never alias the state's own fields.
+unsafe fn buffers<'a>(tail: *mut f32, capacity: usize, pre_size: usize) -> (&'a mut [f32], &'a mut [f32], &'a mut [f32], &'a mut [f32]) {
+    let partition_floats = capacity * PARTITION_FLOATS;
+    let tail = core::slice::from_raw_parts_mut(tail, 2 * partition_floats + 2 * pre_size);
+    let (spectra, rest) = tail.split_at_mut(partition_floats);
+    let (history, rest) = rest.split_at_mut(partition_floats);
+    let (pre_left, pre_right) = rest.split_at_mut(pre_size);
+    (spectra, history, pre_left, pre_right)
+}
+
+/// The DSP, plugged into the SDK's `AudioEffect` template ([`abi::render_effect`]).
+pub struct ConvolutionReverb;
+
+impl AudioEffect for ConvolutionReverb {
+    type State = ConvolutionReverbState;
+
+    fn init(state: &mut ConvolutionReverbState, sample_rate: f32) {
+        state.sample_rate = sample_rate; // stable for the device's life
+        state.capacity = capacity(sample_rate);
+        state.pre_size = pre_delay_size(sample_rate);
+        state.convolver = Convolver::new(state.capacity);
+        state.sample = None; // no response until the engine catches up the `file` pointer right after init
+        state.response = None;
+        // box defaults; the engine pushes the real values right after via `parameter_changed`
+        state.pre_delay = 0.001;
+        state.start = 0.0;
+        state.end = 1.0;
+        state.stretch = 1.0;
+        state.wet = db_to_gain(-3.0);
+        state.dry = 1.0;
+        state.ids[param::PRE_DELAY] = abi::bind_parameter(&PRE_DELAY_FIELD);
+        state.ids[param::START] = abi::bind_parameter(&START_FIELD);
+        state.ids[param::END] = abi::bind_parameter(&END_FIELD);
+        state.ids[param::STRETCH] = abi::bind_parameter(&STRETCH_FIELD);
+        state.ids[param::WET] = abi::bind_parameter(&WET_FIELD);
+        state.ids[param::DRY] = abi::bind_parameter(&DRY_FIELD);
+        state.sample_id = abi::observe_sample(&SAMPLE_POINTER);
+    }
+
+    fn process_audio(state: &mut ConvolutionReverbState, output: [&mut [f32]; 2], block: &Block) {
+        let Some(input) = abi::resolve_input(MAIN_INPUT) else {return};
+        let sample = state.sample.and_then(abi::resolve_sample);
+        ConvolutionReverb::dsp(state, sample.as_ref(), [input.left(), input.right()], output, block.s0 as usize, block.s1 as usize);
+    }
+
+    fn parameter_changed(state: &mut ConvolutionReverbState, id: u32, value: ParamValue) {
+        let Some(index) = state.ids.iter().position(|bound| *bound == id) else {
+            return;
+        };
+        match index {
+            param::PRE_DELAY => state.pre_delay = float_value(value, &PRE_DELAY_MAPPING),
+            param::START => state.start = float_value(value, &UNIPOLAR),
+            param::END => state.end = float_value(value, &UNIPOLAR),
+            param::STRETCH => state.stretch = float_value(value, &STRETCH_MAPPING),
+            param::WET => state.wet = db_to_gain(float_value(value, &GAIN_MAPPING)),
+            param::DRY => state.dry = db_to_gain(float_value(value, &GAIN_MAPPING)),
+            _ => {}
+        }
+    }
+
+    fn reset(state: &mut ConvolutionReverbState) {
+        let (_, history, pre_left, pre_right) = unsafe { buffers(state.tail.as_mut_ptr(), state.capacity, state.pre_size) };
+        state.convolver.reset(history);
+        pre_left.fill(0.0);
+        pre_right.fill(0.0);
+        state.pre_write = 0;
+    }
+}
+
+impl ConvolutionReverb {
+    /// The pure per-range DSP (unit-tested directly) over `[s0, s1)` in absolute quantum coordinates. `sample`
+    /// is the resolved response file (`None` while it loads or when unbound: the wet path goes silent).
+    fn dsp(state: &mut ConvolutionReverbState, sample: Option<&SampleRef>, input: [&[f32]; 2], output: [&mut [f32]; 2],
+           s0: usize, s1: usize) {
+        ConvolutionReverb::track_response(state, sample);
+        // The convolver is `LATENCY` late already; the pre-delay line supplies the rest.
+        let offset = ((state.pre_delay * state.sample_rate) as usize).saturating_sub(LATENCY).min(state.pre_size - 1);
+        let mask = state.pre_size - 1;
+        let mut write = state.pre_write;
+        let (spectra, history, pre_left, pre_right) = unsafe { buffers(state.tail.as_mut_ptr(), state.capacity, state.pre_size) };
+        if let Some(sample) = sample {
+            if state.measuring {
+                ConvolutionReverb::measure_response(state, sample);
+            }
+            if !state.measuring && !state.convolver.is_loaded() {
+                let mapping = state.mapping;
+                state.convolver.load(spectra, LOAD_PARTITIONS, |channel, frame| mapping.read(sample, channel, frame));
+            }
+        }
+        let [delayed_left, delayed_right] = &mut state.delayed;
+        let frames = input[0][s0..s1].iter().zip(&input[1][s0..s1]).zip(&mut delayed_left[s0..s1]).zip(&mut delayed_right[s0..s1]);
+        for (((&left, &right), delayed_left), delayed_right) in frames {
+            pre_left[write] = left;
+            pre_right[write] = right;
+            let read = (write + mask + 1 - offset) & mask;
+            *delayed_left = pre_left[read];
+            *delayed_right = pre_right[read];
+            write = (write + 1) & mask;
+        }
+        state.pre_write = write;
+        let [out_left, out_right] = output;
+        let [delayed_left, delayed_right] = &state.delayed;
+        state.convolver.process(spectra, history, [delayed_left, delayed_right], [&mut *out_left, &mut *out_right], s0, s1);
+        for (channel, out) in [out_left, out_right].into_iter().enumerate() {
+            for index in s0..s1 {
+                out[index] = state.dry * input[channel][index] + state.wet * out[index];
+            }
+        }
+    }
+
+    /// Restart the response build when the file or a trim / stretch value changed (or drop it when the file
+    /// is gone): map the trimmed file onto the engine rate and start measuring its energy. The wet path stays
+    /// silent until the measurement is done.
+    fn track_response(state: &mut ConvolutionReverbState, sample: Option<&SampleRef>) {
+        let (Some(handle), Some(sample)) = (state.sample, sample) else {
+            if state.response.take().is_some() {
+                state.convolver.set_length(0);
+            }
+            return;
+        };
+        let key = ResponseKey {handle, start: state.start, end: state.end, stretch: state.stretch};
+        if state.response == Some(key) {
+            return;
+        }
+        state.response = Some(key);
+        state.convolver.set_length(0);
+        state.measuring = false;
+        let frames = sample.frame_count as f64;
+        let step = sample.sample_rate as f64 / state.sample_rate as f64 / state.stretch as f64;
+        let start = state.start as f64 * frames;
+        // the source past the capped length is never played, so it must not count for the energy either
+        let end = (state.end as f64 * frames).min(start + (MAX_IR_SECONDS * state.sample_rate) as f64 * step);
+        if sample.frame_count == 0 || sample.channel_count == 0 || end <= start {
+            return;
+        }
+        state.mapping = ResponseMapping {offset: start, step, end, gain: 0.0};
+        state.measuring = true;
+        state.measured = start as usize;
+        state.energy = [0.0; 2];
+    }
+
+    /// Add the energy of the next `LOAD_PARTITIONS` partitions' worth of source frames (a sum of squares costs
+    /// far less per frame than a partition's FFT); once the whole response is measured, fix the unit-energy
+    /// gain and size the convolver for the load.
+    fn measure_response(state: &mut ConvolutionReverbState, sample: &SampleRef) {
+        let mapping = state.mapping;
+        let end = (libm::ceil(mapping.end) as usize).min(sample.frame_count as usize);
+        let (from, to) = (state.measured, (state.measured + LOAD_PARTITIONS * BLOCK).min(end));
+        for channel in 0..sample.channel_count.min(2) {
+            state.energy[channel as usize] += sample.plane(channel)[from..to].iter().map(|value| (value * value) as f64).sum::<f64>();
+        }
+        state.measured = to;
+        if to < end {
+            return;
+        }
+        state.measuring = false;
+        let energy = state.energy[0].max(state.energy[1]);
+        // a resampled frame stands for `step` source frames: unit energy after the resampling
+        state.mapping.gain = if energy > 0.0 { libm::sqrt(mapping.step / energy) as f32 } else { 0.0 };
+        state.convolver.set_length(libm::ceil((mapping.end - mapping.offset) / mapping.step) as usize);
+    }
+}
+
+// ---- The device ABI: shared with the engine, called wasm-to-wasm. ----
+
+/// What the host wires this device as (read at load): an audio effect that transforms its input.
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn kind() -> u32 {
+    abi::DEVICE_KIND_AUDIO_EFFECT
+}
+
+/// Bytes the engine must allocate (zeroed) for one instance's state block: the header plus the partition
+/// spectra, the convolver's delay line and the two pre-delay lines, which scale with the sample rate.
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn state_size(sample_rate: f32) -> u32 {
+    let floats = 2 * capacity(sample_rate) * PARTITION_FLOATS + 2 * pre_delay_size(sample_rate);
+    (core::mem::size_of::<ConvolutionReverbState>() + floats * core::mem::size_of::<f32>()) as u32
+}
+
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn process(desc_ptr: usize) {
+    let ports = unsafe { Ports::<ConvolutionReverbState>::from_descriptor(desc_ptr) };
+    abi::render_effect::<ConvolutionReverb>(ports);
+}
+
+/// Boot hook: bind this device's parameters + its sample reference with the host, and stash the sample rate.
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn init(state_ptr: usize, sample_rate: f32) {
+    unsafe { abi::with_state(state_ptr, |state| <ConvolutionReverb as AudioEffect>::init(state, sample_rate)) }
+}
+
+/// Apply a parameter value the host resolved (initial / edit / automation), by the id `init` got back.
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn parameter_changed(state_ptr: usize, id: u32, kind: u32, value: f32) {
+    unsafe { abi::with_state(state_ptr, |state| <ConvolutionReverb as AudioEffect>::parameter_changed(state, id, ParamValue::from_wire(kind, value))) }
+}
+
+/// Apply the observed response file (its `file` pointer), by the id `observe_sample` returned. `present != 0`
+/// means a resident `handle`, `0` means the pointer is unbound.
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn sample_changed(state_ptr: usize, id: u32, handle: u32, present: u32) {
+    unsafe {
+        abi::with_state(state_ptr, |state: &mut ConvolutionReverbState| {
+            if id == state.sample_id {
+                state.sample = if present != 0 {Some(handle)} else {None};
+            }
+        })
+    }
+}
+
+/// Parity probe: the REAL value stored for a UNIT automation value, ids in `init` bind order (the `param` slots).
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn map_parameter(id: u32, unit: f32) -> f32 {
+    let value = ParamValue::Unit(unit);
+    match id as usize {
+        param::PRE_DELAY => float_value(value, &PRE_DELAY_MAPPING),
+        param::START | param::END => float_value(value, &UNIPOLAR),
+        param::STRETCH => float_value(value, &STRETCH_MAPPING),
+        param::WET | param::DRY => float_value(value, &GAIN_MAPPING),
+        _ => f32::NAN
+    }
+}
+
+/// Transport STOP: clear the convolution history and the pre-delay lines so no tail rings into the next
+/// playback (the response spectra stay).
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn reset(state_ptr: usize) {
+    unsafe { abi::with_state(state_ptr, <ConvolutionReverb as AudioEffect>::reset) }
+}
+
+#[cfg(test)]
+mod tests {
+    //! The reverb DSP, driven directly with a fabricated resident response (`resolve_sample` has no host on
+    //! native). The state and its rate-sized tail live in one zeroed, 8-aligned buffer, like the engine's.
+    use super::*;
+
+    const SR: f32 = 48_000.0;
+
+    struct Instance {
+        memory: Vec<u64>
+    }
+
+    impl Instance {
+        fn new() -> Self {
+            let memory = vec![0u64; (state_size(SR) as usize).div_ceil(8)];
+            let mut instance = Instance {memory};
+            let state = instance.state();
+            state.sample_rate = SR;
+            state.capacity = capacity(SR);
+            state.pre_size = pre_delay_size(SR);
+            state.convolver = Convolver::new(state.capacity);
+            state.sample = Some(1);
+            state.pre_delay = 0.001;
+            state.end = 1.0;
+            state.stretch = 1.0;
+            state.wet = 1.0;
+            instance
+        }
+
+        fn state(&mut self) -> &mut ConvolutionReverbState {
+            unsafe { &mut *(self.memory.as_mut_ptr() as *mut ConvolutionReverbState) }
+        }
+
+        // Run `input` (both channels) through in quanta and return the left output.
+        fn run(&mut self, sample: Option<&SampleRef>, input: &[f32]) -> Vec<f32> {
+            let mut result = Vec::with_capacity(input.len());
+            let (mut left, mut right) = ([0.0f32; RENDER_QUANTUM], [0.0f32; RENDER_QUANTUM]);
+            for chunk in input.chunks(RENDER_QUANTUM) {
+                ConvolutionReverb::dsp(self.state(), sample, [chunk, chunk], [&mut left, &mut right], 0, chunk.len());
+                result.extend_from_slice(&left[..chunk.len()]);
+            }
+            result
+        }
+    }
+
+    fn response(frames: &[f32], sample_rate: f32) -> SampleRef {
+        SampleRef {frames_ptr: frames.as_ptr() as usize, frame_count: frames.len() as u32, channel_count: 1, sample_rate}
+    }
+
+    fn impulse(len: usize) -> Vec<f32> {
+        let mut input = vec![0.0f32; len];
+        input[0] = 1.0;
+        input
+    }
+
+    #[test]
+    fn an_impulse_returns_the_normalised_response_after_the_pre_delay() {
+        // a two-tap response (1 then 0.5, 1000 frames apart): unit energy scales it by 1 / sqrt(1.25)
+        let mut frames = vec![0.0f32; 2000];
+        frames[0] = 1.0;
+        frames[1000] = 0.5;
+        let file = response(&frames, SR);
+        let mut instance = Instance::new();
+        instance.state().pre_delay = 0.01; // 480 frames, 128 of them the convolver's own latency
+        let output = instance.run(Some(&file), &impulse(4096));
+        let scale = 1.0 / 1.25f32.sqrt();
+        assert!((output[480] - scale).abs() < 1e-4, "first tap {}", output[480]);
+        assert!((output[1480] - 0.5 * scale).abs() < 1e-4, "second tap {}", output[1480]);
+        let rest: f32 = output.iter().enumerate().filter(|(index, _)| *index != 480 && *index != 1480).map(|(_, sample)| sample.abs()).sum();
+        assert!(rest < 1e-2, "nothing else: {rest}");
+    }
+
+    #[test]
+    fn trim_and_stretch_remap_the_response() {
+        let mut frames = vec![0.0f32; 2000];
+        frames[0] = 1.0;
+        frames[1000] = 0.5;
+        let file = response(&frames, SR);
+        // trimming the first half away leaves the second tap alone, at the start
+        let mut trimmed = Instance::new();
+        trimmed.state().start = 0.5;
+        let output = trimmed.run(Some(&file), &impulse(4096));
+        assert!((output[LATENCY] - 1.0).abs() < 1e-4, "the second tap, normalised to unit energy: {}", output[LATENCY]);
+        // a file at half the engine rate, stretched twice, spreads the taps four times apart
+        let file = response(&frames, SR / 2.0);
+        let mut stretched = Instance::new();
+        stretched.state().stretch = 2.0;
+        let output = stretched.run(Some(&file), &impulse(8192));
+        let (first, second) = (output[LATENCY], output[LATENCY + 4000]);
+        assert!(second > 0.1 && (first - 2.0 * second).abs() < 1e-4, "taps at 0 and 4000: {first} / {second}");
+        assert!((output[LATENCY + 1] - 0.75 * first).abs() < 1e-4, "interpolated between the source frames");
+    }
+
+    #[test]
+    fn without_a_resident_file_only_the_dry_signal_passes() {
+        let frames = vec![1.0f32; 100];
+        let file = response(&frames, SR);
+        let mut instance = Instance::new();
+        instance.state().dry = 0.5;
+        let output = instance.run(Some(&file), &impulse(512));
+        assert!(output[200..].iter().any(|sample| *sample != 0.0), "the response rings");
+        let output = instance.run(None, &impulse(512));
+        assert_eq!(output[0], 0.5);
+        assert!(output[1..].iter().all(|sample| *sample == 0.0), "the response dropped with the file");
+        assert!(instance.state().response.is_none());
+    }
+
+    #[test]
+    fn a_long_response_builds_over_several_blocks() {
+        let frames: Vec<f32> = (0..96_000).map(|index| if index % 1000 == 0 {1.0} else {0.0}).collect();
+        let file = response(&frames, SR);
+        let mut instance = Instance::new();
+        instance.run(Some(&file), &[0.0; RENDER_QUANTUM]);
+        assert!(instance.state().measuring, "96000 frames take more than one block to measure");
+        instance.run(Some(&file), &vec![0.0; 24 * RENDER_QUANTUM]);
+        assert!(!instance.state().measuring && !instance.state().convolver.is_loaded(),
+            "750 partitions take more than one block to build");
+        instance.run(Some(&file), &vec![0.0; 24 * RENDER_QUANTUM]);
+        assert!(instance.state().convolver.is_loaded());
+        <ConvolutionReverb as AudioEffect>::reset(instance.state());
+        assert!(instance.state().convolver.is_loaded(), "a transport stop keeps the response");
+    }
+
+    #[test]
+    fn only_the_capped_response_is_measured_over_several_blocks() {
+        // a unit tap at 0 s and a loud one at 5 s, past the 4 s cap: the loud one neither plays nor normalises
+        let mut frames = vec![0.0f32; 10 * SR as usize];
+        frames[0] = 1.0;
+        frames[5 * SR as usize] = 8.0;
+        let file = response(&frames, SR);
+        let mut instance = Instance::new();
+        instance.run(Some(&file), &[0.0; RENDER_QUANTUM]);
+        assert!(instance.state().measuring, "4 s of source take more than one block to measure");
+        assert_eq!(instance.state().mapping.end, (MAX_IR_SECONDS * SR) as f64);
+        instance.run(Some(&file), &vec![0.0; 200 * RENDER_QUANTUM]);
+        assert!(!instance.state().measuring && instance.state().convolver.is_loaded());
+        let output = instance.run(Some(&file), &impulse(RENDER_QUANTUM * 2));
+        assert!((output[LATENCY] - 1.0).abs() < 1e-4, "normalised by the capped part alone: {}", output[LATENCY]);
+    }
+}
@@ -119,34 +119,34 @@ impl AudioEffect for Dattorro {
     }
 }
 
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn kind() -> u32 {
     abi::DEVICE_KIND_AUDIO_EFFECT
 }
 
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn state_size(_sample_rate: f32) -> u32 {
     core::mem::size_of::<DattorroState>() as u32
 }
 
-#[no_mangle]
-pub extern "C" fn process(desc_ptr: u32) {
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn process(desc_ptr: usize) {
     let ports = unsafe { Ports::<DattorroState>::from_descriptor(desc_ptr) };
     abi::render_effect::<Dattorro>(ports);
 }
 
-#[no_mangle]
-pub extern "C" fn init(state_ptr: u32, sample_rate: f32) {
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn init(state_ptr: usize, sample_rate: f32) {
     unsafe { abi::with_state(state_ptr, |state| <Dattorro as AudioEffect>::init(state, sample_rate)) }
 }
 
-#[no_mangle]
-pub extern "C" fn parameter_changed(state_ptr: u32, id: u32, kind: u32, value: f32) {
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn parameter_changed(state_ptr: usize, id: u32, kind: u32, value: f32) {
     unsafe { abi::with_state(state_ptr, |state| <Dattorro as AudioEffect>::parameter_changed(state, id, ParamValue::from_wire(kind, value))) }
 }
 
 /// Parity probe: the REAL value stored for a UNIT automation value, ids in `init` bind order.
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn map_parameter(id: u32, unit: f32) -> f32 {
     let value = ParamValue::Unit(unit);
     match id {
@@ -158,7 +158,7 @@ pub extern "C" fn map_parameter(id: u32, unit: f32) -> f32 {
 }
 
 /// Transport STOP: the reverb tail dies (mirrors the TS processor's `reset`).
-#[no_mangle]
-pub extern "C" fn reset(state_ptr: u32) {
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn reset(state_ptr: usize) {
     unsafe { abi::with_state(state_ptr, <Dattorro as AudioEffect>::reset) }
 }
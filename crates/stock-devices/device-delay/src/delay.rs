@@ -82,8 +82,7 @@ impl PreDelay {
     }
 
     fn process_interpolate(&mut self, target: &mut [f32], source: &[f32], buffer: &mut [f32], from: usize, to: usize) {
-        let size = buffer.len();
-        let mask = size - 1;
+        let mask = buffer.len() - 1;
         let mut write_position = self.write_position;
         for index in from..to {
             if self.alpha_position > 0 {
@@ -94,14 +93,7 @@ impl PreDelay {
                 self.interpolating = false;
             }
             buffer[write_position] = source[index];
-            let mut read_position = write_position as f64 - self.current_offset;
-            if read_position < 0.0 {
-                read_position += size as f64;
-            }
-            let read_int = libm::floor(read_position) as usize;
-            let alpha = (read_position - read_int as f64) as f32;
-            let read0 = buffer[read_int & mask];
-            target[index] = read0 + alpha * (buffer[(read_int + 1) & mask] - read0);
+            target[index] = dsp::delay_line::read_fractional(buffer, write_position, self.current_offset);
             write_position = (write_position + 1) & mask;
         }
         self.write_position = write_position;
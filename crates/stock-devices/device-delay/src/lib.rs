@@ -218,44 +218,44 @@ impl AudioEffect for Delay {
 // ---- The device ABI: shared with the engine, called wasm-to-wasm. ----
 
 /// What the host wires this device as (read at load): an audio effect that transforms its input.
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn kind() -> u32 {
     abi::DEVICE_KIND_AUDIO_EFFECT
 }
 
 /// Bytes the engine must allocate (zeroed): the fixed header plus the four pow2 delay buffers, whose size
 /// scales with the sample rate (the rate-sized tail).
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn state_size(sample_rate: f32) -> u32 {
     (core::mem::size_of::<DelayState>() + 4 * delay_size(sample_rate) * core::mem::size_of::<f32>()) as u32
 }
 
-#[no_mangle]
-pub extern "C" fn process(desc_ptr: u32) {
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn process(desc_ptr: usize) {
     let ports = unsafe { Ports::<DelayState>::from_descriptor(desc_ptr) };
     abi::render_effect::<Delay>(ports);
 }
 
 /// Boot hook: size the delay buffers from the sample rate, build the DSP, and bind the parameters.
-#[no_mangle]
-pub extern "C" fn init(state_ptr: u32, sample_rate: f32) {
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn init(state_ptr: usize, sample_rate: f32) {
     unsafe { abi::with_state(state_ptr, |state| <Delay as AudioEffect>::init(state, sample_rate)) }
 }
 
 /// Transport STOP: zero the delay lines so the echo tail does not resume on the next playback.
-#[no_mangle]
-pub extern "C" fn reset(state_ptr: u32) {
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn reset(state_ptr: usize) {
     unsafe { abi::with_state(state_ptr, |state| <Delay as AudioEffect>::reset(state)) }
 }
 
 /// Apply a parameter value the host resolved (initial / edit / automation), by the id `init` got back.
-#[no_mangle]
-pub extern "C" fn parameter_changed(state_ptr: u32, id: u32, kind: u32, value: f32) {
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn parameter_changed(state_ptr: usize, id: u32, kind: u32, value: f32) {
     unsafe { abi::with_state(state_ptr, |state| <Delay as AudioEffect>::parameter_changed(state, id, ParamValue::from_wire(kind, value))) }
 }
 
 /// Parity probe: the REAL value stored for a UNIT automation value, ids in `init` bind order (the `param` slots).
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn map_parameter(id: u32, unit: f32) -> f32 {
     let value = ParamValue::Unit(unit);
     match id as usize {
@@ -0,0 +1,424 @@
+//! The FILTER, a multimode AUDIO EFFECT (`FilterDeviceBox`): a resonant filter whose cutoff is swept per
+//! sample by a tempo-synced LFO and an envelope follower, behind an optional drive stage. The low-pass,
+//! high-pass, band-pass and notch modes are a 12 dB/oct `dsp::biquad::ModulatedBiquad`; the ladder mode is
+//! the 24 dB/oct `dsp::ladder::Ladder`, whose resonance runs into self-oscillation at the top of the range.
+//!
+//! The cutoff is kept as a UNIT value (as the Vaporisateur does) and the modulation adds to it: the LFO
+//! (`dsp::lfo::Lfo`, one of the four classic shapes) scaled by its depth, and the envelope (a peak follower
+//! with attack / release times, on the sidechain when one is connected, else on the main input) scaled by
+//! its amount. Both amounts are bipolar, so either can close the filter instead of opening it. While the
+//! transport runs the LFO is re-locked to the song position at every block (a period is one rate fraction),
+//! stopped it runs free at the same frequency.
+//!
+//! Drive is a pre-filter gain (0..24 dB) into a `tanh` soft clip; at 0 dB the stage is bypassed.
+//!
+//! Parameters: mode `[10]` (see `MODE_*`), cutoff `[11]` (Hz, exponential), resonance `[12]` (Q; the ladder
+//! maps its range onto the feedback), drive `[13]` (dB), lfo-rate `[14]` (an index into `RATE_FRACTIONS`),
+//! lfo-shape `[15]`, lfo-depth `[16]`, env-amount `[17]` (both bipolar, in unit cutoff), env-attack `[18]`
+//! and env-release `[19]` (ms), and the SIDE-CHAIN pointer `[30]`.
+//!
+//! Exports: `kind()` (audio effect), `state_size()`, `process(desc_ptr)`, `init(state_ptr, sample_rate)`,
+//! `parameter_changed(...)`, `map_parameter(...)`, `reset(state_ptr)`.
+
+#![cfg_attr(target_family = "wasm", no_std)]
+
+#[cfg(target_family = "wasm")]
+use core::panic::PanicInfo;
+use abi::{float_value, int_value, AudioEffect, Block, ParamValue, Ports, MAIN_INPUT};
+use dsp::biquad::{BiquadResponse, ModulatedBiquad};
+use dsp::ladder::{Ladder, MAX_FEEDBACK};
+use dsp::lfo::Lfo;
+use dsp::osc::ClassicWaveform;
+use dsp::{ppqn, RENDER_QUANTUM};
+use math::db_to_gain;
+use math::value_mapping::{Exponential, Linear, LinearInteger, ValueMapping};
+
+#[cfg(target_family = "wasm")]
+#[panic_handler]
+fn panic(info: &PanicInfo) -> ! {
+    abi::panic_to_host(info) // deposit the message in the engine's panic buffer, then trap (never a silent hang)
+}
+
+const MODE_FIELD: [u16; 1] = [10];
+const CUTOFF_FIELD: [u16; 1] = [11];
+const RESONANCE_FIELD: [u16; 1] = [12];
+const DRIVE_FIELD: [u16; 1] = [13];
+const LFO_RATE_FIELD: [u16; 1] = [14];
+const LFO_SHAPE_FIELD: [u16; 1] = [15];
+const LFO_DEPTH_FIELD: [u16; 1] = [16];
+const ENV_AMOUNT_FIELD: [u16; 1] = [17];
+const ENV_ATTACK_FIELD: [u16; 1] = [18];
+const ENV_RELEASE_FIELD: [u16; 1] = [19];
+const SIDE_CHAIN_FIELD: [u16; 1] = [30];
+
+// WASM CONTRACT: the mode-index values (the first four are `BiquadResponse::from_index`).
+pub const MODE_LOWPASS: i32 = 0;
+pub const MODE_HIGHPASS: i32 = 1;
+pub const MODE_BANDPASS: i32 = 2;
+pub const MODE_NOTCH: i32 = 3;
+pub const MODE_LADDER: i32 = 4;
+
+// The Tidal rate table (descending, so the index is the lfo-rate value): one LFO period per fraction.
+const RATE_FRACTIONS: [(i32, i32); 17] = [
+    (1, 1), (1, 2), (1, 3), (1, 4), (3, 16), (1, 6), (1, 8), (3, 32), (1, 12),
+    (1, 16), (3, 64), (1, 24), (1, 32), (1, 48), (1, 64), (1, 96), (1, 128)
+];
+
+const MIN_CUTOFF: f64 = 20.0;
+const MAX_CUTOFF: f64 = 20_000.0;
+
+const MODE_MAPPING: LinearInteger = LinearInteger {min: 0, max: MODE_LADDER};
+const CUTOFF_MAPPING: Exponential = Exponential {min: MIN_CUTOFF as f32, max: MAX_CUTOFF as f32};
+const RESONANCE_MAPPING: Exponential = Exponential {min: 0.1, max: 10.0};
+const DRIVE_MAPPING: Linear = Linear {min: 0.0, max: 24.0};
+const LFO_RATE_MAPPING: LinearInteger = LinearInteger {min: 0, max: RATE_FRACTIONS.len() as i32 - 1};
+const LFO_SHAPE_MAPPING: LinearInteger = LinearInteger {min: 0, max: 3};
+const BIPOLAR: Linear = Linear::bipolar();
+const ENV_ATTACK_MAPPING: Exponential = Exponential {min: 0.1, max: 100.0};
+const ENV_RELEASE_MAPPING: Exponential = Exponential {min: 1.0, max: 1000.0};
+
+/// One-pole smoothing coefficient `exp(-1 / (sample_rate * seconds))`.
+fn coefficient(sample_rate: f32, seconds: f32) -> f32 {
+    libm::expf(-1.0 / (sample_rate * seconds))
+}
+
+/// Resolve the cutoff as a UNIT value: the automation value directly, or a real Hz mapped back (the filters
+/// map the unit onto Hz themselves).
+fn cutoff_unit(value: ParamValue) -> f32 {
+    match value {
+        ParamValue::Unit(unit) => unit,
+        ParamValue::Float(real) => CUTOFF_MAPPING.x(real),
+        ParamValue::Int(real) => CUTOFF_MAPPING.x(real as f32),
+        ParamValue::Bool(flag) => if flag {1.0} else {0.0}
+    }
+}
+
+/// The filter's per-instance state, from the engine-allocated (zeroed) block: a filter pair per topology, the
+/// LFO, the envelope follower, the real parameter values (the follower coefficients are derived lazily, on
+/// `dirty`), the per-quantum cutoff / LFO scratch, and the parameter / sidechain ids.
+pub struct FilterState {
+    biquads: [ModulatedBiquad; 2],
+    ladders: [Ladder; 2],
+    lfo: Lfo,
+    cutoffs: [f32; RENDER_QUANTUM],
+    lfo_values: [f32; RENDER_QUANTUM],
+    sample_rate: f32,
+    mode: i32,
+    cutoff: f32, // unit
+    resonance: f32, // Q
+    drive_db: f32,
+    lfo_rate: i32,
+    lfo_shape: i32,
+    lfo_depth: f32,
+    env_amount: f32,
+    env_attack_ms: f32,
+    env_release_ms: f32,
+    attack_coeff: f32,
+    release_coeff: f32,
+    envelope: f32,
+    dirty: bool,
+    mode_id: u32,
+    cutoff_id: u32,
+    resonance_id: u32,
+    drive_id: u32,
+    lfo_rate_id: u32,
+    lfo_shape_id: u32,
+    lfo_depth_id: u32,
+    env_amount_id: u32,
+    env_attack_id: u32,
+    env_release_id: u32,
+    sidechain_id: u32
+}
+
+/// The DSP, plugged into the SDK's `AudioEffect` template ([`abi::render_effect`]).
+pub struct Filter;
+
+impl AudioEffect for Filter {
+    type State = FilterState;
+
+    fn init(state: &mut FilterState, sample_rate: f32) {
+        state.sample_rate = sample_rate;
+        state.biquads = [ModulatedBiquad::new(), ModulatedBiquad::new()];
+        state.ladders = [Ladder::new(), Ladder::new()];
+        state.lfo = Lfo::new(sample_rate);
+        // box defaults; the engine pushes the real values right after via `parameter_changed`
+        state.mode = MODE_LOWPASS;
+        state.cutoff = CUTOFF_MAPPING.x(1000.0);
+        state.resonance = dsp::biquad::BUTTERWORTH_Q as f32;
+        state.drive_db = 0.0;
+        state.lfo_rate = 3;
+        state.lfo_shape = 0;
+        state.lfo_depth = 0.0;
+        state.env_amount = 0.0;
+        state.env_attack_ms = 5.0;
+        state.env_release_ms = 100.0;
+        state.envelope = 0.0;
+        state.dirty = true;
+        state.mode_id = abi::bind_parameter(&MODE_FIELD);
+        state.cutoff_id = abi::bind_parameter(&CUTOFF_FIELD);
+        state.resonance_id = abi::bind_parameter(&RESONANCE_FIELD);
+        state.drive_id = abi::bind_parameter(&DRIVE_FIELD);
+        state.lfo_rate_id = abi::bind_parameter(&LFO_RATE_FIELD);
+        state.lfo_shape_id = abi::bind_parameter(&LFO_SHAPE_FIELD);
+        state.lfo_depth_id = abi::bind_parameter(&LFO_DEPTH_FIELD);
+        state.env_amount_id = abi::bind_parameter(&ENV_AMOUNT_FIELD);
+        state.env_attack_id = abi::bind_parameter(&ENV_ATTACK_FIELD);
+        state.env_release_id = abi::bind_parameter(&ENV_RELEASE_FIELD);
+        state.sidechain_id = abi::bind_sidechain(&SIDE_CHAIN_FIELD);
+    }
+
+    fn process_audio(state: &mut FilterState, output: [&mut [f32]; 2], block: &Block) {
+        let Some(input) = abi::resolve_input(MAIN_INPUT) else {return};
+        // Follow the sidechain when it is connected, else the main input.
+        let detector = abi::resolve_input(state.sidechain_id).unwrap_or(input);
+        let transporting = block.flags.0 & abi::BlockFlags::TRANSPORTING != 0;
+        let [out_left, out_right] = output;
+        Filter::dsp(state, input.left(), input.right(), detector.left(), detector.right(), out_left, out_right,
+            block.s0 as usize, block.s1 as usize, transporting.then_some(block.p0), block.bpm);
+    }
+
+    fn parameter_changed(state: &mut FilterState, id: u32, value: ParamValue) {
+        if id == state.mode_id {
+            state.mode = int_value(value, &MODE_MAPPING);
+        } else if id == state.cutoff_id {
+            state.cutoff = cutoff_unit(value);
+        } else if id == state.resonance_id {
+            state.resonance = float_value(value, &RESONANCE_MAPPING);
+        } else if id == state.drive_id {
+            state.drive_db = float_value(value, &DRIVE_MAPPING);
+        } else if id == state.lfo_rate_id {
+            state.lfo_rate = int_value(value, &LFO_RATE_MAPPING);
+        } else if id == state.lfo_shape_id {
+            state.lfo_shape = int_value(value, &LFO_SHAPE_MAPPING);
+        } else if id == state.lfo_depth_id {
+            state.lfo_depth = float_value(value, &BIPOLAR);
+        } else if id == state.env_amount_id {
+            state.env_amount = float_value(value, &BIPOLAR);
+        } else if id == state.env_attack_id {
+            state.env_attack_ms = float_value(value, &ENV_ATTACK_MAPPING);
+            state.dirty = true;
+        } else if id == state.env_release_id {
+            state.env_release_ms = float_value(value, &ENV_RELEASE_MAPPING);
+            state.dirty = true;
+        }
+    }
+
+    fn reset(state: &mut FilterState) {
+        for filter in &mut state.biquads {
+            filter.reset();
+        }
+        for filter in &mut state.ladders {
+            filter.reset();
+        }
+        state.lfo.reset();
+        state.envelope = 0.0;
+    }
+}
+
+impl Filter {
+    /// The pure per-range DSP (unit-tested directly) over `[s0, s1)` in absolute quantum coordinates. `det_*`
+    /// feeds the envelope follower; `position` is the song position at `s0` while the transport runs (the LFO
+    /// locks to it), `None` when stopped (the LFO runs free).
+    #[allow(clippy::too_many_arguments)]
+    fn dsp(state: &mut FilterState, in_left: &[f32], in_right: &[f32], det_left: &[f32], det_right: &[f32],
+           out_left: &mut [f32], out_right: &mut [f32], s0: usize, s1: usize, position: Option<f64>, bpm: f32) {
+        if state.dirty {
+            state.attack_coeff = coefficient(state.sample_rate, state.env_attack_ms * 0.001);
+            state.release_coeff = coefficient(state.sample_rate, state.env_release_ms * 0.001);
+            state.dirty = false;
+        }
+        if state.lfo_depth != 0.0 {
+            let (numerator, denominator) = RATE_FRACTIONS[state.lfo_rate.clamp(0, RATE_FRACTIONS.len() as i32 - 1) as usize];
+            let period = ppqn::from_signature(numerator, denominator);
+            if let Some(position) = position {
+                state.lfo.set_phase(position / period);
+            }
+            let frequency = 1.0 / ppqn::pulses_to_seconds(period, bpm);
+            state.lfo.fill(&mut state.lfo_values, ClassicWaveform::from_index(state.lfo_shape), frequency as f32, s0, s1);
+        } else {
+            state.lfo_values[s0..s1].fill(0.0);
+        }
+        for index in s0..s1 {
+            let level = det_left[index].abs().max(det_right[index].abs()).min(1.0);
+            let coeff = if level > state.envelope { state.attack_coeff } else { state.release_coeff };
+            state.envelope = coeff * state.envelope + (1.0 - coeff) * level;
+            let modulated = state.cutoff + state.lfo_values[index] * state.lfo_depth + state.envelope * state.env_amount;
+            state.cutoffs[index] = modulated.clamp(0.0, 1.0);
+        }
+        let drive = db_to_gain(state.drive_db);
+        for (input, output) in [(in_left, &mut *out_left), (in_right, &mut *out_right)] {
+            if state.drive_db > 0.0 {
+                for index in s0..s1 {
+                    output[index] = libm::tanhf(input[index] * drive);
+                }
+            } else {
+                output[s0..s1].copy_from_slice(&input[s0..s1]);
+            }
+        }
+        let sample_rate = state.sample_rate;
+        if state.mode == MODE_LADDER {
+            let feedback = MAX_FEEDBACK * RESONANCE_MAPPING.x(state.resonance) as f64;
+            for (filter, output) in state.ladders.iter_mut().zip([out_left, out_right]) {
+                filter.process(output, &state.cutoffs, feedback, MIN_CUTOFF, MAX_CUTOFF, sample_rate, s0, s1);
+            }
+        } else {
+            let response = BiquadResponse::from_index(state.mode);
+            for (filter, output) in state.biquads.iter_mut().zip([out_left, out_right]) {
+                filter.set_response(response);
+                filter.process(output, &state.cutoffs, state.resonance as f64, 1, MIN_CUTOFF, MAX_CUTOFF, sample_rate, s0, s1);
+            }
+        }
+    }
+}
+
+// ---- The device ABI: shared with the engine, called wasm-to-wasm. ----
+
+/// What the host wires this device as (read at load): an audio effect that transforms its input.
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn kind() -> u32 {
+    abi::DEVICE_KIND_AUDIO_EFFECT
+}
+
+/// Bytes the engine must allocate (zeroed) for one instance's state block.
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn state_size(_sample_rate: f32) -> u32 {
+    core::mem::size_of::<FilterState>() as u32
+}
+
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn process(desc_ptr: usize) {
+    let ports = unsafe { Ports::<FilterState>::from_descriptor(desc_ptr) };
+    abi::render_effect::<Filter>(ports);
+}
+
+/// Boot hook: bind this device's parameters + its sidechain port with the host, and stash the sample rate.
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn init(state_ptr: usize, sample_rate: f32) {
+    unsafe { abi::with_state(state_ptr, |state| <Filter as AudioEffect>::init(state, sample_rate)) }
+}
+
+/// Apply a parameter value the host resolved (initial / edit / automation), by the id `init` got back.
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn parameter_changed(state_ptr: usize, id: u32, kind: u32, value: f32) {
+    unsafe { abi::with_state(state_ptr, |state| <Filter as AudioEffect>::parameter_changed(state, id, ParamValue::from_wire(kind, value))) }
+}
+
+/// Parity probe: the REAL value stored for a UNIT automation value, ids in `init` bind order.
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn map_parameter(id: u32, unit: f32) -> f32 {
+    let value = ParamValue::Unit(unit);
+    match id {
+        0 => int_value(value, &MODE_MAPPING) as f32,
+        1 => float_value(value, &CUTOFF_MAPPING),
+        2 => float_value(value, &RESONANCE_MAPPING),
+        3 => float_value(value, &DRIVE_MAPPING),
+        4 => int_value(value, &LFO_RATE_MAPPING) as f32,
+        5 => int_value(value, &LFO_SHAPE_MAPPING) as f32,
+        6 | 7 => float_value(value, &BIPOLAR),
+        8 => float_value(value, &ENV_ATTACK_MAPPING),
+        9 => float_value(value, &ENV_RELEASE_MAPPING),
+        _ => f32::NAN
+    }
+}
+
+/// Transport STOP: clear the filter history, the LFO phase and the envelope.
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn reset(state_ptr: usize) {
+    unsafe { abi::with_state(state_ptr, <Filter as AudioEffect>::reset) }
+}
+
+#[cfg(test)]
+mod tests {
+    //! The filter DSP, driven directly (`resolve_input` has no host on native).
+    use super::*;
+
+    const SR: f32 = 48_000.0;
+
+    fn state(mode: i32, cutoff_hz: f32) -> FilterState {
+        let mut state: FilterState = unsafe { core::mem::zeroed() };
+        state.sample_rate = SR;
+        state.lfo = Lfo::new(SR);
+        state.mode = mode;
+        state.cutoff = CUTOFF_MAPPING.x(cutoff_hz);
+        state.resonance = dsp::biquad::BUTTERWORTH_Q as f32;
+        state.lfo_rate = 3;
+        state.env_attack_ms = 1.0;
+        state.env_release_ms = 50.0;
+        state.dirty = true;
+        state
+    }
+
+    fn tone(frequency: f32, len: usize) -> Vec<f32> {
+        (0..len).map(|index| 0.5 * libm::sinf(core::f32::consts::TAU * frequency * index as f32 / SR)).collect()
+    }
+
+    // Run `input` through in quanta (detecting on `detector`) and return the left output.
+    fn run(state: &mut FilterState, input: &[f32], detector: &[f32], transporting: bool) -> Vec<f32> {
+        let mut output = Vec::with_capacity(input.len());
+        let (mut left, mut right) = ([0.0f32; RENDER_QUANTUM], [0.0f32; RENDER_QUANTUM]);
+        for (index, (chunk, det)) in input.chunks(RENDER_QUANTUM).zip(detector.chunks(RENDER_QUANTUM)).enumerate() {
+            // 120 bpm at 48 kHz: a quantum is 128 / 48000 s = 5.12 pulses
+            let position = transporting.then_some(index as f64 * ppqn::samples_to_pulses(RENDER_QUANTUM as f64, 120.0, SR));
+            Filter::dsp(state, chunk, chunk, det, det, &mut left, &mut right, 0, chunk.len(), position, 120.0);
+            output.extend_from_slice(&left[..chunk.len()]);
+        }
+        output
+    }
+
+    fn rms(samples: &[f32]) -> f32 {
+        libm::sqrtf(samples.iter().map(|sample| sample * sample).sum::<f32>() / samples.len() as f32)
+    }
+
+    #[test]
+    fn each_mode_shapes_a_low_and_a_high_tone() {
+        // a 100 Hz and a 5 kHz tone around a 1 kHz cutoff: (low passes, high passes) per mode
+        let expectations = [(MODE_LOWPASS, true, false), (MODE_HIGHPASS, false, true), (MODE_BANDPASS, false, false),
+            (MODE_NOTCH, true, true), (MODE_LADDER, true, false)];
+        for (mode, low_passes, high_passes) in expectations {
+            for (frequency, passes) in [(100.0, low_passes), (5000.0, high_passes)] {
+                let input = tone(frequency, 9600);
+                let output = run(&mut state(mode, 1000.0), &input, &input, false);
+                let gain = rms(&output[4800..]) / rms(&input[4800..]);
+                assert_eq!(gain > 0.5, passes, "mode {mode} at {frequency} Hz: gain {gain}");
+            }
+        }
+    }
+
+    #[test]
+    fn the_envelope_opens_the_filter_on_the_sidechain() {
+        // a closed low-pass over a 5 kHz tone, opened by a loud sidechain in the second half
+        let input = tone(5000.0, 19_200);
+        let detector: Vec<f32> = (0..19_200).map(|index| if index < 9600 { 0.0 } else { 1.0 }).collect();
+        let mut state = state(MODE_LOWPASS, 200.0);
+        state.env_amount = 1.0;
+        let output = run(&mut state, &input, &detector, false);
+        assert!(rms(&output[4800..9600]) < 0.01, "closed while the sidechain is quiet");
+        assert!(rms(&output[14_400..]) > 0.25, "open while it is loud");
+    }
+
+    #[test]
+    fn the_lfo_sweeps_the_cutoff_locked_to_the_song_position() {
+        // a quarter-note square LFO at 120 bpm flips every 12000 samples between a closed and an open filter
+        let input = tone(5000.0, 48_000);
+        let mut state = state(MODE_LOWPASS, 1000.0);
+        state.lfo_shape = 3;
+        state.lfo_depth = 0.4;
+        let output = run(&mut state, &input, &input, true);
+        let open = rms(&output[2000..11_000]);
+        let closed = rms(&output[14_000..23_000]);
+        assert!(open > 4.0 * closed, "square LFO: {open} vs {closed}");
+        assert!(rms(&output[26_000..35_000]) > 4.0 * closed, "the next period opens again");
+    }
+
+    #[test]
+    fn drive_saturates_before_the_filter() {
+        let input: Vec<f32> = vec![0.5; 4800];
+        let mut clean = state(MODE_LOWPASS, 20_000.0);
+        let mut driven = state(MODE_LOWPASS, 20_000.0);
+        driven.drive_db = 24.0;
+        let clean = run(&mut clean, &input, &input, false);
+        let driven = run(&mut driven, &input, &input, false);
+        assert!((clean[4799] - 0.5).abs() < 0.01);
+        assert!(driven[4799] > 0.99 && driven[4799] <= 1.01, "tanh(0.5 * 15.8) is ~1: {}", driven[4799]);
+    }
+}
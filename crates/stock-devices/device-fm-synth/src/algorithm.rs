@@ -0,0 +1,44 @@
+//! The operator routings: the eight classic four-operator FM algorithms. Operators are numbered 0..4 here
+//! (1..4 on the panel); operator 3 is the one with self-feedback. Every modulator has a HIGHER index than the
+//! operator it modulates, so a voice evaluates the operators from 3 down to 0 and each one's modulators are
+//! already computed.
+
+use crate::OPERATORS;
+
+/// One routing: `modulators[op]` is the bit set of operators whose output phase-modulates `op`, `carriers`
+/// the bit set of operators heard at the output.
+#[derive(Clone, Copy)]
+pub struct Algorithm {
+    pub modulators: [u8; OPERATORS],
+    pub carriers: u8
+}
+
+/// Panel numbers in the comments: `a -> b` is "a modulates b", `+` sums into the same target.
+pub const ALGORITHMS: [Algorithm; 8] = [
+    Algorithm {modulators: [0b0010, 0b0100, 0b1000, 0], carriers: 0b0001}, // 4 -> 3 -> 2 -> 1
+    Algorithm {modulators: [0b0010, 0b1100, 0, 0], carriers: 0b0001},      // (3 + 4) -> 2 -> 1
+    Algorithm {modulators: [0b1010, 0b0100, 0, 0], carriers: 0b0001},      // (3 -> 2 + 4) -> 1
+    Algorithm {modulators: [0b0110, 0, 0b1000, 0], carriers: 0b0001},      // (2 + 4 -> 3) -> 1
+    Algorithm {modulators: [0b0010, 0, 0b1000, 0], carriers: 0b0101},      // 2 -> 1, 4 -> 3
+    Algorithm {modulators: [0b1000, 0b1000, 0b1000, 0], carriers: 0b0111}, // 4 -> (1, 2, 3)
+    Algorithm {modulators: [0, 0, 0b1000, 0], carriers: 0b0111},           // 4 -> 3, 1, 2
+    Algorithm {modulators: [0, 0, 0, 0], carriers: 0b1111}                 // 1, 2, 3, 4 (additive)
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn modulators_feed_lower_operators_and_every_algorithm_is_heard() {
+        for (index, algorithm) in ALGORITHMS.iter().enumerate() {
+            assert_ne!(algorithm.carriers, 0, "algorithm {index} has a carrier");
+            for (op, modulators) in algorithm.modulators.iter().enumerate() {
+                assert_eq!(modulators & ((1 << (op + 1)) - 1), 0, "algorithm {index}: operator {op} is modulated by itself or a lower one");
+            }
+            // every operator is heard or modulates something
+            let used = algorithm.modulators.iter().fold(algorithm.carriers, |used, modulators| used | modulators);
+            assert_eq!(used, 0b1111, "algorithm {index} leaves an operator idle");
+        }
+    }
+}
@@ -0,0 +1,399 @@
+//! The FM synth, a polyphonic INSTRUMENT device (`FMSynthDeviceBox`): four sine operators, each with its own
+//! ratio / fine tune, level, ADSR envelope and velocity / key-scaling sensitivities, routed through one of
+//! eight selectable algorithms (see [`algorithm`]), operator 4 feeding back into itself. Notes run through the
+//! shared `voicing` framework like the Vaporisateur (polyphonic or monophonic with glide, the pitch wheel, the
+//! sustain pedal and per-note expressions); the mix is brick-wall limited.
+//!
+//! Heap-free: the voices live in the engine-allocated (zeroed) state block. No `Vec`.
+//!
+//! Exports: `kind()` (instrument), `state_size()`, `process(desc_ptr)`, `init(state_ptr, sample_rate)`,
+//! `parameter_changed(...)`, `map_parameter(...)`, `reset(state_ptr)`.
+
+#![cfg_attr(target_family = "wasm", no_std)]
+
+#[cfg(target_family = "wasm")]
+use core::panic::PanicInfo;
+use abi::{float_value, int_value, Block, EventRecord, Instrument, NoteExpression, ParamValue, Ports, SustainPedal};
+use abi::{CC_SUSTAIN, EVENT_CONTROL, EVENT_NOTE_EXPRESSION, EVENT_NOTE_OFF, EVENT_NOTE_ON, EVENT_PITCH_BEND, PITCH_BEND_RANGE};
+use dsp::{midi_to_hz_base, ppqn};
+use math::value_mapping::{Decibel, Exponential, Linear, LinearInteger, Values};
+use math::db_to_gain;
+use voicing::{Voicing, VoicingMode};
+
+pub mod algorithm;
+mod voice;
+use voice::{FmParams, FmVoice};
+
+#[cfg(target_family = "wasm")]
+#[panic_handler]
+fn panic(info: &PanicInfo) -> ! {
+    abi::panic_to_host(info) // deposit the message in the engine's panic buffer, then trap (never a silent hang)
+}
+
+/// The operators per voice (the `operators` array length).
+pub const OPERATORS: usize = 4;
+const POLY_VOICES: usize = 16; // polyphonic voice slots
+const MONO_STACK: usize = 16; // monophonic held-note stack depth
+const SUSTAINED: usize = 64; // note-offs the sustain pedal can hold back
+
+// The FMSynthDeviceBox field key of the operator array.
+const OPERATORS_KEY: u16 = 40;
+
+const VOLUME_MAPPING: Decibel = Decibel::default_volume(); // decibel(-72, -12, 0)
+const ALGORITHM_MAPPING: LinearInteger = LinearInteger {min: 0, max: algorithm::ALGORITHMS.len() as i32 - 1};
+const RATIO_MAPPING: Exponential = Exponential {min: 0.25, max: 16.0};
+const FINE_MAPPING: Linear = Linear {min: -50.0, max: 50.0}; // cents
+const TIME_MAPPING: Exponential = Exponential {min: 0.001, max: 5.0}; // attack / decay / release (seconds)
+const UNIPOLAR: Linear = Linear::unipolar(); // feedback / glide-time / level / sustain / velocity / key-scaling
+const VOICING_MODE_VALUES: [i32; 2] = [0, 1]; // VoicingMode::{Monophonic, Polyphonic}
+
+// The parameter slots, the order this device binds them (`state.ids[INDEX]`).
+mod param {
+    pub const VOLUME: usize = 0;
+    pub const ALGORITHM: usize = 1;
+    pub const FEEDBACK: usize = 2;
+    pub const GLIDE_TIME: usize = 3;
+    pub const VOICING_MODE: usize = 4;
+    pub const COUNT: usize = 5;
+}
+
+// The per-operator field keys inside an `FMSynthOperator`, which double as the `operator_ids` slots (key - 1).
+mod operator {
+    pub const RATIO: u16 = 1;
+    pub const FINE: u16 = 2;
+    pub const LEVEL: u16 = 3;
+    pub const ATTACK: u16 = 4;
+    pub const DECAY: u16 = 5;
+    pub const SUSTAIN: u16 = 6;
+    pub const RELEASE: u16 = 7;
+    pub const VELOCITY: u16 = 8;
+    pub const KEY_SCALING: u16 = 9;
+    pub const COUNT: usize = 9;
+}
+
+/// The device's per-instance state, interpreted from the engine-allocated (zeroed) block: the voicing
+/// dispatcher, the live parameters the voices read, the sustain pedal, the output limiter, the glide time
+/// (pulses), and the bound ids (top-level, then per operator).
+#[repr(C)]
+pub struct FmState {
+    voicing: Voicing<FmVoice, POLY_VOICES, MONO_STACK>,
+    params: FmParams,
+    sustain: SustainPedal<SUSTAINED>,
+    limiter: dsp::simple_limiter::SimpleLimiter,
+    glide_time: f64,
+    ids: [u32; param::COUNT],
+    operator_ids: [[u32; operator::COUNT]; OPERATORS]
+}
+
+/// The DSP, plugged into the SDK's `Instrument` template ([`abi::render_instrument`]).
+pub struct FmSynth;
+
+impl Instrument for FmSynth {
+    type State = FmState;
+
+    fn init(state: &mut FmState, sample_rate: f32) {
+        state.params.sample_rate = sample_rate; // stable for the device's life
+        state.limiter.prepare(sample_rate);
+        state.ids[param::VOLUME] = abi::bind_parameter(&[10]);
+        state.ids[param::ALGORITHM] = abi::bind_parameter(&[11]);
+        state.ids[param::FEEDBACK] = abi::bind_parameter(&[12]);
+        state.ids[param::GLIDE_TIME] = abi::bind_parameter(&[13]);
+        state.ids[param::VOICING_MODE] = abi::bind_parameter(&[14]);
+        for (index, ids) in state.operator_ids.iter_mut().enumerate() {
+            for (slot, id) in ids.iter_mut().enumerate() {
+                *id = abi::bind_parameter(&[OPERATORS_KEY, index as u16, slot as u16 + 1]);
+            }
+        }
+    }
+
+    fn handle_event(state: &mut FmState, event: &EventRecord) {
+        if event.kind == EVENT_NOTE_ON {
+            let frequency = midi_to_hz_base(event.pitch as f32 + event.cent / 100.0, abi::base_frequency());
+            state.voicing.start(event, frequency, 1.0, state.glide_time, 1, &state.params);
+        } else if event.kind == EVENT_NOTE_OFF {
+            if !state.sustain.hold(event.id) {
+                state.voicing.stop(event.id as i32, state.glide_time);
+            }
+        } else if event.kind == EVENT_PITCH_BEND {
+            state.params.pitch_bend = event.velocity.clamp(-1.0, 1.0) * PITCH_BEND_RANGE;
+        } else if event.kind == EVENT_CONTROL && event.pitch == CC_SUSTAIN {
+            let FmState {sustain, voicing, glide_time, ..} = state;
+            sustain.set(event.velocity, &mut |id| voicing.stop(id as i32, *glide_time));
+        } else if event.kind == EVENT_NOTE_EXPRESSION {
+            if let Some(expression) = NoteExpression::from_record(event) {
+                state.voicing.express(event.id as i32, expression);
+            }
+        }
+    }
+
+    fn process_audio(state: &mut FmState, output: [&mut [f32]; 2], block: &Block) {
+        let [out_left, out_right] = output;
+        state.voicing.process([&mut *out_left, &mut *out_right], block, &state.params);
+        state.limiter.replace(out_left, out_right, 0, out_left.len());
+    }
+
+    fn parameter_changed(state: &mut FmState, id: u32, value: ParamValue) {
+        if let Some(index) = state.ids.iter().position(|bound| *bound == id) {
+            let params = &mut state.params;
+            match index {
+                param::VOLUME => params.gain = db_to_gain(float_value(value, &VOLUME_MAPPING)),
+                param::ALGORITHM => params.algorithm = int_value(value, &ALGORITHM_MAPPING).max(0) as usize,
+                param::FEEDBACK => params.feedback = float_value(value, &UNIPOLAR),
+                param::GLIDE_TIME => state.glide_time = float_value(value, &UNIPOLAR) as f64 * ppqn::BAR,
+                param::VOICING_MODE => state.voicing.set_mode(VoicingMode::from_index(int_value(value, &Values::new(&VOICING_MODE_VALUES)))),
+                _ => {}
+            }
+            return;
+        }
+        for (ids, params) in state.operator_ids.iter().zip(&mut state.params.operators) {
+            let Some(slot) = ids.iter().position(|bound| *bound == id) else {continue};
+            match slot as u16 + 1 {
+                operator::RATIO => {
+                    params.ratio = float_value(value, &RATIO_MAPPING);
+                    params.update_multiplier();
+                }
+                operator::FINE => {
+                    params.fine = float_value(value, &FINE_MAPPING);
+                    params.update_multiplier();
+                }
+                operator::LEVEL => params.level = float_value(value, &UNIPOLAR),
+                operator::ATTACK => params.attack = float_value(value, &TIME_MAPPING),
+                operator::DECAY => params.decay = float_value(value, &TIME_MAPPING),
+                operator::SUSTAIN => params.sustain = float_value(value, &UNIPOLAR),
+                operator::RELEASE => params.release = float_value(value, &TIME_MAPPING),
+                operator::VELOCITY => params.velocity = float_value(value, &UNIPOLAR),
+                operator::KEY_SCALING => params.key_scaling = float_value(value, &UNIPOLAR),
+                _ => {}
+            }
+            return;
+        }
+    }
+
+    fn reset(state: &mut FmState) {
+        state.voicing.reset();
+        state.sustain.reset();
+        state.params.pitch_bend = 0.0;
+    }
+}
+
+/// Host-independent entry for tests: clear the stereo output, dispatch the supplied events through the SDK
+/// template, and run the post-pass. The wasm `process` path uses [`abi::render_instrument`] instead.
+pub fn render(state: &mut FmState, events: &[EventRecord], out_left: &mut [f32], out_right: &mut [f32]) {
+    out_left.fill(0.0);
+    out_right.fill(0.0);
+    let block = Block {index: 0, flags: abi::BlockFlags(0), p0: 0.0, p1: 0.0, s0: 0, s1: out_left.len() as u32, bpm: 120.0};
+    abi::dispatch_range::<FmSynth>(state, [&mut *out_left, &mut *out_right], events, &block);
+    FmSynth::finish(state, [out_left, out_right]);
+}
+
+// ---- The device ABI: shared with the engine, called wasm-to-wasm. ----
+
+/// What the host wires this device as (read at load): an instrument that voices notes into audio.
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn kind() -> u32 {
+    abi::DEVICE_KIND_INSTRUMENT
+}
+
+/// Bytes the engine must allocate (zeroed) for one instance's state block. Independent of `sample_rate`.
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn state_size(_sample_rate: f32) -> u32 {
+    core::mem::size_of::<FmState>() as u32
+}
+
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn process(desc_ptr: usize) {
+    let ports = unsafe { Ports::<FmState>::from_descriptor(desc_ptr) };
+    abi::render_instrument::<FmSynth>(ports);
+}
+
+/// Boot hook: bind this device's parameters with the host, and stash the sample rate.
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn init(state_ptr: usize, sample_rate: f32) {
+    unsafe { abi::with_state(state_ptr, |state| <FmSynth as Instrument>::init(state, sample_rate)) }
+}
+
+/// Apply a parameter value the host resolved (initial / edit / automation), by the id `init` got back.
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn parameter_changed(state_ptr: usize, id: u32, kind: u32, value: f32) {
+    unsafe { abi::with_state(state_ptr, |state| <FmSynth as Instrument>::parameter_changed(state, id, ParamValue::from_wire(kind, value))) }
+}
+
+/// Parity probe: the REAL value stored for a UNIT automation value, ids in `init` bind order (the `param`
+/// slots, then `operator::COUNT` per operator).
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn map_parameter(id: u32, unit: f32) -> f32 {
+    let value = ParamValue::Unit(unit);
+    let id = id as usize;
+    match id {
+        param::VOLUME => return float_value(value, &VOLUME_MAPPING),
+        param::ALGORITHM => return int_value(value, &ALGORITHM_MAPPING) as f32,
+        param::FEEDBACK | param::GLIDE_TIME => return float_value(value, &UNIPOLAR),
+        param::VOICING_MODE => return int_value(value, &Values::new(&VOICING_MODE_VALUES)) as f32,
+        _ => {}
+    }
+    if id >= param::COUNT + OPERATORS * operator::COUNT {
+        return f32::NAN;
+    }
+    match ((id - param::COUNT) % operator::COUNT) as u16 + 1 {
+        operator::RATIO => float_value(value, &RATIO_MAPPING),
+        operator::FINE => float_value(value, &FINE_MAPPING),
+        operator::ATTACK | operator::DECAY | operator::RELEASE => float_value(value, &TIME_MAPPING),
+        operator::LEVEL | operator::SUSTAIN | operator::VELOCITY | operator::KEY_SCALING => float_value(value, &UNIPOLAR),
+        _ => f32::NAN
+    }
+}
+
+/// Transport STOP: drop every voice so playback starts silent.
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn reset(state_ptr: usize) {
+    unsafe { abi::with_state(state_ptr, <FmSynth as Instrument>::reset) }
+}
+
+#[cfg(test)]
+mod tests {
+    //! The FM voice driven through the ABI's `Instrument` dispatch, on a zeroed state like the engine's.
+    use super::*;
+
+    const SR: f32 = 48_000.0;
+
+    /// A zeroed state with the additive algorithm and only operator 1 audible: a plain sine at unity gain,
+    /// ratio 1, a fast full-sustain envelope, no velocity or key scaling. (The engine pushes the real defaults
+    /// via `parameter_changed`; the tests set the fields the DSP reads directly.)
+    fn configured(mode: VoicingMode) -> FmState {
+        let mut state: FmState = unsafe { core::mem::zeroed() };
+        state.params.sample_rate = SR;
+        state.params.gain = 1.0;
+        state.params.algorithm = 7;
+        state.limiter.prepare(SR);
+        state.voicing.set_mode(mode);
+        for (index, params) in state.params.operators.iter_mut().enumerate() {
+            params.ratio = 1.0;
+            params.update_multiplier();
+            params.level = if index == 0 {1.0} else {0.0};
+            params.attack = 0.001;
+            params.decay = 0.001;
+            params.sustain = 1.0;
+            params.release = 0.050;
+        }
+        state
+    }
+
+    fn play(state: &mut FmState, events: &[EventRecord], frames: usize) -> (Vec<f32>, Vec<f32>) {
+        let (mut left, mut right) = (vec![0.0f32; frames], vec![0.0f32; frames]);
+        render(state, events, &mut left, &mut right);
+        (left, right)
+    }
+
+    fn note_on(id: u32, pitch: u32, velocity: f32) -> EventRecord {
+        EventRecord {position: 0.0, offset: 0, kind: EVENT_NOTE_ON, id, pitch, velocity, cent: 0.0, duration: 0.0}
+    }
+
+    fn note_off(id: u32) -> EventRecord {
+        EventRecord {position: 0.0, offset: 0, kind: EVENT_NOTE_OFF, id, pitch: 0, velocity: 0.0, cent: 0.0, duration: 0.0}
+    }
+
+    fn peak(buffer: &[f32]) -> f32 {
+        buffer.iter().fold(0.0f32, |acc, sample| acc.max(sample.abs()))
+    }
+
+    fn rms(buffer: &[f32]) -> f32 {
+        libm::sqrtf(buffer.iter().map(|sample| sample * sample).sum::<f32>() / buffer.len() as f32)
+    }
+
+    /// The rms of the first difference over the rms: `2 sin(π f / sr)` for a sine, larger with overtones.
+    fn brightness(buffer: &[f32]) -> f32 {
+        let difference: Vec<f32> = buffer.windows(2).map(|pair| pair[1] - pair[0]).collect();
+        rms(&difference) / rms(buffer)
+    }
+
+    /// Fundamental estimate: rising zero crossings per second over a steady sustain.
+    fn estimate_frequency(buffer: &[f32]) -> f32 {
+        let crossings = buffer.windows(2).filter(|pair| pair[0] < 0.0 && pair[1] >= 0.0).count();
+        crossings as f32 * SR / buffer.len() as f32
+    }
+
+    #[test]
+    fn a_lone_carrier_plays_a_sine_at_the_note_and_ratio_pitch() {
+        let mut state = configured(VoicingMode::Polyphonic);
+        let (left, right) = play(&mut state, &[note_on(1, 69, 1.0)], 48_000);
+        let frequency = estimate_frequency(&left[4_800..]);
+        assert!((frequency - 440.0).abs() < 2.0, "A4, got {frequency}");
+        assert!((rms(&left[4_800..]) / peak(&left[4_800..]) - core::f32::consts::FRAC_1_SQRT_2).abs() < 0.02, "a pure sine");
+        assert_eq!(left, right, "a mono voice is equal on both channels");
+        let mut octave = configured(VoicingMode::Polyphonic);
+        octave.params.operators[0].ratio = 2.0;
+        octave.params.operators[0].update_multiplier();
+        let (left, _) = play(&mut octave, &[note_on(1, 69, 1.0)], 48_000);
+        let frequency = estimate_frequency(&left[4_800..]);
+        assert!((frequency - 880.0).abs() < 3.0, "ratio 2 is an octave up, got {frequency}");
+    }
+
+    #[test]
+    fn a_modulator_adds_overtones_only_where_the_algorithm_routes_it() {
+        let mut plain = configured(VoicingMode::Polyphonic);
+        let (reference, _) = play(&mut plain, &[note_on(1, 57, 1.0)], 9_600);
+        let mut modulated = configured(VoicingMode::Polyphonic);
+        modulated.params.algorithm = 0; // 4 -> 3 -> 2 -> 1
+        modulated.params.operators[1].level = 0.5;
+        let (bright, _) = play(&mut modulated, &[note_on(1, 57, 1.0)], 9_600);
+        assert!(brightness(&bright[4_800..]) > 2.0 * brightness(&reference[4_800..]), "operator 2 modulates operator 1");
+        let mut additive = configured(VoicingMode::Polyphonic);
+        additive.params.operators[3].level = 0.5; // heard, but in algorithm 7 modulates nothing
+        let (mixed, _) = play(&mut additive, &[note_on(1, 57, 1.0)], 9_600);
+        assert!(brightness(&mixed[4_800..]) < 1.1 * brightness(&reference[4_800..]), "two unmodulated sines stay pure");
+    }
+
+    #[test]
+    fn feedback_brightens_operator_four() {
+        let tone = |feedback: f32| {
+            let mut state = configured(VoicingMode::Polyphonic);
+            state.params.operators[0].level = 0.0;
+            state.params.operators[3].level = 1.0;
+            state.params.feedback = feedback;
+            play(&mut state, &[note_on(1, 57, 1.0)], 9_600).0
+        };
+        let (plain, fed) = (tone(0.0), tone(1.0));
+        assert!(brightness(&fed[4_800..]) > 1.5 * brightness(&plain[4_800..]), "feedback turns the sine toward a saw");
+    }
+
+    #[test]
+    fn a_modulator_envelope_shapes_the_timbre_over_time() {
+        let mut state = configured(VoicingMode::Polyphonic);
+        state.params.algorithm = 0;
+        let modulator = &mut state.params.operators[1];
+        modulator.level = 0.5;
+        modulator.decay = 0.05;
+        modulator.sustain = 0.0;
+        let (left, _) = play(&mut state, &[note_on(1, 57, 1.0)], 24_000);
+        assert!(brightness(&left[480..1_440]) > 2.0 * brightness(&left[19_200..]), "the modulator decays to a pure carrier");
+    }
+
+    #[test]
+    fn velocity_and_key_scaling_set_the_operator_level() {
+        let level = |velocity_sensitivity: f32, key_scaling: f32, pitch: u32| {
+            let mut state = configured(VoicingMode::Polyphonic);
+            state.params.operators[0].velocity = velocity_sensitivity;
+            state.params.operators[0].key_scaling = key_scaling;
+            peak(&play(&mut state, &[note_on(1, pitch, 0.5)], 9_600).0[4_800..])
+        };
+        assert!((level(0.0, 0.0, 60) / level(1.0, 0.0, 60) - 2.0).abs() < 0.05, "full sensitivity follows the velocity");
+        assert!((level(0.0, 0.0, 84) / level(0.0, 1.0, 84) - 4.0).abs() < 0.1, "two octaves up at full key scaling is 12 dB down");
+        assert!((level(0.0, 1.0, 60) - level(0.0, 0.0, 60)).abs() < 1.0e-3, "middle C is unscaled");
+    }
+
+    #[test]
+    fn releasing_a_note_decays_to_silence() {
+        let mut state = configured(VoicingMode::Polyphonic);
+        let (left, _) = play(&mut state, &[note_on(1, 60, 1.0)], 256);
+        assert!(peak(&left) > 0.01, "sustaining while held");
+        let (tail, _) = play(&mut state, &[note_off(1)], 8192);
+        assert!(peak(&tail[6000..]) < 1.0e-4, "decays to silence, got {}", peak(&tail[6000..]));
+    }
+
+    #[test]
+    fn monophonic_mode_voices_a_note() {
+        let mut state = configured(VoicingMode::Monophonic);
+        let (left, _) = play(&mut state, &[note_on(1, 64, 1.0)], 4096);
+        assert!(peak(&left) > 0.01, "the monophonic strategy sounds a note");
+    }
+}
@@ -0,0 +1,229 @@
+//! One FM voice, the [`Voice`] the `voicing` strategies allocate, plus the live parameters it reads
+//! ([`FmParams`], the voicing `Shared` type) and the reusable render workspace.
+//!
+//! Four sine operators (`dsp::fast_math::fast_sin_tau`), each with its own `dsp::adsr::Adsr`, are evaluated
+//! per sample from operator 3 down to 0 through the selected [`Algorithm`]: a modulator's output shifts its
+//! targets' phase by up to `MODULATION_DEPTH` cycles, operator 3 also modulates itself by the average of its
+//! last two outputs (the feedback), and the carriers are averaged into the output.
+
+use core::cell::RefCell;
+use abi::{Block, EventRecord, NoteExpression};
+use dsp::adsr::Adsr;
+use dsp::fast_math::fast_sin_tau;
+use dsp::glide::Glide;
+use dsp::RENDER_QUANTUM;
+use voicing::Voice;
+use crate::algorithm::{Algorithm, ALGORITHMS};
+use crate::OPERATORS;
+
+/// The phase deviation (cycles) of a full-level modulator; 1 cycle is a modulation index of 2π.
+const MODULATION_DEPTH: f64 = 1.0;
+/// The phase deviation (cycles) of operator 3's self-feedback at full amount.
+const FEEDBACK_DEPTH: f64 = 0.5;
+const SILENCE_THRESHOLD: f32 = 1.0e-4; // ≈ -80 dB
+
+/// The per-chunk frequency scratch (one quantum wide), reused by every voice each block. Valid when zeroed.
+pub struct Workspace {
+    freq: [f32; RENDER_QUANTUM]
+}
+
+/// One operator's live parameters: the frequency ratio (with the fine tune folded into `multiplier`), the
+/// output level, the envelope times, and the velocity / keyboard sensitivities.
+#[derive(Clone, Copy)]
+pub struct OperatorParams {
+    pub(crate) ratio: f32,
+    pub(crate) fine: f32, // cents
+    pub(crate) multiplier: f32,
+    pub(crate) level: f32,
+    pub(crate) attack: f32,
+    pub(crate) decay: f32,
+    pub(crate) sustain: f32,
+    pub(crate) release: f32,
+    pub(crate) velocity: f32,    // 0: velocity ignored, 1: the level follows the velocity fully
+    pub(crate) key_scaling: f32  // the level drop per octave above middle C, 1 = 6 dB
+}
+
+impl OperatorParams {
+    pub(crate) fn update_multiplier(&mut self) {
+        self.multiplier = self.ratio * libm::exp2f(self.fine / 1200.0);
+    }
+}
+
+/// The device's live parameters the voice reads (the `voicing::Voice::Shared` type): the output gain, the
+/// algorithm, the feedback amount, the operators, the channel pitch bend, the sample rate and the shared
+/// render [`Workspace`]. Envelope times, velocity and key scaling are read at note-on; the rest per chunk.
+pub struct FmParams {
+    pub(crate) gain: f32,
+    pub(crate) algorithm: usize,
+    pub(crate) feedback: f32,
+    pub(crate) operators: [OperatorParams; OPERATORS],
+    pub(crate) pitch_bend: f32, // the channel pitch wheel in semitones, applied to every sounding voice
+    pub(crate) sample_rate: f32,
+    pub(crate) workspace: RefCell<Workspace>
+}
+
+/// One operator's running state.
+struct Operator {
+    phase: f64,
+    env: Adsr,
+    gain: f32 // the note's level scaling from velocity and key
+}
+
+/// One FM voice: the operators restart in `start` (phase, envelope, velocity / key scaling); `process`
+/// renders the routing. The note's own expressions bend it (tuning), swell its carriers (pressure) and
+/// deepen or soften its modulation (brightness).
+pub struct FmVoice {
+    operators: [Operator; OPERATORS],
+    feedback: [f32; 2], // operator 3's last two outputs
+    glide: Glide,
+    gate: bool,
+    inv_sample_rate: f64,
+    sample_rate: f32,
+    tuning: f32,     // semitones, on top of the channel wheel
+    pressure: f32,   // 0..1, pushes the carrier level toward full scale
+    brightness: f32  // -1..1, scales the modulation depth between none and double
+}
+
+impl Default for FmVoice {
+    fn default() -> Self {
+        Self {
+            operators: core::array::from_fn(|_| Operator {phase: 0.0, env: Adsr::new(0.0), gain: 0.0}),
+            feedback: [0.0; 2], glide: Glide::default(), gate: false, inv_sample_rate: 0.0, sample_rate: 0.0,
+            tuning: 0.0, pressure: 0.0, brightness: 0.0
+        }
+    }
+}
+
+/// The level scaling of an operator for a note: the velocity sensitivity blends from full level to the
+/// velocity, and key scaling drops `6 dB * amount` per octave above middle C.
+fn note_gain(params: &OperatorParams, velocity: f32, pitch: u32) -> f32 {
+    let velocity = 1.0 - params.velocity + params.velocity * velocity;
+    let octaves = (pitch as f32 - 60.0).max(0.0) / 12.0;
+    velocity * libm::exp2f(-params.key_scaling * octaves)
+}
+
+impl FmVoice {
+    /// Render one window (`<= RENDER_QUANTUM`) into the stereo slices, returning `true` once every carrier's
+    /// envelope has finished.
+    fn process_window(&mut self, out_left: &mut [f32], out_right: &mut [f32], block: &Block, shared: &FmParams, work: &mut Workspace) -> bool {
+        let len = out_left.len();
+        let algorithm: Algorithm = ALGORITHMS[shared.algorithm.min(ALGORITHMS.len() - 1)];
+        let carriers = algorithm.carriers.count_ones() as f32;
+        let carrier_gain = (shared.gain / carriers) * (1.0 + self.pressure);
+        let depth = MODULATION_DEPTH * (1.0 + self.brightness as f64);
+        let feedback = FEEDBACK_DEPTH * shared.feedback as f64;
+        let bend = libm::exp2f((shared.pitch_bend + self.tuning) / 12.0);
+        work.freq[..len].fill(1.0);
+        self.glide.process(&mut work.freq, block.bpm, self.sample_rate, 0, len);
+        let mut levels = [0.0f32; OPERATORS];
+        for (level, (operator, params)) in levels.iter_mut().zip(self.operators.iter().zip(&shared.operators)) {
+            *level = params.level * operator.gain;
+        }
+        for ((left, right), freq) in out_left.iter_mut().zip(out_right.iter_mut()).zip(&work.freq[..len]) {
+            let frequency = (*freq * bend) as f64 * self.inv_sample_rate;
+            let mut outputs = [0.0f32; OPERATORS];
+            for op in (0..OPERATORS).rev() {
+                let operator = &mut self.operators[op];
+                let mut modulation = 0.0;
+                for (source, output) in outputs.iter().enumerate().skip(op + 1) {
+                    if algorithm.modulators[op] & (1 << source) != 0 {
+                        modulation += *output as f64 * depth;
+                    }
+                }
+                if op == OPERATORS - 1 {
+                    modulation += (self.feedback[0] + self.feedback[1]) as f64 * 0.5 * feedback;
+                }
+                let env = operator.env.next_value();
+                outputs[op] = fast_sin_tau(operator.phase + modulation) as f32 * env * levels[op];
+                operator.phase += frequency * shared.operators[op].multiplier as f64;
+                operator.phase -= libm::floor(operator.phase);
+            }
+            self.feedback = [self.feedback[1], outputs[OPERATORS - 1]];
+            let mut out = 0.0;
+            for (op, output) in outputs.iter().enumerate() {
+                if algorithm.carriers & (1 << op) != 0 {
+                    out += output;
+                }
+            }
+            *left += out * carrier_gain;
+            *right += out * carrier_gain;
+        }
+        let finished = self.operators.iter().enumerate()
+            .filter(|(op, _)| algorithm.carriers & (1 << op) != 0)
+            .all(|(_, operator)| operator.env.is_idle());
+        finished && !self.gate && self.feedback[1].abs() < SILENCE_THRESHOLD
+    }
+}
+
+impl Voice for FmVoice {
+    type Shared = FmParams;
+
+    fn start(&mut self, event: &EventRecord, frequency: f32, _gain: f32, _spread: f32, _unison: usize, shared: &FmParams) {
+        let sample_rate = shared.sample_rate;
+        self.sample_rate = sample_rate;
+        self.inv_sample_rate = 1.0 / sample_rate as f64;
+        for (operator, params) in self.operators.iter_mut().zip(&shared.operators) {
+            operator.phase = 0.0;
+            operator.env = Adsr::new(sample_rate);
+            operator.env.set(params.attack, params.decay, params.sustain, params.release);
+            operator.env.gate_on();
+            operator.gain = note_gain(params, event.velocity, event.pitch);
+        }
+        self.feedback = [0.0; 2];
+        self.gate = true;
+        self.glide = Glide::default();
+        self.glide.init(frequency as f64);
+        self.tuning = 0.0;
+        self.pressure = 0.0;
+        self.brightness = 0.0;
+    }
+
+    fn stop(&mut self) {
+        for operator in &mut self.operators {
+            operator.env.gate_off();
+        }
+        self.gate = false;
+    }
+
+    fn force_stop(&mut self) {
+        for operator in &mut self.operators {
+            operator.env.force_stop();
+        }
+        self.gate = false;
+    }
+
+    fn start_glide(&mut self, target_frequency: f32, glide_duration: f64) {
+        self.glide.glide_to(target_frequency as f64, glide_duration);
+    }
+
+    fn gate(&self) -> bool {
+        self.gate
+    }
+
+    fn current_frequency(&self) -> f32 {
+        self.glide.current_frequency() as f32
+    }
+
+    fn express(&mut self, expression: NoteExpression) {
+        match expression {
+            NoteExpression::Tuning(semitones) => self.tuning = semitones,
+            NoteExpression::Pressure(pressure) => self.pressure = pressure,
+            NoteExpression::Brightness(brightness) => self.brightness = brightness
+        }
+    }
+
+    fn process(&mut self, output: [&mut [f32]; 2], block: &Block, shared: &FmParams) -> bool {
+        let [out_left, out_right] = output;
+        let mut work = shared.workspace.borrow_mut();
+        let total = out_left.len();
+        let mut base = 0;
+        while base < total {
+            let len = (total - base).min(RENDER_QUANTUM);
+            if self.process_window(&mut out_left[base..base + len], &mut out_right[base..base + len], block, shared, &mut work) {
+                return true;
+            }
+            base += len;
+        }
+        false
+    }
+}
@@ -124,34 +124,34 @@ impl AudioEffect for Fold {
     }
 }
 
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn kind() -> u32 {
     abi::DEVICE_KIND_AUDIO_EFFECT
 }
 
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn state_size(_sample_rate: f32) -> u32 {
     core::mem::size_of::<FoldState>() as u32
 }
 
-#[no_mangle]
-pub extern "C" fn process(desc_ptr: u32) {
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn process(desc_ptr: usize) {
     let ports = unsafe { Ports::<FoldState>::from_descriptor(desc_ptr) };
     abi::render_effect::<Fold>(ports);
 }
 
-#[no_mangle]
-pub extern "C" fn init(state_ptr: u32, sample_rate: f32) {
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn init(state_ptr: usize, sample_rate: f32) {
     unsafe { abi::with_state(state_ptr, |state| <Fold as AudioEffect>::init(state, sample_rate)) }
 }
 
-#[no_mangle]
-pub extern "C" fn parameter_changed(state_ptr: u32, id: u32, kind: u32, value: f32) {
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn parameter_changed(state_ptr: usize, id: u32, kind: u32, value: f32) {
     unsafe { abi::with_state(state_ptr, |state| <Fold as AudioEffect>::parameter_changed(state, id, ParamValue::from_wire(kind, value))) }
 }
 
 /// Parity probe: the REAL value stored for a UNIT automation value, ids in `init` bind order.
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn map_parameter(id: u32, unit: f32) -> f32 {
     let value = ParamValue::Unit(unit);
     match id {
@@ -162,14 +162,14 @@ pub extern "C" fn map_parameter(id: u32, unit: f32) -> f32 {
 }
 
 /// Transport STOP: clear the runtime state (mirrors the TS processor's `reset`).
-#[no_mangle]
-pub extern "C" fn reset(state_ptr: u32) {
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn reset(state_ptr: usize) {
     unsafe { abi::with_state(state_ptr, <Fold as AudioEffect>::reset) }
 }
 
 /// Apply the observed `over-sampling` int field (0/1/2 -> factor 2/4/8), rebuilding the oversampler + ramps.
-#[no_mangle]
-pub extern "C" fn field_changed(state_ptr: u32, id: u32, kind: u32, bits: u32, len: u32) {
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn field_changed(state_ptr: usize, id: u32, kind: u32, bits: usize, len: u32) {
     unsafe {
         abi::with_state(state_ptr, |state: &mut FoldState| {
             if id == state.over_sampling_field_id {
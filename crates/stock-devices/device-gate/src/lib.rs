@@ -80,7 +80,7 @@ pub struct GateState {
     envelope: f32,   // the smoothed 0..1 open amount
     out_max: f32,    // the output peak follower for the editor display (TS `#outMax`)
     editor_id: u32,  // live editor broadcast at `[0]`: [input dB, output dB, envelope dB]
-    editor_ptr: u32,
+    editor_ptr: usize,
     sample_rate: f32,
     threshold_id: u32,
     return_id: u32,
@@ -232,37 +232,37 @@ impl Gate {
 // ---- The device ABI: shared with the engine, called wasm-to-wasm. ----
 
 /// What the host wires this device as (read at load): an audio effect that transforms its input.
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn kind() -> u32 {
     abi::DEVICE_KIND_AUDIO_EFFECT
 }
 
 /// Bytes the engine must allocate (zeroed) for one instance's state block.
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn state_size(_sample_rate: f32) -> u32 {
     core::mem::size_of::<GateState>() as u32
 }
 
-#[no_mangle]
-pub extern "C" fn process(desc_ptr: u32) {
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn process(desc_ptr: usize) {
     let ports = unsafe { Ports::<GateState>::from_descriptor(desc_ptr) };
     abi::render_effect::<Gate>(ports);
 }
 
 /// Boot hook: bind this device's parameters + its sidechain port with the host, and stash the sample rate.
-#[no_mangle]
-pub extern "C" fn init(state_ptr: u32, sample_rate: f32) {
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn init(state_ptr: usize, sample_rate: f32) {
     unsafe { abi::with_state(state_ptr, |state| <Gate as AudioEffect>::init(state, sample_rate)) }
 }
 
 /// Apply a parameter value the host resolved (initial / edit / automation), by the id `init` got back.
-#[no_mangle]
-pub extern "C" fn parameter_changed(state_ptr: u32, id: u32, kind: u32, value: f32) {
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn parameter_changed(state_ptr: usize, id: u32, kind: u32, value: f32) {
     unsafe { abi::with_state(state_ptr, |state| <Gate as AudioEffect>::parameter_changed(state, id, ParamValue::from_wire(kind, value))) }
 }
 
 /// Parity probe: the REAL value stored for a UNIT automation value, ids in `init` bind order.
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn map_parameter(id: u32, unit: f32) -> f32 {
     let value = ParamValue::Unit(unit);
     match id {
@@ -278,8 +278,8 @@ pub extern "C" fn map_parameter(id: u32, unit: f32) -> f32 {
 }
 
 /// Transport STOP: clear the detector / envelope so the gate starts closed and silent next playback.
-#[no_mangle]
-pub extern "C" fn reset(state_ptr: u32) {
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn reset(state_ptr: usize) {
     unsafe { abi::with_state(state_ptr, |state| <Gate as AudioEffect>::reset(state)) }
 }
 
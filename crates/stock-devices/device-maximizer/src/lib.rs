@@ -8,7 +8,7 @@
 //! (observed, not automatable). The device owns the mappings.
 //!
 //! Exports: `kind()` (audio effect), `state_size()`, `process(desc_ptr)`, `init(...)`, `parameter_changed(...)`,
-//! `field_changed(...)`.
+//! `field_changed(...)`, `latency(state_ptr)` (the look-ahead window while look-ahead is on).
 
 #![cfg_attr(target_family = "wasm", no_std)]
 
@@ -65,11 +65,11 @@ pub struct MaximizerState {
     // Live telemetry: the min-held reduction at `[0]` (TS resets it per UI tick; here per block — the UI
     // samples the last block's min) and the INPUT peak/rms meter at `[1]` (TS `#inputPeaks`).
     reduction_id: u32,
-    reduction_ptr: u32,
+    reduction_ptr: usize,
     reduction_min: f32,
     input_meter: StereoMeter,
     input_peaks_id: u32,
-    input_peaks_ptr: u32
+    input_peaks_ptr: usize
 }
 
 /// The DSP, plugged into the SDK's `AudioEffect` template ([`abi::render_effect`]).
@@ -120,6 +120,10 @@ impl AudioEffect for Maximizer {
         state.lookahead_mix.set(if state.lookahead {1.0} else {0.0}, false);
     }
 
+    fn latency(state: &MaximizerState) -> u32 {
+        if state.lookahead {state.look_ahead_frames as u32} else {0}
+    }
+
     fn process_audio(state: &mut MaximizerState, output: [&mut [f32]; 2], block: &Block) {
         let Some(input) = abi::resolve_input(abi::MAIN_INPUT) else {return};
         let [in_left, in_right] = input.channels();
@@ -185,34 +189,34 @@ impl AudioEffect for Maximizer {
     }
 }
 
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn kind() -> u32 {
     abi::DEVICE_KIND_AUDIO_EFFECT
 }
 
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn state_size(_sample_rate: f32) -> u32 {
     core::mem::size_of::<MaximizerState>() as u32
 }
 
-#[no_mangle]
-pub extern "C" fn process(desc_ptr: u32) {
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn process(desc_ptr: usize) {
     let ports = unsafe { Ports::<MaximizerState>::from_descriptor(desc_ptr) };
     abi::render_effect::<Maximizer>(ports);
 }
 
-#[no_mangle]
-pub extern "C" fn init(state_ptr: u32, sample_rate: f32) {
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn init(state_ptr: usize, sample_rate: f32) {
     unsafe { abi::with_state(state_ptr, |state| <Maximizer as AudioEffect>::init(state, sample_rate)) }
 }
 
-#[no_mangle]
-pub extern "C" fn parameter_changed(state_ptr: u32, id: u32, kind: u32, value: f32) {
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn parameter_changed(state_ptr: usize, id: u32, kind: u32, value: f32) {
     unsafe { abi::with_state(state_ptr, |state| <Maximizer as AudioEffect>::parameter_changed(state, id, ParamValue::from_wire(kind, value))) }
 }
 
 /// Parity probe: the REAL value stored for a UNIT automation value, ids in `init` bind order.
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn map_parameter(id: u32, unit: f32) -> f32 {
     let value = ParamValue::Unit(unit);
     match id {
@@ -222,14 +226,22 @@ pub extern "C" fn map_parameter(id: u32, unit: f32) -> f32 {
 }
 
 /// Transport STOP: clear the runtime state (mirrors the TS processor's `reset`).
-#[no_mangle]
-pub extern "C" fn reset(state_ptr: u32) {
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn reset(state_ptr: usize) {
     unsafe { abi::with_state(state_ptr, <Maximizer as AudioEffect>::reset) }
 }
 
+/// Delay compensation: the samples the output lags the input (the look-ahead window while look-ahead is on).
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn latency(state_ptr: usize) -> u32 {
+    let mut latency = 0;
+    unsafe { abi::with_state(state_ptr, |state: &mut MaximizerState| latency = <Maximizer as AudioEffect>::latency(state)) }
+    latency
+}
+
 /// Apply the observed `lookahead` bool field (resets the delay position + envelope on a change, like the TS).
-#[no_mangle]
-pub extern "C" fn field_changed(state_ptr: u32, id: u32, kind: u32, bits: u32, len: u32) {
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn field_changed(state_ptr: usize, id: u32, kind: u32, bits: usize, len: u32) {
     unsafe {
         abi::with_state(state_ptr, |state: &mut MaximizerState| {
             if id == state.lookahead_field_id {
@@ -298,6 +310,13 @@ mod tests {
         out_l
     }
 
+    #[test]
+    fn reports_the_look_ahead_window_as_latency_only_while_on() {
+        // 5 ms at 48 kHz: the delay the engine must compensate on parallel paths.
+        assert_eq!(Maximizer::latency(&state(0.0, true)), 240);
+        assert_eq!(Maximizer::latency(&state(0.0, false)), 0);
+    }
+
     #[test]
     fn quiet_signal_below_threshold_is_only_makeup_scaled() {
         // A -0 dB threshold with a quiet input: no reduction, just the tiny makeup (~unity). Non-lookahead so no delay.
@@ -0,0 +1,388 @@
+//! The MULTIBAND COMPRESSOR AUDIO EFFECT (`MultibandCompressorDeviceBox`): the input is split into 2..4 bands
+//! by the engine's subtractive Linkwitz-Riley crossover (`dsp::linkwitz_riley`, the filter behind the
+//! `Frequency` distributor mode), and each band runs its own CTAGDRC compressor core (`dsp::ctagdrc`:
+//! `GainComputer` -> `LevelDetector`, 6 dB soft knee) before the bands are summed back. Band `i` is the lowpass
+//! of the running remainder, then subtracted out of it, so with no band compressing the sum is the exact input.
+//!
+//! A bypassed band passes its split signal untouched. When any band is soloed only the soloed bands are summed;
+//! the others keep detecting, so their meters stay live. Per-band gain reduction (the block's deepest, in dB,
+//! 0 while bypassed or unused) is broadcast at `[0]` for the editor.
+//!
+//! Parameters (`MultibandCompressorDeviceBox`): band-count `[10]` (2..4), crossovers `[11]`..`[13]` (Hz,
+//! exponential, low to high), and per band `[20, band, *]`: threshold `1` (dB), ratio `2` (exp 1..24), attack
+//! `3` (ms), release `4` (ms), gain `5` (dB makeup), solo `6` and bypass `7` (bools).
+//!
+//! Exports: `kind()` (audio effect), `state_size()`, `process(desc_ptr)`, `init(state_ptr, sample_rate)`,
+//! `parameter_changed(...)`, `map_parameter(...)`, `reset(state_ptr)`.
+
+#![cfg_attr(target_family = "wasm", no_std)]
+
+#[cfg(target_family = "wasm")]
+use core::panic::PanicInfo;
+use abi::{bool_value, float_value, int_value, AudioEffect, Block, ParamValue, Ports, MAIN_INPUT};
+use dsp::ctagdrc::{decibels_to_gain, GainComputer, LevelDetector};
+use dsp::linkwitz_riley::LinkwitzRiley;
+use dsp::RENDER_QUANTUM;
+use math::value_mapping::{Exponential, Linear, LinearInteger};
+
+#[cfg(target_family = "wasm")]
+#[panic_handler]
+fn panic(info: &PanicInfo) -> ! {
+    abi::panic_to_host(info) // deposit the message in the engine's panic buffer, then trap (never a silent hang)
+}
+
+pub const MAX_BANDS: usize = 4;
+const MAX_CROSSOVERS: usize = MAX_BANDS - 1;
+
+// The MultibandCompressorDeviceBox field-key paths (the stable schema keys).
+const BAND_COUNT_FIELD: [u16; 1] = [10];
+const CROSSOVER_FIELDS: [[u16; 1]; MAX_CROSSOVERS] = [[11], [12], [13]];
+const BANDS_KEY: u16 = 20;
+const REDUCTION_FIELD: [u16; 1] = [0]; // live editor values at address.append(0): reduction dB per band
+
+mod param {
+    pub const BAND_COUNT: usize = 0;
+    pub const CROSSOVER: usize = 1; // ..CROSSOVER + MAX_CROSSOVERS
+    pub const COUNT: usize = 4;
+}
+
+// The per-band field keys inside a `MultibandCompressorBand`, which double as the `band_ids` slots (key - 1).
+mod band {
+    pub const THRESHOLD: u16 = 1;
+    pub const RATIO: u16 = 2;
+    pub const ATTACK: u16 = 3;
+    pub const RELEASE: u16 = 4;
+    pub const GAIN: u16 = 5;
+    pub const SOLO: u16 = 6;
+    pub const BYPASS: u16 = 7;
+    pub const COUNT: usize = 7;
+}
+
+const BAND_COUNT_MAPPING: LinearInteger = LinearInteger {min: 2, max: MAX_BANDS as i32};
+const CROSSOVER_MAPPING: Exponential = Exponential {min: 20.0, max: 20_000.0};
+const THRESHOLD_MAPPING: Linear = Linear {min: -60.0, max: 0.0};
+const RATIO_MAPPING: Exponential = Exponential {min: 1.0, max: 24.0};
+const ATTACK_MAPPING: Linear = Linear {min: 0.0, max: 100.0};
+const RELEASE_MAPPING: Linear = Linear {min: 5.0, max: 1500.0};
+const GAIN_MAPPING: Linear = Linear {min: -24.0, max: 24.0};
+
+/// One band's compressor: the static curve, the smoothing ballistics and the band switches.
+pub struct Band {
+    gain_computer: GainComputer,
+    detector: LevelDetector,
+    makeup: f32,
+    solo: bool,
+    bypass: bool
+}
+
+/// The device state: the crossover lowpasses, the per-band compressors and split signals (one quantum each),
+/// the running remainder and detection scratch, the broadcast reductions, and the bound parameter ids.
+pub struct MultibandCompressorState {
+    crossovers: [LinkwitzRiley; MAX_CROSSOVERS],
+    frequencies: [f32; MAX_CROSSOVERS],
+    bands: [Band; MAX_BANDS],
+    signals: [[[f32; RENDER_QUANTUM]; 2]; MAX_BANDS],
+    remainder: [[f32; RENDER_QUANTUM]; 2],
+    detection: [f32; RENDER_QUANTUM],
+    reduction: [f32; MAX_BANDS],
+    band_count: usize,
+    sample_rate: f32,
+    ids: [u32; param::COUNT],
+    band_ids: [[u32; band::COUNT]; MAX_BANDS],
+    reduction_id: u32,
+    reduction_ptr: usize
+}
+
+/// The DSP, plugged into the SDK's `AudioEffect` template ([`abi::render_effect`]).
+pub struct MultibandCompressor;
+
+impl AudioEffect for MultibandCompressor {
+    type State = MultibandCompressorState;
+
+    fn init(state: &mut MultibandCompressorState, sample_rate: f32) {
+        MultibandCompressor::defaults(state, sample_rate);
+        state.ids[param::BAND_COUNT] = abi::bind_parameter(&BAND_COUNT_FIELD);
+        for (index, field) in CROSSOVER_FIELDS.iter().enumerate() {
+            state.ids[param::CROSSOVER + index] = abi::bind_parameter(field);
+        }
+        for (index, ids) in state.band_ids.iter_mut().enumerate() {
+            for (slot, id) in ids.iter_mut().enumerate() {
+                *id = abi::bind_parameter(&[BANDS_KEY, index as u16, slot as u16 + 1]);
+            }
+        }
+        state.reduction_id = abi::bind_broadcast(&REDUCTION_FIELD, MAX_BANDS as u32);
+        state.reduction_ptr = 0;
+    }
+
+    fn process_audio(state: &mut MultibandCompressorState, output: [&mut [f32]; 2], block: &Block) {
+        let Some(input) = abi::resolve_input(MAIN_INPUT) else {return};
+        MultibandCompressor::dsp(state, [input.left(), input.right()], output, block.s0 as usize, block.s1 as usize);
+        if state.reduction_ptr == 0 {
+            state.reduction_ptr = abi::broadcast_ptr(state.reduction_id);
+        }
+        if state.reduction_ptr != 0 {
+            let editor = unsafe { core::slice::from_raw_parts_mut(state.reduction_ptr as *mut f32, MAX_BANDS) };
+            editor.copy_from_slice(&state.reduction);
+        }
+    }
+
+    fn parameter_changed(state: &mut MultibandCompressorState, id: u32, value: ParamValue) {
+        if let Some(index) = state.ids.iter().position(|bound| *bound == id) {
+            if index == param::BAND_COUNT {
+                let count = int_value(value, &BAND_COUNT_MAPPING).clamp(2, MAX_BANDS as i32) as usize;
+                if count != state.band_count {
+                    state.band_count = count;
+                    state.crossovers.iter_mut().for_each(LinkwitzRiley::clear);
+                }
+            } else {
+                let crossover = index - param::CROSSOVER;
+                state.frequencies[crossover] = float_value(value, &CROSSOVER_MAPPING);
+                state.crossovers[crossover].set(state.frequencies[crossover] as f64, state.sample_rate as f64);
+            }
+            return;
+        }
+        for (ids, band) in state.band_ids.iter().zip(&mut state.bands) {
+            let Some(slot) = ids.iter().position(|bound| *bound == id) else {continue};
+            match slot as u16 + 1 {
+                band::THRESHOLD => band.gain_computer.set_threshold(float_value(value, &THRESHOLD_MAPPING)),
+                band::RATIO => band.gain_computer.set_ratio(float_value(value, &RATIO_MAPPING)),
+                band::ATTACK => band.detector.set_attack(float_value(value, &ATTACK_MAPPING) * 0.001),
+                band::RELEASE => band.detector.set_release(float_value(value, &RELEASE_MAPPING) * 0.001),
+                band::GAIN => band.makeup = float_value(value, &GAIN_MAPPING),
+                band::SOLO => band.solo = bool_value(value),
+                band::BYPASS => band.bypass = bool_value(value),
+                _ => {}
+            }
+            return;
+        }
+    }
+
+    fn reset(state: &mut MultibandCompressorState) {
+        state.crossovers.iter_mut().for_each(LinkwitzRiley::clear);
+        state.reduction = [0.0; MAX_BANDS];
+    }
+}
+
+impl MultibandCompressor {
+    /// The box defaults (the engine pushes the real values right after `init` via `parameter_changed`).
+    fn defaults(state: &mut MultibandCompressorState, sample_rate: f32) {
+        state.sample_rate = sample_rate; // stable for the device's life
+        state.band_count = 3;
+        state.frequencies = [200.0, 1_000.0, 5_000.0];
+        for (crossover, frequency) in state.crossovers.iter_mut().zip(state.frequencies) {
+            *crossover = LinkwitzRiley::lowpass();
+            crossover.set(frequency as f64, sample_rate as f64);
+        }
+        for band in &mut state.bands {
+            band.gain_computer = GainComputer::default();
+            band.gain_computer.set_threshold(-20.0);
+            band.gain_computer.set_ratio(4.0);
+            band.detector = LevelDetector::new(sample_rate);
+            band.detector.set_attack(0.010);
+            band.detector.set_release(0.150);
+            band.makeup = 0.0;
+            band.solo = false;
+            band.bypass = false;
+        }
+        state.reduction = [0.0; MAX_BANDS];
+    }
+
+    /// The pure per-range DSP (unit-tested directly) over `[s0, s1)` in absolute quantum coordinates: split,
+    /// compress each band, sum the audible ones into `output`, and record each band's deepest reduction.
+    fn dsp(state: &mut MultibandCompressorState, input: [&[f32]; 2], output: [&mut [f32]; 2], s0: usize, s1: usize) {
+        let count = state.band_count;
+        for (remainder, source) in state.remainder.iter_mut().zip(input) {
+            remainder[s0..s1].copy_from_slice(&source[s0..s1]);
+        }
+        for (crossover, signal) in state.crossovers.iter_mut().zip(&mut state.signals).take(count - 1) {
+            let [rem_left, rem_right] = &mut state.remainder;
+            let [band_left, band_right] = signal;
+            crossover.process(&rem_left[s0..s1], &rem_right[s0..s1], &mut band_left[s0..s1], &mut band_right[s0..s1]);
+            for (remainder, band) in [(rem_left, band_left), (rem_right, band_right)] {
+                for (rest, split) in remainder[s0..s1].iter_mut().zip(&band[s0..s1]) {
+                    *rest -= split;
+                }
+            }
+        }
+        for (signal, remainder) in state.signals[count - 1].iter_mut().zip(&state.remainder) {
+            signal[s0..s1].copy_from_slice(&remainder[s0..s1]);
+        }
+        let soloing = state.bands[..count].iter().any(|band| band.solo);
+        let [out_left, out_right] = output;
+        out_left[s0..s1].fill(0.0);
+        out_right[s0..s1].fill(0.0);
+        state.reduction = [0.0; MAX_BANDS];
+        let detection = &mut state.detection;
+        for ((band, [left, right]), reduction) in state.bands.iter_mut().zip(&state.signals).zip(&mut state.reduction).take(count) {
+            if !band.bypass {
+                for ((level, left), right) in detection[s0..s1].iter_mut().zip(&left[s0..s1]).zip(&right[s0..s1]) {
+                    *level = libm::fabsf(*left).max(libm::fabsf(*right));
+                }
+                band.gain_computer.apply_compression_to_buffer(detection, s0, s1);
+                band.detector.apply_ballistics(detection, s0, s1);
+                *reduction = detection[s0..s1].iter().fold(0.0f32, |deepest, level| deepest.min(*level));
+            }
+            if soloing && !band.solo {
+                continue;
+            }
+            for index in s0..s1 {
+                let gain = if band.bypass {1.0} else {decibels_to_gain(detection[index] + band.makeup)};
+                out_left[index] += left[index] * gain;
+                out_right[index] += right[index] * gain;
+            }
+        }
+    }
+}
+
+// ---- The device ABI: shared with the engine, called wasm-to-wasm. ----
+
+/// What the host wires this device as (read at load): an audio effect that transforms its input.
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn kind() -> u32 {
+    abi::DEVICE_KIND_AUDIO_EFFECT
+}
+
+/// Bytes the engine must allocate (zeroed) for one instance's state block.
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn state_size(_sample_rate: f32) -> u32 {
+    core::mem::size_of::<MultibandCompressorState>() as u32
+}
+
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn process(desc_ptr: usize) {
+    let ports = unsafe { Ports::<MultibandCompressorState>::from_descriptor(desc_ptr) };
+    abi::render_effect::<MultibandCompressor>(ports);
+}
+
+/// Boot hook: bind this device's parameters and the reduction broadcast with the host.
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn init(state_ptr: usize, sample_rate: f32) {
+    unsafe { abi::with_state(state_ptr, |state| <MultibandCompressor as AudioEffect>::init(state, sample_rate)) }
+}
+
+/// Apply a parameter value the host resolved (initial / edit / automation), by the id `init` got back.
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn parameter_changed(state_ptr: usize, id: u32, kind: u32, value: f32) {
+    unsafe { abi::with_state(state_ptr, |state| <MultibandCompressor as AudioEffect>::parameter_changed(state, id, ParamValue::from_wire(kind, value))) }
+}
+
+/// Parity probe: the REAL value stored for a UNIT automation value, ids in `init` bind order (the `param`
+/// slots, then `band::COUNT` per band).
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn map_parameter(id: u32, unit: f32) -> f32 {
+    let value = ParamValue::Unit(unit);
+    let id = id as usize;
+    if id == param::BAND_COUNT {
+        return int_value(value, &BAND_COUNT_MAPPING) as f32;
+    }
+    if id < param::COUNT {
+        return float_value(value, &CROSSOVER_MAPPING);
+    }
+    if id >= param::COUNT + MAX_BANDS * band::COUNT {
+        return f32::NAN;
+    }
+    match ((id - param::COUNT) % band::COUNT) as u16 + 1 {
+        band::THRESHOLD => float_value(value, &THRESHOLD_MAPPING),
+        band::RATIO => float_value(value, &RATIO_MAPPING),
+        band::ATTACK => float_value(value, &ATTACK_MAPPING),
+        band::RELEASE => float_value(value, &RELEASE_MAPPING),
+        band::GAIN => float_value(value, &GAIN_MAPPING),
+        band::SOLO | band::BYPASS => if bool_value(value) {1.0} else {0.0},
+        _ => f32::NAN
+    }
+}
+
+/// Transport STOP: clear the crossover filters and the meters.
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn reset(state_ptr: usize) {
+    unsafe { abi::with_state(state_ptr, <MultibandCompressor as AudioEffect>::reset) }
+}
+
+#[cfg(test)]
+mod tests {
+    //! The multiband DSP, driven directly (`resolve_input` has no host on native).
+    use super::*;
+
+    const SR: f32 = 48_000.0;
+
+    fn compressor() -> Box<MultibandCompressorState> {
+        let mut state: Box<MultibandCompressorState> = Box::new(unsafe { core::mem::zeroed() });
+        MultibandCompressor::defaults(&mut state, SR);
+        for band in &mut state.bands {
+            band.gain_computer.set_threshold(0.0); // nothing compresses until a test lowers a threshold
+        }
+        state
+    }
+
+    fn sine(frequency: f32, amplitude: f32, len: usize) -> Vec<f32> {
+        (0..len).map(|index| amplitude * libm::sinf(2.0 * core::f32::consts::PI * frequency * index as f32 / SR)).collect()
+    }
+
+    // Run `input` (both channels) through in quanta and return the left output.
+    fn run(state: &mut MultibandCompressorState, input: &[f32]) -> Vec<f32> {
+        let mut left = Vec::with_capacity(input.len());
+        let (mut out_left, mut out_right) = ([0.0f32; RENDER_QUANTUM], [0.0f32; RENDER_QUANTUM]);
+        for chunk in input.chunks(RENDER_QUANTUM) {
+            MultibandCompressor::dsp(state, [chunk, chunk], [&mut out_left, &mut out_right], 0, chunk.len());
+            left.extend_from_slice(&out_left[..chunk.len()]);
+        }
+        left
+    }
+
+    fn peak(samples: &[f32]) -> f32 {
+        samples.iter().fold(0.0f32, |peak, sample| peak.max(sample.abs()))
+    }
+
+    #[test]
+    fn an_uncompressed_split_sums_back_to_the_input() {
+        let input: Vec<f32> = sine(80.0, 0.3, 4800).iter().zip(sine(2_500.0, 0.3, 4800)).map(|(a, b)| a + b).collect();
+        for count in 2..=MAX_BANDS {
+            let mut state = compressor();
+            state.band_count = count;
+            let output = run(&mut state, &input);
+            let error = output.iter().zip(&input).fold(0.0f32, |error, (out, source)| error.max((out - source).abs()));
+            assert!(error < 1e-3, "{count} bands: {error}");
+            assert_eq!(state.reduction, [0.0; MAX_BANDS]);
+        }
+    }
+
+    #[test]
+    fn only_the_loud_band_is_reduced_and_reported() {
+        let mut state = compressor();
+        state.band_count = 2;
+        state.crossovers[0].set(2_000.0, SR as f64);
+        for band in &mut state.bands {
+            band.gain_computer.set_threshold(-20.0);
+            band.gain_computer.set_ratio(10.0);
+        }
+        // a loud low tone and a quiet high one: only the low band crosses the threshold
+        let input: Vec<f32> = sine(50.0, 0.5, 9600).iter().zip(sine(10_000.0, 0.02, 9600)).map(|(a, b)| a + b).collect();
+        let output = run(&mut state, &input);
+        assert!(state.reduction[0] < -10.0, "low band reduction {}", state.reduction[0]);
+        assert_eq!(state.reduction[1..], [0.0; MAX_BANDS - 1]);
+        assert!(peak(&output[4800..]) < 0.5 * peak(&input[4800..]), "the low tone is compressed");
+    }
+
+    #[test]
+    fn a_bypassed_band_passes_unprocessed_and_reports_nothing() {
+        let mut state = compressor();
+        state.bands[0].gain_computer.set_threshold(-40.0);
+        state.bands[0].bypass = true;
+        let input = sine(60.0, 0.8, 4800);
+        let output = run(&mut state, &input);
+        assert_eq!(state.reduction[0], 0.0);
+        assert!((peak(&output[2400..]) - 0.8).abs() < 0.02, "{}", peak(&output[2400..]));
+    }
+
+    #[test]
+    fn soloing_a_band_mutes_the_others() {
+        let input = sine(8_000.0, 0.5, 4800);
+        let mut state = compressor();
+        state.bands[0].solo = true;
+        assert!(peak(&run(&mut state, &input)[2400..]) < 0.01, "the low band holds no 8 kHz");
+        let mut state = compressor();
+        state.bands[2].solo = true;
+        let soloed = peak(&run(&mut state, &input)[2400..]);
+        assert!((soloed - peak(&input[2400..])).abs() < 0.02, "the high band carries it: {soloed}");
+    }
+}
@@ -1,8 +1,10 @@
 //! Nano, a simple one-shot sampler instrument, as a runtime-loadable device: a faithful port of the TS
 //! `NanoDeviceProcessor`. It plays ONE loaded sample (its `file` pointer) per note, each voice a pitch-rate
-//! read head with linear interpolation and a squared attack/release envelope (see `voice.rs`). It does NOT
+//! read head (linear, or a windowed sinc when the box asks for it) and a squared attack/release envelope
+//! (see `voice.rs`). It does NOT
 //! use the `voicing` framework: voices are a plain fixed pool, pushed on note-on, freed when they finish
-//! (the TS `Array<Voice>` with a fixed cap).
+//! (the TS `Array<Voice>` with a fixed cap). The channel pitch wheel bends every voice's read rate by up to
+//! [`abi::PITCH_BEND_RANGE`] semitones.
 //!
 //! The sample is resolved through the engine: the device declares its `file` pointer path with
 //! `bind_sample`; the engine resolves it to the AudioFileBox, requests the frames (Route F), and pushes the
@@ -10,13 +12,14 @@
 //! `resolve_sample(handle)`: `None` while it loads (voices are dropped, as in the TS), the frames once ready.
 //!
 //! Exports: `kind()` (instrument), `state_size()`, `process(desc_ptr)`, `init(state_ptr, sample_rate)`,
-//! `parameter_changed(state_ptr, id, kind, value)`.
+//! `parameter_changed(state_ptr, id, kind, value)`, `field_changed(...)`.
 
 #![cfg_attr(target_family = "wasm", no_std)]
 
 #[cfg(target_family = "wasm")]
 use core::panic::PanicInfo;
-use abi::{float_value, Block, EventRecord, Instrument, ParamValue, Ports, EVENT_NOTE_ON};
+use abi::{float_value, Block, EventRecord, FieldValue, Instrument, ParamValue, Ports, EVENT_NOTE_OFF, EVENT_NOTE_ON, EVENT_PITCH_BEND, PITCH_BEND_RANGE};
+use dsp::interpolator::Interpolation;
 use math::db_to_gain;
 use math::value_mapping::{Decibel, Exponential};
 
@@ -32,26 +35,30 @@ fn panic(info: &PanicInfo) -> ! {
 const MAX_VOICES: usize = 64;
 
 // The Nano box's field-key paths (the stable schema keys): volume `[10]` (decibel), the sample `file` pointer
-// `[15]`, and release `[20]` (seconds, exponential).
+// `[15]`, release `[20]` (seconds, exponential), and the read head's `interpolation` `[25]` (a plain int field).
 const VOLUME_FIELD: [u16; 1] = [10];
 const SAMPLE_POINTER: [u16; 1] = [15];
 const RELEASE_FIELD: [u16; 1] = [20];
+const INTERPOLATION_FIELD: [u16; 1] = [25];
 
 const VOLUME_MAPPING: Decibel = Decibel::default_volume();
 const RELEASE_MAPPING: Exponential = Exponential {min: 0.001, max: 8.0}; // seconds
 
 /// The device's per-instance state, interpreted from the engine-allocated (zeroed) block: a fixed voice pool,
-/// the resolved gain / release, the sample rate, the bound sample handle (+ whether one is bound), and the
-/// parameter / sample binding ids the engine pushes against.
+/// the resolved gain / release, the channel pitch bend, the sample rate, the bound sample handle (+ whether one
+/// is bound), and the parameter / sample binding ids the engine pushes against.
 pub struct NanoState {
     voices: [NanoVoice; MAX_VOICES],
     gain: f32,
     release: u32, // release length in samples
+    pitch_bend: f32, // semitones
     sample_rate: f32,
     sample: Option<u32>, // the resolved sample handle while the `file` pointer is bound; `None` when unbound
+    interpolation: Interpolation, // zeroed = `Linear`, the box default
     gain_id: u32,
     release_id: u32,
-    sample_id: u32
+    sample_id: u32,
+    interpolation_id: u32
 }
 
 /// The DSP, plugged into the SDK's `Instrument` template ([`abi::render_instrument`]).
@@ -68,6 +75,7 @@ impl Instrument for Nano {
         state.gain_id = abi::bind_parameter(&VOLUME_FIELD);
         state.release_id = abi::bind_parameter(&RELEASE_FIELD);
         state.sample_id = abi::observe_sample(&SAMPLE_POINTER);
+        state.interpolation_id = abi::observe_field(&INTERPOLATION_FIELD);
     }
 
     fn handle_event(state: &mut NanoState, event: &EventRecord) {
@@ -76,8 +84,12 @@ impl Instrument for Nano {
             if let Some(slot) = state.voices.iter_mut().find(|voice| !voice.is_active()) {
                 slot.start(event.id, event.pitch, event.cent, event.velocity, sample_rate);
             }
-        } else if let Some(voice) = state.voices.iter_mut().find(|voice| voice.is_active() && voice.id() == event.id) {
-            voice.stop();
+        } else if event.kind == EVENT_NOTE_OFF {
+            if let Some(voice) = state.voices.iter_mut().find(|voice| voice.is_active() && voice.id() == event.id) {
+                voice.stop();
+            }
+        } else if event.kind == EVENT_PITCH_BEND {
+            state.pitch_bend = event.velocity.clamp(-1.0, 1.0) * PITCH_BEND_RANGE;
         }
     }
 
@@ -94,11 +106,12 @@ impl Instrument for Nano {
         };
         let left = sample.plane(0);
         let right = if sample.channel_count > 1 {sample.plane(1)} else {left};
-        let rate_ratio = sample.sample_rate as f64 / state.sample_rate as f64;
+        let rate_ratio = sample.sample_rate as f64 / state.sample_rate as f64 * libm::exp2(state.pitch_bend as f64 / 12.0);
         let gain = state.gain;
         let release = state.release;
+        let interpolation = state.interpolation;
         for voice in state.voices.iter_mut() {
-            if voice.is_active() && voice.process(out_left, out_right, left, right, rate_ratio, gain, release) {
+            if voice.is_active() && voice.process(out_left, out_right, left, right, rate_ratio, gain, release, interpolation) {
                 voice.force_stop();
             }
         }
@@ -112,6 +125,14 @@ impl Instrument for Nano {
         }
     }
 
+    fn field_changed(state: &mut NanoState, id: u32, value: FieldValue) {
+        if id == state.interpolation_id {
+            if let FieldValue::Int(index) = value {
+                state.interpolation = Interpolation::from_index(index);
+            }
+        }
+    }
+
     fn sample_changed(state: &mut NanoState, id: u32, sample: Option<u32>) {
         // The sample (its `file` pointer), reactively delivered: a resident handle, or `None` on remove.
         if id == state.sample_id {
@@ -123,6 +144,7 @@ impl Instrument for Nano {
         for voice in state.voices.iter_mut() {
             voice.force_stop();
         }
+        state.pitch_bend = 0.0;
     }
 }
 
@@ -144,38 +166,38 @@ pub fn render(state: &mut NanoState, events: &[EventRecord], out_left: &mut [f32
 // ---- The device ABI: shared with the engine, called wasm-to-wasm. ----
 
 /// What the host wires this device as (read at load): an instrument that voices notes into audio.
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn kind() -> u32 {
     abi::DEVICE_KIND_INSTRUMENT
 }
 
 /// Bytes the engine must allocate (zeroed) for one instance's state block. The voice pool is fixed, so the
 /// size does not depend on `sample_rate`.
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn state_size(_sample_rate: f32) -> u32 {
     core::mem::size_of::<NanoState>() as u32
 }
 
-#[no_mangle]
-pub extern "C" fn process(desc_ptr: u32) {
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn process(desc_ptr: usize) {
     let ports = unsafe { Ports::<NanoState>::from_descriptor(desc_ptr) };
     abi::render_instrument::<Nano>(ports);
 }
 
 /// Boot hook: bind this device's parameters + its sample reference with the host, and stash the sample rate.
-#[no_mangle]
-pub extern "C" fn init(state_ptr: u32, sample_rate: f32) {
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn init(state_ptr: usize, sample_rate: f32) {
     unsafe { abi::with_state(state_ptr, |state| <Nano as Instrument>::init(state, sample_rate)) }
 }
 
 /// Apply a parameter value the host resolved (initial / edit / automation), by the id `init` got back.
-#[no_mangle]
-pub extern "C" fn parameter_changed(state_ptr: u32, id: u32, kind: u32, value: f32) {
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn parameter_changed(state_ptr: usize, id: u32, kind: u32, value: f32) {
     unsafe { abi::with_state(state_ptr, |state| <Nano as Instrument>::parameter_changed(state, id, ParamValue::from_wire(kind, value))) }
 }
 
 /// Parity probe: the REAL value stored for a UNIT automation value, ids in `init` bind order.
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn map_parameter(id: u32, unit: f32) -> f32 {
     let value = ParamValue::Unit(unit);
     match id {
@@ -187,15 +209,21 @@ pub extern "C" fn map_parameter(id: u32, unit: f32) -> f32 {
 
 /// Apply an observed sample reference (its `file` pointer), by the id `observe_sample` returned. `present != 0`
 /// means a resident `handle`, `0` means the pointer is unbound.
-#[no_mangle]
-pub extern "C" fn sample_changed(state_ptr: u32, id: u32, handle: u32, present: u32) {
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn sample_changed(state_ptr: usize, id: u32, handle: u32, present: u32) {
     let sample = if present != 0 {Some(handle)} else {None};
     unsafe { abi::with_state(state_ptr, |state| <Nano as Instrument>::sample_changed(state, id, sample)) }
 }
 
+/// Apply the observed `interpolation` field, by the id `observe_field` returned.
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn field_changed(state_ptr: usize, id: u32, kind: u32, bits: usize, len: u32) {
+    unsafe { abi::with_state(state_ptr, |state| <Nano as Instrument>::field_changed(state, id, FieldValue::from_wire(kind, bits, len))) }
+}
+
 /// Transport STOP: drop every voice so playback starts silent.
-#[no_mangle]
-pub extern "C" fn reset(state_ptr: u32) {
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn reset(state_ptr: usize) {
     unsafe { abi::with_state(state_ptr, |state| <Nano as Instrument>::reset(state)) }
 }
 
@@ -219,4 +247,14 @@ mod tests {
         render(&mut state, &[note_on(1, 60)], &mut left, &mut right, SR);
         assert_eq!(left.iter().fold(0.0f32, |acc, value| acc.max(value.abs())), 0.0, "no audio until a sample is resident");
     }
+
+    #[test]
+    fn the_pitch_wheel_sets_the_bend_and_reset_recentres_it() {
+        let mut state: NanoState = unsafe { core::mem::zeroed() };
+        let bend = EventRecord {kind: EVENT_PITCH_BEND, velocity: -1.0, ..note_on(0, 0)};
+        Nano::handle_event(&mut state, &bend);
+        assert_eq!(state.pitch_bend, -PITCH_BEND_RANGE);
+        Nano::reset(&mut state);
+        assert_eq!(state.pitch_bend, 0.0);
+    }
 }
@@ -1,8 +1,11 @@
 //! The Nano sampler's per-note voice, a port of the inner `Voice` of TS `NanoDeviceProcessor`: a pitch-rate
-//! read head over the loaded sample with linear interpolation and a squared attack/release envelope. Pure DSP
+//! read head over the loaded sample (linear interpolation, or a windowed sinc on request) and a squared
+//! attack/release envelope. Pure DSP
 //! over slices, so it is unit-testable with synthetic frames; the device owns the sample resolution and the
 //! voice pool. Heap-free and valid when zeroed (voices live in the device's zeroed state, a fixed pool).
 
+use dsp::interpolator::Interpolation;
+
 const ATTACK_SECONDS: f32 = 0.003; // the TS voice's fixed 3 ms attack ramp
 
 #[derive(Clone, Copy, Default)]
@@ -55,8 +58,10 @@ impl NanoVoice {
     /// Render additively into the stereo chunk from the planar sample (`left` / `right`, mono passes the same
     /// slice for both), advancing the read head by `speed * rate_ratio`. Returns `true` once finished (the
     /// sample ran out or the release elapsed), so the device frees the slot. `gain` is the device gain (the
-    /// per-note velocity is applied here); `release` is the release length in samples. Mirrors `processSimple`.
-    pub fn process(&mut self, out_left: &mut [f32], out_right: &mut [f32], left: &[f32], right: &[f32], rate_ratio: f64, gain: f32, release: u32) -> bool {
+    /// per-note velocity is applied here); `release` is the release length in samples. Mirrors `processSimple`
+    /// (whose read is `Interpolation::Linear`).
+    #[allow(clippy::too_many_arguments)]
+    pub fn process(&mut self, out_left: &mut [f32], out_right: &mut [f32], left: &[f32], right: &[f32], rate_ratio: f64, gain: f32, release: u32, interpolation: Interpolation) -> bool {
         let num_frames = left.len();
         if num_frames < 2 {
             return true;
@@ -69,7 +74,6 @@ impl NanoVoice {
             if int_position >= num_frames - 1 {
                 return true;
             }
-            let frac = (self.position - int_position as f64) as f32;
             let att = if self.env_position < self.attack {self.env_position as f32 / self.attack as f32} else {1.0};
             let release_factor = if self.releasing {
                 (1.0 - (self.env_position - self.decay_position) as f32 * release_inverse).min(1.0)
@@ -78,8 +82,7 @@ impl NanoVoice {
             };
             let shaped = release_factor * att;
             let env = shaped * shaped;
-            let sample_left = left[int_position] * (1.0 - frac) + left[int_position + 1] * frac;
-            let sample_right = right[int_position] * (1.0 - frac) + right[int_position + 1] * frac;
+            let (sample_left, sample_right) = interpolation.read_stereo(left, right, self.position, self.speed as f64 * rate_ratio);
             out_left[index] += sample_left * gain * env;
             out_right[index] += sample_right * gain * env;
             self.position += self.speed as f64 * rate_ratio;
@@ -95,6 +98,7 @@ impl NanoVoice {
 #[cfg(test)]
 mod tests {
     use super::NanoVoice;
+    use dsp::interpolator::Interpolation;
 
     const SR: f32 = 48_000.0;
 
@@ -126,7 +130,7 @@ mod tests {
         let mut voice = started(60);
         let frames = dc(48_000);
         let (mut left, mut right) = (vec![0.0f32; 64], vec![0.0f32; 64]);
-        assert!(!voice.process(&mut left, &mut right, &frames, &frames, 1.0, 1.0, 4_800), "still sounding");
+        assert!(!voice.process(&mut left, &mut right, &frames, &frames, 1.0, 1.0, 4_800, Interpolation::Linear), "still sounding");
         assert!(left[0].abs() < 0.01, "starts near silent (attack ramp from 0)");
         assert!(left[63] > left[0], "ramps up across the attack");
         assert_eq!(left, right, "a mono sample feeds both channels equally");
@@ -137,7 +141,7 @@ mod tests {
         let mut voice = started(60);
         let frames = dc(32); // tiny sample
         let (mut left, mut right) = (vec![0.0f32; 64], vec![0.0f32; 64]);
-        assert!(voice.process(&mut left, &mut right, &frames, &frames, 1.0, 1.0, 4_800), "ends past the last frame");
+        assert!(voice.process(&mut left, &mut right, &frames, &frames, 1.0, 1.0, 4_800, Interpolation::Linear), "ends past the last frame");
     }
 
     #[test]
@@ -145,15 +149,29 @@ mod tests {
         let mut voice = started(60);
         let frames = dc(48_000);
         let (mut left, mut right) = (vec![0.0f32; 4_800], vec![0.0f32; 4_800]);
-        voice.process(&mut left, &mut right, &frames, &frames, 1.0, 1.0, 4_800); // run past the attack
+        voice.process(&mut left, &mut right, &frames, &frames, 1.0, 1.0, 4_800, Interpolation::Linear); // run past the attack
         voice.stop();
         let release = 480; // ~10 ms
         let (mut tail_left, mut tail_right) = (vec![0.0f32; 1_024], vec![0.0f32; 1_024]);
-        let finished = voice.process(&mut tail_left, &mut tail_right, &frames, &frames, 1.0, 1.0, release);
+        let finished = voice.process(&mut tail_left, &mut tail_right, &frames, &frames, 1.0, 1.0, release, Interpolation::Linear);
         assert!(finished, "the release elapses within the chunk");
         assert!(peak(&tail_left[release as usize..]) < 1.0e-6, "silent once released");
     }
 
+    #[test]
+    fn a_sinc_read_keeps_a_pitched_up_tone_from_aliasing() {
+        // a 15 kHz tone an octave up is 30 kHz, past Nyquist: linear folds it back to 18 kHz, the sinc drops it
+        let frames: Vec<f32> = (0..8_192).map(|i| libm::sinf(2.0 * core::f32::consts::PI * 15_000.0 * i as f32 / SR)).collect();
+        let level = |interpolation: Interpolation| {
+            let mut voice = started(72);
+            let (mut left, mut right) = (vec![0.0f32; 2_048], vec![0.0f32; 2_048]);
+            voice.process(&mut left, &mut right, &frames, &frames, 1.0, 1.0, 4_800, interpolation);
+            peak(&left[512..])
+        };
+        assert!(level(Interpolation::Linear) > 0.1, "linear {}", level(Interpolation::Linear));
+        assert!(level(Interpolation::Sinc) < 0.01, "sinc {}", level(Interpolation::Sinc));
+    }
+
     impl NanoVoice {
         // test-only accessor for the computed read-head rate
         fn process_speed(&self) -> f32 {self.speed}
@@ -62,7 +62,7 @@ pub struct NeuralAmpState {
     // while the UI subscribes (`broadcast_active`), mirroring TS `#needsSpectrum`.
     analyser: AudioAnalyser,
     spectrum_id: u32,
-    spectrum_ptr: u32
+    spectrum_ptr: usize
 }
 
 pub struct NeuralAmpDevice;
@@ -184,34 +184,34 @@ pub fn process_chunk(state: &mut NeuralAmpState, in_left: &[f32], in_right: &[f3
     }
 }
 
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn kind() -> u32 {
     abi::DEVICE_KIND_AUDIO_EFFECT
 }
 
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn state_size(_sample_rate: f32) -> u32 {
     core::mem::size_of::<NeuralAmpState>() as u32
 }
 
-#[no_mangle]
-pub extern "C" fn process(desc_ptr: u32) {
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn process(desc_ptr: usize) {
     let ports = unsafe { Ports::<NeuralAmpState>::from_descriptor(desc_ptr) };
     abi::render_effect::<NeuralAmpDevice>(ports);
 }
 
-#[no_mangle]
-pub extern "C" fn init(state_ptr: u32, sample_rate: f32) {
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn init(state_ptr: usize, sample_rate: f32) {
     unsafe { abi::with_state(state_ptr, |state| <NeuralAmpDevice as AudioEffect>::init(state, sample_rate)) }
 }
 
-#[no_mangle]
-pub extern "C" fn parameter_changed(state_ptr: u32, id: u32, kind: u32, value: f32) {
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn parameter_changed(state_ptr: usize, id: u32, kind: u32, value: f32) {
     unsafe { abi::with_state(state_ptr, |state| <NeuralAmpDevice as AudioEffect>::parameter_changed(state, id, ParamValue::from_wire(kind, value))) }
 }
 
 /// Parity probe: the REAL value stored for a UNIT automation value, ids in `init` bind order.
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn map_parameter(id: u32, unit: f32) -> f32 {
     let value = ParamValue::Unit(unit);
     match id {
@@ -223,8 +223,8 @@ pub extern "C" fn map_parameter(id: u32, unit: f32) -> f32 {
 
 /// Apply the observed `mono` bool field (`[13]`) or the model JSON read off the `model` pointer target
 /// (`observe_target_string([20], 2)`), forwarding each to the JS bridge.
-#[no_mangle]
-pub extern "C" fn field_changed(state_ptr: u32, id: u32, kind: u32, bits: u32, len: u32) {
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn field_changed(state_ptr: usize, id: u32, kind: u32, bits: usize, len: u32) {
     unsafe {
         abi::with_state(state_ptr, |state: &mut NeuralAmpState| {
             apply_field(state, id, FieldValue::from_wire(kind, bits, len));
@@ -233,15 +233,15 @@ pub extern "C" fn field_changed(state_ptr: u32, id: u32, kind: u32, bits: u32, l
 }
 
 /// Transport STOP: reset the nam instances' internal DSP state (mirrors the TS processor's `reset`).
-#[no_mangle]
-pub extern "C" fn reset(state_ptr: u32) {
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn reset(state_ptr: usize) {
     unsafe { abi::with_state(state_ptr, <NeuralAmpDevice as AudioEffect>::reset) }
 }
 
 /// This device's INSTANCE is dying (a genuine removal, never a chain-edit survivor): release the bridge's
 /// nam instance(s), so removing/rebinding a NeuralAmp device no longer leaks its native nam instance(s).
-#[no_mangle]
-pub extern "C" fn terminate(state_ptr: u32) {
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn terminate(state_ptr: usize) {
     unsafe { abi::with_state(state_ptr, |state: &mut NeuralAmpState| abi::nam_release(state.bridge)) }
 }
 
@@ -134,38 +134,38 @@ impl MidiEffect for Transpose {
 }
 
 /// What the host wires this device as (read at load): a MIDI effect (a pull source in the event chain).
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn kind() -> u32 {
     abi::DEVICE_KIND_MIDI_EFFECT
 }
 
 /// Bytes the engine must allocate (zeroed) for one instance's state block.
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn state_size(_sample_rate: f32) -> u32 {
     core::mem::size_of::<TransposeState>() as u32
 }
 
-#[no_mangle]
-pub extern "C" fn process_events(from: f64, to: f64, flags: u32, state_ptr: u32, out_ptr: u32, max: u32) -> u32 {
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn process_events(from: f64, to: f64, flags: u32, state_ptr: usize, out_ptr: usize, max: u32) -> u32 {
     abi::render_midi_effect::<Transpose>(from, to, flags, state_ptr, out_ptr, max)
 }
 
 /// Boot hook: bind this device's semitone parameter with the host (it records the field-path, returns the
 /// id). The `sample_rate` is unused (a MIDI fx produces no audio), but the export signature is uniform.
-#[no_mangle]
-pub extern "C" fn init(state_ptr: u32, sample_rate: f32) {
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn init(state_ptr: usize, sample_rate: f32) {
     unsafe { abi::with_state(state_ptr, |state| <Transpose as MidiEffect>::init(state, sample_rate)) }
 }
 
 /// Apply a semitone value the host resolved (initial / edit / automation), by the id `init` got back. The
 /// `kind` tag tells the SDK how to type the f32 `value` into a `ParamValue` (uniform to map, or a real i32).
-#[no_mangle]
-pub extern "C" fn parameter_changed(state_ptr: u32, id: u32, kind: u32, value: f32) {
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn parameter_changed(state_ptr: usize, id: u32, kind: u32, value: f32) {
     unsafe { abi::with_state(state_ptr, |state| <Transpose as MidiEffect>::parameter_changed(state, id, ParamValue::from_wire(kind, value))) }
 }
 
 /// Parity probe: the REAL value stored for a UNIT automation value, ids in `init` bind order.
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn map_parameter(id: u32, unit: f32) -> f32 {
     let value = ParamValue::Unit(unit);
     match id {
@@ -178,8 +178,8 @@ pub extern "C" fn map_parameter(id: u32, unit: f32) -> f32 {
 
 /// Transport STOP: clear the note-on replay table (TS `reset` clears `#startShifts`). The parameter values
 /// and bound ids survive (bindings, not sounding state).
-#[no_mangle]
-pub extern "C" fn reset(state_ptr: u32) {
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn reset(state_ptr: usize) {
     unsafe { abi::with_state(state_ptr, |state: &mut TransposeState| state.starts_len = 0) }
 }
 
@@ -6,7 +6,8 @@
 //! In the engine this device is instantiated once per `PlayfieldSampleBox`, with a note filter in front so a
 //! slot only sees its own note. Cross-slot behaviour (mute / solo / choke) is the composite's job and is not
 //! here; this proves the voice as a single normal instrument first. `polyphone` is per-slot, so it IS here: a
-//! monophonic slot force-releases its own voices on retrigger.
+//! monophonic slot force-releases its own voices on retrigger. The channel pitch wheel bends every voice by up
+//! to [`abi::PITCH_BEND_RANGE`] semitones on top of the slot's `pitch`.
 //!
 //! The sample is resolved through the engine: the device declares its `file` pointer path with `bind_sample`;
 //! the engine resolves it (Route F) and pushes the handle through `parameter_changed` under the tagged id.
@@ -20,7 +21,8 @@
 
 #[cfg(target_family = "wasm")]
 use core::panic::PanicInfo;
-use abi::{bool_value, float_value, int_value, Block, EventRecord, FieldValue, Instrument, ParamValue, Ports, EVENT_CHOKE, EVENT_NOTE_OFF, EVENT_NOTE_ON};
+use abi::{bool_value, float_value, int_value, Block, EventRecord, FieldValue, Instrument, ParamValue, Ports, EVENT_CHOKE, EVENT_NOTE_OFF, EVENT_NOTE_ON, EVENT_PITCH_BEND, PITCH_BEND_RANGE};
+use dsp::interpolator::Interpolation;
 use dsp::meter::StereoMeter;
 use math::value_mapping::{Exponential, Linear, LinearInteger};
 
@@ -48,6 +50,7 @@ const PEAKS_FIELD: [u16; 1] = [1001];
 // `release` (seconds). The cross-slot fields (mute / solo / exclude) are the composite's.
 const SAMPLE_POINTER: [u16; 1] = [11];
 const INDEX_FIELD: [u16; 1] = [15];
+const INTERPOLATION_FIELD: [u16; 1] = [50]; // the read head's quality, observed like `index`
 // This slot hosts its OWN midi / audio fx chains (it is a composite child): the box keys the composite observes
 // and folds the chains around this device. A leaf instrument with no own chains would export 0.
 const MIDI_EFFECTS_FIELD: u32 = 12;
@@ -68,21 +71,24 @@ const RELEASE_MAPPING: Exponential = Exponential {min: 0.001, max: 5.0}; // seco
 
 /// The device's per-instance state, interpreted from the engine-allocated (zeroed) block: the fixed voice
 /// pool, the engine sample rate, the bound sample handle, the current parameter values the engine pushes (only
-/// `pitch` is read per block while a voice runs; the rest are snapshotted at note-on), a monotonic note-on
-/// counter for oldest-voice stealing, and the binding ids the engine pushes against.
+/// `pitch` is read per block while a voice runs; the rest are snapshotted at note-on), the channel pitch bend,
+/// a monotonic note-on counter for oldest-voice stealing, and the binding ids the engine pushes against.
 pub struct PlayfieldSlotState {
     voices: [SlotVoice; MAX_VOICES],
     positions_id: u32,
-    positions_ptr: u32,
+    positions_ptr: usize,
     meter: StereoMeter,
     peaks_id: u32,
-    peaks_ptr: u32,
+    peaks_ptr: usize,
     sample_rate: f32,
     sample: Option<u32>, // the resolved sample handle while the `file` pointer is bound; `None` when unbound
     note_index: i32, // the MIDI note this slot plays; a note-on with a different pitch is ignored. Observed,
     note_index_id: u32,   // not a parameter: the device `observe_field`s `index` and stores the value here.
+    interpolation: Interpolation, // zeroed = `Linear`, the box default
+    interpolation_id: u32,
     gate: i32,
     pitch_cents: f32,
+    pitch_bend: f32, // semitones
     sample_start: f32,
     sample_end: f32,
     attack_seconds: f32,
@@ -117,6 +123,7 @@ impl Instrument for PlayfieldSlot {
         state.polyphone = false;
         state.note_index = 60; // PlayfieldSampleBox default; the engine catches up the real value right after init
         state.note_index_id = abi::observe_field(&INDEX_FIELD); // a plain field observation, NOT a parameter
+        state.interpolation_id = abi::observe_field(&INTERPOLATION_FIELD);
         state.gate_id = abi::bind_parameter(&GATE_FIELD);
         state.pitch_cents_id = abi::bind_parameter(&PITCH_FIELD);
         state.sample_start_id = abi::bind_parameter(&SAMPLE_START_FIELD);
@@ -169,6 +176,8 @@ impl Instrument for PlayfieldSlot {
                     voice.force_release();
                 }
             }
+        } else if event.kind == EVENT_PITCH_BEND {
+            state.pitch_bend = event.velocity.clamp(-1.0, 1.0) * PITCH_BEND_RANGE;
         }
     }
 
@@ -188,9 +197,10 @@ impl Instrument for PlayfieldSlot {
         let num_frames = sample.frame_count as usize;
         let src_rate = sample.sample_rate;
         let engine_rate = state.sample_rate;
-        let pitch = state.pitch_cents; // the one parameter read live while a voice runs
+        let pitch = state.pitch_cents + state.pitch_bend * 100.0; // the one parameter read live, plus the wheel
+        let interpolation = state.interpolation;
         for voice in state.voices.iter_mut() {
-            if voice.is_used() && voice.process(out_left, out_right, left, right, num_frames, src_rate, engine_rate, pitch) {
+            if voice.is_used() && voice.process(out_left, out_right, left, right, num_frames, src_rate, engine_rate, pitch, interpolation) {
                 voice.free();
             }
         }
@@ -244,6 +254,10 @@ impl Instrument for PlayfieldSlot {
                 FieldValue::Int(note) => note,
                 _ => panic!("Playfield slot index must be an int field")
             };
+        } else if id == state.interpolation_id {
+            if let FieldValue::Int(index) = value {
+                state.interpolation = Interpolation::from_index(index);
+            }
         }
     }
 
@@ -258,6 +272,7 @@ impl Instrument for PlayfieldSlot {
         for voice in state.voices.iter_mut() {
             voice.free();
         }
+        state.pitch_bend = 0.0;
     }
 }
 
@@ -292,51 +307,51 @@ pub fn render(state: &mut PlayfieldSlotState, events: &[EventRecord], out_left:
 // ---- The device ABI: shared with the engine, called wasm-to-wasm. ----
 
 /// What the host wires this device as (read at load): an instrument that voices notes into audio.
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn kind() -> u32 {
     abi::DEVICE_KIND_INSTRUMENT
 }
 
 /// Bytes the engine must allocate (zeroed) for one instance's state block. The voice pool is fixed, so the
 /// size does not depend on `sample_rate`.
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn state_size(_sample_rate: f32) -> u32 {
     core::mem::size_of::<PlayfieldSlotState>() as u32
 }
 
 /// The box field keys hosting this device's OWN midi / audio fx chains when it runs as a composite child. The
 /// composite reads these to observe each chain and fold it around the device, so the keys live with the device.
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn midi_effects_field() -> u32 {
     MIDI_EFFECTS_FIELD
 }
 
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn audio_effects_field() -> u32 {
     AUDIO_EFFECTS_FIELD
 }
 
-#[no_mangle]
-pub extern "C" fn process(desc_ptr: u32) {
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn process(desc_ptr: usize) {
     let ports = unsafe { Ports::<PlayfieldSlotState>::from_descriptor(desc_ptr) };
     abi::render_instrument::<PlayfieldSlot>(ports);
 }
 
 /// Boot hook: bind this device's parameters + its sample reference with the host, and stash the sample rate.
-#[no_mangle]
-pub extern "C" fn init(state_ptr: u32, sample_rate: f32) {
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn init(state_ptr: usize, sample_rate: f32) {
     unsafe { abi::with_state(state_ptr, |state| <PlayfieldSlot as Instrument>::init(state, sample_rate)) }
 }
 
 /// Apply a parameter value the host resolved (initial / edit / automation, or the sample handle under the
 /// tagged sample id), by the id `init` got back.
-#[no_mangle]
-pub extern "C" fn parameter_changed(state_ptr: u32, id: u32, kind: u32, value: f32) {
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn parameter_changed(state_ptr: usize, id: u32, kind: u32, value: f32) {
     unsafe { abi::with_state(state_ptr, |state| <PlayfieldSlot as Instrument>::parameter_changed(state, id, ParamValue::from_wire(kind, value))) }
 }
 
 /// Parity probe: the REAL value stored for a UNIT automation value, ids in `init` bind order.
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn map_parameter(id: u32, unit: f32) -> f32 {
     let value = ParamValue::Unit(unit);
     match id {
@@ -350,24 +365,24 @@ pub extern "C" fn map_parameter(id: u32, unit: f32) -> f32 {
     }
 }
 
-/// Apply an observed plain field's value (its `index`), by the id `observe_field` returned. Driven by the
+/// Apply an observed plain field's value (its `index` or `interpolation`), by the id `observe_field` returned. Driven by the
 /// host's `catchup_and_subscribe`, only inside a transaction. The wire `(kind, bits, len)` decodes to a typed
 /// `FieldValue` (`len` is the string length for `FIELD_KIND_STRING`, unused otherwise).
-#[no_mangle]
-pub extern "C" fn field_changed(state_ptr: u32, id: u32, kind: u32, bits: u32, len: u32) {
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn field_changed(state_ptr: usize, id: u32, kind: u32, bits: usize, len: u32) {
     unsafe { abi::with_state(state_ptr, |state| <PlayfieldSlot as Instrument>::field_changed(state, id, FieldValue::from_wire(kind, bits, len))) }
 }
 
 /// Transport STOP: drop every voice so the slot starts silent.
-#[no_mangle]
-pub extern "C" fn reset(state_ptr: u32) {
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn reset(state_ptr: usize) {
     unsafe { abi::with_state(state_ptr, |state| <PlayfieldSlot as Instrument>::reset(state)) }
 }
 
 /// Apply an observed sample reference (its `file` pointer), by the id `observe_sample` returned. Driven by the
 /// host's pointer observer; `present != 0` means a resident `handle`, `0` means the pointer is unbound.
-#[no_mangle]
-pub extern "C" fn sample_changed(state_ptr: u32, id: u32, handle: u32, present: u32) {
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn sample_changed(state_ptr: usize, id: u32, handle: u32, present: u32) {
     let sample = if present != 0 {Some(handle)} else {None};
     unsafe { abi::with_state(state_ptr, |state| <PlayfieldSlot as Instrument>::sample_changed(state, id, sample)) }
 }
@@ -392,4 +407,14 @@ mod tests {
         render(&mut state, &[note_on(1, 60)], &mut left, &mut right, SR);
         assert_eq!(left.iter().fold(0.0f32, |acc, value| acc.max(value.abs())), 0.0, "no audio until a sample is resident");
     }
+
+    #[test]
+    fn the_pitch_wheel_sets_the_bend_and_reset_recentres_it() {
+        let mut state: PlayfieldSlotState = unsafe { core::mem::zeroed() };
+        let bend = EventRecord {kind: EVENT_PITCH_BEND, velocity: 0.5, ..note_on(0, 0)};
+        PlayfieldSlot::handle_event(&mut state, &bend);
+        assert_eq!(state.pitch_bend, 0.5 * PITCH_BEND_RANGE);
+        PlayfieldSlot::reset(&mut state);
+        assert_eq!(state.pitch_bend, 0.0);
+    }
 }
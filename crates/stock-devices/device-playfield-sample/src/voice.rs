@@ -1,5 +1,5 @@
 //! One Playfield slot voice, a faithful port of the TS `SampleVoice` (the inner voice of
-//! `Playfield/SampleProcessor`): a pitch-rate read head over the loaded sample with linear interpolation, a
+//! `Playfield/SampleProcessor`): a pitch-rate read head over the loaded sample (linear, or a windowed sinc), a
 //! windowed `start`..`end` region (reversed when `end < start`), gate modes (Off / On / Loop), and a squared
 //! attack / release envelope scaled by the note's velocity gain. Pure DSP over slices, so it is unit-testable
 //! with synthetic frames; the device owns the sample resolution and the voice pool. Heap-free and valid when
@@ -17,6 +17,8 @@
 //!   indahouse "sounds very different after the first kick" bug: ~7x spikes pumping the master maximizer);
 //! - a zero-length window (`sign == 0`) ends the voice rather than leaving a stuck read head.
 
+use dsp::interpolator::Interpolation;
+
 const GATE_OFF: i32 = 0;
 const GATE_ON: i32 = 1;
 const GATE_LOOP: i32 = 2;
@@ -32,15 +34,6 @@ fn sign(value: f64) -> f32 {
     }
 }
 
-/// Linear interpolation of a planar buffer at a fractional position. The neighbour past the end reads 0.0
-/// (TS `inp[i + 1] ?? 0`); an out-of-range base index reads 0.0 too (defensive, the gate logic keeps the base
-/// in range before the voice ends).
-fn sample_at(buffer: &[f32], index: usize, frac: f32) -> f32 {
-    let here = buffer.get(index).copied().unwrap_or(0.0);
-    let next = buffer.get(index + 1).copied().unwrap_or(0.0);
-    here * (1.0 - frac) + next * frac
-}
-
 #[derive(Clone, Copy, Default)]
 pub struct SlotVoice {
     used: bool,       // slot occupied: `process` runs and the device frees it when `process` returns true
@@ -146,18 +139,16 @@ impl SlotVoice {
 
     /// Render additively into the stereo chunk from the planar sample (`left` / `right`, mono passes the same
     /// slice for both). `pitch_cents` is read live (the one automatable-during-the-voice parameter). Returns
-    /// `true` once finished, so the device frees the slot. Mirrors TS `processAdd`, with the parity decisions
-    /// noted in the module docs.
+    /// `true` once finished, so the device frees the slot. Mirrors TS `processAdd` (whose read is
+    /// `Interpolation::Linear`: past the end reads 0.0, TS `inp[i + 1] ?? 0`), with the parity decisions noted
+    /// in the module docs.
     #[allow(clippy::too_many_arguments)]
     pub fn process(&mut self, out_left: &mut [f32], out_right: &mut [f32], left: &[f32], right: &[f32],
-                   num_frames: usize, src_rate: f32, engine_rate: f32, pitch_cents: f32) -> bool {
+                   num_frames: usize, src_rate: f32, engine_rate: f32, pitch_cents: f32, interpolation: Interpolation) -> bool {
         let pitch_factor = libm::exp2f(pitch_cents / 1200.0) as f64;
         let rate_ratio = (src_rate as f64 / engine_rate as f64) * self.sign as f64 * pitch_factor;
         for index in 0..out_left.len() {
-            let int_position = self.position as usize;
-            let frac = (self.position - int_position as f64) as f32;
-            let sample_left = sample_at(left, int_position, frac);
-            let sample_right = sample_at(right, int_position, frac);
+            let (sample_left, sample_right) = interpolation.read_stereo(left, right, self.position, rate_ratio);
             let attack_term = self.env_position / self.attack;
             let mut env = if self.released {
                 let release_term = 1.0 - (self.env_position - (self.decay_position + self.attack)) / self.release;
@@ -233,6 +224,7 @@ impl SlotVoice {
 #[cfg(test)]
 mod tests {
     use super::SlotVoice;
+    use dsp::interpolator::Interpolation;
 
     const SR: f32 = 48_000.0;
 
@@ -254,7 +246,7 @@ mod tests {
         let frames = vec![1.0f32; 64];
         let (mut left, mut right) = (vec![0.0f32; 256], vec![0.0f32; 256]);
         // 64-frame sample at native rate finishes within a 256-sample chunk.
-        assert!(voice.process(&mut left, &mut right, &frames, &frames, 64, SR, SR, 0.0), "ends past the last frame");
+        assert!(voice.process(&mut left, &mut right, &frames, &frames, 64, SR, SR, 0.0, Interpolation::Linear), "ends past the last frame");
     }
 
     #[test]
@@ -262,7 +254,7 @@ mod tests {
         let mut voice = started(0, 0.0, 1.0, 48_000);
         let frames = vec![1.0f32; 48_000];
         let (mut left, mut right) = (vec![0.0f32; 64], vec![0.0f32; 64]);
-        assert!(!voice.process(&mut left, &mut right, &frames, &frames, 48_000, SR, SR, 0.0), "still sounding");
+        assert!(!voice.process(&mut left, &mut right, &frames, &frames, 48_000, SR, SR, 0.0, Interpolation::Linear), "still sounding");
         assert!(left[0].abs() < 1.0e-6, "starts at silence (env 0)");
         assert!(left[63] > left[0], "ramps up across the attack");
         assert_eq!(left, right, "a mono sample feeds both channels equally");
@@ -276,19 +268,33 @@ mod tests {
         let mut octave = started(0, 0.0, 1.0, 4_000);
         let (mut l0, mut r0) = (vec![0.0f32; 1_000], vec![0.0f32; 1_000]);
         let (mut l1, mut r1) = (vec![0.0f32; 1_000], vec![0.0f32; 1_000]);
-        native.process(&mut l0, &mut r0, &frames, &frames, 4_000, SR, SR, 0.0);
-        octave.process(&mut l1, &mut r1, &frames, &frames, 4_000, SR, SR, 1200.0);
+        native.process(&mut l0, &mut r0, &frames, &frames, 4_000, SR, SR, 0.0, Interpolation::Linear);
+        octave.process(&mut l1, &mut r1, &frames, &frames, 4_000, SR, SR, 1200.0, Interpolation::Linear);
         // Past the attack the envelope is ~1; the octave-up voice has advanced ~twice as far.
         assert!(l1[900] > l0[900] * 1.8, "an octave up (1200 cents) roughly doubles the read rate");
     }
 
+    #[test]
+    fn a_sinc_read_band_limits_a_reversed_pitched_up_voice() {
+        // a 15 kHz tone an octave up (played backwards, so the rate is negative) lands past Nyquist
+        let frames: Vec<f32> = (0..8_192).map(|i| libm::sinf(2.0 * core::f32::consts::PI * 15_000.0 * i as f32 / SR)).collect();
+        let level = |interpolation: Interpolation| {
+            let mut voice = started(0, 1.0, 0.0, 8_192);
+            let (mut left, mut right) = (vec![0.0f32; 2_048], vec![0.0f32; 2_048]);
+            voice.process(&mut left, &mut right, &frames, &frames, 8_192, SR, SR, 1200.0, interpolation);
+            peak(&left[512..])
+        };
+        assert!(level(Interpolation::Linear) > 0.1, "linear {}", level(Interpolation::Linear));
+        assert!(level(Interpolation::Sinc) < 0.01, "sinc {}", level(Interpolation::Sinc));
+    }
+
     #[test]
     fn reverse_window_plays_backwards_and_finishes_at_zero() {
         // end < start => reverse playback, ending when the head reaches 0.
         let mut voice = started(0, 1.0, 0.0, 4_000);
         let frames = vec![1.0f32; 4_000];
         let (mut left, mut right) = (vec![0.0f32; 8_000], vec![0.0f32; 8_000]);
-        assert!(voice.process(&mut left, &mut right, &frames, &frames, 4_000, SR, SR, 0.0), "reverse run reaches 0 and ends");
+        assert!(voice.process(&mut left, &mut right, &frames, &frames, 4_000, SR, SR, 0.0, Interpolation::Linear), "reverse run reaches 0 and ends");
     }
 
     #[test]
@@ -297,7 +303,7 @@ mod tests {
         let frames = vec![1.0f32; 4_000];
         let (mut left, mut right) = (vec![0.0f32; 16_000], vec![0.0f32; 16_000]);
         // Far more output than the window length: a non-looping voice would have ended; the loop keeps going.
-        assert!(!voice.process(&mut left, &mut right, &frames, &frames, 4_000, SR, SR, 0.0), "loop never runs out");
+        assert!(!voice.process(&mut left, &mut right, &frames, &frames, 4_000, SR, SR, 0.0, Interpolation::Linear), "loop never runs out");
     }
 
     #[test]
@@ -305,10 +311,10 @@ mod tests {
         let mut voice = started(1, 0.0, 1.0, 48_000); // gate On so note-off releases
         let frames = vec![1.0f32; 48_000];
         let (mut left, mut right) = (vec![0.0f32; 4_800], vec![0.0f32; 4_800]);
-        voice.process(&mut left, &mut right, &frames, &frames, 48_000, SR, SR, 0.0); // past the attack
+        voice.process(&mut left, &mut right, &frames, &frames, 48_000, SR, SR, 0.0, Interpolation::Linear); // past the attack
         voice.release();
         let (mut tail_left, mut tail_right) = (vec![0.0f32; 4_096], vec![0.0f32; 4_096]);
-        let finished = voice.process(&mut tail_left, &mut tail_right, &frames, &frames, 48_000, SR, SR, 0.0);
+        let finished = voice.process(&mut tail_left, &mut tail_right, &frames, &frames, 48_000, SR, SR, 0.0, Interpolation::Linear);
         assert!(finished, "the release elapses within the chunk");
         assert!(peak(&tail_left[2_048..]) < 1.0e-6, "silent once released");
     }
@@ -325,10 +331,10 @@ mod tests {
         voice.start(1, 1.0, 0, 0.001, 3.1, 0.0, 0.44, 24_000, SR, 0);
         let frames = vec![0.5f32; 24_000];
         let (mut left, mut right) = (vec![0.0f32; 12_000], vec![0.0f32; 12_000]);
-        assert!(!voice.process(&mut left, &mut right, &frames, &frames, 24_000, SR, SR, 0.0), "still ringing its long natural release");
+        assert!(!voice.process(&mut left, &mut right, &frames, &frames, 24_000, SR, SR, 0.0, Interpolation::Linear), "still ringing its long natural release");
         voice.force_release();
         let (mut tail_left, mut tail_right) = (vec![0.0f32; 256], vec![0.0f32; 256]);
-        let finished = voice.process(&mut tail_left, &mut tail_right, &frames, &frames, 24_000, SR, SR, 0.0);
+        let finished = voice.process(&mut tail_left, &mut tail_right, &frames, &frames, 24_000, SR, SR, 0.0, Interpolation::Linear);
         assert!(finished, "the elapsed fast release ends the voice at once");
         let spike = peak(&tail_left);
         assert!(spike <= 0.5, "a force-release after the natural release must not spike, peak {spike}");
@@ -339,6 +345,6 @@ mod tests {
         let mut voice = started(2, 0.5, 0.5, 4_000); // start == end, gate Loop (the would-be hang)
         let frames = vec![1.0f32; 4_000];
         let (mut left, mut right) = (vec![0.0f32; 256], vec![0.0f32; 256]);
-        assert!(voice.process(&mut left, &mut right, &frames, &frames, 4_000, SR, SR, 0.0), "a zero-length window ends at once");
+        assert!(voice.process(&mut left, &mut right, &frames, &frames, 4_000, SR, SR, 0.0, Interpolation::Linear), "a zero-length window ends at once");
     }
 }
@@ -0,0 +1,491 @@
+//! The RATCHET (note-repeat) MIDI-effect device (`RatchetDeviceBox`): a pull source wired before an
+//! instrument that RETRIGGERS every held note on a tempo-synced grid. It is built like the arpeggiator: the
+//! host calls `process_events(from, to, ...)`, the device pulls its own upstream, tracks the held source
+//! notes by their pulse span (the `duration` a note-on carries, shortened by an early note-off) and strikes
+//! each of them again on every RATE-grid step the span covers, scheduling the note-off of each strike. Unlike
+//! the arp nothing is picked from the stack: a held chord repeats as a chord.
+//!
+//! A RATCHET pattern (`ratchet-length` `[13]`, 0 = off, over the first steps of the `ratchets` array `[14]`)
+//! splits a grid step into 1..8 evenly spaced hits, and the VELOCITY RAMP shapes the hits within a step: a
+//! positive ramp builds up to the source velocity on the last hit (starting at `1 - ramp` of it), a negative
+//! one fades out from it (ending at `1 + ramp` of it). A step with a single hit is struck as played.
+//!
+//! Parameters: rateIndex `[10]` (into `RATE_FRACTIONS`, the arp's table), gate `[11]` (0..1, the hit length as
+//! a fraction of the hit spacing, so repeats of one pitch never overlap), velocityRamp `[12]` (bipolar).
+//! Steps land on the absolute grid `index * rate` (as the arp's), so a render, a replay and a seek strike the
+//! same hits whatever the block boundaries. On a transport jump (DISCONTINUOUS) it releases everything it
+//! holds, as the arp does. Channel events pass through; note expressions are dropped (the repeats carry new
+//! ids). Nothing is emitted while the transport is not moving.
+//!
+//! Exports: `kind()` (midi effect), `state_size()`, `init(...)`, `parameter_changed(...)`, `field_changed(...)`,
+//! `map_parameter(...)`, `process_events(...)`, `reset(...)`.
+
+#![cfg_attr(target_family = "wasm", no_std)]
+
+#[cfg(target_family = "wasm")]
+use core::panic::PanicInfo;
+use abi::{EventRecord, FieldValue, ParamValue, EVENT_NOTE_OFF, EVENT_NOTE_ON};
+use math::value_mapping::{Linear, LinearInteger};
+
+#[cfg(target_family = "wasm")]
+#[panic_handler]
+fn panic(info: &PanicInfo) -> ! {
+    abi::panic_to_host(info) // deposit the message in the engine's panic buffer, then trap (never a silent hang)
+}
+
+// WASM CONTRACT: mirrors `PPQN` (Quarter = 960, Bar = 3840) and `Fraction.toPPQN` = floor(Bar / d) * n.
+const BAR: i64 = 3840;
+
+// WASM CONTRACT: the arp's rate table (`ArpeggioDeviceBoxAdapter.RateFractions`, descending), so a rate index
+// means the same note value on both devices.
+const RATE_FRACTIONS: [(i64, i64); 17] = [
+    (1, 1), (1, 2), (1, 3), (1, 4), (3, 16), (1, 6), (1, 8), (3, 32), (1, 12),
+    (1, 16), (3, 64), (1, 24), (1, 32), (1, 48), (1, 64), (1, 96), (1, 128)
+];
+
+fn rate_ppqn(index: i32) -> f64 {
+    let (numerator, denominator) = RATE_FRACTIONS[index.clamp(0, RATE_FRACTIONS.len() as i32 - 1) as usize];
+    ((BAR / denominator) * numerator) as f64
+}
+
+const RATE_FIELD: [u16; 1] = [10];
+const GATE_FIELD: [u16; 1] = [11];
+const RAMP_FIELD: [u16; 1] = [12];
+const RATCHET_LENGTH_FIELD: [u16; 1] = [13];
+const RATCHETS_KEY: u16 = 14; // the step array: count 1
+
+const RATE_MAPPING: LinearInteger = LinearInteger {min: 0, max: (RATE_FRACTIONS.len() - 1) as i32};
+const GATE_MAPPING: Linear = Linear {min: 0.0, max: 1.0};
+const RAMP_MAPPING: Linear = Linear::bipolar();
+
+pub const RATCHET_STEPS: usize = 16;
+pub const MAX_RATCHET: u32 = 8;
+
+const MAX_SOURCE: usize = 32; // held source notes tracked by their span
+const MAX_RETAINED: usize = 64; // emitted hits awaiting their scheduled note-off
+const EMIT_MAX: usize = 256; // events emitted in one block (note-offs + note-ons)
+const PULL_SCRATCH: usize = 256; // on-stack buffer the upstream pull writes into
+
+#[derive(Clone, Copy)]
+struct SourceNote {
+    start: f64,
+    end: f64,
+    pitch: u32,
+    velocity: f32
+}
+
+#[derive(Clone, Copy)]
+struct Retained {
+    id: u32,
+    pitch: u32,
+    complete: f64
+}
+
+/// The device's per-instance state (engine-allocated, zeroed). `seed` sets the box defaults before the engine
+/// pushes the real values, since a zero rate would never leave the grid loop.
+pub struct RatchetState {
+    source: [SourceNote; MAX_SOURCE],
+    retained: [Retained; MAX_RETAINED],
+    source_count: u32,
+    retained_count: u32,
+    next_id: u32,
+    ratchets: [u32; RATCHET_STEPS],
+    ratchet_length: usize,
+    rate: f64,
+    gate: f32,
+    ramp: f32,
+    rate_id: u32,
+    gate_id: u32,
+    ramp_id: u32,
+    ratchet_length_id: u32,
+    ratchet_ids: [u32; RATCHET_STEPS]
+}
+
+/// The hits grid step `index` is split into (1 without a ratchet pattern).
+fn hits(state: &RatchetState, index: i64) -> u32 {
+    if state.ratchet_length == 0 {
+        return 1;
+    }
+    state.ratchets[index.rem_euclid(state.ratchet_length as i64) as usize].clamp(1, MAX_RATCHET)
+}
+
+/// The velocity scale of hit `hit` of `count` in a step: a positive ramp climbs from `1 - ramp` to 1, a
+/// negative one falls from 1 to `1 + ramp`.
+pub fn ramp_scale(ramp: f32, hit: u32, count: u32) -> f32 {
+    if count < 2 {
+        return 1.0;
+    }
+    let progress = hit as f32 / (count - 1) as f32;
+    if ramp >= 0.0 { 1.0 - ramp * (1.0 - progress) } else { 1.0 + ramp * progress }
+}
+
+/// Drop held source notes whose span has ended at/before `from`.
+fn prune_source(state: &mut RatchetState, from: f64) {
+    let mut index = 0;
+    while index < state.source_count as usize {
+        if state.source[index].end <= from {
+            state.source[index] = state.source[state.source_count as usize - 1];
+            state.source_count -= 1;
+        } else {
+            index += 1;
+        }
+    }
+}
+
+/// Fold one block's pulled input into the held-note set, as the arp does: a note-on adds its span, a note-off
+/// shortens the span of the matching pitch to its release position.
+fn ingest(state: &mut RatchetState, input: &[EventRecord]) {
+    for record in input {
+        if record.kind == EVENT_NOTE_ON {
+            if (state.source_count as usize) < MAX_SOURCE && record.duration > 0.0 {
+                state.source[state.source_count as usize] = SourceNote {
+                    start: record.position, end: record.position + record.duration, pitch: record.pitch, velocity: record.velocity
+                };
+                state.source_count += 1;
+            }
+        } else if record.kind == EVENT_NOTE_OFF {
+            for note in state.source[..state.source_count as usize].iter_mut() {
+                if note.pitch == record.pitch && record.position < note.end {
+                    note.end = record.position;
+                }
+            }
+        }
+    }
+}
+
+// Note-ON before note-OFF at an equal position, as the arp orders them (a mono synth glides between
+// abutting hits instead of retriggering).
+fn lifecycle_rank(record: &EventRecord) -> u8 {
+    if record.kind == EVENT_NOTE_OFF { 1 } else { 0 }
+}
+
+fn emit(events: &mut [EventRecord], count: &mut usize, record: EventRecord) {
+    if *count < events.len() {
+        events[*count] = record;
+        *count += 1;
+    }
+}
+
+fn note_off(id: u32, pitch: u32, position: f64) -> EventRecord {
+    EventRecord {position, offset: 0, kind: EVENT_NOTE_OFF, id, pitch, velocity: 0.0, cent: 0.0, duration: 0.0}
+}
+
+/// Release every retained hit whose scheduled end is `< to`, emitting a note-off at that end.
+fn release_completed(state: &mut RatchetState, to: f64, events: &mut [EventRecord], count: &mut usize) {
+    let mut index = 0;
+    while index < state.retained_count as usize {
+        let retained = state.retained[index];
+        if retained.complete < to {
+            emit(events, count, note_off(retained.id, retained.pitch, retained.complete));
+            state.retained[index] = state.retained[state.retained_count as usize - 1];
+            state.retained_count -= 1;
+        } else {
+            index += 1;
+        }
+    }
+}
+
+/// Produce one block's events for `[from, to)`: release due note-offs (all of them on a DISCONTINUOUS jump),
+/// ingest the upstream, walk the rate grid striking every held note on each hit of each step in the range,
+/// then release the note-offs that come due within the block. Returns the count of position-sorted events
+/// written.
+pub fn process(state: &mut RatchetState, from: f64, to: f64, flags: u32, input: &[EventRecord], out: &mut [EventRecord]) -> usize {
+    let blank = EventRecord {position: 0.0, offset: 0, kind: 0, id: 0, pitch: 0, velocity: 0.0, cent: 0.0, duration: 0.0};
+    let mut events = [blank; EMIT_MAX];
+    let mut count = 0;
+    if flags & abi::BlockFlags::DISCONTINUOUS != 0 {
+        for retained in &state.retained[..state.retained_count as usize] {
+            emit(&mut events, &mut count, note_off(retained.id, retained.pitch, from));
+        }
+        state.retained_count = 0;
+        state.source_count = 0;
+    } else {
+        release_completed(state, to, &mut events, &mut count);
+    }
+    prune_source(state, from);
+    ingest(state, input);
+    for record in input.iter().filter(|record| abi::is_channel_event(record.kind)) {
+        emit(&mut events, &mut count, *record);
+    }
+    if flags & abi::BlockFlags::TRANSPORTING != 0 && state.rate > 0.0 && state.source_count > 0 {
+        let gate = state.gate.clamp(0.0, 1.0) as f64;
+        // the step containing `from`: its later hits may still fall in the range
+        let mut index = (from / state.rate) as i64;
+        while (index as f64) * state.rate < to {
+            let count_in_step = hits(state, index);
+            let spacing = state.rate / count_in_step as f64;
+            let duration = (spacing * gate).max(1.0);
+            for hit in 0..count_in_step {
+                let position = index as f64 * state.rate + hit as f64 * spacing;
+                if position < from || position >= to {
+                    continue;
+                }
+                let scale = ramp_scale(state.ramp, hit, count_in_step);
+                for note_index in 0..state.source_count as usize {
+                    let note = state.source[note_index];
+                    if position < note.start || note.end <= position || state.retained_count as usize >= MAX_RETAINED {
+                        continue;
+                    }
+                    let id = state.next_id;
+                    state.next_id = state.next_id.wrapping_add(1);
+                    let velocity = (note.velocity * scale).clamp(0.0, 1.0);
+                    emit(&mut events, &mut count, EventRecord {
+                        position, offset: 0, kind: EVENT_NOTE_ON, id, pitch: note.pitch, velocity, cent: 0.0, duration
+                    });
+                    state.retained[state.retained_count as usize] = Retained {id, pitch: note.pitch, complete: position + duration};
+                    state.retained_count += 1;
+                }
+            }
+            index += 1;
+        }
+    }
+    release_completed(state, to, &mut events, &mut count);
+    events[..count].sort_unstable_by(|left, right| {
+        left.position.partial_cmp(&right.position).unwrap_or(core::cmp::Ordering::Equal).then(lifecycle_rank(left).cmp(&lifecycle_rank(right)))
+    });
+    let written = count.min(out.len());
+    out[..written].copy_from_slice(&events[..written]);
+    written
+}
+
+/// What the host wires this device as (read at load): a MIDI effect (a pull source in the event chain).
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn kind() -> u32 {
+    abi::DEVICE_KIND_MIDI_EFFECT
+}
+
+/// Bytes the engine must allocate (zeroed) for one instance's state block.
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn state_size(_sample_rate: f32) -> u32 {
+    core::mem::size_of::<RatchetState>() as u32
+}
+
+/// Seed a (zeroed) state with the `RatchetDeviceBox` defaults (1/16, gate 0.5, no ramp, no ratchets) and bind
+/// the parameters and fields. Kept separate from the `init` export so tests can seed a state directly.
+pub fn seed(state: &mut RatchetState) {
+    state.rate = rate_ppqn(9);
+    state.gate = 0.5;
+    state.ramp = 0.0;
+    state.ratchets = [1; RATCHET_STEPS];
+    state.ratchet_length = 0;
+    state.rate_id = abi::bind_parameter(&RATE_FIELD);
+    state.gate_id = abi::bind_parameter(&GATE_FIELD);
+    state.ramp_id = abi::bind_parameter(&RAMP_FIELD);
+    state.ratchet_length_id = abi::observe_field(&RATCHET_LENGTH_FIELD);
+    for (index, id) in state.ratchet_ids.iter_mut().enumerate() {
+        *id = abi::observe_field(&[RATCHETS_KEY, index as u16, 1]);
+    }
+}
+
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn init(state_ptr: usize, _sample_rate: f32) {
+    seed(unsafe { &mut *(state_ptr as *mut RatchetState) });
+}
+
+fn apply_parameter(state: &mut RatchetState, id: u32, value: ParamValue) {
+    if id == state.rate_id {
+        state.rate = rate_ppqn(abi::int_value(value, &RATE_MAPPING));
+    } else if id == state.gate_id {
+        state.gate = abi::float_value(value, &GATE_MAPPING);
+    } else if id == state.ramp_id {
+        state.ramp = abi::float_value(value, &RAMP_MAPPING);
+    }
+}
+
+/// Apply a delivered ratchet field: the pattern length (clamped to the step array) or one step's hit count
+/// (clamped to 1..=`MAX_RATCHET`).
+pub fn apply_field(state: &mut RatchetState, id: u32, value: FieldValue) {
+    let FieldValue::Int(value) = value else { panic!("ratchet fields are ints") };
+    if id == state.ratchet_length_id {
+        state.ratchet_length = (value.max(0) as usize).min(RATCHET_STEPS);
+    } else if let Some(index) = state.ratchet_ids.iter().position(|&step_id| step_id == id) {
+        state.ratchets[index] = (value.max(1) as u32).min(MAX_RATCHET);
+    }
+}
+
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn field_changed(state_ptr: usize, id: u32, kind: u32, bits: usize, len: u32) {
+    let state = unsafe { &mut *(state_ptr as *mut RatchetState) };
+    apply_field(state, id, unsafe { FieldValue::from_wire(kind, bits, len) });
+}
+
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn parameter_changed(state_ptr: usize, id: u32, kind: u32, value: f32) {
+    let state = unsafe { &mut *(state_ptr as *mut RatchetState) };
+    apply_parameter(state, id, ParamValue::from_wire(kind, value));
+}
+
+/// Parity probe: the REAL value stored for a UNIT automation value, ids in `seed` bind order.
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn map_parameter(id: u32, unit: f32) -> f32 {
+    let value = ParamValue::Unit(unit);
+    match id {
+        0 => abi::int_value(value, &RATE_MAPPING) as f32,
+        1 => abi::float_value(value, &GATE_MAPPING),
+        2 => abi::float_value(value, &RAMP_MAPPING),
+        _ => f32::NAN
+    }
+}
+
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn process_events(from: f64, to: f64, flags: u32, state_ptr: usize, out_ptr: usize, max: u32) -> u32 {
+    let state = unsafe { &mut *(state_ptr as *mut RatchetState) };
+    let blank = EventRecord {position: 0.0, offset: 0, kind: 0, id: 0, pitch: 0, velocity: 0.0, cent: 0.0, duration: 0.0};
+    let out = unsafe { core::slice::from_raw_parts_mut(out_ptr as *mut EventRecord, max as usize) };
+    // Split the range at parameter-update boundaries (as the arp does); the source is pulled per sub-range.
+    let mut written = 0usize;
+    let mut sub_from = from;
+    let mut boundary = abi::first_update_position(from);
+    loop {
+        let sub_to = if boundary < to { boundary } else { to };
+        let mut scratch = [blank; PULL_SCRATCH];
+        let pulled = abi::pull_events(sub_from, sub_to, flags, &mut scratch);
+        written += process(state, sub_from, sub_to, flags, &scratch[..pulled], &mut out[written..]);
+        if sub_to >= to {
+            break;
+        }
+        abi::apply_param_changes::<RatchetState>(state, boundary, apply_parameter);
+        sub_from = sub_to;
+        boundary = abi::next_update_position(boundary);
+    }
+    written as u32
+}
+
+/// Transport STOP: forget the held notes and the scheduled note-offs. The parameters survive.
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn reset(state_ptr: usize) {
+    let state = unsafe { &mut *(state_ptr as *mut RatchetState) };
+    state.source_count = 0;
+    state.retained_count = 0;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn state() -> RatchetState {
+        let mut state: RatchetState = unsafe { core::mem::zeroed() };
+        state.rate = rate_ppqn(9); // 1/16 = 240
+        state.gate = 0.5;
+        state.ratchets = [1; RATCHET_STEPS];
+        state
+    }
+
+    fn note_on(position: f64, duration: f64, pitch: u32, velocity: f32) -> EventRecord {
+        EventRecord {position, offset: 0, kind: EVENT_NOTE_ON, id: 1, pitch, velocity, cent: 0.0, duration}
+    }
+
+    // The (position, pitch, velocity, duration) of every note-on over `blocks`, `input` pulled in the first.
+    fn ons(state: &mut RatchetState, input: &[EventRecord], blocks: &[(f64, f64)]) -> Vec<(f64, u32, f32, f64)> {
+        let mut out = [note_on(0.0, 0.0, 0, 0.0); 256];
+        let mut struck = Vec::new();
+        for (index, &(from, to)) in blocks.iter().enumerate() {
+            let written = process(state, from, to, abi::BlockFlags::TRANSPORTING, if index == 0 { input } else { &[] }, &mut out);
+            struck.extend(out[..written].iter().filter(|event| event.kind == EVENT_NOTE_ON)
+                .map(|event| (event.position, event.pitch, event.velocity, event.duration)));
+        }
+        struck
+    }
+
+    #[test]
+    fn a_held_note_retriggers_on_the_rate_grid() {
+        let mut state = state();
+        let struck = ons(&mut state, &[note_on(0.0, 960.0, 60, 0.8)], &[(0.0, 3840.0)]);
+        assert_eq!(struck, [(0.0, 60, 0.8, 120.0), (240.0, 60, 0.8, 120.0), (480.0, 60, 0.8, 120.0), (720.0, 60, 0.8, 120.0)]);
+    }
+
+    #[test]
+    fn a_held_chord_repeats_as_a_chord() {
+        let mut state = state();
+        state.rate = rate_ppqn(3); // 1/4
+        let struck = ons(&mut state, &[note_on(0.0, 1920.0, 60, 0.8), note_on(0.0, 1920.0, 64, 0.8)], &[(0.0, 3840.0)]);
+        let mut pitches: Vec<u32> = struck.iter().map(|&(_, pitch, _, _)| pitch).collect();
+        pitches.sort_unstable();
+        assert_eq!(pitches, [60, 60, 64, 64]);
+    }
+
+    #[test]
+    fn ratchets_split_their_step_into_even_hits() {
+        let mut state = state();
+        state.ratchet_length = 3;
+        state.ratchets[1] = 2;
+        state.ratchets[2] = 4;
+        let struck = ons(&mut state, &[note_on(0.0, 720.0, 60, 1.0)], &[(0.0, 3840.0)]);
+        let positions: Vec<f64> = struck.iter().map(|&(position, _, _, _)| position).collect();
+        assert_eq!(positions, [0.0, 240.0, 360.0, 480.0, 540.0, 600.0, 660.0]);
+        assert_eq!((struck[1].3, struck[3].3), (60.0, 30.0), "the gate is a fraction of the hit spacing");
+    }
+
+    #[test]
+    fn the_velocity_ramp_shapes_the_hits_of_a_step() {
+        let mut state = state();
+        state.ratchet_length = 1;
+        state.ratchets[0] = 3;
+        state.ramp = 0.5;
+        let velocities: Vec<f32> = ons(&mut state, &[note_on(0.0, 240.0, 60, 0.8)], &[(0.0, 240.0)]).iter().map(|&(_, _, velocity, _)| velocity).collect();
+        for (velocity, expected) in velocities.iter().zip([0.4, 0.6, 0.8]) {
+            assert!((velocity - expected).abs() < 1.0e-6, "builds up to the played velocity: {velocities:?}");
+        }
+        assert_eq!((ramp_scale(-0.5, 0, 3), ramp_scale(-0.5, 2, 3)), (1.0, 0.5), "a negative ramp fades out");
+        assert_eq!(ramp_scale(1.0, 0, 1), 1.0, "a single hit is struck as played");
+    }
+
+    #[test]
+    fn block_boundaries_do_not_move_the_hits() {
+        let input = [note_on(0.0, 1920.0, 60, 0.8)];
+        let ratcheted = || {
+            let mut state = state();
+            state.ratchet_length = 2;
+            state.ratchets[1] = 3;
+            state
+        };
+        let whole = ons(&mut ratcheted(), &input, &[(0.0, 1920.0)]);
+        let split = ons(&mut ratcheted(), &input, &[(0.0, 250.0), (250.0, 333.0), (333.0, 1000.0), (1000.0, 1920.0)]);
+        assert_eq!(whole.len(), 16);
+        assert_eq!(whole, split);
+    }
+
+    #[test]
+    fn a_note_off_ends_the_repeats() {
+        let mut state = state();
+        let off = EventRecord {kind: EVENT_NOTE_OFF, ..note_on(500.0, 0.0, 60, 0.0)};
+        let struck = ons(&mut state, &[note_on(0.0, 3840.0, 60, 0.8), off], &[(0.0, 3840.0)]);
+        assert_eq!(struck.len(), 3, "struck at 0, 240 and 480");
+    }
+
+    #[test]
+    fn discontinuous_releases_all_held_notes() {
+        let mut state = state();
+        state.gate = 1.0;
+        let mut out = [note_on(0.0, 0.0, 0, 0.0); 64];
+        process(&mut state, 0.0, 100.0, abi::BlockFlags::TRANSPORTING, &[note_on(0.0, 3840.0, 60, 0.8)], &mut out);
+        assert_eq!(state.retained_count, 1);
+        let flags = abi::BlockFlags::TRANSPORTING | abi::BlockFlags::DISCONTINUOUS;
+        let written = process(&mut state, 2000.0, 2100.0, flags, &[], &mut out);
+        assert_eq!((state.retained_count, state.source_count), (0, 0));
+        assert_eq!(written, 1);
+        assert_eq!((out[0].kind, out[0].position), (EVENT_NOTE_OFF, 2000.0), "released at `from`");
+    }
+
+    #[test]
+    fn channel_events_pass_through_and_a_stopped_transport_is_silent() {
+        let mut state = state();
+        let bend = EventRecord {kind: abi::EVENT_PITCH_BEND, velocity: 0.5, ..note_on(100.0, 0.0, 0, 0.0)};
+        let mut out = [note_on(0.0, 0.0, 0, 0.0); 8];
+        let written = process(&mut state, 0.0, 960.0, 0, &[note_on(0.0, 960.0, 60, 0.8), bend], &mut out);
+        assert_eq!(written, 1);
+        assert_eq!(out[0].kind, abi::EVENT_PITCH_BEND);
+    }
+
+    #[test]
+    fn ratchet_fields_arrive_through_field_changed() {
+        let mut state = state();
+        state.ratchet_length_id = 1;
+        state.ratchet_ids = core::array::from_fn(|index| 2 + index as u32);
+        apply_field(&mut state, 1, FieldValue::Int(40));
+        assert_eq!(state.ratchet_length, RATCHET_STEPS, "the length clamps to the step array");
+        apply_field(&mut state, 3, FieldValue::Int(12));
+        apply_field(&mut state, 4, FieldValue::Int(0));
+        assert_eq!((state.ratchets[1], state.ratchets[2]), (MAX_RATCHET, 1));
+    }
+}
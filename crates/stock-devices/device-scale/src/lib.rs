@@ -0,0 +1,477 @@
+//! The SCALE MIDI-effect device (`ScaleDeviceBox`): a pull source wired before an instrument that snaps each
+//! note-on to the nearest note of a key / scale and optionally stacks diatonic chord tones on it. The scale
+//! masks are the autotune's (`dsp::autotune::SCALE_MASKS`, the same eight scales in the same order), so a
+//! vocal and a MIDI part tuned to "D Dorian" agree on every note. A pitch that lies exactly between two
+//! scale notes snaps DOWN.
+//!
+//! Chords are built in the scale: the third, fifth and seventh are the second, fourth and sixth scale notes
+//! above the snapped root (chromatic has no diatonic thirds, so it stacks a major triad / major seventh).
+//! An inversion lifts the lowest chord tones by an octave; a STRUM delays each tone (lowest first) by the
+//! strum length in pulses, so the device holds the tones that fall past the pulled range and emits them in
+//! a later block. The lowest tone keeps the source note's id, the others get ids of their own, and a
+//! note-off releases every tone of its chord (a tone still waiting for its strum is dropped instead). Tones
+//! outside MIDI range 0..=127 are dropped, never clamped.
+//!
+//! Parameters (`ScaleDeviceBox`): key `[10]` (0..11, C..B), scale `[11]` (the `SCALE_MASKS` index), chord
+//! `[12]` (0 off / 1 triad / 2 seventh), inversion `[13]` (0..3), strum `[14]` (0..480 ppqn). The split at
+//! parameter-update boundaries mirrors `render_midi_effect`. A transport jump drops the pending strummed
+//! tones; the upstream's note-offs still release what sounds.
+//!
+//! Exports: `kind()` (midi effect), `state_size()`, `init(...)`, `parameter_changed(...)`, `map_parameter(...)`,
+//! `process_events(...)`, `reset(...)`.
+
+#![cfg_attr(target_family = "wasm", no_std)]
+
+#[cfg(target_family = "wasm")]
+use core::panic::PanicInfo;
+use abi::{int_value, EventRecord, ParamValue, EVENT_NOTE_OFF, EVENT_NOTE_ON};
+use dsp::autotune::{in_scale, SCALE_MASKS};
+use math::value_mapping::LinearInteger;
+
+#[cfg(target_family = "wasm")]
+#[panic_handler]
+fn panic(info: &PanicInfo) -> ! {
+    abi::panic_to_host(info) // deposit the message in the engine's panic buffer, then trap (never a silent hang)
+}
+
+const KEY_FIELD: [u16; 1] = [10];
+const SCALE_FIELD: [u16; 1] = [11];
+const CHORD_FIELD: [u16; 1] = [12];
+const INVERSION_FIELD: [u16; 1] = [13];
+const STRUM_FIELD: [u16; 1] = [14];
+
+const KEY_MAPPING: LinearInteger = LinearInteger {min: 0, max: 11};
+const SCALE_MAPPING: LinearInteger = LinearInteger {min: 0, max: (SCALE_MASKS.len() - 1) as i32};
+const CHORD_MAPPING: LinearInteger = LinearInteger {min: 0, max: 2};
+const INVERSION_MAPPING: LinearInteger = LinearInteger {min: 0, max: 3};
+const STRUM_MAPPING: LinearInteger = LinearInteger {min: 0, max: 480};
+
+pub const CHORD_OFF: i32 = 0;
+pub const CHORD_TRIAD: i32 = 1;
+pub const CHORD_SEVENTH: i32 = 2;
+
+const MAX_TONES: usize = 4;
+const MAX_HELD: usize = 64; // sounding chords whose note-off is still to come
+const MAX_PENDING: usize = 64; // strummed tones waiting past the pulled range
+const EMIT_MAX: usize = 256;
+const PULL_SCRATCH: usize = 256;
+// Ids of the added chord tones live in their own range, clear of the sequencer's note ids.
+const TONE_ID_BASE: u32 = 0x8000_0000;
+
+#[derive(Clone, Copy)]
+struct Held {
+    source: u32,
+    tones: [(u32, u32); MAX_TONES], // (id, pitch)
+    count: usize
+}
+
+/// The device's per-instance state (engine-allocated, zeroed): the parameter values and ids, the sounding
+/// chords (for their note-offs) and the strummed tones still to emit. Valid when zeroed (C chromatic, no
+/// chord, no strum — a pass-through) until the engine pushes the values.
+pub struct ScaleState {
+    key: i32,
+    scale: i32,
+    chord: i32,
+    inversion: i32,
+    strum: f64,
+    held: [Held; MAX_HELD],
+    held_count: usize,
+    pending: [EventRecord; MAX_PENDING],
+    pending_count: usize,
+    next_id: u32,
+    key_id: u32,
+    scale_id: u32,
+    chord_id: u32,
+    inversion_id: u32,
+    strum_id: u32
+}
+
+/// The scale note nearest `pitch` (ties snap down). Every mask holds its root, so one is always within
+/// six semitones.
+pub fn snap(pitch: i32, key: i32, scale: i32) -> i32 {
+    let mask = SCALE_MASKS[scale.clamp(0, SCALE_MASKS.len() as i32 - 1) as usize];
+    for distance in 0..=6 {
+        if in_scale(pitch - distance, key, mask) {
+            return pitch - distance;
+        }
+        if in_scale(pitch + distance, key, mask) {
+            return pitch + distance;
+        }
+    }
+    pitch
+}
+
+/// The chord on the (in-scale) `root`, ascending and inverted: `(tones, count)`.
+pub fn chord_tones(root: i32, key: i32, scale: i32, chord: i32, inversion: i32) -> ([i32; MAX_TONES], usize) {
+    let mut tones = [root; MAX_TONES];
+    let count = match chord {
+        CHORD_TRIAD => 3,
+        CHORD_SEVENTH => 4,
+        _ => return (tones, 1)
+    };
+    let index = scale.clamp(0, SCALE_MASKS.len() as i32 - 1) as usize;
+    if index == 0 {
+        // chromatic: no diatonic thirds to stack, so a major triad / major seventh
+        for (tone, interval) in tones.iter_mut().zip([0, 4, 7, 11]) {
+            *tone = root + interval;
+        }
+    } else {
+        let mut note = root;
+        for tone in tones.iter_mut().skip(1) {
+            for _ in 0..2 {
+                note += 1;
+                while !in_scale(note, key, SCALE_MASKS[index]) {
+                    note += 1;
+                }
+            }
+            *tone = note;
+        }
+    }
+    // an inversion lifts the lowest tones an octave, which keeps the chord ascending when rotated
+    let inversion = (inversion.max(0) as usize).min(count - 1);
+    tones[..count].rotate_left(inversion);
+    for tone in tones[count - inversion..count].iter_mut() {
+        *tone += 12;
+    }
+    (tones, count)
+}
+
+fn emit(events: &mut [EventRecord], count: &mut usize, record: EventRecord) {
+    if *count < events.len() {
+        events[*count] = record;
+        *count += 1;
+    }
+}
+
+// Releases first at an equal position, so a chord re-struck on the same pitch is not cut by its own release.
+fn rank(record: &EventRecord) -> u8 {
+    if record.kind == EVENT_NOTE_OFF { 0 } else { 1 }
+}
+
+fn note_on(state: &mut ScaleState, record: &EventRecord, to: f64, events: &mut [EventRecord], count: &mut usize) {
+    if state.held_count == MAX_HELD {
+        // No room to track the chord for its note-off: play the source note as it came, so its own note-off
+        // (passed through untracked) still releases it.
+        emit(events, count, *record);
+        return;
+    }
+    let root = snap(record.pitch as i32, state.key, state.scale);
+    let (tones, tone_count) = chord_tones(root, state.key, state.scale, state.chord, state.inversion);
+    let mut held = Held {source: record.id, tones: [(0, 0); MAX_TONES], count: 0};
+    for &pitch in &tones[..tone_count] {
+        if !(0..=127).contains(&pitch) {
+            continue;
+        }
+        let delay = held.count as f64 * state.strum;
+        if record.duration > 0.0 && delay >= record.duration {
+            break; // released before its strum reaches it
+        }
+        let id = if held.count == 0 {
+            record.id
+        } else {
+            state.next_id = state.next_id.wrapping_add(1);
+            TONE_ID_BASE | (state.next_id & !TONE_ID_BASE)
+        };
+        let mut tone = *record;
+        tone.id = id;
+        tone.pitch = pitch as u32;
+        tone.position = record.position + delay;
+        if record.duration > 0.0 {
+            tone.duration = record.duration - delay;
+        }
+        if tone.position < to {
+            emit(events, count, tone);
+        } else if state.pending_count < MAX_PENDING {
+            state.pending[state.pending_count] = tone;
+            state.pending_count += 1;
+        } else {
+            continue;
+        }
+        held.tones[held.count] = (id, pitch as u32);
+        held.count += 1;
+    }
+    if held.count > 0 {
+        state.held[state.held_count] = held;
+        state.held_count += 1;
+    }
+}
+
+fn note_off(state: &mut ScaleState, record: &EventRecord, events: &mut [EventRecord], count: &mut usize) {
+    let Some(index) = state.held[..state.held_count].iter().position(|held| held.source == record.id) else {
+        emit(events, count, *record); // not ours (or dropped): pass through, downstream finds no match
+        return;
+    };
+    let held = state.held[index];
+    state.held_count -= 1;
+    state.held[index] = state.held[state.held_count];
+    for &(id, pitch) in &held.tones[..held.count] {
+        if let Some(waiting) = state.pending[..state.pending_count].iter().position(|tone| tone.id == id) {
+            let tone = state.pending[waiting];
+            state.pending_count -= 1;
+            state.pending[waiting] = state.pending[state.pending_count];
+            if tone.position >= record.position {
+                continue; // its strum had not reached it yet: it never starts
+            }
+            emit(events, count, tone);
+        }
+        let mut off = *record;
+        off.id = id;
+        off.pitch = pitch;
+        emit(events, count, off);
+    }
+}
+
+/// Produce one block's events for `[from, to)` from the pulled `input` (note-ons snapped and stacked,
+/// note-offs releasing their chord, anything else passed through), then the strummed tones that come due.
+/// Returns the count of position-sorted events written.
+pub fn process(state: &mut ScaleState, from: f64, to: f64, flags: u32, input: &[EventRecord], out: &mut [EventRecord]) -> usize {
+    let blank = EventRecord {position: 0.0, offset: 0, kind: 0, id: 0, pitch: 0, velocity: 0.0, cent: 0.0, duration: 0.0};
+    let mut events = [blank; EMIT_MAX];
+    let mut count = 0;
+    if flags & abi::BlockFlags::DISCONTINUOUS != 0 {
+        state.pending_count = 0;
+    }
+    for record in input {
+        match record.kind {
+            EVENT_NOTE_ON => note_on(state, record, to, &mut events, &mut count),
+            EVENT_NOTE_OFF => note_off(state, record, &mut events, &mut count),
+            _ => emit(&mut events, &mut count, *record)
+        }
+    }
+    let mut index = 0;
+    while index < state.pending_count {
+        if state.pending[index].position < to {
+            let mut tone = state.pending[index];
+            tone.position = tone.position.max(from);
+            emit(&mut events, &mut count, tone);
+            state.pending_count -= 1;
+            state.pending[index] = state.pending[state.pending_count];
+        } else {
+            index += 1;
+        }
+    }
+    events[..count].sort_unstable_by(|left, right| {
+        left.position.partial_cmp(&right.position).unwrap_or(core::cmp::Ordering::Equal).then(rank(left).cmp(&rank(right)))
+    });
+    let written = count.min(out.len());
+    out[..written].copy_from_slice(&events[..written]);
+    written
+}
+
+/// What the host wires this device as (read at load): a MIDI effect (a pull source in the event chain).
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn kind() -> u32 {
+    abi::DEVICE_KIND_MIDI_EFFECT
+}
+
+/// Bytes the engine must allocate (zeroed) for one instance's state block.
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn state_size(_sample_rate: f32) -> u32 {
+    core::mem::size_of::<ScaleState>() as u32
+}
+
+/// Bind the parameters. Kept separate from the `init` export so tests can seed a state without going through
+/// the extern.
+pub fn seed(state: &mut ScaleState) {
+    state.key_id = abi::bind_parameter(&KEY_FIELD);
+    state.scale_id = abi::bind_parameter(&SCALE_FIELD);
+    state.chord_id = abi::bind_parameter(&CHORD_FIELD);
+    state.inversion_id = abi::bind_parameter(&INVERSION_FIELD);
+    state.strum_id = abi::bind_parameter(&STRUM_FIELD);
+}
+
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn init(state_ptr: usize, _sample_rate: f32) {
+    seed(unsafe { &mut *(state_ptr as *mut ScaleState) });
+}
+
+pub fn apply_parameter(state: &mut ScaleState, id: u32, value: ParamValue) {
+    if id == state.key_id {
+        state.key = int_value(value, &KEY_MAPPING);
+    } else if id == state.scale_id {
+        state.scale = int_value(value, &SCALE_MAPPING);
+    } else if id == state.chord_id {
+        state.chord = int_value(value, &CHORD_MAPPING);
+    } else if id == state.inversion_id {
+        state.inversion = int_value(value, &INVERSION_MAPPING);
+    } else if id == state.strum_id {
+        state.strum = int_value(value, &STRUM_MAPPING) as f64;
+    }
+}
+
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn parameter_changed(state_ptr: usize, id: u32, kind: u32, value: f32) {
+    let state = unsafe { &mut *(state_ptr as *mut ScaleState) };
+    apply_parameter(state, id, ParamValue::from_wire(kind, value));
+}
+
+/// Parity probe: the REAL value stored for a UNIT automation value, ids in `init` bind order.
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn map_parameter(id: u32, unit: f32) -> f32 {
+    let value = ParamValue::Unit(unit);
+    match id {
+        0 => int_value(value, &KEY_MAPPING) as f32,
+        1 => int_value(value, &SCALE_MAPPING) as f32,
+        2 => int_value(value, &CHORD_MAPPING) as f32,
+        3 => int_value(value, &INVERSION_MAPPING) as f32,
+        4 => int_value(value, &STRUM_MAPPING) as f32,
+        _ => f32::NAN
+    }
+}
+
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn process_events(from: f64, to: f64, flags: u32, state_ptr: usize, out_ptr: usize, max: u32) -> u32 {
+    let state = unsafe { &mut *(state_ptr as *mut ScaleState) };
+    let blank = EventRecord {position: 0.0, offset: 0, kind: 0, id: 0, pitch: 0, velocity: 0.0, cent: 0.0, duration: 0.0};
+    let out = unsafe { core::slice::from_raw_parts_mut(out_ptr as *mut EventRecord, max as usize) };
+    let mut written = 0usize;
+    let mut sub_from = from;
+    let mut boundary = abi::first_update_position(from);
+    loop {
+        let sub_to = if boundary < to { boundary } else { to };
+        let mut scratch = [blank; PULL_SCRATCH];
+        let pulled = abi::pull_events(sub_from, sub_to, flags, &mut scratch);
+        written += process(state, sub_from, sub_to, flags, &scratch[..pulled], &mut out[written..]);
+        if sub_to >= to {
+            break;
+        }
+        abi::apply_param_changes::<ScaleState>(state, boundary, apply_parameter);
+        sub_from = sub_to;
+        boundary = abi::next_update_position(boundary);
+    }
+    written as u32
+}
+
+/// Transport STOP: forget the sounding chords and the pending strummed tones. The parameters survive.
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn reset(state_ptr: usize) {
+    let state = unsafe { &mut *(state_ptr as *mut ScaleState) };
+    state.held_count = 0;
+    state.pending_count = 0;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAJOR: i32 = 1;
+    const MINOR_PENTATONIC: i32 = 4;
+
+    fn state(key: i32, scale: i32, chord: i32, inversion: i32, strum: f64) -> ScaleState {
+        let mut state: ScaleState = unsafe { core::mem::zeroed() };
+        (state.key, state.scale, state.chord, state.inversion, state.strum) = (key, scale, chord, inversion, strum);
+        (state.key_id, state.scale_id, state.chord_id, state.inversion_id, state.strum_id) = (1, 2, 3, 4, 5);
+        state
+    }
+
+    fn record(kind: u32, position: f64, id: u32, pitch: u32) -> EventRecord {
+        EventRecord {position, offset: 0, kind, id, pitch, velocity: 0.8, cent: 0.0, duration: if kind == EVENT_NOTE_ON { 960.0 } else { 0.0 }}
+    }
+
+    fn run(state: &mut ScaleState, from: f64, to: f64, input: &[EventRecord]) -> Vec<(u32, f64, u32)> {
+        let mut out = [record(0, 0.0, 0, 0); 32];
+        let written = process(state, from, to, abi::BlockFlags::TRANSPORTING, input, &mut out);
+        out[..written].iter().map(|event| (event.kind, event.position, event.pitch)).collect()
+    }
+
+    #[test]
+    fn snaps_to_the_nearest_scale_note_ties_down() {
+        // C major: C# (61) sits between C and D and snaps down, F# (66) to F, A# (70) to A, B (71) stays
+        assert_eq!([61, 66, 70, 71, 62].map(|pitch| snap(pitch, 0, MAJOR)), [60, 65, 69, 71, 62]);
+        // D minor pentatonic (D F G A C): E (64) is nearer F than D, B (71) goes up to C, G# (68) ties down
+        assert_eq!([64, 71, 68].map(|pitch| snap(pitch, 2, MINOR_PENTATONIC)), [65, 72, 67]);
+        assert_eq!(snap(61, 0, 0), 61, "chromatic leaves every note alone");
+    }
+
+    #[test]
+    fn stacks_diatonic_triads_and_sevenths() {
+        let tones = |root, chord, inversion| {
+            let (tones, count) = chord_tones(root, 0, MAJOR, chord, inversion);
+            tones[..count].to_vec()
+        };
+        assert_eq!(tones(60, CHORD_TRIAD, 0), [60, 64, 67], "I is major");
+        assert_eq!(tones(62, CHORD_TRIAD, 0), [62, 65, 69], "ii is minor");
+        assert_eq!(tones(71, CHORD_TRIAD, 0), [71, 74, 77], "vii is diminished");
+        assert_eq!(tones(67, CHORD_SEVENTH, 0), [67, 71, 74, 77], "V7 is dominant");
+        assert_eq!(tones(60, CHORD_SEVENTH, 0), [60, 64, 67, 71], "Imaj7");
+        assert_eq!(tones(60, CHORD_OFF, 2), [60], "no chord is the root alone");
+        let (chromatic, count) = chord_tones(61, 0, 0, CHORD_TRIAD, 0);
+        assert_eq!(chromatic[..count], [61, 65, 68], "chromatic stacks a major triad");
+    }
+
+    #[test]
+    fn inversions_lift_the_lowest_tones() {
+        let tones = |chord, inversion| {
+            let (tones, count) = chord_tones(60, 0, MAJOR, chord, inversion);
+            tones[..count].to_vec()
+        };
+        assert_eq!(tones(CHORD_TRIAD, 1), [64, 67, 72]);
+        assert_eq!(tones(CHORD_TRIAD, 2), [67, 72, 76]);
+        assert_eq!(tones(CHORD_TRIAD, 3), [67, 72, 76], "a triad has two inversions");
+        assert_eq!(tones(CHORD_SEVENTH, 3), [71, 72, 76, 79]);
+    }
+
+    #[test]
+    fn a_chord_sounds_and_releases_together() {
+        let mut state = state(0, MAJOR, CHORD_TRIAD, 0, 0.0);
+        let ons = run(&mut state, 0.0, 480.0, &[record(EVENT_NOTE_ON, 0.0, 7, 61)]);
+        assert_eq!(ons, [(EVENT_NOTE_ON, 0.0, 60), (EVENT_NOTE_ON, 0.0, 64), (EVENT_NOTE_ON, 0.0, 67)]);
+        let mut out = [record(0, 0.0, 0, 0); 8];
+        let written = process(&mut state, 480.0, 1200.0, abi::BlockFlags::TRANSPORTING, &[record(EVENT_NOTE_OFF, 960.0, 7, 61)], &mut out);
+        let mut offs: Vec<(u32, u32)> = out[..written].iter().map(|event| (event.kind, event.pitch)).collect();
+        offs.sort_unstable();
+        assert_eq!(offs, [(EVENT_NOTE_OFF, 60), (EVENT_NOTE_OFF, 64), (EVENT_NOTE_OFF, 67)]);
+        assert!(out[..written].iter().any(|event| event.id == 7), "the lowest tone keeps the source id");
+        assert!(out[..written].iter().filter(|event| event.id != 7).all(|event| event.id & TONE_ID_BASE != 0));
+        assert_eq!(state.held_count, 0);
+    }
+
+    #[test]
+    fn a_strum_carries_late_tones_into_later_blocks() {
+        let mut state = state(0, MAJOR, CHORD_TRIAD, 0, 120.0);
+        let first = run(&mut state, 0.0, 200.0, &[record(EVENT_NOTE_ON, 0.0, 7, 60)]);
+        assert_eq!(first, [(EVENT_NOTE_ON, 0.0, 60), (EVENT_NOTE_ON, 120.0, 64)]);
+        let second = run(&mut state, 200.0, 400.0, &[]);
+        assert_eq!(second, [(EVENT_NOTE_ON, 240.0, 67)], "the held tone comes due");
+    }
+
+    #[test]
+    fn a_release_before_the_strum_drops_the_waiting_tone() {
+        let mut state = state(0, MAJOR, CHORD_TRIAD, 0, 120.0);
+        run(&mut state, 0.0, 200.0, &[record(EVENT_NOTE_ON, 0.0, 7, 60)]);
+        let released = run(&mut state, 200.0, 400.0, &[record(EVENT_NOTE_OFF, 200.0, 7, 60)]);
+        assert_eq!(released.len(), 2, "two offs, and the third tone never starts: {released:?}");
+        assert!(released.iter().all(|&(kind, position, _)| kind == EVENT_NOTE_OFF && position == 200.0));
+        assert_eq!(state.pending_count, 0);
+    }
+
+    #[test]
+    fn out_of_range_tones_drop_and_other_events_pass() {
+        let mut state = state(0, MAJOR, CHORD_TRIAD, 0, 0.0);
+        let bend = EventRecord {kind: abi::EVENT_PITCH_BEND, ..record(0, 10.0, 0, 0)};
+        let out = run(&mut state, 0.0, 480.0, &[record(EVENT_NOTE_ON, 0.0, 1, 124), bend]);
+        assert_eq!(out, [(EVENT_NOTE_ON, 0.0, 124), (EVENT_NOTE_ON, 0.0, 127), (abi::EVENT_PITCH_BEND, 10.0, 0)]);
+    }
+
+    #[test]
+    fn a_note_past_the_held_table_passes_through_unchanged() {
+        let mut state = state(0, MAJOR, CHORD_TRIAD, 0, 0.0);
+        for id in 0..MAX_HELD as u32 {
+            run(&mut state, 0.0, 480.0, &[record(EVENT_NOTE_ON, 0.0, id, 60)]);
+        }
+        let on = run(&mut state, 0.0, 480.0, &[record(EVENT_NOTE_ON, 0.0, 99, 61)]);
+        assert_eq!(on, [(EVENT_NOTE_ON, 0.0, 61)], "no chord it could not release");
+        let off = run(&mut state, 480.0, 1200.0, &[record(EVENT_NOTE_OFF, 960.0, 99, 61)]);
+        assert_eq!(off, [(EVENT_NOTE_OFF, 960.0, 61)]);
+        assert_eq!(state.held_count, MAX_HELD);
+    }
+
+    #[test]
+    fn parameters_map_unit_values() {
+        let mut state = state(0, 0, 0, 0, 0.0);
+        apply_parameter(&mut state, 1, ParamValue::Unit(1.0));
+        apply_parameter(&mut state, 2, ParamValue::Int(6));
+        apply_parameter(&mut state, 5, ParamValue::Unit(0.5));
+        assert_eq!((state.key, state.scale, state.strum), (11, 6, 240.0));
+    }
+}
@@ -13,7 +13,7 @@
 
 #[cfg(target_family = "wasm")]
 use core::panic::PanicInfo;
-use abi::{Block, BlockFlags, EventRecord, Ports, EVENT_NOTE_ON};
+use abi::{Block, BlockFlags, EventRecord, Ports, EVENT_NOTE_OFF, EVENT_NOTE_ON};
 use dsp::adsr::Adsr;
 use dsp::{fast_sin, midi_to_hz, PI};
 
@@ -100,7 +100,7 @@ impl abi::Instrument for Synth {
             if let Some(slot) = state.voices.iter_mut().find(|voice| voice.active == 0) {
                 slot.start(event, sample_rate);
             }
-        } else {
+        } else if event.kind == EVENT_NOTE_OFF {
             for voice in state.voices.iter_mut() {
                 if voice.active != 0 && voice.id == event.id {
                     voice.env.gate_off();
@@ -141,25 +141,25 @@ pub fn render(state: &mut SynthState, events: &[EventRecord], out_left: &mut [f3
 /// Bytes the engine must allocate (zeroed) for one instance's state block. The sine's state is a fixed
 /// voice array, so the size does not depend on `sample_rate`; the parameter keeps the ABI uniform with
 /// devices whose state IS rate-sized (e.g. a device with a sample-rate-sized delay buffer).
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn state_size(_sample_rate: f32) -> u32 {
     core::mem::size_of::<SynthState>() as u32
 }
 
 /// What the host wires this device as (read at load): an instrument that voices notes into audio.
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn kind() -> u32 {
     abi::DEVICE_KIND_INSTRUMENT
 }
 
-#[no_mangle]
-pub extern "C" fn process(desc_ptr: u32) {
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn process(desc_ptr: usize) {
     let ports = unsafe { Ports::<SynthState>::from_descriptor(desc_ptr) };
     abi::render_instrument::<Synth>(ports);
 }
 
 /// Boot hook: the engine calls this once when the device is wired, handing it the (stable) sample rate.
-#[no_mangle]
-pub extern "C" fn init(state_ptr: u32, sample_rate: f32) {
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn init(state_ptr: usize, sample_rate: f32) {
     unsafe { abi::with_state(state_ptr, |state| <Synth as abi::Instrument>::init(state, sample_rate)) }
 }
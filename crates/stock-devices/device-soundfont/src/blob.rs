@@ -1,8 +1,8 @@
 //! Zero-copy reader over the SIMPLIFIED soundfont BLOB the host delivers (built on the main thread from the
-//! parsed `.sf2`; see `packages/app/wasm/src/soundfont-simplify.ts`). The device reads it IN PLACE by fixed
-//! byte offset — no allocation, no parsing. All scalars are little-endian.
+//! parsed `.sf2`; see `packages/app/wasm/src/soundfont-simplify.ts`, or natively by the `soundfont-blob` crate).
+//! The device reads it IN PLACE by fixed byte offset — no allocation, no parsing. All scalars are little-endian.
 //!
-//! Layout (WASM CONTRACT — mirrored exactly by the TS builder):
+//! Layout (WASM CONTRACT — mirrored exactly by the TS builder and `soundfont-blob`):
 //! ```text
 //! Header (32 bytes):  magic u32 | version u32 | sample_count u32 | region_count u32 |
 //!                     preset_count u32 | samples_off u32 | regions_off u32 | presets_off u32
@@ -12,10 +12,26 @@
 //! PresetDesc (8):     region_start u32 | region_count u32
 //! PCM: concatenated normalized f32, each SampleDesc.pcm_off points to its plane (mono).
 //! ```
+//!
+//! Version 2 (written only when a region uses them, e.g. by the SFZ builder) extends each RegionDesc to 64
+//! bytes with the SFZ region rules; a version 1 region reads as the neutral [`Region`] defaults:
+//! ```text
+//! RegionDesc (64):    <the 40 bytes above> | gain f32 | tune f32 (cents) |
+//!                     xfin_lo u8 | xfin_hi u8 | xfout_lo u8 | xfout_hi u8 |
+//!                     sw_lo u8 | sw_hi u8 | sw_last u8 | sw_default u8 |
+//!                     seq_length u8 | seq_position u8 | trigger u8 | xf_curve u8 | _pad u32
+//! ```
 
 pub const MAGIC: u32 = 0x4F53_4632; // "OSF2"
 pub const SAMPLE_STRIDE: usize = 24;
 pub const REGION_STRIDE: usize = 40;
+pub const REGION_STRIDE_V2: usize = 64;
+/// The "no key" marker of the key-switch bytes (outside the MIDI range).
+pub const NO_KEY: u8 = 0xFF;
+pub const TRIGGER_ATTACK: u8 = 0;
+pub const TRIGGER_RELEASE: u8 = 1;
+pub const XF_CURVE_POWER: u8 = 0;
+pub const XF_CURVE_GAIN: u8 = 1;
 pub const PRESET_STRIDE: usize = 8;
 
 #[inline]
@@ -29,7 +45,9 @@ fn read_f32(bytes: &[u8], offset: usize) -> f32 {
 }
 
 /// One flattened region (a preset-zone × instrument-zone product), with every SF2 generator already resolved
-/// on the TS side (instrument-overrides-preset, timecent→seconds, sustain 1−x/1000).
+/// on the TS side (instrument-overrides-preset, timecent→seconds, sustain 1−x/1000). The fields after `release`
+/// are the version 2 region rules: a level and fine tune, the velocity crossfade ramps, the key-switch, the
+/// round-robin slot and the trigger.
 #[derive(Clone, Copy)]
 pub struct Region {
     pub key_lo: u8,
@@ -43,7 +61,27 @@ pub struct Region {
     pub attack: f32,
     pub decay: f32,
     pub sustain: f32,
-    pub release: f32
+    pub release: f32,
+    pub gain: f32,
+    pub tune: f32, // cents
+    pub xfin_lo: u8,
+    pub xfin_hi: u8,
+    pub xfout_lo: u8,
+    pub xfout_hi: u8,
+    pub xf_curve: u8,
+    pub sw_lo: u8,
+    pub sw_hi: u8,
+    pub sw_last: u8,
+    pub sw_default: u8,
+    pub seq_length: u8,
+    pub seq_position: u8,
+    pub trigger: u8
+}
+
+/// The MIDI velocity byte of a unit velocity (TS `Math.round(velocity*127)`).
+#[inline]
+pub fn velocity_byte(velocity: f32) -> u32 {
+    (libm::roundf(velocity * 127.0) as u32).min(127)
 }
 
 impl Region {
@@ -53,12 +91,62 @@ impl Region {
         pitch >= self.key_lo as u32 && pitch <= self.key_hi as u32
             && velocity_byte >= self.vel_lo as u32 && velocity_byte <= self.vel_hi as u32
     }
+
+    /// Whether `pitch` is one of this region's key-switch keys (pressing it selects the articulation).
+    #[inline]
+    pub fn switches(&self, pitch: u32) -> bool {
+        self.sw_last != NO_KEY && pitch >= self.sw_lo as u32 && pitch <= self.sw_hi as u32
+    }
+
+    /// Whether the articulation this region belongs to is selected: the last key-switch pressed (or the
+    /// region's default one before any) is its `sw_last`. A region without a key-switch always plays.
+    #[inline]
+    pub fn selected(&self, key_switch: Option<u8>) -> bool {
+        self.sw_last == NO_KEY || key_switch.unwrap_or(self.sw_default) == self.sw_last
+    }
+
+    /// Whether this region's round-robin slot is the one the `counter`-th note on its key plays.
+    #[inline]
+    pub fn in_sequence(&self, counter: u32) -> bool {
+        let length = self.seq_length.max(1) as u32;
+        counter % length + 1 == self.seq_position as u32
+    }
+
+    #[inline]
+    pub fn is_release_trigger(&self) -> bool {
+        self.trigger == TRIGGER_RELEASE
+    }
+
+    /// The region's level at `velocity` (unit): its gain times the velocity crossfade, which ramps in over
+    /// `xfin_lo..xfin_hi` and out over `xfout_lo..xfout_hi`, equal-power unless the curve is linear gain.
+    #[inline]
+    pub fn level(&self, velocity: f32) -> f32 {
+        let velocity = velocity_byte(velocity);
+        let position = |from: u8, to: u8| (velocity as f32 - from as f32) / (to as f32 - from as f32);
+        let fade_in = if velocity >= self.xfin_hi as u32 {
+            1.0
+        } else if velocity <= self.xfin_lo as u32 {
+            0.0
+        } else {
+            position(self.xfin_lo, self.xfin_hi)
+        };
+        let fade_out = if velocity <= self.xfout_lo as u32 {
+            1.0
+        } else if velocity >= self.xfout_hi as u32 {
+            0.0
+        } else {
+            1.0 - position(self.xfout_lo, self.xfout_hi)
+        };
+        let fade = fade_in * fade_out;
+        let fade = if self.xf_curve == XF_CURVE_GAIN {fade} else {libm::sqrtf(fade)};
+        self.gain * fade
+    }
 }
 
 /// One sample: its PCM plane (already normalized f32) + rate + loop points (loop points relative to start).
 #[derive(Clone, Copy)]
 pub struct Sample {
-    pub pcm_ptr: u32, // absolute address of the f32 plane in shared memory (blob base + pcm_off)
+    pub pcm_ptr: usize, // absolute address of the f32 plane in shared memory (blob base + pcm_off)
     pub frame_count: u32,
     pub sample_rate: f32,
     pub loop_start: u32,
@@ -76,19 +164,25 @@ impl Sample {
 /// A zero-copy view over the blob: `base` is the blob's absolute start address (so PCM offsets resolve to
 /// absolute pointers), `bytes` the blob slice for the tables.
 pub struct Soundfont<'a> {
-    base: u32,
-    bytes: &'a [u8]
+    base: usize,
+    bytes: &'a [u8],
+    region_stride: usize
 }
 
 impl<'a> Soundfont<'a> {
-    /// Build a view over `bytes` whose absolute start address is `base`. Returns `None` if the blob is too small
-    /// or the magic is wrong (a not-yet-written / corrupt allocation).
+    /// Build a view over `bytes` whose absolute start address is `base`. Returns `None` if the blob is too small,
+    /// the magic is wrong (a not-yet-written / corrupt allocation) or the version unknown.
     #[inline]
-    pub fn new(base: u32, bytes: &'a [u8]) -> Option<Self> {
+    pub fn new(base: usize, bytes: &'a [u8]) -> Option<Self> {
         if bytes.len() < 32 || read_u32(bytes, 0) != MAGIC {
             return None;
         }
-        Some(Self {base, bytes})
+        let region_stride = match read_u32(bytes, 4) {
+            1 => REGION_STRIDE,
+            2 => REGION_STRIDE_V2,
+            _ => return None
+        };
+        Some(Self {base, bytes, region_stride})
     }
 
     #[inline]
@@ -127,8 +221,8 @@ impl<'a> Soundfont<'a> {
     /// Read the region at absolute region-table index `region_index`.
     #[inline]
     pub fn region(&self, region_index: u32) -> Region {
-        let base = self.regions_off() + region_index as usize * REGION_STRIDE;
-        Region {
+        let base = self.regions_off() + region_index as usize * self.region_stride;
+        let mut region = Region {
             key_lo: self.bytes[base],
             key_hi: self.bytes[base + 1],
             vel_lo: self.bytes[base + 2],
@@ -140,8 +234,31 @@ impl<'a> Soundfont<'a> {
             attack: read_f32(self.bytes, base + 20),
             decay: read_f32(self.bytes, base + 24),
             sustain: read_f32(self.bytes, base + 28),
-            release: read_f32(self.bytes, base + 32)
+            release: read_f32(self.bytes, base + 32),
+            gain: 1.0,
+            tune: 0.0,
+            xfin_lo: 0,
+            xfin_hi: 0,
+            xfout_lo: 127,
+            xfout_hi: 127,
+            xf_curve: XF_CURVE_POWER,
+            sw_lo: NO_KEY,
+            sw_hi: 0,
+            sw_last: NO_KEY,
+            sw_default: NO_KEY,
+            seq_length: 1,
+            seq_position: 1,
+            trigger: TRIGGER_ATTACK
+        };
+        if self.region_stride == REGION_STRIDE_V2 {
+            let rules = &self.bytes[base + 48..base + 60];
+            region.gain = read_f32(self.bytes, base + 40);
+            region.tune = read_f32(self.bytes, base + 44);
+            [region.xfin_lo, region.xfin_hi, region.xfout_lo, region.xfout_hi] = [rules[0], rules[1], rules[2], rules[3]];
+            [region.sw_lo, region.sw_hi, region.sw_last, region.sw_default] = [rules[4], rules[5], rules[6], rules[7]];
+            [region.seq_length, region.seq_position, region.trigger, region.xf_curve] = [rules[8], rules[9], rules[10], rules[11]];
         }
+        region
     }
 
     /// Read the sample at index `sample_index`, resolving its PCM offset to an absolute pointer.
@@ -149,7 +266,7 @@ impl<'a> Soundfont<'a> {
     pub fn sample(&self, sample_index: u32) -> Sample {
         let base = self.samples_off() + sample_index as usize * SAMPLE_STRIDE;
         Sample {
-            pcm_ptr: self.base + read_u32(self.bytes, base),
+            pcm_ptr: self.base + read_u32(self.bytes, base) as usize,
             frame_count: read_u32(self.bytes, base + 4),
             sample_rate: read_f32(self.bytes, base + 8),
             loop_start: read_u32(self.bytes, base + 12),
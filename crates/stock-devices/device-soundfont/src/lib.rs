@@ -4,6 +4,12 @@
 //! on the main thread; see `blob.rs`). The device observes its `file` pointer (`[10]`) via `observe_soundfont`
 //! and its `preset-index` field (`[11]`) via `observe_field`; on a note it selects the preset's regions,
 //! matches the note's key + velocity, and voices each match (layering) with the ported pitch/envelope/loop DSP.
+//! The channel pitch wheel bends every voice's read rate by up to [`abi::PITCH_BEND_RANGE`] semitones, and the
+//! sustain pedal (CC 64) defers note-offs until it lifts.
+//!
+//! A version 2 blob (an SFZ instrument) adds the region rules on top: a key-switch note selects the
+//! articulation whose regions may play, each note on a key advances that key's round-robin counter, which
+//! picks the region in sequence, and releasing a note starts the release-trigger regions it matches.
 //!
 //! Exports: `kind()` (instrument), `state_size()`, `process(desc_ptr)`, `init(state_ptr, sample_rate)`,
 //! `field_changed(...)`, `soundfont_changed(...)`, `reset(state_ptr)`. No parameters (the TS adapter has none).
@@ -12,8 +18,9 @@
 
 #[cfg(target_family = "wasm")]
 use core::panic::PanicInfo;
-use abi::{FieldValue, Block, EventRecord, Instrument, Ports, EVENT_NOTE_ON};
-use libm::roundf;
+use abi::{FieldValue, Block, EventRecord, Instrument, Ports, SustainPedal};
+use abi::{CC_SUSTAIN, EVENT_CONTROL, EVENT_NOTE_OFF, EVENT_NOTE_ON, EVENT_PITCH_BEND, PITCH_BEND_RANGE};
+use libm::exp2;
 
 mod blob;
 mod voice;
@@ -27,6 +34,7 @@ fn panic(info: &PanicInfo) -> ! {
 }
 
 const MAX_VOICES: usize = 128;
+const KEYS: usize = 128;
 
 /// Free every voice (transport stop, soundfont swap, or an unresolvable blob).
 #[inline]
@@ -36,18 +44,108 @@ fn force_stop_all(voices: &mut [SoundfontVoice]) {
     }
 }
 
+/// Note-off: release EVERY voice this note id spawned (a note may have layered several).
+fn release_note(voices: &mut [SoundfontVoice], id: u32) {
+    for voice in voices.iter_mut() {
+        if voice.is_active() && voice.id() == id {
+            voice.release();
+        }
+    }
+}
+
+/// A sounding note, kept until it is released so its release-trigger regions can start with the note's own
+/// key, velocity and round-robin position.
+#[derive(Clone, Copy, Default)]
+struct HeldNote {
+    active: bool,
+    id: u32,
+    pitch: u32,
+    cent: f32,
+    velocity: f32,
+    sequence: u32
+}
+
+/// Voice every region of the selected preset `note` matches: the attack regions on note-on (`release` false),
+/// the release-trigger ones when it ends.
+fn start_regions(state: &mut SoundfontState, soundfont: &Soundfont, note: &HeldNote, release: bool) {
+    let Some((region_start, region_count)) = soundfont.preset_regions(state.preset_index) else { return };
+    let velocity_byte = blob::velocity_byte(note.velocity);
+    for region_index in region_start..region_start + region_count {
+        let region = soundfont.region(region_index);
+        if !region.matches(note.pitch, velocity_byte) || region.is_release_trigger() != release
+            || !region.selected(state.key_switch) || !region.in_sequence(note.sequence) {
+            continue;
+        }
+        let sample = soundfont.sample(region.sample_index);
+        // A matching (preset-zone × instrument-zone) region layers a voice, exactly like the TS loop.
+        if let Some(slot) = state.voices.iter_mut().find(|voice| !voice.is_active()) {
+            slot.start(note.id, note.pitch, note.cent, note.velocity, &region, &sample, state.sample_rate);
+        }
+    }
+}
+
+/// A note ends (note-off, or the pedal lifting): release its voices and start its release-trigger regions.
+fn end_note(state: &mut SoundfontState, soundfont: Option<&Soundfont>, id: u32) {
+    release_note(&mut state.voices, id);
+    let Some(held) = state.held.iter_mut().find(|held| held.active && held.id == id) else { return };
+    held.active = false;
+    let note = *held;
+    if let Some(soundfont) = soundfont {
+        start_regions(state, soundfont, &note, true);
+    }
+}
+
+/// One event against the resolved blob (`None` while unbound / not resident: notes start nothing then).
+fn handle(state: &mut SoundfontState, soundfont: Option<&Soundfont>, event: &EventRecord) {
+    if event.kind == EVENT_NOTE_ON {
+        let Some(soundfont) = soundfont else { return };
+        let Some((region_start, region_count)) = soundfont.preset_regions(state.preset_index) else { return };
+        if (region_start..region_start + region_count).any(|index| soundfont.region(index).switches(event.pitch)) {
+            state.key_switch = Some(event.pitch as u8);
+        }
+        let counter = &mut state.sequence[(event.pitch as usize).min(KEYS - 1)];
+        let note = HeldNote {active: true, id: event.id, pitch: event.pitch, cent: event.cent, velocity: event.velocity, sequence: *counter};
+        *counter = counter.wrapping_add(1);
+        if let Some(slot) = state.held.iter_mut().find(|held| !held.active) {
+            *slot = note;
+        }
+        start_regions(state, soundfont, &note, false);
+    } else if event.kind == EVENT_NOTE_OFF {
+        if !state.sustain.hold(event.id) {
+            end_note(state, soundfont, event.id);
+        }
+    } else if event.kind == EVENT_PITCH_BEND {
+        state.pitch_bend = event.velocity.clamp(-1.0, 1.0) * PITCH_BEND_RANGE;
+    } else if event.kind == EVENT_CONTROL && event.pitch == CC_SUSTAIN {
+        let (mut released, mut count) = ([0u32; MAX_VOICES], 0);
+        state.sustain.set(event.velocity, &mut |id| {
+            released[count] = id;
+            count += 1;
+        });
+        for &id in &released[..count] {
+            end_note(state, soundfont, id);
+        }
+    }
+}
+
 // The Soundfont box's field-key paths: the `file` pointer `[10]` (a SoundfontFileBox) and the `preset-index`
 // int field `[11]`.
 const SOUNDFONT_POINTER: [u16; 1] = [10];
 const PRESET_INDEX_FIELD: [u16; 1] = [11];
 
 /// The device's per-instance state (engine-allocated, zeroed): a fixed voice pool, the resolved soundfont blob
-/// handle (`None` while the pointer is unbound / loading), the selected preset index, the sample rate, and the
-/// observe ids the engine delivers against.
+/// handle (`None` while the pointer is unbound / loading), the selected preset index, the channel pitch bend and
+/// sustain pedal, the region-rule state (the held notes, the last key-switch and the per-key round-robin
+/// counters), the sample rate, and the observe ids the engine delivers against.
 pub struct SoundfontState {
     voices: [SoundfontVoice; MAX_VOICES],
+    held: [HeldNote; MAX_VOICES],
+    key_switch: Option<u8>, // the last key-switch note pressed
+    sequence: [u32; KEYS],  // notes played per key, the round-robin counter
     soundfont: Option<u32>, // the resolved blob handle while the `file` pointer is bound
     preset_index: u32,
+    pitch_bend: f32, // semitones
+    sustain: SustainPedal<MAX_VOICES>,
     sample_rate: f32,
     soundfont_id: u32,
     preset_field_id: u32
@@ -67,32 +165,9 @@ impl Instrument for SoundfontDevice {
     }
 
     fn handle_event(state: &mut SoundfontState, event: &EventRecord) {
-        if event.kind == EVENT_NOTE_ON {
-            let Some(handle) = state.soundfont else { return };
-            let Some(reference) = abi::resolve_soundfont(handle) else { return };
-            let Some(soundfont) = Soundfont::new(reference.ptr, reference.bytes()) else { return };
-            let Some((region_start, region_count)) = soundfont.preset_regions(state.preset_index) else { return };
-            let velocity_byte = (roundf(event.velocity * 127.0) as u32).min(127); // TS `Math.round(velocity*127)`
-            let sample_rate = state.sample_rate;
-            for region_index in region_start..region_start + region_count {
-                let region = soundfont.region(region_index);
-                if !region.matches(event.pitch, velocity_byte) {
-                    continue;
-                }
-                let sample = soundfont.sample(region.sample_index);
-                // A matching (preset-zone × instrument-zone) region layers a voice, exactly like the TS loop.
-                if let Some(slot) = state.voices.iter_mut().find(|voice| !voice.is_active()) {
-                    slot.start(event.id, event.pitch, event.cent, event.velocity, &region, &sample, sample_rate);
-                }
-            }
-        } else {
-            // Note-off: release EVERY voice this note id spawned (a note may have layered several).
-            for voice in state.voices.iter_mut() {
-                if voice.is_active() && voice.id() == event.id {
-                    voice.release();
-                }
-            }
-        }
+        let reference = state.soundfont.and_then(abi::resolve_soundfont);
+        let soundfont = reference.as_ref().and_then(|reference| Soundfont::new(reference.ptr, reference.bytes()));
+        handle(state, soundfont.as_ref(), event);
     }
 
     fn process_audio(state: &mut SoundfontState, output: [&mut [f32]; 2], _block: &Block) {
@@ -107,10 +182,11 @@ impl Instrument for SoundfontDevice {
         let Some(soundfont) = Soundfont::new(reference.ptr, reference.bytes()) else {
             return force_stop_all(&mut state.voices);
         };
+        let bend = exp2(state.pitch_bend as f64 / 12.0);
         for voice in state.voices.iter_mut() {
             if voice.is_active() {
                 let sample = soundfont.sample(voice.sample_index());
-                if voice.process(out_left, out_right, sample.plane()) {
+                if voice.process(out_left, out_right, sample.plane(), bend) {
                     voice.force_stop();
                 }
             }
@@ -130,11 +206,16 @@ impl Instrument for SoundfontDevice {
         if id == state.soundfont_id {
             state.soundfont = soundfont;
             force_stop_all(&mut state.voices);
+            state.key_switch = None;
         }
     }
 
     fn reset(state: &mut SoundfontState) {
         force_stop_all(&mut state.voices);
+        state.sustain.reset();
+        state.pitch_bend = 0.0;
+        state.held = [HeldNote::default(); MAX_VOICES];
+        state.sequence = [0; KEYS];
     }
 }
 
@@ -155,50 +236,50 @@ pub fn render(state: &mut SoundfontState, events: &[EventRecord], out_left: &mut
 
 // ---- The device ABI: shared with the engine, called wasm-to-wasm. ----
 
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn kind() -> u32 {
     abi::DEVICE_KIND_INSTRUMENT
 }
 
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn state_size(_sample_rate: f32) -> u32 {
     core::mem::size_of::<SoundfontState>() as u32
 }
 
-#[no_mangle]
-pub extern "C" fn process(desc_ptr: u32) {
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn process(desc_ptr: usize) {
     let ports = unsafe { Ports::<SoundfontState>::from_descriptor(desc_ptr) };
     abi::render_instrument::<SoundfontDevice>(ports);
 }
 
-#[no_mangle]
-pub extern "C" fn init(state_ptr: u32, sample_rate: f32) {
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn init(state_ptr: usize, sample_rate: f32) {
     unsafe { abi::with_state(state_ptr, |state| <SoundfontDevice as Instrument>::init(state, sample_rate)) }
 }
 
 /// Apply the observed `preset-index` int field (`[11]`).
-#[no_mangle]
-pub extern "C" fn field_changed(state_ptr: u32, id: u32, kind: u32, bits: u32, len: u32) {
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn field_changed(state_ptr: usize, id: u32, kind: u32, bits: usize, len: u32) {
     unsafe { abi::with_state(state_ptr, |state| <SoundfontDevice as Instrument>::field_changed(state, id, FieldValue::from_wire(kind, bits, len))) }
 }
 
 /// Apply an observed soundfont reference (its `file` pointer), by the id `observe_soundfont` returned.
-#[no_mangle]
-pub extern "C" fn soundfont_changed(state_ptr: u32, id: u32, handle: u32, present: u32) {
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn soundfont_changed(state_ptr: usize, id: u32, handle: u32, present: u32) {
     let soundfont = if present != 0 {Some(handle)} else {None};
     unsafe { abi::with_state(state_ptr, |state| <SoundfontDevice as Instrument>::soundfont_changed(state, id, soundfont)) }
 }
 
 /// Transport STOP: drop every voice so playback starts silent.
-#[no_mangle]
-pub extern "C" fn reset(state_ptr: u32) {
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn reset(state_ptr: usize) {
     unsafe { abi::with_state(state_ptr, |state| <SoundfontDevice as Instrument>::reset(state)) }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::blob::{MAGIC, PRESET_STRIDE, REGION_STRIDE, SAMPLE_STRIDE};
+    use crate::blob::{MAGIC, NO_KEY, PRESET_STRIDE, REGION_STRIDE, REGION_STRIDE_V2, SAMPLE_STRIDE, TRIGGER_RELEASE};
 
     const SR: f32 = 48_000.0;
 
@@ -250,6 +331,51 @@ mod tests {
         bytes
     }
 
+    // The version 2 rule bytes of a plain full-range region: no crossfade, no key-switch, slot 1 of 1, attack.
+    const RULES: [u8; 12] = [0, 0, 127, 127, NO_KEY, 0, NO_KEY, NO_KEY, 1, 1, 0, 0];
+
+    // Build a version 2 blob with one preset over `rules.len()` full-range regions, region `i` reading its own
+    // 64-frame DC sample `i`, so a voice's `sample_index` names the region that started it.
+    fn build_rules_blob(rules: &[[u8; 12]]) -> Vec<u8> {
+        const FRAMES: usize = 64;
+        let count = rules.len();
+        let samples_off = 32usize;
+        let regions_off = samples_off + count * SAMPLE_STRIDE;
+        let presets_off = regions_off + count * REGION_STRIDE_V2;
+        let pcm_off = presets_off + PRESET_STRIDE;
+        let mut bytes = vec![0u8; pcm_off + count * FRAMES * 4];
+        let u = |b: &mut Vec<u8>, off: usize, v: u32| b[off..off + 4].copy_from_slice(&v.to_le_bytes());
+        let f = |b: &mut Vec<u8>, off: usize, v: f32| b[off..off + 4].copy_from_slice(&v.to_le_bytes());
+        for (off, value) in [MAGIC, 2, count as u32, count as u32, 1, samples_off as u32, regions_off as u32, presets_off as u32].into_iter().enumerate() {
+            u(&mut bytes, off * 4, value);
+        }
+        for (index, rules) in rules.iter().enumerate() {
+            let sample = samples_off + index * SAMPLE_STRIDE;
+            u(&mut bytes, sample, (pcm_off + index * FRAMES * 4) as u32);
+            u(&mut bytes, sample + 4, FRAMES as u32);
+            f(&mut bytes, sample + 8, SR);
+            let region = regions_off + index * REGION_STRIDE_V2;
+            bytes[region + 1] = 127;
+            bytes[region + 3] = 127;
+            u(&mut bytes, region + 4, index as u32);
+            u(&mut bytes, region + 8, 60);
+            for (off, value) in [(20, 0.001), (24, 0.005), (28, 1.0), (32, 0.05), (40, 1.0)] {
+                f(&mut bytes, region + off, value);
+            }
+            bytes[region + 48..region + 60].copy_from_slice(rules);
+        }
+        u(&mut bytes, presets_off + 4, count as u32);
+        for frame in 0..count * FRAMES {
+            f(&mut bytes, pcm_off + frame * 4, 1.0);
+        }
+        bytes
+    }
+
+    // The sample (= region) index of every active voice, in pool order.
+    fn sounding(state: &SoundfontState) -> Vec<u32> {
+        state.voices.iter().filter(|voice| voice.is_active()).map(|voice| voice.sample_index()).collect()
+    }
+
     fn note_on(id: u32, pitch: u32, velocity: f32) -> EventRecord {
         EventRecord {position: 0.0, offset: 0, kind: EVENT_NOTE_ON, id, pitch, velocity, cent: 0.0, duration: 0.0}
     }
@@ -260,7 +386,7 @@ mod tests {
     #[test]
     fn selects_matching_region_and_ramps_over_attack() {
         let blob = build_blob(48_000, 0.5, 60, 0.02, 1.0); // 20 ms attack, full sustain
-        let soundfont = Soundfont::new(blob.as_ptr() as u32, &blob).expect("valid blob");
+        let soundfont = Soundfont::new(blob.as_ptr() as usize, &blob).expect("valid blob");
         let region = soundfont.region(0);
         assert!(region.matches(60, 100), "note within the region's full key/vel range");
         assert!(!region.matches(60, 128) || region.vel_hi == 127, "velocity above the range would not match");
@@ -269,7 +395,7 @@ mod tests {
         let mut voice = SoundfontVoice::default();
         voice.start(1, 60, 0.0, 1.0, &region, &sample, SR);
         let (mut left, mut right) = (vec![0.0f32; 64], vec![0.0f32; 64]);
-        assert!(!voice.process(&mut left, &mut right, &pcm), "still sounding");
+        assert!(!voice.process(&mut left, &mut right, &pcm, 1.0), "still sounding");
         assert!(left[0].abs() < 0.02, "starts near silent (attack ramp from 0): {}", left[0]);
         assert!(left[63] > left[0], "ramps up across the attack");
         assert_eq!(left, right, "pan center feeds both channels equally");
@@ -282,7 +408,7 @@ mod tests {
         let regions_off = 32 + SAMPLE_STRIDE;
         blob[regions_off] = 60; // key_lo
         blob[regions_off + 1] = 64; // key_hi
-        let soundfont = Soundfont::new(blob.as_ptr() as u32, &blob).unwrap();
+        let soundfont = Soundfont::new(blob.as_ptr() as usize, &blob).unwrap();
         let (start, count) = soundfont.preset_regions(0).unwrap();
         let mut matched = 0;
         for index in start..start + count {
@@ -296,14 +422,14 @@ mod tests {
     #[test]
     fn out_of_range_preset_index_falls_back_to_preset_zero() {
         let blob = build_blob(64, 1.0, 60, 0.0, 1.0);
-        let soundfont = Soundfont::new(blob.as_ptr() as u32, &blob).unwrap();
+        let soundfont = Soundfont::new(blob.as_ptr() as usize, &blob).unwrap();
         assert_eq!(soundfont.preset_regions(99), soundfont.preset_regions(0), "TS `presets[i] ?? presets[0]`");
     }
 
     #[test]
     fn a_voice_sounds_the_dc_plane_through_the_envelope() {
         let blob = build_blob(48_000, 0.4, 60, 0.001, 1.0);
-        let soundfont = Soundfont::new(blob.as_ptr() as u32, &blob).unwrap();
+        let soundfont = Soundfont::new(blob.as_ptr() as usize, &blob).unwrap();
         let region = soundfont.region(0);
         let sample = soundfont.sample(region.sample_index);
         let pcm = vec![0.4f32; 48_000];
@@ -311,10 +437,124 @@ mod tests {
         voice.start(9, 60, 0.0, 1.0, &region, &sample, SR);
         // Render past the 1 ms attack + 3 ms smoothing so the envelope settles to full sustain (1.0).
         let (mut left, mut right) = (vec![0.0f32; 4096], vec![0.0f32; 4096]);
-        assert!(!voice.process(&mut left, &mut right, &pcm), "still sounding");
+        assert!(!voice.process(&mut left, &mut right, &pcm, 1.0), "still sounding");
         let peak = left.iter().fold(0.0f32, |acc, value| acc.max(value.abs()));
         // Per channel: DC 0.4 * gain 1.0 * sustain 1.0 * constant-power pan center cos(pi/4)=0.707 ~= 0.283.
         assert!((peak - 0.4 * core::f32::consts::FRAC_1_SQRT_2).abs() < 0.01, "settles to the panned DC level: peak {peak}");
         let _ = note_on(9, 60, 1.0);
     }
+
+    #[test]
+    fn a_bent_voice_reads_its_sample_faster() {
+        let blob = build_blob(48_000, 1.0, 60, 0.0, 1.0);
+        let soundfont = Soundfont::new(blob.as_ptr() as usize, &blob).unwrap();
+        let region = soundfont.region(0);
+        let sample = soundfont.sample(region.sample_index);
+        // A short plane: a straight read runs 64 frames, an octave-up read (bend 2.0) runs out within 64 output frames.
+        let pcm = vec![1.0f32; 100];
+        let (mut left, mut right) = (vec![0.0f32; 64], vec![0.0f32; 64]);
+        let mut straight = SoundfontVoice::default();
+        straight.start(1, 60, 0.0, 1.0, &region, &sample, SR);
+        assert!(!straight.process(&mut left, &mut right, &pcm, 1.0));
+        let mut bent = SoundfontVoice::default();
+        bent.start(1, 60, 0.0, 1.0, &region, &sample, SR);
+        assert!(bent.process(&mut left, &mut right, &pcm, 2.0), "the bent read head reaches the end first");
+    }
+
+    #[test]
+    fn the_sustain_pedal_defers_the_release() {
+        let mut state: SoundfontState = unsafe { core::mem::zeroed() };
+        let blob = build_blob(48_000, 1.0, 60, 0.001, 1.0);
+        let soundfont = Soundfont::new(blob.as_ptr() as usize, &blob).unwrap();
+        let region = soundfont.region(0);
+        state.voices[0].start(3, 60, 0.0, 1.0, &region, &soundfont.sample(0), SR);
+        let pcm = vec![1.0f32; 48_000];
+        let pedal = |value| EventRecord {kind: EVENT_CONTROL, id: 0, pitch: CC_SUSTAIN, velocity: value, ..note_on(0, 0, 0.0)};
+        let tail = |state: &mut SoundfontState| {
+            let (mut left, mut right) = (vec![0.0f32; 4096], vec![0.0f32; 4096]);
+            state.voices[0].process(&mut left, &mut right, &pcm, 1.0);
+            left[4095]
+        };
+        SoundfontDevice::handle_event(&mut state, &pedal(1.0));
+        SoundfontDevice::handle_event(&mut state, &EventRecord {kind: EVENT_NOTE_OFF, ..note_on(3, 60, 0.0)});
+        assert!(tail(&mut state) > 0.5, "the pedal holds the released note at its sustain level");
+        SoundfontDevice::handle_event(&mut state, &pedal(0.0));
+        assert!(tail(&mut state) < 1.0e-3, "lifting the pedal lets it release");
+    }
+
+    #[test]
+    fn a_version_1_region_reads_the_neutral_rules() {
+        let blob = build_blob(64, 1.0, 60, 0.0, 1.0);
+        let region = Soundfont::new(blob.as_ptr() as usize, &blob).unwrap().region(0);
+        assert_eq!((region.level(1.0), region.level(0.3)), (1.0, 1.0), "no gain and no crossfade");
+        assert_eq!(region.tune, 0.0);
+        assert!(region.selected(None) && region.selected(Some(24)), "no key-switch: always selected");
+        assert!((0..4).all(|counter| region.in_sequence(counter)), "slot 1 of 1 plays every note");
+        assert!(!region.switches(60) && !region.is_release_trigger());
+    }
+
+    #[test]
+    fn velocity_crossfades_keep_the_power_across_two_layers() {
+        let (mut soft, mut loud) = (RULES, RULES);
+        [soft[2], soft[3]] = [64, 96]; // fades out over 64..96
+        [loud[0], loud[1]] = [64, 96]; // fades in over 64..96
+        let blob = build_rules_blob(&[soft, loud]);
+        let soundfont = Soundfont::new(blob.as_ptr() as usize, &blob).unwrap();
+        let (soft, loud) = (soundfont.region(0), soundfont.region(1));
+        assert_eq!((soft.level(40.0 / 127.0), loud.level(40.0 / 127.0)), (1.0, 0.0));
+        assert_eq!((soft.level(1.0), loud.level(1.0)), (0.0, 1.0));
+        let (a, b) = (soft.level(80.0 / 127.0), loud.level(80.0 / 127.0));
+        assert!(a > 0.0 && b > 0.0 && (a * a + b * b - 1.0).abs() < 1.0e-6, "equal power mid-fade: {a} {b}");
+    }
+
+    #[test]
+    fn round_robin_regions_take_turns_on_a_key() {
+        let (mut first, mut second) = (RULES, RULES);
+        [first[8], first[9]] = [2, 1];
+        [second[8], second[9]] = [2, 2];
+        let blob = build_rules_blob(&[first, second]);
+        let soundfont = Soundfont::new(blob.as_ptr() as usize, &blob).unwrap();
+        let mut state: SoundfontState = unsafe { core::mem::zeroed() };
+        for id in 1..=3 {
+            handle(&mut state, Some(&soundfont), &note_on(id, 60, 1.0));
+        }
+        handle(&mut state, Some(&soundfont), &note_on(4, 62, 1.0)); // another key keeps its own counter
+        assert_eq!(sounding(&state), vec![0, 1, 0, 0]);
+    }
+
+    #[test]
+    fn a_key_switch_selects_the_articulation() {
+        let (mut sustained, mut staccato) = (RULES, RULES);
+        [sustained[4], sustained[5], sustained[6], sustained[7]] = [24, 25, 24, 24];
+        [staccato[4], staccato[5], staccato[6], staccato[7]] = [24, 25, 25, 24];
+        let blob = build_rules_blob(&[sustained, staccato]);
+        let soundfont = Soundfont::new(blob.as_ptr() as usize, &blob).unwrap();
+        let mut state: SoundfontState = unsafe { core::mem::zeroed() };
+        handle(&mut state, Some(&soundfont), &note_on(1, 60, 1.0)); // the default switch: sustained
+        assert_eq!(sounding(&state), vec![0]);
+        state.voices[0].force_stop();
+        handle(&mut state, Some(&soundfont), &note_on(2, 25, 1.0)); // pressing a switch key selects staccato
+        assert_eq!(state.key_switch, Some(25));
+        state.voices.iter_mut().for_each(SoundfontVoice::force_stop);
+        handle(&mut state, Some(&soundfont), &note_on(3, 60, 1.0));
+        assert_eq!(sounding(&state), vec![1]);
+    }
+
+    #[test]
+    fn releasing_a_note_starts_its_release_regions() {
+        let mut release = RULES;
+        release[10] = TRIGGER_RELEASE;
+        let blob = build_rules_blob(&[RULES, release]);
+        let soundfont = Soundfont::new(blob.as_ptr() as usize, &blob).unwrap();
+        let mut state: SoundfontState = unsafe { core::mem::zeroed() };
+        let pedal = |value| EventRecord {kind: EVENT_CONTROL, id: 0, pitch: CC_SUSTAIN, velocity: value, ..note_on(0, 0, 0.0)};
+        handle(&mut state, Some(&soundfont), &note_on(7, 60, 0.8));
+        assert_eq!(sounding(&state), vec![0], "the release region waits for the note-off");
+        handle(&mut state, Some(&soundfont), &pedal(1.0));
+        handle(&mut state, Some(&soundfont), &EventRecord {kind: EVENT_NOTE_OFF, ..note_on(7, 60, 0.0)});
+        assert_eq!(sounding(&state), vec![0], "the pedal holds the note, and its release");
+        handle(&mut state, Some(&soundfont), &pedal(0.0));
+        assert_eq!(sounding(&state), vec![0, 1]);
+        assert!(!state.held.iter().any(|held| held.active), "the note is forgotten once released");
+    }
 }
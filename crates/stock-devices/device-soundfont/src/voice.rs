@@ -5,6 +5,8 @@
 //! (already normalized f32) sample plane, a LINEAR ADSR whose per-sample value is 3 ms one-pole smoothed,
 //! constant-power pan, and sample looping when the region's loop mode is on. A voice ends when a non-looping
 //! sample runs out, or when the envelope is idle AND the smoothed gain has fallen below `SILENCE_THRESHOLD`.
+//! A version 2 region also scales the level (its gain and velocity crossfade) and fine-tunes the read rate; a
+//! release-trigger region never loops, it plays its sample out.
 
 use libm::{cosf, exp2f, expf, sinf};
 use crate::blob::{Region, Sample};
@@ -188,14 +190,14 @@ impl SoundfontVoice {
         self.sample_index = region.sample_index;
         self.loop_start = sample.loop_start;
         self.loop_end = sample.loop_end;
-        self.looping = region.loop_mode != 0;
+        self.looping = region.loop_mode != 0 && !region.is_release_trigger();
         self.position = 0.0;
         // pitchRatio = midiToHz(pitch + cent/100) / midiToHz(rootKey) = 2^((pitch + cent/100 - rootKey)/12).
-        let semis = pitch as f32 + cent / 100.0 - region.root_key as f32;
+        let semis = pitch as f32 + cent / 100.0 - region.root_key as f32 + region.tune / 100.0;
         let pitch_ratio = exp2f(semis / 12.0) as f64;
         self.playback_rate = pitch_ratio * sample.sample_rate as f64 / sample_rate as f64;
         // velocityToGain(v) = dbToGain(20*log10(v)) == v for v in (0,1]; 0 at v<=0.
-        self.gain = if velocity > 0.0 {velocity} else {0.0};
+        self.gain = if velocity > 0.0 {velocity * region.level(velocity)} else {0.0};
         let pan_angle = (region.pan + 1.0) * core::f32::consts::FRAC_PI_4;
         self.pan_left = cosf(pan_angle);
         self.pan_right = sinf(pan_angle);
@@ -218,10 +220,11 @@ impl SoundfontVoice {
     }
 
     /// Render additively into the stereo chunk, reading from the voice's sample `pcm` plane (the device fetches
-    /// it from the blob each block). Returns `true` once finished (non-looping sample ran out, or the envelope
-    /// idled and the smoothed gain fell silent), so the device frees the slot. Mirrors `processAdd`.
+    /// it from the blob each block), with the read head sped up by `bend` (the channel pitch-wheel ratio, 1.0 at
+    /// rest). Returns `true` once finished (non-looping sample ran out, or the envelope idled and the smoothed
+    /// gain fell silent), so the device frees the slot. Mirrors `processAdd`.
     #[inline]
-    pub fn process(&mut self, out_left: &mut [f32], out_right: &mut [f32], pcm: &[f32]) -> bool {
+    pub fn process(&mut self, out_left: &mut [f32], out_right: &mut [f32], pcm: &[f32], bend: f64) -> bool {
         let frame_count = pcm.len();
         if frame_count == 0 {
             return true;
@@ -229,6 +232,7 @@ impl SoundfontVoice {
         let last = frame_count - 1;
         let loop_start = self.loop_start as f64;
         let loop_end = self.loop_end as f64;
+        let rate = self.playback_rate * bend;
         for index in 0..out_left.len() {
             let int_position = self.position as usize;
             let sample = if int_position >= last {
@@ -241,7 +245,7 @@ impl SoundfontVoice {
             let amp = sample * self.gain * self.smooth.process(env);
             out_left[index] += amp * self.pan_left;
             out_right[index] += amp * self.pan_right;
-            self.position += self.playback_rate;
+            self.position += rate;
             if self.looping {
                 if self.position >= loop_end && loop_end > loop_start {
                     self.position = loop_start + (self.position - loop_end);
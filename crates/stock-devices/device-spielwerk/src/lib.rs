@@ -34,23 +34,23 @@ pub struct SpielwerkState {
     sample_rate: f32
 }
 
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn kind() -> u32 {
     DEVICE_KIND_MIDI_EFFECT
 }
 
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn state_size(_sample_rate: f32) -> u32 {
     core::mem::size_of::<SpielwerkState>() as u32
 }
 
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn observe_param_collection_field() -> u32 {
     11
 }
 
-#[no_mangle]
-pub extern "C" fn init(state_ptr: u32, sample_rate: f32) {
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn init(state_ptr: usize, sample_rate: f32) {
     unsafe {
         abi::with_state::<SpielwerkState>(state_ptr, |state| {
             state.sample_rate = sample_rate;
@@ -65,8 +65,8 @@ fn forward_param(state: &mut SpielwerkState, id: u32, value: ParamValue) {
     abi::script_param(state.handle, id, value);
 }
 
-#[no_mangle]
-pub extern "C" fn parameter_changed(state_ptr: u32, id: u32, kind: u32, value: f32) {
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn parameter_changed(state_ptr: usize, id: u32, kind: u32, value: f32) {
     unsafe {
         abi::with_state::<SpielwerkState>(state_ptr, |state| {
             forward_param(state, id, ParamValue::from_wire(kind, value));
@@ -77,15 +77,15 @@ pub extern "C" fn parameter_changed(state_ptr: u32, id: u32, kind: u32, value: f
 /// This device's INSTANCE is dying (a genuine removal, never a chain-edit survivor): release the JS-side
 /// script bridge (its Processor + note-tracking runtime), so removing/rebinding a Spielwerk device no longer
 /// orphans one.
-#[no_mangle]
-pub extern "C" fn terminate(state_ptr: u32) {
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn terminate(state_ptr: usize) {
     unsafe { abi::with_state::<SpielwerkState>(state_ptr, |state| abi::script_release(state.handle)) }
 }
 
 /// Pull-responder: pull the upstream notes for `[from, to)` ONCE into the stack scratch, then run the user
 /// generator + tracking in the JS bridge, which writes the transformed notes into the host output buffer.
-#[no_mangle]
-pub extern "C" fn process_events(from: f64, to: f64, flags: u32, state_ptr: u32, out_ptr: u32, max: u32) -> u32 {
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn process_events(from: f64, to: f64, flags: u32, state_ptr: usize, out_ptr: usize, max: u32) -> u32 {
     let state = unsafe { &mut *(state_ptr as *mut SpielwerkState) };
     // Refresh any automated `@param`s at the range start before running the generator (it transforms the whole
     // `[from, to)` once, matching the TS `SpielwerkDeviceProcessor`, so the range-start value is the right grain).
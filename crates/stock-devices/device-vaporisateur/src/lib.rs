@@ -5,7 +5,15 @@
 //! gives portamento, an LFO modulates tune / cutoff / volume, and the note is rendered through the shared
 //! `voicing` framework: `unison` detuned/spread sub-voices per note ([`voicing::VoiceUnison`]), allocated
 //! either polyphonically or monophonically ([`voicing::Voicing`] dispatcher, switched by the voicing-mode
-//! parameter). The whole mix is brick-wall limited (`dsp::simple_limiter`).
+//! parameter). The whole mix is brick-wall limited (`dsp::simple_limiter`). Live channel input is honoured: the
+//! pitch wheel bends every voice by up to [`abi::PITCH_BEND_RANGE`] semitones, and the sustain pedal (CC 64)
+//! holds released notes until it lifts. Per-note expressions (MPE) reach the voices playing that note id:
+//! tuning bends it, pressure swells its level toward full scale and brightness moves its cutoff.
+//!
+//! Beyond the TS port, a modulation matrix ([`mod@matrix`]) routes two LFOs, two envelopes (the amp envelope
+//! and a dedicated modulation envelope), velocity, key, the mod wheel, aftertouch and a per-note random value
+//! onto the oscillator tunes / volumes, cutoff, resonance, pan and unison detune, with bipolar amounts,
+//! evaluated per chunk in each voice.
 //!
 //! Heap-free: every voice lives in the engine-allocated (zeroed) state block, reused across notes (no `new`
 //! per note, no allocator). The voice reads the device's live parameters through `voicing`'s `Shared`
@@ -21,7 +29,8 @@
 
 #[cfg(target_family = "wasm")]
 use core::panic::PanicInfo;
-use abi::{float_value, int_value, Block, EventRecord, Instrument, ParamValue, Ports, EVENT_NOTE_ON};
+use abi::{float_value, int_value, Block, EventRecord, Instrument, NoteExpression, ParamValue, Ports, SustainPedal};
+use abi::{CC_MOD_WHEEL, CC_SUSTAIN, EVENT_CHANNEL_PRESSURE, EVENT_CONTROL, EVENT_NOTE_EXPRESSION, EVENT_NOTE_OFF, EVENT_NOTE_ON, EVENT_PITCH_BEND, PITCH_BEND_RANGE};
 use dsp::osc::ClassicWaveform;
 use dsp::{midi_to_hz_base, ppqn};
 use math::value_mapping::{Decibel, Exponential, Linear, LinearInteger, Values, ValueMapping};
@@ -29,7 +38,9 @@ use math::db_to_gain;
 use voicing::{VoiceUnison, Voicing, VoicingMode};
 
 mod adsr;
+pub mod matrix;
 mod voice;
+use matrix::{ModSource, ModTarget, SLOTS};
 use voice::{VaporisateurParams, VaporisateurVoice};
 
 #[cfg(target_family = "wasm")]
@@ -40,6 +51,7 @@ fn panic(info: &PanicInfo) -> ! {
 
 const POLY_VOICES: usize = 16; // polyphonic voice slots
 const MONO_STACK: usize = 16; // monophonic held-note stack depth
+const SUSTAINED: usize = 64; // note-offs the sustain pedal can hold back
 const UNISON_MAX: usize = 5; // the widest unison (the unison-count values are [1, 3, 5])
 // The editor's envelope playheads at address.append(0) (TS `envValues`): one `env.phase` per sounding
 // voice group (32 max, -1 closes the stream), written only while the UI subscribes.
@@ -62,6 +74,11 @@ const FILTER_ORDER_VALUES: [i32; 4] = [1, 2, 3, 4];
 const UNISON_COUNT_VALUES: [i32; 3] = [1, 3, 5];
 const VOICING_MODE_VALUES: [i32; 2] = [0, 1]; // VoicingMode::{Monophonic, Polyphonic}
 const LFO_WAVEFORM_VALUES: [i32; 4] = [0, 1, 2, 3];
+const MOD_SOURCE_MAPPING: LinearInteger = LinearInteger {min: 0, max: 9}; // matrix::ModSource index
+const MOD_TARGET_MAPPING: LinearInteger = LinearInteger {min: 0, max: matrix::TARGETS as i32}; // matrix::ModTarget index
+
+// The VaporisateurDeviceBox field key of the modulation-matrix array.
+const MODULATIONS_KEY: u16 = 60;
 
 // The parameter slots, the order this device binds them. The id `bind_parameter` returns is stored in
 // `state.ids[INDEX]`; `parameter_changed` finds the slot by matching the incoming id against that table.
@@ -93,7 +110,22 @@ mod param {
     pub const LFO_TARGET_TUNE: usize = 24;
     pub const LFO_TARGET_CUTOFF: usize = 25;
     pub const LFO_TARGET_VOLUME: usize = 26;
-    pub const COUNT: usize = 27;
+    pub const LFO_2_WAVEFORM: usize = 27;
+    pub const LFO_2_RATE: usize = 28;
+    pub const MOD_ATTACK: usize = 29;
+    pub const MOD_DECAY: usize = 30;
+    pub const MOD_SUSTAIN: usize = 31;
+    pub const MOD_RELEASE: usize = 32;
+    pub const COUNT: usize = 33;
+}
+
+// The per-routing field keys inside a `VaporisateurModulation`, which double as the `modulation_ids` slots
+// (key - 1).
+mod modulation {
+    pub const SOURCE: u16 = 1;
+    pub const TARGET: u16 = 2;
+    pub const AMOUNT: u16 = 3;
+    pub const COUNT: usize = 3;
 }
 
 /// Resolve the cutoff as a UNIT value (0..1): the automation value directly, or a real Hz mapped back to the
@@ -109,18 +141,20 @@ fn cutoff_unit(value: ParamValue) -> f32 {
 
 /// The device's per-instance state, interpreted from the engine-allocated (zeroed) block: the voicing
 /// dispatcher (both strategies + the unison voice pools), the live parameters the voices read (including the
-/// shared render workspace), the output limiter, the sample rate, the per-note glide time (pulses) and unison
-/// count, and the bound parameter ids.
+/// shared render workspace), the sustain pedal, the output limiter, the sample rate, the per-note glide time
+/// (pulses) and unison count, and the bound parameter ids (the `param` slots, then per matrix routing).
 pub struct VaporisateurState {
     voicing: Voicing<VoiceUnison<VaporisateurVoice, UNISON_MAX>, POLY_VOICES, MONO_STACK>,
     params: VaporisateurParams,
+    sustain: SustainPedal<SUSTAINED>,
     limiter: dsp::simple_limiter::SimpleLimiter,
     sample_rate: f32,
     glide_time: f64,
     unison_count: i32,
     ids: [u32; param::COUNT],
+    modulation_ids: [[u32; modulation::COUNT]; SLOTS],
     env_id: u32,
-    env_ptr: u32
+    env_ptr: usize
 }
 
 /// The DSP, plugged into the SDK's `Instrument` template ([`abi::render_instrument`]).
@@ -160,6 +194,17 @@ impl Instrument for Vaporisateur {
         state.ids[param::LFO_TARGET_TUNE] = abi::bind_parameter(&[30, 10]);
         state.ids[param::LFO_TARGET_CUTOFF] = abi::bind_parameter(&[30, 11]);
         state.ids[param::LFO_TARGET_VOLUME] = abi::bind_parameter(&[30, 12]);
+        state.ids[param::LFO_2_WAVEFORM] = abi::bind_parameter(&[31, 1]);
+        state.ids[param::LFO_2_RATE] = abi::bind_parameter(&[31, 2]);
+        state.ids[param::MOD_ATTACK] = abi::bind_parameter(&[32, 1]);
+        state.ids[param::MOD_DECAY] = abi::bind_parameter(&[32, 2]);
+        state.ids[param::MOD_SUSTAIN] = abi::bind_parameter(&[32, 3]);
+        state.ids[param::MOD_RELEASE] = abi::bind_parameter(&[32, 4]);
+        for (index, ids) in state.modulation_ids.iter_mut().enumerate() {
+            for (slot, id) in ids.iter_mut().enumerate() {
+                *id = abi::bind_parameter(&[MODULATIONS_KEY, index as u16, slot as u16 + 1]);
+            }
+        }
         state.env_id = abi::bind_broadcast(&ENV_FIELD, ENV_VALUES as u32);
         state.env_ptr = 0;
     }
@@ -172,8 +217,23 @@ impl Instrument for Vaporisateur {
             let glide_time = state.glide_time;
             let unison = state.unison_count.max(1) as usize;
             state.voicing.start(event, frequency, 1.0, glide_time, unison, &state.params);
-        } else {
-            state.voicing.stop(event.id as i32, state.glide_time);
+        } else if event.kind == EVENT_NOTE_OFF {
+            if !state.sustain.hold(event.id) {
+                state.voicing.stop(event.id as i32, state.glide_time);
+            }
+        } else if event.kind == EVENT_PITCH_BEND {
+            state.params.pitch_bend = event.velocity.clamp(-1.0, 1.0) * PITCH_BEND_RANGE;
+        } else if event.kind == EVENT_CONTROL && event.pitch == CC_SUSTAIN {
+            let VaporisateurState {sustain, voicing, glide_time, ..} = state;
+            sustain.set(event.velocity, &mut |id| voicing.stop(id as i32, *glide_time));
+        } else if event.kind == EVENT_CONTROL && event.pitch == CC_MOD_WHEEL {
+            state.params.mod_wheel = event.velocity.clamp(0.0, 1.0);
+        } else if event.kind == EVENT_CHANNEL_PRESSURE {
+            state.params.channel_pressure = event.velocity.clamp(0.0, 1.0);
+        } else if event.kind == EVENT_NOTE_EXPRESSION {
+            if let Some(expression) = NoteExpression::from_record(event) {
+                state.voicing.express(event.id as i32, expression);
+            }
         }
     }
 
@@ -204,6 +264,7 @@ impl Instrument for Vaporisateur {
 
     fn parameter_changed(state: &mut VaporisateurState, id: u32, value: ParamValue) {
         let Some(index) = state.ids.iter().position(|bound| *bound == id) else {
+            Vaporisateur::modulation_changed(state, id, value);
             return;
         };
         let params = &mut state.params;
@@ -244,6 +305,12 @@ impl Instrument for Vaporisateur {
             param::LFO_TARGET_TUNE => params.lfo_target_tune = float_value(value, &BIPOLAR),
             param::LFO_TARGET_CUTOFF => params.lfo_target_cutoff = float_value(value, &BIPOLAR),
             param::LFO_TARGET_VOLUME => params.lfo_target_volume = float_value(value, &BIPOLAR),
+            param::LFO_2_WAVEFORM => params.lfo_2_shape = ClassicWaveform::from_index(int_value(value, &Values::new(&LFO_WAVEFORM_VALUES))),
+            param::LFO_2_RATE => params.lfo_2_rate = float_value(value, &LFO_RATE_MAPPING),
+            param::MOD_ATTACK => params.mod_env_attack = float_value(value, &TIME_MAPPING),
+            param::MOD_DECAY => params.mod_env_decay = float_value(value, &TIME_MAPPING),
+            param::MOD_SUSTAIN => params.mod_env_sustain = float_value(value, &UNIPOLAR),
+            param::MOD_RELEASE => params.mod_env_release = float_value(value, &TIME_MAPPING),
             param::GLIDE_TIME => state.glide_time = float_value(value, &UNIPOLAR) as f64 * ppqn::BAR,
             param::UNISON_COUNT => state.unison_count = int_value(value, &Values::new(&UNISON_COUNT_VALUES)),
             param::VOICING_MODE => state.voicing.set_mode(VoicingMode::from_index(int_value(value, &Values::new(&VOICING_MODE_VALUES)))),
@@ -253,6 +320,26 @@ impl Instrument for Vaporisateur {
 
     fn reset(state: &mut VaporisateurState) {
         state.voicing.reset();
+        state.sustain.reset();
+        state.params.pitch_bend = 0.0;
+        state.params.mod_wheel = 0.0;
+        state.params.channel_pressure = 0.0;
+    }
+}
+
+impl Vaporisateur {
+    /// Apply a matrix routing's source / target / amount, found by its bound id (ignored when unknown).
+    fn modulation_changed(state: &mut VaporisateurState, id: u32, value: ParamValue) {
+        for (ids, slot) in state.modulation_ids.iter().zip(&mut state.params.matrix) {
+            let Some(key) = ids.iter().position(|bound| *bound == id) else {continue};
+            match key as u16 + 1 {
+                modulation::SOURCE => slot.source = ModSource::from_index(int_value(value, &MOD_SOURCE_MAPPING)),
+                modulation::TARGET => slot.target = ModTarget::from_index(int_value(value, &MOD_TARGET_MAPPING)),
+                modulation::AMOUNT => slot.amount = float_value(value, &BIPOLAR),
+                _ => {}
+            }
+            return;
+        }
     }
 }
 
@@ -275,49 +362,59 @@ pub fn render(state: &mut VaporisateurState, events: &[EventRecord], out_left: &
 // ---- The device ABI: shared with the engine, called wasm-to-wasm. ----
 
 /// What the host wires this device as (read at load): an instrument that voices notes into audio.
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn kind() -> u32 {
     abi::DEVICE_KIND_INSTRUMENT
 }
 
 /// Bytes the engine must allocate (zeroed) for one instance's state block. The voice pools are fixed, so the
 /// size does not depend on `sample_rate`.
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn state_size(_sample_rate: f32) -> u32 {
     core::mem::size_of::<VaporisateurState>() as u32
 }
 
-#[no_mangle]
-pub extern "C" fn process(desc_ptr: u32) {
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn process(desc_ptr: usize) {
     let ports = unsafe { Ports::<VaporisateurState>::from_descriptor(desc_ptr) };
     abi::render_instrument::<Vaporisateur>(ports);
 }
 
 /// Boot hook: bind this device's parameters with the host (it records their field-paths and returns an id
 /// each) and stash the (stable) sample rate.
-#[no_mangle]
-pub extern "C" fn init(state_ptr: u32, sample_rate: f32) {
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn init(state_ptr: usize, sample_rate: f32) {
     unsafe { abi::with_state(state_ptr, |state| <Vaporisateur as Instrument>::init(state, sample_rate)) }
 }
 
 /// Apply a parameter value the host resolved (initial / edit / automation), by the id `init` got back. The
 /// `kind` tag tells the SDK how to type the f32 `value` into a `ParamValue`.
-#[no_mangle]
-pub extern "C" fn parameter_changed(state_ptr: u32, id: u32, kind: u32, value: f32) {
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn parameter_changed(state_ptr: usize, id: u32, kind: u32, value: f32) {
     unsafe { abi::with_state(state_ptr, |state| <Vaporisateur as Instrument>::parameter_changed(state, id, ParamValue::from_wire(kind, value))) }
 }
 
-/// Parity probe: the REAL value stored for a UNIT automation value, ids in `init` bind order (the `param` slots).
-#[no_mangle]
+/// Parity probe: the REAL value stored for a UNIT automation value, ids in `init` bind order (the `param`
+/// slots, then `modulation::COUNT` per matrix routing).
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn map_parameter(id: u32, unit: f32) -> f32 {
     let value = ParamValue::Unit(unit);
-    match id as usize {
+    let id = id as usize;
+    if (param::COUNT..param::COUNT + SLOTS * modulation::COUNT).contains(&id) {
+        return match ((id - param::COUNT) % modulation::COUNT) as u16 + 1 {
+            modulation::SOURCE => int_value(value, &MOD_SOURCE_MAPPING) as f32,
+            modulation::TARGET => int_value(value, &MOD_TARGET_MAPPING) as f32,
+            _ => float_value(value, &BIPOLAR)
+        };
+    }
+    match id {
         param::OSC_A_WAVEFORM | param::OSC_B_WAVEFORM => int_value(value, &OSC_WAVEFORM_MAPPING) as f32,
         param::OSC_A_VOLUME | param::OSC_B_VOLUME => float_value(value, &VOLUME_MAPPING),
         param::OSC_A_OCTAVE | param::OSC_B_OCTAVE => int_value(value, &OCTAVE_MAPPING) as f32,
         param::OSC_A_TUNE | param::OSC_B_TUNE => float_value(value, &TUNE_MAPPING),
         param::ATTACK | param::DECAY | param::RELEASE => float_value(value, &TIME_MAPPING),
-        param::SUSTAIN | param::GLIDE_TIME | param::UNISON_STEREO => float_value(value, &UNIPOLAR),
+        param::MOD_ATTACK | param::MOD_DECAY | param::MOD_RELEASE => float_value(value, &TIME_MAPPING),
+        param::SUSTAIN | param::MOD_SUSTAIN | param::GLIDE_TIME | param::UNISON_STEREO => float_value(value, &UNIPOLAR),
         param::CUTOFF => float_value(value, &CUTOFF_MAPPING),
         param::RESONANCE => float_value(value, &RESONANCE_MAPPING),
         param::FILTER_ENVELOPE | param::FILTER_KEYBOARD => float_value(value, &BIPOLAR),
@@ -325,16 +422,16 @@ pub extern "C" fn map_parameter(id: u32, unit: f32) -> f32 {
         param::VOICING_MODE => int_value(value, &Values::new(&VOICING_MODE_VALUES)) as f32,
         param::UNISON_COUNT => int_value(value, &Values::new(&UNISON_COUNT_VALUES)) as f32,
         param::UNISON_DETUNE => float_value(value, &DETUNE_MAPPING),
-        param::LFO_WAVEFORM => int_value(value, &Values::new(&LFO_WAVEFORM_VALUES)) as f32,
-        param::LFO_RATE => float_value(value, &LFO_RATE_MAPPING),
+        param::LFO_WAVEFORM | param::LFO_2_WAVEFORM => int_value(value, &Values::new(&LFO_WAVEFORM_VALUES)) as f32,
+        param::LFO_RATE | param::LFO_2_RATE => float_value(value, &LFO_RATE_MAPPING),
         param::LFO_TARGET_TUNE | param::LFO_TARGET_CUTOFF | param::LFO_TARGET_VOLUME => float_value(value, &BIPOLAR),
         _ => f32::NAN
     }
 }
 
 /// Transport STOP: drop every voice (force-stop the voicing) so playback starts silent.
-#[no_mangle]
-pub extern "C" fn reset(state_ptr: u32) {
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn reset(state_ptr: usize) {
     unsafe { abi::with_state(state_ptr, |state| <Vaporisateur as Instrument>::reset(state)) }
 }
 
@@ -389,6 +486,10 @@ mod tests {
         EventRecord {position: 0.0, offset: 0, kind: abi::EVENT_NOTE_OFF, id, pitch: 0, velocity: 0.0, cent: 0.0, duration: 0.0}
     }
 
+    fn channel(kind: u32, pitch: u32, value: f32) -> EventRecord {
+        EventRecord {position: 0.0, offset: 0, kind, id: 0, pitch, velocity: value, cent: 0.0, duration: 0.0}
+    }
+
     fn peak(buffer: &[f32]) -> f32 {
         buffer.iter().fold(0.0f32, |acc, sample| acc.max(sample.abs()))
     }
@@ -488,4 +589,118 @@ mod tests {
         render(&mut state, &[note_on(1, 64)], &mut left, &mut right, SR);
         assert!(peak(&left) > 0.01, "the monophonic strategy sounds a note");
     }
+
+    #[test]
+    fn the_pitch_wheel_bends_a_sounding_note() {
+        let mut state = configured(VoicingMode::Polyphonic);
+        state.params.osc_a_waveform = ClassicWaveform::Sine;
+        let (mut left, mut right) = (vec![0.0f32; 48_000], vec![0.0f32; 48_000]);
+        render(&mut state, &[note_on(1, 69)], &mut left, &mut right, SR);
+        render(&mut state, &[channel(EVENT_PITCH_BEND, 0, 1.0)], &mut left, &mut right, SR);
+        let bent = estimate_frequency(&left[4_800..], SR);
+        let expected = 440.0 * libm::exp2f(PITCH_BEND_RANGE / 12.0);
+        assert!((bent - expected).abs() < 3.0, "a full bend raises A4 by the bend range, got {bent}");
+    }
+
+    #[test]
+    fn a_tuning_expression_bends_only_its_own_note() {
+        let mut state = configured(VoicingMode::Polyphonic);
+        state.params.osc_a_waveform = ClassicWaveform::Sine;
+        let (mut left, mut right) = (vec![0.0f32; 48_000], vec![0.0f32; 48_000]);
+        render(&mut state, &[note_on(1, 69)], &mut left, &mut right, SR);
+        render(&mut state, &[NoteExpression::Tuning(7.0).record(2, 0.0)], &mut left, &mut right, SR);
+        let untouched = estimate_frequency(&left[4_800..], SR);
+        assert!((untouched - 440.0).abs() < 3.0, "another note's expression leaves A4 alone, got {untouched}");
+        render(&mut state, &[NoteExpression::Tuning(12.0).record(1, 0.0)], &mut left, &mut right, SR);
+        let tuned = estimate_frequency(&left[4_800..], SR);
+        assert!((tuned - 880.0).abs() < 5.0, "an octave of tuning doubles the note, got {tuned}");
+    }
+
+    #[test]
+    fn a_note_released_under_the_sustain_pedal_rings_until_it_lifts() {
+        let mut state = configured(VoicingMode::Polyphonic);
+        let (mut left, mut right) = (vec![0.0f32; 256], vec![0.0f32; 256]);
+        render(&mut state, &[channel(EVENT_CONTROL, CC_SUSTAIN, 1.0), note_on(1, 60)], &mut left, &mut right, SR);
+        let (mut held_left, mut held_right) = (vec![0.0f32; 8192], vec![0.0f32; 8192]);
+        render(&mut state, &[note_off(1)], &mut held_left, &mut held_right, SR);
+        assert!(peak(&held_left[6000..]) > 0.01, "the pedal holds the released note");
+        let (mut tail_left, mut tail_right) = (vec![0.0f32; 8192], vec![0.0f32; 8192]);
+        render(&mut state, &[channel(EVENT_CONTROL, CC_SUSTAIN, 0.0)], &mut tail_left, &mut tail_right, SR);
+        assert!(peak(&tail_left[6000..]) < 1.0e-4, "lifting the pedal releases it");
+    }
+
+    #[test]
+    fn a_controller_is_not_mistaken_for_a_note_off() {
+        let mut state = configured(VoicingMode::Polyphonic);
+        let (mut left, mut right) = (vec![0.0f32; 256], vec![0.0f32; 256]);
+        render(&mut state, &[note_on(0, 60)], &mut left, &mut right, SR);
+        let (mut later_left, mut later_right) = (vec![0.0f32; 8192], vec![0.0f32; 8192]);
+        render(&mut state, &[channel(EVENT_CONTROL, abi::CC_MOD_WHEEL, 0.7)], &mut later_left, &mut later_right, SR);
+        assert!(peak(&later_left[6000..]) > 0.01, "note id 0 keeps sounding through a CC (id 0)");
+    }
+
+    fn route(state: &mut VaporisateurState, slot: usize, source: ModSource, target: ModTarget, amount: f32) {
+        state.params.matrix[slot] = matrix::ModSlot {source, target, amount};
+    }
+
+    #[test]
+    fn the_modulation_envelope_routed_to_tune_raises_the_pitch() {
+        let mut state = configured(VoicingMode::Polyphonic);
+        state.params.osc_a_waveform = ClassicWaveform::Sine;
+        state.params.mod_env_attack = 0.001;
+        state.params.mod_env_decay = 0.001;
+        state.params.mod_env_sustain = 1.0;
+        state.params.mod_env_release = 0.05;
+        route(&mut state, 2, ModSource::Env2, ModTarget::OscATune, 1.0);
+        let (mut left, mut right) = (vec![0.0f32; 48_000], vec![0.0f32; 48_000]);
+        render(&mut state, &[note_on(1, 69)], &mut left, &mut right, SR);
+        let frequency = estimate_frequency(&left[4_800..], SR);
+        assert!((frequency - 880.0).abs() < 5.0, "a full envelope is an octave up, got {frequency}");
+    }
+
+    #[test]
+    fn the_mod_wheel_routed_to_cutoff_opens_the_filter() {
+        let closed_rms = |wheel: f32| {
+            let mut state = configured(VoicingMode::Polyphonic);
+            state.params.flt_cutoff = 0.05;
+            route(&mut state, 0, ModSource::ModWheel, ModTarget::Cutoff, 0.9);
+            let (mut left, mut right) = (vec![0.0f32; 4096], vec![0.0f32; 4096]);
+            render(&mut state, &[channel(EVENT_CONTROL, CC_MOD_WHEEL, wheel), note_on(1, 81)], &mut left, &mut right, SR);
+            rms(&left[1024..])
+        };
+        let (resting, opened) = (closed_rms(0.0), closed_rms(1.0));
+        assert!(opened > 2.0 * resting, "the wheel opens the filter ({opened} vs {resting})");
+    }
+
+    #[test]
+    fn channel_aftertouch_routed_to_volume_mutes_the_oscillator() {
+        let mut state = configured(VoicingMode::Polyphonic);
+        route(&mut state, 0, ModSource::Aftertouch, ModTarget::OscAVolume, -1.0);
+        let (mut left, mut right) = (vec![0.0f32; 4096], vec![0.0f32; 4096]);
+        render(&mut state, &[note_on(1, 60)], &mut left, &mut right, SR);
+        assert!(peak(&left) > 0.01, "audible without pressure");
+        render(&mut state, &[channel(EVENT_CHANNEL_PRESSURE, 0, 1.0)], &mut left, &mut right, SR);
+        assert!(peak(&left[1024..]) < 1.0e-3, "full pressure pulls the volume to nothing, got {}", peak(&left[1024..]));
+    }
+
+    #[test]
+    fn a_note_pressure_expression_drives_the_aftertouch_source_of_its_own_note() {
+        let mut state = configured(VoicingMode::Polyphonic);
+        route(&mut state, 0, ModSource::Aftertouch, ModTarget::OscAVolume, -1.0);
+        let (mut left, mut right) = (vec![0.0f32; 4096], vec![0.0f32; 4096]);
+        render(&mut state, &[note_on(1, 60)], &mut left, &mut right, SR);
+        render(&mut state, &[NoteExpression::Pressure(1.0).record(2, 0.0)], &mut left, &mut right, SR);
+        assert!(peak(&left[1024..]) > 0.01, "another note's pressure leaves this one audible");
+        render(&mut state, &[NoteExpression::Pressure(1.0).record(1, 0.0)], &mut left, &mut right, SR);
+        assert!(peak(&left[1024..]) < 1.0e-3, "the note's own pressure pulls its volume to nothing, got {}", peak(&left[1024..]));
+    }
+
+    #[test]
+    fn velocity_routed_to_pan_moves_the_note_right() {
+        let mut state = configured(VoicingMode::Polyphonic);
+        route(&mut state, 5, ModSource::Velocity, ModTarget::Pan, 0.8);
+        let (mut left, mut right) = (vec![0.0f32; 4096], vec![0.0f32; 4096]);
+        render(&mut state, &[note_on(1, 60)], &mut left, &mut right, SR);
+        assert!(rms(&right) > 2.0 * rms(&left), "a hard velocity pans right ({} vs {})", rms(&right), rms(&left));
+    }
 }
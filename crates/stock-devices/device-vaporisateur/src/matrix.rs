@@ -0,0 +1,195 @@
+//! The modulation matrix: `SLOTS` routings, each a [`ModSource`] scaled by a bipolar amount onto a
+//! [`ModTarget`]. A voice samples its sources once per chunk ([`ModSources`]), [`evaluate`] sums every
+//! routing into one offset per target ([`ModOffsets`]), and the voice applies the offsets over the chunk. The
+//! hardwired LFO targets and the filter envelope stay as they are; the matrix adds on top of them.
+//!
+//! Valid when zeroed: every slot is `Off -> Off`, so an untouched matrix yields all-zero offsets and the
+//! voice renders exactly as without it.
+
+/// The routings in the matrix (the `modulations` array length).
+pub const SLOTS: usize = 8;
+
+/// What a routing reads. LFOs are bipolar (`-1..1`), the envelopes, velocity, mod wheel and aftertouch
+/// unipolar (`0..1`), the key bipolar around middle C, and the random value a bipolar draw per note.
+#[derive(Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub enum ModSource {
+    #[default]
+    Off,
+    Lfo1,
+    Lfo2,
+    Env1,
+    Env2,
+    Velocity,
+    Key,
+    ModWheel,
+    /// The channel pressure or the note's own pressure expression, whichever is higher.
+    Aftertouch,
+    Random
+}
+
+impl ModSource {
+    pub fn from_index(index: i32) -> Self {
+        match index {
+            1 => ModSource::Lfo1,
+            2 => ModSource::Lfo2,
+            3 => ModSource::Env1,
+            4 => ModSource::Env2,
+            5 => ModSource::Velocity,
+            6 => ModSource::Key,
+            7 => ModSource::ModWheel,
+            8 => ModSource::Aftertouch,
+            9 => ModSource::Random,
+            _ => ModSource::Off
+        }
+    }
+}
+
+/// What a routing moves. An offset of `1` is one octave of tune, a doubled oscillator volume, the whole
+/// cutoff / resonance / unison-detune range (in their unit domains), or a hard pan to the right.
+#[derive(Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub enum ModTarget {
+    #[default]
+    Off,
+    OscATune,
+    OscBTune,
+    OscAVolume,
+    OscBVolume,
+    Cutoff,
+    Resonance,
+    Pan,
+    UnisonDetune
+}
+
+/// The targets a routing can reach (every [`ModTarget`] but `Off`).
+pub const TARGETS: usize = 8;
+
+impl ModTarget {
+    pub fn from_index(index: i32) -> Self {
+        match index {
+            1 => ModTarget::OscATune,
+            2 => ModTarget::OscBTune,
+            3 => ModTarget::OscAVolume,
+            4 => ModTarget::OscBVolume,
+            5 => ModTarget::Cutoff,
+            6 => ModTarget::Resonance,
+            7 => ModTarget::Pan,
+            8 => ModTarget::UnisonDetune,
+            _ => ModTarget::Off
+        }
+    }
+}
+
+/// One routing: `source * amount` added to `target`.
+#[derive(Clone, Copy, Default)]
+pub struct ModSlot {
+    pub(crate) source: ModSource,
+    pub(crate) target: ModTarget,
+    pub(crate) amount: f32 // bipolar
+}
+
+impl ModSlot {
+    fn is_active(&self) -> bool {
+        self.source != ModSource::Off && self.target != ModTarget::Off && self.amount != 0.0
+    }
+}
+
+/// Whether any active routing reads `source` (a voice skips computing a source nobody listens to).
+pub fn uses(slots: &[ModSlot; SLOTS], source: ModSource) -> bool {
+    slots.iter().any(|slot| slot.is_active() && slot.source == source)
+}
+
+/// The source values a voice sampled for one chunk.
+#[derive(Clone, Copy, Default)]
+pub struct ModSources {
+    pub lfo_1: f32,
+    pub lfo_2: f32,
+    pub env_1: f32,
+    pub env_2: f32,
+    pub velocity: f32,
+    pub key: f32,
+    pub mod_wheel: f32,
+    pub aftertouch: f32,
+    pub random: f32
+}
+
+impl ModSources {
+    fn value(&self, source: ModSource) -> f32 {
+        match source {
+            ModSource::Off => 0.0,
+            ModSource::Lfo1 => self.lfo_1,
+            ModSource::Lfo2 => self.lfo_2,
+            ModSource::Env1 => self.env_1,
+            ModSource::Env2 => self.env_2,
+            ModSource::Velocity => self.velocity,
+            ModSource::Key => self.key,
+            ModSource::ModWheel => self.mod_wheel,
+            ModSource::Aftertouch => self.aftertouch,
+            ModSource::Random => self.random
+        }
+    }
+}
+
+/// The summed offset per target for one chunk; all zero for an empty matrix.
+#[derive(Clone, Copy, Default)]
+pub struct ModOffsets {
+    values: [f32; TARGETS]
+}
+
+impl ModOffsets {
+    pub fn get(&self, target: ModTarget) -> f32 {
+        match target {
+            ModTarget::Off => 0.0,
+            target => self.values[target as usize - 1]
+        }
+    }
+}
+
+/// Sum every active routing in `slots` over the sampled `sources`.
+pub fn evaluate(slots: &[ModSlot; SLOTS], sources: &ModSources) -> ModOffsets {
+    let mut offsets = ModOffsets::default();
+    for slot in slots.iter().filter(|slot| slot.is_active()) {
+        offsets.values[slot.target as usize - 1] += sources.value(slot.source) * slot.amount;
+    }
+    offsets
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn slot(source: ModSource, target: ModTarget, amount: f32) -> ModSlot {
+        ModSlot {source, target, amount}
+    }
+
+    #[test]
+    fn an_empty_matrix_offsets_nothing() {
+        let sources = ModSources {lfo_1: 1.0, velocity: 1.0, random: -1.0, ..ModSources::default()};
+        let offsets = evaluate(&[ModSlot::default(); SLOTS], &sources);
+        for index in 0..=TARGETS as i32 {
+            assert_eq!(offsets.get(ModTarget::from_index(index)), 0.0);
+        }
+    }
+
+    #[test]
+    fn routings_to_one_target_sum_and_others_stay_apart() {
+        let mut slots = [ModSlot::default(); SLOTS];
+        slots[0] = slot(ModSource::Lfo1, ModTarget::Cutoff, 0.5);
+        slots[3] = slot(ModSource::Velocity, ModTarget::Cutoff, -0.25);
+        slots[7] = slot(ModSource::ModWheel, ModTarget::Pan, 1.0);
+        slots[5] = slot(ModSource::Key, ModTarget::Off, 1.0); // no target: inactive
+        let sources = ModSources {lfo_1: -1.0, velocity: 0.8, mod_wheel: 0.3, key: 1.0, ..ModSources::default()};
+        let offsets = evaluate(&slots, &sources);
+        assert!((offsets.get(ModTarget::Cutoff) - (-0.5 - 0.2)).abs() < 1.0e-6);
+        assert!((offsets.get(ModTarget::Pan) - 0.3).abs() < 1.0e-6);
+        assert_eq!(offsets.get(ModTarget::OscATune), 0.0);
+        assert!(uses(&slots, ModSource::Lfo1) && !uses(&slots, ModSource::Key) && !uses(&slots, ModSource::Lfo2));
+    }
+
+    #[test]
+    fn indices_round_trip_and_unknown_ones_are_off() {
+        assert_eq!(ModSource::from_index(9), ModSource::Random);
+        assert_eq!(ModSource::from_index(42), ModSource::Off);
+        assert_eq!(ModTarget::from_index(8), ModTarget::UnisonDetune);
+        assert_eq!(ModTarget::from_index(-1), ModTarget::Off);
+    }
+}
@@ -6,9 +6,12 @@
 //! per call: they live in a [`Workspace`] held behind a `RefCell` in the shared params, so the engine
 //! allocates them once with the state block and every voice borrows the SAME workspace in turn each block (a
 //! safe analog of the TS module-level shared buffers, which rely on the single-threaded processor).
+//!
+//! On top of the TS voice, each voice runs a second LFO and a modulation envelope and evaluates the
+//! [`crate::matrix`] once per chunk; with an empty matrix the rendered output is unchanged.
 
-use core::cell::RefCell;
-use abi::{Block, EventRecord};
+use core::cell::{Cell, RefCell};
+use abi::{Block, EventRecord, NoteExpression};
 use dsp::biquad::ModulatedBiquad;
 use dsp::glide::Glide;
 use dsp::lfo::Lfo;
@@ -17,8 +20,11 @@ use dsp::panning::{panning_to_gains, Mixing};
 use dsp::smooth::Smooth;
 use dsp::{keyboard_tracking, velocity_to_gain, RENDER_QUANTUM};
 use math::clamp_unit;
+use math::value_mapping::{Exponential, ValueMapping};
 use voicing::Voice;
 use crate::adsr::Adsr;
+use crate::matrix::{self, ModSlot, ModSource, ModSources, ModTarget, SLOTS};
+use crate::{DETUNE_MAPPING, RESONANCE_MAPPING};
 
 pub const MIN_CUTOFF: f64 = 20.0; // VaporisateurSettings.MIN_CUTOFF
 pub const MAX_CUTOFF: f64 = 20_000.0; // VaporisateurSettings.MAX_CUTOFF
@@ -34,6 +40,8 @@ pub struct Workspace {
     freq_b: [f32; RENDER_QUANTUM],
     vca: [f32; RENDER_QUANTUM],
     lfo: [f32; RENDER_QUANTUM],
+    lfo_2: [f32; RENDER_QUANTUM],
+    mod_env: [f32; RENDER_QUANTUM],
     cutoff: [f32; RENDER_QUANTUM],
     osc_a: [f32; RENDER_QUANTUM],
     osc_b: [f32; RENDER_QUANTUM],
@@ -44,7 +52,8 @@ impl Default for Workspace {
     fn default() -> Self {
         Self {
             freq: [0.0; RENDER_QUANTUM], freq_a: [0.0; RENDER_QUANTUM], freq_b: [0.0; RENDER_QUANTUM],
-            vca: [0.0; RENDER_QUANTUM], lfo: [0.0; RENDER_QUANTUM], cutoff: [0.0; RENDER_QUANTUM],
+            vca: [0.0; RENDER_QUANTUM], lfo: [0.0; RENDER_QUANTUM], lfo_2: [0.0; RENDER_QUANTUM],
+            mod_env: [0.0; RENDER_QUANTUM], cutoff: [0.0; RENDER_QUANTUM],
             osc_a: [0.0; RENDER_QUANTUM], osc_b: [0.0; RENDER_QUANTUM], osc_sum: [0.0; RENDER_QUANTUM]
         }
     }
@@ -52,8 +61,9 @@ impl Default for Workspace {
 
 /// The device's live parameters the voice reads (the `voicing::Voice::Shared` type): the resolved real values
 /// (osc gains / waveforms / frequency multipliers, filter cutoff / resonance / envelope amount / order, the
-/// ADSR times, LFO shape / rate / targets, unison detune / stereo), the sample rate, and the shared render
-/// [`Workspace`]. The device mutates these in `parameter_changed`; voices read them at note-on (`start`) and
+/// ADSR times, LFO shape / rate / targets, unison detune / stereo), the second LFO, the modulation envelope
+/// and matrix, the channel pitch bend / mod wheel / aftertouch, the sample rate, the random source's seed, and
+/// the shared render [`Workspace`]. The device mutates these in `parameter_changed`; voices read them at note-on (`start`) and
 /// each chunk (`process`). The osc octave / tune are kept so the frequency multiplier can be recomputed when
 /// either changes.
 pub struct VaporisateurParams {
@@ -81,20 +91,52 @@ pub struct VaporisateurParams {
     pub(crate) lfo_target_tune: f32,
     pub(crate) lfo_target_cutoff: f32,
     pub(crate) lfo_target_volume: f32,
+    pub(crate) lfo_2_shape: ClassicWaveform,
+    pub(crate) lfo_2_rate: f32,
+    pub(crate) mod_env_attack: f32,
+    pub(crate) mod_env_decay: f32,
+    pub(crate) mod_env_sustain: f32,
+    pub(crate) mod_env_release: f32,
+    pub(crate) matrix: [ModSlot; SLOTS],
     pub(crate) unison_detune: f32,
     pub(crate) unison_stereo: f32,
+    pub(crate) pitch_bend: f32, // the channel pitch wheel in semitones, applied to every sounding voice
+    pub(crate) mod_wheel: f32, // the channel mod wheel (CC 1), 0..1
+    pub(crate) channel_pressure: f32, // channel aftertouch, 0..1
     pub(crate) sample_rate: f32,
+    pub(crate) random_seed: Cell<u32>, // advanced by every note-on's random draw
     pub(crate) workspace: RefCell<Workspace>
 }
 
+/// `value` moved by a matrix `offset` in its parameter's unit domain (clamped to the range); `value` itself
+/// when the offset is 0.
+fn modulate(mapping: &Exponential, value: f32, offset: f32) -> f32 {
+    if offset == 0.0 {
+        return value;
+    }
+    mapping.y(clamp_unit(mapping.x(value) + offset))
+}
+
+/// A bipolar (`-1..1`) draw for the matrix random source: a mulberry32 step on the shared seed.
+fn next_random(seed: &Cell<u32>) -> f32 {
+    let state = seed.get().wrapping_add(0x6D2B79F5);
+    seed.set(state);
+    let mut t = (state ^ (state >> 15)).wrapping_mul(state | 1);
+    t ^= t.wrapping_add((t ^ (t >> 7)).wrapping_mul(t | 61));
+    ((t ^ (t >> 14)) as f64 / 4294967296.0 * 2.0 - 1.0) as f32
+}
+
 /// One Vaporisateur voice. All per-note DSP is reconstructed in `start` (fresh phase / envelope / glide, like
 /// the TS `new VaporisateurVoice` per note); `process` renders the osc -> mix -> filter -> ADSR-VCA chain.
+/// The note's own expressions (MPE tuning / pressure / brightness) bend, swell and open it independently.
 pub struct VaporisateurVoice {
     osc_a: BandLimitedOscillator,
     osc_b: BandLimitedOscillator,
     lfo: Lfo,
+    lfo_2: Lfo,
     filter: ModulatedBiquad,
     env: Adsr,
+    mod_env: Adsr,
     glide: Glide,
     gain_a_smooth: Smooth,
     gain_b_smooth: Smooth,
@@ -104,16 +146,23 @@ pub struct VaporisateurVoice {
     spread: f32,
     velocity: f32,
     filter_keyboard_delta: f32,
-    sample_rate: f32
+    key: f32,    // the matrix key source: bipolar around middle C
+    random: f32, // the matrix random source: one bipolar draw per note
+    sample_rate: f32,
+    tuning: f32,     // semitones, on top of the channel wheel
+    pressure: f32,   // 0..1, pushes the level from the velocity gain toward full scale
+    brightness: f32  // -1..1, shifts the unit cutoff by up to half its range
 }
 
 impl Default for VaporisateurVoice {
     fn default() -> Self {
         Self {
             osc_a: BandLimitedOscillator::default(), osc_b: BandLimitedOscillator::default(),
-            lfo: Lfo::default(), filter: ModulatedBiquad::new(), env: Adsr::default(), glide: Glide::default(),
-            gain_a_smooth: Smooth::default(), gain_b_smooth: Smooth::default(), gain_vca_smooth: Smooth::default(),
-            smooth_coeff: 0.0, gain: 0.0, spread: 0.0, velocity: 0.0, filter_keyboard_delta: 0.0, sample_rate: 0.0
+            lfo: Lfo::default(), lfo_2: Lfo::default(), filter: ModulatedBiquad::new(), env: Adsr::default(),
+            mod_env: Adsr::default(), glide: Glide::default(), gain_a_smooth: Smooth::default(),
+            gain_b_smooth: Smooth::default(), gain_vca_smooth: Smooth::default(), smooth_coeff: 0.0, gain: 0.0,
+            spread: 0.0, velocity: 0.0, filter_keyboard_delta: 0.0, key: 0.0, random: 0.0, sample_rate: 0.0,
+            tuning: 0.0, pressure: 0.0, brightness: 0.0
         }
     }
 }
@@ -124,39 +173,56 @@ impl VaporisateurVoice {
     /// borrowed shared `workspace` for scratch.
     fn process_window(&mut self, out_left: &mut [f32], out_right: &mut [f32], block: &Block, shared: &VaporisateurParams, work: &mut Workspace) -> bool {
         let len = out_left.len();
+        self.lfo.fill(&mut work.lfo, shared.lfo_shape, shared.lfo_rate, 0, len);
+        self.env.process(&mut work.vca, 0, len);
+        self.mod_env.process(&mut work.mod_env, 0, len);
+        if matrix::uses(&shared.matrix, ModSource::Lfo2) {
+            self.lfo_2.fill(&mut work.lfo_2, shared.lfo_2_shape, shared.lfo_2_rate, 0, len);
+        }
+        let offsets = matrix::evaluate(&shared.matrix, &self.mod_sources(shared, work));
         let gain = velocity_to_gain(self.velocity) * self.gain;
+        let gain = gain + self.pressure * (1.0 - gain);
+        let cutoff = shared.flt_cutoff + self.filter_keyboard_delta + self.brightness * 0.5 + offsets.get(ModTarget::Cutoff);
+        let resonance = modulate(&RESONANCE_MAPPING, shared.flt_resonance, offsets.get(ModTarget::Resonance));
+        let unison_detune = modulate(&DETUNE_MAPPING, shared.unison_detune, offsets.get(ModTarget::UnisonDetune));
         // WASM CONTRACT: `fast_exp2` mirrors lib-dsp `fastExp2` (the TS voice computes the same f64 product).
-        let detune = dsp::fast_math::fast_exp2(self.spread as f64 * (shared.unison_detune as f64 / 1200.0)) as f32;
-        let panning = self.spread * shared.unison_stereo;
+        let detune = dsp::fast_math::fast_exp2(self.spread as f64 * (unison_detune as f64 / 1200.0)) as f32;
+        let panning = (self.spread * shared.unison_stereo + offsets.get(ModTarget::Pan)).clamp(-1.0, 1.0);
         let [gain_l, gain_r] = panning_to_gains(panning, Mixing::Linear);
         for sample in &mut work.freq[..len] {
             *sample = detune;
         }
         self.glide.process(&mut work.freq, block.bpm, self.sample_rate, 0, len);
-        self.lfo.fill(&mut work.lfo, shared.lfo_shape, shared.lfo_rate, 0, len);
-        self.env.process(&mut work.vca, 0, len);
+        // An offset of 0 is exactly 1.0 (tune) and exactly the unmodulated gain (volume).
+        let frequency_a_multiplier = shared.frequency_a_multiplier * libm::exp2f(offsets.get(ModTarget::OscATune));
+        let frequency_b_multiplier = shared.frequency_b_multiplier * libm::exp2f(offsets.get(ModTarget::OscBTune));
+        let gain_osc_a = shared.gain_osc_a * (1.0 + offsets.get(ModTarget::OscAVolume)).max(0.0);
+        let gain_osc_b = shared.gain_osc_b * (1.0 + offsets.get(ModTarget::OscBVolume)).max(0.0);
         let lfo_target_tune = shared.lfo_target_tune;
         let lfo_target_cutoff = shared.lfo_target_cutoff;
         let lfo_target_volume = shared.lfo_target_volume;
         // BIT-EXACT fast path: with no tune modulation, `exp2f(lfo * 0.0)` is exactly 1.0 and `freq * 1.0`
         // is the identity, so the per-sample call (a large share of the voice cost) can be skipped outright.
         let tune_modulated = lfo_target_tune != 0.0;
+        // The wheel is channel-wide and the tuning per note, both per chunk (an event splits the block);
+        // 0 semitones is exactly 1.0.
+        let bend = libm::exp2f((shared.pitch_bend + self.tuning) / 12.0);
         for index in 0..len {
             let lfo = work.lfo[index];
-            work.cutoff[index] = shared.flt_cutoff + self.filter_keyboard_delta + work.vca[index] * shared.flt_env_amount + lfo * lfo_target_cutoff;
+            work.cutoff[index] = cutoff + work.vca[index] * shared.flt_env_amount + lfo * lfo_target_cutoff;
             work.vca[index] *= clamp_unit(gain + lfo * lfo_target_volume);
             // WASM CONTRACT: `fast_exp2` mirrors lib-dsp `fastExp2`, fed the f64 product like the TS voice.
             let frequency = if tune_modulated { work.freq[index] * dsp::fast_math::fast_exp2(lfo as f64 * lfo_target_tune as f64) as f32 } else { work.freq[index] };
-            work.freq_a[index] = frequency * shared.frequency_a_multiplier;
-            work.freq_b[index] = frequency * shared.frequency_b_multiplier;
+            work.freq_a[index] = frequency * frequency_a_multiplier * bend;
+            work.freq_b[index] = frequency * frequency_b_multiplier * bend;
         }
         self.osc_a.generate_from_frequencies(&mut work.osc_a, &work.freq_a, shared.osc_a_waveform, 0, len);
         self.osc_b.generate_from_frequencies(&mut work.osc_b, &work.freq_b, shared.osc_b_waveform, 0, len);
         for index in 0..len {
-            work.osc_sum[index] = work.osc_a[index] * self.gain_a_smooth.process(self.smooth_coeff, shared.gain_osc_a as f64) as f32
-                + work.osc_b[index] * self.gain_b_smooth.process(self.smooth_coeff, shared.gain_osc_b as f64) as f32;
+            work.osc_sum[index] = work.osc_a[index] * self.gain_a_smooth.process(self.smooth_coeff, gain_osc_a as f64) as f32
+                + work.osc_b[index] * self.gain_b_smooth.process(self.smooth_coeff, gain_osc_b as f64) as f32;
         }
-        self.filter.process(&mut work.osc_sum, &work.cutoff, shared.flt_resonance as f64, shared.flt_order.clamp(1, 4) as usize, MIN_CUTOFF, MAX_CUTOFF, self.sample_rate, 0, len);
+        self.filter.process(&mut work.osc_sum, &work.cutoff, resonance as f64, shared.flt_order.clamp(1, 4) as usize, MIN_CUTOFF, MAX_CUTOFF, self.sample_rate, 0, len);
         for index in 0..len {
             let vca = self.gain_vca_smooth.process(self.smooth_coeff, clamp_unit(work.vca[index]) as f64) as f32;
             let out = work.osc_sum[index] * vca;
@@ -171,6 +237,16 @@ impl VaporisateurVoice {
 }
 
 impl VaporisateurVoice {
+    /// The matrix sources at the start of the chunk just filled into `work`: both LFOs, both envelopes, and
+    /// the note / channel values. Aftertouch is the channel pressure or the note's own, whichever is higher.
+    fn mod_sources(&self, shared: &VaporisateurParams, work: &Workspace) -> ModSources {
+        ModSources {
+            lfo_1: work.lfo[0], lfo_2: work.lfo_2[0], env_1: work.vca[0], env_2: work.mod_env[0],
+            velocity: self.velocity, key: self.key, mod_wheel: shared.mod_wheel,
+            aftertouch: shared.channel_pressure.max(self.pressure), random: self.random
+        }
+    }
+
     /// The amp envelope's UI phase (TS `first.env.phase`), for the editor's envelope playhead broadcast.
     pub fn env_phase(&self) -> f32 {
         self.env.phase() as f32
@@ -190,24 +266,35 @@ impl Voice for VaporisateurVoice {
         self.osc_a = BandLimitedOscillator::new(sample_rate);
         self.osc_b = BandLimitedOscillator::new(sample_rate);
         self.lfo = Lfo::new(sample_rate);
+        self.lfo_2 = Lfo::new(sample_rate);
         self.filter = ModulatedBiquad::new();
         self.env = Adsr::new(sample_rate);
         self.env.set(shared.env_attack, shared.env_decay, shared.env_sustain, shared.env_release);
         self.env.gate_on();
+        self.mod_env = Adsr::new(sample_rate);
+        self.mod_env.set(shared.mod_env_attack, shared.mod_env_decay, shared.mod_env_sustain, shared.mod_env_release);
+        self.mod_env.gate_on();
+        self.key = ((event.pitch as f32 - 60.0) / 60.0).clamp(-1.0, 1.0);
+        self.random = next_random(&shared.random_seed);
         self.glide = Glide::default();
         self.glide.init(frequency as f64);
         self.gain_a_smooth = Smooth::default();
         self.gain_b_smooth = Smooth::default();
         self.gain_vca_smooth = Smooth::default();
         self.smooth_coeff = Smooth::coefficient(SMOOTH_TIME, sample_rate as f64);
+        self.tuning = 0.0;
+        self.pressure = 0.0;
+        self.brightness = 0.0;
     }
 
     fn stop(&mut self) {
         self.env.gate_off();
+        self.mod_env.gate_off();
     }
 
     fn force_stop(&mut self) {
         self.env.force_stop();
+        self.mod_env.force_stop();
     }
 
     fn start_glide(&mut self, target_frequency: f32, glide_duration: f64) {
@@ -222,6 +309,14 @@ impl Voice for VaporisateurVoice {
         self.glide.current_frequency() as f32
     }
 
+    fn express(&mut self, expression: NoteExpression) {
+        match expression {
+            NoteExpression::Tuning(semitones) => self.tuning = semitones,
+            NoteExpression::Pressure(pressure) => self.pressure = pressure,
+            NoteExpression::Brightness(brightness) => self.brightness = brightness
+        }
+    }
+
     fn process(&mut self, output: [&mut [f32]; 2], block: &Block, shared: &VaporisateurParams) -> bool {
         let [out_left, out_right] = output;
         let mut work = shared.workspace.borrow_mut();
@@ -0,0 +1,552 @@
+//! The WAVETABLE synth, a polyphonic INSTRUMENT device (`WavetableDeviceBox`) around the mip-mapped
+//! `dsp::wavetable` oscillator: the table's frame position (plus an envelope sweep) morphs through the frames,
+//! a modulated low-pass (cutoff + resonance + filter-envelope) shapes it, and an ADSR VCA plays it. Notes run
+//! through the shared `voicing` framework like the Vaporisateur: `unison` detuned / spread sub-voices per note
+//! ([`voicing::VoiceUnison`]) allocated polyphonically or monophonically ([`voicing::Voicing`]), with glide,
+//! the pitch wheel, the sustain pedal and per-note expressions (MPE). The mix is brick-wall limited.
+//!
+//! The table is the `file` pointer's `AudioFileBox`, observed with `abi::observe_sample` and resolved per
+//! block with `abi::resolve_sample` (channel 0 is read). A file of at least `FRAME_SIZE` frames is cut into
+//! consecutive `FRAME_SIZE`-frame cycles (the common wavetable layout; more than `MAX_FRAMES` cycles are
+//! picked evenly); a shorter file is one cycle, resampled to `FRAME_SIZE`. Without a file the table is a
+//! single sine. When the file changes, the frames are rebuilt `LOAD_FRAMES` per render call on the render
+//! thread and the voices morph over the frames built so far; a bound file that is not resident yet keeps the
+//! current table playing.
+//!
+//! Heap-free: the voices live in the engine-allocated (zeroed) state block, and the band-limited frames in
+//! its flexible `[f32; 0]` tail (`MAX_FRAMES * FRAME_FLOATS` floats, added by `state_size`). No `Vec`.
+//!
+//! Exports: `kind()` (instrument), `state_size()`, `process(desc_ptr)`, `init(state_ptr, sample_rate)`,
+//! `parameter_changed(...)`, `sample_changed(...)`, `map_parameter(...)`, `reset(state_ptr)`.
+
+#![cfg_attr(target_family = "wasm", no_std)]
+
+#[cfg(target_family = "wasm")]
+use core::panic::PanicInfo;
+use abi::{float_value, int_value, Block, EventRecord, Instrument, NoteExpression, ParamValue, Ports, SampleRef, SustainPedal};
+use abi::{CC_SUSTAIN, EVENT_CONTROL, EVENT_NOTE_EXPRESSION, EVENT_NOTE_OFF, EVENT_NOTE_ON, EVENT_PITCH_BEND, PITCH_BEND_RANGE};
+use dsp::wavetable::{WavetableBuilder, FRAME_FLOATS, FRAME_SIZE};
+use dsp::{midi_to_hz_base, ppqn};
+use math::value_mapping::{Decibel, Exponential, Linear, LinearInteger, Values, ValueMapping};
+use math::db_to_gain;
+use voicing::{VoiceUnison, Voicing, VoicingMode};
+
+mod voice;
+use voice::{WavetableParams, WavetableVoice};
+
+#[cfg(target_family = "wasm")]
+#[panic_handler]
+fn panic(info: &PanicInfo) -> ! {
+    abi::panic_to_host(info) // deposit the message in the engine's panic buffer, then trap (never a silent hang)
+}
+
+const POLY_VOICES: usize = 16; // polyphonic voice slots
+const MONO_STACK: usize = 16; // monophonic held-note stack depth
+const SUSTAINED: usize = 64; // note-offs the sustain pedal can hold back
+const UNISON_MAX: usize = 5; // the widest unison (the unison-count values are [1, 3, 5])
+/// The most frames a table holds; it sizes the tail.
+const MAX_FRAMES: usize = 64;
+/// The frames band-limited per `process_audio` call while a table loads.
+const LOAD_FRAMES: usize = 2;
+
+// The WavetableDeviceBox field-key path of the table file.
+const SAMPLE_POINTER: [u16; 1] = [11];
+
+const VOLUME_MAPPING: Decibel = Decibel::default_volume(); // decibel(-72, -12, 0)
+const TUNE_MAPPING: Linear = Linear {min: -1200.0, max: 1200.0}; // cents
+const OCTAVE_MAPPING: LinearInteger = LinearInteger {min: -3, max: 3};
+const CUTOFF_MAPPING: Exponential = Exponential {min: 20.0, max: 20_000.0}; // used as a UNIT value
+const RESONANCE_MAPPING: Exponential = Exponential {min: 0.01, max: 10.0}; // filter Q
+const TIME_MAPPING: Exponential = Exponential {min: 0.001, max: 5.0}; // attack / decay / release (seconds)
+const DETUNE_MAPPING: Exponential = Exponential {min: 1.0, max: 1200.0}; // unison detune (cents)
+const BIPOLAR: Linear = Linear::bipolar(); // position / filter envelope amounts
+const UNIPOLAR: Linear = Linear::unipolar(); // position / sustain / glide-time / unison-stereo
+const UNISON_COUNT_VALUES: [i32; 3] = [1, 3, 5];
+const VOICING_MODE_VALUES: [i32; 2] = [0, 1]; // VoicingMode::{Monophonic, Polyphonic}
+
+// The parameter slots, the order this device binds them (`state.ids[INDEX]`).
+mod param {
+    pub const VOLUME: usize = 0;
+    pub const POSITION: usize = 1;
+    pub const POSITION_ENVELOPE: usize = 2;
+    pub const OCTAVE: usize = 3;
+    pub const TUNE: usize = 4;
+    pub const CUTOFF: usize = 5;
+    pub const RESONANCE: usize = 6;
+    pub const FILTER_ENVELOPE: usize = 7;
+    pub const ATTACK: usize = 8;
+    pub const DECAY: usize = 9;
+    pub const SUSTAIN: usize = 10;
+    pub const RELEASE: usize = 11;
+    pub const GLIDE_TIME: usize = 12;
+    pub const VOICING_MODE: usize = 13;
+    pub const UNISON_COUNT: usize = 14;
+    pub const UNISON_DETUNE: usize = 15;
+    pub const UNISON_STEREO: usize = 16;
+    pub const COUNT: usize = 17;
+}
+
+/// Resolve the cutoff as a UNIT value (0..1): the automation value directly, or a real Hz mapped back to the
+/// unit interval (the filter maps it to Hz itself).
+fn cutoff_unit(value: ParamValue) -> f32 {
+    match value {
+        ParamValue::Unit(unit) => unit,
+        ParamValue::Float(real) => CUTOFF_MAPPING.x(real),
+        ParamValue::Int(real) => CUTOFF_MAPPING.x(real as f32),
+        ParamValue::Bool(flag) => if flag {1.0} else {0.0}
+    }
+}
+
+/// What the table's frames are built from.
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum TableSource {
+    Sine,
+    File(u32)
+}
+
+/// The table frames `sample` yields: its `FRAME_SIZE` cycles (at most `MAX_FRAMES`), or one resampled cycle.
+fn frames_of(sample: &SampleRef) -> usize {
+    (sample.frame_count as usize / FRAME_SIZE).clamp(1, MAX_FRAMES)
+}
+
+/// Sample `index` (of `FRAME_SIZE`) of table frame `frame` (of `frames`) read from `plane`.
+fn cycle_sample(plane: &[f32], frame: usize, frames: usize, index: usize) -> f32 {
+    let cycles = plane.len() / FRAME_SIZE;
+    if cycles == 0 {
+        // one short cycle, stretched over the frame with linear interpolation
+        let position = index as f64 * plane.len() as f64 / FRAME_SIZE as f64;
+        let at = position as usize;
+        let alpha = (position - at as f64) as f32;
+        let (current, next) = (plane[at], plane[(at + 1) % plane.len()]);
+        return current + alpha * (next - current);
+    }
+    let cycle = if frames > 1 {frame * (cycles - 1) / (frames - 1)} else {0};
+    plane[cycle * FRAME_SIZE + index]
+}
+
+/// The table tail at `tail` (`MAX_FRAMES * FRAME_FLOATS` floats). The tail is disjoint from the state header,
+/// so the slice never aliases the state's own fields.
+unsafe fn table<'a>(tail: *mut f32) -> &'a mut [f32] {
+    core::slice::from_raw_parts_mut(tail, MAX_FRAMES * FRAME_FLOATS)
+}
+
+/// The device's per-instance state, interpreted from the engine-allocated (zeroed) block: the voicing
+/// dispatcher, the live parameters the voices read, the sustain pedal, the output limiter, the frame builder
+/// and the table bookkeeping (source, frames, frames built), the sample rate, the glide time (pulses) and
+/// unison count, the bound ids, and the flexible `[f32; 0]` tail holding the frames.
+#[repr(C)]
+pub struct WavetableState {
+    voicing: Voicing<VoiceUnison<WavetableVoice, UNISON_MAX>, POLY_VOICES, MONO_STACK>,
+    params: WavetableParams,
+    sustain: SustainPedal<SUSTAINED>,
+    limiter: dsp::simple_limiter::SimpleLimiter,
+    builder: WavetableBuilder,
+    sample: Option<u32>, // the resolved sample handle while the `file` pointer is bound; `None` when unbound
+    source: Option<TableSource>,
+    frames: usize,
+    loaded: usize,
+    sample_rate: f32,
+    glide_time: f64,
+    unison_count: i32,
+    ids: [u32; param::COUNT],
+    sample_id: u32,
+    tail: [f32; 0]
+}
+
+/// The DSP, plugged into the SDK's `Instrument` template ([`abi::render_instrument`]).
+pub struct WavetableSynth;
+
+impl Instrument for WavetableSynth {
+    type State = WavetableState;
+
+    fn init(state: &mut WavetableState, sample_rate: f32) {
+        state.sample_rate = sample_rate; // stable for the device's life
+        state.params.sample_rate = sample_rate;
+        state.limiter.prepare(sample_rate);
+        state.builder = WavetableBuilder::new();
+        state.sample = None; // no file until the engine catches up the `file` pointer right after init
+        state.source = None;
+        state.ids[param::VOLUME] = abi::bind_parameter(&[10]);
+        state.ids[param::POSITION] = abi::bind_parameter(&[12]);
+        state.ids[param::POSITION_ENVELOPE] = abi::bind_parameter(&[13]);
+        state.ids[param::OCTAVE] = abi::bind_parameter(&[14]);
+        state.ids[param::TUNE] = abi::bind_parameter(&[15]);
+        state.ids[param::CUTOFF] = abi::bind_parameter(&[16]);
+        state.ids[param::RESONANCE] = abi::bind_parameter(&[17]);
+        state.ids[param::FILTER_ENVELOPE] = abi::bind_parameter(&[18]);
+        state.ids[param::ATTACK] = abi::bind_parameter(&[19]);
+        state.ids[param::DECAY] = abi::bind_parameter(&[20]);
+        state.ids[param::SUSTAIN] = abi::bind_parameter(&[21]);
+        state.ids[param::RELEASE] = abi::bind_parameter(&[22]);
+        state.ids[param::GLIDE_TIME] = abi::bind_parameter(&[23]);
+        state.ids[param::VOICING_MODE] = abi::bind_parameter(&[24]);
+        state.ids[param::UNISON_COUNT] = abi::bind_parameter(&[25]);
+        state.ids[param::UNISON_DETUNE] = abi::bind_parameter(&[26]);
+        state.ids[param::UNISON_STEREO] = abi::bind_parameter(&[27]);
+        state.sample_id = abi::observe_sample(&SAMPLE_POINTER);
+    }
+
+    fn handle_event(state: &mut WavetableState, event: &EventRecord) {
+        if event.kind == EVENT_NOTE_ON {
+            let frequency = midi_to_hz_base(event.pitch as f32 + event.cent / 100.0, abi::base_frequency());
+            let unison = state.unison_count.max(1) as usize;
+            state.voicing.start(event, frequency, 1.0, state.glide_time, unison, &state.params);
+        } else if event.kind == EVENT_NOTE_OFF {
+            if !state.sustain.hold(event.id) {
+                state.voicing.stop(event.id as i32, state.glide_time);
+            }
+        } else if event.kind == EVENT_PITCH_BEND {
+            state.params.pitch_bend = event.velocity.clamp(-1.0, 1.0) * PITCH_BEND_RANGE;
+        } else if event.kind == EVENT_CONTROL && event.pitch == CC_SUSTAIN {
+            let WavetableState {sustain, voicing, glide_time, ..} = state;
+            sustain.set(event.velocity, &mut |id| voicing.stop(id as i32, *glide_time));
+        } else if event.kind == EVENT_NOTE_EXPRESSION {
+            if let Some(expression) = NoteExpression::from_record(event) {
+                state.voicing.express(event.id as i32, expression);
+            }
+        }
+    }
+
+    fn process_audio(state: &mut WavetableState, output: [&mut [f32]; 2], block: &Block) {
+        let sample = state.sample.and_then(abi::resolve_sample);
+        WavetableSynth::load_table(state, sample.as_ref());
+        let [out_left, out_right] = output;
+        state.voicing.process([&mut *out_left, &mut *out_right], block, &state.params);
+        state.limiter.replace(out_left, out_right, 0, out_left.len());
+    }
+
+    fn parameter_changed(state: &mut WavetableState, id: u32, value: ParamValue) {
+        let Some(index) = state.ids.iter().position(|bound| *bound == id) else {
+            return;
+        };
+        let params = &mut state.params;
+        match index {
+            param::VOLUME => params.gain = db_to_gain(float_value(value, &VOLUME_MAPPING)),
+            param::POSITION => params.position = float_value(value, &UNIPOLAR),
+            param::POSITION_ENVELOPE => params.position_env_amount = float_value(value, &BIPOLAR),
+            param::OCTAVE => {
+                params.octave = int_value(value, &OCTAVE_MAPPING);
+                params.frequency_multiplier = libm::exp2f(params.octave as f32 + params.tune / 1200.0);
+            }
+            param::TUNE => {
+                params.tune = float_value(value, &TUNE_MAPPING);
+                params.frequency_multiplier = libm::exp2f(params.octave as f32 + params.tune / 1200.0);
+            }
+            param::CUTOFF => params.flt_cutoff = cutoff_unit(value),
+            param::RESONANCE => params.flt_resonance = float_value(value, &RESONANCE_MAPPING),
+            param::FILTER_ENVELOPE => params.flt_env_amount = float_value(value, &BIPOLAR),
+            param::ATTACK => params.env_attack = float_value(value, &TIME_MAPPING),
+            param::DECAY => params.env_decay = float_value(value, &TIME_MAPPING),
+            param::SUSTAIN => params.env_sustain = float_value(value, &UNIPOLAR),
+            param::RELEASE => params.env_release = float_value(value, &TIME_MAPPING),
+            param::UNISON_DETUNE => params.unison_detune = float_value(value, &DETUNE_MAPPING),
+            param::UNISON_STEREO => params.unison_stereo = float_value(value, &UNIPOLAR),
+            param::GLIDE_TIME => state.glide_time = float_value(value, &UNIPOLAR) as f64 * ppqn::BAR,
+            param::UNISON_COUNT => state.unison_count = int_value(value, &Values::new(&UNISON_COUNT_VALUES)),
+            param::VOICING_MODE => state.voicing.set_mode(VoicingMode::from_index(int_value(value, &Values::new(&VOICING_MODE_VALUES)))),
+            _ => {}
+        }
+    }
+
+    fn sample_changed(state: &mut WavetableState, id: u32, sample: Option<u32>) {
+        if id == state.sample_id {
+            state.sample = sample;
+        }
+    }
+
+    fn reset(state: &mut WavetableState) {
+        state.voicing.reset();
+        state.sustain.reset();
+        state.params.pitch_bend = 0.0;
+    }
+}
+
+impl WavetableSynth {
+    /// Follow the table source (the resolved file, the sine without one; an unresolved bound file keeps the
+    /// current table), restart the build when it changed, band-limit the next `LOAD_FRAMES` frames, and
+    /// point the voices at the frames built so far.
+    fn load_table(state: &mut WavetableState, sample: Option<&SampleRef>) {
+        let sample = sample.filter(|sample| sample.frame_count > 0 && sample.channel_count > 0);
+        let source = match (state.sample, sample) {
+            (Some(handle), Some(_)) => TableSource::File(handle),
+            (Some(_), None) => state.source.unwrap_or(TableSource::Sine),
+            (None, _) => TableSource::Sine
+        };
+        if state.source != Some(source) {
+            state.source = Some(source);
+            state.frames = match (source, sample) {
+                (TableSource::File(_), Some(sample)) => frames_of(sample),
+                _ => 1
+            };
+            state.loaded = 0;
+        }
+        let table = unsafe { table(state.tail.as_mut_ptr()) };
+        if state.loaded < state.frames {
+            let frames = state.frames;
+            let end = (state.loaded + LOAD_FRAMES).min(frames);
+            for frame in state.loaded..end {
+                let target = &mut table[frame * FRAME_FLOATS..][..FRAME_FLOATS];
+                match (source, sample) {
+                    (TableSource::File(_), Some(sample)) => {
+                        let plane = sample.plane(0);
+                        state.builder.build(target, |index| cycle_sample(plane, frame, frames, index));
+                    }
+                    (TableSource::File(_), None) => break, // not resident this block: resume once it is
+                    (TableSource::Sine, _) => {
+                        state.builder.build(target, |index| libm::sinf(core::f32::consts::TAU * index as f32 / FRAME_SIZE as f32));
+                    }
+                }
+                state.loaded = frame + 1;
+            }
+        }
+        state.params.table_ptr = table.as_ptr() as usize;
+        state.params.frames = state.loaded;
+    }
+}
+
+/// Host-independent entry for tests: clear the stereo output, dispatch the supplied events through the SDK
+/// template, and run the post-pass. The wasm `process` path uses [`abi::render_instrument`] instead.
+pub fn render(state: &mut WavetableState, events: &[EventRecord], out_left: &mut [f32], out_right: &mut [f32]) {
+    out_left.fill(0.0);
+    out_right.fill(0.0);
+    let block = Block {index: 0, flags: abi::BlockFlags(0), p0: 0.0, p1: 0.0, s0: 0, s1: out_left.len() as u32, bpm: 120.0};
+    abi::dispatch_range::<WavetableSynth>(state, [&mut *out_left, &mut *out_right], events, &block);
+    WavetableSynth::finish(state, [out_left, out_right]);
+}
+
+// ---- The device ABI: shared with the engine, called wasm-to-wasm. ----
+
+/// What the host wires this device as (read at load): an instrument that voices notes into audio.
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn kind() -> u32 {
+    abi::DEVICE_KIND_INSTRUMENT
+}
+
+/// Bytes the engine must allocate (zeroed) for one instance's state block: the header plus the table tail.
+/// Neither depends on `sample_rate`.
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn state_size(_sample_rate: f32) -> u32 {
+    (core::mem::size_of::<WavetableState>() + MAX_FRAMES * FRAME_FLOATS * core::mem::size_of::<f32>()) as u32
+}
+
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn process(desc_ptr: usize) {
+    let ports = unsafe { Ports::<WavetableState>::from_descriptor(desc_ptr) };
+    abi::render_instrument::<WavetableSynth>(ports);
+}
+
+/// Boot hook: bind this device's parameters + its table file with the host, and stash the sample rate.
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn init(state_ptr: usize, sample_rate: f32) {
+    unsafe { abi::with_state(state_ptr, |state| <WavetableSynth as Instrument>::init(state, sample_rate)) }
+}
+
+/// Apply a parameter value the host resolved (initial / edit / automation), by the id `init` got back.
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn parameter_changed(state_ptr: usize, id: u32, kind: u32, value: f32) {
+    unsafe { abi::with_state(state_ptr, |state| <WavetableSynth as Instrument>::parameter_changed(state, id, ParamValue::from_wire(kind, value))) }
+}
+
+/// Apply the observed table file (its `file` pointer), by the id `observe_sample` returned. `present != 0`
+/// means a resident `handle`, `0` means the pointer is unbound.
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn sample_changed(state_ptr: usize, id: u32, handle: u32, present: u32) {
+    let sample = if present != 0 {Some(handle)} else {None};
+    unsafe { abi::with_state(state_ptr, |state| <WavetableSynth as Instrument>::sample_changed(state, id, sample)) }
+}
+
+/// Parity probe: the REAL value stored for a UNIT automation value, ids in `init` bind order (the `param` slots).
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn map_parameter(id: u32, unit: f32) -> f32 {
+    let value = ParamValue::Unit(unit);
+    match id as usize {
+        param::VOLUME => float_value(value, &VOLUME_MAPPING),
+        param::POSITION | param::SUSTAIN | param::GLIDE_TIME | param::UNISON_STEREO => float_value(value, &UNIPOLAR),
+        param::POSITION_ENVELOPE | param::FILTER_ENVELOPE => float_value(value, &BIPOLAR),
+        param::OCTAVE => int_value(value, &OCTAVE_MAPPING) as f32,
+        param::TUNE => float_value(value, &TUNE_MAPPING),
+        param::CUTOFF => float_value(value, &CUTOFF_MAPPING),
+        param::RESONANCE => float_value(value, &RESONANCE_MAPPING),
+        param::ATTACK | param::DECAY | param::RELEASE => float_value(value, &TIME_MAPPING),
+        param::VOICING_MODE => int_value(value, &Values::new(&VOICING_MODE_VALUES)) as f32,
+        param::UNISON_COUNT => int_value(value, &Values::new(&UNISON_COUNT_VALUES)) as f32,
+        param::UNISON_DETUNE => float_value(value, &DETUNE_MAPPING),
+        _ => f32::NAN
+    }
+}
+
+/// Transport STOP: drop every voice (the table stays) so playback starts silent.
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn reset(state_ptr: usize) {
+    unsafe { abi::with_state(state_ptr, <WavetableSynth as Instrument>::reset) }
+}
+
+#[cfg(test)]
+mod tests {
+    //! The wavetable voice chain driven through the ABI's `Instrument` dispatch, with fabricated resident
+    //! files (`resolve_sample` has no host on native; a bound, unresolved file keeps the table the test
+    //! loaded). The state and its table tail live in one zeroed, 8-aligned buffer, like the engine's.
+    use super::*;
+
+    const SR: f32 = 48_000.0;
+
+    struct Instance {
+        memory: Vec<u64>
+    }
+
+    impl Instance {
+        /// A zeroed state configured for a clearly audible patch: unity gain, the first frame, the filter wide
+        /// open, a fast envelope, one unison voice.
+        fn new(mode: VoicingMode) -> Self {
+            let memory = vec![0u64; (state_size(SR) as usize).div_ceil(8)];
+            let mut instance = Instance {memory};
+            let state = instance.state();
+            state.sample_rate = SR;
+            state.params.sample_rate = SR;
+            state.limiter.prepare(SR);
+            state.builder = WavetableBuilder::new();
+            state.source = None;
+            state.sample = None;
+            state.unison_count = 1;
+            state.voicing.set_mode(mode);
+            let params = &mut state.params;
+            params.gain = 1.0;
+            params.frequency_multiplier = 1.0;
+            params.flt_cutoff = 1.0;
+            params.flt_resonance = 0.7;
+            params.env_attack = 0.001;
+            params.env_decay = 0.001;
+            params.env_sustain = 1.0;
+            params.env_release = 0.050;
+            params.unison_detune = 30.0;
+            params.unison_stereo = 1.0;
+            instance
+        }
+
+        fn state(&mut self) -> &mut WavetableState {
+            unsafe { &mut *(self.memory.as_mut_ptr() as *mut WavetableState) }
+        }
+
+        /// Bind `file` as the table and build all of it.
+        fn load(&mut self, file: &SampleRef) {
+            let state = self.state();
+            state.sample = Some(1);
+            while {
+                WavetableSynth::load_table(state, Some(file));
+                state.loaded < state.frames
+            } {}
+        }
+
+        fn render(&mut self, events: &[EventRecord], frames: usize) -> (Vec<f32>, Vec<f32>) {
+            let (mut left, mut right) = (vec![0.0f32; frames], vec![0.0f32; frames]);
+            render(self.state(), events, &mut left, &mut right);
+            (left, right)
+        }
+    }
+
+    fn file(frames: &[f32]) -> SampleRef {
+        SampleRef {frames_ptr: frames.as_ptr() as usize, frame_count: frames.len() as u32, channel_count: 1, sample_rate: SR}
+    }
+
+    fn note_on(id: u32, pitch: u32) -> EventRecord {
+        EventRecord {position: 0.0, offset: 0, kind: EVENT_NOTE_ON, id, pitch, velocity: 1.0, cent: 0.0, duration: 0.0}
+    }
+
+    fn note_off(id: u32) -> EventRecord {
+        EventRecord {position: 0.0, offset: 0, kind: EVENT_NOTE_OFF, id, pitch: 0, velocity: 0.0, cent: 0.0, duration: 0.0}
+    }
+
+    fn peak(buffer: &[f32]) -> f32 {
+        buffer.iter().fold(0.0f32, |acc, sample| acc.max(sample.abs()))
+    }
+
+    /// Fundamental estimate: rising zero crossings per second over a steady sustain.
+    fn estimate_frequency(buffer: &[f32]) -> f32 {
+        let crossings = buffer.windows(2).filter(|pair| pair[0] < 0.0 && pair[1] >= 0.0).count();
+        crossings as f32 * SR / buffer.len() as f32
+    }
+
+    /// Two cycles: a sine, then a square (odd harmonics, so its band-limited top level still crosses once).
+    fn sine_then_square() -> Vec<f32> {
+        let sine = (0..FRAME_SIZE).map(|index| libm::sinf(core::f32::consts::TAU * index as f32 / FRAME_SIZE as f32));
+        let square = (0..FRAME_SIZE).map(|index| if index < FRAME_SIZE / 2 {1.0} else {-1.0});
+        sine.chain(square).collect()
+    }
+
+    #[test]
+    fn without_a_file_a_note_plays_the_sine_at_its_pitch() {
+        let mut instance = Instance::new(VoicingMode::Polyphonic);
+        let (left, right) = instance.render(&[note_on(1, 69)], 48_000);
+        let frequency = estimate_frequency(&left[4_800..]);
+        assert!((frequency - 440.0).abs() < 2.0, "A4, got {frequency}");
+        assert!(peak(&left) > 0.5 && peak(&left) < 1.5, "audible and bounded, got {}", peak(&left));
+        assert_eq!(left, right, "a centred mono note is equal on both channels");
+        assert_eq!(instance.state().params.frames, 1);
+    }
+
+    #[test]
+    fn a_file_is_cut_into_frames_and_the_position_morphs_through_them() {
+        let frames = sine_then_square();
+        let mut instance = Instance::new(VoicingMode::Polyphonic);
+        instance.load(&file(&frames));
+        assert_eq!(instance.state().params.frames, 2);
+        let (sine, _) = instance.render(&[note_on(1, 57)], 9_600);
+        let mut square_instance = Instance::new(VoicingMode::Polyphonic);
+        square_instance.load(&file(&frames));
+        square_instance.state().params.position = 1.0;
+        let (square, _) = square_instance.render(&[note_on(1, 57)], 9_600);
+        let rms = |buffer: &[f32]| libm::sqrtf(buffer.iter().map(|sample| sample * sample).sum::<f32>() / buffer.len() as f32);
+        // a sine's rms is 1 / sqrt 2 of its peak, a square's equals it
+        let (sine_ratio, square_ratio) = (rms(&sine[4_800..]) / peak(&sine[4_800..]), rms(&square[4_800..]) / peak(&square[4_800..]));
+        assert!((sine_ratio - core::f32::consts::FRAC_1_SQRT_2).abs() < 0.02, "the first frame is the sine: {sine_ratio}");
+        assert!(square_ratio > 0.8, "the last frame is the (band-limited, overshooting) square: {square_ratio}");
+    }
+
+    #[test]
+    fn a_short_file_is_one_resampled_cycle() {
+        let cycle: Vec<f32> = (0..100).map(|index| libm::sinf(core::f32::consts::TAU * index as f32 / 100.0)).collect();
+        let mut instance = Instance::new(VoicingMode::Polyphonic);
+        instance.load(&file(&cycle));
+        assert_eq!(instance.state().params.frames, 1);
+        let (left, _) = instance.render(&[note_on(1, 81)], 48_000);
+        let frequency = estimate_frequency(&left[4_800..]);
+        assert!((frequency - 880.0).abs() < 3.0, "the cycle plays at the note's pitch, got {frequency}");
+    }
+
+    #[test]
+    fn a_long_table_builds_over_several_calls_and_caps_its_frames() {
+        let frames = vec![0.5f32; 100 * FRAME_SIZE];
+        let mut instance = Instance::new(VoicingMode::Polyphonic);
+        let state = instance.state();
+        state.sample = Some(1);
+        WavetableSynth::load_table(state, Some(&file(&frames)));
+        assert_eq!((state.frames, state.loaded), (MAX_FRAMES, LOAD_FRAMES));
+        // unresolved this block: the partial table stays and resumes later
+        WavetableSynth::load_table(state, None);
+        assert_eq!((state.source, state.loaded), (Some(TableSource::File(1)), LOAD_FRAMES));
+        instance.load(&file(&frames));
+        assert_eq!(instance.state().params.frames, MAX_FRAMES);
+        // unbinding the file goes back to the sine
+        instance.state().sample = None;
+        WavetableSynth::load_table(instance.state(), None);
+        assert_eq!((instance.state().source, instance.state().params.frames), (Some(TableSource::Sine), 1));
+    }
+
+    #[test]
+    fn releasing_a_note_decays_to_silence() {
+        let mut instance = Instance::new(VoicingMode::Polyphonic);
+        let (left, _) = instance.render(&[note_on(1, 60)], 256);
+        assert!(peak(&left) > 0.01, "sustaining while held");
+        let (tail, _) = instance.render(&[note_off(1)], 8192);
+        assert!(peak(&tail[6000..]) < 1.0e-4, "decays to silence, got {}", peak(&tail[6000..]));
+    }
+
+    #[test]
+    fn monophonic_mode_and_unison_voice_a_note() {
+        let mut instance = Instance::new(VoicingMode::Monophonic);
+        instance.state().unison_count = 3;
+        let (left, right) = instance.render(&[note_on(1, 64)], 4096);
+        assert!(peak(&left) > 0.01, "the monophonic strategy sounds a note");
+        assert_ne!(left, right, "the unison voices spread across the stereo field");
+    }
+}
@@ -0,0 +1,211 @@
+//! One wavetable voice, the leaf [`Voice`] nested inside a [`voicing::VoiceUnison`], plus the live parameters
+//! it reads ([`WavetableParams`], the voicing `Shared` type) and the reusable render workspace.
+//!
+//! The per-chunk scratch (frequency / frame position / envelope / cutoff / oscillator) lives in a
+//! [`Workspace`] behind a `RefCell` in the shared params, so every voice borrows the SAME buffers in turn each
+//! block (as the Vaporisateur does). The table itself is read through [`WavetableParams::table`], a view over
+//! the device state's tail.
+
+use core::cell::RefCell;
+use abi::{Block, EventRecord, NoteExpression};
+use dsp::adsr::Adsr;
+use dsp::biquad::ModulatedBiquad;
+use dsp::glide::Glide;
+use dsp::panning::{panning_to_gains, Mixing};
+use dsp::smooth::Smooth;
+use dsp::wavetable::{Wavetable, WavetableOscillator, FRAME_FLOATS};
+use dsp::{velocity_to_gain, RENDER_QUANTUM};
+use math::clamp_unit;
+use voicing::Voice;
+
+pub const MIN_CUTOFF: f64 = 20.0;
+pub const MAX_CUTOFF: f64 = 20_000.0;
+const SILENCE_THRESHOLD: f32 = 1.0e-4; // ≈ -80 dB
+const SMOOTH_TIME: f64 = 0.003; // the VCA smoother's time constant (seconds)
+
+/// The per-chunk render scratch (one quantum wide), reused by every voice each block. Valid when zeroed.
+pub struct Workspace {
+    freq: [f32; RENDER_QUANTUM],
+    position: [f32; RENDER_QUANTUM],
+    vca: [f32; RENDER_QUANTUM],
+    cutoff: [f32; RENDER_QUANTUM],
+    osc: [f32; RENDER_QUANTUM]
+}
+
+/// The device's live parameters the voice reads (the `voicing::Voice::Shared` type): the resolved real values
+/// (gain, frame position and its envelope amount, the frequency multiplier from octave / tune, the filter,
+/// the ADSR times, unison detune / stereo), the channel pitch bend, the sample rate, the table the device
+/// loaded this block, and the shared render [`Workspace`].
+pub struct WavetableParams {
+    pub(crate) gain: f32,
+    pub(crate) position: f32,
+    pub(crate) position_env_amount: f32,
+    pub(crate) octave: i32,
+    pub(crate) tune: f32,
+    pub(crate) frequency_multiplier: f32,
+    pub(crate) flt_cutoff: f32, // a unit value (0..1); the filter maps it to Hz
+    pub(crate) flt_resonance: f32,
+    pub(crate) flt_env_amount: f32,
+    pub(crate) env_attack: f32,
+    pub(crate) env_decay: f32,
+    pub(crate) env_sustain: f32,
+    pub(crate) env_release: f32,
+    pub(crate) unison_detune: f32,
+    pub(crate) unison_stereo: f32,
+    pub(crate) pitch_bend: f32, // the channel pitch wheel in semitones, applied to every sounding voice
+    pub(crate) sample_rate: f32,
+    pub(crate) table_ptr: usize, // the first frame in the device state's tail, set before each render
+    pub(crate) frames: usize,    // the frames built so far (0 plays silence)
+    pub(crate) workspace: RefCell<Workspace>
+}
+
+impl WavetableParams {
+    /// The loaded frames as a read view.
+    pub fn table(&self) -> Wavetable<'_> {
+        if self.frames == 0 {
+            return Wavetable::new(&[], 0);
+        }
+        // SAFETY: `table_ptr` points at the device state's tail, which holds at least `frames * FRAME_FLOATS`
+        // built floats and outlives these params; nothing writes the tail while the voices render.
+        let data = unsafe { core::slice::from_raw_parts(self.table_ptr as *const f32, self.frames * FRAME_FLOATS) };
+        Wavetable::new(data, self.frames)
+    }
+}
+
+/// One wavetable voice: a fresh oscillator / filter / envelope / glide per note (reset in `start`); `process`
+/// renders the oscillator -> filter -> ADSR-VCA chain, the envelope also sweeping the frame position and the
+/// cutoff. The note's own expressions (MPE tuning / pressure / brightness) bend, swell and open it.
+pub struct WavetableVoice {
+    osc: WavetableOscillator,
+    filter: ModulatedBiquad,
+    env: Adsr,
+    glide: Glide,
+    gain_vca_smooth: Smooth,
+    smooth_coeff: f64,
+    gate: bool,
+    gain: f32,
+    spread: f32,
+    velocity: f32,
+    sample_rate: f32,
+    tuning: f32,     // semitones, on top of the channel wheel
+    pressure: f32,   // 0..1, pushes the level from the velocity gain toward full scale
+    brightness: f32  // -1..1, shifts the unit cutoff by up to half its range
+}
+
+impl Default for WavetableVoice {
+    fn default() -> Self {
+        Self {
+            osc: WavetableOscillator::default(), filter: ModulatedBiquad::new(), env: Adsr::new(0.0),
+            glide: Glide::default(), gain_vca_smooth: Smooth::default(), smooth_coeff: 0.0, gate: false, gain: 0.0,
+            spread: 0.0, velocity: 0.0, sample_rate: 0.0, tuning: 0.0, pressure: 0.0, brightness: 0.0
+        }
+    }
+}
+
+impl WavetableVoice {
+    /// Render one window (`<= RENDER_QUANTUM`) into the stereo slices, returning `true` once the released
+    /// envelope has faded below silence.
+    fn process_window(&mut self, out_left: &mut [f32], out_right: &mut [f32], block: &Block, shared: &WavetableParams, work: &mut Workspace) -> bool {
+        let len = out_left.len();
+        let gain = velocity_to_gain(self.velocity) * self.gain;
+        let gain = gain + self.pressure * (1.0 - gain);
+        let cutoff = shared.flt_cutoff + self.brightness * 0.5;
+        let detune = libm::exp2f(self.spread * shared.unison_detune / 1200.0);
+        let [gain_l, gain_r] = panning_to_gains(self.spread * shared.unison_stereo, Mixing::Linear);
+        work.freq[..len].fill(detune);
+        self.glide.process(&mut work.freq, block.bpm, self.sample_rate, 0, len);
+        // The wheel is channel-wide and the tuning per note, both per chunk (an event splits the block).
+        let pitch = shared.frequency_multiplier * libm::exp2f((shared.pitch_bend + self.tuning) / 12.0);
+        let lanes = work.freq[..len].iter_mut().zip(&mut work.position[..len]).zip(&mut work.vca[..len]).zip(&mut work.cutoff[..len]);
+        for (((freq, position), vca), unit_cutoff) in lanes {
+            let env = self.env.next_value();
+            *freq *= pitch;
+            *position = clamp_unit(shared.position + env * shared.position_env_amount);
+            *unit_cutoff = cutoff + env * shared.flt_env_amount;
+            *vca = env * gain;
+        }
+        self.osc.generate(&mut work.osc, &work.freq, &work.position, &shared.table(), 0, len);
+        self.filter.process(&mut work.osc, &work.cutoff, shared.flt_resonance as f64, 1, MIN_CUTOFF, MAX_CUTOFF, self.sample_rate, 0, len);
+        let frames = work.osc[..len].iter().zip(&work.vca[..len]).zip(out_left.iter_mut().zip(out_right.iter_mut()));
+        for ((osc, vca), (left, right)) in frames {
+            let vca = self.gain_vca_smooth.process(self.smooth_coeff, *vca as f64) as f32;
+            let out = osc * vca * shared.gain;
+            *left += out * gain_l;
+            *right += out * gain_r;
+            if self.env.is_idle() && vca < SILENCE_THRESHOLD {
+                return true;
+            }
+        }
+        false
+    }
+}
+
+impl Voice for WavetableVoice {
+    type Shared = WavetableParams;
+
+    fn start(&mut self, event: &EventRecord, frequency: f32, gain: f32, spread: f32, _unison: usize, shared: &WavetableParams) {
+        let sample_rate = shared.sample_rate;
+        self.gain = gain;
+        self.spread = spread;
+        self.velocity = event.velocity;
+        self.sample_rate = sample_rate;
+        self.osc = WavetableOscillator::new(sample_rate);
+        self.filter = ModulatedBiquad::new();
+        self.env = Adsr::new(sample_rate);
+        self.env.set(shared.env_attack, shared.env_decay, shared.env_sustain, shared.env_release);
+        self.env.gate_on();
+        self.gate = true;
+        self.glide = Glide::default();
+        self.glide.init(frequency as f64);
+        self.gain_vca_smooth = Smooth::default();
+        self.smooth_coeff = Smooth::coefficient(SMOOTH_TIME, sample_rate as f64);
+        self.tuning = 0.0;
+        self.pressure = 0.0;
+        self.brightness = 0.0;
+    }
+
+    fn stop(&mut self) {
+        self.env.gate_off();
+        self.gate = false;
+    }
+
+    fn force_stop(&mut self) {
+        self.env.force_stop();
+        self.gate = false;
+    }
+
+    fn start_glide(&mut self, target_frequency: f32, glide_duration: f64) {
+        self.glide.glide_to(target_frequency as f64, glide_duration);
+    }
+
+    fn gate(&self) -> bool {
+        self.gate
+    }
+
+    fn current_frequency(&self) -> f32 {
+        self.glide.current_frequency() as f32
+    }
+
+    fn express(&mut self, expression: NoteExpression) {
+        match expression {
+            NoteExpression::Tuning(semitones) => self.tuning = semitones,
+            NoteExpression::Pressure(pressure) => self.pressure = pressure,
+            NoteExpression::Brightness(brightness) => self.brightness = brightness
+        }
+    }
+
+    fn process(&mut self, output: [&mut [f32]; 2], block: &Block, shared: &WavetableParams) -> bool {
+        let [out_left, out_right] = output;
+        let mut work = shared.workspace.borrow_mut();
+        let total = out_left.len();
+        let mut base = 0;
+        while base < total {
+            let len = (total - base).min(RENDER_QUANTUM);
+            if self.process_window(&mut out_left[base..base + len], &mut out_right[base..base + len], block, shared, &mut work) {
+                return true;
+            }
+            base += len;
+        }
+        false
+    }
+}
@@ -29,26 +29,26 @@ pub struct WerkstattState {
     sample_rate: f32
 }
 
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn kind() -> u32 {
     DEVICE_KIND_AUDIO_EFFECT
 }
 
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn state_size(_sample_rate: f32) -> u32 {
     core::mem::size_of::<WerkstattState>() as u32
 }
 
 /// The `parameters` hub field key — the engine observes its `WerkstattParameterBox` children and drives
 /// `parameter_changed` per child (declaration index as id). Parallel to the `midi_effects_field()` convention.
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn observe_param_collection_field() -> u32 {
     11
 }
 
 /// Boot: stash the sample rate, learn this device's box uuid, and create the JS-side script bridge keyed to it.
-#[no_mangle]
-pub extern "C" fn init(state_ptr: u32, sample_rate: f32) {
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn init(state_ptr: usize, sample_rate: f32) {
     unsafe {
         abi::with_state::<WerkstattState>(state_ptr, |state| {
             state.sample_rate = sample_rate;
@@ -63,8 +63,8 @@ fn forward_param(state: &mut WerkstattState, id: u32, value: ParamValue) {
     abi::script_param(state.handle, id, value);
 }
 
-#[no_mangle]
-pub extern "C" fn parameter_changed(state_ptr: u32, id: u32, kind: u32, value: f32) {
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn parameter_changed(state_ptr: usize, id: u32, kind: u32, value: f32) {
     unsafe {
         abi::with_state::<WerkstattState>(state_ptr, |state| {
             forward_param(state, id, ParamValue::from_wire(kind, value));
@@ -74,8 +74,8 @@ pub extern "C" fn parameter_changed(state_ptr: u32, id: u32, kind: u32, value: f
 
 /// This device's INSTANCE is dying (a genuine removal, never a chain-edit survivor): release the JS-side
 /// script bridge (its Processor + runtime), so removing/rebinding a Werkstatt device no longer orphans one.
-#[no_mangle]
-pub extern "C" fn terminate(state_ptr: u32) {
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn terminate(state_ptr: usize) {
     unsafe { abi::with_state::<WerkstattState>(state_ptr, |state| abi::script_release(state.handle)) }
 }
 
@@ -83,13 +83,13 @@ pub extern "C" fn terminate(state_ptr: u32) {
 /// SPLITTING the block at the device's parameter-update positions so an automated `@param` is refreshed between
 /// sub-ranges (mirrors `render_effect`'s split loop — the script processes each sub-range whole). A device with
 /// no automated parameter sees `first_update_position == INFINITY` and renders the block in one call.
-#[no_mangle]
-pub extern "C" fn process(desc_ptr: u32) {
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn process(desc_ptr: usize) {
     let ports = unsafe { Ports::<WerkstattState>::from_descriptor(desc_ptr) };
     let Ports {output, state, blocks, ..} = ports;
     let [out_left, out_right] = output;
-    let out_l = out_left.as_ptr() as u32;
-    let out_r = out_right.as_ptr() as u32;
+    let out_l = out_left.as_ptr() as usize;
+    let out_r = out_right.as_ptr() as usize;
     let (src_l, src_r) = match abi::resolve_input(MAIN_INPUT) {
         Some(input) => (input.left, input.right),
         None => {
@@ -18,6 +18,12 @@
 //! `disableAutomation: true`, so no Value track can target the groove fields — there is no automation to
 //! mirror via `bind_parameter`.
 //!
+//! The same box can carry a groove TEMPLATE ([`template`]): `template-steps` `[12]` (0 = none), the step
+//! length `template-step` `[13]` (ppqn) and the per-step `offset` / `accent` pairs of the `template` array
+//! `[14]`, measured by the studio from a note region or a drum break's transients. While the template has steps it
+//! replaces the Moebius shuffle in `warp` / `unwarp`, and each note-on's velocity is scaled by the accent of
+//! the step it was played on.
+//!
 //! Exports: `kind()` (midi effect), `state_size()`, `init(...)`, `field_changed(...)`, `map_parameter(...)`,
 //! `process_events(...)`.
 
@@ -27,6 +33,9 @@
 use core::panic::PanicInfo;
 use abi::{EventRecord, FieldValue, ParamValue};
 use math::value_mapping::{Linear, Values};
+use template::{GrooveTemplate, MAX_STEPS};
+
+pub mod template;
 
 #[cfg(target_family = "wasm")]
 #[panic_handler]
@@ -38,6 +47,10 @@ fn panic(info: &PanicInfo) -> ! {
 // (amount 10, duration 11) — the observation paths run THROUGH the pointer.
 const AMOUNT_FIELD: [u16; 2] = [10, 10];
 const DURATION_FIELD: [u16; 2] = [10, 11];
+// The template fields (steps 12, step 13, the step array 14 of `{offset 1, accent 2}`), through the pointer too.
+const TEMPLATE_STEPS_FIELD: [u16; 2] = [10, 12];
+const TEMPLATE_STEP_FIELD: [u16; 2] = [10, 13];
+const TEMPLATE_ARRAY_KEY: u16 = 14;
 
 // WASM CONTRACT: mirrors `GrooveShuffleBoxAdapter.DurationPPQNs` (`PPQN.fromSignature` over `Durations`).
 const DURATION_PPQNS: [i32; 9] = [480, 960, 960, 1920, 3840, 7680, 15360, 30720, 61440];
@@ -55,12 +68,18 @@ const PULL_SCRATCH: usize = 256;
 
 /// The device's per-instance state (engine-allocated, seeded in `init`): `h` is the SQUASHED amount
 /// (`squashUnit(amount, 0.01)`, so `0.5` is straight / identity), `duration` the groove cell in pulses.
-/// The ids match the `observe_field` declarations.
+/// `template` is the measured groove that overrides the shuffle while it has steps. The ids match the
+/// `observe_field` declarations.
 pub struct ZeitgeistState {
     h: f64,
     duration: f64,
+    template: GrooveTemplate,
     amount_id: u32,
-    duration_id: u32
+    duration_id: u32,
+    steps_id: u32,
+    step_id: u32,
+    offset_ids: [u32; MAX_STEPS],
+    accent_ids: [u32; MAX_STEPS]
 }
 
 // TS `squashUnit(value, margin)`: clamp to the unit interval, then squeeze into `[margin, 1 - margin]` —
@@ -88,25 +107,32 @@ fn transform(state: &ZeitgeistState, position: f64, forward: bool) -> f64 {
 }
 
 pub fn warp(state: &ZeitgeistState, position: f64) -> f64 {
-    transform(state, position, true)
+    if state.template.is_active() { state.template.warp(position) } else { transform(state, position, true) }
 }
 
 pub fn unwarp(state: &ZeitgeistState, position: f64) -> f64 {
-    transform(state, position, false)
+    if state.template.is_active() { state.template.unwarp(position) } else { transform(state, position, false) }
 }
 
 /// Seed a (zeroed) state with the `GrooveShuffleBox` schema defaults and declare the groove field
-/// observations. Kept separate from the `init` export so tests can seed a state directly (the export takes
-/// a `u32` pointer, which truncates on a 64-bit native test build).
+/// observations. Kept separate from the `init` export so tests can seed a typed state directly.
 pub fn seed(state: &mut ZeitgeistState) {
     state.h = squash_unit(DEFAULT_AMOUNT as f64);
     state.duration = DEFAULT_DURATION;
+    state.template = GrooveTemplate::default();
     state.amount_id = abi::observe_field(&AMOUNT_FIELD);
     state.duration_id = abi::observe_field(&DURATION_FIELD);
+    state.steps_id = abi::observe_field(&TEMPLATE_STEPS_FIELD);
+    state.step_id = abi::observe_field(&TEMPLATE_STEP_FIELD);
+    for index in 0..MAX_STEPS {
+        state.offset_ids[index] = abi::observe_field(&[10, TEMPLATE_ARRAY_KEY, index as u16, 1]);
+        state.accent_ids[index] = abi::observe_field(&[10, TEMPLATE_ARRAY_KEY, index as u16, 2]);
+    }
 }
 
 /// Apply a delivered groove field (mirrors the `GrooveShuffleBoxAdapter` subscriptions): `amount` is squashed
-/// into `h`, `duration` is the real ppqn value (a non-positive one is ignored — the cell length divides).
+/// into `h`, `duration` is the real ppqn value (a non-positive one is ignored — the cell length divides). The
+/// template fields go to the template, which clamps them into range.
 pub fn apply_field(state: &mut ZeitgeistState, id: u32, value: FieldValue) {
     if id == state.amount_id {
         let FieldValue::Float(amount) = value else { panic!("amount must be a float") };
@@ -116,11 +142,24 @@ pub fn apply_field(state: &mut ZeitgeistState, id: u32, value: FieldValue) {
         if duration > 0 {
             state.duration = duration as f64;
         }
+    } else if id == state.steps_id {
+        let FieldValue::Int(steps) = value else { panic!("template-steps must be an int") };
+        state.template.set_steps(steps.max(0) as usize);
+    } else if id == state.step_id {
+        let FieldValue::Int(step) = value else { panic!("template-step must be an int") };
+        state.template.set_step(step as f64);
+    } else if let Some(index) = state.offset_ids.iter().position(|&offset_id| offset_id == id) {
+        let FieldValue::Float(offset) = value else { panic!("offset must be a float") };
+        state.template.set_offset(index, offset as f64);
+    } else if let Some(index) = state.accent_ids.iter().position(|&accent_id| accent_id == id) {
+        let FieldValue::Float(accent) = value else { panic!("accent must be a float") };
+        state.template.set_accent(index, accent);
     }
 }
 
 /// The pure block transform (host-free, so native tests drive it): warp each input record's position and
-/// clamp it into `[from, to]` (TS `clamp(groove.warp(event.position), from, to)`). Returns the count written.
+/// clamp it into `[from, to]` (TS `clamp(groove.warp(event.position), from, to)`); with a template, scale
+/// each note-on's velocity by the accent of its (straight) step. Returns the count written.
 pub fn process(state: &ZeitgeistState, from: f64, to: f64, input: &[EventRecord], out: &mut [EventRecord]) -> usize {
     let mut count = 0;
     for record in input {
@@ -131,6 +170,9 @@ pub fn process(state: &ZeitgeistState, from: f64, to: f64, input: &[EventRecord]
         let position = if warped < from { from } else if warped > to { to } else { warped };
         let mut shifted = *record;
         shifted.position = position;
+        if state.template.is_active() && record.kind == abi::EVENT_NOTE_ON {
+            shifted.velocity = (record.velocity * state.template.accent_at(record.position)).min(1.0);
+        }
         out[count] = shifted;
         count += 1;
     }
@@ -138,26 +180,26 @@ pub fn process(state: &ZeitgeistState, from: f64, to: f64, input: &[EventRecord]
 }
 
 /// What the host wires this device as (read at load): a MIDI effect (a pull source in the event chain).
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn kind() -> u32 {
     abi::DEVICE_KIND_MIDI_EFFECT
 }
 
 /// Bytes the engine must allocate (zeroed) for one instance's state block.
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn state_size(_sample_rate: f32) -> u32 {
     core::mem::size_of::<ZeitgeistState>() as u32
 }
 
-#[no_mangle]
-pub extern "C" fn init(state_ptr: u32, _sample_rate: f32) {
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn init(state_ptr: usize, _sample_rate: f32) {
     seed(unsafe { &mut *(state_ptr as *mut ZeitgeistState) });
 }
 
 /// Apply an observed groove field's value, by the id `observe_field` returned. Driven by the engine's
 /// catch-up + subscription (inside a transaction, never during render).
-#[no_mangle]
-pub extern "C" fn field_changed(state_ptr: u32, id: u32, kind: u32, bits: u32, len: u32) {
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn field_changed(state_ptr: usize, id: u32, kind: u32, bits: usize, len: u32) {
     let state = unsafe { &mut *(state_ptr as *mut ZeitgeistState) };
     apply_field(state, id, unsafe { FieldValue::from_wire(kind, bits, len) });
 }
@@ -167,7 +209,7 @@ pub extern "C" fn field_changed(state_ptr: u32, id: u32, kind: u32, bits: u32, l
 /// (`amount` unipolar, `duration` over `DurationPPQNs`); the parameters live behind the device's `groove`
 /// pointer and the studio disables automation on them, so they are field observations, not `bind_parameter`
 /// bindings (see the module docs).
-#[no_mangle]
+#[cfg_attr(target_family = "wasm", no_mangle)]
 pub extern "C" fn map_parameter(id: u32, unit: f32) -> f32 {
     let value = ParamValue::Unit(unit);
     match id {
@@ -177,8 +219,8 @@ pub extern "C" fn map_parameter(id: u32, unit: f32) -> f32 {
     }
 }
 
-#[no_mangle]
-pub extern "C" fn process_events(from: f64, to: f64, flags: u32, state_ptr: u32, out_ptr: u32, max: u32) -> u32 {
+#[cfg_attr(target_family = "wasm", no_mangle)]
+pub extern "C" fn process_events(from: f64, to: f64, flags: u32, state_ptr: usize, out_ptr: usize, max: u32) -> u32 {
     let state = unsafe { &*(state_ptr as *const ZeitgeistState) };
     let blank = EventRecord {position: 0.0, offset: 0, kind: 0, id: 0, pitch: 0, velocity: 0.0, cent: 0.0, duration: 0.0};
     let mut scratch = [blank; PULL_SCRATCH];
@@ -193,7 +235,9 @@ mod tests {
     use super::*;
 
     fn state(amount: f64, duration: f64) -> ZeitgeistState {
-        ZeitgeistState {h: squash_unit(amount), duration, amount_id: 7, duration_id: 8}
+        ZeitgeistState {h: squash_unit(amount), duration, template: GrooveTemplate::default(), amount_id: 7, duration_id: 8,
+            steps_id: 9, step_id: 10, offset_ids: core::array::from_fn(|i| 11 + i as u32),
+            accent_ids: core::array::from_fn(|i| 11 + (MAX_STEPS + i) as u32)}
     }
 
     // The box amount whose squash equals the device's PREVIOUS hardcoded `h = 0.65`.
@@ -275,4 +319,23 @@ mod tests {
             assert!((record.position - expected).abs() < 1.0e-6, "the off-beats swing exactly like the previous hardcode");
         }
     }
+
+    #[test]
+    fn a_delivered_template_replaces_the_shuffle_and_accents_the_notes() {
+        let mut state = state(legacy_amount(), 480.0);
+        apply_field(&mut state, 9, FieldValue::Int(2));
+        apply_field(&mut state, 10, FieldValue::Int(240));
+        apply_field(&mut state, 12, FieldValue::Float(0.25)); // step 1 a quarter step late
+        apply_field(&mut state, 11 + MAX_STEPS as u32, FieldValue::Float(1.5)); // step 0 accented
+        apply_field(&mut state, 12 + MAX_STEPS as u32, FieldValue::Float(0.5)); // step 1 ducked
+        let input = [note_on(0.0), note_on(240.0), note_on(480.0), note_on(720.0)];
+        let blank = EventRecord {position: 0.0, offset: 0, kind: 0, id: 0, pitch: 0, velocity: 0.0, cent: 0.0, duration: 0.0};
+        let mut out = [blank; 8];
+        let written = process(&state, 0.0, 960.0, &input, &mut out);
+        let grooved: Vec<(f64, f32)> = out[..written].iter().map(|record| (record.position, record.velocity)).collect();
+        assert_eq!(grooved, [(0.0, 1.0), (300.0, 0.4), (480.0, 1.0), (780.0, 0.4)], "velocities clip at 1.0");
+        assert!((unwarp(&state, 300.0) - 240.0).abs() < 1.0e-9, "the pull range unwarps through the template");
+        apply_field(&mut state, 9, FieldValue::Int(0));
+        assert!((warp(&state, 240.0) - 312.0).abs() < 1.0e-6, "clearing the steps restores the shuffle");
+    }
 }
@@ -0,0 +1,196 @@
+//! Groove TEMPLATES: a groove measured from a performance instead of dialled in. A template is a cycle of
+//! `steps` grid steps, each `step` pulses long, carrying a timing offset (a fraction of a step, positive =
+//! late) and a velocity accent (a scale, `1.0` = as played). The studio MEASURES it (TS
+//! `GrooveTemplate.extract`, from a note region's note-ons or an audio region's transient markers) and writes
+//! it into the `GrooveShuffleBox` template fields; the device only APPLIES it, through the same `warp` /
+//! `unwarp` pair as the Moebius shuffle: the warp is piecewise linear between the shifted step anchors, so it
+//! stays a monotone bijection and the pull over the un-warped range keeps working.
+//!
+//! Heap-free and fixed-capacity (the template lives in the device's zeroed state); a zeroed template has no
+//! steps and is inactive.
+
+/// The most steps a template holds (the `GrooveShuffleBox` template array length).
+pub const MAX_STEPS: usize = 16;
+/// The largest offset a step may carry, as a fraction of a step: adjacent anchors stay at least a tenth of
+/// a step apart, so the warp never folds over.
+pub const MAX_OFFSET: f64 = 0.45;
+/// The accent range (a velocity scale).
+pub const MAX_ACCENT: f32 = 2.0;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GrooveTemplate {
+    steps: usize,
+    step: f64,
+    offsets: [f64; MAX_STEPS],
+    accents: [f32; MAX_STEPS]
+}
+
+impl Default for GrooveTemplate {
+    fn default() -> Self {
+        Self {steps: 0, step: 240.0, offsets: [0.0; MAX_STEPS], accents: [1.0; MAX_STEPS]}
+    }
+}
+
+impl GrooveTemplate {
+    /// An empty (straight, unaccented) template of `steps` steps, `step` pulses each.
+    pub fn new(steps: usize, step: f64) -> Self {
+        let mut template = Self::default();
+        template.set_steps(steps);
+        template.set_step(step);
+        template
+    }
+
+    pub fn steps(&self) -> usize {
+        self.steps
+    }
+
+    /// Whether the template shapes anything (a template without steps leaves the stream alone).
+    pub fn is_active(&self) -> bool {
+        self.steps > 0
+    }
+
+    pub fn offset(&self, index: usize) -> f64 {
+        self.offsets[index]
+    }
+
+    pub fn accent(&self, index: usize) -> f32 {
+        self.accents[index]
+    }
+
+    pub fn set_steps(&mut self, steps: usize) {
+        self.steps = steps.min(MAX_STEPS);
+    }
+
+    /// A non-positive step length is ignored (the step divides).
+    pub fn set_step(&mut self, step: f64) {
+        if step > 0.0 {
+            self.step = step;
+        }
+    }
+
+    pub fn set_offset(&mut self, index: usize, offset: f64) {
+        self.offsets[index] = offset.clamp(-MAX_OFFSET, MAX_OFFSET);
+    }
+
+    pub fn set_accent(&mut self, index: usize, accent: f32) {
+        self.accents[index] = accent.clamp(0.0, MAX_ACCENT);
+    }
+
+    /// Straight -> grooved: the position moves with the anchors of the step it lies in. Only meaningful for
+    /// an [active](Self::is_active) template.
+    pub fn warp(&self, position: f64) -> f64 {
+        let cycle = self.cycle();
+        let start = floor(position / cycle) * cycle;
+        let x = (position - start) / self.step;
+        let index = (x as usize).min(self.steps - 1);
+        let (a, b) = (self.anchor(index), self.anchor(index + 1));
+        start + a + (b - a) * (x - index as f64)
+    }
+
+    /// Grooved -> straight, the inverse of [`warp`](Self::warp).
+    pub fn unwarp(&self, position: f64) -> f64 {
+        let cycle = self.cycle();
+        let first = self.anchor(0);
+        let start = floor((position - first) / cycle) * cycle;
+        let local = position - start;
+        let mut index = 0;
+        while index + 1 < self.steps && self.anchor(index + 1) <= local {
+            index += 1;
+        }
+        let (a, b) = (self.anchor(index), self.anchor(index + 1));
+        start + (index as f64 + (local - a) / (b - a)) * self.step
+    }
+
+    /// The accent of the grid step nearest the STRAIGHT `position`.
+    pub fn accent_at(&self, position: f64) -> f32 {
+        let slot = modulo(round(position / self.step), self.steps as f64) as usize;
+        self.accents[slot.min(self.steps - 1)]
+    }
+
+    fn cycle(&self) -> f64 {
+        self.steps as f64 * self.step
+    }
+
+    // Where grid step `index` lands within the cycle; `steps` is the next cycle's first step.
+    fn anchor(&self, index: usize) -> f64 {
+        let offset = self.offsets[index % self.steps];
+        (index as f64 + offset) * self.step
+    }
+}
+
+// `core` has no float rounding; the pulse positions here stay far inside the i64 range.
+fn floor(x: f64) -> f64 {
+    let truncated = x as i64 as f64;
+    if truncated > x { truncated - 1.0 } else { truncated }
+}
+
+fn round(x: f64) -> f64 {
+    floor(x + 0.5)
+}
+
+fn modulo(x: f64, n: f64) -> f64 {
+    x - floor(x / n) * n
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // a 16th grid, every second 16th a third of a step late and softer (mean velocity 0.6): a swung hat line
+    fn swung() -> GrooveTemplate {
+        let mut template = GrooveTemplate::new(2, 240.0);
+        template.set_offset(1, 1.0 / 3.0);
+        template.set_accent(0, 0.8 / 0.6);
+        template.set_accent(1, 0.4 / 0.6);
+        template
+    }
+
+    #[test]
+    fn offsets_and_accents_clamp_into_range() {
+        let mut template = GrooveTemplate::new(32, 240.0);
+        assert_eq!(template.steps(), MAX_STEPS);
+        template.set_offset(0, 0.5);
+        template.set_offset(1, -0.5);
+        template.set_accent(0, 3.0);
+        template.set_accent(1, -1.0);
+        assert_eq!((template.offset(0), template.offset(1)), (MAX_OFFSET, -MAX_OFFSET));
+        assert_eq!((template.accent(0), template.accent(1)), (MAX_ACCENT, 0.0));
+    }
+
+    #[test]
+    fn the_warp_moves_each_step_to_its_measured_spot() {
+        let template = swung();
+        for (straight, grooved) in [(0.0, 0.0), (240.0, 320.0), (480.0, 480.0), (720.0, 800.0), (3840.0, 3840.0)] {
+            assert!((template.warp(straight) - grooved).abs() < 1.0e-9, "{straight} -> {}", template.warp(straight));
+        }
+        // halfway between the downbeat and the late off-beat
+        assert!((template.warp(120.0) - 160.0).abs() < 1.0e-9);
+    }
+
+    #[test]
+    fn unwarp_inverts_warp_even_with_an_early_first_step() {
+        let mut template = GrooveTemplate::new(4, 240.0);
+        for (index, offset) in [-0.2, 0.3, -0.45, 0.1].into_iter().enumerate() {
+            template.set_offset(index, offset);
+        }
+        for position in [0.0, 10.0, 100.0, 240.0, 500.0, 959.0, 960.0, 1000.0, 5000.5] {
+            let warped = template.warp(position);
+            assert!((template.unwarp(warped) - position).abs() < 1.0e-6, "{position} -> {warped}");
+        }
+        let mut previous = template.warp(0.0);
+        for step in 1..2000 {
+            let next = template.warp(step as f64);
+            assert!(next > previous, "monotone");
+            previous = next;
+        }
+    }
+
+    #[test]
+    fn accents_follow_the_nearest_straight_step() {
+        let template = swung();
+        assert_eq!(template.accent_at(0.0), template.accent(0));
+        assert_eq!(template.accent_at(230.0), template.accent(1));
+        assert_eq!(template.accent_at(470.0), template.accent(0));
+    }
+
+}
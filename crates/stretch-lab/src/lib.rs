@@ -6,7 +6,7 @@
 
 extern crate alloc;
 
-pub mod wav;
+pub use audio_metrics::wav;
 pub mod baseline;
 pub mod render;
 pub mod corpus;
@@ -2,14 +2,14 @@
 //! metrics apply depends on the corpus class. The judges are judged first: `tests/metrics_selftest.rs`
 //! calibrates each one on signals with known answers before any engine is measured.
 
-pub mod envelope;
-pub mod goertzel;
 pub mod attack;
 pub mod modulation;
-pub mod spectral;
 pub mod annotate;
 pub mod spurious;
 
+// The signal-level measurements live in `audio-metrics`, shared with the device golden renders.
+pub use audio_metrics::{envelope, goertzel, spectral};
+
 use crate::corpus::{Class, Entry};
 use crate::render::RenderSpec;
 
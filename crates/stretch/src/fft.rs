@@ -1,8 +1,9 @@
 //! A general iterative radix-2 complex FFT (runtime power-of-two size), the crate's one transform:
-//! onset STFT now, phase-vocoder blocks later. Homebrew (no rustfft — dependency policy); twiddles are
-//! computed once in f64 via libm and stored f32. `analyser.rs` in `dsp` is fixed-1024/private and not
-//! reusable, hence this generalized sibling.
+//! onset STFT now, phase-vocoder blocks later. Homebrew (no rustfft — dependency policy). The table fill
+//! and the butterfly pass are `dsp::fft`'s (shared with the heap-free `dsp::fft::FixedFft` the devices
+//! use); this owns runtime-sized `Vec` tables around them.
 
+use alloc::vec;
 use alloc::vec::Vec;
 
 pub struct Fft {
@@ -15,19 +16,10 @@ pub struct Fft {
 impl Fft {
     pub fn new(size: usize) -> Self {
         assert!(size.is_power_of_two() && size >= 2, "fft size must be a power of two >= 2");
-        let levels = size.trailing_zeros();
-        let mut bit_rev = Vec::with_capacity(size);
-        for index in 0..size as u32 {
-            bit_rev.push(index.reverse_bits() >> (32 - levels));
-        }
-        let half = size / 2;
-        let mut cos_table = Vec::with_capacity(half);
-        let mut sin_table = Vec::with_capacity(half);
-        for index in 0..half {
-            let angle = -2.0 * core::f64::consts::PI * index as f64 / size as f64;
-            cos_table.push(libm::cos(angle) as f32);
-            sin_table.push(libm::sin(angle) as f32);
-        }
+        let mut bit_rev = vec![0; size];
+        let mut cos_table = vec![0.0; size / 2];
+        let mut sin_table = vec![0.0; size / 2];
+        dsp::fft::fill_tables(&mut bit_rev, &mut cos_table, &mut sin_table);
         Self {size, bit_rev, cos_table, sin_table}
     }
 
@@ -37,12 +29,12 @@ impl Fft {
 
     /// In-place forward DFT (DIT). `re`/`im` must be `size` long.
     pub fn forward(&self, re: &mut [f32], im: &mut [f32]) {
-        self.transform(re, im, false);
+        dsp::fft::transform(re, im, &self.bit_rev, &self.cos_table, &self.sin_table, false);
     }
 
     /// In-place inverse DFT, scaled by 1/N so `inverse(forward(x)) == x`.
     pub fn inverse(&self, re: &mut [f32], im: &mut [f32]) {
-        self.transform(re, im, true);
+        dsp::fft::transform(re, im, &self.bit_rev, &self.cos_table, &self.sin_table, true);
         let scale = 1.0 / self.size as f32;
         for value in re.iter_mut() {
             *value *= scale;
@@ -51,37 +43,4 @@ impl Fft {
             *value *= scale;
         }
     }
-
-    fn transform(&self, re: &mut [f32], im: &mut [f32], inverse: bool) {
-        let size = self.size;
-        assert!(re.len() == size && im.len() == size, "buffer length must equal fft size");
-        for index in 0..size {
-            let swap = self.bit_rev[index] as usize;
-            if swap > index {
-                re.swap(index, swap);
-                im.swap(index, swap);
-            }
-        }
-        let mut half_block = 1;
-        while half_block < size {
-            let block = half_block * 2;
-            let stride = size / block;
-            for start in (0..size).step_by(block) {
-                let mut twiddle = 0;
-                for even in start..start + half_block {
-                    let odd = even + half_block;
-                    let cos = self.cos_table[twiddle];
-                    let sin = if inverse { -self.sin_table[twiddle] } else { self.sin_table[twiddle] };
-                    let odd_re = re[odd] * cos - im[odd] * sin;
-                    let odd_im = re[odd] * sin + im[odd] * cos;
-                    re[odd] = re[even] - odd_re;
-                    im[odd] = im[even] - odd_im;
-                    re[even] += odd_re;
-                    im[even] += odd_im;
-                    twiddle += stride;
-                }
-            }
-            half_block = block;
-        }
-    }
 }
@@ -8,4 +8,4 @@ extern crate alloc;
 
 mod registry;
 
-pub use registry::registry;
+pub use registry::{names, registry, rules};
@@ -1,12 +1,15 @@
 // @generated by box-forge — do not edit.
 // Regenerate via: npm run build -w @opendaw/studio-forge-boxes
 //
-// The openDAW box-schema registry (name -> field schema) consumed by the `boxgraph` reader.
+// The openDAW box-schema registry (name -> field schema) consumed by the `boxgraph` reader, and the
+// pointer rules (name -> pointer contract) checked by `BoxGraph::validate`, and the field names
+// (name -> field names) used by `BoxGraph::to_json` / `from_json`.
 
 use alloc::boxed::Box;
 use alloc::string::ToString;
-use boxgraph::boxes::Registry;
+use boxgraph::boxes::{FieldName, Names, Registry};
 use boxgraph::field::{FieldType, Schema};
+use boxgraph::rules::{BoxRules, Index, Pointer, Rules, Target};
 
 pub fn registry() -> Registry {
     Registry::from([
@@ -22,7 +25,8 @@ pub fn registry() -> Registry {
         ("TrackBox".to_string(), Schema::from([(1u16, FieldType::Pointer), (2u16, FieldType::Pointer), (3u16, FieldType::Hook), (4u16, FieldType::Hook), (10u16, FieldType::Int32), (11u16, FieldType::Int32), (20u16, FieldType::Boolean), (30u16, FieldType::Boolean)])),
         ("NoteEventBox".to_string(), Schema::from([(1u16, FieldType::Pointer), (10u16, FieldType::Int32), (11u16, FieldType::Int32), (20u16, FieldType::Int32), (21u16, FieldType::Float32), (22u16, FieldType::Int32), (23u16, FieldType::Float32), (24u16, FieldType::Float32), (25u16, FieldType::Int32)])),
         ("NoteEventRepeatBox".to_string(), Schema::from([(1u16, FieldType::Pointer), (2u16, FieldType::Int32), (3u16, FieldType::Float32), (4u16, FieldType::Float32)])),
-        ("NoteEventCollectionBox".to_string(), Schema::from([(1u16, FieldType::Hook), (2u16, FieldType::Hook)])),
+        ("NoteEventCollectionBox".to_string(), Schema::from([(1u16, FieldType::Hook), (2u16, FieldType::Hook), (3u16, FieldType::Hook)])),
+        ("NoteControlEventBox".to_string(), Schema::from([(1u16, FieldType::Pointer), (10u16, FieldType::Int32), (11u16, FieldType::Int32), (12u16, FieldType::Int32), (13u16, FieldType::Float32)])),
         ("NoteRegionBox".to_string(), Schema::from([(1u16, FieldType::Pointer), (2u16, FieldType::Pointer), (10u16, FieldType::Int32), (11u16, FieldType::Int32), (12u16, FieldType::Int32), (13u16, FieldType::Int32), (14u16, FieldType::Int32), (15u16, FieldType::Boolean), (16u16, FieldType::String), (17u16, FieldType::Int32)])),
         ("NoteClipBox".to_string(), Schema::from([(1u16, FieldType::Pointer), (2u16, FieldType::Pointer), (3u16, FieldType::Int32), (4u16, FieldType::Object(Schema::from([(1u16, FieldType::Boolean), (2u16, FieldType::Boolean), (4u16, FieldType::Int32), (5u16, FieldType::Int32), (6u16, FieldType::Int32)]))), (10u16, FieldType::Int32), (11u16, FieldType::Boolean), (12u16, FieldType::String), (13u16, FieldType::Int32)])),
         ("ValueEventBox".to_string(), Schema::from([(1u16, FieldType::Pointer), (10u16, FieldType::Int32), (11u16, FieldType::Int32), (12u16, FieldType::Int32), (13u16, FieldType::Float32)])),
@@ -31,8 +35,8 @@ pub fn registry() -> Registry {
         ("ValueRegionBox".to_string(), Schema::from([(1u16, FieldType::Pointer), (2u16, FieldType::Pointer), (10u16, FieldType::Int32), (11u16, FieldType::Int32), (12u16, FieldType::Int32), (13u16, FieldType::Int32), (14u16, FieldType::Boolean), (15u16, FieldType::String), (16u16, FieldType::Int32)])),
         ("ValueClipBox".to_string(), Schema::from([(1u16, FieldType::Pointer), (2u16, FieldType::Pointer), (3u16, FieldType::Int32), (4u16, FieldType::Object(Schema::from([(1u16, FieldType::Boolean), (2u16, FieldType::Boolean), (4u16, FieldType::Int32), (5u16, FieldType::Int32), (6u16, FieldType::Int32)]))), (10u16, FieldType::Int32), (11u16, FieldType::Boolean), (12u16, FieldType::String), (13u16, FieldType::Int32)])),
         ("SignatureEventBox".to_string(), Schema::from([(1u16, FieldType::Pointer), (9u16, FieldType::Int32), (10u16, FieldType::Int32), (21u16, FieldType::Int32), (22u16, FieldType::Int32)])),
-        ("AudioRegionBox".to_string(), Schema::from([(1u16, FieldType::Pointer), (2u16, FieldType::Pointer), (4u16, FieldType::String), (5u16, FieldType::Pointer), (7u16, FieldType::Float32), (8u16, FieldType::Pointer), (10u16, FieldType::Int32), (11u16, FieldType::Float32), (12u16, FieldType::Float32), (13u16, FieldType::Float32), (14u16, FieldType::Boolean), (15u16, FieldType::String), (16u16, FieldType::Int32), (17u16, FieldType::Float32), (18u16, FieldType::Object(Schema::from([(1u16, FieldType::Float32), (2u16, FieldType::Float32), (3u16, FieldType::Float32), (4u16, FieldType::Float32)])))])),
-        ("AudioClipBox".to_string(), Schema::from([(1u16, FieldType::Pointer), (2u16, FieldType::Pointer), (3u16, FieldType::Int32), (4u16, FieldType::Object(Schema::from([(1u16, FieldType::Boolean), (2u16, FieldType::Boolean), (4u16, FieldType::Int32), (5u16, FieldType::Int32), (6u16, FieldType::Int32)]))), (5u16, FieldType::Pointer), (7u16, FieldType::Float32), (8u16, FieldType::Pointer), (10u16, FieldType::Float32), (11u16, FieldType::Boolean), (12u16, FieldType::String), (13u16, FieldType::Int32), (14u16, FieldType::Float32), (21u16, FieldType::String)])),
+        ("AudioRegionBox".to_string(), Schema::from([(1u16, FieldType::Pointer), (2u16, FieldType::Pointer), (4u16, FieldType::String), (5u16, FieldType::Pointer), (7u16, FieldType::Float32), (8u16, FieldType::Pointer), (10u16, FieldType::Int32), (11u16, FieldType::Float32), (12u16, FieldType::Float32), (13u16, FieldType::Float32), (14u16, FieldType::Boolean), (15u16, FieldType::String), (16u16, FieldType::Int32), (17u16, FieldType::Float32), (18u16, FieldType::Object(Schema::from([(1u16, FieldType::Float32), (2u16, FieldType::Float32), (3u16, FieldType::Float32), (4u16, FieldType::Float32)]))), (19u16, FieldType::Int32)])),
+        ("AudioClipBox".to_string(), Schema::from([(1u16, FieldType::Pointer), (2u16, FieldType::Pointer), (3u16, FieldType::Int32), (4u16, FieldType::Object(Schema::from([(1u16, FieldType::Boolean), (2u16, FieldType::Boolean), (4u16, FieldType::Int32), (5u16, FieldType::Int32), (6u16, FieldType::Int32)]))), (5u16, FieldType::Pointer), (7u16, FieldType::Float32), (8u16, FieldType::Pointer), (10u16, FieldType::Float32), (11u16, FieldType::Boolean), (12u16, FieldType::String), (13u16, FieldType::Int32), (14u16, FieldType::Float32), (15u16, FieldType::Int32), (21u16, FieldType::String)])),
         ("AudioPitchStretchBox".to_string(), Schema::from([(1u16, FieldType::Hook)])),
         ("AudioTimeStretchBox".to_string(), Schema::from([(1u16, FieldType::Hook), (2u16, FieldType::Int32), (3u16, FieldType::Float32)])),
         ("AudioSignalsmithBox".to_string(), Schema::from([(1u16, FieldType::Hook), (2u16, FieldType::Float32)])),
@@ -47,7 +51,7 @@ pub fn registry() -> Registry {
         ("CaptureMidiBox".to_string(), Schema::from([(1u16, FieldType::String), (2u16, FieldType::String), (10u16, FieldType::Int32)])),
         ("AudioBusBox".to_string(), Schema::from([(1u16, FieldType::Pointer), (2u16, FieldType::Pointer), (3u16, FieldType::Hook), (4u16, FieldType::Boolean), (5u16, FieldType::String), (6u16, FieldType::String), (7u16, FieldType::String), (8u16, FieldType::Boolean)])),
         ("AuxSendBox".to_string(), Schema::from([(1u16, FieldType::Pointer), (2u16, FieldType::Pointer), (3u16, FieldType::Int32), (4u16, FieldType::Int32), (5u16, FieldType::Float32), (6u16, FieldType::Float32)])),
-        ("GrooveShuffleBox".to_string(), Schema::from([(1u16, FieldType::String), (10u16, FieldType::Float32), (11u16, FieldType::Int32)])),
+        ("GrooveShuffleBox".to_string(), Schema::from([(1u16, FieldType::String), (10u16, FieldType::Float32), (11u16, FieldType::Int32), (12u16, FieldType::Int32), (13u16, FieldType::Int32), (14u16, FieldType::Array {element: Box::new(FieldType::Object(Schema::from([(1u16, FieldType::Float32), (2u16, FieldType::Float32)]))), length: 16})])),
         ("UnknownAudioEffectDeviceBox".to_string(), Schema::from([(1u16, FieldType::Pointer), (2u16, FieldType::Int32), (3u16, FieldType::String), (4u16, FieldType::Boolean), (5u16, FieldType::Boolean), (10u16, FieldType::String)])),
         ("UnknownMidiEffectDeviceBox".to_string(), Schema::from([(1u16, FieldType::Pointer), (2u16, FieldType::Int32), (3u16, FieldType::String), (4u16, FieldType::Boolean), (5u16, FieldType::Boolean), (10u16, FieldType::String)])),
         ("DeviceInterfaceKnobBox".to_string(), Schema::from([(1u16, FieldType::Pointer), (2u16, FieldType::Pointer), (3u16, FieldType::Int32), (10u16, FieldType::Float32), (11u16, FieldType::String)])),
@@ -55,27 +59,35 @@ pub fn registry() -> Registry {
         ("StereoToolDeviceBox".to_string(), Schema::from([(1u16, FieldType::Pointer), (2u16, FieldType::Int32), (3u16, FieldType::String), (4u16, FieldType::Boolean), (5u16, FieldType::Boolean), (10u16, FieldType::Float32), (11u16, FieldType::Float32), (12u16, FieldType::Float32), (13u16, FieldType::Boolean), (14u16, FieldType::Boolean), (15u16, FieldType::Boolean), (20u16, FieldType::Int32)])),
         ("MaximizerDeviceBox".to_string(), Schema::from([(1u16, FieldType::Pointer), (2u16, FieldType::Int32), (3u16, FieldType::String), (4u16, FieldType::Boolean), (5u16, FieldType::Boolean), (10u16, FieldType::Boolean), (11u16, FieldType::Float32)])),
         ("CompressorDeviceBox".to_string(), Schema::from([(1u16, FieldType::Pointer), (2u16, FieldType::Int32), (3u16, FieldType::String), (4u16, FieldType::Boolean), (5u16, FieldType::Boolean), (10u16, FieldType::Boolean), (11u16, FieldType::Boolean), (12u16, FieldType::Boolean), (13u16, FieldType::Boolean), (14u16, FieldType::Float32), (15u16, FieldType::Float32), (16u16, FieldType::Float32), (17u16, FieldType::Float32), (18u16, FieldType::Float32), (19u16, FieldType::Float32), (20u16, FieldType::Float32), (21u16, FieldType::Float32), (30u16, FieldType::Pointer)])),
+        ("MultibandCompressorDeviceBox".to_string(), Schema::from([(1u16, FieldType::Pointer), (2u16, FieldType::Int32), (3u16, FieldType::String), (4u16, FieldType::Boolean), (5u16, FieldType::Boolean), (10u16, FieldType::Int32), (11u16, FieldType::Float32), (12u16, FieldType::Float32), (13u16, FieldType::Float32), (20u16, FieldType::Array {element: Box::new(FieldType::Object(Schema::from([(1u16, FieldType::Float32), (2u16, FieldType::Float32), (3u16, FieldType::Float32), (4u16, FieldType::Float32), (5u16, FieldType::Float32), (6u16, FieldType::Boolean), (7u16, FieldType::Boolean)]))), length: 4})])),
         ("GateDeviceBox".to_string(), Schema::from([(1u16, FieldType::Pointer), (2u16, FieldType::Int32), (3u16, FieldType::String), (4u16, FieldType::Boolean), (5u16, FieldType::Boolean), (10u16, FieldType::Float32), (11u16, FieldType::Float32), (12u16, FieldType::Float32), (13u16, FieldType::Float32), (14u16, FieldType::Float32), (15u16, FieldType::Float32), (16u16, FieldType::Boolean), (30u16, FieldType::Pointer)])),
+        ("FilterDeviceBox".to_string(), Schema::from([(1u16, FieldType::Pointer), (2u16, FieldType::Int32), (3u16, FieldType::String), (4u16, FieldType::Boolean), (5u16, FieldType::Boolean), (10u16, FieldType::Int32), (11u16, FieldType::Float32), (12u16, FieldType::Float32), (13u16, FieldType::Float32), (14u16, FieldType::Int32), (15u16, FieldType::Int32), (16u16, FieldType::Float32), (17u16, FieldType::Float32), (18u16, FieldType::Float32), (19u16, FieldType::Float32), (30u16, FieldType::Pointer)])),
+        ("ChorusDeviceBox".to_string(), Schema::from([(1u16, FieldType::Pointer), (2u16, FieldType::Int32), (3u16, FieldType::String), (4u16, FieldType::Boolean), (5u16, FieldType::Boolean), (10u16, FieldType::Int32), (11u16, FieldType::Boolean), (12u16, FieldType::Int32), (13u16, FieldType::Float32), (14u16, FieldType::Float32), (15u16, FieldType::Float32), (16u16, FieldType::Float32), (17u16, FieldType::Float32)])),
         ("DelayDeviceBox".to_string(), Schema::from([(1u16, FieldType::Pointer), (2u16, FieldType::Int32), (3u16, FieldType::String), (4u16, FieldType::Boolean), (5u16, FieldType::Boolean), (10u16, FieldType::Float32), (11u16, FieldType::Float32), (12u16, FieldType::Float32), (13u16, FieldType::Float32), (14u16, FieldType::Float32), (15u16, FieldType::Float32), (16u16, FieldType::Float32), (17u16, FieldType::Float32), (19u16, FieldType::Float32), (20u16, FieldType::Float32), (22u16, FieldType::Float32), (23u16, FieldType::Float32), (24u16, FieldType::Float32), (99u16, FieldType::Int32)])),
         ("AutotuneDeviceBox".to_string(), Schema::from([(1u16, FieldType::Pointer), (2u16, FieldType::Int32), (3u16, FieldType::String), (4u16, FieldType::Boolean), (5u16, FieldType::Boolean), (10u16, FieldType::Int32), (11u16, FieldType::Int32), (12u16, FieldType::Float32), (13u16, FieldType::Float32), (14u16, FieldType::Float32), (15u16, FieldType::Float32)])),
         ("CrusherDeviceBox".to_string(), Schema::from([(1u16, FieldType::Pointer), (2u16, FieldType::Int32), (3u16, FieldType::String), (4u16, FieldType::Boolean), (5u16, FieldType::Boolean), (10u16, FieldType::Float32), (11u16, FieldType::Int32), (12u16, FieldType::Float32), (13u16, FieldType::Float32)])),
         ("DattorroReverbDeviceBox".to_string(), Schema::from([(1u16, FieldType::Pointer), (2u16, FieldType::Int32), (3u16, FieldType::String), (4u16, FieldType::Boolean), (5u16, FieldType::Boolean), (10u16, FieldType::Float32), (11u16, FieldType::Float32), (12u16, FieldType::Float32), (13u16, FieldType::Float32), (14u16, FieldType::Float32), (15u16, FieldType::Float32), (16u16, FieldType::Float32), (17u16, FieldType::Float32), (18u16, FieldType::Float32), (19u16, FieldType::Float32), (20u16, FieldType::Float32), (21u16, FieldType::Float32)])),
+        ("ConvolutionReverbDeviceBox".to_string(), Schema::from([(1u16, FieldType::Pointer), (2u16, FieldType::Int32), (3u16, FieldType::String), (4u16, FieldType::Boolean), (5u16, FieldType::Boolean), (10u16, FieldType::Float32), (11u16, FieldType::Float32), (12u16, FieldType::Float32), (13u16, FieldType::Float32), (14u16, FieldType::Float32), (15u16, FieldType::Float32), (20u16, FieldType::Pointer)])),
         ("VelocityDeviceBox".to_string(), Schema::from([(1u16, FieldType::Pointer), (2u16, FieldType::Int32), (3u16, FieldType::String), (4u16, FieldType::Boolean), (5u16, FieldType::Boolean), (10u16, FieldType::Float32), (11u16, FieldType::Float32), (12u16, FieldType::Int32), (13u16, FieldType::Float32), (14u16, FieldType::Float32), (15u16, FieldType::Float32)])),
         ("FoldDeviceBox".to_string(), Schema::from([(1u16, FieldType::Pointer), (2u16, FieldType::Int32), (3u16, FieldType::String), (4u16, FieldType::Boolean), (5u16, FieldType::Boolean), (10u16, FieldType::Float32), (11u16, FieldType::Int32), (12u16, FieldType::Float32)])),
         ("TidalDeviceBox".to_string(), Schema::from([(1u16, FieldType::Pointer), (2u16, FieldType::Int32), (3u16, FieldType::String), (4u16, FieldType::Boolean), (5u16, FieldType::Boolean), (10u16, FieldType::Float32), (11u16, FieldType::Float32), (20u16, FieldType::Float32), (21u16, FieldType::Float32), (22u16, FieldType::Float32), (23u16, FieldType::Float32)])),
         ("RevampDeviceBox".to_string(), Schema::from([(1u16, FieldType::Pointer), (2u16, FieldType::Int32), (3u16, FieldType::String), (4u16, FieldType::Boolean), (5u16, FieldType::Boolean), (10u16, FieldType::Object(Schema::from([(1u16, FieldType::Boolean), (10u16, FieldType::Float32), (11u16, FieldType::Int32), (12u16, FieldType::Float32)]))), (11u16, FieldType::Object(Schema::from([(1u16, FieldType::Boolean), (10u16, FieldType::Float32), (11u16, FieldType::Float32)]))), (12u16, FieldType::Object(Schema::from([(1u16, FieldType::Boolean), (10u16, FieldType::Float32), (11u16, FieldType::Float32), (12u16, FieldType::Float32)]))), (13u16, FieldType::Object(Schema::from([(1u16, FieldType::Boolean), (10u16, FieldType::Float32), (11u16, FieldType::Float32), (12u16, FieldType::Float32)]))), (14u16, FieldType::Object(Schema::from([(1u16, FieldType::Boolean), (10u16, FieldType::Float32), (11u16, FieldType::Float32), (12u16, FieldType::Float32)]))), (15u16, FieldType::Object(Schema::from([(1u16, FieldType::Boolean), (10u16, FieldType::Float32), (11u16, FieldType::Float32)]))), (16u16, FieldType::Object(Schema::from([(1u16, FieldType::Boolean), (10u16, FieldType::Float32), (11u16, FieldType::Int32), (12u16, FieldType::Float32)])))])),
         ("ReverbDeviceBox".to_string(), Schema::from([(1u16, FieldType::Pointer), (2u16, FieldType::Int32), (3u16, FieldType::String), (4u16, FieldType::Boolean), (5u16, FieldType::Boolean), (10u16, FieldType::Float32), (11u16, FieldType::Float32), (12u16, FieldType::Float32), (13u16, FieldType::Float32), (14u16, FieldType::Float32), (15u16, FieldType::Float32)])),
-        ("VaporisateurDeviceBox".to_string(), Schema::from([(1u16, FieldType::Pointer), (2u16, FieldType::String), (3u16, FieldType::String), (4u16, FieldType::Boolean), (5u16, FieldType::Boolean), (14u16, FieldType::Float32), (15u16, FieldType::Float32), (16u16, FieldType::Float32), (17u16, FieldType::Float32), (18u16, FieldType::Float32), (19u16, FieldType::Float32), (20u16, FieldType::Float32), (21u16, FieldType::Float32), (22u16, FieldType::Int32), (23u16, FieldType::Int32), (24u16, FieldType::Float32), (25u16, FieldType::Float32), (26u16, FieldType::Int32), (27u16, FieldType::Float32), (30u16, FieldType::Object(Schema::from([(1u16, FieldType::Int32), (2u16, FieldType::Float32), (3u16, FieldType::Boolean), (10u16, FieldType::Float32), (11u16, FieldType::Float32), (12u16, FieldType::Float32)]))), (40u16, FieldType::Array {element: Box::new(FieldType::Object(Schema::from([(1u16, FieldType::Int32), (2u16, FieldType::Float32), (3u16, FieldType::Int32), (4u16, FieldType::Float32)]))), length: 2}), (50u16, FieldType::Object(Schema::from([(1u16, FieldType::Float32), (2u16, FieldType::Float32), (3u16, FieldType::Float32), (4u16, FieldType::Float32)]))), (99u16, FieldType::Int32)])),
+        ("VaporisateurDeviceBox".to_string(), Schema::from([(1u16, FieldType::Pointer), (2u16, FieldType::String), (3u16, FieldType::String), (4u16, FieldType::Boolean), (5u16, FieldType::Boolean), (14u16, FieldType::Float32), (15u16, FieldType::Float32), (16u16, FieldType::Float32), (17u16, FieldType::Float32), (18u16, FieldType::Float32), (19u16, FieldType::Float32), (20u16, FieldType::Float32), (21u16, FieldType::Float32), (22u16, FieldType::Int32), (23u16, FieldType::Int32), (24u16, FieldType::Float32), (25u16, FieldType::Float32), (26u16, FieldType::Int32), (27u16, FieldType::Float32), (30u16, FieldType::Object(Schema::from([(1u16, FieldType::Int32), (2u16, FieldType::Float32), (3u16, FieldType::Boolean), (10u16, FieldType::Float32), (11u16, FieldType::Float32), (12u16, FieldType::Float32)]))), (31u16, FieldType::Object(Schema::from([(1u16, FieldType::Int32), (2u16, FieldType::Float32)]))), (32u16, FieldType::Object(Schema::from([(1u16, FieldType::Float32), (2u16, FieldType::Float32), (3u16, FieldType::Float32), (4u16, FieldType::Float32)]))), (40u16, FieldType::Array {element: Box::new(FieldType::Object(Schema::from([(1u16, FieldType::Int32), (2u16, FieldType::Float32), (3u16, FieldType::Int32), (4u16, FieldType::Float32)]))), length: 2}), (50u16, FieldType::Object(Schema::from([(1u16, FieldType::Float32), (2u16, FieldType::Float32), (3u16, FieldType::Float32), (4u16, FieldType::Float32)]))), (60u16, FieldType::Array {element: Box::new(FieldType::Object(Schema::from([(1u16, FieldType::Int32), (2u16, FieldType::Int32), (3u16, FieldType::Float32)]))), length: 8}), (99u16, FieldType::Int32)])),
+        ("WavetableDeviceBox".to_string(), Schema::from([(1u16, FieldType::Pointer), (2u16, FieldType::String), (3u16, FieldType::String), (4u16, FieldType::Boolean), (5u16, FieldType::Boolean), (10u16, FieldType::Float32), (11u16, FieldType::Pointer), (12u16, FieldType::Float32), (13u16, FieldType::Float32), (14u16, FieldType::Int32), (15u16, FieldType::Float32), (16u16, FieldType::Float32), (17u16, FieldType::Float32), (18u16, FieldType::Float32), (19u16, FieldType::Float32), (20u16, FieldType::Float32), (21u16, FieldType::Float32), (22u16, FieldType::Float32), (23u16, FieldType::Float32), (24u16, FieldType::Int32), (25u16, FieldType::Int32), (26u16, FieldType::Float32), (27u16, FieldType::Float32)])),
+        ("FMSynthDeviceBox".to_string(), Schema::from([(1u16, FieldType::Pointer), (2u16, FieldType::String), (3u16, FieldType::String), (4u16, FieldType::Boolean), (5u16, FieldType::Boolean), (10u16, FieldType::Float32), (11u16, FieldType::Int32), (12u16, FieldType::Float32), (13u16, FieldType::Float32), (14u16, FieldType::Int32), (40u16, FieldType::Array {element: Box::new(FieldType::Object(Schema::from([(1u16, FieldType::Float32), (2u16, FieldType::Float32), (3u16, FieldType::Float32), (4u16, FieldType::Float32), (5u16, FieldType::Float32), (6u16, FieldType::Float32), (7u16, FieldType::Float32), (8u16, FieldType::Float32), (9u16, FieldType::Float32)]))), length: 4})])),
         ("MIDIOutputDeviceBox".to_string(), Schema::from([(1u16, FieldType::Pointer), (2u16, FieldType::String), (3u16, FieldType::String), (4u16, FieldType::Boolean), (5u16, FieldType::Boolean), (11u16, FieldType::Int32), (13u16, FieldType::Hook), (14u16, FieldType::Pointer)])),
         ("MIDIOutputBox".to_string(), Schema::from([(1u16, FieldType::Pointer), (2u16, FieldType::Hook), (3u16, FieldType::String), (4u16, FieldType::String), (5u16, FieldType::Int32), (6u16, FieldType::Boolean)])),
         ("MIDIOutputParameterBox".to_string(), Schema::from([(1u16, FieldType::Pointer), (2u16, FieldType::String), (3u16, FieldType::Int32), (4u16, FieldType::Float32)])),
         ("SoundfontDeviceBox".to_string(), Schema::from([(1u16, FieldType::Pointer), (2u16, FieldType::String), (3u16, FieldType::String), (4u16, FieldType::Boolean), (5u16, FieldType::Boolean), (10u16, FieldType::Pointer), (11u16, FieldType::Int32)])),
-        ("NanoDeviceBox".to_string(), Schema::from([(1u16, FieldType::Pointer), (2u16, FieldType::String), (3u16, FieldType::String), (4u16, FieldType::Boolean), (5u16, FieldType::Boolean), (10u16, FieldType::Float32), (15u16, FieldType::Pointer), (20u16, FieldType::Float32)])),
+        ("NanoDeviceBox".to_string(), Schema::from([(1u16, FieldType::Pointer), (2u16, FieldType::String), (3u16, FieldType::String), (4u16, FieldType::Boolean), (5u16, FieldType::Boolean), (10u16, FieldType::Float32), (15u16, FieldType::Pointer), (20u16, FieldType::Float32), (25u16, FieldType::Int32)])),
         ("PlayfieldDeviceBox".to_string(), Schema::from([(1u16, FieldType::Pointer), (2u16, FieldType::String), (3u16, FieldType::String), (4u16, FieldType::Boolean), (5u16, FieldType::Boolean), (10u16, FieldType::Hook)])),
-        ("PlayfieldSampleBox".to_string(), Schema::from([(10u16, FieldType::Pointer), (11u16, FieldType::Pointer), (12u16, FieldType::Hook), (13u16, FieldType::Hook), (15u16, FieldType::Int32), (21u16, FieldType::String), (22u16, FieldType::Boolean), (23u16, FieldType::Boolean), (40u16, FieldType::Boolean), (41u16, FieldType::Boolean), (42u16, FieldType::Boolean), (43u16, FieldType::Boolean), (44u16, FieldType::Int32), (45u16, FieldType::Float32), (46u16, FieldType::Float32), (47u16, FieldType::Float32), (48u16, FieldType::Float32), (49u16, FieldType::Float32)])),
+        ("PlayfieldSampleBox".to_string(), Schema::from([(10u16, FieldType::Pointer), (11u16, FieldType::Pointer), (12u16, FieldType::Hook), (13u16, FieldType::Hook), (15u16, FieldType::Int32), (21u16, FieldType::String), (22u16, FieldType::Boolean), (23u16, FieldType::Boolean), (40u16, FieldType::Boolean), (41u16, FieldType::Boolean), (42u16, FieldType::Boolean), (43u16, FieldType::Boolean), (44u16, FieldType::Int32), (45u16, FieldType::Float32), (46u16, FieldType::Float32), (47u16, FieldType::Float32), (48u16, FieldType::Float32), (49u16, FieldType::Float32), (50u16, FieldType::Int32)])),
         ("TapeDeviceBox".to_string(), Schema::from([(1u16, FieldType::Pointer), (2u16, FieldType::String), (3u16, FieldType::String), (4u16, FieldType::Boolean), (5u16, FieldType::Boolean), (10u16, FieldType::Float32), (11u16, FieldType::Float32), (12u16, FieldType::Float32), (13u16, FieldType::Float32)])),
-        ("ArpeggioDeviceBox".to_string(), Schema::from([(1u16, FieldType::Pointer), (2u16, FieldType::Int32), (3u16, FieldType::String), (4u16, FieldType::Boolean), (5u16, FieldType::Boolean), (10u16, FieldType::Int32), (11u16, FieldType::Int32), (12u16, FieldType::Int32), (13u16, FieldType::Float32), (14u16, FieldType::Int32), (15u16, FieldType::Float32)])),
+        ("ArpeggioDeviceBox".to_string(), Schema::from([(1u16, FieldType::Pointer), (2u16, FieldType::Int32), (3u16, FieldType::String), (4u16, FieldType::Boolean), (5u16, FieldType::Boolean), (10u16, FieldType::Int32), (11u16, FieldType::Int32), (12u16, FieldType::Int32), (13u16, FieldType::Float32), (14u16, FieldType::Int32), (15u16, FieldType::Float32), (16u16, FieldType::Int32), (17u16, FieldType::Array {element: Box::new(FieldType::Object(Schema::from([(1u16, FieldType::Float32), (2u16, FieldType::Float32), (3u16, FieldType::Boolean), (4u16, FieldType::Boolean)]))), length: 16})])),
         ("PitchDeviceBox".to_string(), Schema::from([(1u16, FieldType::Pointer), (2u16, FieldType::Int32), (3u16, FieldType::String), (4u16, FieldType::Boolean), (5u16, FieldType::Boolean), (10u16, FieldType::Int32), (11u16, FieldType::Float32), (12u16, FieldType::Int32)])),
+        ("ScaleDeviceBox".to_string(), Schema::from([(1u16, FieldType::Pointer), (2u16, FieldType::Int32), (3u16, FieldType::String), (4u16, FieldType::Boolean), (5u16, FieldType::Boolean), (10u16, FieldType::Int32), (11u16, FieldType::Int32), (12u16, FieldType::Int32), (13u16, FieldType::Int32), (14u16, FieldType::Int32)])),
+        ("RatchetDeviceBox".to_string(), Schema::from([(1u16, FieldType::Pointer), (2u16, FieldType::Int32), (3u16, FieldType::String), (4u16, FieldType::Boolean), (5u16, FieldType::Boolean), (10u16, FieldType::Int32), (11u16, FieldType::Float32), (12u16, FieldType::Float32), (13u16, FieldType::Int32), (14u16, FieldType::Array {element: Box::new(FieldType::Object(Schema::from([(1u16, FieldType::Int32)]))), length: 16})])),
         ("ZeitgeistDeviceBox".to_string(), Schema::from([(1u16, FieldType::Pointer), (2u16, FieldType::Int32), (3u16, FieldType::String), (4u16, FieldType::Boolean), (5u16, FieldType::Boolean), (10u16, FieldType::Pointer)])),
         ("NeuralAmpDeviceBox".to_string(), Schema::from([(1u16, FieldType::Pointer), (2u16, FieldType::Int32), (3u16, FieldType::String), (4u16, FieldType::Boolean), (5u16, FieldType::Boolean), (11u16, FieldType::Float32), (12u16, FieldType::Float32), (13u16, FieldType::Boolean), (14u16, FieldType::Float32), (20u16, FieldType::Pointer)])),
         ("VocoderDeviceBox".to_string(), Schema::from([(1u16, FieldType::Pointer), (2u16, FieldType::Int32), (3u16, FieldType::String), (4u16, FieldType::Boolean), (5u16, FieldType::Boolean), (10u16, FieldType::Float32), (11u16, FieldType::Float32), (12u16, FieldType::Float32), (13u16, FieldType::Float32), (14u16, FieldType::Float32), (15u16, FieldType::Float32), (16u16, FieldType::Float32), (17u16, FieldType::Float32), (18u16, FieldType::Int32), (19u16, FieldType::String), (20u16, FieldType::Float32), (21u16, FieldType::Float32), (30u16, FieldType::Pointer)])),
@@ -101,3 +113,209 @@ pub fn registry() -> Registry {
         ("ModuleGainBox".to_string(), Schema::from([(1u16, FieldType::Object(Schema::from([(1u16, FieldType::Pointer), (2u16, FieldType::String), (3u16, FieldType::Int32), (4u16, FieldType::Int32), (5u16, FieldType::Boolean), (6u16, FieldType::Boolean)]))), (10u16, FieldType::Hook), (12u16, FieldType::Hook), (20u16, FieldType::Float32)])),
     ])
 }
+
+pub fn rules() -> Rules {
+    Rules::from([
+        ("MetaDataBox".to_string(), BoxRules {target: Target {accepts: &[], mandatory: false, exclusive: false}, pointers: &[(&[1], Pointer {pointer_type: "MetaData", mandatory: true})], targets: &[], index: None}),
+        ("ProjectMetaBox".to_string(), BoxRules {target: Target {accepts: &["ProjectMeta"], mandatory: true, exclusive: false}, pointers: &[], targets: &[], index: None}),
+        ("RootBox".to_string(), BoxRules {target: Target {accepts: &["MetaData"], mandatory: false, exclusive: false}, pointers: &[(&[1], Pointer {pointer_type: "Timeline", mandatory: true}), (&[4], Pointer {pointer_type: "Groove", mandatory: true}), (&[100], Pointer {pointer_type: "Shadertoy", mandatory: false}), (&[101], Pointer {pointer_type: "ProjectMeta", mandatory: false}), (&[111], Pointer {pointer_type: "Editing", mandatory: false})], targets: &[(&[2], Target {accepts: &["User"], mandatory: false, exclusive: false}), (&[10], Target {accepts: &["ModularSetup"], mandatory: false, exclusive: false}), (&[20], Target {accepts: &["AudioUnits"], mandatory: false, exclusive: false}), (&[21], Target {accepts: &["AudioBusses"], mandatory: false, exclusive: false}), (&[30], Target {accepts: &["AudioOutput"], mandatory: true, exclusive: false}), (&[35], Target {accepts: &["MIDIDevice"], mandatory: false, exclusive: false})], index: None}),
+        ("SelectionBox".to_string(), BoxRules {target: Target {accepts: &[], mandatory: false, exclusive: false}, pointers: &[(&[1], Pointer {pointer_type: "Selection", mandatory: true}), (&[2], Pointer {pointer_type: "Selection", mandatory: true})], targets: &[], index: None}),
+        ("UserInterfaceBox".to_string(), BoxRules {target: Target {accepts: &["MetaData"], mandatory: false, exclusive: false}, pointers: &[(&[1], Pointer {pointer_type: "User", mandatory: true}), (&[21], Pointer {pointer_type: "Editing", mandatory: false}), (&[22], Pointer {pointer_type: "Editing", mandatory: false}), (&[23], Pointer {pointer_type: "Editing", mandatory: false})], targets: &[(&[10], Target {accepts: &["Selection"], mandatory: false, exclusive: false}), (&[11], Target {accepts: &["FileUploadState"], mandatory: false, exclusive: false}), (&[30], Target {accepts: &["MIDIControllers"], mandatory: false, exclusive: false})], index: None}),
+        ("UploadFileBox".to_string(), BoxRules {target: Target {accepts: &[], mandatory: false, exclusive: false}, pointers: &[(&[1], Pointer {pointer_type: "FileUploadState", mandatory: true}), (&[2], Pointer {pointer_type: "FileUploadState", mandatory: true})], targets: &[], index: None}),
+        ("ShadertoyBox".to_string(), BoxRules {target: Target {accepts: &["Shadertoy"], mandatory: true, exclusive: false}, pointers: &[], targets: &[], index: None}),
+        ("MIDIControllerBox".to_string(), BoxRules {target: Target {accepts: &[], mandatory: false, exclusive: false}, pointers: &[(&[1], Pointer {pointer_type: "MIDIControllers", mandatory: true}), (&[2], Pointer {pointer_type: "MIDIControl", mandatory: true})], targets: &[], index: None}),
+        ("TimelineBox".to_string(), BoxRules {target: Target {accepts: &["MetaData"], mandatory: false, exclusive: false}, pointers: &[(&[22, 1], Pointer {pointer_type: "ValueEventCollection", mandatory: false})], targets: &[(&[1], Target {accepts: &["Timeline"], mandatory: true, exclusive: false}), (&[21, 1], Target {accepts: &["MarkerTrack"], mandatory: false, exclusive: false}), (&[23, 1], Target {accepts: &["SignatureAutomation"], mandatory: false, exclusive: false})], index: None}),
+        ("TrackBox".to_string(), BoxRules {target: Target {accepts: &["Selection", "PianoMode", "MetaData"], mandatory: false, exclusive: false}, pointers: &[(&[1], Pointer {pointer_type: "TrackCollection", mandatory: true}), (&[2], Pointer {pointer_type: "Automation", mandatory: true})], targets: &[(&[3], Target {accepts: &["RegionCollection"], mandatory: false, exclusive: false}), (&[4], Target {accepts: &["ClipCollection"], mandatory: false, exclusive: false})], index: Some(Index {field: &[10], collection: &[1]})}),
+        ("NoteEventBox".to_string(), BoxRules {target: Target {accepts: &["Selection", "NoteEventFeature"], mandatory: false, exclusive: false}, pointers: &[(&[1], Pointer {pointer_type: "NoteEvents", mandatory: true})], targets: &[], index: None}),
+        ("NoteEventRepeatBox".to_string(), BoxRules {target: Target {accepts: &[], mandatory: false, exclusive: false}, pointers: &[(&[1], Pointer {pointer_type: "NoteEventFeature", mandatory: true})], targets: &[], index: None}),
+        ("NoteEventCollectionBox".to_string(), BoxRules {target: Target {accepts: &["Selection"], mandatory: false, exclusive: false}, pointers: &[], targets: &[(&[1], Target {accepts: &["NoteEvents"], mandatory: false, exclusive: false}), (&[2], Target {accepts: &["NoteEventCollection"], mandatory: true, exclusive: false}), (&[3], Target {accepts: &["NoteControlEvents"], mandatory: false, exclusive: false})], index: None}),
+        ("NoteControlEventBox".to_string(), BoxRules {target: Target {accepts: &["Selection"], mandatory: false, exclusive: false}, pointers: &[(&[1], Pointer {pointer_type: "NoteControlEvents", mandatory: true})], targets: &[], index: None}),
+        ("NoteRegionBox".to_string(), BoxRules {target: Target {accepts: &["Selection", "Editing", "MetaData"], mandatory: false, exclusive: false}, pointers: &[(&[1], Pointer {pointer_type: "RegionCollection", mandatory: true}), (&[2], Pointer {pointer_type: "NoteEventCollection", mandatory: true})], targets: &[], index: None}),
+        ("NoteClipBox".to_string(), BoxRules {target: Target {accepts: &["Selection", "Editing", "MetaData"], mandatory: false, exclusive: false}, pointers: &[(&[1], Pointer {pointer_type: "ClipCollection", mandatory: true}), (&[2], Pointer {pointer_type: "NoteEventCollection", mandatory: true})], targets: &[], index: Some(Index {field: &[3], collection: &[1]})}),
+        ("ValueEventBox".to_string(), BoxRules {target: Target {accepts: &["Selection"], mandatory: false, exclusive: false}, pointers: &[(&[1], Pointer {pointer_type: "ValueEvents", mandatory: true})], targets: &[(&[12], Target {accepts: &["ValueInterpolation"], mandatory: false, exclusive: false})], index: None}),
+        ("ValueEventCollectionBox".to_string(), BoxRules {target: Target {accepts: &["Selection"], mandatory: false, exclusive: false}, pointers: &[], targets: &[(&[1], Target {accepts: &["ValueEvents"], mandatory: false, exclusive: false}), (&[2], Target {accepts: &["ValueEventCollection"], mandatory: true, exclusive: false})], index: None}),
+        ("ValueEventCurveBox".to_string(), BoxRules {target: Target {accepts: &[], mandatory: false, exclusive: false}, pointers: &[(&[1], Pointer {pointer_type: "ValueInterpolation", mandatory: true})], targets: &[], index: None}),
+        ("ValueRegionBox".to_string(), BoxRules {target: Target {accepts: &["Selection", "Editing", "MetaData"], mandatory: false, exclusive: false}, pointers: &[(&[1], Pointer {pointer_type: "RegionCollection", mandatory: true}), (&[2], Pointer {pointer_type: "ValueEventCollection", mandatory: true})], targets: &[], index: None}),
+        ("ValueClipBox".to_string(), BoxRules {target: Target {accepts: &["Selection", "Editing", "MetaData"], mandatory: false, exclusive: false}, pointers: &[(&[1], Pointer {pointer_type: "ClipCollection", mandatory: true}), (&[2], Pointer {pointer_type: "ValueEventCollection", mandatory: true})], targets: &[], index: Some(Index {field: &[3], collection: &[1]})}),
+        ("SignatureEventBox".to_string(), BoxRules {target: Target {accepts: &["Selection"], mandatory: false, exclusive: false}, pointers: &[(&[1], Pointer {pointer_type: "SignatureAutomation", mandatory: true})], targets: &[], index: Some(Index {field: &[9], collection: &[1]})}),
+        ("AudioRegionBox".to_string(), BoxRules {target: Target {accepts: &["Selection", "Editing", "MetaData"], mandatory: false, exclusive: false}, pointers: &[(&[1], Pointer {pointer_type: "RegionCollection", mandatory: true}), (&[2], Pointer {pointer_type: "AudioFile", mandatory: true}), (&[5], Pointer {pointer_type: "ValueEventCollection", mandatory: true}), (&[8], Pointer {pointer_type: "AudioPlayMode", mandatory: false})], targets: &[], index: None}),
+        ("AudioClipBox".to_string(), BoxRules {target: Target {accepts: &["Selection", "Editing", "MetaData"], mandatory: false, exclusive: false}, pointers: &[(&[1], Pointer {pointer_type: "ClipCollection", mandatory: true}), (&[2], Pointer {pointer_type: "AudioFile", mandatory: true}), (&[5], Pointer {pointer_type: "ValueEventCollection", mandatory: true}), (&[8], Pointer {pointer_type: "AudioPlayMode", mandatory: false})], targets: &[], index: Some(Index {field: &[3], collection: &[1]})}),
+        ("AudioPitchStretchBox".to_string(), BoxRules {target: Target {accepts: &["AudioPlayMode"], mandatory: true, exclusive: false}, pointers: &[], targets: &[(&[1], Target {accepts: &["WarpMarkers"], mandatory: true, exclusive: false})], index: None}),
+        ("AudioTimeStretchBox".to_string(), BoxRules {target: Target {accepts: &["AudioPlayMode"], mandatory: true, exclusive: false}, pointers: &[], targets: &[(&[1], Target {accepts: &["WarpMarkers"], mandatory: true, exclusive: false})], index: None}),
+        ("AudioSignalsmithBox".to_string(), BoxRules {target: Target {accepts: &["AudioPlayMode"], mandatory: true, exclusive: false}, pointers: &[], targets: &[(&[1], Target {accepts: &["WarpMarkers"], mandatory: true, exclusive: false})], index: None}),
+        ("TransientMarkerBox".to_string(), BoxRules {target: Target {accepts: &["Selection"], mandatory: false, exclusive: false}, pointers: &[(&[1], Pointer {pointer_type: "TransientMarkers", mandatory: true})], targets: &[], index: None}),
+        ("WarpMarkerBox".to_string(), BoxRules {target: Target {accepts: &["Selection"], mandatory: false, exclusive: false}, pointers: &[(&[1], Pointer {pointer_type: "WarpMarkers", mandatory: true})], targets: &[], index: None}),
+        ("MarkerBox".to_string(), BoxRules {target: Target {accepts: &["Selection", "MetaData"], mandatory: false, exclusive: false}, pointers: &[(&[1], Pointer {pointer_type: "MarkerTrack", mandatory: true})], targets: &[], index: None}),
+        ("AudioFileBox".to_string(), BoxRules {target: Target {accepts: &["AudioFile", "FileUploadState", "MetaData"], mandatory: true, exclusive: false}, pointers: &[], targets: &[(&[10], Target {accepts: &["TransientMarkers"], mandatory: false, exclusive: false})], index: None}),
+        ("SoundfontFileBox".to_string(), BoxRules {target: Target {accepts: &["SoundfontFile"], mandatory: true, exclusive: false}, pointers: &[], targets: &[], index: None}),
+        ("NeuralAmpModelBox".to_string(), BoxRules {target: Target {accepts: &["NeuralAmpModel"], mandatory: true, exclusive: false}, pointers: &[], targets: &[], index: None}),
+        ("AudioUnitBox".to_string(), BoxRules {target: Target {accepts: &["Selection", "Automation", "MetaData", "SideChain"], mandatory: false, exclusive: false}, pointers: &[(&[2], Pointer {pointer_type: "AudioUnits", mandatory: true}), (&[25], Pointer {pointer_type: "AudioOutput", mandatory: false}), (&[26], Pointer {pointer_type: "Capture", mandatory: false})], targets: &[(&[3], Target {accepts: &["Editing"], mandatory: false, exclusive: false}), (&[12], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[13], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[14], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[15], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[20], Target {accepts: &["TrackCollection"], mandatory: false, exclusive: false}), (&[21], Target {accepts: &["MIDIEffectHost"], mandatory: false, exclusive: false}), (&[22], Target {accepts: &["InstrumentHost", "AudioOutput"], mandatory: false, exclusive: true}), (&[23], Target {accepts: &["AudioEffectHost"], mandatory: false, exclusive: false}), (&[24], Target {accepts: &["AuxSend"], mandatory: false, exclusive: false})], index: Some(Index {field: &[11], collection: &[2]})}),
+        ("CaptureAudioBox".to_string(), BoxRules {target: Target {accepts: &["Capture"], mandatory: true, exclusive: false}, pointers: &[], targets: &[], index: None}),
+        ("CaptureMidiBox".to_string(), BoxRules {target: Target {accepts: &["Capture"], mandatory: true, exclusive: false}, pointers: &[], targets: &[], index: None}),
+        ("AudioBusBox".to_string(), BoxRules {target: Target {accepts: &["SideChain", "Selection"], mandatory: false, exclusive: false}, pointers: &[(&[1], Pointer {pointer_type: "AudioBusses", mandatory: true}), (&[2], Pointer {pointer_type: "AudioOutput", mandatory: true})], targets: &[(&[3], Target {accepts: &["AudioOutput"], mandatory: false, exclusive: false})], index: None}),
+        ("AuxSendBox".to_string(), BoxRules {target: Target {accepts: &["SideChain"], mandatory: false, exclusive: false}, pointers: &[(&[1], Pointer {pointer_type: "AuxSend", mandatory: true}), (&[2], Pointer {pointer_type: "AudioOutput", mandatory: true})], targets: &[(&[5], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[6], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false})], index: Some(Index {field: &[3], collection: &[1]})}),
+        ("GrooveShuffleBox".to_string(), BoxRules {target: Target {accepts: &["Groove"], mandatory: true, exclusive: false}, pointers: &[], targets: &[(&[10], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[11], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false})], index: None}),
+        ("UnknownAudioEffectDeviceBox".to_string(), BoxRules {target: Target {accepts: &["Device", "Selection", "MetaData", "SideChain"], mandatory: false, exclusive: false}, pointers: &[(&[1], Pointer {pointer_type: "AudioEffectHost", mandatory: true})], targets: &[], index: Some(Index {field: &[2], collection: &[1]})}),
+        ("UnknownMidiEffectDeviceBox".to_string(), BoxRules {target: Target {accepts: &["Device", "Selection", "MetaData"], mandatory: false, exclusive: false}, pointers: &[(&[1], Pointer {pointer_type: "MIDIEffectHost", mandatory: true})], targets: &[], index: Some(Index {field: &[2], collection: &[1]})}),
+        ("DeviceInterfaceKnobBox".to_string(), BoxRules {target: Target {accepts: &[], mandatory: false, exclusive: false}, pointers: &[(&[1], Pointer {pointer_type: "DeviceUserInterface", mandatory: true}), (&[2], Pointer {pointer_type: "ParameterController", mandatory: true})], targets: &[], index: Some(Index {field: &[3], collection: &[1]})}),
+        ("ModularDeviceBox".to_string(), BoxRules {target: Target {accepts: &["Device", "Selection", "MetaData", "SideChain"], mandatory: false, exclusive: false}, pointers: &[(&[1], Pointer {pointer_type: "AudioEffectHost", mandatory: true}), (&[10], Pointer {pointer_type: "ModularSetup", mandatory: true})], targets: &[(&[11, 1], Target {accepts: &["DeviceUserInterface"], mandatory: false, exclusive: false})], index: Some(Index {field: &[2], collection: &[1]})}),
+        ("StereoToolDeviceBox".to_string(), BoxRules {target: Target {accepts: &["Device", "Selection", "MetaData", "SideChain"], mandatory: false, exclusive: false}, pointers: &[(&[1], Pointer {pointer_type: "AudioEffectHost", mandatory: true})], targets: &[(&[10], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[11], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[12], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[13], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[14], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[15], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false})], index: Some(Index {field: &[2], collection: &[1]})}),
+        ("MaximizerDeviceBox".to_string(), BoxRules {target: Target {accepts: &["Device", "Selection", "MetaData", "SideChain"], mandatory: false, exclusive: false}, pointers: &[(&[1], Pointer {pointer_type: "AudioEffectHost", mandatory: true})], targets: &[(&[11], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false})], index: Some(Index {field: &[2], collection: &[1]})}),
+        ("CompressorDeviceBox".to_string(), BoxRules {target: Target {accepts: &["Device", "Selection", "MetaData", "SideChain"], mandatory: false, exclusive: false}, pointers: &[(&[1], Pointer {pointer_type: "AudioEffectHost", mandatory: true}), (&[30], Pointer {pointer_type: "SideChain", mandatory: false})], targets: &[(&[10], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[11], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[12], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[13], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[14], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[15], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[16], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[17], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[18], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[19], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[20], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[21], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false})], index: Some(Index {field: &[2], collection: &[1]})}),
+        ("MultibandCompressorDeviceBox".to_string(), BoxRules {target: Target {accepts: &["Device", "Selection", "MetaData", "SideChain"], mandatory: false, exclusive: false}, pointers: &[(&[1], Pointer {pointer_type: "AudioEffectHost", mandatory: true})], targets: &[(&[10], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[11], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[12], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[13], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[20, 0, 1], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[20, 0, 2], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[20, 0, 3], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[20, 0, 4], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[20, 0, 5], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[20, 0, 6], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[20, 0, 7], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[20, 1, 1], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[20, 1, 2], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[20, 1, 3], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[20, 1, 4], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[20, 1, 5], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[20, 1, 6], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[20, 1, 7], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[20, 2, 1], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[20, 2, 2], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[20, 2, 3], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[20, 2, 4], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[20, 2, 5], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[20, 2, 6], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[20, 2, 7], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[20, 3, 1], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[20, 3, 2], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[20, 3, 3], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[20, 3, 4], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[20, 3, 5], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[20, 3, 6], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[20, 3, 7], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false})], index: Some(Index {field: &[2], collection: &[1]})}),
+        ("GateDeviceBox".to_string(), BoxRules {target: Target {accepts: &["Device", "Selection", "MetaData", "SideChain"], mandatory: false, exclusive: false}, pointers: &[(&[1], Pointer {pointer_type: "AudioEffectHost", mandatory: true}), (&[30], Pointer {pointer_type: "SideChain", mandatory: false})], targets: &[(&[10], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[11], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[12], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[13], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[14], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[15], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[16], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false})], index: Some(Index {field: &[2], collection: &[1]})}),
+        ("FilterDeviceBox".to_string(), BoxRules {target: Target {accepts: &["Device", "Selection", "MetaData", "SideChain"], mandatory: false, exclusive: false}, pointers: &[(&[1], Pointer {pointer_type: "AudioEffectHost", mandatory: true}), (&[30], Pointer {pointer_type: "SideChain", mandatory: false})], targets: &[(&[10], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[11], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[12], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[13], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[14], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[15], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[16], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[17], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[18], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[19], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false})], index: Some(Index {field: &[2], collection: &[1]})}),
+        ("ChorusDeviceBox".to_string(), BoxRules {target: Target {accepts: &["Device", "Selection", "MetaData", "SideChain"], mandatory: false, exclusive: false}, pointers: &[(&[1], Pointer {pointer_type: "AudioEffectHost", mandatory: true})], targets: &[(&[10], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[11], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[12], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[13], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[14], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[15], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[16], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[17], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false})], index: Some(Index {field: &[2], collection: &[1]})}),
+        ("DelayDeviceBox".to_string(), BoxRules {target: Target {accepts: &["Device", "Selection", "MetaData", "SideChain"], mandatory: false, exclusive: false}, pointers: &[(&[1], Pointer {pointer_type: "AudioEffectHost", mandatory: true})], targets: &[(&[10], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[11], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[12], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[13], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[14], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[15], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[16], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[17], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[19], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[20], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[22], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[23], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[24], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false})], index: Some(Index {field: &[2], collection: &[1]})}),
+        ("AutotuneDeviceBox".to_string(), BoxRules {target: Target {accepts: &["Device", "Selection", "MetaData", "SideChain"], mandatory: false, exclusive: false}, pointers: &[(&[1], Pointer {pointer_type: "AudioEffectHost", mandatory: true})], targets: &[(&[10], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[11], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[12], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[13], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[14], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[15], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false})], index: Some(Index {field: &[2], collection: &[1]})}),
+        ("CrusherDeviceBox".to_string(), BoxRules {target: Target {accepts: &["Device", "Selection", "MetaData", "SideChain"], mandatory: false, exclusive: false}, pointers: &[(&[1], Pointer {pointer_type: "AudioEffectHost", mandatory: true})], targets: &[(&[10], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[11], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[12], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[13], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false})], index: Some(Index {field: &[2], collection: &[1]})}),
+        ("DattorroReverbDeviceBox".to_string(), BoxRules {target: Target {accepts: &["Device", "Selection", "MetaData", "SideChain"], mandatory: false, exclusive: false}, pointers: &[(&[1], Pointer {pointer_type: "AudioEffectHost", mandatory: true})], targets: &[(&[10], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[11], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[12], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[13], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[14], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[15], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[16], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[17], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[18], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[19], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[20], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[21], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false})], index: Some(Index {field: &[2], collection: &[1]})}),
+        ("ConvolutionReverbDeviceBox".to_string(), BoxRules {target: Target {accepts: &["Device", "Selection", "MetaData", "SideChain"], mandatory: false, exclusive: false}, pointers: &[(&[1], Pointer {pointer_type: "AudioEffectHost", mandatory: true}), (&[20], Pointer {pointer_type: "AudioFile", mandatory: false})], targets: &[(&[10], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[11], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[12], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[13], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[14], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[15], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false})], index: Some(Index {field: &[2], collection: &[1]})}),
+        ("VelocityDeviceBox".to_string(), BoxRules {target: Target {accepts: &["Device", "Selection", "MetaData"], mandatory: false, exclusive: false}, pointers: &[(&[1], Pointer {pointer_type: "MIDIEffectHost", mandatory: true})], targets: &[(&[10], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[11], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[12], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[13], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[14], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[15], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false})], index: Some(Index {field: &[2], collection: &[1]})}),
+        ("FoldDeviceBox".to_string(), BoxRules {target: Target {accepts: &["Device", "Selection", "MetaData", "SideChain"], mandatory: false, exclusive: false}, pointers: &[(&[1], Pointer {pointer_type: "AudioEffectHost", mandatory: true})], targets: &[(&[10], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[12], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false})], index: Some(Index {field: &[2], collection: &[1]})}),
+        ("TidalDeviceBox".to_string(), BoxRules {target: Target {accepts: &["Device", "Selection", "MetaData", "SideChain"], mandatory: false, exclusive: false}, pointers: &[(&[1], Pointer {pointer_type: "AudioEffectHost", mandatory: true})], targets: &[(&[10], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[11], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[20], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[21], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[22], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[23], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false})], index: Some(Index {field: &[2], collection: &[1]})}),
+        ("RevampDeviceBox".to_string(), BoxRules {target: Target {accepts: &["Device", "Selection", "MetaData", "SideChain"], mandatory: false, exclusive: false}, pointers: &[(&[1], Pointer {pointer_type: "AudioEffectHost", mandatory: true})], targets: &[(&[10, 1], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[10, 10], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[10, 11], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[10, 12], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[11, 1], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[11, 10], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[11, 11], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[12, 1], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[12, 10], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[12, 11], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[12, 12], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[13, 1], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[13, 10], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[13, 11], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[13, 12], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[14, 1], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[14, 10], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[14, 11], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[14, 12], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[15, 1], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[15, 10], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[15, 11], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[16, 1], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[16, 10], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[16, 11], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[16, 12], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false})], index: Some(Index {field: &[2], collection: &[1]})}),
+        ("ReverbDeviceBox".to_string(), BoxRules {target: Target {accepts: &["Device", "Selection", "MetaData", "SideChain"], mandatory: false, exclusive: false}, pointers: &[(&[1], Pointer {pointer_type: "AudioEffectHost", mandatory: true})], targets: &[(&[10], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[11], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[12], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[13], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[14], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[15], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false})], index: Some(Index {field: &[2], collection: &[1]})}),
+        ("VaporisateurDeviceBox".to_string(), BoxRules {target: Target {accepts: &["Device", "Selection", "MetaData", "SideChain"], mandatory: false, exclusive: false}, pointers: &[(&[1], Pointer {pointer_type: "InstrumentHost", mandatory: true})], targets: &[(&[14], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[15], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[16], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[17], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[18], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[19], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[20], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[21], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[22], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[23], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[24], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[25], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[26], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[27], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[30, 1], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[30, 2], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[30, 3], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[30, 10], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[30, 11], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[30, 12], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[31, 1], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[31, 2], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[32, 1], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[32, 2], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[32, 3], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[32, 4], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[40, 0, 1], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[40, 0, 2], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[40, 0, 3], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[40, 0, 4], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[40, 1, 1], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[40, 1, 2], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[40, 1, 3], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[40, 1, 4], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[50, 1], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[50, 2], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[50, 3], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[50, 4], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[60, 0, 1], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[60, 0, 2], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[60, 0, 3], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[60, 1, 1], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[60, 1, 2], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[60, 1, 3], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[60, 2, 1], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[60, 2, 2], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[60, 2, 3], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[60, 3, 1], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[60, 3, 2], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[60, 3, 3], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[60, 4, 1], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[60, 4, 2], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[60, 4, 3], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[60, 5, 1], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[60, 5, 2], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[60, 5, 3], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[60, 6, 1], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[60, 6, 2], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[60, 6, 3], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[60, 7, 1], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[60, 7, 2], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[60, 7, 3], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false})], index: None}),
+        ("WavetableDeviceBox".to_string(), BoxRules {target: Target {accepts: &["Device", "Selection", "MetaData", "SideChain"], mandatory: false, exclusive: false}, pointers: &[(&[1], Pointer {pointer_type: "InstrumentHost", mandatory: true}), (&[11], Pointer {pointer_type: "AudioFile", mandatory: false})], targets: &[(&[10], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[12], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[13], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[14], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[15], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[16], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[17], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[18], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[19], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[20], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[21], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[22], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[23], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[24], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[25], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[26], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[27], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false})], index: None}),
+        ("FMSynthDeviceBox".to_string(), BoxRules {target: Target {accepts: &["Device", "Selection", "MetaData", "SideChain"], mandatory: false, exclusive: false}, pointers: &[(&[1], Pointer {pointer_type: "InstrumentHost", mandatory: true})], targets: &[(&[10], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[11], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[12], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[13], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[14], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[40, 0, 1], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[40, 0, 2], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[40, 0, 3], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[40, 0, 4], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[40, 0, 5], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[40, 0, 6], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[40, 0, 7], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[40, 0, 8], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[40, 0, 9], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[40, 1, 1], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[40, 1, 2], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[40, 1, 3], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[40, 1, 4], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[40, 1, 5], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[40, 1, 6], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[40, 1, 7], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[40, 1, 8], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[40, 1, 9], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[40, 2, 1], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[40, 2, 2], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[40, 2, 3], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[40, 2, 4], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[40, 2, 5], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[40, 2, 6], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[40, 2, 7], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[40, 2, 8], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[40, 2, 9], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[40, 3, 1], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[40, 3, 2], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[40, 3, 3], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[40, 3, 4], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[40, 3, 5], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[40, 3, 6], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[40, 3, 7], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[40, 3, 8], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[40, 3, 9], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false})], index: None}),
+        ("MIDIOutputDeviceBox".to_string(), BoxRules {target: Target {accepts: &["Device", "Selection", "MetaData", "SideChain"], mandatory: false, exclusive: false}, pointers: &[(&[1], Pointer {pointer_type: "InstrumentHost", mandatory: true}), (&[14], Pointer {pointer_type: "MIDIDevice", mandatory: false})], targets: &[(&[13], Target {accepts: &["Parameter"], mandatory: false, exclusive: false})], index: None}),
+        ("MIDIOutputBox".to_string(), BoxRules {target: Target {accepts: &[], mandatory: false, exclusive: false}, pointers: &[(&[1], Pointer {pointer_type: "MIDIDevice", mandatory: true})], targets: &[(&[2], Target {accepts: &["MIDIDevice"], mandatory: true, exclusive: false})], index: None}),
+        ("MIDIOutputParameterBox".to_string(), BoxRules {target: Target {accepts: &[], mandatory: false, exclusive: false}, pointers: &[(&[1], Pointer {pointer_type: "Parameter", mandatory: true})], targets: &[(&[4], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: true, exclusive: false})], index: None}),
+        ("SoundfontDeviceBox".to_string(), BoxRules {target: Target {accepts: &["Device", "Selection", "MetaData", "SideChain"], mandatory: false, exclusive: false}, pointers: &[(&[1], Pointer {pointer_type: "InstrumentHost", mandatory: true}), (&[10], Pointer {pointer_type: "SoundfontFile", mandatory: false})], targets: &[], index: None}),
+        ("NanoDeviceBox".to_string(), BoxRules {target: Target {accepts: &["Device", "Selection", "MetaData", "SideChain"], mandatory: false, exclusive: false}, pointers: &[(&[1], Pointer {pointer_type: "InstrumentHost", mandatory: true}), (&[15], Pointer {pointer_type: "AudioFile", mandatory: false})], targets: &[(&[10], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[20], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false})], index: None}),
+        ("PlayfieldDeviceBox".to_string(), BoxRules {target: Target {accepts: &["Device", "Selection", "MetaData", "SideChain"], mandatory: false, exclusive: false}, pointers: &[(&[1], Pointer {pointer_type: "InstrumentHost", mandatory: true})], targets: &[(&[10], Target {accepts: &["Sample"], mandatory: false, exclusive: false})], index: None}),
+        ("PlayfieldSampleBox".to_string(), BoxRules {target: Target {accepts: &["Editing", "SideChain", "Selection"], mandatory: false, exclusive: false}, pointers: &[(&[10], Pointer {pointer_type: "Sample", mandatory: true}), (&[11], Pointer {pointer_type: "AudioFile", mandatory: true})], targets: &[(&[12], Target {accepts: &["MIDIEffectHost"], mandatory: false, exclusive: false}), (&[13], Target {accepts: &["AudioEffectHost"], mandatory: false, exclusive: false}), (&[40], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[41], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[42], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[43], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[44], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[45], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[46], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[47], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[48], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[49], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false})], index: None}),
+        ("TapeDeviceBox".to_string(), BoxRules {target: Target {accepts: &["Device", "Selection", "MetaData", "SideChain", "Automation"], mandatory: false, exclusive: false}, pointers: &[(&[1], Pointer {pointer_type: "InstrumentHost", mandatory: true})], targets: &[(&[10], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[11], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[12], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[13], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false})], index: None}),
+        ("ArpeggioDeviceBox".to_string(), BoxRules {target: Target {accepts: &["Device", "Selection", "MetaData"], mandatory: false, exclusive: false}, pointers: &[(&[1], Pointer {pointer_type: "MIDIEffectHost", mandatory: true})], targets: &[(&[10], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[11], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[12], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[13], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[14], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[15], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false})], index: Some(Index {field: &[2], collection: &[1]})}),
+        ("PitchDeviceBox".to_string(), BoxRules {target: Target {accepts: &["Device", "Selection", "MetaData"], mandatory: false, exclusive: false}, pointers: &[(&[1], Pointer {pointer_type: "MIDIEffectHost", mandatory: true})], targets: &[(&[10], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[11], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[12], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false})], index: Some(Index {field: &[2], collection: &[1]})}),
+        ("ScaleDeviceBox".to_string(), BoxRules {target: Target {accepts: &["Device", "Selection", "MetaData"], mandatory: false, exclusive: false}, pointers: &[(&[1], Pointer {pointer_type: "MIDIEffectHost", mandatory: true})], targets: &[(&[10], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[11], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[12], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[13], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[14], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false})], index: Some(Index {field: &[2], collection: &[1]})}),
+        ("RatchetDeviceBox".to_string(), BoxRules {target: Target {accepts: &["Device", "Selection", "MetaData"], mandatory: false, exclusive: false}, pointers: &[(&[1], Pointer {pointer_type: "MIDIEffectHost", mandatory: true})], targets: &[(&[10], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[11], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[12], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false})], index: Some(Index {field: &[2], collection: &[1]})}),
+        ("ZeitgeistDeviceBox".to_string(), BoxRules {target: Target {accepts: &["Device", "Selection", "MetaData"], mandatory: false, exclusive: false}, pointers: &[(&[1], Pointer {pointer_type: "MIDIEffectHost", mandatory: true}), (&[10], Pointer {pointer_type: "Groove", mandatory: true})], targets: &[], index: Some(Index {field: &[2], collection: &[1]})}),
+        ("NeuralAmpDeviceBox".to_string(), BoxRules {target: Target {accepts: &["Device", "Selection", "MetaData", "SideChain"], mandatory: false, exclusive: false}, pointers: &[(&[1], Pointer {pointer_type: "AudioEffectHost", mandatory: true}), (&[20], Pointer {pointer_type: "NeuralAmpModel", mandatory: false})], targets: &[(&[11], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[12], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[14], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false})], index: Some(Index {field: &[2], collection: &[1]})}),
+        ("VocoderDeviceBox".to_string(), BoxRules {target: Target {accepts: &["Device", "Selection", "MetaData", "SideChain"], mandatory: false, exclusive: false}, pointers: &[(&[1], Pointer {pointer_type: "AudioEffectHost", mandatory: true}), (&[30], Pointer {pointer_type: "SideChain", mandatory: false})], targets: &[(&[10], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[11], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[12], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[13], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[14], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[15], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[16], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[17], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[20], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[21], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false})], index: Some(Index {field: &[2], collection: &[1]})}),
+        ("WaveshaperDeviceBox".to_string(), BoxRules {target: Target {accepts: &["Device", "Selection", "MetaData", "SideChain"], mandatory: false, exclusive: false}, pointers: &[(&[1], Pointer {pointer_type: "AudioEffectHost", mandatory: true})], targets: &[(&[11], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[12], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[13], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false})], index: Some(Index {field: &[2], collection: &[1]})}),
+        ("WerkstattDeviceBox".to_string(), BoxRules {target: Target {accepts: &["Device", "Selection", "MetaData", "SideChain"], mandatory: false, exclusive: false}, pointers: &[(&[1], Pointer {pointer_type: "AudioEffectHost", mandatory: true})], targets: &[(&[11], Target {accepts: &["Parameter"], mandatory: false, exclusive: false}), (&[12], Target {accepts: &["Sample"], mandatory: false, exclusive: false})], index: Some(Index {field: &[2], collection: &[1]})}),
+        ("WerkstattParameterBox".to_string(), BoxRules {target: Target {accepts: &[], mandatory: false, exclusive: false}, pointers: &[(&[1], Pointer {pointer_type: "Parameter", mandatory: true})], targets: &[(&[4], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false})], index: Some(Index {field: &[3], collection: &[1]})}),
+        ("WerkstattSampleBox".to_string(), BoxRules {target: Target {accepts: &[], mandatory: false, exclusive: false}, pointers: &[(&[1], Pointer {pointer_type: "Sample", mandatory: true}), (&[4], Pointer {pointer_type: "AudioFile", mandatory: false})], targets: &[], index: Some(Index {field: &[3], collection: &[1]})}),
+        ("SpielwerkDeviceBox".to_string(), BoxRules {target: Target {accepts: &["Device", "Selection", "MetaData"], mandatory: false, exclusive: false}, pointers: &[(&[1], Pointer {pointer_type: "MIDIEffectHost", mandatory: true})], targets: &[(&[11], Target {accepts: &["Parameter"], mandatory: false, exclusive: false}), (&[12], Target {accepts: &["Sample"], mandatory: false, exclusive: false})], index: Some(Index {field: &[2], collection: &[1]})}),
+        ("ApparatDeviceBox".to_string(), BoxRules {target: Target {accepts: &["Device", "Selection", "MetaData", "SideChain"], mandatory: false, exclusive: false}, pointers: &[(&[1], Pointer {pointer_type: "InstrumentHost", mandatory: true})], targets: &[(&[11], Target {accepts: &["Parameter"], mandatory: false, exclusive: false}), (&[12], Target {accepts: &["Sample"], mandatory: false, exclusive: false})], index: None}),
+        ("NoopInstrumentBox".to_string(), BoxRules {target: Target {accepts: &["Device", "Selection", "MetaData", "SideChain"], mandatory: false, exclusive: false}, pointers: &[(&[1], Pointer {pointer_type: "InstrumentHost", mandatory: true})], targets: &[], index: None}),
+        ("CompositeDeviceBox".to_string(), BoxRules {target: Target {accepts: &["Device", "Selection", "MetaData", "SideChain"], mandatory: false, exclusive: false}, pointers: &[(&[1], Pointer {pointer_type: "InstrumentHost", mandatory: true})], targets: &[(&[10], Target {accepts: &["CompositeCell"], mandatory: false, exclusive: false})], index: None}),
+        ("CompositeCellBox".to_string(), BoxRules {target: Target {accepts: &["Selection"], mandatory: false, exclusive: false}, pointers: &[(&[1], Pointer {pointer_type: "CompositeCell", mandatory: true})], targets: &[(&[2], Target {accepts: &["InstrumentHost"], mandatory: true, exclusive: false}), (&[3], Target {accepts: &["MIDIEffectHost"], mandatory: false, exclusive: false}), (&[4], Target {accepts: &["AudioEffectHost"], mandatory: false, exclusive: false})], index: Some(Index {field: &[5], collection: &[1]})}),
+        ("AudioEffectCompositeBox".to_string(), BoxRules {target: Target {accepts: &["Device", "Selection", "MetaData", "SideChain"], mandatory: false, exclusive: false}, pointers: &[(&[1], Pointer {pointer_type: "AudioEffectHost", mandatory: true})], targets: &[(&[10], Target {accepts: &["AudioEffectCompositeCell"], mandatory: false, exclusive: false}), (&[11], Target {accepts: &["SideChain"], mandatory: false, exclusive: false}), (&[12], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[13], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false})], index: Some(Index {field: &[2], collection: &[1]})}),
+        ("AudioEffectCompositeCellBox".to_string(), BoxRules {target: Target {accepts: &["Editing", "Selection"], mandatory: false, exclusive: false}, pointers: &[(&[1], Pointer {pointer_type: "AudioEffectCompositeCell", mandatory: true})], targets: &[(&[2], Target {accepts: &["AudioEffectHost"], mandatory: false, exclusive: false}), (&[40], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[41], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[42], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[43], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false})], index: Some(Index {field: &[3], collection: &[1]})}),
+        ("StereoCompositeBox".to_string(), BoxRules {target: Target {accepts: &["Device", "Selection", "MetaData", "SideChain"], mandatory: false, exclusive: false}, pointers: &[(&[1], Pointer {pointer_type: "AudioEffectHost", mandatory: true})], targets: &[(&[10], Target {accepts: &["AudioEffectCompositeCell"], mandatory: false, exclusive: false}), (&[11], Target {accepts: &["SideChain"], mandatory: false, exclusive: false}), (&[12], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[13], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false})], index: Some(Index {field: &[2], collection: &[1]})}),
+        ("FrequencySplitBox".to_string(), BoxRules {target: Target {accepts: &["Device", "Selection", "MetaData", "SideChain"], mandatory: false, exclusive: false}, pointers: &[(&[1], Pointer {pointer_type: "AudioEffectHost", mandatory: true})], targets: &[(&[10], Target {accepts: &["AudioEffectCompositeCell"], mandatory: false, exclusive: false}), (&[11], Target {accepts: &["SideChain"], mandatory: false, exclusive: false}), (&[12], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[13], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[14], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[15], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false}), (&[16], Target {accepts: &["Modulation", "Automation", "MIDIControl"], mandatory: false, exclusive: false})], index: Some(Index {field: &[2], collection: &[1]})}),
+        ("ModularBox".to_string(), BoxRules {target: Target {accepts: &[], mandatory: false, exclusive: false}, pointers: &[(&[1], Pointer {pointer_type: "ModularSetup", mandatory: true})], targets: &[(&[2], Target {accepts: &["ModularSetup"], mandatory: true, exclusive: false}), (&[3], Target {accepts: &["Editing"], mandatory: false, exclusive: false}), (&[11], Target {accepts: &["ModuleCollection"], mandatory: false, exclusive: false}), (&[12], Target {accepts: &["ConnectionCollection"], mandatory: false, exclusive: false})], index: None}),
+        ("ModuleConnectionBox".to_string(), BoxRules {target: Target {accepts: &[], mandatory: false, exclusive: false}, pointers: &[(&[1], Pointer {pointer_type: "ConnectionCollection", mandatory: true}), (&[2], Pointer {pointer_type: "VoltageConnection", mandatory: true}), (&[3], Pointer {pointer_type: "VoltageConnection", mandatory: true})], targets: &[], index: None}),
+        ("ModularAudioInputBox".to_string(), BoxRules {target: Target {accepts: &["Selection"], mandatory: false, exclusive: false}, pointers: &[(&[1, 1], Pointer {pointer_type: "ModuleCollection", mandatory: true})], targets: &[(&[10], Target {accepts: &["VoltageConnection"], mandatory: false, exclusive: false})], index: None}),
+        ("ModularAudioOutputBox".to_string(), BoxRules {target: Target {accepts: &["Selection"], mandatory: false, exclusive: false}, pointers: &[(&[1, 1], Pointer {pointer_type: "ModuleCollection", mandatory: true})], targets: &[(&[10], Target {accepts: &["VoltageConnection"], mandatory: false, exclusive: false})], index: None}),
+        ("ModuleDelayBox".to_string(), BoxRules {target: Target {accepts: &["Selection"], mandatory: false, exclusive: false}, pointers: &[(&[1, 1], Pointer {pointer_type: "ModuleCollection", mandatory: true})], targets: &[(&[10], Target {accepts: &["VoltageConnection"], mandatory: false, exclusive: false}), (&[11], Target {accepts: &["VoltageConnection"], mandatory: false, exclusive: false}), (&[20], Target {accepts: &["ParameterController"], mandatory: false, exclusive: false})], index: None}),
+        ("ModuleMultiplierBox".to_string(), BoxRules {target: Target {accepts: &["Selection"], mandatory: false, exclusive: false}, pointers: &[(&[1, 1], Pointer {pointer_type: "ModuleCollection", mandatory: true})], targets: &[(&[10], Target {accepts: &["VoltageConnection"], mandatory: false, exclusive: false}), (&[11], Target {accepts: &["VoltageConnection"], mandatory: false, exclusive: false}), (&[12], Target {accepts: &["VoltageConnection"], mandatory: false, exclusive: false})], index: None}),
+        ("ModuleGainBox".to_string(), BoxRules {target: Target {accepts: &["Selection"], mandatory: false, exclusive: false}, pointers: &[(&[1, 1], Pointer {pointer_type: "ModuleCollection", mandatory: true})], targets: &[(&[10], Target {accepts: &["VoltageConnection"], mandatory: false, exclusive: false}), (&[12], Target {accepts: &["VoltageConnection"], mandatory: false, exclusive: false}), (&[20], Target {accepts: &["ParameterController"], mandatory: false, exclusive: false})], index: None}),
+    ])
+}
+
+pub fn names() -> Names {
+    Names::from([
+        ("MetaDataBox".to_string(), &[FieldName {key: 1, name: "target", fields: &[]}, FieldName {key: 2, name: "origin", fields: &[]}, FieldName {key: 3, name: "value", fields: &[]}] as &[FieldName]),
+        ("ProjectMetaBox".to_string(), &[FieldName {key: 1, name: "project-name", fields: &[]}, FieldName {key: 2, name: "artist", fields: &[]}, FieldName {key: 3, name: "description", fields: &[]}, FieldName {key: 4, name: "tag-list", fields: &[]}, FieldName {key: 5, name: "notepad", fields: &[]}, FieldName {key: 6, name: "created", fields: &[]}, FieldName {key: 7, name: "modified", fields: &[]}, FieldName {key: 8, name: "cover-id", fields: &[]}] as &[FieldName]),
+        ("RootBox".to_string(), &[FieldName {key: 1, name: "timeline", fields: &[]}, FieldName {key: 2, name: "users", fields: &[]}, FieldName {key: 3, name: "created", fields: &[]}, FieldName {key: 4, name: "groove", fields: &[]}, FieldName {key: 5, name: "base-frequency", fields: &[]}, FieldName {key: 10, name: "modular-setups", fields: &[]}, FieldName {key: 20, name: "audio-units", fields: &[]}, FieldName {key: 21, name: "audio-busses", fields: &[]}, FieldName {key: 30, name: "output-device", fields: &[]}, FieldName {key: 35, name: "output-midi-devices", fields: &[]}, FieldName {key: 40, name: "piano-mode", fields: &[FieldName {key: 1, name: "keyboard", fields: &[]}, FieldName {key: 2, name: "time-range-in-quarters", fields: &[]}, FieldName {key: 3, name: "note-scale", fields: &[]}, FieldName {key: 4, name: "note-labels", fields: &[]}, FieldName {key: 5, name: "transpose", fields: &[]}]}, FieldName {key: 100, name: "shadertoy", fields: &[]}, FieldName {key: 101, name: "project-meta", fields: &[]}, FieldName {key: 111, name: "editing-channel", fields: &[]}] as &[FieldName]),
+        ("SelectionBox".to_string(), &[FieldName {key: 1, name: "selection", fields: &[]}, FieldName {key: 2, name: "selectable", fields: &[]}] as &[FieldName]),
+        ("UserInterfaceBox".to_string(), &[FieldName {key: 1, name: "root", fields: &[]}, FieldName {key: 10, name: "selection", fields: &[]}, FieldName {key: 11, name: "upload-states", fields: &[]}, FieldName {key: 21, name: "editing-device-chain", fields: &[]}, FieldName {key: 22, name: "editing-timeline-region", fields: &[]}, FieldName {key: 23, name: "editing-modular-system", fields: &[]}, FieldName {key: 30, name: "midi-controllers", fields: &[]}] as &[FieldName]),
+        ("UploadFileBox".to_string(), &[FieldName {key: 1, name: "user", fields: &[]}, FieldName {key: 2, name: "file", fields: &[]}] as &[FieldName]),
+        ("ShadertoyBox".to_string(), &[FieldName {key: 1, name: "shader-code", fields: &[]}, FieldName {key: 2, name: "highres", fields: &[]}] as &[FieldName]),
+        ("MIDIControllerBox".to_string(), &[FieldName {key: 1, name: "controllers", fields: &[]}, FieldName {key: 2, name: "parameter", fields: &[]}, FieldName {key: 3, name: "device-id", fields: &[]}, FieldName {key: 4, name: "device-channel", fields: &[]}, FieldName {key: 5, name: "control-id", fields: &[]}] as &[FieldName]),
+        ("TimelineBox".to_string(), &[FieldName {key: 1, name: "root", fields: &[]}, FieldName {key: 10, name: "signature", fields: &[FieldName {key: 1, name: "nominator", fields: &[]}, FieldName {key: 2, name: "denominator", fields: &[]}]}, FieldName {key: 11, name: "loop-area", fields: &[FieldName {key: 1, name: "enabled", fields: &[]}, FieldName {key: 2, name: "from", fields: &[]}, FieldName {key: 3, name: "to", fields: &[]}]}, FieldName {key: 21, name: "marker-track", fields: &[FieldName {key: 1, name: "markers", fields: &[]}, FieldName {key: 10, name: "index", fields: &[]}, FieldName {key: 20, name: "enabled", fields: &[]}]}, FieldName {key: 22, name: "tempo-track", fields: &[FieldName {key: 1, name: "events", fields: &[]}, FieldName {key: 10, name: "index", fields: &[]}, FieldName {key: 15, name: "min-bpm", fields: &[]}, FieldName {key: 16, name: "max-bpm", fields: &[]}, FieldName {key: 20, name: "enabled", fields: &[]}]}, FieldName {key: 23, name: "signature-track", fields: &[FieldName {key: 1, name: "events", fields: &[]}, FieldName {key: 10, name: "index", fields: &[]}, FieldName {key: 20, name: "enabled", fields: &[]}]}, FieldName {key: 30, name: "durationInPulses", fields: &[]}, FieldName {key: 31, name: "bpm", fields: &[]}] as &[FieldName]),
+        ("TrackBox".to_string(), &[FieldName {key: 1, name: "tracks", fields: &[]}, FieldName {key: 2, name: "target", fields: &[]}, FieldName {key: 3, name: "regions", fields: &[]}, FieldName {key: 4, name: "clips", fields: &[]}, FieldName {key: 10, name: "index", fields: &[]}, FieldName {key: 11, name: "type", fields: &[]}, FieldName {key: 20, name: "enabled", fields: &[]}, FieldName {key: 30, name: "exclude-piano-mode", fields: &[]}] as &[FieldName]),
+        ("NoteEventBox".to_string(), &[FieldName {key: 1, name: "events", fields: &[]}, FieldName {key: 10, name: "position", fields: &[]}, FieldName {key: 11, name: "duration", fields: &[]}, FieldName {key: 20, name: "pitch", fields: &[]}, FieldName {key: 21, name: "velocity", fields: &[]}, FieldName {key: 22, name: "play-count", fields: &[]}, FieldName {key: 23, name: "play-curve", fields: &[]}, FieldName {key: 24, name: "cent", fields: &[]}, FieldName {key: 25, name: "chance", fields: &[]}] as &[FieldName]),
+        ("NoteEventRepeatBox".to_string(), &[FieldName {key: 1, name: "event", fields: &[]}, FieldName {key: 2, name: "count", fields: &[]}, FieldName {key: 3, name: "curve", fields: &[]}, FieldName {key: 4, name: "length", fields: &[]}] as &[FieldName]),
+        ("NoteEventCollectionBox".to_string(), &[FieldName {key: 1, name: "events", fields: &[]}, FieldName {key: 2, name: "owners", fields: &[]}, FieldName {key: 3, name: "controls", fields: &[]}] as &[FieldName]),
+        ("NoteControlEventBox".to_string(), &[FieldName {key: 1, name: "events", fields: &[]}, FieldName {key: 10, name: "position", fields: &[]}, FieldName {key: 11, name: "type", fields: &[]}, FieldName {key: 12, name: "controller", fields: &[]}, FieldName {key: 13, name: "value", fields: &[]}] as &[FieldName]),
+        ("NoteRegionBox".to_string(), &[FieldName {key: 1, name: "regions", fields: &[]}, FieldName {key: 2, name: "events", fields: &[]}, FieldName {key: 10, name: "position", fields: &[]}, FieldName {key: 11, name: "duration", fields: &[]}, FieldName {key: 12, name: "loop-offset", fields: &[]}, FieldName {key: 13, name: "loop-duration", fields: &[]}, FieldName {key: 14, name: "event-offset", fields: &[]}, FieldName {key: 15, name: "mute", fields: &[]}, FieldName {key: 16, name: "label", fields: &[]}, FieldName {key: 17, name: "hue", fields: &[]}] as &[FieldName]),
+        ("NoteClipBox".to_string(), &[FieldName {key: 1, name: "clips", fields: &[]}, FieldName {key: 2, name: "events", fields: &[]}, FieldName {key: 3, name: "index", fields: &[]}, FieldName {key: 4, name: "trigger-mode", fields: &[FieldName {key: 1, name: "loop", fields: &[]}, FieldName {key: 2, name: "reverse", fields: &[]}, FieldName {key: 4, name: "speed", fields: &[]}, FieldName {key: 5, name: "quantise", fields: &[]}, FieldName {key: 6, name: "trigger", fields: &[]}]}, FieldName {key: 10, name: "duration", fields: &[]}, FieldName {key: 11, name: "mute", fields: &[]}, FieldName {key: 12, name: "label", fields: &[]}, FieldName {key: 13, name: "hue", fields: &[]}] as &[FieldName]),
+        ("ValueEventBox".to_string(), &[FieldName {key: 1, name: "events", fields: &[]}, FieldName {key: 10, name: "position", fields: &[]}, FieldName {key: 11, name: "index", fields: &[]}, FieldName {key: 12, name: "interpolation", fields: &[]}, FieldName {key: 13, name: "value", fields: &[]}] as &[FieldName]),
+        ("ValueEventCollectionBox".to_string(), &[FieldName {key: 1, name: "events", fields: &[]}, FieldName {key: 2, name: "owners", fields: &[]}] as &[FieldName]),
+        ("ValueEventCurveBox".to_string(), &[FieldName {key: 1, name: "event", fields: &[]}, FieldName {key: 2, name: "slope", fields: &[]}] as &[FieldName]),
+        ("ValueRegionBox".to_string(), &[FieldName {key: 1, name: "regions", fields: &[]}, FieldName {key: 2, name: "events", fields: &[]}, FieldName {key: 10, name: "position", fields: &[]}, FieldName {key: 11, name: "duration", fields: &[]}, FieldName {key: 12, name: "loop-offset", fields: &[]}, FieldName {key: 13, name: "loop-duration", fields: &[]}, FieldName {key: 14, name: "mute", fields: &[]}, FieldName {key: 15, name: "label", fields: &[]}, FieldName {key: 16, name: "hue", fields: &[]}] as &[FieldName]),
+        ("ValueClipBox".to_string(), &[FieldName {key: 1, name: "clips", fields: &[]}, FieldName {key: 2, name: "events", fields: &[]}, FieldName {key: 3, name: "index", fields: &[]}, FieldName {key: 4, name: "trigger-mode", fields: &[FieldName {key: 1, name: "loop", fields: &[]}, FieldName {key: 2, name: "reverse", fields: &[]}, FieldName {key: 4, name: "speed", fields: &[]}, FieldName {key: 5, name: "quantise", fields: &[]}, FieldName {key: 6, name: "trigger", fields: &[]}]}, FieldName {key: 10, name: "duration", fields: &[]}, FieldName {key: 11, name: "mute", fields: &[]}, FieldName {key: 12, name: "label", fields: &[]}, FieldName {key: 13, name: "hue", fields: &[]}] as &[FieldName]),
+        ("SignatureEventBox".to_string(), &[FieldName {key: 1, name: "events", fields: &[]}, FieldName {key: 9, name: "index", fields: &[]}, FieldName {key: 10, name: "relative-position", fields: &[]}, FieldName {key: 21, name: "nominator", fields: &[]}, FieldName {key: 22, name: "denominator", fields: &[]}] as &[FieldName]),
+        ("AudioRegionBox".to_string(), &[FieldName {key: 1, name: "regions", fields: &[]}, FieldName {key: 2, name: "file", fields: &[]}, FieldName {key: 4, name: "time-base", fields: &[]}, FieldName {key: 5, name: "events", fields: &[]}, FieldName {key: 7, name: "waveform-offset", fields: &[]}, FieldName {key: 8, name: "play-mode", fields: &[]}, FieldName {key: 10, name: "position", fields: &[]}, FieldName {key: 11, name: "duration", fields: &[]}, FieldName {key: 12, name: "loop-offset", fields: &[]}, FieldName {key: 13, name: "loop-duration", fields: &[]}, FieldName {key: 14, name: "mute", fields: &[]}, FieldName {key: 15, name: "label", fields: &[]}, FieldName {key: 16, name: "hue", fields: &[]}, FieldName {key: 17, name: "gain", fields: &[]}, FieldName {key: 18, name: "fading", fields: &[FieldName {key: 1, name: "in", fields: &[]}, FieldName {key: 2, name: "out", fields: &[]}, FieldName {key: 3, name: "in-slope", fields: &[]}, FieldName {key: 4, name: "out-slope", fields: &[]}]}, FieldName {key: 19, name: "interpolation", fields: &[]}] as &[FieldName]),
+        ("AudioClipBox".to_string(), &[FieldName {key: 1, name: "clips", fields: &[]}, FieldName {key: 2, name: "file", fields: &[]}, FieldName {key: 3, name: "index", fields: &[]}, FieldName {key: 4, name: "trigger-mode", fields: &[FieldName {key: 1, name: "loop", fields: &[]}, FieldName {key: 2, name: "reverse", fields: &[]}, FieldName {key: 4, name: "speed", fields: &[]}, FieldName {key: 5, name: "quantise", fields: &[]}, FieldName {key: 6, name: "trigger", fields: &[]}]}, FieldName {key: 5, name: "events", fields: &[]}, FieldName {key: 7, name: "waveform-offset", fields: &[]}, FieldName {key: 8, name: "play-mode", fields: &[]}, FieldName {key: 10, name: "duration", fields: &[]}, FieldName {key: 11, name: "mute", fields: &[]}, FieldName {key: 12, name: "label", fields: &[]}, FieldName {key: 13, name: "hue", fields: &[]}, FieldName {key: 14, name: "gain", fields: &[]}, FieldName {key: 15, name: "interpolation", fields: &[]}, FieldName {key: 21, name: "time-base", fields: &[]}] as &[FieldName]),
+        ("AudioPitchStretchBox".to_string(), &[FieldName {key: 1, name: "warp-markers", fields: &[]}] as &[FieldName]),
+        ("AudioTimeStretchBox".to_string(), &[FieldName {key: 1, name: "warp-markers", fields: &[]}, FieldName {key: 2, name: "transient-play-mode", fields: &[]}, FieldName {key: 3, name: "playback-rate", fields: &[]}] as &[FieldName]),
+        ("AudioSignalsmithBox".to_string(), &[FieldName {key: 1, name: "warp-markers", fields: &[]}, FieldName {key: 2, name: "transpose", fields: &[]}] as &[FieldName]),
+        ("TransientMarkerBox".to_string(), &[FieldName {key: 1, name: "owner", fields: &[]}, FieldName {key: 2, name: "position", fields: &[]}] as &[FieldName]),
+        ("WarpMarkerBox".to_string(), &[FieldName {key: 1, name: "owner", fields: &[]}, FieldName {key: 2, name: "position", fields: &[]}, FieldName {key: 3, name: "seconds", fields: &[]}] as &[FieldName]),
+        ("MarkerBox".to_string(), &[FieldName {key: 1, name: "track", fields: &[]}, FieldName {key: 2, name: "position", fields: &[]}, FieldName {key: 3, name: "plays", fields: &[]}, FieldName {key: 4, name: "label", fields: &[]}, FieldName {key: 5, name: "hue", fields: &[]}] as &[FieldName]),
+        ("AudioFileBox".to_string(), &[FieldName {key: 1, name: "start-in-seconds", fields: &[]}, FieldName {key: 2, name: "end-in-seconds", fields: &[]}, FieldName {key: 3, name: "file-name", fields: &[]}, FieldName {key: 10, name: "transient-markers", fields: &[]}] as &[FieldName]),
+        ("SoundfontFileBox".to_string(), &[FieldName {key: 1, name: "file-name", fields: &[]}] as &[FieldName]),
+        ("NeuralAmpModelBox".to_string(), &[FieldName {key: 1, name: "label", fields: &[]}, FieldName {key: 2, name: "model", fields: &[]}, FieldName {key: 3, name: "pack-id", fields: &[]}] as &[FieldName]),
+        ("AudioUnitBox".to_string(), &[FieldName {key: 1, name: "type", fields: &[]}, FieldName {key: 2, name: "collection", fields: &[]}, FieldName {key: 3, name: "editing", fields: &[]}, FieldName {key: 11, name: "index", fields: &[]}, FieldName {key: 12, name: "volume", fields: &[]}, FieldName {key: 13, name: "panning", fields: &[]}, FieldName {key: 14, name: "mute", fields: &[]}, FieldName {key: 15, name: "solo", fields: &[]}, FieldName {key: 20, name: "tracks", fields: &[]}, FieldName {key: 21, name: "midi-effects", fields: &[]}, FieldName {key: 22, name: "input", fields: &[]}, FieldName {key: 23, name: "audio-effects", fields: &[]}, FieldName {key: 24, name: "aux-sends", fields: &[]}, FieldName {key: 25, name: "output", fields: &[]}, FieldName {key: 26, name: "capture", fields: &[]}] as &[FieldName]),
+        ("CaptureAudioBox".to_string(), &[FieldName {key: 1, name: "device-id", fields: &[]}, FieldName {key: 2, name: "record-mode", fields: &[]}, FieldName {key: 10, name: "request-channels", fields: &[]}, FieldName {key: 11, name: "gain-db", fields: &[]}, FieldName {key: 12, name: "input-latency", fields: &[]}] as &[FieldName]),
+        ("CaptureMidiBox".to_string(), &[FieldName {key: 1, name: "device-id", fields: &[]}, FieldName {key: 2, name: "record-mode", fields: &[]}, FieldName {key: 10, name: "channel", fields: &[]}] as &[FieldName]),
+        ("AudioBusBox".to_string(), &[FieldName {key: 1, name: "collection", fields: &[]}, FieldName {key: 2, name: "output", fields: &[]}, FieldName {key: 3, name: "input", fields: &[]}, FieldName {key: 4, name: "enabled", fields: &[]}, FieldName {key: 5, name: "icon", fields: &[]}, FieldName {key: 6, name: "label", fields: &[]}, FieldName {key: 7, name: "color", fields: &[]}, FieldName {key: 8, name: "minimized", fields: &[]}] as &[FieldName]),
+        ("AuxSendBox".to_string(), &[FieldName {key: 1, name: "audio-unit", fields: &[]}, FieldName {key: 2, name: "target-bus", fields: &[]}, FieldName {key: 3, name: "index", fields: &[]}, FieldName {key: 4, name: "routing", fields: &[]}, FieldName {key: 5, name: "send-gain", fields: &[]}, FieldName {key: 6, name: "send-pan", fields: &[]}] as &[FieldName]),
+        ("GrooveShuffleBox".to_string(), &[FieldName {key: 1, name: "label", fields: &[]}, FieldName {key: 10, name: "amount", fields: &[]}, FieldName {key: 11, name: "duration", fields: &[]}, FieldName {key: 12, name: "template-steps", fields: &[]}, FieldName {key: 13, name: "template-step", fields: &[]}, FieldName {key: 14, name: "template", fields: &[FieldName {key: 1, name: "offset", fields: &[]}, FieldName {key: 2, name: "accent", fields: &[]}]}] as &[FieldName]),
+        ("UnknownAudioEffectDeviceBox".to_string(), &[FieldName {key: 1, name: "host", fields: &[]}, FieldName {key: 2, name: "index", fields: &[]}, FieldName {key: 3, name: "label", fields: &[]}, FieldName {key: 4, name: "enabled", fields: &[]}, FieldName {key: 5, name: "minimized", fields: &[]}, FieldName {key: 10, name: "comment", fields: &[]}] as &[FieldName]),
+        ("UnknownMidiEffectDeviceBox".to_string(), &[FieldName {key: 1, name: "host", fields: &[]}, FieldName {key: 2, name: "index", fields: &[]}, FieldName {key: 3, name: "label", fields: &[]}, FieldName {key: 4, name: "enabled", fields: &[]}, FieldName {key: 5, name: "minimized", fields: &[]}, FieldName {key: 10, name: "comment", fields: &[]}] as &[FieldName]),
+        ("DeviceInterfaceKnobBox".to_string(), &[FieldName {key: 1, name: "user-interface", fields: &[]}, FieldName {key: 2, name: "parameter", fields: &[]}, FieldName {key: 3, name: "index", fields: &[]}, FieldName {key: 10, name: "anchor", fields: &[]}, FieldName {key: 11, name: "color", fields: &[]}] as &[FieldName]),
+        ("ModularDeviceBox".to_string(), &[FieldName {key: 1, name: "host", fields: &[]}, FieldName {key: 2, name: "index", fields: &[]}, FieldName {key: 3, name: "label", fields: &[]}, FieldName {key: 4, name: "enabled", fields: &[]}, FieldName {key: 5, name: "minimized", fields: &[]}, FieldName {key: 10, name: "modular-setup", fields: &[]}, FieldName {key: 11, name: "user-interface", fields: &[FieldName {key: 1, name: "elements", fields: &[]}]}] as &[FieldName]),
+        ("StereoToolDeviceBox".to_string(), &[FieldName {key: 1, name: "host", fields: &[]}, FieldName {key: 2, name: "index", fields: &[]}, FieldName {key: 3, name: "label", fields: &[]}, FieldName {key: 4, name: "enabled", fields: &[]}, FieldName {key: 5, name: "minimized", fields: &[]}, FieldName {key: 10, name: "volume", fields: &[]}, FieldName {key: 11, name: "panning", fields: &[]}, FieldName {key: 12, name: "stereo", fields: &[]}, FieldName {key: 13, name: "invert-l", fields: &[]}, FieldName {key: 14, name: "invert-r", fields: &[]}, FieldName {key: 15, name: "swap", fields: &[]}, FieldName {key: 20, name: "panning-mixing", fields: &[]}] as &[FieldName]),
+        ("MaximizerDeviceBox".to_string(), &[FieldName {key: 1, name: "host", fields: &[]}, FieldName {key: 2, name: "index", fields: &[]}, FieldName {key: 3, name: "label", fields: &[]}, FieldName {key: 4, name: "enabled", fields: &[]}, FieldName {key: 5, name: "minimized", fields: &[]}, FieldName {key: 10, name: "lookahead", fields: &[]}, FieldName {key: 11, name: "threshold", fields: &[]}] as &[FieldName]),
+        ("CompressorDeviceBox".to_string(), &[FieldName {key: 1, name: "host", fields: &[]}, FieldName {key: 2, name: "index", fields: &[]}, FieldName {key: 3, name: "label", fields: &[]}, FieldName {key: 4, name: "enabled", fields: &[]}, FieldName {key: 5, name: "minimized", fields: &[]}, FieldName {key: 10, name: "lookahead", fields: &[]}, FieldName {key: 11, name: "automakeup", fields: &[]}, FieldName {key: 12, name: "autoattack", fields: &[]}, FieldName {key: 13, name: "autorelease", fields: &[]}, FieldName {key: 14, name: "inputgain", fields: &[]}, FieldName {key: 15, name: "threshold", fields: &[]}, FieldName {key: 16, name: "ratio", fields: &[]}, FieldName {key: 17, name: "knee", fields: &[]}, FieldName {key: 18, name: "attack", fields: &[]}, FieldName {key: 19, name: "release", fields: &[]}, FieldName {key: 20, name: "makeup", fields: &[]}, FieldName {key: 21, name: "mix", fields: &[]}, FieldName {key: 30, name: "side-chain", fields: &[]}] as &[FieldName]),
+        ("MultibandCompressorDeviceBox".to_string(), &[FieldName {key: 1, name: "host", fields: &[]}, FieldName {key: 2, name: "index", fields: &[]}, FieldName {key: 3, name: "label", fields: &[]}, FieldName {key: 4, name: "enabled", fields: &[]}, FieldName {key: 5, name: "minimized", fields: &[]}, FieldName {key: 10, name: "band-count", fields: &[]}, FieldName {key: 11, name: "crossover-low", fields: &[]}, FieldName {key: 12, name: "crossover-mid", fields: &[]}, FieldName {key: 13, name: "crossover-high", fields: &[]}, FieldName {key: 20, name: "bands", fields: &[FieldName {key: 1, name: "threshold", fields: &[]}, FieldName {key: 2, name: "ratio", fields: &[]}, FieldName {key: 3, name: "attack", fields: &[]}, FieldName {key: 4, name: "release", fields: &[]}, FieldName {key: 5, name: "gain", fields: &[]}, FieldName {key: 6, name: "solo", fields: &[]}, FieldName {key: 7, name: "bypass", fields: &[]}]}] as &[FieldName]),
+        ("GateDeviceBox".to_string(), &[FieldName {key: 1, name: "host", fields: &[]}, FieldName {key: 2, name: "index", fields: &[]}, FieldName {key: 3, name: "label", fields: &[]}, FieldName {key: 4, name: "enabled", fields: &[]}, FieldName {key: 5, name: "minimized", fields: &[]}, FieldName {key: 10, name: "threshold", fields: &[]}, FieldName {key: 11, name: "return", fields: &[]}, FieldName {key: 12, name: "attack", fields: &[]}, FieldName {key: 13, name: "hold", fields: &[]}, FieldName {key: 14, name: "release", fields: &[]}, FieldName {key: 15, name: "floor", fields: &[]}, FieldName {key: 16, name: "inverse", fields: &[]}, FieldName {key: 30, name: "side-chain", fields: &[]}] as &[FieldName]),
+        ("FilterDeviceBox".to_string(), &[FieldName {key: 1, name: "host", fields: &[]}, FieldName {key: 2, name: "index", fields: &[]}, FieldName {key: 3, name: "label", fields: &[]}, FieldName {key: 4, name: "enabled", fields: &[]}, FieldName {key: 5, name: "minimized", fields: &[]}, FieldName {key: 10, name: "mode", fields: &[]}, FieldName {key: 11, name: "cutoff", fields: &[]}, FieldName {key: 12, name: "resonance", fields: &[]}, FieldName {key: 13, name: "drive", fields: &[]}, FieldName {key: 14, name: "lfo-rate", fields: &[]}, FieldName {key: 15, name: "lfo-shape", fields: &[]}, FieldName {key: 16, name: "lfo-depth", fields: &[]}, FieldName {key: 17, name: "env-amount", fields: &[]}, FieldName {key: 18, name: "env-attack", fields: &[]}, FieldName {key: 19, name: "env-release", fields: &[]}, FieldName {key: 30, name: "side-chain", fields: &[]}] as &[FieldName]),
+        ("ChorusDeviceBox".to_string(), &[FieldName {key: 1, name: "host", fields: &[]}, FieldName {key: 2, name: "index", fields: &[]}, FieldName {key: 3, name: "label", fields: &[]}, FieldName {key: 4, name: "enabled", fields: &[]}, FieldName {key: 5, name: "minimized", fields: &[]}, FieldName {key: 10, name: "mode", fields: &[]}, FieldName {key: 11, name: "sync", fields: &[]}, FieldName {key: 12, name: "rate-index", fields: &[]}, FieldName {key: 13, name: "rate", fields: &[]}, FieldName {key: 14, name: "depth", fields: &[]}, FieldName {key: 15, name: "feedback", fields: &[]}, FieldName {key: 16, name: "spread", fields: &[]}, FieldName {key: 17, name: "mix", fields: &[]}] as &[FieldName]),
+        ("DelayDeviceBox".to_string(), &[FieldName {key: 1, name: "host", fields: &[]}, FieldName {key: 2, name: "index", fields: &[]}, FieldName {key: 3, name: "label", fields: &[]}, FieldName {key: 4, name: "enabled", fields: &[]}, FieldName {key: 5, name: "minimized", fields: &[]}, FieldName {key: 10, name: "delay-musical", fields: &[]}, FieldName {key: 11, name: "feedback", fields: &[]}, FieldName {key: 12, name: "cross", fields: &[]}, FieldName {key: 13, name: "filter", fields: &[]}, FieldName {key: 14, name: "wet", fields: &[]}, FieldName {key: 15, name: "dry", fields: &[]}, FieldName {key: 16, name: "pre-sync-time-left", fields: &[]}, FieldName {key: 17, name: "pre-millis-time-left", fields: &[]}, FieldName {key: 19, name: "pre-sync-time-right", fields: &[]}, FieldName {key: 20, name: "pre-millis-time-right", fields: &[]}, FieldName {key: 22, name: "delay-millis", fields: &[]}, FieldName {key: 23, name: "lfo-speed", fields: &[]}, FieldName {key: 24, name: "lfo-depth", fields: &[]}, FieldName {key: 99, name: "version", fields: &[]}] as &[FieldName]),
+        ("AutotuneDeviceBox".to_string(), &[FieldName {key: 1, name: "host", fields: &[]}, FieldName {key: 2, name: "index", fields: &[]}, FieldName {key: 3, name: "label", fields: &[]}, FieldName {key: 4, name: "enabled", fields: &[]}, FieldName {key: 5, name: "minimized", fields: &[]}, FieldName {key: 10, name: "key", fields: &[]}, FieldName {key: 11, name: "scale", fields: &[]}, FieldName {key: 12, name: "amount", fields: &[]}, FieldName {key: 13, name: "retune", fields: &[]}, FieldName {key: 14, name: "shift", fields: &[]}, FieldName {key: 15, name: "smooth", fields: &[]}] as &[FieldName]),
+        ("CrusherDeviceBox".to_string(), &[FieldName {key: 1, name: "host", fields: &[]}, FieldName {key: 2, name: "index", fields: &[]}, FieldName {key: 3, name: "label", fields: &[]}, FieldName {key: 4, name: "enabled", fields: &[]}, FieldName {key: 5, name: "minimized", fields: &[]}, FieldName {key: 10, name: "crush", fields: &[]}, FieldName {key: 11, name: "bits", fields: &[]}, FieldName {key: 12, name: "boost", fields: &[]}, FieldName {key: 13, name: "mix", fields: &[]}] as &[FieldName]),
+        ("DattorroReverbDeviceBox".to_string(), &[FieldName {key: 1, name: "host", fields: &[]}, FieldName {key: 2, name: "index", fields: &[]}, FieldName {key: 3, name: "label", fields: &[]}, FieldName {key: 4, name: "enabled", fields: &[]}, FieldName {key: 5, name: "minimized", fields: &[]}, FieldName {key: 10, name: "preDelay", fields: &[]}, FieldName {key: 11, name: "bandwidth", fields: &[]}, FieldName {key: 12, name: "inputDiffusion1", fields: &[]}, FieldName {key: 13, name: "inputDiffusion2", fields: &[]}, FieldName {key: 14, name: "decay", fields: &[]}, FieldName {key: 15, name: "decayDiffusion1", fields: &[]}, FieldName {key: 16, name: "decayDiffusion2", fields: &[]}, FieldName {key: 17, name: "damping", fields: &[]}, FieldName {key: 18, name: "excursionRate", fields: &[]}, FieldName {key: 19, name: "excursionDepth", fields: &[]}, FieldName {key: 20, name: "wet", fields: &[]}, FieldName {key: 21, name: "dry", fields: &[]}] as &[FieldName]),
+        ("ConvolutionReverbDeviceBox".to_string(), &[FieldName {key: 1, name: "host", fields: &[]}, FieldName {key: 2, name: "index", fields: &[]}, FieldName {key: 3, name: "label", fields: &[]}, FieldName {key: 4, name: "enabled", fields: &[]}, FieldName {key: 5, name: "minimized", fields: &[]}, FieldName {key: 10, name: "pre-delay", fields: &[]}, FieldName {key: 11, name: "start", fields: &[]}, FieldName {key: 12, name: "end", fields: &[]}, FieldName {key: 13, name: "stretch", fields: &[]}, FieldName {key: 14, name: "wet", fields: &[]}, FieldName {key: 15, name: "dry", fields: &[]}, FieldName {key: 20, name: "file", fields: &[]}] as &[FieldName]),
+        ("VelocityDeviceBox".to_string(), &[FieldName {key: 1, name: "host", fields: &[]}, FieldName {key: 2, name: "index", fields: &[]}, FieldName {key: 3, name: "label", fields: &[]}, FieldName {key: 4, name: "enabled", fields: &[]}, FieldName {key: 5, name: "minimized", fields: &[]}, FieldName {key: 10, name: "magnet-position", fields: &[]}, FieldName {key: 11, name: "magnet-strength", fields: &[]}, FieldName {key: 12, name: "random-seed", fields: &[]}, FieldName {key: 13, name: "random-amount", fields: &[]}, FieldName {key: 14, name: "offset", fields: &[]}, FieldName {key: 15, name: "mix", fields: &[]}] as &[FieldName]),
+        ("FoldDeviceBox".to_string(), &[FieldName {key: 1, name: "host", fields: &[]}, FieldName {key: 2, name: "index", fields: &[]}, FieldName {key: 3, name: "label", fields: &[]}, FieldName {key: 4, name: "enabled", fields: &[]}, FieldName {key: 5, name: "minimized", fields: &[]}, FieldName {key: 10, name: "drive", fields: &[]}, FieldName {key: 11, name: "over-sampling", fields: &[]}, FieldName {key: 12, name: "volume", fields: &[]}] as &[FieldName]),
+        ("TidalDeviceBox".to_string(), &[FieldName {key: 1, name: "host", fields: &[]}, FieldName {key: 2, name: "index", fields: &[]}, FieldName {key: 3, name: "label", fields: &[]}, FieldName {key: 4, name: "enabled", fields: &[]}, FieldName {key: 5, name: "minimized", fields: &[]}, FieldName {key: 10, name: "slope", fields: &[]}, FieldName {key: 11, name: "symmetry", fields: &[]}, FieldName {key: 20, name: "rate", fields: &[]}, FieldName {key: 21, name: "depth", fields: &[]}, FieldName {key: 22, name: "offset", fields: &[]}, FieldName {key: 23, name: "channel-offset", fields: &[]}] as &[FieldName]),
+        ("RevampDeviceBox".to_string(), &[FieldName {key: 1, name: "host", fields: &[]}, FieldName {key: 2, name: "index", fields: &[]}, FieldName {key: 3, name: "label", fields: &[]}, FieldName {key: 4, name: "enabled", fields: &[]}, FieldName {key: 5, name: "minimized", fields: &[]}, FieldName {key: 10, name: "high-pass", fields: &[FieldName {key: 1, name: "enabled", fields: &[]}, FieldName {key: 10, name: "frequency", fields: &[]}, FieldName {key: 11, name: "order", fields: &[]}, FieldName {key: 12, name: "q", fields: &[]}]}, FieldName {key: 11, name: "low-shelf", fields: &[FieldName {key: 1, name: "enabled", fields: &[]}, FieldName {key: 10, name: "frequency", fields: &[]}, FieldName {key: 11, name: "gain", fields: &[]}]}, FieldName {key: 12, name: "low-bell", fields: &[FieldName {key: 1, name: "enabled", fields: &[]}, FieldName {key: 10, name: "frequency", fields: &[]}, FieldName {key: 11, name: "gain", fields: &[]}, FieldName {key: 12, name: "q", fields: &[]}]}, FieldName {key: 13, name: "mid-bell", fields: &[FieldName {key: 1, name: "enabled", fields: &[]}, FieldName {key: 10, name: "frequency", fields: &[]}, FieldName {key: 11, name: "gain", fields: &[]}, FieldName {key: 12, name: "q", fields: &[]}]}, FieldName {key: 14, name: "high-bell", fields: &[FieldName {key: 1, name: "enabled", fields: &[]}, FieldName {key: 10, name: "frequency", fields: &[]}, FieldName {key: 11, name: "gain", fields: &[]}, FieldName {key: 12, name: "q", fields: &[]}]}, FieldName {key: 15, name: "high-shelf", fields: &[FieldName {key: 1, name: "enabled", fields: &[]}, FieldName {key: 10, name: "frequency", fields: &[]}, FieldName {key: 11, name: "gain", fields: &[]}]}, FieldName {key: 16, name: "low-pass", fields: &[FieldName {key: 1, name: "enabled", fields: &[]}, FieldName {key: 10, name: "frequency", fields: &[]}, FieldName {key: 11, name: "order", fields: &[]}, FieldName {key: 12, name: "q", fields: &[]}]}] as &[FieldName]),
+        ("ReverbDeviceBox".to_string(), &[FieldName {key: 1, name: "host", fields: &[]}, FieldName {key: 2, name: "index", fields: &[]}, FieldName {key: 3, name: "label", fields: &[]}, FieldName {key: 4, name: "enabled", fields: &[]}, FieldName {key: 5, name: "minimized", fields: &[]}, FieldName {key: 10, name: "decay", fields: &[]}, FieldName {key: 11, name: "pre-delay", fields: &[]}, FieldName {key: 12, name: "damp", fields: &[]}, FieldName {key: 13, name: "filter", fields: &[]}, FieldName {key: 14, name: "wet", fields: &[]}, FieldName {key: 15, name: "dry", fields: &[]}] as &[FieldName]),
+        ("VaporisateurDeviceBox".to_string(), &[FieldName {key: 1, name: "host", fields: &[]}, FieldName {key: 2, name: "label", fields: &[]}, FieldName {key: 3, name: "icon", fields: &[]}, FieldName {key: 4, name: "enabled", fields: &[]}, FieldName {key: 5, name: "minimized", fields: &[]}, FieldName {key: 14, name: "cutoff", fields: &[]}, FieldName {key: 15, name: "resonance", fields: &[]}, FieldName {key: 16, name: "attack", fields: &[]}, FieldName {key: 17, name: "release", fields: &[]}, FieldName {key: 18, name: "filter-envelope", fields: &[]}, FieldName {key: 19, name: "decay", fields: &[]}, FieldName {key: 20, name: "sustain", fields: &[]}, FieldName {key: 21, name: "glide-time", fields: &[]}, FieldName {key: 22, name: "voicing-mode", fields: &[]}, FieldName {key: 23, name: "unison-count", fields: &[]}, FieldName {key: 24, name: "unison-detune", fields: &[]}, FieldName {key: 25, name: "unison-stereo", fields: &[]}, FieldName {key: 26, name: "filter-order", fields: &[]}, FieldName {key: 27, name: "filter-keyboard", fields: &[]}, FieldName {key: 30, name: "lfo", fields: &[FieldName {key: 1, name: "waveform", fields: &[]}, FieldName {key: 2, name: "rate", fields: &[]}, FieldName {key: 3, name: "sync", fields: &[]}, FieldName {key: 10, name: "target-tune", fields: &[]}, FieldName {key: 11, name: "target-cutoff", fields: &[]}, FieldName {key: 12, name: "target-volume", fields: &[]}]}, FieldName {key: 31, name: "mod-lfo", fields: &[FieldName {key: 1, name: "waveform", fields: &[]}, FieldName {key: 2, name: "rate", fields: &[]}]}, FieldName {key: 32, name: "mod-envelope", fields: &[FieldName {key: 1, name: "attack", fields: &[]}, FieldName {key: 2, name: "decay", fields: &[]}, FieldName {key: 3, name: "sustain", fields: &[]}, FieldName {key: 4, name: "release", fields: &[]}]}, FieldName {key: 40, name: "oscillators", fields: &[FieldName {key: 1, name: "waveform", fields: &[]}, FieldName {key: 2, name: "volume", fields: &[]}, FieldName {key: 3, name: "octave", fields: &[]}, FieldName {key: 4, name: "tune", fields: &[]}]}, FieldName {key: 50, name: "noise", fields: &[FieldName {key: 1, name: "attack", fields: &[]}, FieldName {key: 2, name: "hold", fields: &[]}, FieldName {key: 3, name: "release", fields: &[]}, FieldName {key: 4, name: "volume", fields: &[]}]}, FieldName {key: 60, name: "modulations", fields: &[FieldName {key: 1, name: "source", fields: &[]}, FieldName {key: 2, name: "target", fields: &[]}, FieldName {key: 3, name: "amount", fields: &[]}]}, FieldName {key: 99, name: "version", fields: &[]}] as &[FieldName]),
+        ("WavetableDeviceBox".to_string(), &[FieldName {key: 1, name: "host", fields: &[]}, FieldName {key: 2, name: "label", fields: &[]}, FieldName {key: 3, name: "icon", fields: &[]}, FieldName {key: 4, name: "enabled", fields: &[]}, FieldName {key: 5, name: "minimized", fields: &[]}, FieldName {key: 10, name: "volume", fields: &[]}, FieldName {key: 11, name: "file", fields: &[]}, FieldName {key: 12, name: "position", fields: &[]}, FieldName {key: 13, name: "position-envelope", fields: &[]}, FieldName {key: 14, name: "octave", fields: &[]}, FieldName {key: 15, name: "tune", fields: &[]}, FieldName {key: 16, name: "cutoff", fields: &[]}, FieldName {key: 17, name: "resonance", fields: &[]}, FieldName {key: 18, name: "filter-envelope", fields: &[]}, FieldName {key: 19, name: "attack", fields: &[]}, FieldName {key: 20, name: "decay", fields: &[]}, FieldName {key: 21, name: "sustain", fields: &[]}, FieldName {key: 22, name: "release", fields: &[]}, FieldName {key: 23, name: "glide-time", fields: &[]}, FieldName {key: 24, name: "voicing-mode", fields: &[]}, FieldName {key: 25, name: "unison-count", fields: &[]}, FieldName {key: 26, name: "unison-detune", fields: &[]}, FieldName {key: 27, name: "unison-stereo", fields: &[]}] as &[FieldName]),
+        ("FMSynthDeviceBox".to_string(), &[FieldName {key: 1, name: "host", fields: &[]}, FieldName {key: 2, name: "label", fields: &[]}, FieldName {key: 3, name: "icon", fields: &[]}, FieldName {key: 4, name: "enabled", fields: &[]}, FieldName {key: 5, name: "minimized", fields: &[]}, FieldName {key: 10, name: "volume", fields: &[]}, FieldName {key: 11, name: "algorithm", fields: &[]}, FieldName {key: 12, name: "feedback", fields: &[]}, FieldName {key: 13, name: "glide-time", fields: &[]}, FieldName {key: 14, name: "voicing-mode", fields: &[]}, FieldName {key: 40, name: "operators", fields: &[FieldName {key: 1, name: "ratio", fields: &[]}, FieldName {key: 2, name: "fine", fields: &[]}, FieldName {key: 3, name: "level", fields: &[]}, FieldName {key: 4, name: "attack", fields: &[]}, FieldName {key: 5, name: "decay", fields: &[]}, FieldName {key: 6, name: "sustain", fields: &[]}, FieldName {key: 7, name: "release", fields: &[]}, FieldName {key: 8, name: "velocity", fields: &[]}, FieldName {key: 9, name: "key-scaling", fields: &[]}]}] as &[FieldName]),
+        ("MIDIOutputDeviceBox".to_string(), &[FieldName {key: 1, name: "host", fields: &[]}, FieldName {key: 2, name: "label", fields: &[]}, FieldName {key: 3, name: "icon", fields: &[]}, FieldName {key: 4, name: "enabled", fields: &[]}, FieldName {key: 5, name: "minimized", fields: &[]}, FieldName {key: 11, name: "channel", fields: &[]}, FieldName {key: 13, name: "parameters", fields: &[]}, FieldName {key: 14, name: "device", fields: &[]}] as &[FieldName]),
+        ("MIDIOutputBox".to_string(), &[FieldName {key: 1, name: "root", fields: &[]}, FieldName {key: 2, name: "device", fields: &[]}, FieldName {key: 3, name: "id", fields: &[]}, FieldName {key: 4, name: "label", fields: &[]}, FieldName {key: 5, name: "delayInMs", fields: &[]}, FieldName {key: 6, name: "send-transport-messages", fields: &[]}] as &[FieldName]),
+        ("MIDIOutputParameterBox".to_string(), &[FieldName {key: 1, name: "owner", fields: &[]}, FieldName {key: 2, name: "label", fields: &[]}, FieldName {key: 3, name: "controller", fields: &[]}, FieldName {key: 4, name: "value", fields: &[]}] as &[FieldName]),
+        ("SoundfontDeviceBox".to_string(), &[FieldName {key: 1, name: "host", fields: &[]}, FieldName {key: 2, name: "label", fields: &[]}, FieldName {key: 3, name: "icon", fields: &[]}, FieldName {key: 4, name: "enabled", fields: &[]}, FieldName {key: 5, name: "minimized", fields: &[]}, FieldName {key: 10, name: "file", fields: &[]}, FieldName {key: 11, name: "preset-index", fields: &[]}] as &[FieldName]),
+        ("NanoDeviceBox".to_string(), &[FieldName {key: 1, name: "host", fields: &[]}, FieldName {key: 2, name: "label", fields: &[]}, FieldName {key: 3, name: "icon", fields: &[]}, FieldName {key: 4, name: "enabled", fields: &[]}, FieldName {key: 5, name: "minimized", fields: &[]}, FieldName {key: 10, name: "volume", fields: &[]}, FieldName {key: 15, name: "file", fields: &[]}, FieldName {key: 20, name: "release", fields: &[]}, FieldName {key: 25, name: "interpolation", fields: &[]}] as &[FieldName]),
+        ("PlayfieldDeviceBox".to_string(), &[FieldName {key: 1, name: "host", fields: &[]}, FieldName {key: 2, name: "label", fields: &[]}, FieldName {key: 3, name: "icon", fields: &[]}, FieldName {key: 4, name: "enabled", fields: &[]}, FieldName {key: 5, name: "minimized", fields: &[]}, FieldName {key: 10, name: "samples", fields: &[]}] as &[FieldName]),
+        ("PlayfieldSampleBox".to_string(), &[FieldName {key: 10, name: "device", fields: &[]}, FieldName {key: 11, name: "file", fields: &[]}, FieldName {key: 12, name: "midi-effects", fields: &[]}, FieldName {key: 13, name: "audio-effects", fields: &[]}, FieldName {key: 15, name: "index", fields: &[]}, FieldName {key: 21, name: "icon", fields: &[]}, FieldName {key: 22, name: "enabled", fields: &[]}, FieldName {key: 23, name: "minimized", fields: &[]}, FieldName {key: 40, name: "mute", fields: &[]}, FieldName {key: 41, name: "solo", fields: &[]}, FieldName {key: 42, name: "exclude", fields: &[]}, FieldName {key: 43, name: "polyphone", fields: &[]}, FieldName {key: 44, name: "gate", fields: &[]}, FieldName {key: 45, name: "pitch", fields: &[]}, FieldName {key: 46, name: "sample-start", fields: &[]}, FieldName {key: 47, name: "sample-end", fields: &[]}, FieldName {key: 48, name: "attack", fields: &[]}, FieldName {key: 49, name: "release", fields: &[]}, FieldName {key: 50, name: "interpolation", fields: &[]}] as &[FieldName]),
+        ("TapeDeviceBox".to_string(), &[FieldName {key: 1, name: "host", fields: &[]}, FieldName {key: 2, name: "label", fields: &[]}, FieldName {key: 3, name: "icon", fields: &[]}, FieldName {key: 4, name: "enabled", fields: &[]}, FieldName {key: 5, name: "minimized", fields: &[]}, FieldName {key: 10, name: "flutter", fields: &[]}, FieldName {key: 11, name: "wow", fields: &[]}, FieldName {key: 12, name: "noise", fields: &[]}, FieldName {key: 13, name: "saturation", fields: &[]}] as &[FieldName]),
+        ("ArpeggioDeviceBox".to_string(), &[FieldName {key: 1, name: "host", fields: &[]}, FieldName {key: 2, name: "index", fields: &[]}, FieldName {key: 3, name: "label", fields: &[]}, FieldName {key: 4, name: "enabled", fields: &[]}, FieldName {key: 5, name: "minimized", fields: &[]}, FieldName {key: 10, name: "mode-index", fields: &[]}, FieldName {key: 11, name: "num-octaves", fields: &[]}, FieldName {key: 12, name: "rate-index", fields: &[]}, FieldName {key: 13, name: "gate", fields: &[]}, FieldName {key: 14, name: "repeat", fields: &[]}, FieldName {key: 15, name: "velocity", fields: &[]}, FieldName {key: 16, name: "pattern-length", fields: &[]}, FieldName {key: 17, name: "pattern", fields: &[FieldName {key: 1, name: "velocity", fields: &[]}, FieldName {key: 2, name: "gate", fields: &[]}, FieldName {key: 3, name: "tie", fields: &[]}, FieldName {key: 4, name: "rest", fields: &[]}]}] as &[FieldName]),
+        ("PitchDeviceBox".to_string(), &[FieldName {key: 1, name: "host", fields: &[]}, FieldName {key: 2, name: "index", fields: &[]}, FieldName {key: 3, name: "label", fields: &[]}, FieldName {key: 4, name: "enabled", fields: &[]}, FieldName {key: 5, name: "minimized", fields: &[]}, FieldName {key: 10, name: "semi-tones", fields: &[]}, FieldName {key: 11, name: "cents", fields: &[]}, FieldName {key: 12, name: "octaves", fields: &[]}] as &[FieldName]),
+        ("ScaleDeviceBox".to_string(), &[FieldName {key: 1, name: "host", fields: &[]}, FieldName {key: 2, name: "index", fields: &[]}, FieldName {key: 3, name: "label", fields: &[]}, FieldName {key: 4, name: "enabled", fields: &[]}, FieldName {key: 5, name: "minimized", fields: &[]}, FieldName {key: 10, name: "key", fields: &[]}, FieldName {key: 11, name: "scale", fields: &[]}, FieldName {key: 12, name: "chord", fields: &[]}, FieldName {key: 13, name: "inversion", fields: &[]}, FieldName {key: 14, name: "strum", fields: &[]}] as &[FieldName]),
+        ("RatchetDeviceBox".to_string(), &[FieldName {key: 1, name: "host", fields: &[]}, FieldName {key: 2, name: "index", fields: &[]}, FieldName {key: 3, name: "label", fields: &[]}, FieldName {key: 4, name: "enabled", fields: &[]}, FieldName {key: 5, name: "minimized", fields: &[]}, FieldName {key: 10, name: "rate-index", fields: &[]}, FieldName {key: 11, name: "gate", fields: &[]}, FieldName {key: 12, name: "velocity-ramp", fields: &[]}, FieldName {key: 13, name: "ratchet-length", fields: &[]}, FieldName {key: 14, name: "ratchets", fields: &[FieldName {key: 1, name: "count", fields: &[]}]}] as &[FieldName]),
+        ("ZeitgeistDeviceBox".to_string(), &[FieldName {key: 1, name: "host", fields: &[]}, FieldName {key: 2, name: "index", fields: &[]}, FieldName {key: 3, name: "label", fields: &[]}, FieldName {key: 4, name: "enabled", fields: &[]}, FieldName {key: 5, name: "minimized", fields: &[]}, FieldName {key: 10, name: "groove", fields: &[]}] as &[FieldName]),
+        ("NeuralAmpDeviceBox".to_string(), &[FieldName {key: 1, name: "host", fields: &[]}, FieldName {key: 2, name: "index", fields: &[]}, FieldName {key: 3, name: "label", fields: &[]}, FieldName {key: 4, name: "enabled", fields: &[]}, FieldName {key: 5, name: "minimized", fields: &[]}, FieldName {key: 11, name: "input-gain", fields: &[]}, FieldName {key: 12, name: "output-gain", fields: &[]}, FieldName {key: 13, name: "mono", fields: &[]}, FieldName {key: 14, name: "mix", fields: &[]}, FieldName {key: 20, name: "model", fields: &[]}] as &[FieldName]),
+        ("VocoderDeviceBox".to_string(), &[FieldName {key: 1, name: "host", fields: &[]}, FieldName {key: 2, name: "index", fields: &[]}, FieldName {key: 3, name: "label", fields: &[]}, FieldName {key: 4, name: "enabled", fields: &[]}, FieldName {key: 5, name: "minimized", fields: &[]}, FieldName {key: 10, name: "carrier-min-freq", fields: &[]}, FieldName {key: 11, name: "carrier-max-freq", fields: &[]}, FieldName {key: 12, name: "modulator-min-freq", fields: &[]}, FieldName {key: 13, name: "modulator-max-freq", fields: &[]}, FieldName {key: 14, name: "q-end", fields: &[]}, FieldName {key: 15, name: "q-start", fields: &[]}, FieldName {key: 16, name: "env-release", fields: &[]}, FieldName {key: 17, name: "mix", fields: &[]}, FieldName {key: 18, name: "band-count", fields: &[]}, FieldName {key: 19, name: "modulator-source", fields: &[]}, FieldName {key: 20, name: "env-attack", fields: &[]}, FieldName {key: 21, name: "gain", fields: &[]}, FieldName {key: 30, name: "side-chain", fields: &[]}] as &[FieldName]),
+        ("WaveshaperDeviceBox".to_string(), &[FieldName {key: 1, name: "host", fields: &[]}, FieldName {key: 2, name: "index", fields: &[]}, FieldName {key: 3, name: "label", fields: &[]}, FieldName {key: 4, name: "enabled", fields: &[]}, FieldName {key: 5, name: "minimized", fields: &[]}, FieldName {key: 10, name: "equation", fields: &[]}, FieldName {key: 11, name: "input-gain", fields: &[]}, FieldName {key: 12, name: "output-gain", fields: &[]}, FieldName {key: 13, name: "mix", fields: &[]}] as &[FieldName]),
+        ("WerkstattDeviceBox".to_string(), &[FieldName {key: 1, name: "host", fields: &[]}, FieldName {key: 2, name: "index", fields: &[]}, FieldName {key: 3, name: "label", fields: &[]}, FieldName {key: 4, name: "enabled", fields: &[]}, FieldName {key: 5, name: "minimized", fields: &[]}, FieldName {key: 10, name: "code", fields: &[]}, FieldName {key: 11, name: "parameters", fields: &[]}, FieldName {key: 12, name: "samples", fields: &[]}] as &[FieldName]),
+        ("WerkstattParameterBox".to_string(), &[FieldName {key: 1, name: "owner", fields: &[]}, FieldName {key: 2, name: "label", fields: &[]}, FieldName {key: 3, name: "index", fields: &[]}, FieldName {key: 4, name: "value", fields: &[]}, FieldName {key: 5, name: "defaultValue", fields: &[]}] as &[FieldName]),
+        ("WerkstattSampleBox".to_string(), &[FieldName {key: 1, name: "owner", fields: &[]}, FieldName {key: 2, name: "label", fields: &[]}, FieldName {key: 3, name: "index", fields: &[]}, FieldName {key: 4, name: "file", fields: &[]}] as &[FieldName]),
+        ("SpielwerkDeviceBox".to_string(), &[FieldName {key: 1, name: "host", fields: &[]}, FieldName {key: 2, name: "index", fields: &[]}, FieldName {key: 3, name: "label", fields: &[]}, FieldName {key: 4, name: "enabled", fields: &[]}, FieldName {key: 5, name: "minimized", fields: &[]}, FieldName {key: 10, name: "code", fields: &[]}, FieldName {key: 11, name: "parameters", fields: &[]}, FieldName {key: 12, name: "samples", fields: &[]}] as &[FieldName]),
+        ("ApparatDeviceBox".to_string(), &[FieldName {key: 1, name: "host", fields: &[]}, FieldName {key: 2, name: "label", fields: &[]}, FieldName {key: 3, name: "icon", fields: &[]}, FieldName {key: 4, name: "enabled", fields: &[]}, FieldName {key: 5, name: "minimized", fields: &[]}, FieldName {key: 10, name: "code", fields: &[]}, FieldName {key: 11, name: "parameters", fields: &[]}, FieldName {key: 12, name: "samples", fields: &[]}] as &[FieldName]),
+        ("NoopInstrumentBox".to_string(), &[FieldName {key: 1, name: "host", fields: &[]}, FieldName {key: 2, name: "label", fields: &[]}, FieldName {key: 3, name: "icon", fields: &[]}, FieldName {key: 4, name: "enabled", fields: &[]}, FieldName {key: 5, name: "minimized", fields: &[]}] as &[FieldName]),
+        ("CompositeDeviceBox".to_string(), &[FieldName {key: 1, name: "host", fields: &[]}, FieldName {key: 2, name: "label", fields: &[]}, FieldName {key: 3, name: "icon", fields: &[]}, FieldName {key: 4, name: "enabled", fields: &[]}, FieldName {key: 5, name: "minimized", fields: &[]}, FieldName {key: 10, name: "cells", fields: &[]}] as &[FieldName]),
+        ("CompositeCellBox".to_string(), &[FieldName {key: 1, name: "composite", fields: &[]}, FieldName {key: 2, name: "instrument", fields: &[]}, FieldName {key: 3, name: "midi-effects", fields: &[]}, FieldName {key: 4, name: "audio-effects", fields: &[]}, FieldName {key: 5, name: "index", fields: &[]}] as &[FieldName]),
+        ("AudioEffectCompositeBox".to_string(), &[FieldName {key: 1, name: "host", fields: &[]}, FieldName {key: 2, name: "index", fields: &[]}, FieldName {key: 3, name: "label", fields: &[]}, FieldName {key: 4, name: "enabled", fields: &[]}, FieldName {key: 5, name: "minimized", fields: &[]}, FieldName {key: 10, name: "entries", fields: &[]}, FieldName {key: 11, name: "input", fields: &[]}, FieldName {key: 12, name: "dry", fields: &[]}, FieldName {key: 13, name: "wet", fields: &[]}] as &[FieldName]),
+        ("AudioEffectCompositeCellBox".to_string(), &[FieldName {key: 1, name: "composite", fields: &[]}, FieldName {key: 2, name: "audio-effects", fields: &[]}, FieldName {key: 3, name: "index", fields: &[]}, FieldName {key: 4, name: "label", fields: &[]}, FieldName {key: 5, name: "minimized", fields: &[]}, FieldName {key: 40, name: "gain", fields: &[]}, FieldName {key: 41, name: "mute", fields: &[]}, FieldName {key: 42, name: "solo", fields: &[]}, FieldName {key: 43, name: "pan", fields: &[]}] as &[FieldName]),
+        ("StereoCompositeBox".to_string(), &[FieldName {key: 1, name: "host", fields: &[]}, FieldName {key: 2, name: "index", fields: &[]}, FieldName {key: 3, name: "label", fields: &[]}, FieldName {key: 4, name: "enabled", fields: &[]}, FieldName {key: 5, name: "minimized", fields: &[]}, FieldName {key: 10, name: "entries", fields: &[]}, FieldName {key: 11, name: "input", fields: &[]}, FieldName {key: 12, name: "dry", fields: &[]}, FieldName {key: 13, name: "wet", fields: &[]}] as &[FieldName]),
+        ("FrequencySplitBox".to_string(), &[FieldName {key: 1, name: "host", fields: &[]}, FieldName {key: 2, name: "index", fields: &[]}, FieldName {key: 3, name: "label", fields: &[]}, FieldName {key: 4, name: "enabled", fields: &[]}, FieldName {key: 5, name: "minimized", fields: &[]}, FieldName {key: 10, name: "entries", fields: &[]}, FieldName {key: 11, name: "input", fields: &[]}, FieldName {key: 12, name: "dry", fields: &[]}, FieldName {key: 13, name: "wet", fields: &[]}, FieldName {key: 14, name: "crossover1", fields: &[]}, FieldName {key: 15, name: "crossover2", fields: &[]}, FieldName {key: 16, name: "crossover3", fields: &[]}] as &[FieldName]),
+        ("ModularBox".to_string(), &[FieldName {key: 1, name: "collection", fields: &[]}, FieldName {key: 2, name: "device", fields: &[]}, FieldName {key: 3, name: "editing", fields: &[]}, FieldName {key: 11, name: "modules", fields: &[]}, FieldName {key: 12, name: "connections", fields: &[]}, FieldName {key: 13, name: "label", fields: &[]}] as &[FieldName]),
+        ("ModuleConnectionBox".to_string(), &[FieldName {key: 1, name: "collection", fields: &[]}, FieldName {key: 2, name: "source", fields: &[]}, FieldName {key: 3, name: "target", fields: &[]}] as &[FieldName]),
+        ("ModularAudioInputBox".to_string(), &[FieldName {key: 1, name: "attributes", fields: &[FieldName {key: 1, name: "collection", fields: &[]}, FieldName {key: 2, name: "label", fields: &[]}, FieldName {key: 3, name: "x", fields: &[]}, FieldName {key: 4, name: "y", fields: &[]}, FieldName {key: 5, name: "collapsed", fields: &[]}, FieldName {key: 6, name: "removable", fields: &[]}]}, FieldName {key: 10, name: "output", fields: &[]}] as &[FieldName]),
+        ("ModularAudioOutputBox".to_string(), &[FieldName {key: 1, name: "attributes", fields: &[FieldName {key: 1, name: "collection", fields: &[]}, FieldName {key: 2, name: "label", fields: &[]}, FieldName {key: 3, name: "x", fields: &[]}, FieldName {key: 4, name: "y", fields: &[]}, FieldName {key: 5, name: "collapsed", fields: &[]}, FieldName {key: 6, name: "removable", fields: &[]}]}, FieldName {key: 10, name: "input", fields: &[]}] as &[FieldName]),
+        ("ModuleDelayBox".to_string(), &[FieldName {key: 1, name: "attributes", fields: &[FieldName {key: 1, name: "collection", fields: &[]}, FieldName {key: 2, name: "label", fields: &[]}, FieldName {key: 3, name: "x", fields: &[]}, FieldName {key: 4, name: "y", fields: &[]}, FieldName {key: 5, name: "collapsed", fields: &[]}, FieldName {key: 6, name: "removable", fields: &[]}]}, FieldName {key: 10, name: "voltage-input", fields: &[]}, FieldName {key: 11, name: "voltage-output", fields: &[]}, FieldName {key: 20, name: "time", fields: &[]}] as &[FieldName]),
+        ("ModuleMultiplierBox".to_string(), &[FieldName {key: 1, name: "attributes", fields: &[FieldName {key: 1, name: "collection", fields: &[]}, FieldName {key: 2, name: "label", fields: &[]}, FieldName {key: 3, name: "x", fields: &[]}, FieldName {key: 4, name: "y", fields: &[]}, FieldName {key: 5, name: "collapsed", fields: &[]}, FieldName {key: 6, name: "removable", fields: &[]}]}, FieldName {key: 10, name: "voltage-input-x", fields: &[]}, FieldName {key: 11, name: "voltage-input-y", fields: &[]}, FieldName {key: 12, name: "voltage-output", fields: &[]}, FieldName {key: 20, name: "multiplier", fields: &[]}] as &[FieldName]),
+        ("ModuleGainBox".to_string(), &[FieldName {key: 1, name: "attributes", fields: &[FieldName {key: 1, name: "collection", fields: &[]}, FieldName {key: 2, name: "label", fields: &[]}, FieldName {key: 3, name: "x", fields: &[]}, FieldName {key: 4, name: "y", fields: &[]}, FieldName {key: 5, name: "collapsed", fields: &[]}, FieldName {key: 6, name: "removable", fields: &[]}]}, FieldName {key: 10, name: "voltage-input", fields: &[]}, FieldName {key: 12, name: "voltage-output", fields: &[]}, FieldName {key: 20, name: "gain", fields: &[]}] as &[FieldName]),
+    ])
+}
@@ -0,0 +1,69 @@
+//! The JSON codec over every test project: `to_json` → `from_json` must restore the binary graph byte for
+//! byte (and so its checksum), with every field printed by name.
+
+use std::fs;
+use std::path::Path;
+use boxgraph::bytes::ByteReader;
+use boxgraph::graph::BoxGraph;
+use studio_boxes::{names, registry};
+
+const MAGIC_OPEN: i32 = 0x4F50_454E;
+const FORMAT_VERSION: i32 = 2;
+
+fn od_files() -> Vec<String> {
+    let directory = Path::new(env!("CARGO_MANIFEST_DIR")).join("../../test-files");
+    let mut files: Vec<String> = fs::read_dir(&directory).unwrap()
+        .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+        .filter(|file| file.ends_with(".od"))
+        .collect();
+    files.sort();
+    files
+}
+
+fn load_chunk(file: &str) -> Vec<u8> {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("../../test-files").join(file);
+    let bytes = fs::read(&path).unwrap_or_else(|error| panic!("read {path:?}: {error}"));
+    let mut reader = ByteReader::new(&bytes);
+    assert_eq!(reader.read_int().unwrap(), MAGIC_OPEN, "magic OPEN");
+    assert_eq!(reader.read_int().unwrap(), FORMAT_VERSION, "format version");
+    let length = reader.read_int().unwrap() as usize;
+    reader.read_raw(length).unwrap()
+}
+
+#[test]
+fn names_cover_every_box() {
+    let names = names();
+    for name in registry().keys() {
+        assert!(names.contains_key(name), "no field names for {name}");
+    }
+}
+
+#[test]
+fn every_project_round_trips_through_json() {
+    let (registry, names) = (registry(), names());
+    let files = od_files();
+    assert!(files.len() >= 10, "expected the test projects, found {files:?}");
+    for file in files {
+        let chunk = load_chunk(&file);
+        let graph = BoxGraph::from_bytes(&chunk, &registry).unwrap();
+        let json = graph.to_json(&names);
+        let restored = BoxGraph::from_json(&json, &registry, &names).unwrap_or_else(|error| panic!("{file}: {error:?}"));
+        assert_eq!(restored.checksum(), graph.checksum(), "{file}: checksum");
+        assert_eq!(restored.to_bytes(), graph.to_bytes(), "{file}: bytes");
+        assert_eq!(restored.to_json(&names), json, "{file}: json");
+    }
+}
+
+#[test]
+fn fields_are_printed_by_name() {
+    let (registry, names) = (registry(), names());
+    let graph = BoxGraph::from_bytes(&load_chunk("all-boxes.od"), &registry).unwrap();
+    let json = graph.to_json(&names);
+    let numeric = json.lines().find(|line| {
+        let key = line.trim_start().strip_prefix('"').and_then(|rest| rest.split_once("\": ")).map(|(key, _)| key);
+        key.is_some_and(|key| key.parse::<u16>().is_ok())
+    });
+    assert_eq!(numeric, None, "a field without a name");
+    assert!(json.contains("\"name\": \"RootBox\""));
+    assert!(json.contains("\"timeline\": "));
+}
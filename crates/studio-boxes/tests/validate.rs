@@ -0,0 +1,54 @@
+//! Checks the generated pointer rules against the test projects: every project saved by the studio
+//! satisfies them, and the best-effort wiring of `all-boxes.od` (which does violate them) repairs to a
+//! graph that does.
+
+use std::fs;
+use std::path::Path;
+use boxgraph::bytes::ByteReader;
+use boxgraph::graph::BoxGraph;
+use studio_boxes::{registry, rules};
+
+const MAGIC_OPEN: i32 = 0x4F50_454E;
+const FORMAT_VERSION: i32 = 2;
+const PROJECTS: [&str; 10] = [
+    "80s.od", "atstil.od", "audio-bug.od", "automation-bug.od", "breakit.od",
+    "env-bug.od", "indahouse.od", "openup.od", "overlap.od", "video.od"
+];
+
+fn load_graph(file: &str) -> BoxGraph {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("../../test-files").join(file);
+    let bytes = fs::read(&path).unwrap_or_else(|error| panic!("read {path:?}: {error}"));
+    let mut reader = ByteReader::new(&bytes);
+    assert_eq!(reader.read_int().unwrap(), MAGIC_OPEN, "magic OPEN");
+    assert_eq!(reader.read_int().unwrap(), FORMAT_VERSION, "format version");
+    let length = reader.read_int().unwrap() as usize;
+    BoxGraph::from_bytes(&reader.read_raw(length).unwrap(), &registry()).unwrap_or_else(|error| panic!("parse {file}: {error:?}"))
+}
+
+#[test]
+fn rules_cover_every_box() {
+    let rules = rules();
+    for name in registry().keys() {
+        assert!(rules.contains_key(name), "no rules for {name}");
+    }
+}
+
+#[test]
+fn studio_projects_satisfy_the_rules() {
+    let rules = rules();
+    for file in PROJECTS {
+        let violations = load_graph(file).validate(&rules);
+        assert!(violations.is_empty(), "{file}: {} violation(s), first: {:?}", violations.len(), violations.first());
+    }
+}
+
+#[test]
+fn all_boxes_fixture_repairs_to_a_valid_graph() {
+    let (registry, rules) = (registry(), rules());
+    let mut graph = load_graph("all-boxes.od");
+    assert!(!graph.validate(&rules).is_empty(), "the fixture wires pointers best-effort");
+    let updates = graph.repair(&registry, &rules).unwrap();
+    assert!(!updates.is_empty());
+    assert_eq!(graph.validate(&rules), vec![]);
+    assert!(graph.dangling().is_empty());
+}
@@ -1,11 +1,13 @@
 //! Transport over a 128-sample render quantum. `process_quantum` is the fixed-bpm fast path (one
 //! block); `render_quantum` is the block loop: it splits the quantum at the nearest action — a
-//! marker jump (the marker track's section repeats), the loop-area end, or a tempo-change grid
-//! (where a `ValueEvent` bpm map changes the bpm). At a loop end or a marker jump it emits the
+//! marker jump (the marker track's section repeats), the loop-area end, a punch-in / punch-out
+//! point, or a tempo-change grid (where a `ValueEvent` bpm map changes the bpm). At a loop end or a marker jump it emits the
 //! partial block, jumps the position back (loop start / section start), re-evaluates the bpm there
 //! (the discontinuity), and keeps filling the quantum, so a wrap is sample-accurate with no gap.
 //! Mirrors core-processors `BlockRenderer`, including its action precedence: markers are evaluated
 //! first, the loop takes over only when strictly earlier, tempo only when strictly earlier than both.
+//! The punch points (no TS counterpart) only split: every block lies wholly inside or outside the punch
+//! range, so a recorder can gate takes per block.
 
 use engine_env::ppqn::{pulses_to_samples, samples_to_pulses};
 use value::event::EventCollection;
@@ -27,7 +29,8 @@ fn quantize_ceil(position: f64, grid: f64) -> f64 {
 
 /// A processed slice of one quantum: pulse range `[p0, p1)` over sample range `[s0, s1)` at `bpm`.
 /// `discontinuous` is true for the first block after a position jump (a loop wrap), the Rust analog
-/// of the TS `BlockFlag.discontinuous`, so consumers can release state held across the jump.
+/// of the TS `BlockFlag.discontinuous`, so consumers can release state held across the jump. `punch`
+/// is true when the punch range is enabled and the block lies inside it.
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct Block {
     pub p0: f64,
@@ -36,6 +39,7 @@ pub struct Block {
     pub s1: usize,
     pub bpm: f32,
     pub discontinuous: bool,
+    pub punch: bool,
 }
 
 /// A timeline marker (the `MarkerBox` essentials): a section start plus how often the section plays
@@ -56,12 +60,13 @@ fn floor_last_index(markers: &[Marker], position: f64) -> isize {
 }
 
 /// The nearest event that splits a sub-block: a marker boundary (the index into the marker slice the
-/// action was evaluated from), a bpm change at a tempo grid, or the loop-area end.
+/// action was evaluated from), a bpm change at a tempo grid, the loop-area end, or a punch point.
 enum Action {
     None,
     Marker(usize),
     Tempo(f32),
     Loop,
+    Punch,
 }
 
 pub struct Transport {
@@ -77,6 +82,9 @@ pub struct Transport {
     loop_enabled: bool,
     loop_from: f64, // pulses
     loop_to: f64,   // pulses
+    punch_enabled: bool,
+    punch_from: f64, // pulses, the punch-in
+    punch_to: f64,   // pulses, the punch-out
     current_marker: Option<([u8; 16], i32)>, // the active marker + its plays SO FAR (TS `#currentMarker: [adapter, int]`)
     markers_dirty: bool, // the marker set was edited (TS `#someMarkersChanged`); the next block re-resolves the active marker
     marker_changed: bool, // the active marker state changed this quantum; the engine drains it into a switchMarkerState notification
@@ -85,7 +93,7 @@ pub struct Transport {
 impl Transport {
     pub fn new(sample_rate: f32, bpm: f32) -> Self {
         Self {position: 0.0, free_running: 0.0, bpm, nominal_bpm: bpm, sample_rate, playing: false, loop_pause: false, leap: false, loop_enabled: false, loop_from: 0.0, loop_to: 0.0,
-            current_marker: None, markers_dirty: false, marker_changed: false}
+            punch_enabled: false, punch_from: 0.0, punch_to: 0.0, current_marker: None, markers_dirty: false, marker_changed: false}
     }
 
     pub fn position(&self) -> f64 {self.position}
@@ -102,8 +110,16 @@ impl Transport {
     pub fn set_loop_pause(&mut self, pause: bool) {self.loop_pause = pause}
     pub fn set_loop_from(&mut self, from: f64) {self.loop_from = from}
     pub fn set_loop_to(&mut self, to: f64) {self.loop_to = to}
+    pub fn set_punch_enabled(&mut self, enabled: bool) {self.punch_enabled = enabled}
+    pub fn set_punch_from(&mut self, from: f64) {self.punch_from = from}
+    pub fn set_punch_to(&mut self, to: f64) {self.punch_to = to}
     pub fn play(&mut self) {self.playing = true}
 
+    /// The punch-in / punch-out positions while punching is enabled and the range is not empty.
+    pub fn punch_range(&self) -> Option<(f64, f64)> {
+        (self.punch_enabled && self.punch_from < self.punch_to).then_some((self.punch_from, self.punch_to))
+    }
+
     /// The marker collection was edited (TS `markerTrack.subscribe` -> `#someMarkersChanged`): the next
     /// rendered block re-resolves the active marker at its start position.
     pub fn notify_markers_changed(&mut self) {self.markers_dirty = true}
@@ -161,7 +177,7 @@ impl Transport {
         let p0 = self.free_running;
         let p1 = p0 + samples_to_pulses(RENDER_QUANTUM as f64, self.bpm, self.sample_rate);
         self.free_running = p1;
-        Block {p0, p1, s0: 0, s1: RENDER_QUANTUM, bpm: self.bpm, discontinuous: false}
+        Block {p0, p1, s0: 0, s1: RENDER_QUANTUM, bpm: self.bpm, discontinuous: false, punch: false}
     }
 
     /// The free-running pulse range for a PARTIAL paused tail of `samples` (a `pauseOnLoopDisabled`
@@ -170,7 +186,7 @@ impl Transport {
         let p0 = self.free_running;
         let p1 = p0 + samples_to_pulses(samples as f64, self.bpm, self.sample_rate);
         self.free_running = p1;
-        Block {p0, p1, s0: 0, s1: samples, bpm: self.bpm, discontinuous: false}
+        Block {p0, p1, s0: 0, s1: samples, bpm: self.bpm, discontinuous: false, punch: false}
     }
 
     /// Advance one 128-sample quantum and return its block. Fixed bpm with no events → exactly one
@@ -180,7 +196,7 @@ impl Transport {
         let p0 = self.position;
         let p1 = p0 + samples_to_pulses(RENDER_QUANTUM as f64, self.bpm, self.sample_rate);
         self.position = p1;
-        Block {p0, p1, s0: 0, s1: RENDER_QUANTUM, bpm: self.bpm, discontinuous: false}
+        Block {p0, p1, s0: 0, s1: RENDER_QUANTUM, bpm: self.bpm, discontinuous: false, punch: self.punched(p0)}
     }
 
     /// Render one quantum into `emit`, splitting at the nearest action: a marker boundary (when the
@@ -255,6 +271,16 @@ impl Transport {
                 action_position = self.loop_to;
                 action = Action::Loop;
             }
+            // --- PUNCH --- a punch point strictly inside the block and strictly earlier splits it, so no block
+            // straddles the punch range's edges. A point on a loop end or marker boundary needs no split.
+            if let Some((from, to)) = self.punch_range() {
+                for point in [from, to] {
+                    if p0 < point && point < p1 && point < action_position {
+                        action_position = point;
+                        action = Action::Punch;
+                    }
+                }
+            }
             // --- TEMPO AUTOMATION --- evaluated LAST, strictly-earlier only (TS order): the loop keeps
             // winning the grid-on-loop-end tie (applying the tempo change there would advance the position
             // onto `loop_to` and the wrap would never fire; bpm is re-evaluated at the loop start anyway).
@@ -273,7 +299,7 @@ impl Transport {
             match action {
                 Action::None => {
                     let s1 = s0 + sn;
-                    emit(&Block {p0, p1, s0, s1, bpm: self.bpm, discontinuous});
+                    emit(&Block {p0, p1, s0, s1, bpm: self.bpm, discontinuous, punch: self.punched(p0)});
                     discontinuous = false;
                     p0 = p1;
                     s0 = s1;
@@ -310,6 +336,14 @@ impl Transport {
                     p0 = action_position;
                     self.bpm = new_bpm;
                 }
+                Action::Punch => {
+                    let s1 = self.emit_until(action_position, p0, s0, discontinuous, &mut emit);
+                    if s1 > s0 {
+                        discontinuous = false;
+                    }
+                    s0 = s1;
+                    p0 = action_position;
+                }
                 Action::Loop => {
                     // the partial block up to the loop end carries the current flag; the next block,
                     // resuming at the loop start, is the discontinuity.
@@ -338,11 +372,16 @@ impl Transport {
     fn emit_until<F: FnMut(&Block)>(&self, action_position: f64, p0: f64, s0: usize, discontinuous: bool, emit: &mut F) -> usize {
         let s1 = s0 + pulses_to_samples(action_position - p0, self.bpm, self.sample_rate) as i64 as usize;
         if s1 > s0 {
-            emit(&Block {p0, p1: action_position, s0, s1, bpm: self.bpm, discontinuous});
+            emit(&Block {p0, p1: action_position, s0, s1, bpm: self.bpm, discontinuous, punch: self.punched(p0)});
         }
         s1
     }
 
+    /// Whether a block starting at `p0` lies inside the punch range (blocks never straddle its edges).
+    fn punched(&self, p0: f64) -> bool {
+        self.punch_range().is_some_and(|(from, to)| from <= p0 && p0 < to)
+    }
+
     /// Set the live bpm at `position`: the tempo map's value when automating (falling back to the
     /// nominal bpm), otherwise the nominal bpm itself. So with no tempo map the live bpm is always the
     /// configured `TimelineBox.bpm`, with no stale value left over from a previous automated pass.
@@ -0,0 +1,86 @@
+//! The punch range in the block loop: blocks split at the punch-in and punch-out so none straddles an
+//! edge, each carries whether it lies inside, and a loop wrap re-enters the range on every pass.
+
+use transport::transport::{Block, Transport, RENDER_QUANTUM};
+
+// 120 bpm @ 48k: a quantum spans 5.12 pulses.
+fn transport(from: f64, to: f64) -> Transport {
+    let mut transport = Transport::new(48_000.0, 120.0);
+    transport.set_punch_enabled(true);
+    transport.set_punch_from(from);
+    transport.set_punch_to(to);
+    transport
+}
+
+fn render(transport: &mut Transport) -> Vec<Block> {
+    let mut blocks = Vec::new();
+    transport.render_quantum(None, &[], false, |block| blocks.push(*block));
+    blocks
+}
+
+fn spans(blocks: &[Block]) -> Vec<(f64, f64, bool)> {
+    blocks.iter().map(|block| (block.p0, block.p1, block.punch)).collect()
+}
+
+#[test]
+fn splits_at_the_punch_in_and_out() {
+    let mut transport = transport(1.0, 3.0);
+    transport.play();
+    let blocks = render(&mut transport);
+    assert_eq!(spans(&blocks), vec![(0.0, 1.0, false), (1.0, 3.0, true), (3.0, 5.12, false)]);
+    assert_eq!((blocks[0].s1, blocks[1].s0, blocks[1].s1, blocks[2].s1), (25, 25, 75, RENDER_QUANTUM));
+    assert!(blocks.iter().all(|block| !block.discontinuous), "a punch point is no jump");
+    assert_eq!(transport.position(), 5.12);
+}
+
+#[test]
+fn a_block_inside_the_range_is_not_split() {
+    let mut transport = transport(0.0, 100.0);
+    transport.seek(10.0);
+    transport.play();
+    let blocks = render(&mut transport);
+    assert_eq!(blocks.len(), 1);
+    assert!(blocks[0].punch && blocks[0].discontinuous);
+    assert!(transport.process_quantum().punch, "the fast path flags the range too");
+}
+
+#[test]
+fn disabled_or_empty_ranges_never_punch() {
+    for (enabled, from, to) in [(false, 1.0, 3.0), (true, 3.0, 3.0), (true, 3.0, 1.0)] {
+        let mut transport = transport(from, to);
+        transport.set_punch_enabled(enabled);
+        transport.play();
+        let blocks = render(&mut transport);
+        assert_eq!(spans(&blocks), vec![(0.0, 5.12, false)], "{enabled} {from}..{to}");
+        assert_eq!(transport.punch_range(), None);
+    }
+}
+
+#[test]
+fn a_loop_wrap_re_enters_the_range() {
+    // punch 1..2 inside the loop 0..3: out, in, out up to the wrap, then out again from the loop start.
+    let mut transport = transport(1.0, 2.0);
+    transport.set_loop_enabled(true);
+    transport.set_loop_from(0.0);
+    transport.set_loop_to(3.0);
+    transport.play();
+    let blocks = render(&mut transport);
+    assert_eq!(spans(&blocks)[..5], [(0.0, 1.0, false), (1.0, 2.0, true), (2.0, 3.0, false), (0.0, 1.0, false), (1.0, 2.0, true)]);
+    assert!(blocks[3].discontinuous, "the pass after the wrap starts discontinuous");
+    assert!(!blocks[5].punch && blocks[5].p0 == 2.0);
+    let next = render(&mut transport);
+    let punched: Vec<bool> = next.iter().map(|block| block.punch).collect();
+    assert_eq!(punched[..4], [false, false, true, false], "the next pass punches in again");
+    assert!(next[1].discontinuous && next[1].p0 == 0.0);
+}
+
+#[test]
+fn a_punch_out_past_the_loop_end_ends_at_the_wrap() {
+    let mut transport = transport(2.0, 10.0);
+    transport.set_loop_enabled(true);
+    transport.set_loop_from(0.0);
+    transport.set_loop_to(3.0);
+    transport.play();
+    let blocks = render(&mut transport);
+    assert_eq!(spans(&blocks)[..3], [(0.0, 2.0, false), (2.0, 3.0, true), (0.0, 2.0, false)]);
+}
@@ -0,0 +1,56 @@
+//! ControlEvent, a channel event stored next to the notes of a collection (`NoteControlEventBox`): a
+//! MIDI CC, the pitch wheel or channel pressure at a position. Ordered by position only, so events at
+//! the same position keep their insertion order (the collection inserts after an equal-key run).
+
+use core::cmp::Ordering;
+use crate::event::{Event, ExactEq};
+
+/// What a control event changes (the box's `type`, `NoteControlType` in TS).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ControlKind {
+    /// A continuous controller (`0..=127`); the value is `0..1`.
+    Change(u8),
+    /// The pitch wheel; the value is bipolar `-1..1`.
+    PitchBend,
+    /// Channel aftertouch; the value is `0..1`.
+    Pressure
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct ControlEvent {
+    pub position: f64, // pulses (ppqn)
+    pub kind: ControlKind,
+    pub value: f32
+}
+
+impl Event for ControlEvent {
+    fn position(&self) -> f64 {
+        self.position
+    }
+}
+
+impl ExactEq for ControlEvent {
+    fn exact_eq(&self, other: &Self) -> bool {
+        self.position == other.position && self.kind == other.kind && self.value == other.value
+    }
+}
+
+impl PartialEq for ControlEvent {
+    fn eq(&self, other: &Self) -> bool {
+        self.position == other.position
+    }
+}
+
+impl Eq for ControlEvent {}
+
+impl PartialOrd for ControlEvent {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ControlEvent {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.position.total_cmp(&other.position)
+    }
+}
@@ -9,6 +9,7 @@
 
 extern crate alloc;
 
+pub mod control;
 pub mod event;
 pub mod note;
 pub mod region;
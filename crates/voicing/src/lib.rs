@@ -8,10 +8,14 @@
 //! held-note stack with legato glide); [`VoiceUnison`] (N detuned/spread sub-voices played as one note, itself
 //! a [`Voice`] so it nests into either strategy); and [`Voicing`], the runtime dispatcher that switches between
 //! the two strategies by [`VoicingMode`].
+//!
+//! Per-note expression (MPE / CLAP note expressions: tuning, pressure, brightness) reaches a voice through
+//! [`Voicing::express`]: the strategy finds the voice(s) playing that note id and hands each the decoded
+//! [`NoteExpression`] via [`Voice::express`].
 
 #![cfg_attr(not(test), no_std)]
 
-use abi::{Block, EventRecord};
+use abi::{Block, EventRecord, NoteExpression};
 
 /// One synth voice's per-note DSP, driven by a [`VoicingStrategy`]. The framework calls `start` when a note is
 /// assigned to this voice, `stop` on note-off (enter the release), `force_stop` when the voice is stolen, and
@@ -38,6 +42,11 @@ pub trait Voice {
     /// Render additively into the stereo `output` for one chunk, reading the device's live `shared` params;
     /// return `true` when fully finished (silent and released), so the pool frees the slot.
     fn process(&mut self, output: [&mut [f32]; 2], block: &Block, shared: &Self::Shared) -> bool;
+    /// Apply a per-note `expression` to this voice's note (held or releasing). It persists until the next
+    /// expression of the same kind; `start` resets every expression to rest. Default: ignored.
+    fn express(&mut self, expression: NoteExpression) {
+        let _ = expression;
+    }
 }
 
 /// A note-allocation strategy over a fixed set of [`Voice`]s (a port of TS `VoicingStrategy`), implemented by
@@ -54,6 +63,8 @@ pub trait VoicingStrategy {
     fn force_stop(&mut self);
     fn process(&mut self, output: [&mut [f32]; 2], block: &Block, shared: &<Self::Voice as Voice>::Shared) -> bool;
     fn reset(&mut self);
+    /// Route a per-note expression to the voice(s) sounding `note_id`; a note no longer sounding ignores it.
+    fn express(&mut self, note_id: i32, expression: NoteExpression);
 }
 
 /// One pool slot: a voice plus the note id it is playing and whether it is in use.
@@ -150,6 +161,14 @@ impl<V: Voice + Default, const VOICES: usize> VoicingStrategy for PolyphonicStra
             }
         }
     }
+
+    fn express(&mut self, note_id: i32, expression: NoteExpression) {
+        for slot in &mut self.slots {
+            if slot.active && slot.note_id == note_id {
+                slot.voice.express(expression);
+            }
+        }
+    }
 }
 
 /// `U` detuned/spread sub-voices played as ONE note (a port of TS `VoiceUnison`), and itself a [`Voice`] so it
@@ -235,13 +254,37 @@ impl<V: Voice + Default, const U: usize> Voice for VoiceUnison<V, U> {
         }
         finished
     }
+
+    fn express(&mut self, expression: NoteExpression) {
+        for voice in self.voices.iter_mut().take(self.active) {
+            voice.express(expression);
+        }
+    }
 }
 
-/// One held key: its note id and frequency, kept so releasing the top note can glide back to the one beneath.
+/// One held key: its note id, frequency and latest expressions, kept so releasing the top note can glide back
+/// to the one beneath and hand the voice that note's expression.
 #[derive(Clone, Copy, Default)]
 struct HeldNote {
     id: i32,
-    frequency: f32
+    frequency: f32,
+    tuning: f32,
+    pressure: f32,
+    brightness: f32
+}
+
+impl HeldNote {
+    fn new(id: i32, frequency: f32) -> Self {
+        Self {id, frequency, ..Self::default()}
+    }
+
+    fn remember(&mut self, expression: NoteExpression) {
+        match expression {
+            NoteExpression::Tuning(value) => self.tuning = value,
+            NoteExpression::Pressure(value) => self.pressure = value,
+            NoteExpression::Brightness(value) => self.brightness = value
+        }
+    }
 }
 
 /// The monophonic voice pool depth. TS holds an unbounded `#processing` array; here retrigger tails only
@@ -302,6 +345,13 @@ impl<V: Voice + Default, const STACK: usize> MonophonicStrategy<V, STACK> {
         }
     }
 
+    // Hand the voice to `note`: every expression is set, so nothing of the previous top note's lingers.
+    fn express_held(&mut self, voice: usize, note: HeldNote) {
+        self.voices[voice].express(NoteExpression::Tuning(note.tuning));
+        self.voices[voice].express(NoteExpression::Pressure(note.pressure));
+        self.voices[voice].express(NoteExpression::Brightness(note.brightness));
+    }
+
     fn allocate(&mut self) -> usize {
         if let Some(index) = self.processing.iter().position(|used| !used) {
             return index;
@@ -319,12 +369,13 @@ impl<V: Voice + Default, const STACK: usize> VoicingStrategy for MonophonicStrat
 
     fn start(&mut self, event: &EventRecord, frequency: f32, gain: f32, glide_duration: f64, unison: usize, shared: &V::Shared) {
         if self.depth < STACK {
-            self.held[self.depth] = HeldNote {id: event.id as i32, frequency};
+            self.held[self.depth] = HeldNote::new(event.id as i32, frequency);
             self.depth += 1;
         }
         if let Some(triggered) = self.triggered {
             if self.voices[triggered].gate() {
                 self.voices[triggered].start_glide(frequency, glide_duration); // legato: glide, no retrigger
+                self.express_held(triggered, HeldNote::new(event.id as i32, frequency));
                 return;
             }
         }
@@ -358,8 +409,10 @@ impl<V: Voice + Default, const STACK: usize> VoicingStrategy for MonophonicStrat
             return;
         };
         if was_top && self.depth > 0 {
-            // Released the topmost key: glide back to the next held note (TS `stop`).
-            self.voices[triggered].start_glide(self.held[self.depth - 1].frequency, glide_duration);
+            // Released the topmost key: glide back to the next held note (TS `stop`), with its expression.
+            let next = self.held[self.depth - 1];
+            self.voices[triggered].start_glide(next.frequency, glide_duration);
+            self.express_held(triggered, next);
             return;
         }
         if self.depth == 0 {
@@ -404,6 +457,22 @@ impl<V: Voice + Default, const STACK: usize> VoicingStrategy for MonophonicStrat
         self.sounding = None;
         self.depth = 0;
     }
+
+    // One voice plays whichever held note is on top, so only that note's expression moves it (a lower held
+    // key's expression would otherwise bend the note it is not sounding). A buried key's expression is kept
+    // and applied when the key is back on top.
+    fn express(&mut self, note_id: i32, expression: NoteExpression) {
+        let Some(position) = self.held[..self.depth].iter().position(|note| note.id == note_id) else {
+            return;
+        };
+        self.held[position].remember(expression);
+        if position != self.depth - 1 {
+            return;
+        }
+        if let Some(sounding) = self.sounding {
+            self.voices[sounding].express(expression);
+        }
+    }
 }
 
 /// The voicing mode a synth switches between at runtime, mirroring the TS `VoicingMode` enum (Monophonic = 0,
@@ -480,6 +549,14 @@ impl<V: Voice + Default, const VOICES: usize, const STACK: usize> Voicing<V, VOI
         }
     }
 
+    /// Route a per-note expression to the active strategy.
+    pub fn express(&mut self, note_id: i32, expression: NoteExpression) {
+        match self.mode {
+            VoicingMode::Polyphonic => self.polyphonic.express(note_id, expression),
+            VoicingMode::Monophonic => self.monophonic.express(note_id, expression)
+        }
+    }
+
     /// Render both strategies additively into `output`, the active one plus any decaying outgoing voices.
     pub fn process(&mut self, output: [&mut [f32]; 2], block: &Block, shared: &V::Shared) {
         let [out_left, out_right] = output;
@@ -514,7 +591,7 @@ impl<V: Voice + Default, const VOICES: usize, const STACK: usize> Voicing<V, VOI
 #[cfg(test)]
 mod tests {
     use super::{MonophonicStrategy, Voice, VoiceUnison, Voicing, VoicingMode, VoicingStrategy, PolyphonicStrategy};
-    use abi::{Block, BlockFlags, EventRecord, EVENT_NOTE_ON};
+    use abi::{Block, BlockFlags, EventRecord, NoteExpression, EVENT_NOTE_ON};
 
     // A deterministic mock voice: it outputs its gain while gated, then fades over MOCK_RELEASE chunks once
     // released, reporting finished when the fade completes (or immediately when force-stopped).
@@ -524,7 +601,8 @@ mod tests {
     struct MockVoice {
         gain: f32,
         gated: bool,
-        release: i32
+        release: i32,
+        tuning: f32
     }
 
     impl Voice for MockVoice {
@@ -533,6 +611,7 @@ mod tests {
             self.gain = gain;
             self.gated = true;
             self.release = MOCK_RELEASE;
+            self.tuning = 0.0;
         }
         fn stop(&mut self) {
             self.gated = false;
@@ -560,6 +639,11 @@ mod tests {
             }
             !self.gated && self.release == 0
         }
+        fn express(&mut self, expression: NoteExpression) {
+            if let NoteExpression::Tuning(semitones) = expression {
+                self.tuning = semitones;
+            }
+        }
     }
 
     fn note(id: u32) -> EventRecord {
@@ -627,6 +711,23 @@ mod tests {
         assert_eq!(voicing.active_count(), 0);
     }
 
+    #[test]
+    fn an_expression_reaches_only_the_voice_playing_its_note() {
+        let mut voicing = PolyphonicStrategy::<MockVoice, 4>::new();
+        voicing.start(&note(1), 440.0, 0.5, 0.0, 1, &());
+        voicing.start(&note(2), 550.0, 0.5, 0.0, 1, &());
+        voicing.express(2, NoteExpression::Tuning(1.5));
+        let tunings: Vec<(i32, f32)> = voicing.slots.iter().filter(|slot| slot.active)
+            .map(|slot| (slot.note_id, slot.voice.tuning)).collect();
+        assert!(tunings.contains(&(1, 0.0)) && tunings.contains(&(2, 1.5)), "{tunings:?}");
+        voicing.stop(2, 0.0);
+        voicing.express(2, NoteExpression::Tuning(-2.0));
+        assert!(voicing.slots.iter().any(|slot| slot.note_id == 2 && slot.voice.tuning == -2.0),
+            "a releasing note still follows its expression");
+        voicing.express(7, NoteExpression::Tuning(12.0));
+        assert!(voicing.slots.iter().all(|slot| slot.voice.tuning != 12.0), "an unknown note id is ignored");
+    }
+
     // ---- VoiceUnison ----
 
     // A voice whose output IS the spread it was started with, so a unison's summed output reveals how it fans
@@ -754,6 +855,36 @@ mod tests {
         assert!(mono.is_active());
     }
 
+    #[test]
+    fn mono_follows_only_the_top_held_notes_expression() {
+        let mut mono = MonophonicStrategy::<MockVoice, 8>::new();
+        mono.start(&note(1), 100.0, 0.8, GLIDE, 1, &());
+        mono.start(&note(2), 200.0, 0.8, GLIDE, 1, &());
+        let tuning = |mono: &MonophonicStrategy<MockVoice, 8>| mono.voices[mono.sounding.unwrap()].tuning;
+        mono.express(1, NoteExpression::Tuning(3.0));
+        assert_eq!(tuning(&mono), 0.0, "the buried key does not bend the sounding note");
+        mono.express(2, NoteExpression::Tuning(0.5));
+        assert_eq!(tuning(&mono), 0.5);
+        mono.stop(2, GLIDE);
+        mono.express(1, NoteExpression::Tuning(-1.0));
+        assert_eq!(tuning(&mono), -1.0, "back on top, the lower key drives the voice");
+    }
+
+    #[test]
+    fn mono_hands_a_buried_keys_last_expression_back_to_the_voice() {
+        let mut mono = MonophonicStrategy::<MockVoice, 8>::new();
+        mono.start(&note(1), 100.0, 0.8, GLIDE, 1, &());
+        mono.express(1, NoteExpression::Tuning(2.0));
+        mono.start(&note(2), 200.0, 0.8, GLIDE, 1, &());
+        let tuning = |mono: &MonophonicStrategy<MockVoice, 8>| mono.voices[mono.sounding.unwrap()].tuning;
+        assert_eq!(tuning(&mono), 0.0, "the new top note starts untuned");
+        mono.express(1, NoteExpression::Tuning(-3.0)); // buried: remembered, not applied
+        mono.express(2, NoteExpression::Tuning(0.5));
+        assert_eq!(tuning(&mono), 0.5);
+        mono.stop(2, GLIDE);
+        assert_eq!(tuning(&mono), -3.0, "the lower key's last expression is applied on hand-back");
+    }
+
     #[test]
     fn mono_releasing_the_last_note_lets_the_voice_decay() {
         let mut mono = MonophonicStrategy::<FreqMock, 8>::new();
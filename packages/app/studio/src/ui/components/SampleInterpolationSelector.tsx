@@ -0,0 +1,26 @@
+import {int, Lifecycle, MutableObservableValue} from "@opendaw/lib-std"
+import {createElement} from "@opendaw/lib-jsx"
+import {SampleInterpolation} from "@opendaw/studio-adapters"
+import {RadioGroup} from "@/ui/components/RadioGroup"
+
+type Construct = {
+    lifecycle: Lifecycle
+    model: MutableObservableValue<int>
+    className?: string
+}
+
+// How a sample is read between its frames when it plays at another rate.
+export const SampleInterpolationSelector = ({lifecycle, model, className}: Construct) => (
+    <RadioGroup lifecycle={lifecycle}
+                model={model}
+                className={className}
+                elements={[
+                    {value: SampleInterpolation.Linear, element: (<span>Lin</span>), tooltip: "Linear"},
+                    {value: SampleInterpolation.Sinc, element: (<span>Sinc</span>), tooltip: "Windowed sinc"},
+                    {
+                        value: SampleInterpolation.SincHigh,
+                        element: (<span>HQ</span>),
+                        tooltip: "Windowed sinc, high quality (more CPU)"
+                    }
+                ]}/>
+)
@@ -6,12 +6,17 @@ import {
     AudioEffectCompositeBox,
     AutotuneDeviceBox,
     FrequencySplitBox,
+    MultibandCompressorDeviceBox,
     StereoCompositeBox,
     BoxVisitor,
+    ChorusDeviceBox,
     CompressorDeviceBox,
+    ConvolutionReverbDeviceBox,
     CrusherDeviceBox,
     DattorroReverbDeviceBox,
     DelayDeviceBox,
+    FMSynthDeviceBox,
+    FilterDeviceBox,
     FoldDeviceBox,
     GateDeviceBox,
     MaximizerDeviceBox,
@@ -22,8 +27,10 @@ import {
     PitchDeviceBox,
     PlayfieldDeviceBox,
     PlayfieldSampleBox,
+    RatchetDeviceBox,
     RevampDeviceBox,
     ReverbDeviceBox,
+    ScaleDeviceBox,
     SoundfontDeviceBox,
     SpielwerkDeviceBox,
     StereoToolDeviceBox,
@@ -35,6 +42,7 @@ import {
     VelocityDeviceBox,
     VocoderDeviceBox,
     WaveshaperDeviceBox,
+    WavetableDeviceBox,
     WerkstattDeviceBox,
     ZeitgeistDeviceBox
 } from "@opendaw/studio-boxes"
@@ -49,12 +57,17 @@ import {
     AudioEffectCompositeBoxAdapter,
     AutotuneDeviceBoxAdapter,
     FrequencySplitBoxAdapter,
+    MultibandCompressorDeviceBoxAdapter,
     StereoCompositeBoxAdapter,
+    ChorusDeviceBoxAdapter,
     CompressorDeviceBoxAdapter,
+    ConvolutionReverbDeviceBoxAdapter,
     CrusherDeviceBoxAdapter,
     DattorroReverbDeviceBoxAdapter,
     DelayDeviceBoxAdapter,
     DeviceHost,
+    FMSynthDeviceBoxAdapter,
+    FilterDeviceBoxAdapter,
     FoldDeviceBoxAdapter,
     GateDeviceBoxAdapter,
     MaximizerDeviceBoxAdapter,
@@ -65,8 +78,10 @@ import {
     PitchDeviceBoxAdapter,
     PlayfieldDeviceBoxAdapter,
     PlayfieldSampleBoxAdapter,
+    RatchetDeviceBoxAdapter,
     RevampDeviceBoxAdapter,
     ReverbDeviceBoxAdapter,
+    ScaleDeviceBoxAdapter,
     SoundfontDeviceBoxAdapter,
     SpielwerkDeviceBoxAdapter,
     StereoToolDeviceBoxAdapter,
@@ -78,6 +93,7 @@ import {
     VelocityDeviceBoxAdapter,
     VocoderDeviceBoxAdapter,
     WaveshaperDeviceBoxAdapter,
+    WavetableDeviceBoxAdapter,
     WerkstattDeviceBoxAdapter,
     ZeitgeistDeviceBoxAdapter
 } from "@opendaw/studio-adapters"
@@ -88,12 +104,16 @@ import {ModularDeviceEditor} from "@/ui/devices/audio-effects/ModularDeviceEdito
 import {asDefined, Lifecycle} from "@opendaw/lib-std"
 import {Box} from "@opendaw/lib-box"
 import {PitchDeviceEditor} from "./midi-effects/PitchDeviceEditor"
+import {RatchetDeviceEditor} from "./midi-effects/RatchetDeviceEditor"
+import {ScaleDeviceEditor} from "./midi-effects/ScaleDeviceEditor"
 import {TapeDeviceEditor} from "@/ui/devices/instruments/TapeDeviceEditor.tsx"
 import {VaporisateurDeviceEditor} from "@/ui/devices/instruments/VaporisateurDeviceEditor.tsx"
 import {AudioBusEditor} from "@/ui/devices/AudioBusEditor.tsx"
 import {ApparatDeviceEditor} from "./instruments/ApparatDeviceEditor"
 import {NanoDeviceEditor} from "./instruments/NanoDeviceEditor"
 import {PlayfieldDeviceEditor} from "./instruments/PlayfieldDeviceEditor"
+import {WavetableDeviceEditor} from "./instruments/WavetableDeviceEditor"
+import {FMSynthDeviceEditor} from "./instruments/FMSynthDeviceEditor"
 import {StereoToolDeviceEditor} from "./audio-effects/StereoToolDeviceEditor"
 import {PlayfieldSampleEditor} from "./instruments/PlayfieldSampleEditor"
 import {ZeitgeistDeviceEditor} from "@/ui/devices/midi-effects/ZeitgeistDeviceEditor"
@@ -106,6 +126,10 @@ import {GateDeviceEditor} from "@/ui/devices/audio-effects/GateDeviceEditor"
 import {AutotuneDeviceEditor} from "@/ui/devices/audio-effects/AutotuneDeviceEditor"
 import {CrusherDeviceEditor} from "@/ui/devices/audio-effects/CrusherDeviceEditor"
 import {FoldDeviceEditor} from "@/ui/devices/audio-effects/FoldDeviceEditor"
+import {FilterDeviceEditor} from "@/ui/devices/audio-effects/FilterDeviceEditor"
+import {ChorusDeviceEditor} from "@/ui/devices/audio-effects/ChorusDeviceEditor"
+import {ConvolutionReverbDeviceEditor} from "@/ui/devices/audio-effects/ConvolutionReverbDeviceEditor"
+import {MultibandCompressorDeviceEditor} from "@/ui/devices/audio-effects/MultibandCompressorDeviceEditor"
 import {MIDIOutputDeviceEditor} from "@/ui/devices/instruments/MIDIOutputDeviceEditor"
 import {VelocityDeviceEditor} from "@/ui/devices/midi-effects/VelocityDeviceEditor"
 import {TidalDeviceEditor} from "@/ui/devices/audio-effects/TidalDeviceEditor"
@@ -138,6 +162,18 @@ export namespace DeviceEditorFactory {
                                    adapter={service.project.boxAdapters.adapterFor(box, PitchDeviceBoxAdapter)}
                                    deviceHost={deviceHost}/>
             ),
+            visitRatchetDeviceBox: (box: RatchetDeviceBox) => (
+                <RatchetDeviceEditor lifecycle={lifecycle}
+                                     service={service}
+                                     adapter={service.project.boxAdapters.adapterFor(box, RatchetDeviceBoxAdapter)}
+                                     deviceHost={deviceHost}/>
+            ),
+            visitScaleDeviceBox: (box: ScaleDeviceBox) => (
+                <ScaleDeviceEditor lifecycle={lifecycle}
+                                   service={service}
+                                   adapter={service.project.boxAdapters.adapterFor(box, ScaleDeviceBoxAdapter)}
+                                   deviceHost={deviceHost}/>
+            ),
             visitVelocityDeviceBox: (box: VelocityDeviceBox) => (
                 <VelocityDeviceEditor lifecycle={lifecycle}
                                       service={service}
@@ -181,6 +217,18 @@ export namespace DeviceEditorFactory {
                                           adapter={service.project.boxAdapters.adapterFor(box, VaporisateurDeviceBoxAdapter)}
                                           deviceHost={deviceHost}/>
             ),
+            visitWavetableDeviceBox: (box: WavetableDeviceBox): JsxValue => (
+                <WavetableDeviceEditor lifecycle={lifecycle}
+                                       service={service}
+                                       adapter={service.project.boxAdapters.adapterFor(box, WavetableDeviceBoxAdapter)}
+                                       deviceHost={deviceHost}/>
+            ),
+            visitFMSynthDeviceBox: (box: FMSynthDeviceBox): JsxValue => (
+                <FMSynthDeviceEditor lifecycle={lifecycle}
+                                     service={service}
+                                     adapter={service.project.boxAdapters.adapterFor(box, FMSynthDeviceBoxAdapter)}
+                                     deviceHost={deviceHost}/>
+            ),
             visitMIDIOutputDeviceBox: (box: MIDIOutputDeviceBox): JsxValue => (
                 <MIDIOutputDeviceEditor lifecycle={lifecycle}
                                         service={service}
@@ -298,6 +346,30 @@ export namespace DeviceEditorFactory {
                                   adapter={service.project.boxAdapters.adapterFor(box, FoldDeviceBoxAdapter)}
                                   deviceHost={deviceHost}/>
             ),
+            visitFilterDeviceBox: (box: FilterDeviceBox) => (
+                <FilterDeviceEditor lifecycle={lifecycle}
+                                    service={service}
+                                    adapter={service.project.boxAdapters.adapterFor(box, FilterDeviceBoxAdapter)}
+                                    deviceHost={deviceHost}/>
+            ),
+            visitChorusDeviceBox: (box: ChorusDeviceBox) => (
+                <ChorusDeviceEditor lifecycle={lifecycle}
+                                    service={service}
+                                    adapter={service.project.boxAdapters.adapterFor(box, ChorusDeviceBoxAdapter)}
+                                    deviceHost={deviceHost}/>
+            ),
+            visitConvolutionReverbDeviceBox: (box: ConvolutionReverbDeviceBox) => (
+                <ConvolutionReverbDeviceEditor lifecycle={lifecycle}
+                                               service={service}
+                                               adapter={service.project.boxAdapters.adapterFor(box, ConvolutionReverbDeviceBoxAdapter)}
+                                               deviceHost={deviceHost}/>
+            ),
+            visitMultibandCompressorDeviceBox: (box: MultibandCompressorDeviceBox) => (
+                <MultibandCompressorDeviceEditor lifecycle={lifecycle}
+                                                 service={service}
+                                                 adapter={service.project.boxAdapters.adapterFor(box, MultibandCompressorDeviceBoxAdapter)}
+                                                 deviceHost={deviceHost}/>
+            ),
             visitCompressorDeviceBox: (box: CompressorDeviceBox) => (
                 <CompressorDeviceEditor lifecycle={lifecycle}
                                         service={service}
@@ -0,0 +1,55 @@
+import css from "./ChorusDeviceEditor.sass?inline"
+import {ChorusDeviceBoxAdapter, DeviceHost} from "@opendaw/studio-adapters"
+import {Lifecycle} from "@opendaw/lib-std"
+import {createElement} from "@opendaw/lib-jsx"
+import {DeviceEditor} from "@/ui/devices/DeviceEditor.tsx"
+import {MenuItems} from "@/ui/devices/menu-items.ts"
+import {ControlBuilder} from "@/ui/devices/ControlBuilder.tsx"
+import {DevicePeakMeter} from "@/ui/devices/panel/DevicePeakMeter.tsx"
+import {Html} from "@opendaw/lib-dom"
+import {StudioService} from "@/service/StudioService"
+import {EffectFactories} from "@opendaw/studio-core"
+import {ParameterToggleButton} from "@/ui/devices/ParameterToggleButton"
+
+const className = Html.adoptStyleSheet(css, "ChorusDeviceEditor")
+
+type Construct = {
+    lifecycle: Lifecycle
+    service: StudioService
+    adapter: ChorusDeviceBoxAdapter
+    deviceHost: DeviceHost
+}
+
+export const ChorusDeviceEditor = ({lifecycle, service, adapter, deviceHost}: Construct) => {
+    const {project} = service
+    const {editing, midiLearning} = project
+    const {mode, sync, rateIndex, rate, depth, feedback, spread, mix} = adapter.namedParameter
+    return (
+        <DeviceEditor lifecycle={lifecycle}
+                      service={service}
+                      adapter={adapter}
+                      populateMenu={parent => MenuItems.forEffectDevice(parent, service, deviceHost, adapter)}
+                      populateControls={() => (
+                          <div className={className}>
+                              {[mode, rateIndex, rate, depth, feedback, spread, mix]
+                                  .map(parameter => ControlBuilder.createKnob({
+                                      lifecycle,
+                                      editing,
+                                      midiLearning,
+                                      adapter,
+                                      parameter
+                                  }))}
+                              <div className="sync">
+                                  <ParameterToggleButton lifecycle={lifecycle}
+                                                         editing={editing}
+                                                         parameter={sync}/>
+                              </div>
+                          </div>)}
+                      populateMeter={() => (
+                          <DevicePeakMeter lifecycle={lifecycle}
+                                           receiver={project.liveStreamReceiver}
+                                           address={adapter.address}/>
+                      )}
+                      icon={EffectFactories.AudioNamed.Chorus.defaultIcon}/>
+    )
+}
@@ -0,0 +1,67 @@
+import css from "./ConvolutionReverbDeviceEditor.sass?inline"
+import {ConvolutionReverbDeviceBoxAdapter, DeviceHost} from "@opendaw/studio-adapters"
+import {asInstanceOf, Lifecycle} from "@opendaw/lib-std"
+import {createElement} from "@opendaw/lib-jsx"
+import {DeviceEditor} from "@/ui/devices/DeviceEditor.tsx"
+import {MenuItems} from "@/ui/devices/menu-items.ts"
+import {ControlBuilder} from "@/ui/devices/ControlBuilder.tsx"
+import {DevicePeakMeter} from "@/ui/devices/panel/DevicePeakMeter.tsx"
+import {Html} from "@opendaw/lib-dom"
+import {StudioService} from "@/service/StudioService"
+import {EffectFactories} from "@opendaw/studio-core"
+import {AudioFileBox} from "@opendaw/studio-boxes"
+import {IconSymbol} from "@opendaw/studio-enums"
+import {Icon} from "@/ui/components/Icon"
+import {SampleSelector, SampleSelectStrategy} from "@/ui/devices/SampleSelector"
+
+const className = Html.adoptStyleSheet(css, "ConvolutionReverbDeviceEditor")
+
+type Construct = {
+    lifecycle: Lifecycle
+    service: StudioService
+    adapter: ConvolutionReverbDeviceBoxAdapter
+    deviceHost: DeviceHost
+}
+
+export const ConvolutionReverbDeviceEditor = ({lifecycle, service, adapter, deviceHost}: Construct) => {
+    const {project} = service
+    const {editing, midiLearning} = project
+    const responseDropZone: HTMLElement = (
+        <div className="response-drop">
+            <Icon symbol={IconSymbol.Waveform}/>
+        </div>
+    )
+    const sampleSelector = new SampleSelector(service, SampleSelectStrategy.forPointerField(adapter.file))
+    lifecycle.ownAll(
+        adapter.file.catchupAndSubscribe(pointer => pointer.targetVertex.match({
+            none: () => responseDropZone.removeAttribute("sample"),
+            some: ({box}) => responseDropZone.setAttribute("sample", asInstanceOf(box, AudioFileBox).fileName.getValue())
+        })),
+        sampleSelector.configureBrowseClick(responseDropZone),
+        sampleSelector.configureContextMenu(responseDropZone),
+        sampleSelector.configureDrop(responseDropZone)
+    )
+    return (
+        <DeviceEditor lifecycle={lifecycle}
+                      service={service}
+                      adapter={adapter}
+                      populateMenu={parent => MenuItems.forEffectDevice(parent, service, deviceHost, adapter)}
+                      populateControls={() => (
+                          <div className={className}>
+                              {Object.values(adapter.namedParameter).map(parameter => ControlBuilder.createKnob({
+                                  lifecycle,
+                                  editing,
+                                  midiLearning,
+                                  adapter,
+                                  parameter
+                              }))}
+                              {responseDropZone}
+                          </div>)}
+                      populateMeter={() => (
+                          <DevicePeakMeter lifecycle={lifecycle}
+                                           receiver={project.liveStreamReceiver}
+                                           address={adapter.address}/>
+                      )}
+                      icon={EffectFactories.AudioNamed.ConvolutionReverb.defaultIcon}/>
+    )
+}
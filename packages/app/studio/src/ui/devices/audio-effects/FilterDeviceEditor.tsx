@@ -0,0 +1,54 @@
+import css from "./FilterDeviceEditor.sass?inline"
+import {DeviceHost, FilterDeviceBoxAdapter} from "@opendaw/studio-adapters"
+import {Lifecycle} from "@opendaw/lib-std"
+import {createElement} from "@opendaw/lib-jsx"
+import {DeviceEditor} from "@/ui/devices/DeviceEditor.tsx"
+import {MenuItems} from "@/ui/devices/menu-items.ts"
+import {ControlBuilder} from "@/ui/devices/ControlBuilder.tsx"
+import {DevicePeakMeter} from "@/ui/devices/panel/DevicePeakMeter.tsx"
+import {Html} from "@opendaw/lib-dom"
+import {StudioService} from "@/service/StudioService"
+import {EffectFactories} from "@opendaw/studio-core"
+import {SidechainButton} from "@/ui/devices/SidechainButton"
+
+const className = Html.adoptStyleSheet(css, "FilterDeviceEditor")
+
+type Construct = {
+    lifecycle: Lifecycle
+    service: StudioService
+    adapter: FilterDeviceBoxAdapter
+    deviceHost: DeviceHost
+}
+
+export const FilterDeviceEditor = ({lifecycle, service, adapter, deviceHost}: Construct) => {
+    const {project} = service
+    const {editing, midiLearning} = project
+    return (
+        <DeviceEditor lifecycle={lifecycle}
+                      service={service}
+                      adapter={adapter}
+                      populateMenu={parent => MenuItems.forEffectDevice(parent, service, deviceHost, adapter)}
+                      populateControls={() => (
+                          <div className={className}>
+                              {Object.values(adapter.namedParameter).map(parameter => ControlBuilder.createKnob({
+                                  lifecycle,
+                                  editing,
+                                  midiLearning,
+                                  adapter,
+                                  parameter
+                              }))}
+                              <div className="sidechain">
+                                  <SidechainButton sideChain={adapter.sideChain}
+                                                   rootBoxAdapter={project.rootBoxAdapter}
+                                                   deviceHost={deviceHost}
+                                                   editing={editing}/>
+                              </div>
+                          </div>)}
+                      populateMeter={() => (
+                          <DevicePeakMeter lifecycle={lifecycle}
+                                           receiver={project.liveStreamReceiver}
+                                           address={adapter.address}/>
+                      )}
+                      icon={EffectFactories.AudioNamed.Filter.defaultIcon}/>
+    )
+}
@@ -0,0 +1,79 @@
+import css from "./MultibandCompressorDeviceEditor.sass?inline"
+import {DeviceHost, MultibandCompressorDeviceBoxAdapter} from "@opendaw/studio-adapters"
+import {Lifecycle} from "@opendaw/lib-std"
+import {createElement} from "@opendaw/lib-jsx"
+import {DeviceEditor} from "@/ui/devices/DeviceEditor.tsx"
+import {MenuItems} from "@/ui/devices/menu-items.ts"
+import {ControlBuilder} from "@/ui/devices/ControlBuilder.tsx"
+import {DevicePeakMeter} from "@/ui/devices/panel/DevicePeakMeter.tsx"
+import {AnimationFrame, Html} from "@opendaw/lib-dom"
+import {StudioService} from "@/service/StudioService"
+import {EffectFactories} from "@opendaw/studio-core"
+import {ParameterToggleButton} from "@/ui/devices/ParameterToggleButton"
+
+const className = Html.adoptStyleSheet(css, "MultibandCompressorDeviceEditor")
+
+type Construct = {
+    lifecycle: Lifecycle
+    service: StudioService
+    adapter: MultibandCompressorDeviceBoxAdapter
+    deviceHost: DeviceHost
+}
+
+export const MultibandCompressorDeviceEditor = ({lifecycle, service, adapter, deviceHost}: Construct) => {
+    const {project} = service
+    const {editing, midiLearning} = project
+    const {bandCount, crossoverLow, crossoverMid, crossoverHigh, bands} = adapter.namedParameter
+    const reduction = new Float32Array(bands.length)
+    lifecycle.own(project.liveStreamReceiver.subscribeFloats(adapter.reduction, values => reduction.set(values)))
+    const readouts: ReadonlyArray<HTMLElement> = bands.map(() => <div className="reduction"/>)
+    lifecycle.own(AnimationFrame.add(() => readouts.forEach((readout, index) =>
+        readout.textContent = `${reduction[index].toFixed(1)} dB`)))
+    // The bands past the band count keep their settings but are not processed: dim them.
+    const sections: ReadonlyArray<HTMLElement> = bands.map(({threshold, ratio, attack, release, gain, solo, bypass}, index) => (
+        <div className="band">
+            {[threshold, ratio, attack, release, gain].map(parameter => ControlBuilder.createKnob({
+                lifecycle,
+                editing,
+                midiLearning,
+                adapter,
+                parameter
+            }))}
+            <div className="toggles">
+                <ParameterToggleButton lifecycle={lifecycle} editing={editing} parameter={solo}/>
+                <ParameterToggleButton lifecycle={lifecycle} editing={editing} parameter={bypass}/>
+                {readouts[index]}
+            </div>
+        </div>
+    ))
+    lifecycle.own(bandCount.catchupAndSubscribe(owner => {
+        const count = owner.getValue()
+        sections.forEach((section, index) => section.classList.toggle("unused", index >= count))
+    }))
+    return (
+        <DeviceEditor lifecycle={lifecycle}
+                      service={service}
+                      adapter={adapter}
+                      populateMenu={parent => MenuItems.forEffectDevice(parent, service, deviceHost, adapter)}
+                      populateControls={() => (
+                          <div className={className}>
+                              <div className="split">
+                                  {[bandCount, crossoverLow, crossoverMid, crossoverHigh]
+                                      .map(parameter => ControlBuilder.createKnob({
+                                          lifecycle,
+                                          editing,
+                                          midiLearning,
+                                          adapter,
+                                          parameter
+                                      }))}
+                              </div>
+                              {sections}
+                          </div>)}
+                      populateMeter={() => (
+                          <DevicePeakMeter lifecycle={lifecycle}
+                                           receiver={project.liveStreamReceiver}
+                                           address={adapter.address}/>
+                      )}
+                      icon={EffectFactories.AudioNamed.MultibandCompressor.defaultIcon}/>
+    )
+}
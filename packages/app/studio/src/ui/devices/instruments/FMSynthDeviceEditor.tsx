@@ -0,0 +1,62 @@
+import css from "./FMSynthDeviceEditor.sass?inline"
+import {Lifecycle} from "@opendaw/lib-std"
+import {createElement} from "@opendaw/lib-jsx"
+import {DeviceEditor} from "@/ui/devices/DeviceEditor.tsx"
+import {MenuItems} from "@/ui/devices/menu-items.ts"
+import {DeviceHost, FMSynthDeviceBoxAdapter, InstrumentFactories} from "@opendaw/studio-adapters"
+import {ControlBuilder} from "@/ui/devices/ControlBuilder.tsx"
+import {DevicePeakMeter} from "@/ui/devices/panel/DevicePeakMeter.tsx"
+import {Html} from "@opendaw/lib-dom"
+import {StudioService} from "@/service/StudioService"
+
+const className = Html.adoptStyleSheet(css, "FMSynthDeviceEditor")
+
+type Construct = {
+    lifecycle: Lifecycle
+    service: StudioService
+    adapter: FMSynthDeviceBoxAdapter
+    deviceHost: DeviceHost
+}
+
+export const FMSynthDeviceEditor = ({lifecycle, service, adapter, deviceHost}: Construct) => {
+    const {project} = service
+    const {editing, midiLearning} = project
+    const {volume, algorithm, feedback, voicingMode, glideTime, operators} = adapter.namedParameter
+    return (
+        <DeviceEditor lifecycle={lifecycle}
+                      service={service}
+                      adapter={adapter}
+                      populateMenu={parent => MenuItems.forAudioUnitInput(parent, service, deviceHost)}
+                      populateControls={() => (
+                          <div className={className}>
+                              <div className="global">
+                                  {[algorithm, feedback, voicingMode, glideTime, volume]
+                                      .map(parameter => ControlBuilder.createKnob({
+                                          lifecycle,
+                                          editing,
+                                          midiLearning,
+                                          adapter,
+                                          parameter
+                                      }))}
+                              </div>
+                              {operators.map(operator => (
+                                  <div className="operator">
+                                      {Object.values(operator).map(parameter => ControlBuilder.createKnob({
+                                          lifecycle,
+                                          editing,
+                                          midiLearning,
+                                          adapter,
+                                          parameter
+                                      }))}
+                                  </div>
+                              ))}
+                          </div>
+                      )}
+                      populateMeter={() => (
+                          <DevicePeakMeter lifecycle={lifecycle}
+                                           receiver={project.liveStreamReceiver}
+                                           address={adapter.address}/>
+                      )}
+                      icon={InstrumentFactories.FMSynth.defaultIcon}/>
+    )
+}
\ No newline at end of file
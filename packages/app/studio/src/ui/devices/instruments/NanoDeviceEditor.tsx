@@ -12,6 +12,8 @@ import {AudioFileBox} from "@opendaw/studio-boxes"
 import {Icon} from "@/ui/components/Icon"
 import {SampleSelector, SampleSelectStrategy} from "@/ui/devices/SampleSelector"
 import {StudioService} from "@/service/StudioService"
+import {EditWrapper} from "@/ui/wrapper/EditWrapper"
+import {SampleInterpolationSelector} from "@/ui/components/SampleInterpolationSelector"
 
 const className = Html.adoptStyleSheet(css, "NanoDeviceEditor")
 
@@ -63,6 +65,12 @@ export const NanoDeviceEditor = ({lifecycle, service, adapter, deviceHost}: Cons
                                   parameter: release
                               })}
                               {sampleDropZone}
+                              <div className="interpolation">
+                                  <span>Interp.</span>
+                                  <SampleInterpolationSelector lifecycle={lifecycle}
+                                                               model={EditWrapper.forValue(editing, adapter.interpolationField)}
+                                                               className="radio-group"/>
+                              </div>
                           </div>
                       )}
                       populateMeter={() => (
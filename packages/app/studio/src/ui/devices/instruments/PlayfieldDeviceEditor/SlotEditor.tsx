@@ -18,6 +18,7 @@ import {RelativeUnitValueDragging} from "@/ui/wrapper/RelativeUnitValueDragging"
 import {SnapValueThresholdInPixels} from "@/ui/timeline/editors/value/ValueMoveModifier"
 import {Colors, IconSymbol, Pointers} from "@opendaw/studio-enums"
 import {PointerField} from "@opendaw/lib-box"
+import {SampleInterpolationSelector} from "@/ui/components/SampleInterpolationSelector"
 
 const className = Html.adoptStyleSheet(css, "SlotEditor")
 
@@ -189,6 +190,12 @@ export const SlotEditor = ({lifecycle, service, adapter}: Construct) => {
                                 ]}
                     />
                 </div>
+                <div className="column">
+                    <div className="label">Interp.</div>
+                    <SampleInterpolationSelector lifecycle={lifecycle}
+                                                 model={EditWrapper.forValue(editing, adapter.interpolationField)}
+                                                 className="radio-group"/>
+                </div>
                 {createParameterLabel(sampleStart)}
                 {createParameterLabel(sampleEnd)}
                 {createParameterLabel(attack)}
@@ -35,6 +35,7 @@ import {EnvelopeDisplay} from "@/ui/devices/instruments/VaporisateurDeviceEditor
 import {FilterDisplay} from "@/ui/devices/instruments/VaporisateurDeviceEditor/FilterDisplay"
 import {Logo} from "@/ui/devices/instruments/VaporisateurDeviceEditor/Logo"
 import {OscillatorSelector} from "@/ui/devices/instruments/VaporisateurDeviceEditor/OscillatorSelector"
+import {ModulationMatrix} from "@/ui/devices/instruments/VaporisateurDeviceEditor/ModulationMatrix"
 import {AutomationControl} from "@/ui/components/AutomationControl"
 
 const className = Html.adoptStyleSheet(css, "editor")
@@ -68,7 +69,8 @@ export const VaporisateurDeviceEditor = ({lifecycle, service, adapter, deviceHos
         oscillators, noise, unisonCount, unisonDetune, unisonStereo, glideTime,
         cutoff, resonance, filterEnvelope, filterKeyboard, filterOrder,
         lfoWaveform, lfoRate, lfoTargetTune, lfoTargetCutoff, lfoTargetVolume,
-        attack, decay, sustain, release, voicingMode
+        attack, decay, sustain, release, voicingMode,
+        modLfoWaveform, modLfoRate, modEnvAttack, modEnvDecay, modEnvSustain, modEnvRelease
     } = adapter.namedParameter
     const createLabelControlFrag = (lifecycle: Lifecycle,
                                     parameter: AutomatableParameterFieldAdapter<number>,
@@ -161,6 +163,8 @@ export const VaporisateurDeviceEditor = ({lifecycle, service, adapter, deviceHos
                               <div className="label filter-section"/>
                               <div className="label lfo-section"/>
                               <div className="label env-section"/>
+                              <div className="label mod-lfo-section"/>
+                              <div className="label mod-env-section"/>
                               <div style={{display: "contents"}}>
                                   <Logo/>
                                   <div/>
@@ -284,6 +288,24 @@ export const VaporisateurDeviceEditor = ({lifecycle, service, adapter, deviceHos
                                   <div>{createLabelControlFrag(lifecycle, sustain)}</div>
                                   <div>{createLabelControlFrag(lifecycle, release)}</div>
                               </div>
+                              <div style={{display: "contents"}}>
+                                  <header>
+                                      <WaveformDisplay lifecycle={lifecycle}
+                                                       adapter={bindWaveformParameter(lifecycle, modLfoWaveform)}/>
+                                  </header>
+                                  <div/>
+                                  <div>{createWaveformSelector(lifecycle, modLfoWaveform)}</div>
+                                  <div>{createLabelControlFrag(lifecycle, modLfoRate)}</div>
+                              </div>
+                              <div style={{display: "contents"}}>
+                                  <div/>
+                                  <div/>
+                                  <div>{createLabelControlFrag(lifecycle, modEnvAttack)}</div>
+                                  <div>{createLabelControlFrag(lifecycle, modEnvDecay)}</div>
+                                  <div>{createLabelControlFrag(lifecycle, modEnvSustain)}</div>
+                                  <div>{createLabelControlFrag(lifecycle, modEnvRelease)}</div>
+                              </div>
+                              <ModulationMatrix lifecycle={lifecycle} editing={editing} adapter={adapter}/>
                           </div>
                       )}
                       populateMeter={() => (
@@ -0,0 +1,58 @@
+import css from "./ModulationMatrix.sass?inline"
+import {Editing, Lifecycle} from "@opendaw/lib-std"
+import {createElement} from "@opendaw/lib-jsx"
+import {Html} from "@opendaw/lib-dom"
+import {
+    AutomatableParameterFieldAdapter,
+    VaporisateurDeviceBoxAdapter,
+    VaporisateurSettings
+} from "@opendaw/studio-adapters"
+import {MenuItem} from "@opendaw/studio-core"
+import {MenuButton} from "@/ui/components/MenuButton"
+import {RelativeUnitValueDragging} from "@/ui/wrapper/RelativeUnitValueDragging"
+import {ParameterLabel} from "@/ui/components/ParameterLabel"
+
+const className = Html.adoptStyleSheet(css, "ModulationMatrix")
+
+type Construct = {
+    lifecycle: Lifecycle
+    editing: Editing
+    adapter: VaporisateurDeviceBoxAdapter
+}
+
+export const ModulationMatrix = ({lifecycle, editing, adapter}: Construct) => {
+    const createSelector = (parameter: AutomatableParameterFieldAdapter<number>, labels: ReadonlyArray<string>) => (
+        <MenuButton onInit={button => lifecycle.own(parameter.catchupAndSubscribe(owner =>
+            button.textContent = labels[owner.getValue()] ?? labels[0]))}
+                    root={MenuItem.root().setRuntimeChildrenProcedure(parent => labels
+                        .forEach((label, index) => parent.addMenuItem(
+                            MenuItem.default({label, checked: parameter.getValue() === index})
+                                .setTriggerProcedure(() => editing.modify(() => parameter.setValue(index)))))))}
+                    appearance={{framed: true, tinyTriangle: true}}
+                    stretch={true}>{labels[0]}</MenuButton>
+    )
+    const {MOD_SOURCE_STRINGS, MOD_TARGET_STRINGS} = VaporisateurSettings
+    return (
+        <div className={className}>
+            <span>#</span>
+            <span>Source</span>
+            <span>Target</span>
+            <span>Amount</span>
+            {adapter.namedParameter.modulations.flatMap(({source, target, amount}, index) => [
+                <span>{index + 1}</span>,
+                createSelector(source, MOD_SOURCE_STRINGS),
+                createSelector(target, MOD_TARGET_STRINGS),
+                <RelativeUnitValueDragging lifecycle={lifecycle}
+                                           editing={editing}
+                                           parameter={amount}
+                                           options={{snap: {threshold: 0.5}}}
+                                           supressValueFlyout={true}>
+                    <ParameterLabel lifecycle={lifecycle}
+                                    parameter={amount}
+                                    classList={["center"]}
+                                    framed={true}/>
+                </RelativeUnitValueDragging>
+            ])}
+        </div>
+    )
+}
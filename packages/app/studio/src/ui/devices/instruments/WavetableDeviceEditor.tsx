@@ -0,0 +1,67 @@
+import css from "./WavetableDeviceEditor.sass?inline"
+import {asInstanceOf, Lifecycle} from "@opendaw/lib-std"
+import {createElement} from "@opendaw/lib-jsx"
+import {DeviceEditor} from "@/ui/devices/DeviceEditor.tsx"
+import {MenuItems} from "@/ui/devices/menu-items.ts"
+import {DeviceHost, InstrumentFactories, WavetableDeviceBoxAdapter} from "@opendaw/studio-adapters"
+import {IconSymbol} from "@opendaw/studio-enums"
+import {ControlBuilder} from "@/ui/devices/ControlBuilder.tsx"
+import {DevicePeakMeter} from "@/ui/devices/panel/DevicePeakMeter.tsx"
+import {Html} from "@opendaw/lib-dom"
+import {AudioFileBox} from "@opendaw/studio-boxes"
+import {Icon} from "@/ui/components/Icon"
+import {SampleSelector, SampleSelectStrategy} from "@/ui/devices/SampleSelector"
+import {StudioService} from "@/service/StudioService"
+
+const className = Html.adoptStyleSheet(css, "WavetableDeviceEditor")
+
+type Construct = {
+    lifecycle: Lifecycle
+    service: StudioService
+    adapter: WavetableDeviceBoxAdapter
+    deviceHost: DeviceHost
+}
+
+export const WavetableDeviceEditor = ({lifecycle, service, adapter, deviceHost}: Construct) => {
+    const {project} = service
+    const {editing, midiLearning} = project
+    const sampleDropZone: HTMLElement = (
+        <div className="sample-drop">
+            <Icon symbol={IconSymbol.Waveform}/>
+        </div>
+    )
+    const sampleSelector = new SampleSelector(service, SampleSelectStrategy.forPointerField(adapter.file))
+    lifecycle.ownAll(
+        adapter.file.catchupAndSubscribe(pointer => pointer.targetVertex.match({
+            none: () => sampleDropZone.removeAttribute("sample"),
+            some: ({box}) => sampleDropZone.setAttribute("sample", asInstanceOf(box, AudioFileBox).fileName.getValue())
+        })),
+        sampleSelector.configureBrowseClick(sampleDropZone),
+        sampleSelector.configureContextMenu(sampleDropZone),
+        sampleSelector.configureDrop(sampleDropZone)
+    )
+    return (
+        <DeviceEditor lifecycle={lifecycle}
+                      service={service}
+                      adapter={adapter}
+                      populateMenu={parent => MenuItems.forAudioUnitInput(parent, service, deviceHost)}
+                      populateControls={() => (
+                          <div className={className}>
+                              {Object.values(adapter.namedParameter).map(parameter => ControlBuilder.createKnob({
+                                  lifecycle,
+                                  editing,
+                                  midiLearning,
+                                  adapter,
+                                  parameter
+                              }))}
+                              {sampleDropZone}
+                          </div>
+                      )}
+                      populateMeter={() => (
+                          <DevicePeakMeter lifecycle={lifecycle}
+                                           receiver={project.liveStreamReceiver}
+                                           address={adapter.address}/>
+                      )}
+                      icon={InstrumentFactories.Wavetable.defaultIcon}/>
+    )
+}
\ No newline at end of file
@@ -1,6 +1,6 @@
 import css from "./ArpeggioDeviceEditor.sass?inline"
 import {ArpeggioDeviceBoxAdapter, DeviceHost} from "@opendaw/studio-adapters"
-import {Lifecycle} from "@opendaw/lib-std"
+import {clamp, int, Lifecycle, StringMapping} from "@opendaw/lib-std"
 import {DeviceEditor} from "@/ui/devices/DeviceEditor.tsx"
 import {MenuItems} from "@/ui/devices/menu-items.ts"
 import {createElement} from "@opendaw/lib-jsx"
@@ -9,6 +9,9 @@ import {DeviceMidiMeter} from "@/ui/devices/panel/DeviceMidiMeter.tsx"
 import {Html} from "@opendaw/lib-dom"
 import {StudioService} from "@/service/StudioService"
 import {EffectFactories} from "@opendaw/studio-core"
+import {NumberInput} from "@/ui/components/NumberInput"
+import {Checkbox} from "@/ui/components/Checkbox"
+import {EditWrapper} from "@/ui/wrapper/EditWrapper"
 
 const className = Html.adoptStyleSheet(css, "ArpeggioDeviceEditor")
 
@@ -23,6 +26,36 @@ export const ArpeggioDeviceEditor = ({lifecycle, service, adapter, deviceHost}:
     const {modeIndex, numOctaves, rate, gate, repeat, velocity} = adapter.namedParameter
     const {project} = service
     const {editing, midiLearning} = project
+    const percent = StringMapping.percent({fractionDigits: 0})
+    // Each step scales the arp's velocity and gate, ties into the next step or rests. The steps past the pattern
+    // length are kept but not played: dim them.
+    const steps: ReadonlyArray<HTMLElement> = adapter.patternSteps.map(({velocity, gate, tie, rest}, index) => (
+        <div className="step">
+            <h1>{index + 1}</h1>
+            <NumberInput lifecycle={lifecycle}
+                         model={EditWrapper.forValue(editing, velocity)}
+                         mapper={percent}
+                         step={0.01}
+                         guard={{guard: (value: number): number => clamp(value, 0.0, 1.0)}}/>
+            <NumberInput lifecycle={lifecycle}
+                         model={EditWrapper.forValue(editing, gate)}
+                         mapper={percent}
+                         step={0.01}
+                         guard={{guard: (value: number): number => clamp(value, 0.0, 2.0)}}/>
+            <div className="switches">
+                <Checkbox lifecycle={lifecycle}
+                          model={EditWrapper.forValue(editing, tie)}
+                          appearance={{framed: true, tooltip: "Tie into the next step"}}>T</Checkbox>
+                <Checkbox lifecycle={lifecycle}
+                          model={EditWrapper.forValue(editing, rest)}
+                          appearance={{framed: true, tooltip: "Rest"}}>R</Checkbox>
+            </div>
+        </div>
+    ))
+    lifecycle.own(adapter.patternLength.catchupAndSubscribe(owner => {
+        const length = owner.getValue()
+        steps.forEach((step, index) => step.classList.toggle("unused", index >= length))
+    }))
     return (
         <DeviceEditor lifecycle={lifecycle}
                       service={service}
@@ -30,48 +63,26 @@ export const ArpeggioDeviceEditor = ({lifecycle, service, adapter, deviceHost}:
                       populateMenu={parent => MenuItems.forEffectDevice(parent, service, deviceHost, adapter)}
                       populateControls={() => (
                           <div className={className}>
-                              {ControlBuilder.createKnob({
-                                  lifecycle,
-                                  editing,
-                                  midiLearning,
-                                  adapter,
-                                  parameter: modeIndex
-                              })}
-                              {ControlBuilder.createKnob({
-                                  lifecycle,
-                                  editing,
-                                  midiLearning,
-                                  adapter,
-                                  parameter: rate
-                              })}
-                              {ControlBuilder.createKnob({
-                                  lifecycle,
-                                  editing,
-                                  midiLearning,
-                                  adapter,
-                                  parameter: numOctaves
-                              })}
-                              {ControlBuilder.createKnob({
-                                  lifecycle,
-                                  editing,
-                                  midiLearning,
-                                  adapter,
-                                  parameter: repeat
-                              })}
-                              {ControlBuilder.createKnob({
-                                  lifecycle,
-                                  editing,
-                                  midiLearning,
-                                  adapter,
-                                  parameter: gate
-                              })}
-                              {ControlBuilder.createKnob({
-                                  lifecycle,
-                                  editing,
-                                  midiLearning,
-                                  adapter,
-                                  parameter: velocity
-                              })}
+                              <div className="knobs">
+                                  {[modeIndex, rate, numOctaves, repeat, gate, velocity]
+                                      .map(parameter => ControlBuilder.createKnob({
+                                          lifecycle,
+                                          editing,
+                                          midiLearning,
+                                          adapter,
+                                          parameter
+                                      }))}
+                              </div>
+                              <div className="pattern">
+                                  <div className="length">
+                                      <h1>Steps</h1>
+                                      <NumberInput lifecycle={lifecycle}
+                                                   model={EditWrapper.forValue(editing, adapter.patternLength)}
+                                                   maxChars={2}
+                                                   guard={{guard: (value: int): int => clamp(value, 0, 16)}}/>
+                                  </div>
+                                  <div className="steps">{steps}</div>
+                              </div>
                           </div>
                       )}
                       populateMeter={() => (
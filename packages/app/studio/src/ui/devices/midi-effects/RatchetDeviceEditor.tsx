@@ -0,0 +1,76 @@
+import css from "./RatchetDeviceEditor.sass?inline"
+import {DeviceHost, RatchetDeviceBoxAdapter} from "@opendaw/studio-adapters"
+import {clamp, int, Lifecycle} from "@opendaw/lib-std"
+import {DeviceEditor} from "@/ui/devices/DeviceEditor.tsx"
+import {MenuItems} from "@/ui/devices/menu-items.ts"
+import {createElement} from "@opendaw/lib-jsx"
+import {ControlBuilder} from "@/ui/devices/ControlBuilder.tsx"
+import {DeviceMidiMeter} from "@/ui/devices/panel/DeviceMidiMeter.tsx"
+import {Html} from "@opendaw/lib-dom"
+import {StudioService} from "@/service/StudioService"
+import {EffectFactories} from "@opendaw/studio-core"
+import {NumberInput} from "@/ui/components/NumberInput"
+import {EditWrapper} from "@/ui/wrapper/EditWrapper"
+
+const className = Html.adoptStyleSheet(css, "RatchetDeviceEditor")
+
+type Construct = {
+    lifecycle: Lifecycle
+    service: StudioService
+    adapter: RatchetDeviceBoxAdapter
+    deviceHost: DeviceHost
+}
+
+export const RatchetDeviceEditor = ({lifecycle, service, adapter, deviceHost}: Construct) => {
+    const {rate, gate, velocityRamp} = adapter.namedParameter
+    const {project} = service
+    const {editing, liveStreamReceiver, midiLearning} = project
+    // The steps past the pattern length are kept but not played: dim them.
+    const steps: ReadonlyArray<HTMLElement> = adapter.ratchetCounts.map(count => (
+        <div className="step">
+            <NumberInput lifecycle={lifecycle}
+                         model={EditWrapper.forValue(editing, count)}
+                         maxChars={1}
+                         guard={{guard: (value: int): int => clamp(value, 1, 8)}}/>
+        </div>
+    ))
+    lifecycle.own(adapter.ratchetLength.catchupAndSubscribe(owner => {
+        const length = owner.getValue()
+        steps.forEach((step, index) => step.classList.toggle("unused", index >= length))
+    }))
+    return (
+        <DeviceEditor lifecycle={lifecycle}
+                      service={service}
+                      adapter={adapter}
+                      populateMenu={parent => MenuItems.forEffectDevice(parent, service, deviceHost, adapter)}
+                      populateControls={() => (
+                          <div className={className}>
+                              <div className="knobs">
+                                  {[rate, gate, velocityRamp].map(parameter => ControlBuilder.createKnob({
+                                      lifecycle,
+                                      editing,
+                                      midiLearning,
+                                      adapter,
+                                      parameter
+                                  }))}
+                              </div>
+                              <div className="pattern">
+                                  <div className="length">
+                                      <h1>Steps</h1>
+                                      <NumberInput lifecycle={lifecycle}
+                                                   model={EditWrapper.forValue(editing, adapter.ratchetLength)}
+                                                   maxChars={2}
+                                                   guard={{guard: (value: int): int => clamp(value, 0, 16)}}/>
+                                  </div>
+                                  <div className="steps">{steps}</div>
+                              </div>
+                          </div>
+                      )}
+                      populateMeter={() => (
+                          <DeviceMidiMeter lifecycle={lifecycle}
+                                           receiver={liveStreamReceiver}
+                                           address={adapter.address}/>
+                      )}
+                      icon={EffectFactories.MidiNamed.Ratchet.defaultIcon}/>
+    )
+}
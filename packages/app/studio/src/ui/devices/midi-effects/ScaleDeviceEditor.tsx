@@ -0,0 +1,49 @@
+import css from "./ScaleDeviceEditor.sass?inline"
+import {DeviceHost, ScaleDeviceBoxAdapter} from "@opendaw/studio-adapters"
+import {Lifecycle} from "@opendaw/lib-std"
+import {DeviceEditor} from "@/ui/devices/DeviceEditor.tsx"
+import {MenuItems} from "@/ui/devices/menu-items.ts"
+import {createElement} from "@opendaw/lib-jsx"
+import {ControlBuilder} from "@/ui/devices/ControlBuilder.tsx"
+import {DeviceMidiMeter} from "@/ui/devices/panel/DeviceMidiMeter.tsx"
+import {Html} from "@opendaw/lib-dom"
+import {StudioService} from "@/service/StudioService"
+import {EffectFactories} from "@opendaw/studio-core"
+
+const className = Html.adoptStyleSheet(css, "ScaleDeviceEditor")
+
+type Construct = {
+    lifecycle: Lifecycle
+    service: StudioService
+    adapter: ScaleDeviceBoxAdapter
+    deviceHost: DeviceHost
+}
+
+export const ScaleDeviceEditor = ({lifecycle, service, adapter, deviceHost}: Construct) => {
+    const {key, scale, chord, inversion, strum} = adapter.namedParameter
+    const {project} = service
+    const {editing, liveStreamReceiver, midiLearning} = project
+    return (
+        <DeviceEditor lifecycle={lifecycle}
+                      service={service}
+                      adapter={adapter}
+                      populateMenu={parent => MenuItems.forEffectDevice(parent, service, deviceHost, adapter)}
+                      populateControls={() => (
+                          <div className={className}>
+                              {[key, scale, chord, inversion, strum].map(parameter => ControlBuilder.createKnob({
+                                  lifecycle,
+                                  editing,
+                                  midiLearning,
+                                  adapter,
+                                  parameter
+                              }))}
+                          </div>
+                      )}
+                      populateMeter={() => (
+                          <DeviceMidiMeter lifecycle={lifecycle}
+                                           receiver={liveStreamReceiver}
+                                           address={adapter.address}/>
+                      )}
+                      icon={EffectFactories.MidiNamed.Scale.defaultIcon}/>
+    )
+}
@@ -1,6 +1,15 @@
 import css from "./ZeitgeistDeviceEditor.sass?inline"
-import {DeviceHost, GrooveShuffleBoxAdapter, ZeitgeistDeviceBoxAdapter} from "@opendaw/studio-adapters"
-import {Lifecycle} from "@opendaw/lib-std"
+import {
+    AnyRegionBoxAdapter,
+    AudioRegionBoxAdapter,
+    DeviceHost,
+    GrooveShuffleBoxAdapter,
+    GrooveTemplate,
+    NoteRegionBoxAdapter,
+    ZeitgeistDeviceBoxAdapter
+} from "@opendaw/studio-adapters"
+import {isInstanceOf, Lifecycle} from "@opendaw/lib-std"
+import {PPQN} from "@opendaw/lib-dsp"
 import {DeviceEditor} from "@/ui/devices/DeviceEditor.tsx"
 import {MenuItems} from "@/ui/devices/menu-items.ts"
 import {createElement} from "@opendaw/lib-jsx"
@@ -8,10 +17,14 @@ import {ControlBuilder} from "@/ui/devices/ControlBuilder.tsx"
 import {DeviceMidiMeter} from "@/ui/devices/panel/DeviceMidiMeter.tsx"
 import {Html} from "@opendaw/lib-dom"
 import {StudioService} from "@/service/StudioService"
-import {EffectFactories} from "@opendaw/studio-core"
+import {EffectFactories, MenuItem} from "@opendaw/studio-core"
 
 const className = Html.adoptStyleSheet(css, "ZeitgeistDeviceEditor")
 
+// One bar of 16th notes
+const TemplateSteps = 16
+const TemplateStep = PPQN.SemiQuaver
+
 type Construct = {
     lifecycle: Lifecycle
     service: StudioService
@@ -23,12 +36,34 @@ export const ZeitgeistDeviceEditor = ({lifecycle, service, adapter, deviceHost}:
     const grooveAdapter = adapter.groove() as GrooveShuffleBoxAdapter
     const {amount, duration} = grooveAdapter.namedParameter
     const {project} = service
-    const {editing, liveStreamReceiver, midiLearning} = project
+    const {editing, liveStreamReceiver, midiLearning, regionSelection, tempoMap} = project
+    const extractTemplate = (region: AnyRegionBoxAdapter): GrooveTemplate | null =>
+        isInstanceOf(region, NoteRegionBoxAdapter)
+            ? GrooveTemplate.fromNoteRegion(region, TemplateSteps, TemplateStep)
+            : isInstanceOf(region, AudioRegionBoxAdapter)
+                ? GrooveTemplate.fromAudioRegion(region, tempoMap, TemplateSteps, TemplateStep)
+                : null
     return (
         <DeviceEditor lifecycle={lifecycle}
                       service={service}
                       adapter={adapter}
-                      populateMenu={parent => MenuItems.forEffectDevice(parent, service, deviceHost, adapter)}
+                      populateMenu={parent => {
+                          const selected = regionSelection.selected()
+                          parent.addMenuItem(
+                              MenuItem.default({
+                                  label: "Extract Groove from Selected Region",
+                                  selectable: selected.length === 1
+                                      && (selected[0].type === "note-region" || selected[0].type === "audio-region")
+                              }).setTriggerProcedure(() => {
+                                  const template = extractTemplate(selected[0])
+                                  if (template !== null) {
+                                      editing.modify(() => grooveAdapter.writeTemplate(template))
+                                  }
+                              }),
+                              MenuItem.default({label: "Clear Groove Template", selectable: grooveAdapter.hasTemplate})
+                                  .setTriggerProcedure(() => editing.modify(() => grooveAdapter.clearTemplate())))
+                          MenuItems.forEffectDevice(parent, service, deviceHost, adapter)
+                      }}
                       populateControls={() => (
                           <div className={className}>
                               {ControlBuilder.createKnob({
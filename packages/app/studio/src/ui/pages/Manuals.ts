@@ -59,12 +59,24 @@ export const Manuals: ReadonlyArray<Manual> = [
                         path: "/manuals/devices/audio/autotune",
                         icon: EffectFactories.Autotune.defaultIcon
                     },
+                    {
+                        type: "page",
+                        label: "Chorus",
+                        path: "/manuals/devices/audio/chorus",
+                        icon: EffectFactories.Chorus.defaultIcon
+                    },
                     {
                         type: "page",
                         label: "Compressor",
                         path: "/manuals/devices/audio/compressor",
                         icon: EffectFactories.Compressor.defaultIcon
                     },
+                    {
+                        type: "page",
+                        label: "Convolution Reverb",
+                        path: "/manuals/devices/audio/convolution-reverb",
+                        icon: EffectFactories.ConvolutionReverb.defaultIcon
+                    },
                     {
                         type: "page",
                         label: "Crusher",
@@ -83,6 +95,12 @@ export const Manuals: ReadonlyArray<Manual> = [
                         path: "/manuals/devices/audio/delay",
                         icon: EffectFactories.Delay.defaultIcon
                     },
+                    {
+                        type: "page",
+                        label: "Filter",
+                        path: "/manuals/devices/audio/filter",
+                        icon: EffectFactories.Filter.defaultIcon
+                    },
                     {
                         type: "page",
                         label: "Fold",
@@ -119,6 +137,12 @@ export const Manuals: ReadonlyArray<Manual> = [
                         path: "/manuals/devices/audio/maximizer",
                         icon: EffectFactories.Maximizer.defaultIcon
                     },
+                    {
+                        type: "page",
+                        label: "Multiband Compressor",
+                        path: "/manuals/devices/audio/multiband-compressor",
+                        icon: EffectFactories.MultibandCompressor.defaultIcon
+                    },
                     {
                         type: "page",
                         label: "Revamp",
@@ -179,6 +203,12 @@ export const Manuals: ReadonlyArray<Manual> = [
                         path: "/manuals/devices/instruments/apparat",
                         icon: InstrumentFactories.Apparat.defaultIcon
                     },
+                    {
+                        type: "page",
+                        label: "FM Synth",
+                        path: "/manuals/devices/instruments/fm-synth",
+                        icon: InstrumentFactories.FMSynth.defaultIcon
+                    },
                     {
                         type: "page",
                         label: "MIDIOutput",
@@ -214,6 +244,12 @@ export const Manuals: ReadonlyArray<Manual> = [
                         label: "Vaporisateur",
                         path: "/manuals/devices/instruments/vaporisateur",
                         icon: InstrumentFactories.Vaporisateur.defaultIcon
+                    },
+                    {
+                        type: "page",
+                        label: "Wavetable",
+                        path: "/manuals/devices/instruments/wavetable",
+                        icon: InstrumentFactories.Wavetable.defaultIcon
                     }
                 ]
             },
@@ -233,6 +269,18 @@ export const Manuals: ReadonlyArray<Manual> = [
                         path: "/manuals/devices/midi/pitch",
                         icon: EffectFactories.Pitch.defaultIcon
                     },
+                    {
+                        type: "page",
+                        label: "Ratchet",
+                        path: "/manuals/devices/midi/ratchet",
+                        icon: EffectFactories.MidiNamed.Ratchet.defaultIcon
+                    },
+                    {
+                        type: "page",
+                        label: "Scale",
+                        path: "/manuals/devices/midi/scale",
+                        icon: EffectFactories.MidiNamed.Scale.defaultIcon
+                    },
                     {
                         type: "page",
                         label: "Spielwerk",
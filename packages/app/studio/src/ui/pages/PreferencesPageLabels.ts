@@ -114,7 +114,11 @@ export namespace PreferencesPageLabels {
                 automationEnabled: "Record automation",
                 olderTakeAction: "Older take action",
                 olderTakeScope: "Older take scope",
-                inputLatency: "Input latency"
+                inputLatency: "Input latency",
+                preRollBars: "Pre-roll bars",
+                punchEnabled: "Punch in/out",
+                punchFrom: "Punch-in (ppqn)",
+                punchTo: "Punch-out (ppqn)"
             }
         }
     }
@@ -125,6 +129,10 @@ export namespace PreferencesPageLabels {
         },
         recording: {
             countInBars: EngineSettings.RecordingCountInBars.map(value => ({value, label: `${value}`})),
+            preRollBars: EngineSettings.RecordingPreRollBars.map(value => ({
+                value,
+                label: value === 0 ? "None" : `${value}`
+            })),
             olderTakeAction: EngineSettings.OlderTakeActionOptions.map(value => ({
                 value,
                 label: value === "disable-track" ? "Disable track" : "Mute region"
@@ -13,8 +13,9 @@ import {
     UUID
 } from "@opendaw/lib-std"
 import {BoxEditing} from "@opendaw/lib-box"
-import {NoteEventBox, NoteEventCollectionBox, NoteRegionBox, TrackBox} from "@opendaw/studio-boxes"
+import {NoteControlEventBox, NoteEventBox, NoteEventCollectionBox, NoteRegionBox, TrackBox} from "@opendaw/studio-boxes"
 import {AudioUnitBoxAdapter, ColorCodes, TrackType} from "@opendaw/studio-adapters"
+import {NoteControlType} from "@opendaw/studio-enums"
 import {PPQN, ppqn} from "@opendaw/lib-dsp"
 import {Dialogs} from "@/ui/components/dialogs.tsx"
 import {Promises, Wait} from "@opendaw/lib-runtime"
@@ -69,6 +70,7 @@ export namespace MidiImport {
                     if (midiEvents.every(event => event.type !== ControlType.NOTE_ON && event.type !== ControlType.NOTE_OFF)) {continue}
                     const map = new Map<byte, { position: ppqn, note: byte, velocity: unitValue }>
                     const notes: Array<{ position: ppqn, duration: ppqn, pitch: byte, velocity: unitValue }> = []
+                    const controls: Array<{ position: ppqn, type: NoteControlType, controller: byte, value: number }> = []
                     let duration = 0 | 0
                     for (const midiEvent of midiEvents) {
                         const index = midiEvents.indexOf(midiEvent)
@@ -86,7 +88,13 @@ export namespace MidiImport {
                                     velocity: data.velocity
                                 })
                                 duration = Math.max(duration, position)
-                            }
+                            },
+                            controller: (controller: byte, value: unitValue) =>
+                                controls.push({position, type: NoteControlType.Controller, controller, value}),
+                            pitchBend: (value: number) =>
+                                controls.push({position, type: NoteControlType.PitchBend, controller: 0, value}),
+                            channelPressure: (value: unitValue) =>
+                                controls.push({position, type: NoteControlType.ChannelPressure, controller: 0, value})
                         })
                         progress.setValue(index / midiEvents.length)
                         if (Date.now() - lastTime > 16.0) {
@@ -122,6 +130,15 @@ export namespace MidiImport {
                             box.events.refer(collection.events)
                         })
                     })
+                    controls.forEach(({position, type, controller, value}) => {
+                        NoteControlEventBox.create(boxGraph, UUID.generate(), box => {
+                            box.position.setValue(position)
+                            box.type.setValue(type)
+                            box.controller.setValue(controller)
+                            box.value.setValue(value)
+                            box.events.refer(collection.controls)
+                        })
+                    })
                     NoteRegionBox.create(boxGraph, UUID.generate(), box => {
                         box.position.setValue(0)
                         box.duration.setValue(duration)
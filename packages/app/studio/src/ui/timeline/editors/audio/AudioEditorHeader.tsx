@@ -7,6 +7,7 @@ import {AudioEventOwnerReader} from "@/ui/timeline/editors/EventOwnerReader"
 import {StretchSelector} from "@/ui/timeline/editors/audio/StretchSelector"
 import {NumberInput} from "@/ui/components/NumberInput"
 import {EditWrapper} from "@/ui/wrapper/EditWrapper"
+import {SampleInterpolationSelector} from "@/ui/components/SampleInterpolationSelector"
 
 const className = Html.adoptStyleSheet(css, "AudioEditorHeader")
 
@@ -34,6 +35,10 @@ export const AudioEditorHeader = ({lifecycle, project, reader}: Construct) => {
                              model={EditWrapper.forValue(editing, reader.audioContent.waveformOffset)}/>
                 <span>sec</span>
             </div>
+            <span className="label">Interpolation:</span>
+            <SampleInterpolationSelector lifecycle={lifecycle}
+                                         model={EditWrapper.forValue(editing, reader.audioContent.interpolation)}
+                                         className="interpolation"/>
         </div>
     )
 }
\ No newline at end of file
@@ -1,11 +1,13 @@
 // Decode an openDAW project BUNDLE (.odb) — a JSZip archive holding the project box graph plus its sample (and
 // soundfont) assets. Mirrors the studio `ProjectBundle` format: `version` = "1", `uuid` (the project id, binary),
-// `project.od` (the box graph), `meta.json`, and `samples/<uuid>/audio.wav` (+ peaks/meta, which the engine
-// ignores). This is a PURE decode (no OPFS / no engine) so it is node-testable; the BundlePlayer page writes the
-// returned samples into `SampleStorage` and boots the engine on the returned box graph.
+// `project.od` (the box graph), `meta.json`, `samples/<uuid>/audio.wav` (+ peaks/meta, which the engine
+// ignores) and `soundfonts/<uuid>/soundfont.sf2` (or a `soundfont.sfz` instrument folder). This is a PURE decode
+// (no OPFS / no engine) so it is node-testable; the BundlePlayer page writes the returned samples into
+// `SampleStorage` and boots the engine on the returned box graph.
 import {UUID} from "@opendaw/lib-std"
 import {ProjectSkeleton} from "@opendaw/studio-adapters"
 import type {BoxGraph} from "@opendaw/lib-box"
+import type {SfzFile} from "../../../studio/core-wasm/src/sfz-import"
 
 export type Bundle = {
     version: string
@@ -15,6 +17,7 @@ export type Bundle = {
     meta: unknown
     samples: ReadonlyArray<{uuid: UUID.Bytes, wav: ArrayBuffer}> // extracted audio, keyed by sample uuid
     soundfonts: ReadonlyArray<{uuid: UUID.Bytes, sf2: ArrayBuffer}> // extracted raw .sf2, keyed by soundfont uuid
+    instruments: ReadonlyArray<{uuid: UUID.Bytes, files: ReadonlyArray<SfzFile>}> // SFZ folders, keyed by soundfont uuid
 }
 
 const PROJECT_FILE = "project.od" // ProjectPaths.ProjectFile
@@ -55,6 +58,20 @@ export const decodeBundle = async (arrayBuffer: ArrayBuffer): Promise<Bundle> =>
             pending.push(file.async("arraybuffer").then(sf2 => {soundfonts.push({uuid: soundfontUuid, sf2: sf2 as ArrayBuffer})}))
         })
     }
+    // A `soundfonts/<uuid>/soundfont.sfz` takes its whole folder along (includes and samples, by relative path).
+    const instruments: Array<{uuid: UUID.Bytes, files: Array<SfzFile>}> = []
+    if (soundfontsFolder !== null) {
+        soundfontsFolder.forEach((relativePath, file) => {
+            if (file.dir || !relativePath.endsWith("/soundfont.sfz")) {return}
+            const id = relativePath.slice(0, relativePath.indexOf("/"))
+            const files: Array<SfzFile> = []
+            instruments.push({uuid: UUID.parse(id) as UUID.Bytes, files})
+            soundfontsFolder.folder(id)?.forEach((path, entry) => {
+                if (entry.dir) {return}
+                pending.push(entry.async("arraybuffer").then(bytes => {files.push({path, bytes: bytes as ArrayBuffer})}))
+            })
+        })
+    }
     await Promise.all(pending)
-    return {version, uuid, boxGraph, project, meta, samples, soundfonts}
+    return {version, uuid, boxGraph, project, meta, samples, soundfonts, instruments}
 }
@@ -14,6 +14,7 @@ import {ScriptBridges, ScriptEngine} from "../../../../studio/core-wasm/src/scri
 import {NamBridges} from "../../../../studio/core-wasm/src/nam-bridge"
 import {linkDevice, registerComposite, registerEffectComposite} from "../../../../studio/core-wasm/src/device-linker"
 import {loadEngineModules} from "../../../../studio/core-wasm/src/engine-modules"
+import {SfzImporter} from "../../../../studio/core-wasm/src/sfz-import"
 import {loadSoundfontBlob, simplifySoundfontBytes} from "../soundfont-fetch"
 import type {Bundle} from "../bundle"
 import type {OfflineResult} from "./result"
@@ -108,9 +109,12 @@ const drainResources = async (engine: any, memory: WebAssembly.Memory, bundle: B
         const uuid = new Uint8Array(memory.buffer.slice(rp, rp + 16)) as UUID.Bytes
         const uuidString = UUID.toString(uuid)
         const carried = bundle.soundfonts.find(entry => UUID.toString(entry.uuid) === uuidString)
+        const instrument = bundle.instruments.find(entry => UUID.toString(entry.uuid) === uuidString)
         try {
-            // Prefer the .sf2 the bundle carries (no network); fall back to the asset server otherwise.
-            const blob = new Uint8Array(carried !== undefined ? await simplifySoundfontBytes(carried.sf2) : await loadSoundfontBlob(uuid))
+            // Prefer what the bundle carries (an SFZ folder, then an .sf2; no network); fall back to the asset server.
+            const blob = new Uint8Array(instrument !== undefined
+                ? await (await SfzImporter.load("/wasm/sfz_wasm.wasm")).build(instrument.files, "soundfont.sfz")
+                : carried !== undefined ? await simplifySoundfontBytes(carried.sf2) : await loadSoundfontBlob(uuid))
             const pointer = engine.soundfont_allocate(handle, blob.byteLength)
             new Uint8Array(memory.buffer, pointer, blob.byteLength).set(blob)
             engine.soundfont_set_ready(handle)
@@ -11,19 +11,19 @@ import {readFileSync} from "node:fs"
 import {isDefined, Option, Optional, panic, Terminable, UUID} from "@opendaw/lib-std"
 import {Address, BoxGraph, Constraints, Float32Field, PrimitiveType} from "@opendaw/lib-box"
 import {
-    ArpeggioDeviceBox, AudioFileBox, AudioUnitBox, AutotuneDeviceBox, CompressorDeviceBox, CrusherDeviceBox, DattorroReverbDeviceBox,
-    DelayDeviceBox, FoldDeviceBox, GateDeviceBox, MaximizerDeviceBox, NanoDeviceBox, NeuralAmpDeviceBox,
-    PitchDeviceBox, PlayfieldDeviceBox, PlayfieldSampleBox, RevampDeviceBox, ReverbDeviceBox, StereoToolDeviceBox,
-    TidalDeviceBox, VaporisateurDeviceBox, VelocityDeviceBox, VocoderDeviceBox, WaveshaperDeviceBox
+    ArpeggioDeviceBox, AudioFileBox, AudioUnitBox, AutotuneDeviceBox, ChorusDeviceBox, CompressorDeviceBox, ConvolutionReverbDeviceBox, CrusherDeviceBox, DattorroReverbDeviceBox,
+    DelayDeviceBox, FMSynthDeviceBox, FilterDeviceBox, FoldDeviceBox, GateDeviceBox, MaximizerDeviceBox, MultibandCompressorDeviceBox, NanoDeviceBox, NeuralAmpDeviceBox,
+    PitchDeviceBox, PlayfieldDeviceBox, PlayfieldSampleBox, RatchetDeviceBox, RevampDeviceBox, ReverbDeviceBox, ScaleDeviceBox, StereoToolDeviceBox,
+    TidalDeviceBox, VaporisateurDeviceBox, VelocityDeviceBox, VocoderDeviceBox, WaveshaperDeviceBox, WavetableDeviceBox
 } from "@opendaw/studio-boxes"
 import {
-    ArpeggioDeviceBoxAdapter, AutotuneDeviceBoxAdapter, AutomatableParameterFieldAdapter, BoxAdapters, BoxAdaptersContext, CompressorDeviceBoxAdapter,
-    CrusherDeviceBoxAdapter, DattorroReverbDeviceBoxAdapter, DelayDeviceBoxAdapter, FoldDeviceBoxAdapter,
-    GateDeviceBoxAdapter, MaximizerDeviceBoxAdapter, NanoDeviceBoxAdapter, NeuralAmpDeviceBoxAdapter,
+    ArpeggioDeviceBoxAdapter, AutotuneDeviceBoxAdapter, AutomatableParameterFieldAdapter, BoxAdapters, BoxAdaptersContext, ChorusDeviceBoxAdapter, CompressorDeviceBoxAdapter,
+    ConvolutionReverbDeviceBoxAdapter, CrusherDeviceBoxAdapter, DattorroReverbDeviceBoxAdapter, DelayDeviceBoxAdapter, FMSynthDeviceBoxAdapter, FilterDeviceBoxAdapter, FoldDeviceBoxAdapter,
+    GateDeviceBoxAdapter, MaximizerDeviceBoxAdapter, MultibandCompressorDeviceBoxAdapter, NanoDeviceBoxAdapter, NeuralAmpDeviceBoxAdapter,
     ParameterFieldAdapters, PitchDeviceBoxAdapter, PlayfieldSampleBoxAdapter, ProjectSkeleton,
-    RevampDeviceBoxAdapter, ReverbDeviceBoxAdapter, SampleLoader, SampleLoaderManager, StereoToolDeviceBoxAdapter,
+    RatchetDeviceBoxAdapter, RevampDeviceBoxAdapter, ReverbDeviceBoxAdapter, SampleLoader, ScaleDeviceBoxAdapter, SampleLoaderManager, StereoToolDeviceBoxAdapter,
     TidalDeviceBoxAdapter, VaporisateurDeviceBoxAdapter, VelocityDeviceBoxAdapter, VocoderDeviceBoxAdapter,
-    WaveshaperDeviceBoxAdapter
+    WaveshaperDeviceBoxAdapter, WavetableDeviceBoxAdapter
 } from "@opendaw/studio-adapters"
 import {DEVICE_STACK_SIZE, DeviceExports, parseDylink} from "../../../studio/core-wasm/src/device-linker"
 
@@ -104,15 +104,25 @@ const buildBoxes = () => {
     const vocoder = VocoderDeviceBox.create(boxGraph, UUID.generate(), box => {box.host.refer(effectUnit.audioEffects); box.index.setValue(12)})
     const waveshaper = WaveshaperDeviceBox.create(boxGraph, UUID.generate(), box => {box.host.refer(effectUnit.audioEffects); box.index.setValue(13)})
     const autotune = AutotuneDeviceBox.create(boxGraph, UUID.generate(), box => {box.host.refer(effectUnit.audioEffects); box.index.setValue(14)})
+    const filter = FilterDeviceBox.create(boxGraph, UUID.generate(), box => {box.host.refer(effectUnit.audioEffects); box.index.setValue(15)})
+    const chorus = ChorusDeviceBox.create(boxGraph, UUID.generate(), box => {box.host.refer(effectUnit.audioEffects); box.index.setValue(16)})
+    const convolutionReverb = ConvolutionReverbDeviceBox.create(boxGraph, UUID.generate(), box => {box.host.refer(effectUnit.audioEffects); box.index.setValue(17)})
+    const multibandCompressor = MultibandCompressorDeviceBox.create(boxGraph, UUID.generate(), box => {box.host.refer(effectUnit.audioEffects); box.index.setValue(18)})
     const arpeggio = ArpeggioDeviceBox.create(boxGraph, UUID.generate(), box => {box.host.refer(effectUnit.midiEffects); box.index.setValue(0)})
     const pitch = PitchDeviceBox.create(boxGraph, UUID.generate(), box => {box.host.refer(effectUnit.midiEffects); box.index.setValue(1)})
     const velocity = VelocityDeviceBox.create(boxGraph, UUID.generate(), box => {box.host.refer(effectUnit.midiEffects); box.index.setValue(2)})
+    const scale = ScaleDeviceBox.create(boxGraph, UUID.generate(), box => {box.host.refer(effectUnit.midiEffects); box.index.setValue(3)})
+    const ratchet = RatchetDeviceBox.create(boxGraph, UUID.generate(), box => {box.host.refer(effectUnit.midiEffects); box.index.setValue(4)})
     const vaporisateurUnit = createUnit(2)
     const nanoUnit = createUnit(3)
     const playfieldUnit = createUnit(4)
+    const wavetableUnit = createUnit(5)
+    const fmSynthUnit = createUnit(6)
     const vaporisateur = VaporisateurDeviceBox.create(boxGraph, UUID.generate(), box => box.host.refer(vaporisateurUnit.input))
     const nano = NanoDeviceBox.create(boxGraph, UUID.generate(), box => box.host.refer(nanoUnit.input))
     const playfield = PlayfieldDeviceBox.create(boxGraph, UUID.generate(), box => box.host.refer(playfieldUnit.input))
+    const wavetable = WavetableDeviceBox.create(boxGraph, UUID.generate(), box => box.host.refer(wavetableUnit.input))
+    const fmSynth = FMSynthDeviceBox.create(boxGraph, UUID.generate(), box => box.host.refer(fmSynthUnit.input))
     const file = AudioFileBox.create(boxGraph, UUID.generate(), box => {
         box.startInSeconds.setValue(0.0)
         box.endInSeconds.setValue(1.0)
@@ -125,7 +135,7 @@ const buildBoxes = () => {
     })
     boxGraph.endTransaction()
     return {boxGraph, compressor, crusher, dattorro, delay, fold, gate, maximizer, neuralAmp, revamp, reverb,
-        stereoTool, tidal, vocoder, waveshaper, autotune, arpeggio, pitch, velocity, vaporisateur, nano, playfieldSample}
+        stereoTool, tidal, vocoder, waveshaper, autotune, filter, chorus, convolutionReverb, multibandCompressor, arpeggio, pitch, velocity, scale, ratchet, vaporisateur, nano, playfieldSample, wavetable, fmSynth}
 }
 
 const boxes = buildBoxes()
@@ -200,20 +210,30 @@ const CASES: ReadonlyArray<DeviceCase> = [
         createAdapter: context => new ArpeggioDeviceBoxAdapter(context, boxes.arpeggio), tsOnly: []},
     {name: "autotune", file: "device_autotune.wasm",
         createAdapter: context => new AutotuneDeviceBoxAdapter(context, boxes.autotune), tsOnly: []},
+    {name: "chorus", file: "device_chorus.wasm",
+        createAdapter: context => new ChorusDeviceBoxAdapter(context, boxes.chorus), tsOnly: []},
     {name: "compressor", file: "device_compressor.wasm",
         createAdapter: context => new CompressorDeviceBoxAdapter(context, boxes.compressor), tsOnly: []},
+    {name: "convolution-reverb", file: "device_convolution_reverb.wasm",
+        createAdapter: context => new ConvolutionReverbDeviceBoxAdapter(context, boxes.convolutionReverb), tsOnly: []},
     {name: "crusher", file: "device_crusher.wasm",
         createAdapter: context => new CrusherDeviceBoxAdapter(context, boxes.crusher), tsOnly: []},
     {name: "dattorro-reverb", file: "device_dattorro_reverb.wasm",
         createAdapter: context => new DattorroReverbDeviceBoxAdapter(context, boxes.dattorro), tsOnly: []},
     {name: "delay", file: "device_delay.wasm",
         createAdapter: context => new DelayDeviceBoxAdapter(context, boxes.delay), tsOnly: []},
+    {name: "filter", file: "device_filter.wasm",
+        createAdapter: context => new FilterDeviceBoxAdapter(context, boxes.filter), tsOnly: []},
+    {name: "fm-synth", file: "device_fm_synth.wasm",
+        createAdapter: context => new FMSynthDeviceBoxAdapter(context, boxes.fmSynth), tsOnly: []},
     {name: "fold", file: "device_fold.wasm",
         createAdapter: context => new FoldDeviceBoxAdapter(context, boxes.fold), tsOnly: []},
     {name: "gate", file: "device_gate.wasm",
         createAdapter: context => new GateDeviceBoxAdapter(context, boxes.gate), tsOnly: []},
     {name: "maximizer", file: "device_maximizer.wasm",
         createAdapter: context => new MaximizerDeviceBoxAdapter(context, boxes.maximizer), tsOnly: []},
+    {name: "multiband-compressor", file: "device_multiband_compressor.wasm",
+        createAdapter: context => new MultibandCompressorDeviceBoxAdapter(context, boxes.multibandCompressor), tsOnly: []},
     {name: "nano", file: "device_nano.wasm",
         createAdapter: context => new NanoDeviceBoxAdapter(context, boxes.nano), tsOnly: []},
     {name: "neural-amp", file: "device_neural_amp.wasm",
@@ -226,10 +246,14 @@ const CASES: ReadonlyArray<DeviceCase> = [
         // composite registration (childMuteKey / childSoloKey / excludeKey), not device parameters.
         tsOnly: [fieldPath(boxes.playfieldSample.mute.address), fieldPath(boxes.playfieldSample.solo.address),
             fieldPath(boxes.playfieldSample.exclude.address)]},
+    {name: "ratchet", file: "device_ratchet.wasm",
+        createAdapter: context => new RatchetDeviceBoxAdapter(context, boxes.ratchet), tsOnly: []},
     {name: "revamp", file: "device_revamp.wasm",
         createAdapter: context => new RevampDeviceBoxAdapter(context, boxes.revamp), tsOnly: []},
     {name: "reverb", file: "device_reverb.wasm",
         createAdapter: context => new ReverbDeviceBoxAdapter(context, boxes.reverb), tsOnly: []},
+    {name: "scale", file: "device_scale.wasm",
+        createAdapter: context => new ScaleDeviceBoxAdapter(context, boxes.scale), tsOnly: []},
     {name: "stereo-tool", file: "device_stereo_tool.wasm",
         createAdapter: context => new StereoToolDeviceBoxAdapter(context, boxes.stereoTool), tsOnly: []},
     {name: "tidal", file: "device_tidal.wasm",
@@ -245,7 +269,9 @@ const CASES: ReadonlyArray<DeviceCase> = [
     {name: "vocoder", file: "device_vocoder.wasm",
         createAdapter: context => new VocoderDeviceBoxAdapter(context, boxes.vocoder), tsOnly: []},
     {name: "waveshaper", file: "device_waveshaper.wasm",
-        createAdapter: context => new WaveshaperDeviceBoxAdapter(context, boxes.waveshaper), tsOnly: []}
+        createAdapter: context => new WaveshaperDeviceBoxAdapter(context, boxes.waveshaper), tsOnly: []},
+    {name: "wavetable", file: "device_wavetable.wasm",
+        createAdapter: context => new WavetableDeviceBoxAdapter(context, boxes.wavetable), tsOnly: []}
 ]
 
 const expectValue = (rust: number, tsValue: unknown, type: PrimitiveType, label: string): void => {
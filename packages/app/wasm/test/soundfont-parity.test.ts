@@ -0,0 +1,19 @@
+// Pins the TS soundfont builder to the shared fixture pair in test-files/soundfont/: `parity.sf2` parsed with
+// `soundfont2` and flattened by `simplifySoundfont` must give exactly `parity.osf2`. The native builder
+// (crates/soundfont-blob, tests/parity.rs) is held to the same bytes, so both sides build the same blob.
+import * as path from "node:path"
+import {readFileSync} from "node:fs"
+import {describe, expect, it} from "vitest"
+import {simplifySoundfont} from "../../../studio/core-wasm/src/soundfont-simplify"
+import {parseSoundfont} from "../src/soundfont-fetch"
+
+const FIXTURES = path.resolve(__dirname, "../../../../test-files/soundfont")
+
+describe("soundfont parity", () => {
+    it("builds the fixture blob from the fixture font", async () => {
+        const bytes = readFileSync(path.join(FIXTURES, "parity.sf2"))
+        const font = await parseSoundfont(bytes.buffer.slice(bytes.byteOffset, bytes.byteOffset + bytes.byteLength) as ArrayBuffer)
+        const expected = new Uint8Array(readFileSync(path.join(FIXTURES, "parity.osf2")))
+        expect(new Uint8Array(simplifySoundfont(font))).toEqual(expected)
+    })
+})
@@ -1,12 +1,15 @@
 import {writeFileSync} from "node:fs"
 import {isDefined, panic} from "@opendaw/lib-std"
-import {PointerTypes} from "@opendaw/lib-box"
-import {AnyField, FieldRecord, Schema} from "./schema"
+import {NoPointers, PointerRules, PointerTypes} from "@opendaw/lib-box"
+import {AnyField, BoxSchema, FieldRecord, Schema} from "./schema"
 
 // Emits the Rust box-schema registry consumed by the `boxgraph` reader. Unlike the TS emitter
 // (one class file per box), Rust gets ONE `registry.rs` building a `name -> field-schema` map.
-// Only serialization-relevant data is emitted: field key -> type, array length, nested objects.
-// Ignored (UI/validation only): constraints, units, defaults, pointer rules, value curves, tags.
+// Serialization data (field key -> type, array length, nested objects) goes into `registry()`; the
+// pointer contract (pointer types, mandatory/exclusive, accepted types, collection indices) into
+// `rules()`, checked by `BoxGraph::validate`; field names into `names()`, for the JSON codec. Pointer
+// types are emitted by enum member name.
+// Ignored (UI only): constraints, units, defaults, value curves, tags.
 
 const rustFieldType = <E extends PointerTypes>(field: AnyField<E>): string => {
     switch (field.type) {
@@ -42,21 +45,89 @@ const rustSchema = <E extends PointerTypes>(fields: FieldRecord<E>): string => {
     return entries.length === 0 ? "Schema::new()" : `Schema::from([${entries.join(", ")}])`
 }
 
+const rustNames = <E extends PointerTypes>(fields: FieldRecord<E>): string => {
+    const nested = (field: AnyField<E>): string => field.type === "object" ? rustNames(field.class.fields)
+        : field.type === "array" ? nested(field.element) : "&[]"
+    const entries = Object.entries(fields)
+        .filter(([_, field]) => field.type !== "reserved" && field.deprecated !== true)
+        .map(([key, field]) => `FieldName {key: ${Number(key)}, name: "${field.name}", fields: ${nested(field)}}`)
+    return `&[${entries.join(", ")}]`
+}
+
+type RuleEntries = { pointers: Array<string>, targets: Array<string>, index: Array<string> }
+
+const rustPath = (path: ReadonlyArray<number>): string => `&[${path.join(", ")}]`
+
+const rustTarget = <E extends PointerTypes>(rules: PointerRules<E>, print: (type: E) => string): string => {
+    const accepts = rules.accepts.map(type => `"${print(type)}"`).join(", ")
+    return `Target {accepts: &[${accepts}], mandatory: ${rules.mandatory}, exclusive: ${rules.exclusive === true}}`
+}
+
+const collectRules = <E extends PointerTypes>(fields: FieldRecord<E>, path: ReadonlyArray<number>,
+                                              print: (type: E) => string, entries: RuleEntries): void => {
+    const visit = (field: AnyField<E>, path: ReadonlyArray<number>): void => {
+        if (field.type === "reserved" || field.deprecated === true) {return}
+        switch (field.type) {
+            case "pointer":
+                entries.pointers.push(`(${rustPath(path)}, Pointer {pointer_type: "${print(field.pointerType)}", mandatory: ${field.mandatory}})`)
+                return
+            case "object":
+                return collectRules(field.class.fields, path, print, entries)
+            case "array":
+                for (let index = 0; index < field.length; index++) {visit(field.element, [...path, index])}
+                return
+            default:
+                if (isDefined(field.pointerRules)) {
+                    entries.targets.push(`(${rustPath(path)}, ${rustTarget(field.pointerRules, print)})`)
+                }
+                if (field.type === "int32" && field.constraints === "index") {
+                    entries.index.push(rustPath(path))
+                }
+        }
+    }
+    Object.entries(fields).forEach(([key, field]) => visit(field, [...path, Number(key)]))
+}
+
+const rustBoxRules = <E extends PointerTypes>(box: BoxSchema<E>, print: (type: E) => string): string => {
+    const entries: RuleEntries = {pointers: [], targets: [], index: []}
+    collectRules(box.class.fields, [], print, entries)
+    // The collection a box is indexed in is the one its first mandatory pointer joins. Event boxes order by
+    // (position, index): there the index only separates events at one position, so they get no index rule.
+    const fields = Object.values(box.class.fields)
+    const collection = Object.entries(box.class.fields)
+        .find(([_, field]) => field.type === "pointer" && field.mandatory && field.deprecated !== true)
+    const positioned = fields.some(field => field.name === "position")
+    const index = entries.index.length === 1 && isDefined(collection) && !positioned
+        ? `Some(Index {field: ${entries.index[0]}, collection: &[${collection[0]}]})` : "None"
+    const target = rustTarget(box.pointerRules ?? NoPointers, print)
+    return `BoxRules {target: ${target}, pointers: &[${entries.pointers.join(", ")}], targets: &[${entries.targets.join(", ")}], index: ${index}}`
+}
+
 export const writeRustRegistry = <E extends PointerTypes>(schema: Schema<E>, path: string): void => {
     const boxes = schema.boxes
         .map(box => `        ("${box.class.name}".to_string(), ${rustSchema(box.class.fields)}),`)
         .join("\n")
+    const print = (type: E): string => schema.pointers.print(type).split(".").pop()!
+    const rules = schema.boxes
+        .map(box => `        ("${box.class.name}".to_string(), ${rustBoxRules(box, print)}),`)
+        .join("\n")
+    const names = schema.boxes
+        .map(box => `        ("${box.class.name}".to_string(), ${rustNames(box.class.fields)} as &[FieldName]),`)
+        .join("\n")
     const usesBox = boxes.includes("Box::new")
     const imports = [
         usesBox ? "use alloc::boxed::Box;" : null,
         "use alloc::string::ToString;",
-        "use boxgraph::boxes::Registry;",
-        "use boxgraph::field::{FieldType, Schema};"
+        "use boxgraph::boxes::{FieldName, Names, Registry};",
+        "use boxgraph::field::{FieldType, Schema};",
+        "use boxgraph::rules::{BoxRules, Index, Pointer, Rules, Target};"
     ].filter(isDefined).join("\n")
     const body = `// @generated by box-forge — do not edit.
 // Regenerate via: npm run build -w @opendaw/studio-forge-boxes
 //
-// The openDAW box-schema registry (name -> field schema) consumed by the \`boxgraph\` reader.
+// The openDAW box-schema registry (name -> field schema) consumed by the \`boxgraph\` reader, and the
+// pointer rules (name -> pointer contract) checked by \`BoxGraph::validate\`, and the field names
+// (name -> field names) used by \`BoxGraph::to_json\` / \`from_json\`.
 
 ${imports}
 
@@ -65,6 +136,18 @@ pub fn registry() -> Registry {
 ${boxes}
     ])
 }
+
+pub fn rules() -> Rules {
+    Rules::from([
+${rules}
+    ])
+}
+
+pub fn names() -> Names {
+    Names::from([
+${names}
+    ])
+}
 `
     writeFileSync(path, body)
 }
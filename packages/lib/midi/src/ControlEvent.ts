@@ -57,6 +57,10 @@ export class ControlEvent implements Event<ControlType> {
                 safeExecute(visitor.controller, this.param0, this.param1 / 127.0)
                 break
             }
+            case ControlType.CHANNEL_AFTER_TOUCH: {
+                safeExecute(visitor.channelPressure, this.param0 / 127.0)
+                break
+            }
             default: {break}
         }
     }
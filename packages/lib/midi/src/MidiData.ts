@@ -4,7 +4,7 @@ import {ppqn} from "@opendaw/lib-dsp"
 
 export namespace MidiData {
     export enum Command {
-        NoteOn = 0x90, NoteOff = 0x80, PitchBend = 0xE0, Controller = 0xB0,
+        NoteOn = 0x90, NoteOff = 0x80, PitchBend = 0xE0, Controller = 0xB0, ChannelPressure = 0xD0,
         Start = 0xFA, Continue = 0xFB, Stop = 0xFC, Clock = 0xF8, Position = 0xF2
     }
 
@@ -19,6 +19,7 @@ export namespace MidiData {
     export const isNoteOff = (d: Uint8Array) => readCommand(d) === Command.NoteOff || (readCommand(d) === Command.NoteOn && readVelocity(d) === 0)
     export const isPitchWheel = (d: Uint8Array) => readCommand(d) === Command.PitchBend
     export const isController = (d: Uint8Array) => readCommand(d) === Command.Controller
+    export const isChannelPressure = (d: Uint8Array) => readCommand(d) === Command.ChannelPressure
     export const isClock = (d: Uint8Array) => d[0] === Command.Clock
     export const isStart = (d: Uint8Array) => d[0] === Command.Start
     export const isContinue = (d: Uint8Array) => d[0] === Command.Continue
@@ -57,6 +58,7 @@ export namespace MidiData {
         else if (isNoteOff(data)) safeExecute(v.noteOff, readPitch(data))
         else if (isPitchWheel(data)) safeExecute(v.pitchBend, asPitchBend(data))
         else if (isController(data)) safeExecute(v.controller, readParam1(data), readParam2(data) / 127)
+        else if (isChannelPressure(data)) safeExecute(v.channelPressure, readParam1(data) / 127)
         else if (isClock(data)) safeExecute(v.clock)
         else if (isStart(data)) safeExecute(v.start)
         else if (isContinue(data)) safeExecute(v.continue)
@@ -6,6 +6,7 @@ export interface MidiEventVisitor {
     noteOff?(note: byte): void
     pitchBend?(delta: number): void
     controller?(id: byte, value: unitValue): void
+    channelPressure?(value: unitValue): void
     clock?(): void
     start?(): void
     continue?(): void
@@ -26,11 +26,15 @@ import {
     AutotuneDeviceBox,
     AuxSendBox,
     BoxVisitor,
+    ChorusDeviceBox,
     CompressorDeviceBox,
+    ConvolutionReverbDeviceBox,
     CrusherDeviceBox,
     DattorroReverbDeviceBox,
     DelayDeviceBox,
     DeviceInterfaceKnobBox,
+    FMSynthDeviceBox,
+    FilterDeviceBox,
     FoldDeviceBox,
     GateDeviceBox,
     GrooveShuffleBox,
@@ -45,6 +49,7 @@ import {
     ModuleDelayBox,
     ModuleGainBox,
     ModuleMultiplierBox,
+    MultibandCompressorDeviceBox,
     NanoDeviceBox,
     NeuralAmpDeviceBox,
     NeuralAmpModelBox,
@@ -53,6 +58,8 @@ import {
     NoteEventCollectionBox,
     NoteRegionBox,
     PitchDeviceBox,
+    RatchetDeviceBox,
+    ScaleDeviceBox,
     AudioEffectCompositeBox,
     AudioEffectCompositeCellBox,
     PlayfieldDeviceBox,
@@ -83,6 +90,7 @@ import {
     VocoderDeviceBox,
     WarpMarkerBox,
     WaveshaperDeviceBox,
+    WavetableDeviceBox,
     WerkstattDeviceBox,
     ZeitgeistDeviceBox
 } from "@opendaw/studio-boxes"
@@ -118,8 +126,12 @@ import {ValueClipBoxAdapter} from "./timeline/clip/ValueClipBoxAdapter"
 import {TrackBoxAdapter} from "./timeline/TrackBoxAdapter"
 import {TapeDeviceBoxAdapter} from "./devices/instruments/TapeDeviceBoxAdapter"
 import {VaporisateurDeviceBoxAdapter} from "./devices/instruments/VaporisateurDeviceBoxAdapter"
+import {WavetableDeviceBoxAdapter} from "./devices/instruments/WavetableDeviceBoxAdapter"
+import {FMSynthDeviceBoxAdapter} from "./devices/instruments/FMSynthDeviceBoxAdapter"
 import {ArpeggioDeviceBoxAdapter} from "./devices/midi-effects/ArpeggioDeviceBoxAdapter"
 import {PitchDeviceBoxAdapter} from "./devices/midi-effects/PitchDeviceBoxAdapter"
+import {RatchetDeviceBoxAdapter} from "./devices/midi-effects/RatchetDeviceBoxAdapter"
+import {ScaleDeviceBoxAdapter} from "./devices/midi-effects/ScaleDeviceBoxAdapter"
 import {SpielwerkDeviceBoxAdapter} from "./devices/midi-effects/SpielwerkDeviceBoxAdapter"
 import {ApparatDeviceBoxAdapter} from "./devices/instruments/ApparatDeviceBoxAdapter"
 import {NanoDeviceBoxAdapter} from "./devices/instruments/NanoDeviceBoxAdapter"
@@ -144,6 +156,10 @@ import {GateDeviceBoxAdapter} from "./devices/audio-effects/GateDeviceBoxAdapter
 import {AutotuneDeviceBoxAdapter} from "./devices/audio-effects/AutotuneDeviceBoxAdapter"
 import {CrusherDeviceBoxAdapter} from "./devices/audio-effects/CrusherDeviceBoxAdapter"
 import {FoldDeviceBoxAdapter} from "./devices/audio-effects/FoldDeviceBoxAdapter"
+import {FilterDeviceBoxAdapter} from "./devices/audio-effects/FilterDeviceBoxAdapter"
+import {ChorusDeviceBoxAdapter} from "./devices/audio-effects/ChorusDeviceBoxAdapter"
+import {ConvolutionReverbDeviceBoxAdapter} from "./devices/audio-effects/ConvolutionReverbDeviceBoxAdapter"
+import {MultibandCompressorDeviceBoxAdapter} from "./devices/audio-effects/MultibandCompressorDeviceBoxAdapter"
 import {MIDIOutputDeviceBoxAdapter} from "./devices/instruments/MIDIOutputDeviceBoxAdapter"
 import {VelocityDeviceBoxAdapter} from "./devices/midi-effects/VelocityDeviceBoxAdapter"
 import {TidalDeviceBoxAdapter} from "./devices/audio-effects/TidalDeviceBoxAdapter"
@@ -235,7 +251,12 @@ export class BoxAdapters implements Terminable {
             visitDelayDeviceBox: (box: DelayDeviceBox) => new DelayDeviceBoxAdapter(this.#context, box),
             visitDeviceInterfaceKnobBox: (box: DeviceInterfaceKnobBox) => new DeviceInterfaceKnobAdapter(this.#context, box),
             visitTidalDeviceBox: (box: TidalDeviceBox) => new TidalDeviceBoxAdapter(this.#context, box),
+            visitFMSynthDeviceBox: (box: FMSynthDeviceBox) => new FMSynthDeviceBoxAdapter(this.#context, box),
             visitFoldDeviceBox: (box: FoldDeviceBox) => new FoldDeviceBoxAdapter(this.#context, box),
+            visitFilterDeviceBox: (box: FilterDeviceBox) => new FilterDeviceBoxAdapter(this.#context, box),
+            visitChorusDeviceBox: (box: ChorusDeviceBox) => new ChorusDeviceBoxAdapter(this.#context, box),
+            visitConvolutionReverbDeviceBox: (box: ConvolutionReverbDeviceBox) => new ConvolutionReverbDeviceBoxAdapter(this.#context, box),
+            visitMultibandCompressorDeviceBox: (box: MultibandCompressorDeviceBox) => new MultibandCompressorDeviceBoxAdapter(this.#context, box),
             visitGrooveShuffleBox: (box: GrooveShuffleBox) => new GrooveShuffleBoxAdapter(this.#context, box),
             visitMarkerBox: (box: MarkerBox) => new MarkerBoxAdapter(this.#context, box),
             visitSignatureEventBox: (box: SignatureEventBox) => new SignatureEventBoxAdapter(this.#context, box),
@@ -256,6 +277,8 @@ export class BoxAdapters implements Terminable {
             visitNoteEventCollectionBox: (box: NoteEventCollectionBox): BoxAdapter => new NoteEventCollectionBoxAdapter(this.#context, box),
             visitNoteRegionBox: (box: NoteRegionBox) => new NoteRegionBoxAdapter(this.#context, box),
             visitPitchDeviceBox: (box: PitchDeviceBox) => new PitchDeviceBoxAdapter(this.#context, box),
+            visitRatchetDeviceBox: (box: RatchetDeviceBox) => new RatchetDeviceBoxAdapter(this.#context, box),
+            visitScaleDeviceBox: (box: ScaleDeviceBox) => new ScaleDeviceBoxAdapter(this.#context, box),
             visitPlayfieldDeviceBox: (box: PlayfieldDeviceBox) => new PlayfieldDeviceBoxAdapter(this.#context, box),
             visitPlayfieldSampleBox: (box: PlayfieldSampleBox) => new PlayfieldSampleBoxAdapter(this.#context, box),
             visitAudioEffectCompositeBox: (box: AudioEffectCompositeBox) => new AudioEffectCompositeBoxAdapter(this.#context, box),
@@ -281,6 +304,7 @@ export class BoxAdapters implements Terminable {
             visitVaporisateurDeviceBox: (box: VaporisateurDeviceBox) => new VaporisateurDeviceBoxAdapter(this.#context, box),
             visitVocoderDeviceBox: (box: VocoderDeviceBox) => new VocoderDeviceBoxAdapter(this.#context, box),
             visitWaveshaperDeviceBox: (box: WaveshaperDeviceBox) => new WaveshaperDeviceBoxAdapter(this.#context, box),
+            visitWavetableDeviceBox: (box: WavetableDeviceBox) => new WavetableDeviceBoxAdapter(this.#context, box),
             visitWerkstattDeviceBox: (box: WerkstattDeviceBox) => new WerkstattDeviceBoxAdapter(this.#context, box),
             visitVelocityDeviceBox: (box: VelocityDeviceBox) => new VelocityDeviceBoxAdapter(this.#context, box),
             visitZeitgeistDeviceBox: (box: ZeitgeistDeviceBox) => new ZeitgeistDeviceBoxAdapter(this.#context, box)
@@ -5,6 +5,8 @@ export namespace DeviceManualUrls {
     // MIDI Effects
     export const Arpeggio = "manuals/devices/midi/arpeggio"
     export const Pitch = "manuals/devices/midi/pitch"
+    export const Ratchet = "manuals/devices/midi/ratchet"
+    export const Scale = "manuals/devices/midi/scale"
     export const Spielwerk = "manuals/devices/midi/spielwerk"
     export const Velocity = "manuals/devices/midi/velocity"
     export const Zeitgeist = "manuals/devices/midi/zeitgeist"
@@ -20,6 +22,10 @@ export namespace DeviceManualUrls {
     export const Reverb = "manuals/devices/audio/cheap-reverb"
     export const Crusher = "manuals/devices/audio/crusher"
     export const Fold = "manuals/devices/audio/fold"
+    export const Filter = "manuals/devices/audio/filter"
+    export const Chorus = "manuals/devices/audio/chorus"
+    export const ConvolutionReverb = "manuals/devices/audio/convolution-reverb"
+    export const MultibandCompressor = "manuals/devices/audio/multiband-compressor"
     export const Tidal = "manuals/devices/audio/tidal"
     export const Revamp = "manuals/devices/audio/revamp"
     export const Modular = "manuals/devices/audio/modular"
@@ -38,5 +44,7 @@ export namespace DeviceManualUrls {
     export const Vaporisateur = "manuals/devices/instruments/vaporisateur"
     export const MIDIOutput = "manuals/devices/instruments/midi-output"
     export const Soundfont = "manuals/devices/instruments/soundfont"
+    export const Wavetable = "manuals/devices/instruments/wavetable"
+    export const FMSynth = "manuals/devices/instruments/fm-synth"
     export const FrequencySplit = "manuals/devices/audio/frequency-split"
 }
\ No newline at end of file
@@ -1,4 +1,4 @@
-import {byte, panic, unitValue, UUID} from "@opendaw/lib-std"
+import {bipolar, byte, panic, unitValue, UUID} from "@opendaw/lib-std"
 import {MidiData} from "@opendaw/lib-midi"
 import {ppqn} from "@opendaw/lib-dsp"
 
@@ -14,7 +14,19 @@ export type NoteSignalAudition = {
     velocity: unitValue
 }
 
-export type NoteSignal = NoteSignalOn | NoteSignalOff | NoteSignalAudition
+export type NoteSignalControl = { type: "control", uuid: UUID.Bytes, controller: byte, value: unitValue }
+
+export type NoteSignalPitchBend = { type: "pitch-bend", uuid: UUID.Bytes, value: bipolar }
+
+export type NoteSignalChannelPressure = { type: "channel-pressure", uuid: UUID.Bytes, value: unitValue }
+
+export type NoteSignal =
+    | NoteSignalOn
+    | NoteSignalOff
+    | NoteSignalAudition
+    | NoteSignalControl
+    | NoteSignalPitchBend
+    | NoteSignalChannelPressure
 
 export namespace NoteSignal {
     export const on = (uuid: UUID.Bytes, pitch: byte, velocity: unitValue): NoteSignalOn =>
@@ -27,6 +39,10 @@ export namespace NoteSignal {
     export const isOn = (signal: NoteSignal): signal is NoteSignalOn => signal.type === "note-on"
     export const isOff = (signal: NoteSignal): signal is NoteSignalOff => signal.type === "note-off"
     export const isAudition = (signal: NoteSignal): signal is NoteSignalAudition => signal.type === "note-audition"
+    export const isControl = (signal: NoteSignal): signal is NoteSignalControl => signal.type === "control"
+    export const isPitchBend = (signal: NoteSignal): signal is NoteSignalPitchBend => signal.type === "pitch-bend"
+    export const isChannelPressure = (signal: NoteSignal): signal is NoteSignalChannelPressure =>
+        signal.type === "channel-pressure"
 
     export const fromEvent = (event: MIDIMessageEvent, uuid: UUID.Bytes): NoteSignal => {
         const data = event.data!
@@ -37,6 +53,12 @@ export namespace NoteSignal {
         } else if (MidiData.isNoteOff(data)) {
             const pitch = MidiData.readPitch(data)
             return ({type: "note-off", uuid, pitch})
+        } else if (MidiData.isController(data)) {
+            return ({type: "control", uuid, controller: MidiData.readParam1(data), value: MidiData.readParam2(data) / 127})
+        } else if (MidiData.isPitchWheel(data)) {
+            return ({type: "pitch-bend", uuid, value: MidiData.asPitchBend(data)})
+        } else if (MidiData.isChannelPressure(data)) {
+            return ({type: "channel-pressure", uuid, value: MidiData.readParam1(data) / 127})
         }
         return panic("Unknown MIDI event")
     }
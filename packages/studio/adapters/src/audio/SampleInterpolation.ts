@@ -0,0 +1,3 @@
+// WASM CONTRACT: the `interpolation` field of AudioRegionBox, AudioClipBox, NanoDeviceBox and PlayfieldSampleBox,
+// read by dsp `Interpolation::from_index` (anything else falls back to linear).
+export enum SampleInterpolation {Linear, Sinc, SincHigh}
@@ -0,0 +1,90 @@
+import {ChorusDeviceBox} from "@opendaw/studio-boxes"
+import {Option, StringMapping, UUID, ValueMapping} from "@opendaw/lib-std"
+import {Address, BooleanField, Int32Field, PointerField, StringField} from "@opendaw/lib-box"
+import {Pointers} from "@opendaw/studio-enums"
+import {AudioEffectDeviceAdapter, DeviceHost, Devices} from "../../DeviceAdapter"
+import {LabeledAudioOutput} from "../../LabeledAudioOutputsOwner"
+import {BoxAdaptersContext} from "../../BoxAdaptersContext"
+import {DeviceManualUrls} from "../../DeviceManualUrls"
+import {ParameterAdapterSet} from "../../ParameterAdapterSet"
+import {AudioUnitBoxAdapter} from "../../audio-unit/AudioUnitBoxAdapter"
+import {TidalDeviceBoxAdapter} from "./TidalDeviceBoxAdapter"
+
+export class ChorusDeviceBoxAdapter implements AudioEffectDeviceAdapter {
+    readonly type = "audio-effect"
+    readonly accepts = "audio"
+    readonly manualUrl = DeviceManualUrls.Chorus
+
+    readonly #context: BoxAdaptersContext
+    readonly #box: ChorusDeviceBox
+
+    readonly #parametric: ParameterAdapterSet
+    readonly namedParameter // let typescript infer the type
+
+    constructor(context: BoxAdaptersContext, box: ChorusDeviceBox) {
+        this.#context = context
+        this.#box = box
+        this.#parametric = new ParameterAdapterSet(this.#context)
+        this.namedParameter = this.#wrapParameters(box)
+    }
+
+    get box(): ChorusDeviceBox {return this.#box}
+    get uuid(): UUID.Bytes {return this.#box.address.uuid}
+    get address(): Address {return this.#box.address}
+    get indexField(): Int32Field {return this.#box.index}
+    get labelField(): StringField {return this.#box.label}
+    get enabledField(): BooleanField {return this.#box.enabled}
+    get minimizedField(): BooleanField {return this.#box.minimized}
+    get host(): PointerField<Pointers.AudioEffectHost> {return this.#box.host}
+
+    deviceHost(): DeviceHost {
+        return this.#context.boxAdapters
+            .adapterFor(this.#box.host.targetVertex.unwrap("no device-host").box, Devices.isHost)
+    }
+
+    audioUnitBoxAdapter(): AudioUnitBoxAdapter {return this.deviceHost().audioUnitBoxAdapter()}
+
+    *labeledAudioOutputs(): Iterable<LabeledAudioOutput> {
+        yield {address: this.address, label: this.labelField.getValue(), children: () => Option.None}
+    }
+
+    terminate(): void {
+        this.#parametric.terminate()
+    }
+
+    #wrapParameters(box: ChorusDeviceBox) {
+        const {RateFractions, RateStringMapping} = TidalDeviceBoxAdapter
+        return {
+            mode: this.#parametric.createParameter(
+                box.mode,
+                ValueMapping.linearInteger(0, 2),
+                StringMapping.indices("", ["Chorus", "Flanger", "Phaser"]), "Mode"),
+            sync: this.#parametric.createParameter(
+                box.sync, ValueMapping.bool, StringMapping.bool, "Sync"),
+            rateIndex: this.#parametric.createParameter(
+                box.rateIndex,
+                ValueMapping.linearInteger(0, RateFractions.length - 1),
+                RateStringMapping, "Rate (Sync)"),
+            rate: this.#parametric.createParameter(
+                box.rate,
+                ValueMapping.exponential(0.05, 10.0),
+                StringMapping.numeric({unit: "Hz", fractionDigits: 2}), "Rate"),
+            depth: this.#parametric.createParameter(
+                box.depth,
+                ValueMapping.unipolar(),
+                StringMapping.percent({fractionDigits: 0}), "Depth"),
+            feedback: this.#parametric.createParameter(
+                box.feedback,
+                ValueMapping.bipolar(),
+                StringMapping.percent({fractionDigits: 0, bipolar: true}), "Feedback", 0.5),
+            spread: this.#parametric.createParameter(
+                box.spread,
+                ValueMapping.linear(0.0, 180.0),
+                StringMapping.numeric({unit: "°", fractionDigits: 0}), "Spread"),
+            mix: this.#parametric.createParameter(
+                box.mix,
+                ValueMapping.unipolar(),
+                StringMapping.percent(), "Dry/Wet")
+        } as const
+    }
+}
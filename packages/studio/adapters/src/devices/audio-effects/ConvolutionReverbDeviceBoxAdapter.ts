@@ -0,0 +1,83 @@
+import {ConvolutionReverbDeviceBox} from "@opendaw/studio-boxes"
+import {Option, StringMapping, UUID, ValueMapping} from "@opendaw/lib-std"
+import {Address, BooleanField, Int32Field, PointerField, StringField} from "@opendaw/lib-box"
+import {Pointers} from "@opendaw/studio-enums"
+import {AudioEffectDeviceAdapter, DeviceHost, Devices} from "../../DeviceAdapter"
+import {LabeledAudioOutput} from "../../LabeledAudioOutputsOwner"
+import {BoxAdaptersContext} from "../../BoxAdaptersContext"
+import {DeviceManualUrls} from "../../DeviceManualUrls"
+import {ParameterAdapterSet} from "../../ParameterAdapterSet"
+import {AudioUnitBoxAdapter} from "../../audio-unit/AudioUnitBoxAdapter"
+
+export class ConvolutionReverbDeviceBoxAdapter implements AudioEffectDeviceAdapter {
+    readonly type = "audio-effect"
+    readonly accepts = "audio"
+    readonly manualUrl = DeviceManualUrls.ConvolutionReverb
+
+    readonly #context: BoxAdaptersContext
+    readonly #box: ConvolutionReverbDeviceBox
+
+    readonly #parametric: ParameterAdapterSet
+    readonly namedParameter // let typescript infer the type
+
+    constructor(context: BoxAdaptersContext, box: ConvolutionReverbDeviceBox) {
+        this.#context = context
+        this.#box = box
+        this.#parametric = new ParameterAdapterSet(this.#context)
+        this.namedParameter = this.#wrapParameters(box)
+    }
+
+    get box(): ConvolutionReverbDeviceBox {return this.#box}
+    get uuid(): UUID.Bytes {return this.#box.address.uuid}
+    get address(): Address {return this.#box.address}
+    get indexField(): Int32Field {return this.#box.index}
+    get labelField(): StringField {return this.#box.label}
+    get enabledField(): BooleanField {return this.#box.enabled}
+    get minimizedField(): BooleanField {return this.#box.minimized}
+    get host(): PointerField<Pointers.AudioEffectHost> {return this.#box.host}
+    get file(): PointerField<Pointers.AudioFile> {return this.#box.file}
+
+    deviceHost(): DeviceHost {
+        return this.#context.boxAdapters
+            .adapterFor(this.#box.host.targetVertex.unwrap("no device-host").box, Devices.isHost)
+    }
+
+    audioUnitBoxAdapter(): AudioUnitBoxAdapter {return this.deviceHost().audioUnitBoxAdapter()}
+
+    *labeledAudioOutputs(): Iterable<LabeledAudioOutput> {
+        yield {address: this.address, label: this.labelField.getValue(), children: () => Option.None}
+    }
+
+    terminate(): void {
+        this.#parametric.terminate()
+    }
+
+    #wrapParameters(box: ConvolutionReverbDeviceBox) {
+        return {
+            preDelay: this.#parametric.createParameter(
+                box.preDelay,
+                ValueMapping.exponential(0.001, 0.500),
+                StringMapping.numeric({unit: "s", fractionDigits: 1, unitPrefix: true}), "Pre-Delay"),
+            start: this.#parametric.createParameter(
+                box.start,
+                ValueMapping.unipolar(),
+                StringMapping.percent({fractionDigits: 1}), "Start"),
+            end: this.#parametric.createParameter(
+                box.end,
+                ValueMapping.unipolar(),
+                StringMapping.percent({fractionDigits: 1}), "End"),
+            stretch: this.#parametric.createParameter(
+                box.stretch,
+                ValueMapping.exponential(0.5, 2.0),
+                StringMapping.numeric({unit: "x", fractionDigits: 2}), "Stretch"),
+            dry: this.#parametric.createParameter(
+                box.dry,
+                ValueMapping.DefaultDecibel,
+                StringMapping.numeric({unit: "db", fractionDigits: 1}), "Dry"),
+            wet: this.#parametric.createParameter(
+                box.wet,
+                ValueMapping.DefaultDecibel,
+                StringMapping.numeric({unit: "db", fractionDigits: 1}), "Wet")
+        } as const
+    }
+}
@@ -0,0 +1,101 @@
+import {FilterDeviceBox} from "@opendaw/studio-boxes"
+import {Option, StringMapping, UUID, ValueMapping} from "@opendaw/lib-std"
+import {Address, BooleanField, Int32Field, PointerField, StringField} from "@opendaw/lib-box"
+import {Pointers} from "@opendaw/studio-enums"
+import {AudioEffectDeviceAdapter, DeviceHost, Devices} from "../../DeviceAdapter"
+import {LabeledAudioOutput} from "../../LabeledAudioOutputsOwner"
+import {BoxAdaptersContext} from "../../BoxAdaptersContext"
+import {DeviceManualUrls} from "../../DeviceManualUrls"
+import {ParameterAdapterSet} from "../../ParameterAdapterSet"
+import {AudioUnitBoxAdapter} from "../../audio-unit/AudioUnitBoxAdapter"
+import {TidalDeviceBoxAdapter} from "./TidalDeviceBoxAdapter"
+
+export class FilterDeviceBoxAdapter implements AudioEffectDeviceAdapter {
+    readonly type = "audio-effect"
+    readonly accepts = "audio"
+    readonly manualUrl = DeviceManualUrls.Filter
+
+    readonly #context: BoxAdaptersContext
+    readonly #box: FilterDeviceBox
+
+    readonly #parametric: ParameterAdapterSet
+    readonly namedParameter // let typescript infer the type
+
+    constructor(context: BoxAdaptersContext, box: FilterDeviceBox) {
+        this.#context = context
+        this.#box = box
+        this.#parametric = new ParameterAdapterSet(this.#context)
+        this.namedParameter = this.#wrapParameters(box)
+    }
+
+    get box(): FilterDeviceBox {return this.#box}
+    get uuid(): UUID.Bytes {return this.#box.address.uuid}
+    get address(): Address {return this.#box.address}
+    get indexField(): Int32Field {return this.#box.index}
+    get labelField(): StringField {return this.#box.label}
+    get enabledField(): BooleanField {return this.#box.enabled}
+    get minimizedField(): BooleanField {return this.#box.minimized}
+    get host(): PointerField<Pointers.AudioEffectHost> {return this.#box.host}
+    get sideChain(): PointerField<Pointers.SideChain> {return this.#box.sideChain}
+
+    deviceHost(): DeviceHost {
+        return this.#context.boxAdapters
+            .adapterFor(this.#box.host.targetVertex.unwrap("no device-host").box, Devices.isHost)
+    }
+
+    audioUnitBoxAdapter(): AudioUnitBoxAdapter {return this.deviceHost().audioUnitBoxAdapter()}
+
+    *labeledAudioOutputs(): Iterable<LabeledAudioOutput> {
+        yield {address: this.address, label: this.labelField.getValue(), children: () => Option.None}
+    }
+
+    terminate(): void {
+        this.#parametric.terminate()
+    }
+
+    #wrapParameters(box: FilterDeviceBox) {
+        const {RateFractions, RateStringMapping} = TidalDeviceBoxAdapter
+        return {
+            mode: this.#parametric.createParameter(
+                box.mode,
+                ValueMapping.linearInteger(0, 4),
+                StringMapping.indices("", ["LowPass", "HighPass", "BandPass", "Notch", "Ladder"]), "Mode"),
+            cutoff: this.#parametric.createParameter(
+                box.cutoff,
+                ValueMapping.exponential(20.0, 20_000.0),
+                StringMapping.numeric({unit: "Hz", unitPrefix: true, fractionDigits: 1}), "Cutoff"),
+            resonance: this.#parametric.createParameter(
+                box.resonance,
+                ValueMapping.exponential(0.1, 10.0),
+                StringMapping.numeric({unit: "q", fractionDigits: 2}), "Resonance"),
+            drive: this.#parametric.createParameter(
+                box.drive,
+                ValueMapping.linear(0.0, 24.0),
+                StringMapping.decible, "Drive"),
+            lfoRate: this.#parametric.createParameter(
+                box.lfoRate,
+                ValueMapping.linearInteger(0, RateFractions.length - 1),
+                RateStringMapping, "LFO Rate"),
+            lfoShape: this.#parametric.createParameter(
+                box.lfoShape,
+                ValueMapping.linearInteger(0, 3),
+                StringMapping.indices("", ["Sine", "Triangle", "Saw", "Square"]), "LFO Shape"),
+            lfoDepth: this.#parametric.createParameter(
+                box.lfoDepth,
+                ValueMapping.bipolar(),
+                StringMapping.percent({fractionDigits: 0, bipolar: true}), "LFO Depth", 0.5),
+            envAmount: this.#parametric.createParameter(
+                box.envAmount,
+                ValueMapping.bipolar(),
+                StringMapping.percent({fractionDigits: 0, bipolar: true}), "Env Amount", 0.5),
+            envAttack: this.#parametric.createParameter(
+                box.envAttack,
+                ValueMapping.exponential(0.1, 100.0),
+                StringMapping.numeric({unit: "ms", fractionDigits: 1}), "Env Attack"),
+            envRelease: this.#parametric.createParameter(
+                box.envRelease,
+                ValueMapping.exponential(1.0, 1000.0),
+                StringMapping.numeric({unit: "ms", fractionDigits: 0}), "Env Release")
+        } as const
+    }
+}
@@ -0,0 +1,103 @@
+import {MultibandCompressorBand, MultibandCompressorDeviceBox} from "@opendaw/studio-boxes"
+import {Option, StringMapping, UUID, ValueMapping} from "@opendaw/lib-std"
+import {Address, BooleanField, Int32Field, PointerField, StringField} from "@opendaw/lib-box"
+import {Pointers} from "@opendaw/studio-enums"
+import {AudioEffectDeviceAdapter, DeviceHost, Devices} from "../../DeviceAdapter"
+import {LabeledAudioOutput} from "../../LabeledAudioOutputsOwner"
+import {BoxAdaptersContext} from "../../BoxAdaptersContext"
+import {DeviceManualUrls} from "../../DeviceManualUrls"
+import {ParameterAdapterSet} from "../../ParameterAdapterSet"
+import {AudioUnitBoxAdapter} from "../../audio-unit/AudioUnitBoxAdapter"
+import {AutomatableParameterFieldAdapter} from "../../AutomatableParameterFieldAdapter"
+
+export class MultibandCompressorDeviceBoxAdapter implements AudioEffectDeviceAdapter {
+    readonly type = "audio-effect"
+    readonly accepts = "audio"
+    readonly manualUrl = DeviceManualUrls.MultibandCompressor
+
+    readonly #context: BoxAdaptersContext
+    readonly #box: MultibandCompressorDeviceBox
+
+    readonly #parametric: ParameterAdapterSet
+    readonly namedParameter // let typescript infer the type
+
+    constructor(context: BoxAdaptersContext, box: MultibandCompressorDeviceBox) {
+        this.#context = context
+        this.#box = box
+        this.#parametric = new ParameterAdapterSet(this.#context)
+        this.namedParameter = this.#wrapParameters(box)
+    }
+
+    get box(): MultibandCompressorDeviceBox {return this.#box}
+    get uuid(): UUID.Bytes {return this.#box.address.uuid}
+    get address(): Address {return this.#box.address}
+    get indexField(): Int32Field {return this.#box.index}
+    get labelField(): StringField {return this.#box.label}
+    get enabledField(): BooleanField {return this.#box.enabled}
+    get minimizedField(): BooleanField {return this.#box.minimized}
+    get host(): PointerField<Pointers.AudioEffectHost> {return this.#box.host}
+    // live gain reduction per band in dB (0 while bypassed or unused), broadcast by the processor
+    get reduction(): Address {return this.#box.address.append(0)}
+
+    deviceHost(): DeviceHost {
+        return this.#context.boxAdapters
+            .adapterFor(this.#box.host.targetVertex.unwrap("no device-host").box, Devices.isHost)
+    }
+
+    audioUnitBoxAdapter(): AudioUnitBoxAdapter {return this.deviceHost().audioUnitBoxAdapter()}
+
+    *labeledAudioOutputs(): Iterable<LabeledAudioOutput> {
+        yield {address: this.address, label: this.labelField.getValue(), children: () => Option.None}
+    }
+
+    terminate(): void {
+        this.#parametric.terminate()
+    }
+
+    #wrapParameters(box: MultibandCompressorDeviceBox) {
+        return {
+            bandCount: this.#parametric.createParameter(
+                box.bandCount,
+                ValueMapping.linearInteger(2, 4),
+                StringMapping.numeric({fractionDigits: 0}), "Bands"),
+            crossoverLow: this.#parametric.createParameter(
+                box.crossoverLow, CrossoverMapping, CrossoverStringMapping, "Low X-Over"),
+            crossoverMid: this.#parametric.createParameter(
+                box.crossoverMid, CrossoverMapping, CrossoverStringMapping, "Mid X-Over"),
+            crossoverHigh: this.#parametric.createParameter(
+                box.crossoverHigh, CrossoverMapping, CrossoverStringMapping, "High X-Over"),
+            bands: box.bands.fields().map((band, index) => createBand(this.#parametric, band, `Band ${index + 1}`))
+        } as const
+    }
+}
+
+export type BandParameters = {
+    threshold: AutomatableParameterFieldAdapter<number>
+    ratio: AutomatableParameterFieldAdapter<number>
+    attack: AutomatableParameterFieldAdapter<number>
+    release: AutomatableParameterFieldAdapter<number>
+    gain: AutomatableParameterFieldAdapter<number>
+    solo: AutomatableParameterFieldAdapter<boolean>
+    bypass: AutomatableParameterFieldAdapter<boolean>
+}
+
+const CrossoverMapping = ValueMapping.exponential(20.0, 20_000.0)
+const CrossoverStringMapping = StringMapping.numeric({unit: "Hz", unitPrefix: true, fractionDigits: 1})
+
+const createBand = (parametric: ParameterAdapterSet, band: MultibandCompressorBand, name: string): BandParameters => ({
+    threshold: parametric.createParameter(
+        band.threshold, ValueMapping.linear(-60.0, 0.0), StringMapping.decible, `${name} Threshold`),
+    ratio: parametric.createParameter(
+        band.ratio, ValueMapping.exponential(1.0, 24.0),
+        StringMapping.numeric({fractionDigits: 1}), `${name} Ratio`),
+    attack: parametric.createParameter(
+        band.attack, ValueMapping.linear(0.0, 100.0),
+        StringMapping.numeric({unit: "ms", fractionDigits: 1}), `${name} Attack`),
+    release: parametric.createParameter(
+        band.release, ValueMapping.linear(5.0, 1500.0),
+        StringMapping.numeric({unit: "ms", fractionDigits: 1}), `${name} Release`),
+    gain: parametric.createParameter(
+        band.gain, ValueMapping.linear(-24.0, 24.0), StringMapping.decible, `${name} Gain`),
+    solo: parametric.createParameter(band.solo, ValueMapping.bool, StringMapping.bool, `${name} Solo`),
+    bypass: parametric.createParameter(band.bypass, ValueMapping.bool, StringMapping.bool, `${name} Bypass`)
+})
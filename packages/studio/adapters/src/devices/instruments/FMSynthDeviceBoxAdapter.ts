@@ -0,0 +1,119 @@
+import {FMSynthDeviceBox} from "@opendaw/studio-boxes"
+import {Option, StringMapping, UUID, ValueMapping} from "@opendaw/lib-std"
+import {Address, BooleanField, StringField} from "@opendaw/lib-box"
+import {DeviceHost, Devices, InstrumentDeviceBoxAdapter} from "../../DeviceAdapter"
+import {LabeledAudioOutput} from "../../LabeledAudioOutputsOwner"
+import {BoxAdaptersContext} from "../../BoxAdaptersContext"
+import {DeviceManualUrls} from "../../DeviceManualUrls"
+import {ParameterAdapterSet} from "../../ParameterAdapterSet"
+import {TrackType} from "../../timeline/TrackType"
+import {AudioUnitBoxAdapter} from "../../audio-unit/AudioUnitBoxAdapter"
+import {VoicingMode} from "@opendaw/studio-enums"
+
+export class FMSynthDeviceBoxAdapter implements InstrumentDeviceBoxAdapter {
+    readonly type = "instrument"
+    readonly accepts = "midi"
+    readonly manualUrl = DeviceManualUrls.FMSynth
+
+    readonly #context: BoxAdaptersContext
+    readonly #box: FMSynthDeviceBox
+
+    readonly #parametric: ParameterAdapterSet
+    readonly namedParameter // let typescript infer the type
+
+    constructor(context: BoxAdaptersContext, box: FMSynthDeviceBox) {
+        this.#context = context
+        this.#box = box
+        this.#parametric = new ParameterAdapterSet(this.#context)
+        this.namedParameter = this.#wrapParameters(box)
+    }
+
+    get box(): FMSynthDeviceBox {return this.#box}
+    get uuid(): UUID.Bytes {return this.#box.address.uuid}
+    get address(): Address {return this.#box.address}
+    get labelField(): StringField {return this.#box.label}
+    get iconField(): StringField {return this.#box.icon}
+    get defaultTrackType(): TrackType {return TrackType.Notes}
+    get enabledField(): BooleanField {return this.#box.enabled}
+    get minimizedField(): BooleanField {return this.#box.minimized}
+    get acceptsMidiEvents(): boolean {return true}
+
+    deviceHost(): DeviceHost {
+        return this.#context.boxAdapters
+            .adapterFor(this.#box.host.targetVertex.unwrap("no device-host").box, Devices.isHost)
+    }
+
+    audioUnitBoxAdapter(): AudioUnitBoxAdapter {return this.deviceHost().audioUnitBoxAdapter()}
+
+    *labeledAudioOutputs(): Iterable<LabeledAudioOutput> {
+        yield {address: this.address, label: this.labelField.getValue(), children: () => Option.None}
+    }
+
+    terminate(): void {
+        this.#parametric.terminate()
+    }
+
+    #wrapParameters(box: FMSynthDeviceBox) {
+        const VoiceModes = [VoicingMode.Monophonic, VoicingMode.Polyphonic]
+        return {
+            volume: this.#parametric.createParameter(
+                box.volume,
+                ValueMapping.DefaultDecibel,
+                StringMapping.numeric({unit: "db", fractionDigits: 1}), "Volume"),
+            algorithm: this.#parametric.createParameter(
+                box.algorithm,
+                ValueMapping.linearInteger(0, 7),
+                StringMapping.indices("", ["1", "2", "3", "4", "5", "6", "7", "8"]), "Algorithm"),
+            feedback: this.#parametric.createParameter(
+                box.feedback,
+                ValueMapping.unipolar(),
+                StringMapping.percent({fractionDigits: 1}), "Feedback"),
+            voicingMode: this.#parametric.createParameter(
+                box.voicingMode,
+                ValueMapping.values(VoiceModes),
+                StringMapping.values("", VoiceModes, ["mono", "poly"]), "Play Mode", 0.5),
+            glideTime: this.#parametric.createParameter(
+                box.glideTime,
+                ValueMapping.unipolar(),
+                StringMapping.percent({fractionDigits: 1}), "Glide time", 0.0),
+            operators: box.operators.fields().map(operator => ({
+                ratio: this.#parametric.createParameter(
+                    operator.ratio,
+                    ValueMapping.exponential(0.25, 16.0),
+                    StringMapping.numeric({unit: "x", fractionDigits: 2}), "Ratio"),
+                fine: this.#parametric.createParameter(
+                    operator.fine,
+                    ValueMapping.linear(-50.0, +50.0),
+                    StringMapping.numeric({unit: "ct", fractionDigits: 1}), "Fine", 0.5),
+                level: this.#parametric.createParameter(
+                    operator.level,
+                    ValueMapping.unipolar(),
+                    StringMapping.percent({fractionDigits: 1}), "Level"),
+                attack: this.#parametric.createParameter(
+                    operator.attack,
+                    ValueMapping.exponential(0.001, 5.0),
+                    StringMapping.numeric({unit: "s", fractionDigits: 3}), "Attack"),
+                decay: this.#parametric.createParameter(
+                    operator.decay,
+                    ValueMapping.exponential(0.001, 5.0),
+                    StringMapping.numeric({unit: "s", fractionDigits: 3}), "Decay"),
+                sustain: this.#parametric.createParameter(
+                    operator.sustain,
+                    ValueMapping.unipolar(),
+                    StringMapping.percent({fractionDigits: 1}), "Sustain"),
+                release: this.#parametric.createParameter(
+                    operator.release,
+                    ValueMapping.exponential(0.001, 5.0),
+                    StringMapping.numeric({unit: "s", fractionDigits: 3}), "Release"),
+                velocity: this.#parametric.createParameter(
+                    operator.velocity,
+                    ValueMapping.unipolar(),
+                    StringMapping.percent({fractionDigits: 0}), "Velocity"),
+                keyScaling: this.#parametric.createParameter(
+                    operator.keyScaling,
+                    ValueMapping.unipolar(),
+                    StringMapping.percent({fractionDigits: 0}), "Key Scale")
+            }))
+        } as const
+    }
+}
\ No newline at end of file
@@ -1,6 +1,6 @@
 import {NanoDeviceBox} from "@opendaw/studio-boxes"
 import {Option, StringMapping, UUID, ValueMapping} from "@opendaw/lib-std"
-import {Address, BooleanField, StringField} from "@opendaw/lib-box"
+import {Address, BooleanField, Int32Field, StringField} from "@opendaw/lib-box"
 import {DeviceHost, Devices, InstrumentDeviceBoxAdapter} from "../../DeviceAdapter"
 import {LabeledAudioOutput} from "../../LabeledAudioOutputsOwner"
 import {BoxAdaptersContext} from "../../BoxAdaptersContext"
@@ -35,6 +35,7 @@ export class NanoDeviceBoxAdapter implements InstrumentDeviceBoxAdapter {
     get defaultTrackType(): TrackType {return TrackType.Notes}
     get enabledField(): BooleanField {return this.#box.enabled}
     get minimizedField(): BooleanField {return this.#box.minimized}
+    get interpolationField(): Int32Field {return this.#box.interpolation} // a SampleInterpolation
     get acceptsMidiEvents(): boolean {return true}
 
     deviceHost(): DeviceHost {
@@ -68,6 +68,7 @@ export class PlayfieldSampleBoxAdapter implements DeviceHost, InstrumentDeviceBo
 
     get gate(): Gate {return this.#box.gate.getValue()}
     get exclude(): boolean {return this.#box.exclude.getValue()}
+    get interpolationField(): Int32Field {return this.#box.interpolation} // a SampleInterpolation
     get label(): string {
         return `${this.device().labelField.getValue()} > ${this.#file.mapOr(file => file.box.fileName.getValue(), "No file")}`
     }
@@ -168,7 +168,45 @@ export class VaporisateurDeviceBoxAdapter implements InstrumentDeviceBoxAdapter
             lfoTargetCutoff: this.#parametric.createParameter(
                 box.lfo.targetCutoff,
                 ValueMapping.bipolar(),
-                StringMapping.percent({fractionDigits: 1}), "Cutoff ⦿", 0.5)
+                StringMapping.percent({fractionDigits: 1}), "Cutoff ⦿", 0.5),
+            modLfoWaveform: this.#parametric.createParameter(
+                box.modLfo.waveform,
+                VaporisateurSettings.LFO_WAVEFORM_VALUE_MAPPING,
+                VaporisateurSettings.LFO_WAVEFORM_STRING_MAPPING, "LFO 2 Shape", 0.0),
+            modLfoRate: this.#parametric.createParameter(
+                box.modLfo.rate,
+                ValueMapping.exponential(0.0001, 30.0),
+                StringMapping.numeric({unit: "Hz", fractionDigits: 1, unitPrefix: true}), "Rate", 0.0),
+            modEnvAttack: this.#parametric.createParameter(
+                box.modEnvelope.attack,
+                ValueMapping.exponential(0.001, 5.0),
+                StringMapping.numeric({unit: "s", fractionDigits: 3}), "Attack"),
+            modEnvDecay: this.#parametric.createParameter(
+                box.modEnvelope.decay,
+                ValueMapping.exponential(0.001, 5.0),
+                StringMapping.numeric({unit: "s", fractionDigits: 3}), "Decay"),
+            modEnvSustain: this.#parametric.createParameter(
+                box.modEnvelope.sustain,
+                ValueMapping.unipolar(),
+                StringMapping.percent({fractionDigits: 1}), "Sustain"),
+            modEnvRelease: this.#parametric.createParameter(
+                box.modEnvelope.release,
+                ValueMapping.exponential(0.001, 5.0),
+                StringMapping.numeric({unit: "s", fractionDigits: 3}), "Release"),
+            modulations: box.modulations.fields().map((slot, index) => ({
+                source: this.#parametric.createParameter(
+                    slot.source,
+                    VaporisateurSettings.MOD_SOURCE_VALUE_MAPPING,
+                    VaporisateurSettings.MOD_SOURCE_STRING_MAPPING, `Mod ${index + 1} Source`),
+                target: this.#parametric.createParameter(
+                    slot.target,
+                    VaporisateurSettings.MOD_TARGET_VALUE_MAPPING,
+                    VaporisateurSettings.MOD_TARGET_STRING_MAPPING, `Mod ${index + 1} Target`),
+                amount: this.#parametric.createParameter(
+                    slot.amount,
+                    ValueMapping.bipolar(),
+                    StringMapping.percent({fractionDigits: 0}), `Mod ${index + 1} Amount`, 0.5)
+            }))
         } as const
     }
 }
\ No newline at end of file
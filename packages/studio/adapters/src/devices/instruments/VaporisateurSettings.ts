@@ -8,6 +8,15 @@ export const VaporisateurSettings = (() => {
     const FILTER_ORDER_STRINGS = ["12", "24", "36", "48"]
     const LFO_WAVEFORM_VALUES = [ClassicWaveform.sine, ClassicWaveform.triangle, ClassicWaveform.saw, ClassicWaveform.square]
     const LFO_WAVEFORM_STRINGS = ["Sine", "Triangle", "Saw", "Square"]
+    // the device's ModSource / ModTarget order (the box stores the index)
+    const MOD_SOURCE_STRINGS = [
+        "Off", "LFO 1", "LFO 2", "Env 1", "Env 2", "Velocity", "Key", "Mod Wheel", "Aftertouch", "Random"
+    ]
+    const MOD_TARGET_STRINGS = [
+        "Off", "Osc A Tune", "Osc B Tune", "Osc A Volume", "Osc B Volume", "Cutoff", "Resonance", "Pan", "Detune"
+    ]
+    const MOD_SOURCE_VALUES = MOD_SOURCE_STRINGS.map((_, index) => index)
+    const MOD_TARGET_VALUES = MOD_TARGET_STRINGS.map((_, index) => index)
     return {
         MIN_CUTOFF,
         MAX_CUTOFF,
@@ -20,6 +29,14 @@ export const VaporisateurSettings = (() => {
         LFO_WAVEFORM_VALUES,
         LFO_WAVEFORM_STRINGS,
         LFO_WAVEFORM_VALUE_MAPPING: ValueMapping.values(LFO_WAVEFORM_VALUES),
-        LFO_WAVEFORM_STRING_MAPPING: StringMapping.values("", LFO_WAVEFORM_VALUES, LFO_WAVEFORM_STRINGS)
+        LFO_WAVEFORM_STRING_MAPPING: StringMapping.values("", LFO_WAVEFORM_VALUES, LFO_WAVEFORM_STRINGS),
+        MOD_SOURCE_VALUES,
+        MOD_SOURCE_STRINGS,
+        MOD_SOURCE_VALUE_MAPPING: ValueMapping.values(MOD_SOURCE_VALUES),
+        MOD_SOURCE_STRING_MAPPING: StringMapping.values("", MOD_SOURCE_VALUES, MOD_SOURCE_STRINGS),
+        MOD_TARGET_VALUES,
+        MOD_TARGET_STRINGS,
+        MOD_TARGET_VALUE_MAPPING: ValueMapping.values(MOD_TARGET_VALUES),
+        MOD_TARGET_STRING_MAPPING: StringMapping.values("", MOD_TARGET_VALUES, MOD_TARGET_STRINGS)
     }
 })()
\ No newline at end of file
@@ -0,0 +1,131 @@
+import {WavetableDeviceBox} from "@opendaw/studio-boxes"
+import {Option, StringMapping, UUID, ValueMapping} from "@opendaw/lib-std"
+import {Address, BooleanField, PointerField, StringField} from "@opendaw/lib-box"
+import {DeviceHost, Devices, InstrumentDeviceBoxAdapter} from "../../DeviceAdapter"
+import {LabeledAudioOutput} from "../../LabeledAudioOutputsOwner"
+import {BoxAdaptersContext} from "../../BoxAdaptersContext"
+import {DeviceManualUrls} from "../../DeviceManualUrls"
+import {ParameterAdapterSet} from "../../ParameterAdapterSet"
+import {TrackType} from "../../timeline/TrackType"
+import {AudioUnitBoxAdapter} from "../../audio-unit/AudioUnitBoxAdapter"
+import {Pointers, VoicingMode} from "@opendaw/studio-enums"
+import {VaporisateurSettings} from "./VaporisateurSettings"
+
+export class WavetableDeviceBoxAdapter implements InstrumentDeviceBoxAdapter {
+    readonly type = "instrument"
+    readonly accepts = "midi"
+    readonly manualUrl = DeviceManualUrls.Wavetable
+
+    readonly #context: BoxAdaptersContext
+    readonly #box: WavetableDeviceBox
+
+    readonly #parametric: ParameterAdapterSet
+    readonly namedParameter // let typescript infer the type
+
+    constructor(context: BoxAdaptersContext, box: WavetableDeviceBox) {
+        this.#context = context
+        this.#box = box
+        this.#parametric = new ParameterAdapterSet(this.#context)
+        this.namedParameter = this.#wrapParameters(box)
+    }
+
+    get box(): WavetableDeviceBox {return this.#box}
+    get file(): PointerField<Pointers.AudioFile> {return this.#box.file}
+    get uuid(): UUID.Bytes {return this.#box.address.uuid}
+    get address(): Address {return this.#box.address}
+    get labelField(): StringField {return this.#box.label}
+    get iconField(): StringField {return this.#box.icon}
+    get defaultTrackType(): TrackType {return TrackType.Notes}
+    get enabledField(): BooleanField {return this.#box.enabled}
+    get minimizedField(): BooleanField {return this.#box.minimized}
+    get acceptsMidiEvents(): boolean {return true}
+
+    deviceHost(): DeviceHost {
+        return this.#context.boxAdapters
+            .adapterFor(this.#box.host.targetVertex.unwrap("no device-host").box, Devices.isHost)
+    }
+
+    audioUnitBoxAdapter(): AudioUnitBoxAdapter {return this.deviceHost().audioUnitBoxAdapter()}
+
+    *labeledAudioOutputs(): Iterable<LabeledAudioOutput> {
+        yield {address: this.address, label: this.labelField.getValue(), children: () => Option.None}
+    }
+
+    terminate(): void {
+        this.#parametric.terminate()
+    }
+
+    #wrapParameters(box: WavetableDeviceBox) {
+        const VoiceModes = [VoicingMode.Monophonic, VoicingMode.Polyphonic]
+        return {
+            volume: this.#parametric.createParameter(
+                box.volume,
+                ValueMapping.DefaultDecibel,
+                StringMapping.numeric({unit: "db", fractionDigits: 1}), "Volume"),
+            position: this.#parametric.createParameter(
+                box.position,
+                ValueMapping.unipolar(),
+                StringMapping.percent({fractionDigits: 1}), "Position"),
+            positionEnvelope: this.#parametric.createParameter(
+                box.positionEnvelope,
+                ValueMapping.bipolar(),
+                StringMapping.percent({fractionDigits: 1}), "Pos. Env.", 0.5),
+            octave: this.#parametric.createParameter(
+                box.octave,
+                ValueMapping.linearInteger(-3, 3),
+                StringMapping.numeric({unit: "oct"}), "Octave", 0.5),
+            tune: this.#parametric.createParameter(
+                box.tune,
+                ValueMapping.linear(-1200.0, +1200.0),
+                StringMapping.numeric({unit: "ct", fractionDigits: 0}), "Tune", 0.5),
+            cutoff: this.#parametric.createParameter(
+                box.cutoff,
+                VaporisateurSettings.CUTOFF_VALUE_MAPPING,
+                VaporisateurSettings.CUTOFF_STRING_MAPPING, "Flt. Cutoff"),
+            resonance: this.#parametric.createParameter(
+                box.resonance,
+                ValueMapping.exponential(0.01, 10.0),
+                StringMapping.numeric({unit: "q", fractionDigits: 3}), "Flt. Q"),
+            filterEnvelope: this.#parametric.createParameter(
+                box.filterEnvelope,
+                ValueMapping.bipolar(),
+                StringMapping.percent({fractionDigits: 1}), "Flt. Env.", 0.5),
+            attack: this.#parametric.createParameter(
+                box.attack,
+                ValueMapping.exponential(0.001, 5.0),
+                StringMapping.numeric({unit: "s", fractionDigits: 3}), "Attack"),
+            decay: this.#parametric.createParameter(
+                box.decay,
+                ValueMapping.exponential(0.001, 5.0),
+                StringMapping.numeric({unit: "s", fractionDigits: 3}), "Decay"),
+            sustain: this.#parametric.createParameter(
+                box.sustain,
+                ValueMapping.unipolar(),
+                StringMapping.percent({fractionDigits: 1}), "Sustain"),
+            release: this.#parametric.createParameter(
+                box.release,
+                ValueMapping.exponential(0.001, 5.0),
+                StringMapping.numeric({unit: "s", fractionDigits: 3}), "Release"),
+            voicingMode: this.#parametric.createParameter(
+                box.voicingMode,
+                ValueMapping.values(VoiceModes),
+                StringMapping.values("", VoiceModes, ["mono", "poly"]), "Play Mode", 0.5),
+            glideTime: this.#parametric.createParameter(
+                box.glideTime,
+                ValueMapping.unipolar(),
+                StringMapping.percent({fractionDigits: 1}), "Glide time", 0.0),
+            unisonCount: this.#parametric.createParameter(
+                box.unisonCount,
+                ValueMapping.values([1, 3, 5]),
+                StringMapping.values("#", [1, 3, 5], [1, 3, 5].map(x => String(x))), "Unisono", 0.0),
+            unisonDetune: this.#parametric.createParameter(
+                box.unisonDetune,
+                ValueMapping.exponential(1.0, 1200.0),
+                StringMapping.numeric({unit: "ct", fractionDigits: 0}), "Detune", 0.0),
+            unisonStereo: this.#parametric.createParameter(
+                box.unisonStereo,
+                ValueMapping.unipolar(),
+                StringMapping.percent({fractionDigits: 0}), "Stereo", 0.0)
+        } as const
+    }
+}
\ No newline at end of file
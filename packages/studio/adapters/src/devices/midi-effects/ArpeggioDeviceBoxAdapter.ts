@@ -1,6 +1,6 @@
 import {ArpeggioDeviceBox} from "@opendaw/studio-boxes"
 import {Pointers} from "@opendaw/studio-enums"
-import {Address, BooleanField, Int32Field, PointerField, StringField} from "@opendaw/lib-box"
+import {Address, BooleanField, Float32Field, Int32Field, PointerField, StringField} from "@opendaw/lib-box"
 import {Fraction} from "@opendaw/lib-dsp"
 import {StringMapping, UUID, ValueMapping} from "@opendaw/lib-std"
 import {DeviceHost, Devices, MidiEffectDeviceAdapter} from "../../DeviceAdapter"
@@ -9,6 +9,13 @@ import {DeviceManualUrls} from "../../DeviceManualUrls"
 import {ParameterAdapterSet} from "../../ParameterAdapterSet"
 import {AudioUnitBoxAdapter} from "../../audio-unit/AudioUnitBoxAdapter"
 
+export type ArpeggioPatternStep = {
+    readonly velocity: Float32Field
+    readonly gate: Float32Field
+    readonly tie: BooleanField
+    readonly rest: BooleanField
+}
+
 export class ArpeggioDeviceBoxAdapter implements MidiEffectDeviceAdapter {
     static RateFractions = Fraction.builder()
         .add([1, 1]).add([1, 2]).add([1, 3]).add([1, 4])
@@ -20,6 +27,9 @@ export class ArpeggioDeviceBoxAdapter implements MidiEffectDeviceAdapter {
 
     static RateStringMapping = StringMapping.indices("", this.RateFractions.map(([n, d]) => `${n}/${d}`))
 
+    // WASM CONTRACT: the mode-index order of device-arpeggio (`MODE_UP` ..= `MODE_CHORD`).
+    static Modes = ["Up", "Down", "UpDown", "As Played", "Random", "Converge", "Diverge", "Chord"] as const
+
     readonly type = "midi-effect"
     readonly accepts = "midi"
     readonly manualUrl = DeviceManualUrls.Arpeggio
@@ -44,6 +54,12 @@ export class ArpeggioDeviceBoxAdapter implements MidiEffectDeviceAdapter {
     get enabledField(): BooleanField {return this.#box.enabled}
     get minimizedField(): BooleanField {return this.#box.minimized}
     get host(): PointerField<Pointers.MIDIEffectHost> {return this.#box.host}
+    // The rhythm pattern: how many of the steps are used (0 = off) and each step's velocity and gate scale,
+    // tie and rest.
+    get patternLength(): Int32Field {return this.#box.patternLength}
+    get patternSteps(): ReadonlyArray<ArpeggioPatternStep> {
+        return this.#box.pattern.fields().map(({velocity, gate, tie, rest}) => ({velocity, gate, tie, rest}))
+    }
 
     deviceHost(): DeviceHost {
         return this.#context.boxAdapters
@@ -58,8 +74,8 @@ export class ArpeggioDeviceBoxAdapter implements MidiEffectDeviceAdapter {
         return {
             modeIndex: this.#parametric.createParameter(
                 box.modeIndex,
-                ValueMapping.linearInteger(0, 2),
-                StringMapping.indices("", ["Up", "Down", "UpDown"]), "mode"),
+                ValueMapping.linearInteger(0, ArpeggioDeviceBoxAdapter.Modes.length - 1),
+                StringMapping.indices("", ArpeggioDeviceBoxAdapter.Modes), "mode"),
             numOctaves: this.#parametric.createParameter(
                 box.numOctaves,
                 ValueMapping.linearInteger(1, 5),
@@ -82,4 +98,4 @@ export class ArpeggioDeviceBoxAdapter implements MidiEffectDeviceAdapter {
                 StringMapping.percent({fractionDigits: 0, bipolar: false}), "Velocity")
         } as const
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,66 @@
+import {Pointers} from "@opendaw/studio-enums"
+import {StringMapping, UUID, ValueMapping} from "@opendaw/lib-std"
+import {Address, BooleanField, Int32Field, PointerField, StringField} from "@opendaw/lib-box"
+import {RatchetDeviceBox} from "@opendaw/studio-boxes"
+import {DeviceHost, Devices, MidiEffectDeviceAdapter} from "../../DeviceAdapter"
+import {BoxAdaptersContext} from "../../BoxAdaptersContext"
+import {DeviceManualUrls} from "../../DeviceManualUrls"
+import {ParameterAdapterSet} from "../../ParameterAdapterSet"
+import {AudioUnitBoxAdapter} from "../../audio-unit/AudioUnitBoxAdapter"
+import {ArpeggioDeviceBoxAdapter} from "./ArpeggioDeviceBoxAdapter"
+
+export class RatchetDeviceBoxAdapter implements MidiEffectDeviceAdapter {
+    readonly type = "midi-effect"
+    readonly accepts = "midi"
+    readonly manualUrl = DeviceManualUrls.Ratchet
+
+    readonly #context: BoxAdaptersContext
+    readonly #box: RatchetDeviceBox
+    readonly #parametric: ParameterAdapterSet
+    readonly namedParameter // let typescript infer the type
+
+    constructor(context: BoxAdaptersContext, box: RatchetDeviceBox) {
+        this.#context = context
+        this.#box = box
+        this.#parametric = new ParameterAdapterSet(this.#context)
+        this.namedParameter = this.#wrapParameters(box)
+    }
+
+    get box(): RatchetDeviceBox {return this.#box}
+    get uuid(): UUID.Bytes {return this.#box.address.uuid}
+    get address(): Address {return this.#box.address}
+    get indexField(): Int32Field {return this.#box.index}
+    get labelField(): StringField {return this.#box.label}
+    get enabledField(): BooleanField {return this.#box.enabled}
+    get minimizedField(): BooleanField {return this.#box.minimized}
+    get host(): PointerField<Pointers.MIDIEffectHost> {return this.#box.host}
+    // The ratchet pattern: how many of the steps are used (0 = off) and each step's hit count (1..8).
+    get ratchetLength(): Int32Field {return this.#box.ratchetLength}
+    get ratchetCounts(): ReadonlyArray<Int32Field> {return this.#box.ratchets.fields().map(step => step.count)}
+
+    deviceHost(): DeviceHost {
+        return this.#context.boxAdapters
+            .adapterFor(this.#box.host.targetVertex.unwrap("no device-host").box, Devices.isHost)
+    }
+
+    audioUnitBoxAdapter(): AudioUnitBoxAdapter {return this.deviceHost().audioUnitBoxAdapter()}
+
+    terminate(): void {this.#parametric.terminate()}
+
+    #wrapParameters(box: RatchetDeviceBox) {
+        return {
+            rate: this.#parametric.createParameter(
+                box.rateIndex,
+                ValueMapping.linearInteger(0, ArpeggioDeviceBoxAdapter.RateFractions.length - 1),
+                ArpeggioDeviceBoxAdapter.RateStringMapping, "Rate"),
+            gate: this.#parametric.createParameter(
+                box.gate,
+                ValueMapping.unipolar(),
+                StringMapping.percent({fractionDigits: 0}), "Gate"),
+            velocityRamp: this.#parametric.createParameter(
+                box.velocityRamp,
+                ValueMapping.bipolar(),
+                StringMapping.percent({fractionDigits: 0, bipolar: true}), "Ramp")
+        } as const
+    }
+}
@@ -0,0 +1,71 @@
+import {Pointers} from "@opendaw/studio-enums"
+import {StringMapping, UUID, ValueMapping} from "@opendaw/lib-std"
+import {Address, BooleanField, Int32Field, PointerField, StringField} from "@opendaw/lib-box"
+import {ScaleDeviceBox} from "@opendaw/studio-boxes"
+import {DeviceHost, Devices, MidiEffectDeviceAdapter} from "../../DeviceAdapter"
+import {BoxAdaptersContext} from "../../BoxAdaptersContext"
+import {DeviceManualUrls} from "../../DeviceManualUrls"
+import {ParameterAdapterSet} from "../../ParameterAdapterSet"
+import {AudioUnitBoxAdapter} from "../../audio-unit/AudioUnitBoxAdapter"
+
+export class ScaleDeviceBoxAdapter implements MidiEffectDeviceAdapter {
+    readonly type = "midi-effect"
+    readonly accepts = "midi"
+    readonly manualUrl = DeviceManualUrls.Scale
+
+    readonly #context: BoxAdaptersContext
+    readonly #box: ScaleDeviceBox
+    readonly #parametric: ParameterAdapterSet
+    readonly namedParameter // let typescript infer the type
+
+    constructor(context: BoxAdaptersContext, box: ScaleDeviceBox) {
+        this.#context = context
+        this.#box = box
+        this.#parametric = new ParameterAdapterSet(this.#context)
+        this.namedParameter = this.#wrapParameters(box)
+    }
+
+    get box(): ScaleDeviceBox {return this.#box}
+    get uuid(): UUID.Bytes {return this.#box.address.uuid}
+    get address(): Address {return this.#box.address}
+    get indexField(): Int32Field {return this.#box.index}
+    get labelField(): StringField {return this.#box.label}
+    get enabledField(): BooleanField {return this.#box.enabled}
+    get minimizedField(): BooleanField {return this.#box.minimized}
+    get host(): PointerField<Pointers.MIDIEffectHost> {return this.#box.host}
+
+    deviceHost(): DeviceHost {
+        return this.#context.boxAdapters
+            .adapterFor(this.#box.host.targetVertex.unwrap("no device-host").box, Devices.isHost)
+    }
+
+    audioUnitBoxAdapter(): AudioUnitBoxAdapter {return this.deviceHost().audioUnitBoxAdapter()}
+
+    terminate(): void {this.#parametric.terminate()}
+
+    #wrapParameters(box: ScaleDeviceBox) {
+        return {
+            key: this.#parametric.createParameter(
+                box.key,
+                ValueMapping.linearInteger(0, 11),
+                StringMapping.indices("", ["C", "C#", "D", "D#", "E", "F", "F#", "G", "G#", "A", "A#", "B"]), "Key"),
+            scale: this.#parametric.createParameter(
+                box.scale,
+                ValueMapping.linearInteger(0, 7),
+                StringMapping.indices("", ["Chrom", "Major", "Minor", "MajPent", "MinPent", "Blues", "Dorian", "Mixo"]),
+                "Scale"),
+            chord: this.#parametric.createParameter(
+                box.chord,
+                ValueMapping.linearInteger(0, 2),
+                StringMapping.indices("", ["Off", "Triad", "Seventh"]), "Chord"),
+            inversion: this.#parametric.createParameter(
+                box.inversion,
+                ValueMapping.linearInteger(0, 3),
+                StringMapping.numeric({fractionDigits: 0}), "Inversion"),
+            strum: this.#parametric.createParameter(
+                box.strum,
+                ValueMapping.linearInteger(0, 480),
+                StringMapping.numeric({unit: "ppqn", fractionDigits: 0}), "Strum")
+        } as const
+    }
+}
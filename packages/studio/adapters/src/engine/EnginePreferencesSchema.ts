@@ -2,6 +2,7 @@ import {z} from "zod"
 
 const _BeatSubDivisionOptions = [1, 2, 4, 8] as const
 const _RecordingCountInBars = [1, 2, 3, 4, 5, 6, 7, 8] as const
+const _RecordingPreRollBars = [0, 1, 2, 4] as const
 const _OlderTakeActionOptions = ["disable-track", "mute-region"] as const
 const _OlderTakeScopeOptions = ["none", "all", "previous-only"] as const
 
@@ -37,14 +38,22 @@ export const EngineSettingsSchema = z.object({
         automationEnabled: z.boolean(),
         olderTakeAction: z.union(_OlderTakeActionOptions.map(value => z.literal(value))),
         olderTakeScope: z.union(_OlderTakeScopeOptions.map(value => z.literal(value))),
-        inputLatency: z.number().min(-1)
+        inputLatency: z.number().min(-1),
+        preRollBars: z.union(_RecordingPreRollBars.map(value => z.literal(value))),
+        punchEnabled: z.boolean(),
+        punchFrom: z.number().min(0),
+        punchTo: z.number().min(0)
     }).default({
         countInBars: 1,
         allowTakes: true,
         automationEnabled: true,
         olderTakeAction: "mute-region",
         olderTakeScope: "previous-only",
-        inputLatency: 0
+        inputLatency: 0,
+        preRollBars: 1,
+        punchEnabled: false,
+        punchFrom: 15360,
+        punchTo: 30720
     })
 })
 
@@ -53,6 +62,7 @@ export type EngineSettings = z.infer<typeof EngineSettingsSchema>
 export namespace EngineSettings {
     export const BeatSubDivisionOptions = _BeatSubDivisionOptions
     export const RecordingCountInBars = _RecordingCountInBars
+    export const RecordingPreRollBars = _RecordingPreRollBars
     export const OlderTakeActionOptions = _OlderTakeActionOptions
     export const OlderTakeScopeOptions = _OlderTakeScopeOptions
 }
\ No newline at end of file
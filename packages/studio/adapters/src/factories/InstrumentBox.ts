@@ -1,17 +1,21 @@
 import {
     ApparatDeviceBox,
+    FMSynthDeviceBox,
     MIDIOutputDeviceBox,
     NanoDeviceBox,
     PlayfieldDeviceBox,
     SoundfontDeviceBox,
     TapeDeviceBox,
-    VaporisateurDeviceBox
+    VaporisateurDeviceBox,
+    WavetableDeviceBox
 } from "@opendaw/studio-boxes"
 
 export type InstrumentBox =
     | ApparatDeviceBox
     | TapeDeviceBox
     | VaporisateurDeviceBox
+    | WavetableDeviceBox
+    | FMSynthDeviceBox
     | NanoDeviceBox
     | PlayfieldDeviceBox
     | SoundfontDeviceBox
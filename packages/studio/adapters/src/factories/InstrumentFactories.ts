@@ -2,6 +2,7 @@ import {
     ApparatDeviceBox,
     AudioFileBox,
     BoxIO,
+    FMSynthDeviceBox,
     MIDIOutputDeviceBox,
     NanoDeviceBox,
     PlayfieldDeviceBox,
@@ -9,7 +10,8 @@ import {
     SoundfontDeviceBox,
     SoundfontFileBox,
     TapeDeviceBox,
-    VaporisateurDeviceBox
+    VaporisateurDeviceBox,
+    WavetableDeviceBox
 } from "@opendaw/studio-boxes"
 import {byte, isDefined, UUID} from "@opendaw/lib-std"
 import {ClassicWaveform} from "@opendaw/lib-dsp"
@@ -133,6 +135,44 @@ export namespace InstrumentFactories {
             })
     }
 
+    export const Wavetable: InstrumentFactory<void, WavetableDeviceBox> = {
+        defaultName: "Wavetable",
+        defaultIcon: IconSymbol.Sawtooth,
+        briefDescription: "Wavetable Synth",
+        description: "Wavetable synthesizer that morphs through the cycles of a sample",
+        manualPage: DeviceManualUrls.Wavetable,
+        trackType: TrackType.Notes,
+        create: (boxGraph: BoxGraph<BoxIO.TypeMap>,
+                 host: Field<Pointers.InstrumentHost | Pointers.AudioOutput>,
+                 name: string,
+                 icon: IconSymbol,
+                 _attachment?: void): WavetableDeviceBox =>
+            WavetableDeviceBox.create(boxGraph, UUID.generate(), box => {
+                box.label.setValue(name)
+                box.icon.setValue(IconSymbol.toName(icon))
+                box.host.refer(host)
+            })
+    }
+
+    export const FMSynth: InstrumentFactory<void, FMSynthDeviceBox> = {
+        defaultName: "FM Synth",
+        defaultIcon: IconSymbol.Sine,
+        briefDescription: "FM Synth",
+        description: "Four-operator frequency-modulation synthesizer",
+        manualPage: DeviceManualUrls.FMSynth,
+        trackType: TrackType.Notes,
+        create: (boxGraph: BoxGraph<BoxIO.TypeMap>,
+                 host: Field<Pointers.InstrumentHost | Pointers.AudioOutput>,
+                 name: string,
+                 icon: IconSymbol,
+                 _attachment?: void): FMSynthDeviceBox =>
+            FMSynthDeviceBox.create(boxGraph, UUID.generate(), box => {
+                box.label.setValue(name)
+                box.icon.setValue(IconSymbol.toName(icon))
+                box.host.refer(host)
+            })
+    }
+
     export const MIDIOutput: InstrumentFactory<void, MIDIOutputDeviceBox> = {
         defaultName: "MIDIOutput",
         defaultIcon: IconSymbol.Midi,
@@ -186,7 +226,7 @@ export namespace InstrumentFactories {
         })
     }
 
-    export const Named = {Apparat, MIDIOutput, Nano, Playfield, Soundfont, Tape, Vaporisateur}
+    export const Named = {Apparat, FMSynth, MIDIOutput, Nano, Playfield, Soundfont, Tape, Vaporisateur, Wavetable}
     export type Keys = keyof typeof Named
 
     const useAudioFile = (boxGraph: BoxGraph, fileUUID: UUID.Bytes, name: string, duration: number) =>
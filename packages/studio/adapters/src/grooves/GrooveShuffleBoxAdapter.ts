@@ -5,6 +5,7 @@ import {GroovePattern, GroovePatternFunction, ppqn, PPQN} from "@opendaw/lib-dsp
 import {GrooveAdapter} from "./GrooveBoxAdapter"
 import {BoxAdaptersContext} from "../BoxAdaptersContext"
 import {ParameterAdapterSet} from "../ParameterAdapterSet"
+import {GrooveTemplate} from "./GrooveTemplate"
 
 export class GrooveShuffleBoxAdapter implements GrooveAdapter {
     static readonly Durations: ReadonlyArray<[int, int]> = [
@@ -50,6 +51,20 @@ export class GrooveShuffleBoxAdapter implements GrooveAdapter {
     unwarp(position: ppqn): ppqn {return this.#groove.unwarp(position)}
     warp(position: ppqn): ppqn {return this.#groove.warp(position)}
 
+    // Must be called within a modify transaction. Steps past the template are reset to straight.
+    writeTemplate({steps, step, offsets, accents}: GrooveTemplate): void {
+        this.#box.templateSteps.setValue(steps)
+        this.#box.templateStep.setValue(step)
+        this.#box.template.fields().forEach((field, index) => {
+            field.offset.setValue(offsets[index] ?? 0.0)
+            field.accent.setValue(accents[index] ?? 1.0)
+        })
+    }
+
+    // Must be called within a modify transaction. Without steps, the Moebius shuffle is back in charge.
+    clearTemplate(): void {this.#box.templateSteps.setValue(0)}
+
+    get hasTemplate(): boolean {return this.#box.templateSteps.getValue() > 0}
     get box(): GrooveShuffleBox {return this.#box}
     get uuid(): UUID.Bytes {return this.#box.address.uuid}
     get address(): Address {return this.#box.address}
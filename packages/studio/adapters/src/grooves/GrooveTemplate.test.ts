@@ -0,0 +1,63 @@
+import {describe, expect, it} from "vitest"
+import {DefaultObservableValue, Option} from "@opendaw/lib-std"
+import {ConstantTempoMap} from "@opendaw/lib-dsp"
+import {GrooveTemplate} from "./GrooveTemplate"
+import {AudioRegionBoxAdapter} from "../timeline/region/AudioRegionBoxAdapter"
+
+describe("GrooveTemplate.extract", () => {
+    it("measures offsets and accents per step", () => {
+        // a 16th grid, every second 16th a third of a step late and softer
+        const onsets = Array.from({length: 32}, (_, index) => {
+            const late = index % 2 === 1
+            return {position: index * 240 + (late ? 80 : 0), velocity: late ? 0.4 : 0.8}
+        })
+        const {steps, offsets, accents} = GrooveTemplate.extract(onsets, 2, 240)
+        expect(steps).toBe(2)
+        expect(offsets[0]).toBeCloseTo(0.0)
+        expect(offsets[1]).toBeCloseTo(1.0 / 3.0)
+        expect(accents[0]).toBeCloseTo(0.8 / 0.6)
+        expect(accents[1]).toBeCloseTo(0.4 / 0.6)
+    })
+
+    it("snaps onsets to the nearest step and clamps", () => {
+        const {offsets, accents} = GrooveTemplate.extract([
+            {position: 230, velocity: 1.0},
+            {position: 480 + 119, velocity: 1.0}
+        ], 4, 240)
+        expect(offsets[1]).toBeCloseTo(-10 / 240)
+        expect(offsets[2]).toBe(GrooveTemplate.MaxOffset)
+        expect([offsets[0], offsets[3]]).toEqual([0.0, 0.0])
+        expect(accents[0]).toBe(1.0)
+    })
+
+    it("caps the steps at the template length", () => {
+        expect(GrooveTemplate.extract([], 32, 240).steps).toBe(GrooveTemplate.MaxSteps)
+    })
+
+    it("hands the accents of a quantised note line on without timing", () => {
+        const onsets = Array.from({length: 16}, (_, index) =>
+            ({position: index * 240, velocity: index % 4 === 0 ? 1.0 : 0.5}))
+        const {offsets, accents} = GrooveTemplate.extract(onsets, 4, 240)
+        expect(accents[0]).toBeGreaterThan(1.5)
+        expect(accents[1]).toBeLessThan(0.9)
+        expect(offsets).toEqual([0.0, 0.0, 0.0, 0.0])
+    })
+})
+
+describe("GrooveTemplate.fromAudioRegion", () => {
+    it("converts transients from seconds at the tempo", () => {
+        // 120 bpm: a 16th is 0.125 s, so a hit 1/24 s late is a third of a step
+        const transients = Array.from({length: 8}, (_, index) =>
+            ({position: index * 0.125 + (index % 2 === 1 ? 1.0 / 24.0 : 0.0)}))
+        const region = {
+            offset: 0, position: 0, complete: 1920,
+            waveformOffset: new DefaultObservableValue(0.0),
+            optWarpMarkers: Option.None,
+            optFile: Option.wrap({transients: {asArray: () => transients}})
+        } as unknown as AudioRegionBoxAdapter
+        const tempoMap = new ConstantTempoMap(new DefaultObservableValue(120))
+        const {offsets} = GrooveTemplate.fromAudioRegion(region, tempoMap, 2, 240)
+        expect(offsets[0]).toBeCloseTo(0.0)
+        expect(offsets[1]).toBeCloseTo(1.0 / 3.0)
+    })
+})
@@ -0,0 +1,94 @@
+import {clamp, float, int, unitValue} from "@opendaw/lib-std"
+import {LoopableRegion, ppqn, seconds, TempoMap} from "@opendaw/lib-dsp"
+import {NoteRegionBoxAdapter} from "../timeline/region/NoteRegionBoxAdapter"
+import {AudioRegionBoxAdapter} from "../timeline/region/AudioRegionBoxAdapter"
+
+/**
+ * A groove measured from a performance: a cycle of `steps` grid steps, each `step` pulses long, carrying a timing
+ * offset (a fraction of a step, positive = late) and a velocity accent (a scale, 1.0 = as played).
+ * Templates are only measured here: the result is written into the template fields of the GrooveShuffleBox
+ * (`GrooveShuffleBoxAdapter.writeTemplate`), which device-zeitgeist observes and applies.
+ */
+export type GrooveTemplate = {
+    readonly steps: int
+    readonly step: ppqn
+    readonly offsets: ReadonlyArray<number>
+    readonly accents: ReadonlyArray<float>
+}
+
+export namespace GrooveTemplate {
+    export const MaxSteps = 16
+    export const MaxOffset = 0.45
+    export const MaxAccent = 2.0
+
+    export type Onset = { position: ppqn, velocity: unitValue }
+
+    // Each onset snaps to its nearest grid step. Offsets and accents are averaged per step of the cycle.
+    // Steps nothing lands on stay straight.
+    export const extract = (onsets: Iterable<Onset>, steps: int, step: ppqn): GrooveTemplate => {
+        steps = clamp(steps, 0, MaxSteps)
+        const offsets: Array<number> = new Array(steps).fill(0.0)
+        const accents: Array<float> = new Array(steps).fill(1.0)
+        if (steps === 0 || step <= 0) {return {steps, step, offsets, accents}}
+        const offsetSums = new Float64Array(steps)
+        const velocitySums = new Float64Array(steps)
+        const counts = new Int32Array(steps)
+        let total = 0
+        for (const {position, velocity} of onsets) {
+            const grid = Math.round(position / step)
+            const slot = ((grid % steps) + steps) % steps
+            offsetSums[slot] += position / step - grid
+            velocitySums[slot] += velocity
+            counts[slot]++
+            total++
+        }
+        if (total === 0) {return {steps, step, offsets, accents}}
+        const mean = velocitySums.reduce((sum, value) => sum + value, 0.0) / total
+        for (let slot = 0; slot < steps; slot++) {
+            const count = counts[slot]
+            if (count === 0) {continue}
+            offsets[slot] = clamp(offsetSums[slot] / count, -MaxOffset, MaxOffset)
+            if (mean > 0.0) {accents[slot] = clamp(velocitySums[slot] / count / mean, 0.0, MaxAccent)}
+        }
+        return {steps, step, offsets, accents}
+    }
+
+    // The note-ons of every loop cycle of the region, at their song positions.
+    export const fromNoteRegion = (region: NoteRegionBoxAdapter, steps: int, step: ppqn): GrooveTemplate =>
+        extract(region.optCollection.mapOr(collection => {
+            const onsets: Array<Onset> = []
+            for (const {resultStart, resultEnd, rawStart}
+                of LoopableRegion.locateLoops(region, region.position, region.complete)) {
+                for (const event of collection.events.iterateRange(resultStart - rawStart, resultEnd - rawStart)) {
+                    onsets.push({position: rawStart + event.position, velocity: event.velocity})
+                }
+            }
+            return onsets
+        }, []), steps, step)
+
+    // The transient markers of the region's file that fall into the region, at their song positions. Warped regions
+    // place a marker by its warp markers, unwarped ones by the tempo map. Markers carry no strength, so no accents.
+    export const fromAudioRegion = (region: AudioRegionBoxAdapter,
+                                    tempoMap: TempoMap, steps: int, step: ppqn): GrooveTemplate => {
+        const origin: seconds = tempoMap.ppqnToSeconds(region.offset)
+        const waveformOffset: seconds = region.waveformOffset.getValue()
+        const toLocal = region.optWarpMarkers.match({
+            none: () => (time: seconds): ppqn => tempoMap.secondsToPPQN(origin + time - waveformOffset) - region.offset,
+            some: warpMarkers => (time: seconds): ppqn => {
+                const markers = warpMarkers.asArray()
+                if (markers.length < 2) {return NaN}
+                let index = 0
+                while (index < markers.length - 2 && markers[index + 1].seconds <= time) {index++}
+                const a = markers[index]
+                const b = markers[index + 1]
+                return a.position + (b.position - a.position) * (time - a.seconds) / (b.seconds - a.seconds)
+            }
+        })
+        const onsets: Array<Onset> = []
+        region.optFile.ifSome(file => file.transients.asArray().forEach(marker => {
+            const position = region.offset + toLocal(marker.position)
+            if (position >= region.position && position < region.complete) {onsets.push({position, velocity: 1.0})}
+        }))
+        return extract(onsets, steps, step)
+    }
+}
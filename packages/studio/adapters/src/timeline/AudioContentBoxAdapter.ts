@@ -1,4 +1,4 @@
-import {MutableObservableValue, ObservableOption, Option} from "@opendaw/lib-std"
+import {int, MutableObservableValue, ObservableOption, Option} from "@opendaw/lib-std"
 import {EventCollection, ppqn, TimeBase} from "@opendaw/lib-dsp"
 import {BoxAdapter} from "../BoxAdapter"
 import {AudioPlayMode} from "../audio/AudioPlayMode"
@@ -16,6 +16,7 @@ export interface AudioContentBoxAdapter extends BoxAdapter {
     get duration(): ppqn
     get observableOptPlayMode(): ObservableOption<AudioPlayMode>
     get waveformOffset(): MutableObservableValue<number>
+    get interpolation(): MutableObservableValue<int> // a SampleInterpolation
     get isPlayModeNoStretch(): boolean
     get asPlayModePitchStretch(): Option<AudioPitchStretchBoxAdapter>
     get asPlayModeTimeStretch(): Option<AudioTimeStretchBoxAdapter>
@@ -107,6 +107,7 @@ export class AudioClipBoxAdapter implements AudioContentBoxAdapter, ClipBoxAdapt
             box.hue.setValue(this.hue)
             box.duration.setValue(this.duration)
             box.mute.setValue(this.mute)
+            box.interpolation.setValue(this.interpolation.getValue())
             box.clips.refer(this.#box.clips.targetVertex.unwrap("clips.target"))
             box.file.refer(this.#box.file.targetVertex.unwrap("file.target"))
             box.events.refer(this.#box.events.targetVertex.unwrap("events.target"))
@@ -135,6 +136,7 @@ export class AudioClipBoxAdapter implements AudioContentBoxAdapter, ClipBoxAdapt
     get optCollection(): Option<never> {return Option.None}
     get timeBase(): TimeBase {return asEnumValue(this.#box.timeBase.getValue(), TimeBase)}
     get waveformOffset(): MutableObservableValue<number> {return this.#box.waveformOffset}
+    get interpolation(): MutableObservableValue<int> {return this.#box.interpolation}
     get isPlayModeNoStretch(): boolean {return this.#box.playMode.isEmpty()}
     get asPlayModePitchStretch(): Option<AudioPitchStretchBoxAdapter> {
         return this.observableOptPlayMode.map(mode => isInstanceOf(mode, AudioPitchStretchBoxAdapter) ? mode : null)
@@ -192,6 +192,7 @@ export class AudioRegionBoxAdapter implements AudioContentBoxAdapter, LoopableRe
     get observableOptPlayMode(): ObservableOption<AudioPlayMode> {return this.#playMode}
     get timeBase(): TimeBase {return asEnumValue(this.#box.timeBase.getValue(), TimeBase)}
     get waveformOffset(): MutableObservableValue<number> {return this.#box.waveformOffset}
+    get interpolation(): MutableObservableValue<int> {return this.#box.interpolation}
     get isPlayModeNoStretch(): boolean {return this.#box.playMode.isEmpty()}
     get asPlayModePitchStretch(): Option<AudioPitchStretchBoxAdapter> {
         return this.observableOptPlayMode.map(mode => isInstanceOf(mode, AudioPitchStretchBoxAdapter) ? mode : null)
@@ -273,6 +274,7 @@ export class AudioRegionBoxAdapter implements AudioContentBoxAdapter, LoopableRe
                 box.label.setValue(this.label)
                 box.gain.setValue(this.gain.getValue())
                 box.waveformOffset.setValue(this.waveformOffset.getValue())
+                box.interpolation.setValue(this.interpolation.getValue())
                 clonedPlayMode.ifSome(mode => box.playMode.refer(mode))
                 this.#fadingAdapter.copyTo(box.fading)
             }), AudioRegionBoxAdapter)
@@ -42,10 +42,12 @@ DEVICE_TOOLCHAIN="${DEVICE_TOOLCHAIN:-nightly}"
 
 # The PIC side-module device crates. ADD A NEW DEVICE HERE (its crate name) and it is built, size-optimised,
 # and copied to public/ automatically. The wasm artifact basename is the crate name with '-' -> '_'.
-DEVICE_CRATES="device-autotune device-revamp device-pitch device-arpeggio device-zeitgeist device-tidal device-vaporisateur device-nano device-delay device-playfield-sample device-gate device-werkstatt device-apparat device-spielwerk device-waveshaper device-crusher device-fold device-stereo-tool device-velocity device-maximizer device-compressor device-reverb device-dattorro-reverb device-soundfont device-vocoder device-neural-amp"
+DEVICE_CRATES="device-autotune device-revamp device-pitch device-scale device-ratchet device-arpeggio device-zeitgeist device-tidal device-vaporisateur device-wavetable device-fm-synth device-nano device-delay device-playfield-sample device-gate device-filter device-chorus device-werkstatt device-apparat device-spielwerk device-waveshaper device-crusher device-fold device-stereo-tool device-velocity device-maximizer device-compressor device-multiband-compressor device-reverb device-dattorro-reverb device-convolution-reverb device-soundfont device-vocoder device-neural-amp"
 
 RUSTFLAGS="$SIMD" cargo rustc -p engine --release --target "$TARGET" -- \
   -C link-arg=--import-memory -C link-arg=--import-table $SHARED
+# The main-thread SFZ importer (sfz-import.ts): its own memory, empty imports, default build.
+cargo build -p sfz-wasm --release --target "$TARGET"
 for crate in $DEVICE_CRATES; do
   RUSTFLAGS="$PIC_RUSTFLAGS" cargo "+$DEVICE_TOOLCHAIN" build -p "$crate" --release --target "$TARGET" -Zbuild-std=core
 done
@@ -78,5 +80,6 @@ fi
 DIST="$ROOT/packages/studio/core-wasm/dist"
 mkdir -p "$DIST/wasm/plugins"
 cp "$OUT/engine.wasm" "$DIST/wasm/"
+cp "$OUT/sfz_wasm.wasm" "$DIST/wasm/"
 for module in $DEVICE_MODULES; do cp "$OUT/$module.wasm" "$DIST/wasm/plugins/"; done
-echo "built: engine.wasm + stock devices + werkstatt/apparat/spielwerk"
+echo "built: engine.wasm + stock devices + werkstatt/apparat/spielwerk + sfz_wasm.wasm"
@@ -42,6 +42,8 @@ export type DeviceExports = {
     // Fires ONCE, only when the device's INSTANCE dies (a genuine removal — never a chain-edit survivor):
     // releases resources it holds outside its state block (e.g. a bridge's JS-side instance).
     terminate?: (statePtr: number) => void
+    // The samples the device's output lags its input (a look-ahead delay); the engine's delay compensation reads it.
+    latency?: (statePtr: number) => number
     midi_effects_field?: () => number
     audio_effects_field?: () => number
     observe_param_collection_field?: () => number
@@ -55,7 +57,8 @@ export type LinkerEngine = {
     device_alloc: (size: number) => number
     device_register: (processIndex: number, stateSize: number, kind: number, initIndex: number,
                       parameterChangedIndex: number, fieldChangedIndex: number, sampleChangedIndex: number,
-                      soundfontChangedIndex: number, resetIndex: number, terminateIndex: number, midiEffectsField: number,
+                      soundfontChangedIndex: number, resetIndex: number, terminateIndex: number, latencyIndex: number,
+                      midiEffectsField: number,
                       audioEffectsField: number, paramCollectionField: number, sampleCollectionField: number) => number
     device_set_box_type: (deviceId: number, nameLen: number) => void
     composite_register: (nameLen: number, childrenField: number, indexKey: number, excludeKey: number,
@@ -157,6 +160,7 @@ export const linkDevice = (engine: LinkerEngine, memory: WebAssembly.Memory, tab
         installOptional(device.init), installOptional(device.parameter_changed),
         installOptional(device.field_changed), installOptional(device.sample_changed),
         installOptional(device.soundfont_changed), installOptional(device.reset), installOptional(device.terminate),
+        installOptional(device.latency),
         device.midi_effects_field?.() ?? 0, device.audio_effects_field?.() ?? 0,
         device.observe_param_collection_field?.() ?? 0, device.observe_sample_collection_field?.() ?? 0)
     engine.device_set_box_type(deviceId, writeName(engine, memory, boxType))
@@ -7,8 +7,9 @@ export type EngineExports = {
     device_alloc: (size: number) => number
     // `terminateIndex` fires once, ONLY when the device's INSTANCE dies (a genuine removal — never a
     // chain-edit survivor): releases resources it holds outside its state block (e.g. a bridge's JS-side
-    // instance). 0 when the device exports none.
-    device_register: (processIndex: number, stateSize: number, kind: number, initIndex: number, parameterChangedIndex: number, fieldChangedIndex: number, sampleChangedIndex: number, soundfontChangedIndex: number, resetIndex: number, terminateIndex: number, midiEffectsField: number, audioEffectsField: number, paramCollectionField: number, sampleCollectionField: number) => number
+    // instance). 0 when the device exports none. `latencyIndex` is the device's optional `latency(statePtr)`
+    // (its look-ahead delay), read by the engine's delay compensation.
+    device_register: (processIndex: number, stateSize: number, kind: number, initIndex: number, parameterChangedIndex: number, fieldChangedIndex: number, sampleChangedIndex: number, soundfontChangedIndex: number, resetIndex: number, terminateIndex: number, latencyIndex: number, midiEffectsField: number, audioEffectsField: number, paramCollectionField: number, sampleCollectionField: number) => number
     // Map a device-box type to the just-registered device: the box-type UTF-8 name is written into the
     // input buffer (nameLen bytes) first. This is the device table the engine instantiates boxes through.
     device_set_box_type: (deviceId: number, nameLen: number) => void
@@ -60,6 +61,9 @@ export type EngineExports = {
     // comes from the caller's preferences; the ignored region uuid is written into the input scratch first.
     prepare_recording_state: (countIn: number, countInBars: number) => void
     stop_recording: () => void
+    // PUNCH range (ppqn) gating takes via BlockFlags.PUNCH, and the pre-roll bars played before the punch-in.
+    set_punch: (enabled: number, from: number, to: number) => void
+    set_pre_roll_bars: (bars: number) => void
     ignore_note_region: () => void
     // EFFECTS monitoring (TS EngineCommands.updateMonitoringMap): `set_monitoring_map` reads `count`
     // records of [unit uuid 16][left ch i32 LE][right ch i32 LE] (right -1 = mono) from the input scratch;
@@ -89,6 +93,11 @@ export type EngineExports = {
     note_signal_on: (pitch: number, velocity: number) => void
     note_signal_off: (pitch: number) => void
     note_signal_audition: (pitch: number, duration: number, velocity: number) => void
+    // Channel controls from the same input, emitted at the next block: a CC (value 0..1), the pitch wheel
+    // (-1..1) and channel pressure (0..1). Same uuid-first contract as the note signals.
+    note_signal_control: (controller: number, value: number) => void
+    note_signal_pitch_bend: (value: number) => void
+    note_signal_channel_pressure: (value: number) => void
     // CLIP LAUNCHING: write the 16-byte uuid into the input buffer first — a CLIP uuid for play (the
     // engine resolves its track), a TRACK uuid for stop. Transitions queue as 20-byte records
     // [uuid 16][kind u32 LE: 0 started, 1 stopped, 2 obsolete] drained via `clip_changes_take` (reserve
@@ -85,14 +85,20 @@ export const createEngineMemory = (): WebAssembly.Memory => {
 // each unit's chains from the box, ordered by the device `index`); only the type mapping matters.
 export const DEVICES: ReadonlyArray<{ url: string, boxType: string }> = [
     {url: "/wasm/plugins/device_vaporisateur.wasm", boxType: "VaporisateurDeviceBox"}, // instrument
+    {url: "/wasm/plugins/device_wavetable.wasm", boxType: "WavetableDeviceBox"}, // instrument (wavetable file)
+    {url: "/wasm/plugins/device_fm_synth.wasm", boxType: "FMSynthDeviceBox"}, // instrument
     {url: "/wasm/plugins/device_nano.wasm", boxType: "NanoDeviceBox"},         // instrument (sampler)
     {url: "/wasm/plugins/device_revamp.wasm", boxType: "RevampDeviceBox"},     // audio effect
     {url: "/wasm/plugins/device_tidal.wasm", boxType: "TidalDeviceBox"},       // audio effect
     {url: "/wasm/plugins/device_delay.wasm", boxType: "DelayDeviceBox"},       // audio effect
     {url: "/wasm/plugins/device_gate.wasm", boxType: "GateDeviceBox"},         // audio effect (sidechain)
+    {url: "/wasm/plugins/device_filter.wasm", boxType: "FilterDeviceBox"},     // audio effect (sidechain)
+    {url: "/wasm/plugins/device_chorus.wasm", boxType: "ChorusDeviceBox"},     // audio effect (chorus / flanger / phaser)
     {url: "/wasm/plugins/device_arpeggio.wasm", boxType: "ArpeggioDeviceBox"}, // midi effect
     {url: "/wasm/plugins/device_zeitgeist.wasm", boxType: "ZeitgeistDeviceBox"}, // midi effect
     {url: "/wasm/plugins/device_pitch.wasm", boxType: "PitchDeviceBox"},     // midi effect
+    {url: "/wasm/plugins/device_scale.wasm", boxType: "ScaleDeviceBox"},     // midi effect
+    {url: "/wasm/plugins/device_ratchet.wasm", boxType: "RatchetDeviceBox"}, // midi effect
     {url: "/wasm/plugins/device_werkstatt.wasm", boxType: "WerkstattDeviceBox"}, // scriptable audio effect
     {url: "/wasm/plugins/device_apparat.wasm", boxType: "ApparatDeviceBox"},   // scriptable instrument
     {url: "/wasm/plugins/device_spielwerk.wasm", boxType: "SpielwerkDeviceBox"}, // scriptable midi effect
@@ -103,8 +109,10 @@ export const DEVICES: ReadonlyArray<{ url: string, boxType: string }> = [
     {url: "/wasm/plugins/device_velocity.wasm", boxType: "VelocityDeviceBox"}, // midi effect
     {url: "/wasm/plugins/device_maximizer.wasm", boxType: "MaximizerDeviceBox"}, // audio effect
     {url: "/wasm/plugins/device_compressor.wasm", boxType: "CompressorDeviceBox"}, // audio effect (sidechain)
+    {url: "/wasm/plugins/device_multiband_compressor.wasm", boxType: "MultibandCompressorDeviceBox"}, // audio effect (2..4 bands)
     {url: "/wasm/plugins/device_reverb.wasm", boxType: "ReverbDeviceBox"},     // audio effect
     {url: "/wasm/plugins/device_dattorro_reverb.wasm", boxType: "DattorroReverbDeviceBox"}, // audio effect
+    {url: "/wasm/plugins/device_convolution_reverb.wasm", boxType: "ConvolutionReverbDeviceBox"}, // audio effect (impulse-response sample)
     {url: "/wasm/plugins/device_soundfont.wasm", boxType: "SoundfontDeviceBox"}, // instrument (preset sampler)
     {url: "/wasm/plugins/device_vocoder.wasm", boxType: "VocoderDeviceBox"},   // audio effect (channel vocoder + sidechain)
     {url: "/wasm/plugins/device_neural_amp.wasm", boxType: "NeuralAmpDeviceBox"}, // audio effect (NAM, via the nam bridge)
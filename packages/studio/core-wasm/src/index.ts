@@ -5,3 +5,5 @@ export * from "./WasmEngine"
 export * from "./engine-modules"
 export type {EngineExports} from "./engine-exports"
 export {readPanicMessage} from "./engine-exports"
+// Builds the same blob from an SFZ instrument folder, through the `sfz_wasm.wasm` importer next to the engine.
+export * from "./sfz-import"
@@ -161,6 +161,10 @@ class WasmEngineProcessor extends AudioWorkletProcessor {
                 engine.set_metronome_monophonic(monophonic ? 1 : 0), "metronome", "monophonic"),
             this.#preferences.catchupAndSubscribe(allowTakes =>
                 engine.set_allow_takes(allowTakes ? 1 : 0), "recording", "allowTakes"),
+            this.#preferences.catchupAndSubscribe(bars =>
+                engine.set_pre_roll_bars(bars), "recording", "preRollBars"),
+            this.#preferences.catchupAndSubscribe(({punchEnabled, punchFrom, punchTo}) =>
+                engine.set_punch(punchEnabled ? 1 : 0, punchFrom, punchTo), "recording"),
             this.#preferences.catchupAndSubscribe(pauseOnLoopDisabled =>
                 engine.set_pause_on_loop_disabled(pauseOnLoopDisabled ? 1 : 0), "playback", "pauseOnLoopDisabled"),
             this.#preferences.catchupAndSubscribe(truncate =>
@@ -427,8 +431,8 @@ class WasmEngineProcessor extends AudioWorkletProcessor {
         }
     }
 
-    // Route a live note signal (on-screen keys / pads / MIDI input) to the engine: the target
-    // AudioUnitBox uuid goes into the input scratch, then the matching export fires.
+    // Route a live note signal (on-screen keys / pads / MIDI input, including its CCs / pitch wheel / pressure)
+    // to the engine: the target AudioUnitBox uuid goes into the input scratch, then the matching export fires.
     #noteSignal(signal: NoteSignal): void {
         const pointer = this.#engine.input_reserve(16)
         new Uint8Array(this.#memory.buffer, pointer, 16).set(signal.uuid)
@@ -438,6 +442,12 @@ class WasmEngineProcessor extends AudioWorkletProcessor {
             this.#engine.note_signal_off(signal.pitch)
         } else if (NoteSignal.isAudition(signal)) {
             this.#engine.note_signal_audition(signal.pitch, signal.duration, signal.velocity)
+        } else if (NoteSignal.isControl(signal)) {
+            this.#engine.note_signal_control(signal.controller, signal.value)
+        } else if (NoteSignal.isPitchBend(signal)) {
+            this.#engine.note_signal_pitch_bend(signal.value)
+        } else if (NoteSignal.isChannelPressure(signal)) {
+            this.#engine.note_signal_channel_pressure(signal.value)
         }
     }
 
@@ -2,20 +2,30 @@
 // run JS-side by the script bridge. It reads the upstream input `EventRecord`s the device pulled (note-on /
 // note-off), builds the `UserEvent` stream, runs the user generator `*process(block, events)`, validates each
 // yielded note, schedules future-block notes, and correlates note-on -> note-off via a retainer so it can emit
-// the stops. The persistent state (retainer + scheduler) lives in `SpielwerkRuntime` across blocks.
+// the stops. A yielded note may carry `glide: {semitones, duration?}`: the runtime then steps a per-note tuning
+// expression from 0 to `semitones` over `duration` pulses (default: the whole note), across blocks, one step per
+// note per range. The persistent state (retainer + scheduler + running glides) lives in `SpielwerkRuntime` across
+// blocks.
 //
 // EventRecord (abi, 40 bytes, little-endian): position f64@0, offset u32@8, kind u32@12, id u32@16,
-// pitch u32@20, velocity f32@24, cent f32@28, duration f64@32. kind: 0 = note-on, 1 = note-off. `duration` is
-// the note's pulse length, carried on the note-on so the user script's `event.duration` is exact.
+// pitch u32@20, velocity f32@24, cent f32@28, duration f64@32. kind: 0 = note-on, 1 = note-off, 7 = note
+// expression (id = note, pitch = expression kind, velocity = value). `duration` is the note's pulse length,
+// carried on the note-on so the user script's `event.duration` is exact.
 
 const RECORD_SIZE = 40
 const KIND_NOTE_ON = 0
 const KIND_NOTE_OFF = 1
+const KIND_NOTE_EXPRESSION = 7
+const EXPRESSION_TUNING = 0
 const MAX_NOTES_PER_BLOCK = 128
 const MAX_SCHEDULED_NOTES = 128
+const MAX_GLIDE_SEMITONES = 120 // the abi's tuning expression range
+const GLIDE_STEP = 10 // pulses between tuning steps (48 per quarter at 480 ppqn)
 
-type ScheduledNote = {position: number, duration: number, pitch: number, velocity: number, cent: number}
+type Glide = {semitones: number, duration: number}
+type ScheduledNote = {position: number, duration: number, pitch: number, velocity: number, cent: number, glide: Glide | null}
 type RetainedNote = {id: number, position: number, duration: number, pitch: number, velocity: number, cent: number}
+type RunningGlide = {id: number, start: number, duration: number, semitones: number, next: number}
 
 let nextOutputId = 1
 
@@ -24,11 +34,13 @@ export class SpielwerkRuntime {
     readonly retained: RetainedNote[] = []   // emitted notes awaiting their note-off (position + duration reached)
     readonly scheduled: ScheduledNote[] = [] // notes the script placed in a future block
     readonly sourceToOutput = new Map<number, Set<number>>() // upstream note-on id -> emitted output ids it spawned
+    readonly glides: RunningGlide[] = []     // emitted notes still stepping toward their glide target
 
     reset(): void {
         this.retained.length = 0
         this.scheduled.length = 0
         this.sourceToOutput.clear()
+        this.glides.length = 0
     }
 }
 
@@ -54,9 +66,18 @@ const validateNote = (note: any, from: number): string | null => {
     if (note.duration <= 0) {return `Duration must be positive: ${note.duration}`}
     if (typeof note.position !== "number" || note.position !== note.position) {return `Invalid position: ${note.position}`}
     if (note.position < from) {return `Position ${note.position} is in the past (block starts at ${from})`}
+    if (note.glide !== undefined && note.glide !== null) {
+        const {semitones, duration} = note.glide
+        if (typeof semitones !== "number" || semitones !== semitones) {return `Invalid glide semitones: ${semitones}`}
+        if (Math.abs(semitones) > MAX_GLIDE_SEMITONES) {return `Glide out of range: ${semitones} (must be within ±${MAX_GLIDE_SEMITONES})`}
+        if (duration !== undefined && (typeof duration !== "number" || !(duration > 0))) {return `Glide duration must be positive: ${duration}`}
+    }
     return null
 }
 
+const toGlide = (note: any): Glide | null => note.glide === undefined || note.glide === null ? null
+    : {semitones: note.glide.semitones, duration: note.glide.duration ?? note.duration}
+
 // Run one pulled range through the user generator + tracking, writing output records, returning the count.
 // Throws on a script error / validation failure / flood (the bridge catches it and silences).
 export const runSpielwerk = (runtime: SpielwerkRuntime, proc: any, memory: ArrayBufferLike,
@@ -70,12 +91,16 @@ export const runSpielwerk = (runtime: SpielwerkRuntime, proc: any, memory: Array
         writeRecord(out, outCount++, kind, position, id, pitch, velocity, cent, duration)
     }
 
+    // Step every running glide through this range, before any note-off ends it below.
+    stepGlides(runtime, to, emit)
+
     // Release retained notes whose span completed within this range, emitting their note-off.
     for (let i = runtime.retained.length - 1; i >= 0; i--) {
         const note = runtime.retained[i]
         const end = note.position + note.duration
         if (end < to) {
             runtime.retained.splice(i, 1)
+            endGlide(runtime, note.id)
             emit(KIND_NOTE_OFF, end, note.id, note.pitch, 0, 0, 0)
         }
     }
@@ -97,6 +122,7 @@ export const runSpielwerk = (runtime: SpielwerkRuntime, proc: any, memory: Array
                     const index = runtime.retained.findIndex(note => note.id === outputId)
                     if (index >= 0) {
                         const note = runtime.retained.splice(index, 1)[0]
+                        endGlide(runtime, note.id)
                         emit(KIND_NOTE_OFF, position, note.id, note.pitch, 0, 0, 0)
                     }
                 }
@@ -111,7 +137,9 @@ export const runSpielwerk = (runtime: SpielwerkRuntime, proc: any, memory: Array
         const note = runtime.scheduled[i]
         if (note.position >= from && note.position < to) {
             runtime.scheduled.splice(i, 1)
-            emit(KIND_NOTE_ON, note.position, retain(runtime, note), note.pitch, note.velocity, note.cent, note.duration)
+            const id = retain(runtime, note)
+            emit(KIND_NOTE_ON, note.position, id, note.pitch, note.velocity, note.cent, note.duration)
+            startGlide(runtime, id, note, to, emit)
         }
     }
 
@@ -136,7 +164,7 @@ export const runSpielwerk = (runtime: SpielwerkRuntime, proc: any, memory: Array
         if (++noteCount > MAX_NOTES_PER_BLOCK) {throw new Error(`Note flood: exceeded ${MAX_NOTES_PER_BLOCK} notes per block`)}
         const error = validateNote(yielded, from)
         if (error !== null) {throw new Error(error)}
-        const note: ScheduledNote = {position: yielded.position, duration: yielded.duration, pitch: yielded.pitch, velocity: yielded.velocity, cent: yielded.cent ?? 0}
+        const note: ScheduledNote = {position: yielded.position, duration: yielded.duration, pitch: yielded.pitch, velocity: yielded.velocity, cent: yielded.cent ?? 0, glide: toGlide(yielded)}
         if (note.position >= to) {
             if (runtime.scheduled.length >= MAX_SCHEDULED_NOTES) {throw new Error(`Scheduler full: exceeded ${MAX_SCHEDULED_NOTES} scheduled notes`)}
             runtime.scheduled.push(note)
@@ -148,11 +176,49 @@ export const runSpielwerk = (runtime: SpielwerkRuntime, proc: any, memory: Array
                 set.add(id)
             }
             emit(KIND_NOTE_ON, note.position, id, note.pitch, note.velocity, note.cent, note.duration)
+            startGlide(runtime, id, note, to, emit)
         }
     }
     return outCount
 }
 
+type Emit = (kind: number, position: number, id: number, pitch: number, velocity: number, cent: number, duration: number) => void
+
+// Begin a just-emitted note's glide and step it through the rest of the current range.
+const startGlide = (runtime: SpielwerkRuntime, id: number, note: ScheduledNote, to: number, emit: Emit): void => {
+    if (note.glide === null || note.glide.semitones === 0) {return}
+    const glide = {id, start: note.position, duration: note.glide.duration, semitones: note.glide.semitones, next: note.position + GLIDE_STEP}
+    runtime.glides.push(glide)
+    stepGlide(runtime, glide, to, emit)
+}
+
+const stepGlides = (runtime: SpielwerkRuntime, to: number, emit: Emit): void => {
+    for (let i = runtime.glides.length - 1; i >= 0; i--) {
+        stepGlide(runtime, runtime.glides[i], to, emit)
+    }
+}
+
+// Advance the glide over its steps before `to` and emit only the last of them: one tuning expression per note per
+// range (the voice would overwrite the earlier ones within the range anyway), so glides never count against the
+// output cap beyond one record each. The glide is dropped once the target is reached.
+const stepGlide = (runtime: SpielwerkRuntime, glide: RunningGlide, to: number, emit: Emit): void => {
+    if (glide.next >= to) {return}
+    const end = glide.start + glide.duration
+    const last = glide.next + Math.ceil((to - glide.next) / GLIDE_STEP - 1) * GLIDE_STEP
+    const position = Math.min(last, end)
+    emit(KIND_NOTE_EXPRESSION, position, glide.id, EXPRESSION_TUNING, glide.semitones * (position - glide.start) / glide.duration, 0, 0)
+    if (position >= end) {
+        runtime.glides.splice(runtime.glides.indexOf(glide), 1)
+    } else {
+        glide.next = last + GLIDE_STEP
+    }
+}
+
+const endGlide = (runtime: SpielwerkRuntime, id: number): void => {
+    const index = runtime.glides.findIndex(glide => glide.id === id)
+    if (index >= 0) {runtime.glides.splice(index, 1)}
+}
+
 const retain = (runtime: SpielwerkRuntime, note: ScheduledNote): number => {
     const id = nextOutputId++
     runtime.retained.push({id, position: note.position, duration: note.duration, pitch: note.pitch, velocity: note.velocity, cent: note.cent})
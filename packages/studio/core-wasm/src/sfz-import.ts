@@ -0,0 +1,86 @@
+// Builds the SIMPLIFIED soundfont BLOB from an SFZ instrument — the SFZ counterpart of `soundfont-simplify.ts`.
+// The reading itself is Rust (`crates/soundfont-blob/src/sfz.rs`, the same code the offline renderer runs),
+// shipped as the standalone `sfz_wasm.wasm` (crates/sfz-wasm): own-memory instance, empty imports, raw
+// pointer/length ABI. The main thread stages the instrument's folder into it — text files as UTF-8, samples
+// decoded to planar f32 — and reads the blob back.
+import {WavFile} from "@opendaw/lib-dsp"
+import type {AudioData} from "@opendaw/lib-dsp"
+
+// One file of the instrument's folder, by its path relative to the `.sfz` file ('/'-separated).
+export type SfzFile = {path: string, bytes: ArrayBuffer}
+
+// Decodes a sample file. The default reads WAV; a host with an AudioContext can pass its `decodeAudioData`.
+export type SfzSampleDecoder = (bytes: ArrayBuffer, path: string) => Promise<AudioData>
+
+const SAMPLE_EXTENSION = /\.(wav|flac|ogg|mp3|aif|aiff|wv)$/i
+
+interface SfzExports {
+    memory: WebAssembly.Memory
+    alloc_bytes(len: number): number
+    free_bytes(ptr: number, len: number): void
+    sfz_reset(): void
+    sfz_stage_text(pathPtr: number, pathLen: number, textPtr: number, textLen: number): void
+    sfz_stage_sample(pathPtr: number, pathLen: number, planesPtr: number, channelCount: number,
+                     frameCount: number, sampleRate: number): void
+    sfz_build(textPtr: number, textLen: number): number
+    sfz_output_ptr(): number
+    sfz_output_len(): number
+}
+
+export class SfzImporter {
+    static async load(url: string): Promise<SfzImporter> {
+        const bytes = await fetch(url).then(response => response.arrayBuffer())
+        const {instance} = await WebAssembly.instantiate(bytes, {})
+        return new SfzImporter(instance.exports as unknown as SfzExports)
+    }
+
+    readonly #exports: SfzExports
+
+    private constructor(exports: SfzExports) {this.#exports = exports}
+
+    // Build the blob of the instrument at `sfzPath` within `files` (its includes and samples beside it).
+    async build(files: ReadonlyArray<SfzFile>, sfzPath: string,
+                decode: SfzSampleDecoder = async bytes => WavFile.decodeFloats(bytes)): Promise<ArrayBuffer> {
+        const exports = this.#exports
+        const instrument = files.find(file => file.path === sfzPath)
+        if (instrument === undefined) {return Promise.reject(new Error(`${sfzPath}: not in the instrument folder`))}
+        // Decode everything first: staging must not interleave with an await (another build could reset).
+        const decoded = await Promise.all(files.map(async file =>
+            SAMPLE_EXTENSION.test(file.path) ? {file, audio: await decode(file.bytes, file.path)} : {file, audio: null}))
+        exports.sfz_reset()
+        for (const {file, audio} of decoded) {
+            if (file === instrument) {continue}
+            const path = this.#copyIn(new TextEncoder().encode(file.path))
+            if (audio === null) {
+                const text = this.#copyIn(new Uint8Array(file.bytes))
+                exports.sfz_stage_text(path.ptr, path.len, text.ptr, text.len)
+                this.#free(text)
+            } else {
+                const frames = audio.numberOfFrames
+                const planes = {ptr: exports.alloc_bytes(audio.numberOfChannels * frames * 4), len: audio.numberOfChannels * frames * 4}
+                // alloc grows linear memory (detaches views), so take the views after allocating.
+                for (let channel = 0; channel < audio.numberOfChannels; channel++) {
+                    new Float32Array(exports.memory.buffer, planes.ptr + channel * frames * 4, frames).set(audio.frames[channel])
+                }
+                exports.sfz_stage_sample(path.ptr, path.len, planes.ptr, audio.numberOfChannels, frames, audio.sampleRate)
+                this.#free(planes)
+            }
+            this.#free(path)
+        }
+        const text = this.#copyIn(new Uint8Array(instrument.bytes))
+        const built = exports.sfz_build(text.ptr, text.len)
+        this.#free(text)
+        const output = new Uint8Array(exports.memory.buffer, exports.sfz_output_ptr(), exports.sfz_output_len()).slice()
+        exports.sfz_reset()
+        if (built === 0) {return Promise.reject(new Error(`${sfzPath}: ${new TextDecoder().decode(output)}`))}
+        return output.buffer
+    }
+
+    #copyIn(bytes: Uint8Array): {ptr: number, len: number} {
+        const ptr = this.#exports.alloc_bytes(bytes.byteLength)
+        new Uint8Array(this.#exports.memory.buffer, ptr, bytes.byteLength).set(bytes)
+        return {ptr, len: bytes.byteLength}
+    }
+
+    #free({ptr, len}: {ptr: number, len: number}): void {this.#exports.free_bytes(ptr, len)}
+}
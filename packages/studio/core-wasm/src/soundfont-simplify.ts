@@ -150,7 +150,10 @@ export const simplifySoundfont = (soundfont: SoundFont2): ArrayBuffer => {
                 const sample = instZone.sample ?? soundfont.samples[numeric(instZone, GeneratorType.SampleId) ?? 0]
                 if (sample === undefined) {continue}
                 const sampleIndex = internSample(sample)
-                const rootKey = numeric(instZone, GeneratorType.OverridingRootKey) ?? sample.header.originalPitch ?? 60
+                // `-1` (or any value off the keyboard) is "no override", as the spec reads it.
+                const overridingRootKey = numeric(instZone, GeneratorType.OverridingRootKey)
+                const rootKey = overridingRootKey !== undefined && overridingRootKey >= 0 && overridingRootKey < 128
+                    ? overridingRootKey : sample.header.originalPitch ?? 60
                 const sampleModes = combined(presetZone, instZone, GeneratorType.SampleModes) ?? 0
                 const sustain = combined(presetZone, instZone, GeneratorType.SustainVolEnv)
                 regions.push({
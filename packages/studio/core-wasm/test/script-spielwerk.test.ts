@@ -0,0 +1,46 @@
+import {describe, expect, it} from "vitest"
+import {runSpielwerk, SpielwerkRuntime} from "../src/script-spielwerk"
+
+const RECORD_SIZE = 40
+const OUT_MAX = 256
+
+// Yields one gliding note per pitch at the start of the range, each lasting the whole bar.
+const glidingChord = (pitches: ReadonlyArray<number>) => ({
+    * process(block: {from: number}) {
+        for (const pitch of pitches) {
+            yield {position: block.from, duration: 3840, pitch, velocity: 1.0, glide: {semitones: 12}}
+        }
+    }
+})
+
+const readKinds = (memory: ArrayBuffer, count: number): Array<{kind: number, position: number, value: number}> =>
+    Array.from({length: count}, (_, index) => {
+        const view = new DataView(memory, index * RECORD_SIZE, RECORD_SIZE)
+        return {kind: view.getUint32(12, true), position: view.getFloat64(0, true), value: view.getFloat32(24, true)}
+    })
+
+describe("Spielwerk glides", () => {
+    it("emit one tuning step per note per range", () => {
+        const memory = new ArrayBuffer(OUT_MAX * RECORD_SIZE)
+        const runtime = new SpielwerkRuntime()
+        const count = runSpielwerk(runtime, glidingChord([60, 64, 67, 72]), memory,
+            0, 0, 0, OUT_MAX, 0, 960, 120, 0, 0, 128)
+        const records = readKinds(memory, count)
+        expect(records.filter(({kind}) => kind === 0).length).toBe(4)
+        const steps = records.filter(({kind}) => kind === 7)
+        expect(steps.length).toBe(4)
+        steps.forEach(({position, value}) => {
+            expect(position).toBe(950)
+            expect(value).toBeCloseTo(12 * 950 / 3840)
+        })
+    })
+
+    it("do not flood the output over a long range", () => {
+        const memory = new ArrayBuffer(OUT_MAX * RECORD_SIZE)
+        const runtime = new SpielwerkRuntime()
+        const pitches = Array.from({length: 16}, (_, index) => 48 + index)
+        expect(() => runSpielwerk(runtime, glidingChord(pitches), memory,
+            0, 0, 0, OUT_MAX, 0, 3840, 120, 0, 0, 128)).not.toThrow()
+        expect(runtime.glides.length).toBe(16)
+    })
+})
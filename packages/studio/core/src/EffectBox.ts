@@ -3,18 +3,24 @@ import {
     AudioEffectCompositeBox,
     AutotuneDeviceBox,
     MaximizerDeviceBox,
+    MultibandCompressorDeviceBox,
     StereoCompositeBox,
+    ChorusDeviceBox,
     CompressorDeviceBox,
+    ConvolutionReverbDeviceBox,
     CrusherDeviceBox,
     DattorroReverbDeviceBox,
     DelayDeviceBox,
+    FilterDeviceBox,
     FoldDeviceBox,
     GateDeviceBox,
     ModularDeviceBox,
     NeuralAmpDeviceBox,
     PitchDeviceBox,
+    RatchetDeviceBox,
     RevampDeviceBox,
     ReverbDeviceBox,
+    ScaleDeviceBox,
     StereoToolDeviceBox,
     TidalDeviceBox,
     UnknownAudioEffectDeviceBox,
@@ -29,8 +35,9 @@ import {
 
 export type EffectBox =
     | ArpeggioDeviceBox | PitchDeviceBox | VelocityDeviceBox | ZeitgeistDeviceBox | UnknownMidiEffectDeviceBox
-    | SpielwerkDeviceBox
+    | RatchetDeviceBox | ScaleDeviceBox | SpielwerkDeviceBox
     | MaximizerDeviceBox | DelayDeviceBox | ReverbDeviceBox | RevampDeviceBox | StereoToolDeviceBox | TidalDeviceBox
     | ModularDeviceBox | UnknownAudioEffectDeviceBox | CompressorDeviceBox | GateDeviceBox
     | AutotuneDeviceBox | CrusherDeviceBox | FoldDeviceBox | DattorroReverbDeviceBox | NeuralAmpDeviceBox | VocoderDeviceBox
+    | FilterDeviceBox | ChorusDeviceBox | ConvolutionReverbDeviceBox | MultibandCompressorDeviceBox
     | WaveshaperDeviceBox | WerkstattDeviceBox | AudioEffectCompositeBox | StereoCompositeBox
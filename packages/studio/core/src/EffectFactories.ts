@@ -5,10 +5,13 @@ import {
     AudioEffectCompositeBox,
     AudioEffectCompositeCellBox,
     AutotuneDeviceBox,
+    ChorusDeviceBox,
     CompressorDeviceBox,
+    ConvolutionReverbDeviceBox,
     CrusherDeviceBox,
     DattorroReverbDeviceBox,
     DelayDeviceBox,
+    FilterDeviceBox,
     FoldDeviceBox,
     FrequencySplitBox,
     GateDeviceBox,
@@ -19,10 +22,13 @@ import {
     ModularBox,
     ModularDeviceBox,
     ModuleConnectionBox,
+    MultibandCompressorDeviceBox,
     NeuralAmpDeviceBox,
     PitchDeviceBox,
+    RatchetDeviceBox,
     RevampDeviceBox,
     ReverbDeviceBox,
+    ScaleDeviceBox,
     StereoCompositeBox,
     StereoToolDeviceBox,
     TidalDeviceBox,
@@ -79,6 +85,40 @@ export namespace EffectFactories {
             })
     }
 
+    export const Ratchet: EffectFactory = {
+        defaultName: "Ratchet",
+        defaultIcon: IconSymbol.PlayRepeat,
+        briefDescription: "Note Repeat",
+        description: "Retriggers held notes on a tempo-synced grid with ratchet patterns",
+        manualPage: DeviceManualUrls.Ratchet,
+        separatorBefore: false,
+        external: false,
+        type: "midi",
+        create: ({boxGraph}, hostField, index) =>
+            RatchetDeviceBox.create(boxGraph, UUID.generate(), (box) => {
+                box.label.setValue("Ratchet")
+                box.index.setValue(index)
+                box.host.refer(hostField)
+            })
+    }
+
+    export const Scale: EffectFactory = {
+        defaultName: "Scale",
+        defaultIcon: IconSymbol.Piano,
+        briefDescription: "Scale & Chords",
+        description: "Snaps notes to a key and scale and stacks diatonic chords on them",
+        manualPage: DeviceManualUrls.Scale,
+        separatorBefore: false,
+        external: false,
+        type: "midi",
+        create: ({boxGraph}, hostField, index) =>
+            ScaleDeviceBox.create(boxGraph, UUID.generate(), (box) => {
+                box.label.setValue("Scale")
+                box.index.setValue(index)
+                box.host.refer(hostField)
+            })
+    }
+
     export const Velocity: EffectFactory = {
         defaultName: "Velocity",
         defaultIcon: IconSymbol.Velocity,
@@ -319,6 +359,74 @@ export namespace EffectFactories {
             })
     }
 
+    export const Filter: EffectFactory = {
+        defaultName: "Filter",
+        defaultIcon: IconSymbol.LowPass,
+        briefDescription: "Multimode Filter",
+        description: "A resonant filter with a tempo-synced LFO, an envelope follower and drive",
+        manualPage: DeviceManualUrls.Filter,
+        separatorBefore: false,
+        external: false,
+        type: "audio",
+        create: ({boxGraph}, hostField, index): FilterDeviceBox =>
+            FilterDeviceBox.create(boxGraph, UUID.generate(), (box) => {
+                box.label.setValue("Filter")
+                box.index.setValue(index)
+                box.host.refer(hostField)
+            })
+    }
+
+    export const Chorus: EffectFactory = {
+        defaultName: "Chorus",
+        defaultIcon: IconSymbol.Sine,
+        briefDescription: "Chorus, Flanger & Phaser",
+        description: "Modulates a short delay or an all-pass chain for chorus, flanger and phaser sweeps",
+        manualPage: DeviceManualUrls.Chorus,
+        separatorBefore: false,
+        external: false,
+        type: "audio",
+        create: ({boxGraph}, hostField, index): ChorusDeviceBox =>
+            ChorusDeviceBox.create(boxGraph, UUID.generate(), (box) => {
+                box.label.setValue("Chorus")
+                box.index.setValue(index)
+                box.host.refer(hostField)
+            })
+    }
+
+    export const ConvolutionReverb: EffectFactory = {
+        defaultName: "Convolution Reverb",
+        defaultIcon: IconSymbol.Cube,
+        briefDescription: "Convolution Reverb",
+        description: "Places the signal in a space captured by an impulse-response sample",
+        manualPage: DeviceManualUrls.ConvolutionReverb,
+        separatorBefore: false,
+        external: false,
+        type: "audio",
+        create: ({boxGraph}, hostField, index): ConvolutionReverbDeviceBox =>
+            ConvolutionReverbDeviceBox.create(boxGraph, UUID.generate(), (box) => {
+                box.label.setValue("Convolution Reverb")
+                box.index.setValue(index)
+                box.host.refer(hostField)
+            })
+    }
+
+    export const MultibandCompressor: EffectFactory = {
+        defaultName: "Multiband Compressor",
+        defaultIcon: IconSymbol.Compressor,
+        briefDescription: "Multiband Compressor",
+        description: "Compresses up to four frequency bands independently",
+        manualPage: DeviceManualUrls.MultibandCompressor,
+        separatorBefore: false,
+        external: false,
+        type: "audio",
+        create: ({boxGraph}, hostField, index): MultibandCompressorDeviceBox =>
+            MultibandCompressorDeviceBox.create(boxGraph, UUID.generate(), (box) => {
+                box.label.setValue("Multiband Compressor")
+                box.index.setValue(index)
+                box.host.refer(hostField)
+            })
+    }
+
     export const Waveshaper: EffectFactory = {
         defaultName: "Waveshaper",
         defaultIcon: IconSymbol.Curve,
@@ -551,6 +659,8 @@ export namespace EffectFactories {
     export const MidiNamed = {
         Arpeggio,
         Pitch,
+        Ratchet,
+        Scale,
         Spielwerk,
         Velocity,
         Zeitgeist
@@ -558,13 +668,17 @@ export namespace EffectFactories {
 
     export const AudioNamed = {
         AudioEffectComposite, // FX Composite
+        MultibandCompressor,
         StereoComposite,      // Stereo Split
         FrequencySplit,       // Frequency Split
         Autotune,
+        Chorus,
         Compressor,
+        ConvolutionReverb, // Convolution Reverb
         Crusher,
         DattorroReverb,  // Dattorro Reverb
         Delay,
+        Filter,
         Fold,
         Reverb,          // Free Reverb
         Gate,
@@ -91,8 +91,15 @@ export class EngineWorklet extends AudioWorkletNode implements Engine {
         const budgetMs = (RenderQuantum / context.sampleRate) * 1000
         const reader = SyncStream.reader<EngineState>(EngineStateSchema(), state => {
             this.#isPlaying.setValue(state.isPlaying)
-            this.#isRecording.setValue(state.isRecording)
-            this.#isCountingIn.setValue(state.isCountingIn)
+            // Never report both flags down in between: a count-in turns into recording and, when looping takes
+            // with a punch range, a take's punch-out turns recording into the wait for the next punch-in.
+            if (state.isCountingIn) {
+                this.#isCountingIn.setValue(state.isCountingIn)
+                this.#isRecording.setValue(state.isRecording)
+            } else {
+                this.#isRecording.setValue(state.isRecording)
+                this.#isCountingIn.setValue(state.isCountingIn)
+            }
             this.#countInBeatsRemaining.setValue(state.countInBeatsRemaining)
             this.#playbackTimestamp.setValue(state.playbackTimestamp)
             this.#bpm.setValue(state.bpm)
@@ -282,6 +282,9 @@ export class CaptureMidi extends Capture<CaptureMidiBox> {
                     } else if (MidiData.isNoteOff(data) && activeNotes[pitch] > 0) {
                         activeNotes[pitch]--
                         this.#notifier.notify(NoteSignal.fromEvent(event, this.uuid))
+                    } else if (MidiData.isController(data) || MidiData.isPitchWheel(data)
+                        || MidiData.isChannelPressure(data)) {
+                        this.#notifier.notify(NoteSignal.fromEvent(event, this.uuid))
                     }
                 }
             })),
@@ -214,7 +214,8 @@ export namespace RecordAudio {
                 // From here on, isRecording is true
                 const loopEnabled = loopArea.enabled.getValue()
                 const loopFrom = loopArea.from.getValue()
-                const allowTakes = project.engine.preferences.settings.recording.allowTakes
+                const {allowTakes, punchEnabled, punchFrom, punchTo} = project.engine.preferences.settings.recording
+                const punched = punchEnabled && punchFrom < punchTo
                 if (loopEnabled && allowTakes && currentTake.nonEmpty() && currentPosition < lastPosition) {
                     // Compute the take's length from its own start position to
                     // loopTo, not from loopFrom. When recording begins mid-loop,
@@ -224,7 +225,11 @@ export namespace RecordAudio {
                     // regionBox.duration that previously caused peak drift):
                     // subsequent takes start at loopFrom, so this evaluates to
                     // the full loop length exactly as before.
+                    // With a punch range a take ends at the punch-out and the next one starts at the punch-in:
+                    // the file keeps running meanwhile, so its offset advances by the whole pass up to there.
                     const loopTo = loopArea.to.getValue()
+                    const takeEnd = punched ? Math.min(loopTo, punchTo) : loopTo
+                    const nextStart = punched ? Math.max(loopFrom, punchFrom) : loopFrom
                     editing.modify(() => {
                         currentTake.ifSome(take => {
                             if (take.regionBox.duration.getValue() <= 0) {
@@ -232,13 +237,13 @@ export namespace RecordAudio {
                                 currentTake = Option.None
                                 return
                             }
-                            const takeDurationSeconds = tempoMap.intervalToSeconds(
-                                take.regionBox.position.getValue(), loopTo)
-                            finalizeTake(take, takeDurationSeconds)
-                            currentWaveformOffset += takeDurationSeconds
+                            const takePosition = take.regionBox.position.getValue()
+                            finalizeTake(take, tempoMap.intervalToSeconds(takePosition, takeEnd))
+                            currentWaveformOffset += tempoMap.intervalToSeconds(takePosition, loopTo)
+                                + tempoMap.intervalToSeconds(loopFrom, nextStart)
                         })
                         if (currentTake.nonEmpty()) {
-                            startNewTake(loopFrom)
+                            startNewTake(nextStart)
                         }
                     }, false)
                 }
@@ -12,7 +12,8 @@ import {
     UUID
 } from "@opendaw/lib-std"
 import {ppqn, PPQN} from "@opendaw/lib-dsp"
-import {NoteEventBox, NoteEventCollectionBox, NoteRegionBox, TrackBox} from "@opendaw/studio-boxes"
+import {NoteControlEventBox, NoteEventBox, NoteEventCollectionBox, NoteRegionBox, TrackBox} from "@opendaw/studio-boxes"
+import {NoteControlType} from "@opendaw/studio-enums"
 import {ColorCodes, NoteSignal, TrackType, UnionBoxTypes} from "@opendaw/studio-adapters"
 import {Project} from "../project"
 import {Capture} from "./Capture"
@@ -124,19 +125,37 @@ export namespace RecordMidi {
             pendingNotes.clear()
         }
 
+        const recordControl = (type: NoteControlType, controller: byte, value: number) => {
+            currentTake.ifSome(({regionBox, collection}) => editing.modify(() => {
+                const controlPosition = position.getValue() + latency - regionBox.position.getValue()
+                if (controlPosition < 0) {return}
+                NoteControlEventBox.create(boxGraph, UUID.generate(), box => {
+                    box.position.setValue(controlPosition)
+                    box.type.setValue(type)
+                    box.controller.setValue(controller)
+                    box.value.setValue(value)
+                    box.events.refer(collection.controls)
+                })
+            }, false))
+        }
+
         const startNewTake = (position: ppqn) => {
             const previousTrack = currentTake.mapOr(take => take.trackBox, null)
             currentTake = Option.wrap(createTakeRegion(position, previousTrack))
         }
 
         terminator.own(position.catchupAndSubscribe(owner => {
-            if (!isRecording.getValue()) {return}
+            if (!isRecording.getValue()) {
+                activeNotes.clear() // held notes end where the recording does (stop or punch-out)
+                return
+            }
             const currentPosition = owner.getValue()
             const writePosition = currentPosition + latency
             const loopEnabled = loopArea.enabled.getValue()
             const loopFrom = loopArea.from.getValue()
             const loopTo = loopArea.to.getValue()
-            const allowTakes = project.engine.preferences.settings.recording.allowTakes
+            const {allowTakes, punchEnabled, punchFrom, punchTo} = project.engine.preferences.settings.recording
+            const punched = punchEnabled && punchFrom < punchTo
             if (loopEnabled && allowTakes && currentTake.nonEmpty() && currentPosition < lastPosition) {
                 editing.modify(() => {
                     currentTake.ifSome(take => {
@@ -150,14 +169,14 @@ export namespace RecordMidi {
                         positionOffset += actualDurationPPQN
                     })
                     if (currentTake.nonEmpty()) {
-                        startNewTake(loopFrom)
+                        startNewTake(punched ? Math.max(loopFrom, punchFrom) : loopFrom)
                     }
                 }, false)
             }
             lastPosition = currentPosition
             if (currentTake.isEmpty()) {
                 editing.modify(() => {
-                    const pos = quantizeFloor(currentPosition, beats)
+                    const pos = punched ? punchFrom : quantizeFloor(currentPosition, beats)
                     const take = createTakeRegion(pos, null)
                     currentTake = Option.wrap(take)
                     flushPendingNotes(take)
@@ -167,7 +186,8 @@ export namespace RecordMidi {
                 editing.modify(() => {
                     if (regionBox.isAttached() && collection.isAttached()) {
                         const {position: regionPosition, duration, loopDuration} = regionBox
-                        const maxDuration = loopEnabled && allowTakes ? loopTo - regionPosition.getValue() : Infinity
+                        const takeEnd = Math.min(loopEnabled && allowTakes ? loopTo : Infinity, punched ? punchTo : Infinity)
+                        const maxDuration = takeEnd - regionPosition.getValue()
                         const newDuration = Math.max(duration.getValue(),
                             Math.min(maxDuration, quantizeCeil(writePosition, beats) - regionPosition.getValue()))
                         duration.setValue(newDuration)
@@ -196,6 +216,7 @@ export namespace RecordMidi {
                     pendingNotes.set(pitch, velocity)
                     return
                 }
+                if (!isRecording.getValue()) {return} // past the punch-out, waiting for the next take
                 const take = currentTake.unwrap()
                 const {regionBox, collection} = take
                 editing.modify(() => {
@@ -216,6 +237,12 @@ export namespace RecordMidi {
                 } else {
                     activeNotes.delete(signal.pitch)
                 }
+            } else if (NoteSignal.isControl(signal)) {
+                recordControl(NoteControlType.Controller, signal.controller, signal.value)
+            } else if (NoteSignal.isPitchBend(signal)) {
+                recordControl(NoteControlType.PitchBend, 0, signal.value)
+            } else if (NoteSignal.isChannelPressure(signal)) {
+                recordControl(NoteControlType.ChannelPressure, 0, signal.value)
             }
         }))
         terminator.own(Terminable.create(() => {
@@ -46,7 +46,12 @@ export class Recording {
             engine.isCountingIn.subscribe(stop),
             Terminable.create(() => Recording.#instance = Option.None)
         )
-        this.#instance = Option.wrap(new Recording(countIn && !engine.isPlaying.getValue(), engine.position.getValue()))
+        // With a punch range the engine leads in to the punch-in (a pre-roll, or the playhead running on), so the
+        // captured file starts with that lead-in on the wall-clock and never with a bare count-in.
+        const {punchEnabled, punchFrom, punchTo} = engine.preferences.settings.recording
+        const punched = punchEnabled && punchFrom < punchTo
+        this.#instance = Option.wrap(new Recording(countIn && !punched && !engine.isPlaying.getValue(),
+            engine.position.getValue()))
         return terminator
     }
 
@@ -0,0 +1 @@
+export enum NoteControlType {Controller, PitchBend, ChannelPressure}
\ No newline at end of file
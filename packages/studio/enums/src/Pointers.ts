@@ -58,4 +58,5 @@ export enum Pointers {
     NeuralAmpModel,
     CompositeCell,
     AudioEffectCompositeCell,
+    NoteControlEvents,
 }
\ No newline at end of file
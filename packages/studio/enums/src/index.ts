@@ -3,6 +3,7 @@ export * from "./AudioSendRouting"
 export * from "./AudioUnitType"
 export * from "./Colors"
 export * from "./IconSymbol"
+export * from "./NoteControlType"
 export * from "./Pointers"
 export * from "./TransientPlayMode"
 export * from "./VoicingMode"
\ No newline at end of file
@@ -0,0 +1,36 @@
+import {BoxSchema} from "@opendaw/lib-box-forge"
+import {Pointers} from "@opendaw/studio-enums"
+import {DeviceFactory} from "../../std/DeviceFactory"
+import {ParameterPointerRules} from "../../std/Defaults"
+
+export const ChorusDeviceBox: BoxSchema<Pointers> = DeviceFactory.createAudioEffect("ChorusDeviceBox", {
+    10: {
+        type: "int32", name: "mode", pointerRules: ParameterPointerRules,
+        value: 0, constraints: {length: 3}, unit: "" // Chorus, Flanger, Phaser
+    },
+    11: {type: "boolean", name: "sync", pointerRules: ParameterPointerRules, value: true},
+    12: {
+        type: "int32", name: "rate-index", pointerRules: ParameterPointerRules,
+        value: 0, constraints: {length: 17}, unit: ""
+    },
+    13: {
+        type: "float32", name: "rate", pointerRules: ParameterPointerRules,
+        value: 0.5, constraints: {min: 0.05, max: 10.0, scaling: "exponential"}, unit: "Hz"
+    },
+    14: {
+        type: "float32", name: "depth", pointerRules: ParameterPointerRules,
+        value: 0.5, constraints: "unipolar", unit: "%"
+    },
+    15: {
+        type: "float32", name: "feedback", pointerRules: ParameterPointerRules,
+        value: 0.0, constraints: "bipolar", unit: ""
+    },
+    16: {
+        type: "float32", name: "spread", pointerRules: ParameterPointerRules,
+        value: 90.0, constraints: {min: 0.0, max: 180.0, scaling: "linear"}, unit: "°"
+    },
+    17: {
+        type: "float32", name: "mix", pointerRules: ParameterPointerRules,
+        value: 0.5, constraints: "unipolar", unit: "%"
+    }
+})
@@ -0,0 +1,32 @@
+import {BoxSchema} from "@opendaw/lib-box-forge"
+import {Pointers} from "@opendaw/studio-enums"
+import {ParameterPointerRules} from "../../std/Defaults"
+import {DeviceFactory} from "../../std/DeviceFactory"
+
+export const ConvolutionReverbDeviceBox: BoxSchema<Pointers> = DeviceFactory.createAudioEffect("ConvolutionReverbDeviceBox", {
+    10: {
+        type: "float32", name: "pre-delay", pointerRules: ParameterPointerRules,
+        value: 0.001, constraints: {min: 0.001, max: 0.500, scaling: "exponential"}, unit: "s"
+    },
+    11: {
+        type: "float32", name: "start", pointerRules: ParameterPointerRules,
+        value: 0.0, constraints: "unipolar", unit: "%"
+    },
+    12: {
+        type: "float32", name: "end", pointerRules: ParameterPointerRules,
+        value: 1.0, constraints: "unipolar", unit: "%"
+    },
+    13: {
+        type: "float32", name: "stretch", pointerRules: ParameterPointerRules,
+        value: 1.0, constraints: {min: 0.5, max: 2.0, scaling: "exponential"}, unit: ""
+    },
+    14: {
+        type: "float32", name: "wet", pointerRules: ParameterPointerRules,
+        value: -3.0, constraints: "decibel", unit: "dB"
+    },
+    15: {
+        type: "float32", name: "dry", pointerRules: ParameterPointerRules,
+        value: 0.0, constraints: "decibel", unit: "dB"
+    },
+    20: {type: "pointer", name: "file", pointerType: Pointers.AudioFile, mandatory: false}
+})
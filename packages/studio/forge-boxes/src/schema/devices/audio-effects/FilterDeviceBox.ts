@@ -0,0 +1,50 @@
+import {BoxSchema} from "@opendaw/lib-box-forge"
+import {Pointers} from "@opendaw/studio-enums"
+import {DeviceFactory} from "../../std/DeviceFactory"
+import {ParameterPointerRules} from "../../std/Defaults"
+
+export const FilterDeviceBox: BoxSchema<Pointers> = DeviceFactory.createAudioEffect("FilterDeviceBox", {
+    10: {
+        type: "int32", name: "mode", pointerRules: ParameterPointerRules,
+        value: 0, constraints: {length: 5}, unit: "" // LowPass, HighPass, BandPass, Notch, Ladder
+    },
+    11: {
+        type: "float32", name: "cutoff", pointerRules: ParameterPointerRules,
+        value: 1000.0, constraints: {min: 20.0, max: 20_000.0, scaling: "exponential"}, unit: "Hz"
+    },
+    12: {
+        type: "float32", name: "resonance", pointerRules: ParameterPointerRules,
+        value: Math.SQRT1_2, constraints: {min: 0.1, max: 10.0, scaling: "exponential"}, unit: "q"
+    },
+    13: {
+        type: "float32", name: "drive", pointerRules: ParameterPointerRules,
+        value: 0.0, constraints: {min: 0.0, max: 24.0, scaling: "linear"}, unit: "dB"
+    },
+    14: {
+        type: "int32", name: "lfo-rate", pointerRules: ParameterPointerRules,
+        value: 3, constraints: {length: 17}, unit: ""
+    },
+    15: {
+        type: "int32", name: "lfo-shape", pointerRules: ParameterPointerRules,
+        value: 0, constraints: {length: 4}, unit: "" // Sine, Triangle, Saw, Square
+    },
+    16: {
+        type: "float32", name: "lfo-depth", pointerRules: ParameterPointerRules,
+        value: 0.0, constraints: "bipolar", unit: ""
+    },
+    17: {
+        type: "float32", name: "env-amount", pointerRules: ParameterPointerRules,
+        value: 0.0, constraints: "bipolar", unit: ""
+    },
+    18: {
+        type: "float32", name: "env-attack", pointerRules: ParameterPointerRules,
+        value: 5.0, constraints: {min: 0.1, max: 100.0, scaling: "exponential"}, unit: "ms"
+    },
+    19: {
+        type: "float32", name: "env-release", pointerRules: ParameterPointerRules,
+        value: 100.0, constraints: {min: 1.0, max: 1000.0, scaling: "exponential"}, unit: "ms"
+    },
+    30: {
+        type: "pointer", name: "side-chain", pointerType: Pointers.SideChain, mandatory: false
+    }
+})
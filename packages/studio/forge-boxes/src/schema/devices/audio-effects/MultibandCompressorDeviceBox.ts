@@ -0,0 +1,57 @@
+import {BoxSchema} from "@opendaw/lib-box-forge"
+import {Pointers} from "@opendaw/studio-enums"
+import {DeviceFactory} from "../../std/DeviceFactory"
+import {ParameterPointerRules} from "../../std/Defaults"
+
+const crossoverConstraints = {min: 20.0, max: 20_000.0, scaling: "exponential"} as const
+
+export const MultibandCompressorDeviceBox: BoxSchema<Pointers> = DeviceFactory.createAudioEffect("MultibandCompressorDeviceBox", {
+    10: {
+        type: "int32", name: "band-count", pointerRules: ParameterPointerRules,
+        value: 3, constraints: {min: 2, max: 4}, unit: ""
+    },
+    11: {
+        type: "float32", name: "crossover-low", pointerRules: ParameterPointerRules,
+        value: 200.0, constraints: crossoverConstraints, unit: "Hz"
+    },
+    12: {
+        type: "float32", name: "crossover-mid", pointerRules: ParameterPointerRules,
+        value: 1_000.0, constraints: crossoverConstraints, unit: "Hz"
+    },
+    13: {
+        type: "float32", name: "crossover-high", pointerRules: ParameterPointerRules,
+        value: 5_000.0, constraints: crossoverConstraints, unit: "Hz"
+    },
+    20: {
+        type: "array", name: "bands", length: 4, element: {
+            type: "object",
+            class: {
+                name: "MultibandCompressorBand",
+                fields: {
+                    1: {
+                        type: "float32", name: "threshold", pointerRules: ParameterPointerRules,
+                        value: -20.0, constraints: {min: -60.0, max: 0.0, scaling: "linear"}, unit: "dB"
+                    },
+                    2: {
+                        type: "float32", name: "ratio", pointerRules: ParameterPointerRules,
+                        value: 4.0, constraints: {min: 1.0, max: 24.0, scaling: "exponential"}, unit: ""
+                    },
+                    3: {
+                        type: "float32", name: "attack", pointerRules: ParameterPointerRules,
+                        value: 10.0, constraints: {min: 0.0, max: 100.0, scaling: "linear"}, unit: "ms"
+                    },
+                    4: {
+                        type: "float32", name: "release", pointerRules: ParameterPointerRules,
+                        value: 150.0, constraints: {min: 5.0, max: 1500.0, scaling: "linear"}, unit: "ms"
+                    },
+                    5: {
+                        type: "float32", name: "gain", pointerRules: ParameterPointerRules,
+                        value: 0.0, constraints: {min: -24.0, max: 24.0, scaling: "linear"}, unit: "dB"
+                    },
+                    6: {type: "boolean", name: "solo", pointerRules: ParameterPointerRules, value: false},
+                    7: {type: "boolean", name: "bypass", pointerRules: ParameterPointerRules, value: false}
+                }
+            }
+        }
+    }
+})
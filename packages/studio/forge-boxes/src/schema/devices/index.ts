@@ -4,8 +4,12 @@ import {RevampDeviceBox} from "./audio-effects/RevampDeviceBox"
 import {ReverbDeviceBox} from "./audio-effects/ReverbDeviceBox"
 import {TapeDeviceBox} from "./instruments/TapeDeviceBox"
 import {VaporisateurDeviceBox} from "./instruments/VaporisateurDeviceBox"
+import {WavetableDeviceBox} from "./instruments/WavetableDeviceBox"
+import {FMSynthDeviceBox} from "./instruments/FMSynthDeviceBox"
 import {ArpeggioDeviceBox} from "./midi-effects/ArpeggioDeviceBox"
 import {PitchDeviceBox} from "./midi-effects/PitchDeviceBox"
+import {ScaleDeviceBox} from "./midi-effects/ScaleDeviceBox"
+import {RatchetDeviceBox} from "./midi-effects/RatchetDeviceBox"
 import {NanoDeviceBox} from "./instruments/NanoDeviceBox"
 import {PlayfieldDeviceBox, PlayfieldSampleBox} from "./instruments/PlayfieldDeviceBox"
 import {StereoToolDeviceBox} from "./audio-effects/StereoToolDeviceBox"
@@ -15,6 +19,7 @@ import {UnknownMidiEffectDevice} from "./midi-effects/UnknownMidiEffectDevice"
 import {SoundfontDeviceBox} from "./instruments/SoundfontDeviceBox"
 import {MaximizerDeviceBox} from "./audio-effects/MaximizerDeviceBox"
 import {CompressorDeviceBox} from "./audio-effects/CompressorDeviceBox"
+import {MultibandCompressorDeviceBox} from "./audio-effects/MultibandCompressorDeviceBox"
 import {AutotuneDeviceBox} from "./audio-effects/AutotuneDeviceBox"
 import {CrusherDeviceBox} from "./audio-effects/CrusherDeviceBox"
 import {FoldDeviceBox} from "./audio-effects/FoldDeviceBox"
@@ -24,7 +29,10 @@ import {MIDIOutputBox} from "./instruments/MIDIOutputBox"
 import {MIDIOutputParameterBox} from "./instruments/MIDIOutputParameterBox"
 import {TidalDeviceBox} from "./audio-effects/TidalDeviceBox"
 import {DattorroReverbDeviceBox} from "./audio-effects/DattorroReverbDeviceBox"
+import {ConvolutionReverbDeviceBox} from "./audio-effects/ConvolutionReverbDeviceBox"
 import {GateDeviceBox} from "./audio-effects/GateDeviceBox"
+import {FilterDeviceBox} from "./audio-effects/FilterDeviceBox"
+import {ChorusDeviceBox} from "./audio-effects/ChorusDeviceBox"
 import {NeuralAmpDeviceBox} from "./audio-effects/NeuralAmpDeviceBox"
 import {VocoderDeviceBox} from "./audio-effects/VocoderDeviceBox"
 import {WaveshaperDeviceBox} from "./audio-effects/WaveshaperDeviceBox"
@@ -49,17 +57,23 @@ export const DeviceDefinitions = [
     StereoToolDeviceBox,
     MaximizerDeviceBox,
     CompressorDeviceBox,
+    MultibandCompressorDeviceBox,
     GateDeviceBox,
+    FilterDeviceBox,
+    ChorusDeviceBox,
     DelayDeviceBox,
     AutotuneDeviceBox,
     CrusherDeviceBox,
     DattorroReverbDeviceBox,
+    ConvolutionReverbDeviceBox,
     VelocityDeviceBox,
     FoldDeviceBox,
     TidalDeviceBox,
     RevampDeviceBox,
     ReverbDeviceBox,
     VaporisateurDeviceBox,
+    WavetableDeviceBox,
+    FMSynthDeviceBox,
     MIDIOutputDeviceBox,
     MIDIOutputBox,
     MIDIOutputParameterBox,
@@ -70,6 +84,8 @@ export const DeviceDefinitions = [
     TapeDeviceBox,
     ArpeggioDeviceBox,
     PitchDeviceBox,
+    ScaleDeviceBox,
+    RatchetDeviceBox,
     ZeitgeistDeviceBox,
     NeuralAmpDeviceBox,
     VocoderDeviceBox,
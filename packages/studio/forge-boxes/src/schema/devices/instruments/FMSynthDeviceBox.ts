@@ -0,0 +1,66 @@
+import {BoxSchema} from "@opendaw/lib-box-forge"
+import {Pointers, VoicingMode} from "@opendaw/studio-enums"
+import {ParameterPointerRules, UnipolarConstraints} from "../../std/Defaults"
+import {DeviceFactory} from "../../std/DeviceFactory"
+
+const TimeConstraints = {constraints: {min: 0.001, max: 5.0, scaling: "exponential"}, unit: "s"} as const
+
+export const FMSynthDeviceBox: BoxSchema<Pointers> = DeviceFactory.createInstrument("FMSynthDeviceBox", "notes", {
+    10: {
+        type: "float32", name: "volume", pointerRules: ParameterPointerRules,
+        value: -6.0, constraints: "decibel", unit: "dB"
+    },
+    11: {
+        type: "int32", name: "algorithm", pointerRules: ParameterPointerRules,
+        value: 0, constraints: {min: 0, max: 7}, unit: ""
+    },
+    12: {
+        type: "float32", name: "feedback", pointerRules: ParameterPointerRules,
+        value: 0.0, ...UnipolarConstraints
+    },
+    13: {
+        type: "float32", name: "glide-time", pointerRules: ParameterPointerRules,
+        value: 0.0, ...UnipolarConstraints
+    },
+    14: {
+        type: "int32", name: "voicing-mode", pointerRules: ParameterPointerRules,
+        value: VoicingMode.Polyphonic, constraints: {values: [VoicingMode.Monophonic, VoicingMode.Polyphonic]}, unit: ""
+    },
+    40: {
+        type: "array", name: "operators", length: 4, element: {
+            type: "object",
+            class: {
+                name: "FMSynthOperator",
+                fields: {
+                    1: {
+                        type: "float32", name: "ratio", pointerRules: ParameterPointerRules,
+                        value: 1.0, constraints: {min: 0.25, max: 16.0, scaling: "exponential"}, unit: ""
+                    },
+                    2: {
+                        type: "float32", name: "fine", pointerRules: ParameterPointerRules,
+                        value: 0.0, constraints: {min: -50.0, max: 50.0, scaling: "linear"}, unit: "ct"
+                    },
+                    3: {
+                        type: "float32", name: "level", pointerRules: ParameterPointerRules,
+                        value: 0.5, ...UnipolarConstraints
+                    },
+                    4: {type: "float32", name: "attack", pointerRules: ParameterPointerRules, value: 0.001, ...TimeConstraints},
+                    5: {type: "float32", name: "decay", pointerRules: ParameterPointerRules, value: 0.5, ...TimeConstraints},
+                    6: {
+                        type: "float32", name: "sustain", pointerRules: ParameterPointerRules,
+                        value: 0.5, ...UnipolarConstraints
+                    },
+                    7: {type: "float32", name: "release", pointerRules: ParameterPointerRules, value: 0.2, ...TimeConstraints},
+                    8: {
+                        type: "float32", name: "velocity", pointerRules: ParameterPointerRules,
+                        value: 0.5, ...UnipolarConstraints
+                    },
+                    9: {
+                        type: "float32", name: "key-scaling", pointerRules: ParameterPointerRules,
+                        value: 0.0, ...UnipolarConstraints
+                    }
+                }
+            }
+        }
+    }
+})
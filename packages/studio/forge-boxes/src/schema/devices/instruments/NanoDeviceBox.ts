@@ -12,5 +12,6 @@ export const NanoDeviceBox: BoxSchema<Pointers> = DeviceFactory.createInstrument
     20: {
         type: "float32", name: "release", pointerRules: ParameterPointerRules,
         value: 0.1, constraints: {min: 0.001, max: 8.0, scaling: "exponential"}, unit: "s"
-    }
+    },
+    25: {type: "int32", name: "interpolation", value: 0, constraints: {length: 3}, unit: ""} // Linear, Sinc, Sinc HQ
 })
\ No newline at end of file
@@ -56,7 +56,8 @@ export const PlayfieldSampleBox: BoxSchema<Pointers> = {
             49: {
                 type: "float32", name: "release", pointerRules: ParameterPointerRules,
                 value: 0.020, constraints: {min: 0.001, max: 5.0, scaling: "exponential"}, unit: "s"
-            }
+            },
+            50: {type: "int32", name: "interpolation", value: 0, constraints: {length: 3}, unit: ""} // Linear, Sinc, Sinc HQ
         }
     },
     pointerRules: {accepts: [Pointers.Editing, Pointers.SideChain, Pointers.Selection], mandatory: false},
@@ -4,6 +4,7 @@ import {BipolarConstraints, ParameterPointerRules, UnipolarConstraints} from "..
 import {DeviceFactory} from "../../std/DeviceFactory"
 import {ClassicWaveform} from "@opendaw/lib-dsp"
 
+const TimeConstraints = {constraints: {min: 0.001, max: 5.0, scaling: "exponential"}, unit: "s"} as const
 const NoiseEnv = {value: 0.001, constraints: {min: 0.001, max: 5.0, scaling: "exponential"}, unit: "s"} as const
 
 export const VaporisateurDeviceBox: BoxSchema<Pointers> = DeviceFactory.createInstrument("VaporisateurDeviceBox", "notes", {
@@ -114,6 +115,40 @@ export const VaporisateurDeviceBox: BoxSchema<Pointers> = DeviceFactory.createIn
             }
         }
     },
+    31: {
+        type: "object", name: "mod-lfo", class: {
+            name: "VaporisateurModLFO",
+            fields: {
+                1: {
+                    type: "int32", name: "waveform", pointerRules: ParameterPointerRules,
+                    constraints: {
+                        values: [
+                            ClassicWaveform.sine, ClassicWaveform.triangle,
+                            ClassicWaveform.saw, ClassicWaveform.square
+                        ]
+                    }, unit: ""
+                },
+                2: {
+                    type: "float32", name: "rate", pointerRules: ParameterPointerRules,
+                    value: 1.0, constraints: {min: 0.0001, max: 30.0, scaling: "exponential"}, unit: "Hz"
+                }
+            }
+        }
+    },
+    32: {
+        type: "object", name: "mod-envelope", class: {
+            name: "VaporisateurModEnvelope",
+            fields: {
+                1: {type: "float32", name: "attack", pointerRules: ParameterPointerRules, value: 0.001, ...TimeConstraints},
+                2: {type: "float32", name: "decay", pointerRules: ParameterPointerRules, value: 0.5, ...TimeConstraints},
+                3: {
+                    type: "float32", name: "sustain", pointerRules: ParameterPointerRules,
+                    value: 0.0, ...UnipolarConstraints
+                },
+                4: {type: "float32", name: "release", pointerRules: ParameterPointerRules, value: 0.1, ...TimeConstraints}
+            }
+        }
+    },
     40: {
         type: "array", name: "oscillators", length: 2, element: {
             type: "object",
@@ -168,5 +203,29 @@ export const VaporisateurDeviceBox: BoxSchema<Pointers> = DeviceFactory.createIn
             }
         }
     },
+    60: {
+        type: "array", name: "modulations", length: 8, element: {
+            type: "object",
+            class: {
+                name: "VaporisateurModulation",
+                fields: {
+                    // off, lfo-1, lfo-2, env-1, env-2, velocity, key, mod-wheel, aftertouch, random
+                    1: {
+                        type: "int32", name: "source", pointerRules: ParameterPointerRules,
+                        value: 0, constraints: {min: 0, max: 9}, unit: ""
+                    },
+                    // off, osc-a-tune, osc-b-tune, osc-a-volume, osc-b-volume, cutoff, resonance, pan, unison-detune
+                    2: {
+                        type: "int32", name: "target", pointerRules: ParameterPointerRules,
+                        value: 0, constraints: {min: 0, max: 8}, unit: ""
+                    },
+                    3: {
+                        type: "float32", name: "amount", pointerRules: ParameterPointerRules,
+                        ...BipolarConstraints
+                    }
+                }
+            }
+        }
+    },
     99: {type: "int32", name: "version", constraints: "any", unit: ""}
 })
\ No newline at end of file
@@ -0,0 +1,69 @@
+import {BoxSchema} from "@opendaw/lib-box-forge"
+import {Pointers, VoicingMode} from "@opendaw/studio-enums"
+import {BipolarConstraints, ParameterPointerRules, UnipolarConstraints} from "../../std/Defaults"
+import {DeviceFactory} from "../../std/DeviceFactory"
+
+const TimeConstraints = {constraints: {min: 0.001, max: 5.0, scaling: "exponential"}, unit: "s"} as const
+
+export const WavetableDeviceBox: BoxSchema<Pointers> = DeviceFactory.createInstrument("WavetableDeviceBox", "notes", {
+    10: {
+        type: "float32", name: "volume", pointerRules: ParameterPointerRules,
+        value: -6.0, constraints: "decibel", unit: "dB"
+    },
+    11: {type: "pointer", name: "file", pointerType: Pointers.AudioFile, mandatory: false},
+    12: {
+        type: "float32", name: "position", pointerRules: ParameterPointerRules,
+        value: 0.0, ...UnipolarConstraints
+    },
+    13: {
+        type: "float32", name: "position-envelope", pointerRules: ParameterPointerRules,
+        ...BipolarConstraints
+    },
+    14: {
+        type: "int32", name: "octave", pointerRules: ParameterPointerRules,
+        value: 0, constraints: {min: -3, max: 3}, unit: "oct"
+    },
+    15: {
+        type: "float32", name: "tune", pointerRules: ParameterPointerRules,
+        constraints: {min: -1200.0, max: 1200.0, scaling: "linear"}, unit: "ct"
+    },
+    16: {
+        type: "float32", name: "cutoff", pointerRules: ParameterPointerRules,
+        value: 20_000.0, constraints: {min: 20.0, max: 20_000.0, scaling: "exponential"}, unit: "Hz"
+    },
+    17: {
+        type: "float32", name: "resonance", pointerRules: ParameterPointerRules,
+        value: Math.SQRT1_2, constraints: {min: 0.01, max: 10.0, scaling: "exponential"}, unit: "q"
+    },
+    18: {
+        type: "float32", name: "filter-envelope", pointerRules: ParameterPointerRules,
+        ...BipolarConstraints
+    },
+    19: {type: "float32", name: "attack", pointerRules: ParameterPointerRules, value: 0.001, ...TimeConstraints},
+    20: {type: "float32", name: "decay", pointerRules: ParameterPointerRules, value: 0.001, ...TimeConstraints},
+    21: {
+        type: "float32", name: "sustain", pointerRules: ParameterPointerRules,
+        value: 1.0, ...UnipolarConstraints
+    },
+    22: {type: "float32", name: "release", pointerRules: ParameterPointerRules, value: 0.1, ...TimeConstraints},
+    23: {
+        type: "float32", name: "glide-time", pointerRules: ParameterPointerRules,
+        value: 0.0, ...UnipolarConstraints
+    },
+    24: {
+        type: "int32", name: "voicing-mode", pointerRules: ParameterPointerRules,
+        value: VoicingMode.Polyphonic, constraints: {values: [VoicingMode.Monophonic, VoicingMode.Polyphonic]}, unit: ""
+    },
+    25: {
+        type: "int32", name: "unison-count", pointerRules: ParameterPointerRules,
+        value: 1, constraints: {values: [1, 3, 5]}, unit: ""
+    },
+    26: {
+        type: "float32", name: "unison-detune", pointerRules: ParameterPointerRules,
+        value: 30, constraints: {min: 1.0, max: 1200.0, scaling: "exponential"}, unit: "ct"
+    },
+    27: {
+        type: "float32", name: "unison-stereo", pointerRules: ParameterPointerRules,
+        value: 1.0, ...UnipolarConstraints
+    }
+})
@@ -6,7 +6,7 @@ import {DeviceFactory} from "../../std/DeviceFactory"
 export const ArpeggioDeviceBox: BoxSchema<Pointers> = DeviceFactory.createMidiEffect("ArpeggioDeviceBox", {
     10: {
         type: "int32", name: "mode-index", pointerRules: ParameterPointerRules,
-        value: 0, constraints: {length: 3}, unit: ""
+        value: 0, constraints: {length: 8}, unit: "" // Up, Down, UpDown, AsPlayed, Random, Converge, Diverge, Chord
     },
     11: {
         type: "int32", name: "num-octaves", pointerRules: ParameterPointerRules,
@@ -27,5 +27,20 @@ export const ArpeggioDeviceBox: BoxSchema<Pointers> = DeviceFactory.createMidiEf
     15: {
         type: "float32", name: "velocity", pointerRules: ParameterPointerRules,
         value: 0.0, constraints: "bipolar", unit: ""
+    },
+    16: {type: "int32", name: "pattern-length", value: 0, constraints: {min: 0, max: 16}, unit: ""},
+    17: {
+        type: "array", name: "pattern", length: 16, element: {
+            type: "object",
+            class: {
+                name: "ArpeggioStep",
+                fields: {
+                    1: {type: "float32", name: "velocity", value: 1.0, constraints: "unipolar", unit: "%"},
+                    2: {type: "float32", name: "gate", value: 1.0, constraints: {min: 0.0, max: 2.0, scaling: "linear"}, unit: ""},
+                    3: {type: "boolean", name: "tie", value: false},
+                    4: {type: "boolean", name: "rest", value: false}
+                }
+            }
+        }
     }
 })
\ No newline at end of file
@@ -0,0 +1,31 @@
+import {BoxSchema} from "@opendaw/lib-box-forge"
+import {Pointers} from "@opendaw/studio-enums"
+import {ParameterPointerRules} from "../../std/Defaults"
+import {DeviceFactory} from "../../std/DeviceFactory"
+
+export const RatchetDeviceBox: BoxSchema<Pointers> = DeviceFactory.createMidiEffect("RatchetDeviceBox", {
+    10: {
+        type: "int32", name: "rate-index", pointerRules: ParameterPointerRules,
+        value: 9, constraints: {length: 17}, unit: ""
+    },
+    11: {
+        type: "float32", name: "gate", pointerRules: ParameterPointerRules,
+        value: 0.5, constraints: "unipolar", unit: ""
+    },
+    12: {
+        type: "float32", name: "velocity-ramp", pointerRules: ParameterPointerRules,
+        value: 0.0, constraints: "bipolar", unit: ""
+    },
+    13: {type: "int32", name: "ratchet-length", value: 0, constraints: {min: 0, max: 16}, unit: ""},
+    14: {
+        type: "array", name: "ratchets", length: 16, element: {
+            type: "object",
+            class: {
+                name: "RatchetStep",
+                fields: {
+                    1: {type: "int32", name: "count", value: 1, constraints: {min: 1, max: 8}, unit: ""}
+                }
+            }
+        }
+    }
+})
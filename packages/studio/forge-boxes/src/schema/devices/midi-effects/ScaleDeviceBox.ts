@@ -0,0 +1,27 @@
+import {BoxSchema} from "@opendaw/lib-box-forge"
+import {Pointers} from "@opendaw/studio-enums"
+import {ParameterPointerRules} from "../../std/Defaults"
+import {DeviceFactory} from "../../std/DeviceFactory"
+
+export const ScaleDeviceBox: BoxSchema<Pointers> = DeviceFactory.createMidiEffect("ScaleDeviceBox", {
+    10: {
+        type: "int32", name: "key", pointerRules: ParameterPointerRules,
+        value: 0, constraints: {min: 0, max: 11}, unit: ""
+    },
+    11: {
+        type: "int32", name: "scale", pointerRules: ParameterPointerRules,
+        value: 1, constraints: {length: 8}, unit: "" // Chromatic, Major, Minor, MajPent, MinPent, Blues, Dorian, Mixolydian
+    },
+    12: {
+        type: "int32", name: "chord", pointerRules: ParameterPointerRules,
+        value: 0, constraints: {length: 3}, unit: "" // Off, Triad, Seventh
+    },
+    13: {
+        type: "int32", name: "inversion", pointerRules: ParameterPointerRules,
+        value: 0, constraints: {min: 0, max: 3}, unit: ""
+    },
+    14: {
+        type: "int32", name: "strum", pointerRules: ParameterPointerRules,
+        value: 0, constraints: {min: 0, max: 480}, unit: "ppqn"
+    }
+})
@@ -1,5 +1,5 @@
 import {Pointers} from "@opendaw/studio-enums"
-import {ParameterPointerRules, UnipolarConstraints} from "./Defaults"
+import {BipolarConstraints, ParameterPointerRules, UnipolarConstraints} from "./Defaults"
 import {BoxSchema, FieldRecord, mergeFields, reserveMany} from "@opendaw/lib-box-forge"
 import {PPQN} from "@opendaw/lib-dsp"
 import {Objects} from "@opendaw/lib-std"
@@ -26,6 +26,20 @@ export const GrooveShuffleBox: BoxSchema<Pointers> = createGrooveBox("GrooveShuf
         type: "int32", name: "duration", pointerRules: ParameterPointerRules,
         value: PPQN.fromSignature(1, 8),
         constraints: "non-negative", unit: "ppqn"
+    },
+    12: {type: "int32", name: "template-steps", value: 0, constraints: {min: 0, max: 16}, unit: ""},
+    13: {type: "int32", name: "template-step", value: PPQN.fromSignature(1, 16), constraints: "positive", unit: "ppqn"},
+    14: {
+        type: "array", name: "template", length: 16, element: {
+            type: "object",
+            class: {
+                name: "GrooveTemplateStep",
+                fields: {
+                    1: {type: "float32", name: "offset", value: 0.0, ...BipolarConstraints},
+                    2: {type: "float32", name: "accent", value: 1.0, constraints: {min: 0.0, max: 2.0, scaling: "linear"}, unit: ""}
+                }
+            }
+        }
     }
 })
 
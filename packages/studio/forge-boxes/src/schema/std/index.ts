@@ -18,6 +18,7 @@ import {AudioClipBox} from "./timeline/AudioClipBox"
 import {NoteEventBox} from "./timeline/NoteEventBox"
 import {NoteEventRepeatBox} from "./timeline/NoteEventRepeatBox"
 import {NoteEventCollectionBox} from "./timeline/NoteEventCollectionBox"
+import {NoteControlEventBox} from "./timeline/NoteControlEventBox"
 import {NoteRegionBox} from "./timeline/NoteRegionBox"
 import {ValueEventBox} from "./timeline/ValueEventBox"
 import {ValueEventCurveBox} from "./timeline/ValueEventCurveBox"
@@ -38,7 +39,7 @@ export const Definitions = [
     MetaDataBox, ProjectMetaBox,
     RootBox, SelectionBox, UserInterfaceBox, UploadFileBox, ShadertoyBox, MIDIControllerBox,
     TimelineBox, TrackBox,
-    NoteEventBox, NoteEventRepeatBox, NoteEventCollectionBox, NoteRegionBox, NoteClipBox,
+    NoteEventBox, NoteEventRepeatBox, NoteEventCollectionBox, NoteControlEventBox, NoteRegionBox, NoteClipBox,
     ValueEventBox, ValueEventCollectionBox, ValueEventCurveBox, ValueRegionBox, ValueClipBox, SignatureEventBox,
     AudioRegionBox, AudioClipBox, AudioPitchStretchBox, AudioTimeStretchBox, AudioSignalsmithBox, TransientMarkerBox, WarpMarkerBox,
     MarkerBox,
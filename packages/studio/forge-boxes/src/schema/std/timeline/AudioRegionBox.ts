@@ -34,7 +34,8 @@ export const AudioRegionBox: BoxSchema<Pointers> = {
                         4: {type: "float32", name: "out-slope", value: 0.25, constraints: "unipolar", unit: "ratio"}
                     }
                 }
-            }
+            },
+            19: {type: "int32", name: "interpolation", value: 0, constraints: {length: 3}, unit: ""} // Linear, Sinc, Sinc HQ
         }
     }, pointerRules: {accepts: [Pointers.Selection, Pointers.Editing, Pointers.MetaData], mandatory: false}
 }
\ No newline at end of file
@@ -0,0 +1,23 @@
+import {BoxSchema} from "@opendaw/lib-box-forge"
+import {NoteControlType, Pointers} from "@opendaw/studio-enums"
+import {BipolarConstraints, MidiNoteConstraints, PPQNPositionConstraints} from "../Defaults"
+
+// A channel event stored next to the notes of a collection: a MIDI CC (value 0...1), the pitch wheel
+// (value -1...1) or channel pressure (value 0...1). 'controller' is only read for a CC.
+export const NoteControlEventBox: BoxSchema<Pointers> = {
+    type: "box",
+    class: {
+        name: "NoteControlEventBox",
+        fields: {
+            1: {type: "pointer", name: "events", pointerType: Pointers.NoteControlEvents, mandatory: true},
+            10: {type: "int32", name: "position", ...PPQNPositionConstraints},
+            11: {
+                type: "int32", name: "type", value: NoteControlType.Controller,
+                constraints: {values: [NoteControlType.Controller, NoteControlType.PitchBend, NoteControlType.ChannelPressure]},
+                unit: ""
+            },
+            12: {type: "int32", name: "controller", value: 1, ...MidiNoteConstraints}, // [0...127]
+            13: {type: "float32", name: "value", value: 0.0, ...BipolarConstraints} // [-1...1]
+        }
+    }, pointerRules: {accepts: [Pointers.Selection], mandatory: false}
+}
\ No newline at end of file
@@ -7,7 +7,8 @@ export const NoteEventCollectionBox: BoxSchema<Pointers> = {
         name: "NoteEventCollectionBox",
         fields: {
             1: {type: "field", name: "events", pointerRules: {accepts: [Pointers.NoteEvents], mandatory: false}},
-            2: {type: "field", name: "owners", pointerRules: {accepts: [Pointers.NoteEventCollection], mandatory: true}}
+            2: {type: "field", name: "owners", pointerRules: {accepts: [Pointers.NoteEventCollection], mandatory: true}},
+            3: {type: "field", name: "controls", pointerRules: {accepts: [Pointers.NoteControlEvents], mandatory: false}}
         }
     }, pointerRules: {accepts: [Pointers.Selection], mandatory: false},
     resource: "shared"